@@ -14,7 +14,9 @@ use tauri::{command, AppHandle, State};
 
 use super::auth::AppState;
 use super::github::CachedResponse;
+use super::repo_filter::load_repository_filter;
 use crate::auth::{handle_unauthorized, reasons};
+use crate::database::repo_filter::RepositoryRef;
 use crate::database::{cache_durations, cache_types};
 use crate::github::client::GitHubError;
 use crate::github::types::ActivityEvent;
@@ -289,7 +291,15 @@ pub async fn get_activity_feed_with_cache(
     .await;
 
     match events_result {
-        Ok(events) => {
+        Ok(mut events) => {
+            // Events only carry the repository name, so the fork / archived /
+            // private parts of the filter can't apply here. Best-effort: an
+            // unreadable filter shows every event.
+            match load_repository_filter(state.db.pool(), user.id).await {
+                Ok(filter) => events
+                    .retain(|e| filter.allows(&RepositoryRef::named(&e.repo.name), &user.username)),
+                Err(e) => eprintln!("Failed to load repository filter: {}", e),
+            }
             let items: Vec<ActivityFeedItem> = events.iter().map(normalize_event).collect();
             let payload = ActivityFeed { items };
 
@@ -2,12 +2,12 @@
 //!
 //! These commands handle challenge-related operations: CRUD, progress tracking, etc.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use tauri::{command, State};
 
 use super::auth::AppState;
 use crate::database::challenge;
-use crate::database::models::Challenge;
+use crate::database::models::{Challenge, ChallengeTokens, ChallengeType, TokenSpend};
 
 /// Challenge info for frontend with additional computed fields
 #[derive(Debug, Clone, serde::Serialize)]
@@ -24,11 +24,15 @@ pub struct ChallengeInfo {
     pub end_date: String,
     pub status: String,
     pub completed_at: Option<String>,
+    /// Why an auto-generated target was chosen
+    pub target_reason: Option<String>,
     // Computed fields
     pub progress_percent: f32,
     pub remaining_time_hours: i64,
     pub is_completed: bool,
     pub is_expired: bool,
+    /// Scheduled custom challenge whose period has not begun yet
+    pub is_upcoming: bool,
 }
 
 impl From<Challenge> for ChallengeInfo {
@@ -42,6 +46,7 @@ impl From<Challenge> for ChallengeInfo {
         let remaining_hours = (c.end_date - now).num_hours().max(0);
         let is_completed = c.status == "completed";
         let is_expired = c.end_date < now && c.status == "active";
+        let is_upcoming = c.start_date > now && c.status == "active";
 
         ChallengeInfo {
             id: c.id,
@@ -55,10 +60,12 @@ impl From<Challenge> for ChallengeInfo {
             end_date: c.end_date.to_rfc3339(),
             status: c.status,
             completed_at: c.completed_at.map(|dt| dt.to_rfc3339()),
+            target_reason: c.target_reason,
             progress_percent: progress,
             remaining_time_hours: remaining_hours,
             is_completed,
             is_expired,
+            is_upcoming,
         }
     }
 }
@@ -71,6 +78,38 @@ pub struct CreateChallengeRequest {
     pub target_metric: String,
     pub target_value: i32,
     pub reward_xp: Option<i32>,
+    /// First day of a custom challenge (`YYYY-MM-DD`, UTC)
+    #[serde(default)]
+    pub start_date: Option<String>,
+    /// Last day of a custom challenge, inclusive (`YYYY-MM-DD`, UTC)
+    #[serde(default)]
+    pub end_date: Option<String>,
+}
+
+/// Resolve the period of a new challenge: fixed windows for the calendar
+/// types, the requested dates for `custom`.
+fn resolve_challenge_period(
+    request: &CreateChallengeRequest,
+    challenge_type: ChallengeType,
+    now: chrono::DateTime<Utc>,
+) -> Result<(chrono::DateTime<Utc>, chrono::DateTime<Utc>), String> {
+    if challenge_type != ChallengeType::Custom {
+        return Ok(challenge::calculate_challenge_period(
+            &request.challenge_type,
+            now,
+        ));
+    }
+
+    let parse = |value: &Option<String>, field: &str| {
+        let value = value
+            .as_deref()
+            .ok_or_else(|| format!("カスタムチャレンジには{}が必要です", field))?;
+        chrono::NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+            .map_err(|e| format!("{}の形式が不正です: {}", field, e))
+    };
+    let start = parse(&request.start_date, "開始日")?;
+    let end = parse(&request.end_date, "終了日")?;
+    challenge::calculate_custom_challenge_period(start, end, now)
 }
 
 /// Get all active challenges for current user
@@ -156,22 +195,21 @@ pub async fn create_challenge(
         .ok_or("Not logged in")?;
 
     // Validate challenge type
-    if request.challenge_type != "daily" && request.challenge_type != "weekly" {
-        return Err("Invalid challenge type. Must be 'daily' or 'weekly'".to_string());
-    }
+    let challenge_type = ChallengeType::parse(&request.challenge_type).ok_or(
+        "Invalid challenge type. Must be one of: daily, weekly, monthly, quarterly, custom",
+    )?;
 
     // Validate target metric
-    let valid_metrics = ["commits", "prs", "reviews", "issues"];
-    if !valid_metrics.contains(&request.target_metric.as_str()) {
-        return Err(
-            "Invalid target metric. Must be one of: commits, prs, reviews, issues".to_string(),
-        );
-    }
+    challenge::validate_target_metric(&request.target_metric)?;
+    let target_metric = match challenge::language_of_metric(&request.target_metric) {
+        Some(language) => format!("{}{}", challenge::LANGUAGE_METRIC_PREFIX, language),
+        None => request.target_metric.clone(),
+    };
 
     // Check if there's already an active challenge of this type and metric
     if state
         .db
-        .has_active_challenge(user.id, &request.challenge_type, &request.target_metric)
+        .has_active_challenge(user.id, &request.challenge_type, &target_metric)
         .await
         .map_err(|e| e.to_string())?
     {
@@ -179,12 +217,15 @@ pub async fn create_challenge(
     }
 
     let now = Utc::now();
-    let (start_date, end_date) =
-        challenge::calculate_challenge_period(&request.challenge_type, now);
+    let (start_date, end_date) = resolve_challenge_period(&request, challenge_type, now)?;
 
-    // Calculate reward XP if not provided
+    // Calculate reward XP if not provided, scaled for longer windows
     let reward_xp = request.reward_xp.unwrap_or_else(|| {
-        challenge::calculate_reward_xp(&request.target_metric, request.target_value)
+        challenge::calculate_period_reward_xp(
+            &target_metric,
+            request.target_value,
+            end_date - start_date,
+        )
     });
 
     let challenge = state
@@ -192,7 +233,7 @@ pub async fn create_challenge(
         .create_challenge(
             user.id,
             &request.challenge_type,
-            &request.target_metric,
+            &target_metric,
             request.target_value,
             reward_xp,
             start_date,
@@ -332,6 +373,155 @@ pub async fn get_challenge_stats(state: State<'_, AppState>) -> Result<Challenge
     })
 }
 
+/// Error of a token spent on a challenge that isn't (or stopped being)
+/// active
+const INACTIVE_CHALLENGE_MESSAGE: &str = "進行中のチャレンジではありません";
+
+/// Check that a token can be spent on `challenge`: an auto-generated
+/// daily/weekly challenge that is still running
+fn check_token_target(challenge: &Challenge, now: DateTime<Utc>) -> Result<(), String> {
+    if !challenge::TOKEN_CHALLENGE_TYPES.contains(&challenge.challenge_type.as_str()) {
+        return Err(
+            "リロール・スキップできるのはデイリー/ウィークリーチャレンジのみです".to_string(),
+        );
+    }
+    if challenge.status != "active" || challenge.end_date < now {
+        return Err(INACTIVE_CHALLENGE_MESSAGE.to_string());
+    }
+    Ok(())
+}
+
+/// Turn a token spend that didn't go through into its error message
+fn token_spend_result(spend: TokenSpend, no_token_message: &str) -> Result<(), String> {
+    match spend {
+        TokenSpend::Spent => Ok(()),
+        TokenSpend::NoToken => Err(no_token_message.to_string()),
+        // Finished or retired after `check_token_target` looked at it
+        TokenSpend::ChallengeInactive => Err(INACTIVE_CHALLENGE_MESSAGE.to_string()),
+    }
+}
+
+/// Get the current user's reroll / skip token balance
+#[command]
+pub async fn get_challenge_tokens(state: State<'_, AppState>) -> Result<ChallengeTokens, String> {
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    state
+        .db
+        .get_challenge_tokens(user.id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Replace an active daily/weekly challenge with another template,
+/// spending a reroll token
+#[command]
+pub async fn reroll_challenge(
+    state: State<'_, AppState>,
+    challenge_id: i64,
+) -> Result<ChallengeInfo, String> {
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    let current = state
+        .db
+        .get_challenge_by_id(challenge_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    if current.user_id != user.id {
+        return Err("Challenge not found".to_string());
+    }
+    let now = Utc::now();
+    check_token_target(&current, now)?;
+
+    let active = state
+        .db
+        .get_active_challenges(user.id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let active_metrics: Vec<&str> = active
+        .iter()
+        .filter(|c| c.id != current.id && c.challenge_type == current.challenge_type)
+        .map(|c| c.target_metric.as_str())
+        .collect();
+    let template = challenge::pick_reroll_template(
+        &current.challenge_type,
+        &current.target_metric,
+        &active_metrics,
+        now.timestamp_subsec_nanos() as usize,
+    )
+    .ok_or("入れ替え先のチャレンジが見つかりません")?;
+
+    let spend = state
+        .db
+        .reroll_challenge_with_token(
+            user.id,
+            current.id,
+            &template.target_metric,
+            template.target_value,
+            template.reward_xp,
+            template.reason.as_deref(),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    token_spend_result(spend, "リロールトークンがありません")?;
+
+    let challenge = state
+        .db
+        .get_challenge_by_id(current.id)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(ChallengeInfo::from(challenge))
+}
+
+/// Retire an active daily/weekly challenge without failing it, spending a
+/// skip token
+#[command]
+pub async fn skip_challenge(
+    state: State<'_, AppState>,
+    challenge_id: i64,
+) -> Result<ChallengeInfo, String> {
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    let current = state
+        .db
+        .get_challenge_by_id(challenge_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    if current.user_id != user.id {
+        return Err("Challenge not found".to_string());
+    }
+    check_token_target(&current, Utc::now())?;
+
+    let spend = state
+        .db
+        .skip_challenge_with_token(user.id, current.id)
+        .await
+        .map_err(|e| e.to_string())?;
+    token_spend_result(spend, "スキップトークンがありません")?;
+
+    let challenge = state
+        .db
+        .get_challenge_by_id(current.id)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(ChallengeInfo::from(challenge))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -365,4 +555,98 @@ mod tests {
         assert!(end > now);
         assert!(end <= now + Duration::days(7));
     }
+
+    fn request(
+        challenge_type: &str,
+        start: Option<&str>,
+        end: Option<&str>,
+    ) -> CreateChallengeRequest {
+        CreateChallengeRequest {
+            challenge_type: challenge_type.to_string(),
+            target_metric: "commits".to_string(),
+            target_value: 30,
+            reward_xp: None,
+            start_date: start.map(String::from),
+            end_date: end.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_resolve_challenge_period_custom() {
+        let now = Utc::now();
+        let start = (now + Duration::days(3)).date_naive();
+        let end = start + Duration::days(13);
+        let req = request(
+            "custom",
+            Some(&start.format("%Y-%m-%d").to_string()),
+            Some(&end.format("%Y-%m-%d").to_string()),
+        );
+
+        let (from, to) = resolve_challenge_period(&req, ChallengeType::Custom, now).unwrap();
+        assert_eq!(from.date_naive(), start);
+        assert_eq!((to - from).num_days(), 14);
+
+        // Dates are required and must parse
+        assert!(resolve_challenge_period(
+            &request("custom", None, None),
+            ChallengeType::Custom,
+            now
+        )
+        .is_err());
+        assert!(resolve_challenge_period(
+            &request("custom", Some("2026/01/01"), Some("2026-01-31")),
+            ChallengeType::Custom,
+            now
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_resolve_challenge_period_ignores_dates_for_calendar_types() {
+        let now = Utc::now();
+        let req = request("monthly", Some("2000-01-01"), Some("2000-01-02"));
+        let (from, to) = resolve_challenge_period(&req, ChallengeType::Monthly, now).unwrap();
+        assert_eq!(from, now);
+        assert!(to > now && to <= now + Duration::days(31));
+        assert!(ChallengeType::parse("yearly").is_none());
+    }
+
+    fn challenge_of(challenge_type: &str, status: &str, end_date: DateTime<Utc>) -> Challenge {
+        Challenge {
+            id: 1,
+            user_id: 1,
+            challenge_type: challenge_type.to_string(),
+            target_metric: "reviews".to_string(),
+            target_value: 1,
+            current_value: 0,
+            reward_xp: 20,
+            start_date: end_date - Duration::days(1),
+            end_date,
+            status: status.to_string(),
+            completed_at: None,
+            target_reason: None,
+        }
+    }
+
+    #[test]
+    fn test_check_token_target() {
+        let now = Utc::now();
+        let later = now + Duration::hours(3);
+
+        assert!(check_token_target(&challenge_of("daily", "active", later), now).is_ok());
+        assert!(check_token_target(&challenge_of("weekly", "active", later), now).is_ok());
+
+        // Only auto-generated short challenges
+        assert!(check_token_target(&challenge_of("monthly", "active", later), now).is_err());
+        assert!(check_token_target(&challenge_of("custom", "active", later), now).is_err());
+
+        // Only while running
+        assert!(check_token_target(&challenge_of("daily", "completed", later), now).is_err());
+        assert!(check_token_target(&challenge_of("daily", "skipped", later), now).is_err());
+        assert!(check_token_target(
+            &challenge_of("daily", "active", now - Duration::hours(1)),
+            now
+        )
+        .is_err());
+    }
 }
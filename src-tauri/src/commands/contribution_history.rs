@@ -0,0 +1,225 @@
+//! Contribution history commands
+//!
+//! GitHub's contribution calendar only covers the past year, so every
+//! calendar the app fetches is persisted into `contribution_days` (see
+//! [`persist_contribution_calendar`], called from the stats sync paths) and
+//! `backfill_contribution_history` fetches the earlier years once per year.
+//! `get_contribution_history` and
+//! `get_contribution_calendar_for_year` read the local table only, so the
+//! contribution graph can switch years and show lifetime totals offline.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/commands/mod.rs
+//!   └─ src-tauri/src/commands/github.rs (persist_contribution_calendar)
+//! Dependencies:
+//!   ├─ src-tauri/src/database/models/contribution_history.rs
+//!   ├─ src-tauri/src/database/repository/contribution_history.rs
+//!   └─ src-tauri/src/github/client.rs (get_contribution_history_year)
+
+use chrono::{Datelike, NaiveDate, Utc};
+use tauri::{AppHandle, State};
+
+use crate::auth::map_github_result;
+use crate::commands::AppState;
+use crate::database::models::contribution_history::{
+    calendar_weeks, year_range, ContributionDayCount, ContributionHistory,
+};
+use crate::database::Database;
+use crate::github::types::{ContributionCalendar, ContributionDay, ContributionWeek};
+use crate::github::GitHubClient;
+
+/// `sync_metadata.sync_type` recording the last backfill run
+pub const CONTRIBUTION_HISTORY_SYNC_TYPE: &str = "contribution_history";
+
+/// Store every day of a fetched contribution calendar.
+pub(crate) async fn persist_contribution_calendar(
+    db: &Database,
+    user_id: i64,
+    calendar: &ContributionCalendar,
+) -> Result<usize, String> {
+    let days: Vec<ContributionDayCount> = calendar
+        .weeks
+        .iter()
+        .flat_map(|week| &week.contribution_days)
+        .filter_map(|day| {
+            NaiveDate::parse_from_str(&day.date, "%Y-%m-%d")
+                .ok()
+                .map(|date| ContributionDayCount {
+                    date,
+                    count: day.contribution_count,
+                })
+        })
+        .collect();
+
+    db.upsert_contribution_days(user_id, &days)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+async fn load_contribution_history(
+    db: &Database,
+    user_id: i64,
+) -> Result<ContributionHistory, String> {
+    let years = db
+        .get_contribution_year_totals(user_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let backfilled_years = db
+        .get_backfilled_contribution_years(user_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let backfilled_at = db
+        .get_sync_metadata(user_id, CONTRIBUTION_HISTORY_SYNC_TYPE)
+        .await
+        .map_err(|e| e.to_string())?
+        .and_then(|m| m.last_sync_at);
+
+    Ok(ContributionHistory::new(
+        years,
+        &backfilled_years,
+        backfilled_at,
+    ))
+}
+
+/// Get the stored contribution history with per-year and lifetime totals
+#[tauri::command]
+pub async fn get_contribution_history(
+    state: State<'_, AppState>,
+) -> Result<ContributionHistory, String> {
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    load_contribution_history(&state.db, user.id).await
+}
+
+/// Get the contribution calendar of one year from the stored history
+#[tauri::command]
+pub async fn get_contribution_calendar_for_year(
+    state: State<'_, AppState>,
+    year: i32,
+) -> Result<ContributionCalendar, String> {
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    let (start, end) = year_range(year, Utc::now().date_naive())
+        .ok_or_else(|| format!("{}年のコントリビューションはまだありません", year))?;
+    let days = state
+        .db
+        .get_contribution_days_range(user.id, start, end)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let weeks = calendar_weeks(start, end, &days)
+        .into_iter()
+        .map(|week| ContributionWeek {
+            contribution_days: week
+                .into_iter()
+                .map(|day| ContributionDay {
+                    contribution_count: day.count,
+                    date: day.date.format("%Y-%m-%d").to_string(),
+                    weekday: day.date.weekday().num_days_from_sunday() as i32,
+                })
+                .collect(),
+        })
+        .collect();
+
+    Ok(ContributionCalendar {
+        total_contributions: days.iter().map(|d| d.count).sum(),
+        weeks,
+    })
+}
+
+/// Fetch and store every past year that has not been backfilled yet.
+///
+/// The current year is kept up to date by the regular stats sync. Each
+/// year is stored as soon as it is fetched, so an interrupted backfill
+/// (rate limit, network) resumes where it stopped on the next call.
+#[tauri::command]
+pub async fn backfill_contribution_history(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<ContributionHistory, String> {
+    let token = state
+        .token_manager
+        .get_access_token()
+        .await
+        .map_err(|e| e.to_string())?;
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    let client = GitHubClient::new(token);
+    let current_year = Utc::now().year();
+    let backfilled = state
+        .db
+        .get_backfilled_contribution_years(user.id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // The first request also tells us which years have contributions
+    let first = map_github_result(
+        &app,
+        state.inner(),
+        client
+            .get_contribution_history_year(&user.username, current_year)
+            .await,
+    )
+    .await?;
+    persist_contribution_calendar(&state.db, user.id, &first.contribution_calendar).await?;
+
+    for year in first
+        .contribution_years
+        .into_iter()
+        .filter(|year| *year < current_year && !backfilled.contains(year))
+    {
+        let collection = map_github_result(
+            &app,
+            state.inner(),
+            client
+                .get_contribution_history_year(&user.username, year)
+                .await,
+        )
+        .await?;
+        persist_contribution_calendar(&state.db, user.id, &collection.contribution_calendar)
+            .await?;
+        state
+            .db
+            .mark_contribution_year_backfilled(user.id, year)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    state
+        .db
+        .get_or_create_sync_metadata(user.id, CONTRIBUTION_HISTORY_SYNC_TYPE)
+        .await
+        .map_err(|e| e.to_string())?;
+    state
+        .db
+        .update_sync_metadata(
+            user.id,
+            CONTRIBUTION_HISTORY_SYNC_TYPE,
+            Some(Utc::now().to_rfc3339()),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    load_contribution_history(&state.db, user.id).await
+}
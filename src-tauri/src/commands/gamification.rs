@@ -2,25 +2,72 @@
 //!
 //! These commands handle the gamification features: XP, levels, badges, etc.
 
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use tauri::{command, AppHandle, State};
 
 use super::auth::AppState;
+use super::github::{badge_context_from_user_stats, NewBadgeInfo};
+use super::repo_filter::load_repository_filter;
 use crate::auth::map_github_result;
-use crate::database::{badge, level, xp::XpBreakdown, Badge, UserStats, XpHistoryEntry};
+use crate::database::{
+    badge, level, season_bounds, season_level, xp::XpBreakdown, Badge, SeasonArchive, UserStats,
+    XpHistoryEntry,
+};
 use crate::github::GitHubClient;
 use crate::utils::numeric::clamp_to_u64;
 
 /// Level info for frontend
+///
+/// `total_xp` is lifetime XP; the level fields are computed from
+/// `level_xp`, the XP earned since the last prestige.
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LevelInfo {
     pub current_level: i32,
     pub total_xp: i32,
+    pub level_xp: i32,
     pub xp_for_current_level: i32,
     pub xp_for_next_level: i32,
     pub xp_to_next_level: i32,
     pub progress_percent: f32,
+    pub prestige_rank: i32,
+    pub prestige_title: Option<String>,
+    pub xp_multiplier: f64,
+    /// Level required before the user can prestige (user setting)
+    pub prestige_level: i32,
+    pub can_prestige: bool,
+}
+
+impl LevelInfo {
+    /// Build level info from user stats and the configured prestige level
+    pub fn from_stats(stats: &UserStats, prestige_level: i32) -> Self {
+        let level_xp = stats.level_xp();
+        let current_level = level::level_from_xp(level_xp);
+
+        LevelInfo {
+            current_level,
+            total_xp: stats.total_xp,
+            level_xp,
+            xp_for_current_level: level::xp_for_level(current_level),
+            xp_for_next_level: level::xp_for_level(current_level + 1),
+            xp_to_next_level: level::xp_to_next_level(level_xp),
+            progress_percent: level::progress_to_next_level(level_xp),
+            prestige_rank: stats.prestige_rank,
+            prestige_title: level::prestige_title(stats.prestige_rank).map(str::to_string),
+            xp_multiplier: level::prestige_xp_multiplier(stats.prestige_rank),
+            prestige_level,
+            can_prestige: level::can_prestige(current_level, stats.prestige_rank, prestige_level),
+        }
+    }
+}
+
+/// Result of a prestige
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrestigeResult {
+    pub level_info: LevelInfo,
+    /// Badges earned by reaching the new prestige rank
+    pub new_badges: Vec<NewBadgeInfo>,
 }
 
 /// Get level info for current user
@@ -40,17 +87,13 @@ pub async fn get_level_info(state: State<'_, AppState>) -> Result<Option<LevelIn
             .map_err(|e| e.to_string())?;
 
         if let Some(s) = stats {
-            let total_xp = s.total_xp;
-            let current_level = level::level_from_xp(total_xp);
-
-            Ok(Some(LevelInfo {
-                current_level,
-                total_xp,
-                xp_for_current_level: level::xp_for_level(current_level),
-                xp_for_next_level: level::xp_for_level(current_level + 1),
-                xp_to_next_level: level::xp_to_next_level(total_xp),
-                progress_percent: level::progress_to_next_level(total_xp),
-            }))
+            let settings = state
+                .db
+                .get_or_create_user_settings(u.id)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            Ok(Some(LevelInfo::from_stats(&s, settings.prestige_level)))
         } else {
             Ok(None)
         }
@@ -59,6 +102,198 @@ pub async fn get_level_info(state: State<'_, AppState>) -> Result<Option<LevelIn
     }
 }
 
+/// Prestige: reset the level to 1 while keeping XP history and badges
+///
+/// Requires the user to have reached their configured prestige level.
+/// Raises the prestige rank (and with it the permanent XP multiplier) and
+/// awards any prestige badges the new rank qualifies for.
+#[command]
+pub async fn prestige(state: State<'_, AppState>) -> Result<PrestigeResult, String> {
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    let stats = state
+        .db
+        .get_user_stats(user.id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("User stats not found")?;
+    let settings = state
+        .db
+        .get_or_create_user_settings(user.id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let current_level = level::level_from_xp(stats.level_xp());
+    if stats.prestige_rank >= level::MAX_PRESTIGE_RANK {
+        return Err("プレステージランクが上限に達しています".to_string());
+    }
+    if !level::can_prestige(current_level, stats.prestige_rank, settings.prestige_level) {
+        return Err(format!(
+            "プレステージにはレベル {} 以上が必要です",
+            settings.prestige_level
+        ));
+    }
+
+    let updated = state
+        .db
+        .prestige_user(user.id, &stats)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("プレステージに失敗しました。もう一度お試しください")?;
+
+    // Award prestige badges right away instead of waiting for the next sync
+    let new_badges = award_new_badges(&state, user.id, &updated).await?;
+
+    Ok(PrestigeResult {
+        level_info: LevelInfo::from_stats(&updated, settings.prestige_level),
+        new_badges,
+    })
+}
+
+/// Evaluate badges against the locally stored stats and award any newly
+/// earned ones
+async fn award_new_badges(
+    state: &State<'_, AppState>,
+    user_id: i64,
+    stats: &UserStats,
+) -> Result<Vec<NewBadgeInfo>, String> {
+    let earned_badge_ids: Vec<String> = state
+        .db
+        .get_user_badges(user_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|b| b.badge_id)
+        .collect();
+    let badge_context = badge_context_from_user_stats(stats);
+    let badge_definitions = badge::get_all_badge_definitions();
+
+    let mut new_badges = Vec::new();
+    for result in badge::evaluate_badges(&badge_context, &earned_badge_ids) {
+        state
+            .db
+            .award_badge(user_id, &result.badge_type, &result.badge_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if let Some(def) = badge_definitions.iter().find(|d| d.id == result.badge_id) {
+            new_badges.push(NewBadgeInfo {
+                badge_id: def.id.clone(),
+                badge_type: def.badge_type.clone(),
+                name: def.name.clone(),
+                description: def.description.clone(),
+                rarity: def.rarity.clone(),
+                icon: def.icon.clone(),
+            });
+        }
+    }
+
+    Ok(new_badges)
+}
+
+/// Current season progress for frontend
+///
+/// Season XP is tracked next to the lifetime `total_xp` and restarts every
+/// calendar quarter.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonInfo {
+    /// Season identifier, e.g. "2026-Q4"
+    pub season_id: String,
+    pub starts_on: NaiveDate,
+    /// First day of the next season (exclusive)
+    pub ends_on: NaiveDate,
+    pub days_remaining: i64,
+    pub season_xp: i32,
+    pub season_level: i32,
+    pub xp_to_next_level: i32,
+    pub progress_percent: f32,
+    pub best_streak: i32,
+    pub seasons_completed: i32,
+    /// Season archived by this call, when the previous one had ended
+    pub archived_season: Option<SeasonArchive>,
+    /// Season badges earned by that archive
+    pub new_badges: Vec<NewBadgeInfo>,
+}
+
+/// Get the current season, archiving the previous one if it has ended
+#[command]
+pub async fn get_season_info(state: State<'_, AppState>) -> Result<SeasonInfo, String> {
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    let today = Utc::now().date_naive();
+    let archived_season = state
+        .db
+        .roll_over_season(user.id, today)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let stats = state
+        .db
+        .get_user_stats(user.id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("User stats not found")?;
+
+    let new_badges = if archived_season.is_some() {
+        award_new_badges(&state, user.id, &stats).await?
+    } else {
+        Vec::new()
+    };
+
+    let season_id = stats
+        .season_id
+        .clone()
+        .ok_or("シーズン情報が見つかりません")?;
+    let (starts_on, ends_on) =
+        season_bounds(&season_id).ok_or_else(|| format!("シーズンIDが不正です: {}", season_id))?;
+
+    Ok(SeasonInfo {
+        season_id,
+        starts_on,
+        ends_on,
+        days_remaining: (ends_on - today).num_days(),
+        season_xp: stats.season_xp,
+        season_level: season_level(stats.season_xp),
+        xp_to_next_level: level::xp_to_next_level(stats.season_xp),
+        progress_percent: level::progress_to_next_level(stats.season_xp),
+        best_streak: stats.season_best_streak,
+        seasons_completed: stats.seasons_completed,
+        archived_season,
+        new_badges,
+    })
+}
+
+/// Get archived seasons, most recent first
+#[command]
+pub async fn get_season_history(
+    state: State<'_, AppState>,
+    limit: Option<i32>,
+) -> Result<Vec<SeasonArchive>, String> {
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    state
+        .db
+        .get_season_history(user.id, limit.unwrap_or(8))
+        .await
+        .map_err(|e| e.to_string())
+}
+
 /// Add XP to current user (for testing/admin purposes)
 #[command]
 pub async fn add_xp(
@@ -404,7 +639,9 @@ pub async fn recalculate_xp_history(
         .await
         .map_err(|e| e.to_string())?;
 
-    let client = GitHubClient::new(token);
+    // The recalculated totals honour the repository filter like live syncs
+    let repository_filter = load_repository_filter(state.db.pool(), user.id).await?;
+    let client = GitHubClient::new(token).with_repository_filter(repository_filter);
     let contributions = map_github_result(
         &app,
         state.inner(),
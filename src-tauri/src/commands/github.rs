@@ -5,7 +5,13 @@
 use tauri::{command, AppHandle, Emitter, State};
 
 use super::auth::AppState;
+use super::contribution_history::persist_contribution_calendar;
+use super::period_comparison::load_period_totals;
+use super::repo_filter::{
+    clear_rebaseline_pending, filtered_github_client, is_rebaseline_pending, load_repository_filter,
+};
 use crate::auth::map_github_result;
+use crate::database::models::PeriodRange;
 use crate::database::{
     badge, challenge, level, streak, xp, GitHubStatsSnapshot, UserStats, UserStatsGitHubAggregates,
     XpActionType,
@@ -61,7 +67,7 @@ pub async fn get_github_stats(
         .map_err(|e| e.to_string())?
         .ok_or("Not logged in")?;
 
-    let client = GitHubClient::new(token);
+    let client = filtered_github_client(&state, user.id, token).await;
     map_github_result(
         &app,
         state.inner(),
@@ -158,6 +164,9 @@ pub struct XpBreakdownResult {
     pub reviews_xp: i32,
     pub stars_xp: i32,
     pub streak_bonus_xp: i32,
+    pub prestige_bonus_xp: i32,
+    /// Commit XP removed by the XP safeguards (0 or negative)
+    pub adjusted_xp: i32,
     pub total_xp: i32,
 }
 
@@ -224,6 +233,116 @@ pub async fn sync_github_stats(
     Ok(result)
 }
 
+/// Finished challenges read per sync for adaptive difficulty; enough to
+/// cover `challenge::DIFFICULTY_HISTORY_WINDOW` per challenge type and metric.
+const CHALLENGE_OUTCOME_HISTORY_LIMIT: i64 = 100;
+
+/// Repositories scanned for per-commit activity by the XP safeguards
+const XP_SAFEGUARD_MAX_REPOS: i32 = 30;
+/// Oldest commits (days) the XP safeguards look at after a long gap
+const XP_SAFEGUARD_MAX_WINDOW_DAYS: i64 = 30;
+
+/// Commit XP the user's safeguards remove from this sync.
+///
+/// Looks at commits made since the previous successful sync (back to the
+/// start of that UTC day, so the daily cap sees the whole day). Returns
+/// `None` when there is no previous sync, every safeguard is off, or the
+/// commit activity can't be fetched — the safeguards are best-effort and
+/// never fail a sync.
+async fn commit_xp_adjustment(
+    state: &AppState,
+    client: &GitHubClient,
+    user_id: i64,
+    username: &str,
+) -> Option<xp::CommitAdjustment> {
+    use crate::sync_scheduler::GITHUB_STATS_SYNC_TYPE;
+
+    let safeguards = match state.db.get_or_create_user_settings(user_id).await {
+        Ok(settings) => settings.xp_safeguards(),
+        Err(e) => {
+            eprintln!("Failed to load XP safeguard settings: {}", e);
+            return None;
+        }
+    };
+    if !safeguards.is_active() {
+        return None;
+    }
+
+    let last_sync_at = state
+        .db
+        .get_sync_metadata(user_id, GITHUB_STATS_SYNC_TYPE)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|m| m.last_sync_at)
+        .and_then(|at| chrono::DateTime::parse_from_rfc3339(&at).ok())?
+        .with_timezone(&chrono::Utc);
+
+    let now = chrono::Utc::now();
+    let window_start = last_sync_at
+        .max(now - chrono::Duration::days(XP_SAFEGUARD_MAX_WINDOW_DAYS))
+        .date_naive();
+    let since = format!("{}T00:00:00Z", window_start);
+
+    let activity = match client
+        .get_commit_activity(username, &since, XP_SAFEGUARD_MAX_REPOS)
+        .await
+    {
+        Ok(activity) => activity,
+        Err(e) => {
+            eprintln!("Skipping XP safeguards, commit activity unavailable: {}", e);
+            return None;
+        }
+    };
+
+    let small_diff_days: std::collections::HashSet<String> = if safeguards.min_commit_lines > 0 {
+        state
+            .db
+            .get_daily_code_stats_range(user_id, window_start, now.date_naive())
+            .await
+            .unwrap_or_else(|e| {
+                eprintln!("Failed to load daily code stats for XP safeguards: {}", e);
+                Vec::new()
+            })
+            .into_iter()
+            .filter(|day| {
+                xp::is_small_diff_day(
+                    day.additions,
+                    day.deletions,
+                    day.commits_count,
+                    safeguards.min_commit_lines,
+                )
+            })
+            .map(|day| day.date)
+            .collect()
+    } else {
+        std::collections::HashSet::new()
+    };
+
+    let commits: Vec<xp::SafeguardCommit> = activity
+        .into_iter()
+        .map(|commit| {
+            let committed_at = chrono::DateTime::parse_from_rfc3339(&commit.committed_date)
+                .map(|dt| dt.with_timezone(&chrono::Utc))
+                .ok();
+            xp::SafeguardCommit {
+                date: committed_at
+                    .map(|dt| dt.date_naive().to_string())
+                    .unwrap_or_default(),
+                repository: commit.repository,
+                is_bot: commit.is_bot,
+                is_new: committed_at.is_some_and(|dt| dt > last_sync_at),
+            }
+        })
+        .collect();
+
+    Some(xp::calculate_commit_adjustment(
+        &commits,
+        &small_diff_days,
+        &safeguards,
+    ))
+}
+
 /// Core GitHub stats sync routine.
 ///
 /// Shared by the `sync_github_stats` Tauri command and the background sync
@@ -254,10 +373,22 @@ pub async fn run_github_sync(
         .map_err(|e| e.to_string())?
         .ok_or("Not logged in")?;
 
-    let client = GitHubClient::new(token);
+    // Strict load: silently counting every repository would make the XP
+    // diff jump.
+    let repository_filter = load_repository_filter(state.db.pool(), user.id).await?;
+    let client = GitHubClient::new(token).with_repository_filter(repository_filter);
     let github_stats =
         map_github_result(app, state, client.get_user_stats(&user.username).await).await?;
 
+    // Archive an ended season before this sync's XP is credited, so the
+    // XP counts towards the new season. The season badges it unlocks are
+    // picked up by the badge evaluation below.
+    state
+        .db
+        .roll_over_season(user.id, chrono::Utc::now().date_naive())
+        .await
+        .map_err(|e| e.to_string())?;
+
     // Single source of truth for "previous GitHub stats" — the most recent
     // `github_stats_snapshots` row for this user (Issue #189). When the
     // user has already synced earlier today, this returns *today's* row,
@@ -268,6 +399,12 @@ pub async fn run_github_sync(
         .await
         .map_err(|e| e.to_string())?;
 
+    // A changed repository filter moves every cumulative total,
+    // so the first sync after the change only stores the filtered totals
+    // as the new baseline instead of diffing against the old ones.
+    let rebaseline_pending = is_rebaseline_pending(state.db.pool(), user.id).await?;
+    let rebaseline = rebaseline_pending && previous_snapshot_for_xp.is_some();
+
     // Get current streak for XP bonus calculation
     let current_streak = state
         .db
@@ -282,6 +419,7 @@ pub async fn run_github_sync(
     // cumulative metric (e.g. losing a star) clamps to 0 instead of
     // turning into negative XP. (DoD: `XpBreakdown` の各フィールドが非負)
     let (xp_breakdown, xp_gained) = match &previous_snapshot_for_xp {
+        Some(_) if rebaseline => (xp::XpBreakdown::default(), 0),
         Some(prev) => {
             let breakdown = xp::XpBreakdown::calculate(
                 diff_count(github_stats.total_commits, prev.total_commits),
@@ -316,6 +454,30 @@ pub async fn run_github_sync(
         }
     };
 
+    // XP safeguards: bot commits, excluded repositories, tiny
+    // diffs and commits past the daily cap earn less commit XP. The first
+    // sync's lifetime totals are never adjusted. The full breakdown is
+    // still recorded and the reduction goes into its own `xp_adjusted`
+    // history entry, so the history explains where the XP went.
+    let commit_adjustment = if previous_snapshot_for_xp.is_some() && xp_breakdown.commits_xp > 0 {
+        commit_xp_adjustment(state, &client, user.id, &user.username)
+            .await
+            .filter(|adjustment| adjustment.xp_reduction > 0)
+    } else {
+        None
+    };
+    let unadjusted_xp = xp_gained;
+    let xp_gained = match &commit_adjustment {
+        Some(adjustment) => {
+            xp_breakdown
+                .with_commit_reduction(adjustment.xp_reduction, current_streak)
+                .total_xp
+        }
+        None => xp_gained,
+    };
+    // Zero or negative: XP removed by the safeguards
+    let adjusted_xp = xp_gained - unadjusted_xp;
+
     // Persist the new XP-diff baseline *before* awarding any XP. Without
     // a transaction API we can't make the two writes atomic, so we pick
     // the safer ordering: if the snapshot save fails we return early
@@ -336,12 +498,25 @@ pub async fn run_github_sync(
         github_stats.total_stars_received,
         github_stats.total_contributions,
         &today,
-    );
+    )
+    .with_reliability(github_stats.search_fallback, rebaseline_pending);
     state
         .db
         .save_github_stats_snapshot(&current_snapshot)
         .await
         .map_err(|e| format!("Failed to persist XP-diff baseline snapshot: {}", e))?;
+    // Keep the calendar days beyond GitHub's one-year window
+    if let Some(calendar) = &github_stats.contribution_calendar {
+        if let Err(e) = persist_contribution_calendar(&state.db, user.id, calendar).await {
+            eprintln!("Failed to persist contribution calendar: {}", e);
+        }
+    }
+    if rebaseline_pending {
+        // Best-effort: if this fails the next sync re-baselines once more
+        if let Err(e) = clear_rebaseline_pending(state.db.pool(), user.id).await {
+            eprintln!("Failed to clear repository filter rebaseline flag: {}", e);
+        }
+    }
 
     // Get current stats for level comparison and streak update
     let current_stats = state
@@ -351,7 +526,7 @@ pub async fn run_github_sync(
         .map_err(|e| e.to_string())?
         .ok_or("User stats not found")?;
 
-    let old_level = level::level_from_xp(current_stats.total_xp);
+    let old_level = level::level_from_xp(current_stats.level_xp());
     let old_streak = current_stats.current_streak;
 
     // Update streak from GitHub contribution calendar data
@@ -412,19 +587,23 @@ pub async fn run_github_sync(
         )
     };
 
-    // Total XP gained (activity XP + streak bonus)
-    let total_xp_gained = xp_gained + streak_bonus_xp;
+    // Prestige ranks add a permanent bonus on top of activity XP + streak bonus
+    let prestige_bonus_xp =
+        level::prestige_bonus_xp(xp_gained + streak_bonus_xp, current_stats.prestige_rank);
 
-    // Add XP if there's any gain
-    let updated_stats = if total_xp_gained > 0 {
+    // Total XP gained (activity XP + streak bonus + prestige bonus)
+    let total_xp_gained = xp_gained + streak_bonus_xp + prestige_bonus_xp;
+
+    // Add XP if there's any gain (or a safeguard adjustment to explain)
+    let updated_stats = if total_xp_gained > 0 || commit_adjustment.is_some() {
         // Record activity XP gain with breakdown
-        if xp_gained > 0 {
+        if unadjusted_xp > 0 {
             state
                 .db
                 .record_xp_gain(
                     user.id,
                     "github_sync",
-                    xp_gained,
+                    unadjusted_xp,
                     Some("GitHub stats sync"),
                     None,
                     Some(&xp_breakdown),
@@ -433,6 +612,22 @@ pub async fn run_github_sync(
                 .map_err(|e| e.to_string())?;
         }
 
+        // Record the safeguard reduction as a negative audit entry
+        if let Some(adjustment) = &commit_adjustment {
+            state
+                .db
+                .record_xp_gain(
+                    user.id,
+                    &XpActionType::XpAdjusted.to_string(),
+                    adjusted_xp,
+                    Some(&adjustment.description()),
+                    None,
+                    None,
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
         // Record streak bonus XP (no breakdown for streak bonus)
         if streak_bonus_xp > 0 {
             let description = if let Some(milestone) = streak_bonus_result.milestone_reached {
@@ -455,6 +650,27 @@ pub async fn run_github_sync(
                 .map_err(|e| e.to_string())?;
         }
 
+        // Record prestige bonus XP
+        if prestige_bonus_xp > 0 {
+            let description = format!(
+                "プレステージボーナス (x{:.2})",
+                level::prestige_xp_multiplier(current_stats.prestige_rank)
+            );
+
+            state
+                .db
+                .record_xp_gain(
+                    user.id,
+                    &XpActionType::PrestigeBonus.to_string(),
+                    prestige_bonus_xp,
+                    Some(&description),
+                    None,
+                    None,
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
         // Add total XP to user stats
         state
             .db
@@ -471,7 +687,7 @@ pub async fn run_github_sync(
             .ok_or("User stats not found")?
     };
 
-    let new_level = level::level_from_xp(updated_stats.total_xp);
+    let new_level = level::level_from_xp(updated_stats.level_xp());
     let level_up = new_level > old_level;
 
     // Prime the activity cache so subsequent `get_github_stats_with_cache`
@@ -505,6 +721,8 @@ pub async fn run_github_sync(
         reviews_xp: xp_breakdown.reviews_xp,
         stars_xp: xp_breakdown.stars_xp,
         streak_bonus_xp,
+        prestige_bonus_xp,
+        adjusted_xp,
         total_xp: total_xp_gained,
     };
 
@@ -517,6 +735,20 @@ pub async fn run_github_sync(
         }
     };
 
+    // Challenge tokens: a reroll token per level gained, a skip token per
+    // streak milestone
+    let reroll_tokens_earned = (new_level - old_level).max(0);
+    let skip_tokens_earned = i32::from(streak_bonus_result.milestone_reached.is_some());
+    if reroll_tokens_earned > 0 || skip_tokens_earned > 0 {
+        if let Err(e) = state
+            .db
+            .grant_challenge_tokens(user.id, reroll_tokens_earned, skip_tokens_earned)
+            .await
+        {
+            eprintln!("Failed to grant challenge tokens: {}", e);
+        }
+    }
+
     // Emit XP gained event for frontend and send OS notification if enabled
     if total_xp_gained > 0 {
         let event = XpGainedEvent {
@@ -645,6 +877,9 @@ pub async fn run_github_sync(
         languages_count: github_stats.languages_count,
         current_level: new_level as i32,
         total_stars_received: github_stats.total_stars_received,
+        prestige_rank: updated_stats.prestige_rank,
+        seasons_completed: updated_stats.seasons_completed,
+        best_season_percentile: updated_stats.best_season_percentile,
     };
 
     // Get already earned badges
@@ -712,15 +947,60 @@ pub async fn run_github_sync(
     }
 
     // Challenge auto-generation and progress update
-    // Build challenge stats from current GitHub stats
-    let challenge_stats = challenge::ChallengeStats::new(
-        github_stats.total_commits,
-        github_stats.total_prs,
-        github_stats.total_reviews,
-        github_stats.total_issues,
-    );
+    let now = chrono::Utc::now();
+    let today_code_stats = state
+        .db
+        .get_daily_code_stats(user.id, now.date_naive())
+        .await
+        .ok()
+        .flatten();
+
+    // Build challenge stats from current GitHub stats. Challenges created
+    // below start now, so today's code stats so far are their baseline.
+    let challenge_stats = challenge::ChallengeStats {
+        prs_merged: github_stats.total_prs_merged,
+        issues_closed: github_stats.total_issues_closed,
+        start_day: today_code_stats
+            .as_ref()
+            .map(challenge::CodeDayBaseline::from)
+            .unwrap_or_default(),
+        ..challenge::ChallengeStats::new(
+            github_stats.total_commits,
+            github_stats.total_prs,
+            github_stats.total_reviews,
+            github_stats.total_issues,
+        )
+    };
     let challenge_stats_json = serde_json::to_string(&challenge_stats).unwrap_or_default();
 
+    // Generated targets follow the difficulty preference and how the user
+    // fared on recent challenges of the same type and metric
+    let difficulty = user_settings
+        .as_ref()
+        .map(|settings| settings.challenge_difficulty)
+        .unwrap_or_default();
+    let challenge_records = challenge::tally_challenge_outcomes(
+        &state
+            .db
+            .get_recent_challenge_outcomes(user.id, CHALLENGE_OUTCOME_HISTORY_LIMIT)
+            .await
+            .unwrap_or_default(),
+    );
+
+    // Base targets come from the last four complete weeks of local history
+    // (snapshots, code stats), or the minimum targets without any
+    let today = now.date_naive();
+    let history_range = PeriodRange::new(
+        today - chrono::Duration::days(challenge::HISTORY_WINDOW_DAYS),
+        today - chrono::Duration::days(1),
+    );
+    let historical = load_period_totals(&state.db, user.id, &history_range)
+        .await
+        .ok()
+        .and_then(|totals| challenge::HistoricalStats::from_period_totals(&totals));
+    let has_history = historical.is_some();
+    let historical = historical.unwrap_or_default();
+
     // Check if we need to generate new daily challenges
     let last_daily = state
         .db
@@ -728,14 +1008,18 @@ pub async fn run_github_sync(
         .await
         .ok()
         .flatten();
-    let now = chrono::Utc::now();
 
     if challenge::should_generate_daily_challenges(last_daily, now) {
         // Generate daily challenges
         let config = challenge::ChallengeGeneratorConfig::default();
-        let historical = challenge::HistoricalStats::default(); // TODO: Calculate from GitHub data
         let targets = challenge::calculate_recommended_targets(&historical, &config);
-        let daily_templates = challenge::generate_daily_challenges(&targets);
+        let daily_templates = challenge::adapt_templates(
+            challenge::generate_daily_challenges(&targets),
+            &challenge_records,
+            difficulty,
+            has_history,
+            now,
+        );
 
         for template in daily_templates {
             let (start, end) = challenge::calculate_challenge_period(&template.challenge_type, now);
@@ -750,6 +1034,7 @@ pub async fn run_github_sync(
                     start,
                     end,
                     &challenge_stats_json,
+                    template.reason.as_deref(),
                 )
                 .await
             {
@@ -769,9 +1054,14 @@ pub async fn run_github_sync(
     if challenge::should_generate_weekly_challenges(last_weekly, now) {
         // Generate weekly challenges
         let config = challenge::ChallengeGeneratorConfig::default();
-        let historical = challenge::HistoricalStats::default(); // TODO: Calculate from GitHub data
         let targets = challenge::calculate_recommended_targets(&historical, &config);
-        let weekly_templates = challenge::generate_weekly_challenges(&targets);
+        let weekly_templates = challenge::adapt_templates(
+            challenge::generate_weekly_challenges(&targets),
+            &challenge_records,
+            difficulty,
+            has_history,
+            now,
+        );
 
         for template in weekly_templates {
             let (start, end) = challenge::calculate_challenge_period(&template.challenge_type, now);
@@ -786,6 +1076,7 @@ pub async fn run_github_sync(
                     start,
                     end,
                     &challenge_stats_json,
+                    template.reason.as_deref(),
                 )
                 .await
             {
@@ -794,6 +1085,81 @@ pub async fn run_github_sync(
         }
     }
 
+    // Monthly and quarterly challenges. Unlike daily/weekly, the user may
+    // already have set one of these up by hand, so skip metrics that
+    // already have an active challenge of the same type.
+    let last_monthly = state
+        .db
+        .get_last_monthly_challenge_date(user.id)
+        .await
+        .ok()
+        .flatten();
+    let last_quarterly = state
+        .db
+        .get_last_quarterly_challenge_date(user.id)
+        .await
+        .ok()
+        .flatten();
+
+    let mut long_term_templates = Vec::new();
+    if challenge::should_generate_monthly_challenges(last_monthly, now)
+        || challenge::should_generate_quarterly_challenges(last_quarterly, now)
+    {
+        let config = challenge::ChallengeGeneratorConfig::default();
+        let targets = challenge::calculate_recommended_targets(&historical, &config);
+        if challenge::should_generate_monthly_challenges(last_monthly, now) {
+            long_term_templates.extend(challenge::generate_monthly_challenges(
+                &targets,
+                challenge::challenge_window("monthly", now),
+            ));
+        }
+        if challenge::should_generate_quarterly_challenges(last_quarterly, now) {
+            long_term_templates.extend(challenge::generate_quarterly_challenges(
+                &targets,
+                challenge::challenge_window("quarterly", now),
+            ));
+        }
+    }
+
+    let long_term_templates = challenge::adapt_templates(
+        long_term_templates,
+        &challenge_records,
+        difficulty,
+        has_history,
+        now,
+    );
+    for template in long_term_templates {
+        if state
+            .db
+            .has_active_challenge(user.id, &template.challenge_type, &template.target_metric)
+            .await
+            .unwrap_or(false)
+        {
+            continue;
+        }
+        let (start, end) = challenge::calculate_challenge_period(&template.challenge_type, now);
+        if let Err(e) = state
+            .db
+            .create_challenge_with_stats(
+                user.id,
+                &template.challenge_type,
+                &template.target_metric,
+                template.target_value,
+                template.reward_xp,
+                start,
+                end,
+                &challenge_stats_json,
+                template.reason.as_deref(),
+            )
+            .await
+        {
+            eprintln!(
+                "Failed to create {} challenge: {}",
+                template.challenge_type, e
+            );
+        }
+    }
+
     // Update progress for active challenges
     let active_challenges = match state.db.get_active_challenges(user.id).await {
         Ok(challenges) => challenges,
@@ -803,16 +1169,61 @@ pub async fn run_github_sync(
         }
     };
 
-    for ch in active_challenges {
-        // Get start stats for this challenge
-        if let Ok(Some(start_stats_json)) = state.db.get_challenge_start_stats(ch.id).await {
+    // Custom challenges may be scheduled ahead; nothing counts before
+    // they start.
+    let started_challenges: Vec<_> = active_challenges
+        .into_iter()
+        .filter(|ch| ch.start_date <= now)
+        .collect();
+    let activity = load_challenge_activity(
+        state,
+        &client,
+        &user.username,
+        user.id,
+        &started_challenges,
+        now,
+    )
+    .await;
+
+    for ch in started_challenges {
+        // Get start stats for this challenge. Challenges created without a
+        // baseline (manual or scheduled ones) take the current stats as
+        // their starting point on the first sync after they start.
+        let start_stats_json = match state.db.get_challenge_start_stats(ch.id).await {
+            Ok(Some(json)) => Some(json),
+            Ok(None) => {
+                let baseline = challenge::ChallengeStats {
+                    start_day: challenge::start_day_baseline(
+                        ch.start_date,
+                        now,
+                        today_code_stats.as_ref(),
+                    ),
+                    ..challenge_stats.clone()
+                };
+                let baseline_json = serde_json::to_string(&baseline).unwrap_or_default();
+                if let Err(e) = state
+                    .db
+                    .set_challenge_start_stats(ch.id, &baseline_json)
+                    .await
+                {
+                    eprintln!("Failed to record challenge start stats: {}", e);
+                }
+                None
+            }
+            Err(_) => None,
+        };
+        if let Some(start_stats_json) = start_stats_json {
             if let Ok(start_stats) =
                 serde_json::from_str::<challenge::ChallengeStats>(&start_stats_json)
             {
                 // Calculate progress based on metric
-                let progress = challenge_stats
-                    .get_metric(&ch.target_metric)
-                    .saturating_sub(start_stats.get_metric(&ch.target_metric));
+                let progress = challenge::calculate_progress_for_metric(
+                    &ch.target_metric,
+                    ch.start_date,
+                    &start_stats,
+                    &challenge_stats,
+                    &activity,
+                );
 
                 // Update progress in database
                 if progress > ch.current_value {
@@ -898,6 +1309,71 @@ pub async fn run_github_sync(
     })
 }
 
+/// Load what the richer challenge metrics count progress from, for the
+/// challenges that need it: `daily_code_stats` since the earliest start,
+/// repository languages from the cached language breakdown, and review
+/// turnaround from GitHub. Failures only leave that part empty.
+async fn load_challenge_activity(
+    state: &AppState,
+    client: &GitHubClient,
+    username: &str,
+    user_id: i64,
+    challenges: &[crate::database::models::Challenge],
+    now: chrono::DateTime<chrono::Utc>,
+) -> challenge::ChallengeActivity {
+    let mut activity = challenge::ChallengeActivity::default();
+    let needing = |pred: fn(&str) -> bool| {
+        challenges
+            .iter()
+            .filter(|ch| pred(&ch.target_metric))
+            .map(|ch| ch.start_date)
+            .min()
+    };
+
+    if let Some(since) = needing(challenge::uses_code_stats) {
+        match state
+            .db
+            .get_daily_code_stats_range(user_id, since.date_naive(), now.date_naive())
+            .await
+        {
+            Ok(days) => {
+                activity.code_days = days
+                    .iter()
+                    .filter_map(challenge::CodeActivityDay::from_daily)
+                    .collect();
+            }
+            Err(e) => eprintln!("Failed to load code stats for challenges: {}", e),
+        }
+    }
+
+    if needing(|m| challenge::language_of_metric(m).is_some()).is_some() {
+        if let Ok(Some((json, _, _))) = state
+            .db
+            .get_any_cache(user_id, cache_types::LANGUAGE_BREAKDOWN)
+            .await
+        {
+            if let Ok(breakdown) =
+                serde_json::from_str::<crate::github::types::LanguageBreakdownResponse>(&json)
+            {
+                activity.repo_languages = breakdown
+                    .repositories
+                    .into_iter()
+                    .filter_map(|r| r.primary_language.map(|l| (r.name_with_owner, l)))
+                    .collect();
+            }
+        }
+    }
+
+    if let Some(since) = needing(|m| m == challenge::FAST_REVIEWS_METRIC) {
+        match client.get_review_turnarounds(username, since, now).await {
+            Ok(reviews) => activity.reviews = reviews,
+            Err(e) => eprintln!("Failed to load reviews for challenges: {}", e),
+        }
+    }
+
+    activity
+}
+
 /// Persist a successful GitHub sync to `sync_metadata`.
 ///
 /// Centralised so manual (`sync_github_stats`) and scheduled
@@ -979,6 +1455,13 @@ pub async fn get_contribution_calendar(
     )
     .await?;
 
+    if let Err(e) =
+        persist_contribution_calendar(&state.db, user.id, &contributions.contribution_calendar)
+            .await
+    {
+        eprintln!("Failed to persist contribution calendar: {}", e);
+    }
+
     serde_json::to_value(contributions.contribution_calendar).map_err(|e| e.to_string())
 }
 
@@ -1007,7 +1490,7 @@ fn github_stats_aggregates(stats: &GitHubStats) -> UserStatsGitHubAggregates {
 /// renders without re-spending the Search 30 req/min budget on every
 /// view. Fresh values are written back during `sync_github_stats` (or
 /// the user-triggered `refresh_badges_progress`).
-fn badge_context_from_user_stats(stats: &UserStats) -> badge::BadgeEvalContext {
+pub(crate) fn badge_context_from_user_stats(stats: &UserStats) -> badge::BadgeEvalContext {
     badge::BadgeEvalContext {
         total_commits: stats.total_commits,
         current_streak: stats.current_streak,
@@ -1019,8 +1502,11 @@ fn badge_context_from_user_stats(stats: &UserStats) -> badge::BadgeEvalContext {
         total_prs_merged: stats.total_prs_merged,
         total_issues_closed: stats.total_issues_closed,
         languages_count: stats.languages_count,
-        current_level: level::level_from_xp(stats.total_xp),
+        current_level: level::level_from_xp(stats.level_xp()),
         total_stars_received: stats.total_stars_received,
+        prestige_rank: stats.prestige_rank,
+        seasons_completed: stats.seasons_completed,
+        best_season_percentile: stats.best_season_percentile,
     }
 }
 
@@ -1141,7 +1627,7 @@ pub async fn refresh_badges_progress(
         .await
         .map_err(|e| e.to_string())?;
 
-    let client = GitHubClient::new(token);
+    let client = filtered_github_client(&state, user.id, token).await;
     let github_stats = map_github_result(
         &app,
         state.inner(),
@@ -1189,7 +1675,9 @@ pub async fn refresh_badges_progress(
 // Code Statistics Commands (Issue #74)
 // ============================================================================
 
-use crate::database::models::code_stats::{CodeStatsResponse, RateLimitInfo, StatsPeriod};
+use crate::database::models::code_stats::{
+    CodeStatsResponse, RateLimitInfo, RepositoryCodeStats, StatsPeriod,
+};
 use crate::github::types::RateLimitDetailed;
 
 /// Result of code statistics sync
@@ -1231,7 +1719,7 @@ pub async fn sync_code_stats(
         .map_err(|e| e.to_string())?
         .ok_or("Not logged in")?;
 
-    let client = GitHubClient::new(token);
+    let client = filtered_github_client(&state, user.id, token).await;
 
     // Check if sync is needed (cache for 6 hours)
     let cache_duration_hours = 6;
@@ -1302,6 +1790,22 @@ pub async fn sync_code_stats(
             )
             .await
             .map_err(|e| e.to_string())?;
+        let repository_stats: Vec<RepositoryCodeStats> = daily
+            .repository_stats
+            .iter()
+            .map(|split| RepositoryCodeStats {
+                date: daily.date.clone(),
+                repository: split.repository.clone(),
+                additions: split.additions,
+                deletions: split.deletions,
+                commits_count: split.commits_count,
+            })
+            .collect();
+        state
+            .db
+            .replace_daily_repository_code_stats(user.id, date, &repository_stats)
+            .await
+            .map_err(|e| e.to_string())?;
 
         total_additions += daily.additions;
         total_deletions += daily.deletions;
@@ -1409,10 +1913,11 @@ pub async fn get_rate_limit_info(
 
 use crate::auth::{handle_unauthorized, reasons};
 use crate::database::cache_types;
+use crate::database::models::repo_filter::split_repository_name;
 use crate::github::client::GitHubError;
 
 /// Check if the error is a network error (should fallback to cache)
-fn is_network_error(error: &GitHubError) -> bool {
+pub(crate) fn is_network_error(error: &GitHubError) -> bool {
     matches!(
         error,
         GitHubError::HttpRequest(_) | GitHubError::RateLimited(_)
@@ -1459,13 +1964,19 @@ pub async fn get_github_stats_with_cache(
             .await
             .map_err(|e| GitHubError::ApiError(e.to_string()))?;
 
-        let client = GitHubClient::new(token);
+        let client = filtered_github_client(&state, user.id, token).await;
         client.get_user_stats(&user.username).await
     }
     .await;
 
     match api_result {
         Ok(stats) => {
+            if let Some(calendar) = &stats.contribution_calendar {
+                if let Err(e) = persist_contribution_calendar(&state.db, user.id, calendar).await {
+                    eprintln!("Failed to persist contribution calendar: {}", e);
+                }
+            }
+
             // API succeeded - always refresh the cache (audit §9.2: cache must
             // be populated on the success path, not only when falling back).
             let stats_json = serde_json::to_string(&stats)
@@ -1780,7 +2291,7 @@ pub async fn get_today_commits_with_cache(
             .get_access_token()
             .await
             .map_err(|e| GitHubError::ApiError(e.to_string()))?;
-        let client = GitHubClient::new(token);
+        let client = filtered_github_client(&state, user.id, token).await;
         client
             .get_today_commits(&user.username, &since, TODAY_COMMITS_MAX_REPOS)
             .await
@@ -1925,7 +2436,7 @@ pub async fn get_language_breakdown_with_cache(
             .get_access_token()
             .await
             .map_err(|e| GitHubError::ApiError(e.to_string()))?;
-        let client = GitHubClient::new(token);
+        let client = filtered_github_client(&state, user.id, token).await;
         client
             .get_language_breakdown(&user.username, &since, LANGUAGE_BREAKDOWN_MAX_REPOS)
             .await
@@ -1999,3 +2510,127 @@ pub async fn get_language_breakdown_with_cache(
         }
     }
 }
+
+/// Windows (in days) the repository drill-down can be requested for.
+const REPOSITORY_DETAIL_WINDOWS: [i32; 3] = [30, 90, 365];
+
+/// Default drill-down window, in days.
+const REPOSITORY_DETAIL_DEFAULT_DAYS: i32 = 90;
+
+/// Per-repository drill-down with a 1-hour SQLite cache.
+///
+/// Commits over time, additions / deletions, PRs opened / merged, reviews
+/// given, issues, languages and the user's share of the repository's
+/// commits over the last `days` (30 / 90 / 365, default 90) days. Each
+/// repository and window is cached under its own
+/// [`cache_types::repository_detail_key`] row with the same network-error
+/// fallback as the other `*_with_cache` commands.
+#[command]
+pub async fn get_repository_detail_with_cache(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name_with_owner: String,
+    days: Option<i32>,
+) -> Result<CachedResponse<crate::github::types::RepositoryDetail>, String> {
+    let (owner, name) = split_repository_name(name_with_owner.trim()).ok_or_else(|| {
+        format!(
+            "リポジトリは owner/name の形式で指定してください: {}",
+            name_with_owner
+        )
+    })?;
+    let days = days.unwrap_or(REPOSITORY_DETAIL_DEFAULT_DAYS);
+    if !REPOSITORY_DETAIL_WINDOWS.contains(&days) {
+        return Err(format!(
+            "集計期間は30・90・365日から選択してください: {}",
+            days
+        ));
+    }
+
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    let cache_key = cache_types::repository_detail_key(&format!("{}/{}", owner, name), days);
+    let now = chrono::Utc::now();
+
+    let api_result = async {
+        let token = state
+            .token_manager
+            .get_access_token()
+            .await
+            .map_err(|e| GitHubError::ApiError(e.to_string()))?;
+        let client = GitHubClient::new(token);
+        client
+            .get_repository_detail(&user.username, owner, name, days)
+            .await
+    }
+    .await;
+
+    match api_result {
+        Ok(payload) => {
+            let payload_json = serde_json::to_string(&payload)
+                .map_err(|e| format!("Failed to serialize repository detail: {}", e))?;
+
+            let expires_at = now
+                + chrono::Duration::minutes(crate::database::cache_durations::REPOSITORY_DETAIL);
+
+            let _ = state
+                .db
+                .save_cache(user.id, &cache_key, &payload_json, expires_at)
+                .await;
+
+            Ok(CachedResponse {
+                data: payload,
+                from_cache: false,
+                cached_at: Some(now.to_rfc3339()),
+                expires_at: Some(expires_at.to_rfc3339()),
+            })
+        }
+        Err(GitHubError::Unauthorized) => {
+            handle_unauthorized(&app, state.inner(), reasons::GITHUB_UNAUTHORIZED).await;
+            Err(GitHubError::Unauthorized.to_string())
+        }
+        Err(GitHubError::NotFound(_)) => {
+            Err(format!("リポジトリが見つかりません: {}/{}", owner, name))
+        }
+        Err(api_error) => {
+            if !is_network_error(&api_error) {
+                return Err(format!("GitHub API error: {}", api_error));
+            }
+
+            eprintln!(
+                "Repository detail fetch failed, attempting cache fallback: {}",
+                api_error
+            );
+
+            let cache_result = state
+                .db
+                .get_any_cache(user.id, &cache_key)
+                .await
+                .ok()
+                .flatten();
+
+            match cache_result {
+                Some((data_json, cached_at, expires_at)) => {
+                    let payload: crate::github::types::RepositoryDetail =
+                        serde_json::from_str(&data_json)
+                            .map_err(|e| format!("Failed to parse cached data: {}", e))?;
+
+                    Ok(CachedResponse {
+                        data: payload,
+                        from_cache: true,
+                        cached_at: Some(cached_at),
+                        expires_at: Some(expires_at),
+                    })
+                }
+                None => Err(format!(
+                    "GitHub APIにアクセスできず、キャッシュもありません: {}",
+                    api_error
+                )),
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Activity heatmap commands
+//!
+//! `get_activity_heatmap` returns one normalised calendar for any
+//! [`heatmap_metrics`] value, read from the local database only:
+//!
+//! - `contributions`: stored contribution calendar (`contribution_days`)
+//! - `xp`: live XP per day (`xp_history`)
+//! - `lines` / `commits`: code stats sync (`daily_code_stats`), or one
+//!   repository's share of it (`daily_repository_code_stats`) with the
+//!   repository filter
+//!
+//! The split is only written by code stats syncs since migration v37, so
+//! older days show nothing for a repository. Contributions and XP have no
+//! split at all and reject the filter (see [`HeatmapRequest::resolve`]).
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   └─ src-tauri/src/commands/mod.rs
+//! Dependencies:
+//!   ├─ src-tauri/src/database/models/heatmap.rs
+//!   ├─ src-tauri/src/database/repository/contribution_history.rs
+//!   ├─ src-tauri/src/database/repository/xp_history.rs (get_xp_totals_by_day)
+//!   └─ src-tauri/src/database/repository/code_stats.rs
+//!      (daily and per-repository code stats)
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{NaiveDate, Utc};
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::database::models::heatmap::{
+    heatmap_metrics, ActivityHeatmap, HeatmapRequest, HeatmapValue,
+};
+use crate::database::repository::XP_HISTORY_SOURCE_LIVE;
+
+/// Get a heatmap calendar of one metric for the last year or a given year
+#[tauri::command]
+pub async fn get_activity_heatmap(
+    state: State<'_, AppState>,
+    request: HeatmapRequest,
+) -> Result<ActivityHeatmap, String> {
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    let (start, end) = request.resolve(Utc::now().date_naive())?;
+    let db = &state.db;
+
+    // Also needed for the repository list, whatever the metric
+    let code_stats = db
+        .get_daily_code_stats_range(user.id, start, end)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut repositories: BTreeMap<String, String> = BTreeMap::new();
+    for name in code_stats.iter().flat_map(|stats| stats.repositories()) {
+        repositories.entry(name.to_lowercase()).or_insert(name);
+    }
+
+    // (date, additions, deletions, commits) of the lines / commits metrics
+    let code_days: Vec<(String, i32, i32, i32)> = match &request.repository {
+        Some(repository) => db
+            .get_repository_code_stats_range(user.id, repository, start, end)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|s| (s.date, s.additions, s.deletions, s.commits_count))
+            .collect(),
+        None => code_stats
+            .iter()
+            .map(|s| (s.date.clone(), s.additions, s.deletions, s.commits_count))
+            .collect(),
+    };
+
+    let values: HashMap<NaiveDate, HeatmapValue> = match request.metric.as_str() {
+        heatmap_metrics::CONTRIBUTIONS => db
+            .get_contribution_days_range(user.id, start, end)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|day| {
+                (
+                    day.date,
+                    HeatmapValue {
+                        value: day.count as i64,
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect(),
+        heatmap_metrics::XP => db
+            .get_xp_totals_by_day(user.id, start, end, XP_HISTORY_SOURCE_LIVE)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|(date, xp)| {
+                (
+                    date,
+                    HeatmapValue {
+                        value: xp,
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect(),
+        metric => code_days
+            .into_iter()
+            .filter_map(|(date, additions, deletions, commits)| {
+                let date = NaiveDate::parse_from_str(&date, "%Y-%m-%d").ok()?;
+                let value = if metric == heatmap_metrics::LINES {
+                    HeatmapValue {
+                        value: additions as i64 + deletions as i64,
+                        additions: Some(additions as i64),
+                        deletions: Some(deletions as i64),
+                    }
+                } else {
+                    HeatmapValue {
+                        value: commits as i64,
+                        ..Default::default()
+                    }
+                };
+                Some((date, value))
+            })
+            .collect(),
+    };
+
+    Ok(ActivityHeatmap::build(
+        &request.metric,
+        request.repository.clone(),
+        start,
+        end,
+        &values,
+        repositories.into_values().collect(),
+    ))
+}
@@ -0,0 +1,620 @@
+//! Bulk kanban operations
+//!
+//! `bulk_update_issues` applies one action (move, add / remove labels, set
+//! assignees, close) to many selected cards:
+//!
+//! 1. Every target is resolved and planned up front; unknown issues and
+//!    archived repositories become per-issue failures.
+//! 2. The planned change is written to `cached_issues` for all of them in
+//!    one transaction, so the board redraws once instead of per card.
+//! 3. One PATCH per issue goes to GitHub, at most
+//!    [`BULK_CONCURRENCY`] at a time. Accepted issues are re-cached from
+//!    GitHub's response; rejected ones are rolled back to their previous
+//!    row and reported in `failed`.
+//!
+//! Moves follow `update_issue_status`: offline, or for issues with a
+//! change still queued, they go to the outbox instead of failing. Other
+//! actions are not queued, like the edit commands.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   └─ src-tauri/src/commands/mod.rs
+//! Dependencies:
+//!   ├─ src-tauri/src/auth/session.rs         (map_github_result)
+//!   ├─ src-tauri/src/commands/issues.rs      (get_project, resolve_project_repository,
+//!   │                                         upsert_cached_issue, fetch_cached_issue)
+//!   ├─ src-tauri/src/commands/issue_edit.rs  (with_status_label, status_for_close_reason)
+//!   ├─ src-tauri/src/commands/issue_outbox.rs (has_pending_operation, enqueue_status_change)
+//!   ├─ src-tauri/src/database/models/issue_bulk.rs
+//!   └─ src-tauri/src/github/issues.rs        (IssuesClient, IssueEdit)
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use futures::stream::{self, StreamExt};
+use sqlx::{Pool, Sqlite};
+use tauri::{AppHandle, State};
+
+use crate::auth::map_github_result;
+use crate::commands::issue_edit::{status_for_close_reason, with_status_label};
+use crate::commands::issue_outbox::{
+    enqueue_status_change, has_pending_operation, is_offline_error,
+};
+use crate::commands::issues::{
+    archived_repository_error, fetch_cached_issue, get_access_token, get_project,
+    resolve_project_repository, upsert_cached_issue,
+};
+use crate::commands::AppState;
+use crate::database::models::issue_bulk::{
+    dedupe_bulk_targets, BulkIssueAction, BulkIssueFailure, BulkIssueResult, BulkIssueTarget,
+    BULK_CONCURRENCY, MAX_BULK_ISSUES,
+};
+use crate::database::models::project::{CachedIssue, IssueStatus, ProjectRepository};
+use crate::github::issues::{IssueEdit, IssuesClient};
+
+/// The PATCH for one issue and the column it ends up in.
+#[derive(Debug, Clone)]
+pub(crate) struct BulkPlan {
+    pub edit: IssueEdit,
+    pub status: IssueStatus,
+}
+
+/// One issue waiting for its GitHub call.
+struct BulkJob {
+    target: BulkIssueTarget,
+    repository: ProjectRepository,
+    issue: CachedIssue,
+    plan: BulkPlan,
+}
+
+/// Columns of a `cached_issues` row a bulk action can change.
+#[derive(Debug, Clone, PartialEq)]
+struct CachedIssueFields {
+    status: String,
+    state: String,
+    state_reason: Option<String>,
+    labels_json: Option<String>,
+    assignees_json: Option<String>,
+    assignee_login: Option<String>,
+    assignee_avatar_url: Option<String>,
+}
+
+impl CachedIssueFields {
+    fn of(issue: &CachedIssue) -> Self {
+        Self {
+            status: issue.status.clone(),
+            state: issue.state.clone(),
+            state_reason: issue.state_reason.clone(),
+            labels_json: issue.labels_json.clone(),
+            assignees_json: issue.assignees_json.clone(),
+            assignee_login: issue.assignee_login.clone(),
+            assignee_avatar_url: issue.assignee_avatar_url.clone(),
+        }
+    }
+
+    /// The row as it will look once GitHub accepts `plan`.
+    fn planned(issue: &CachedIssue, plan: &BulkPlan) -> Self {
+        let mut fields = Self::of(issue);
+        fields.status = plan.status.to_string();
+        if let Some(state) = &plan.edit.state {
+            fields.state = state.clone();
+            fields.state_reason = plan.edit.state_reason.clone();
+        }
+        if let Some(labels) = &plan.edit.labels {
+            fields.labels_json =
+                Some(serde_json::to_string(labels).unwrap_or_else(|_| "[]".to_string()));
+        }
+        if let Some(assignees) = &plan.edit.assignees {
+            fields.assignees_json =
+                Some(serde_json::to_string(assignees).unwrap_or_else(|_| "[]".to_string()));
+            if assignees.first() != fields.assignee_login.as_ref() {
+                // The avatar of a new first assignee is only known after
+                // GitHub answers.
+                fields.assignee_login = assignees.first().cloned();
+                fields.assignee_avatar_url = None;
+            }
+        }
+        fields
+    }
+}
+
+/// Work out the PATCH that applies `action` to `issue`.
+pub(crate) fn plan_bulk_edit(action: &BulkIssueAction, issue: &CachedIssue) -> BulkPlan {
+    let current_labels = issue.get_labels();
+    match action {
+        BulkIssueAction::Move { status } => {
+            let mut edit = IssueEdit {
+                labels: Some(with_status_label(&current_labels, *status)),
+                ..Default::default()
+            };
+            let closes = matches!(status, IssueStatus::Done | IssueStatus::Cancelled);
+            if closes && issue.state != "closed" {
+                edit.state = Some("closed".to_string());
+                edit.state_reason = Some(
+                    if *status == IssueStatus::Done {
+                        "completed"
+                    } else {
+                        "not_planned"
+                    }
+                    .to_string(),
+                );
+            } else if !closes && issue.state == "closed" {
+                edit.state = Some("open".to_string());
+                edit.state_reason = Some("reopened".to_string());
+            }
+            BulkPlan {
+                edit,
+                status: *status,
+            }
+        }
+        BulkIssueAction::AddLabels { labels } => {
+            let mut merged = current_labels;
+            for label in labels {
+                if !merged.contains(label) {
+                    merged.push(label.clone());
+                }
+            }
+            BulkPlan {
+                edit: IssueEdit {
+                    labels: Some(merged),
+                    ..Default::default()
+                },
+                status: issue.get_status(),
+            }
+        }
+        BulkIssueAction::RemoveLabels { labels } => BulkPlan {
+            edit: IssueEdit {
+                labels: Some(
+                    current_labels
+                        .into_iter()
+                        .filter(|l| !labels.contains(l))
+                        .collect(),
+                ),
+                ..Default::default()
+            },
+            status: issue.get_status(),
+        },
+        BulkIssueAction::SetAssignees { assignees } => BulkPlan {
+            edit: IssueEdit {
+                assignees: Some(assignees.clone()),
+                ..Default::default()
+            },
+            status: issue.get_status(),
+        },
+        BulkIssueAction::Close { reason } => {
+            // `validate` already rejected unknown reasons
+            let status = status_for_close_reason(reason).unwrap_or(IssueStatus::Done);
+            BulkPlan {
+                edit: IssueEdit {
+                    state: Some("closed".to_string()),
+                    state_reason: Some(reason.clone()),
+                    labels: Some(with_status_label(&current_labels, status)),
+                    ..Default::default()
+                },
+                status,
+            }
+        }
+    }
+}
+
+/// Write `rows` (cached issue id → fields) in a single transaction.
+async fn write_cached_fields(
+    pool: &Pool<Sqlite>,
+    rows: &[(i64, CachedIssueFields)],
+    now: &str,
+) -> Result<(), String> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to begin bulk update: {}", e))?;
+
+    for (id, fields) in rows {
+        sqlx::query(
+            r#"
+            UPDATE cached_issues
+            SET status = ?, state = ?, state_reason = ?, labels_json = ?, assignees_json = ?,
+                assignee_login = ?, assignee_avatar_url = ?, cached_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(&fields.status)
+        .bind(&fields.state)
+        .bind(&fields.state_reason)
+        .bind(&fields.labels_json)
+        .bind(&fields.assignees_json)
+        .bind(&fields.assignee_login)
+        .bind(&fields.assignee_avatar_url)
+        .bind(now)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to update cache: {}", e))?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to commit bulk update: {}", e))
+}
+
+fn failure(target: &BulkIssueTarget, error: String) -> BulkIssueFailure {
+    BulkIssueFailure {
+        issue_number: target.issue_number,
+        repo_full_name: target.repo_full_name.clone(),
+        error,
+    }
+}
+
+/// Apply one action to many issues of a project
+#[tauri::command]
+pub async fn bulk_update_issues(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    project_id: i64,
+    targets: Vec<BulkIssueTarget>,
+    action: BulkIssueAction,
+) -> Result<BulkIssueResult, String> {
+    let action = action.normalized();
+    action.validate()?;
+    let targets = dedupe_bulk_targets(targets);
+    if targets.is_empty() {
+        return Err("Issue を1つ以上選択してください".to_string());
+    }
+    if targets.len() > MAX_BULK_ISSUES {
+        return Err(format!(
+            "一度に操作できる Issue は {} 件までです",
+            MAX_BULK_ISSUES
+        ));
+    }
+
+    let project = get_project(state.clone(), project_id).await?;
+    if project.is_archived {
+        return Err(
+            "プロジェクトはアーカイブ状態です。Issue を一括操作するには、まず Re-link Repository でリポジトリを再リンクしてください。"
+                .to_string(),
+        );
+    }
+    let pool = state.db.pool();
+    let move_status = match &action {
+        BulkIssueAction::Move { status } => Some(*status),
+        _ => None,
+    };
+
+    let mut result = BulkIssueResult::default();
+    // (repo_full_name, number) of every issue to return in `updated`
+    let mut settled: Vec<(String, i32)> = Vec::new();
+    let mut jobs: Vec<BulkJob> = Vec::new();
+    let mut repositories: HashMap<Option<String>, Result<ProjectRepository, String>> =
+        HashMap::new();
+
+    // 1. Resolve and plan
+    for target in targets {
+        if !repositories.contains_key(&target.repo_full_name) {
+            let resolved =
+                resolve_project_repository(pool, &project, target.repo_full_name.as_deref()).await;
+            repositories.insert(target.repo_full_name.clone(), resolved);
+        }
+        let repository = match &repositories[&target.repo_full_name] {
+            Ok(repository) if repository.is_archived => {
+                result
+                    .failed
+                    .push(failure(&target, archived_repository_error(repository)));
+                continue;
+            }
+            Ok(repository) => repository.clone(),
+            Err(e) => {
+                result.failed.push(failure(&target, e.clone()));
+                continue;
+            }
+        };
+        let full_name = repository.repo_full_name.clone();
+
+        let queue_only = target.issue_number < 0
+            || has_pending_operation(pool, project_id, &full_name, target.issue_number).await?;
+        if queue_only {
+            match move_status {
+                Some(status) => {
+                    match enqueue_status_change(
+                        pool,
+                        project.user_id,
+                        project_id,
+                        &full_name,
+                        target.issue_number,
+                        status,
+                    )
+                    .await
+                    {
+                        Ok(()) => {
+                            result.queued += 1;
+                            settled.push((full_name, target.issue_number));
+                        }
+                        Err(e) => result.failed.push(failure(&target, e)),
+                    }
+                }
+                None => result.failed.push(failure(
+                    &target,
+                    "この Issue にはオフラインで行った変更が同期待ちです。同期が完了してから操作してください。"
+                        .to_string(),
+                )),
+            }
+            continue;
+        }
+
+        match fetch_cached_issue(pool, project_id, &full_name, target.issue_number).await {
+            Ok(issue) => {
+                let plan = plan_bulk_edit(&action, &issue);
+                jobs.push(BulkJob {
+                    target,
+                    repository,
+                    issue,
+                    plan,
+                });
+            }
+            Err(e) => result.failed.push(failure(&target, e)),
+        }
+    }
+
+    if !jobs.is_empty() {
+        let access_token = get_access_token(&state).await?;
+        let client = IssuesClient::new(access_token);
+
+        // 2. One optimistic write for the whole selection
+        let now = Utc::now().to_rfc3339();
+        let planned: Vec<(i64, CachedIssueFields)> = jobs
+            .iter()
+            .map(|job| {
+                (
+                    job.issue.id,
+                    CachedIssueFields::planned(&job.issue, &job.plan),
+                )
+            })
+            .collect();
+        write_cached_fields(pool, &planned, &now).await?;
+
+        // 3. GitHub, a few requests at a time
+        let outcomes: Vec<_> = stream::iter(jobs)
+            .map(|job| {
+                let client = &client;
+                async move {
+                    let response = client
+                        .edit_issue(
+                            &job.repository.repo_owner,
+                            &job.repository.repo_name,
+                            job.issue.number,
+                            &job.plan.edit,
+                        )
+                        .await;
+                    (job, response)
+                }
+            })
+            .buffered(BULK_CONCURRENCY)
+            .collect()
+            .await;
+
+        let now = Utc::now().to_rfc3339();
+        let mut rollback = Vec::new();
+        let mut offline_moves = Vec::new();
+        for (job, response) in outcomes {
+            let full_name = job.repository.repo_full_name.clone();
+            match response {
+                Ok(updated) => {
+                    match upsert_cached_issue(pool, project_id, &full_name, &updated, &now).await {
+                        Ok(()) => settled.push((full_name, job.issue.number)),
+                        Err(e) => result.failed.push(failure(&job.target, e)),
+                    }
+                }
+                Err(e) => {
+                    rollback.push((job.issue.id, CachedIssueFields::of(&job.issue)));
+                    if move_status.is_some() && is_offline_error(&e) {
+                        offline_moves.push(job);
+                    } else {
+                        let error = map_github_result::<()>(&app, state.inner(), Err(e))
+                            .await
+                            .err()
+                            .unwrap_or_default();
+                        result.failed.push(failure(&job.target, error));
+                    }
+                }
+            }
+        }
+        write_cached_fields(pool, &rollback, &now).await?;
+
+        // Offline moves are queued from their restored row so a discard
+        // rolls back to the last synced state.
+        if let Some(status) = move_status {
+            for job in offline_moves {
+                let full_name = job.repository.repo_full_name.clone();
+                match enqueue_status_change(
+                    pool,
+                    project.user_id,
+                    project_id,
+                    &full_name,
+                    job.issue.number,
+                    status,
+                )
+                .await
+                {
+                    Ok(()) => {
+                        result.queued += 1;
+                        settled.push((full_name, job.issue.number));
+                    }
+                    Err(e) => result.failed.push(failure(&job.target, e)),
+                }
+            }
+        }
+    }
+
+    for (full_name, number) in settled {
+        result
+            .updated
+            .push(fetch_cached_issue(pool, project_id, &full_name, number).await?);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::connection::Database;
+    use crate::database::test_support::seed_user_and_project;
+
+    fn issue(state: &str, status: &str, labels: &[&str]) -> CachedIssue {
+        CachedIssue {
+            id: 1,
+            project_id: 1,
+            repo_full_name: Some("octo/app".to_string()),
+            github_issue_id: 100,
+            number: 7,
+            title: "Fix login".to_string(),
+            body: None,
+            state: state.to_string(),
+            status: status.to_string(),
+            priority: None,
+            assignee_login: Some("alice".to_string()),
+            assignee_avatar_url: Some("https://avatars/alice".to_string()),
+            labels_json: Some(serde_json::to_string(labels).unwrap()),
+            html_url: None,
+            github_created_at: None,
+            github_updated_at: None,
+            cached_at: "2026-10-01T00:00:00Z".to_string(),
+            is_archived: false,
+            archived_at: None,
+            pending_sync: false,
+            assignees_json: Some(r#"["alice"]"#.to_string()),
+            milestone_number: None,
+            milestone_title: None,
+            state_reason: None,
+            linked_prs_json: None,
+        }
+    }
+
+    #[test]
+    fn test_plan_move_closes_and_reopens() {
+        let open = issue("open", "in-progress", &["status:in-progress", "bug"]);
+        let plan = plan_bulk_edit(
+            &BulkIssueAction::Move {
+                status: IssueStatus::Cancelled,
+            },
+            &open,
+        );
+        assert_eq!(plan.status, IssueStatus::Cancelled);
+        assert_eq!(plan.edit.state.as_deref(), Some("closed"));
+        assert_eq!(plan.edit.state_reason.as_deref(), Some("not_planned"));
+        assert_eq!(
+            plan.edit.labels,
+            Some(vec!["bug".to_string(), "status:cancelled".to_string()])
+        );
+
+        let closed = issue("closed", "done", &["status:done"]);
+        let plan = plan_bulk_edit(
+            &BulkIssueAction::Move {
+                status: IssueStatus::Todo,
+            },
+            &closed,
+        );
+        assert_eq!(plan.edit.state.as_deref(), Some("open"));
+        assert_eq!(plan.edit.state_reason.as_deref(), Some("reopened"));
+
+        // Moving between open columns leaves the state alone
+        let plan = plan_bulk_edit(
+            &BulkIssueAction::Move {
+                status: IssueStatus::InReview,
+            },
+            &open,
+        );
+        assert_eq!(plan.edit.state, None);
+    }
+
+    #[test]
+    fn test_plan_label_and_assignee_actions() {
+        let current = issue("open", "todo", &["status:todo", "bug"]);
+
+        let plan = plan_bulk_edit(
+            &BulkIssueAction::AddLabels {
+                labels: vec!["bug".into(), "ui".into()],
+            },
+            &current,
+        );
+        assert_eq!(
+            plan.edit.labels,
+            Some(vec!["status:todo".into(), "bug".into(), "ui".into()])
+        );
+        assert_eq!(plan.status, IssueStatus::Todo);
+
+        let plan = plan_bulk_edit(
+            &BulkIssueAction::RemoveLabels {
+                labels: vec!["bug".into()],
+            },
+            &current,
+        );
+        assert_eq!(plan.edit.labels, Some(vec!["status:todo".into()]));
+
+        let plan = plan_bulk_edit(
+            &BulkIssueAction::SetAssignees {
+                assignees: vec!["bob".into()],
+            },
+            &current,
+        );
+        let fields = CachedIssueFields::planned(&current, &plan);
+        assert_eq!(fields.assignee_login.as_deref(), Some("bob"));
+        assert_eq!(fields.assignee_avatar_url, None);
+        assert_eq!(fields.assignees_json.as_deref(), Some(r#"["bob"]"#));
+    }
+
+    #[tokio::test]
+    async fn test_write_cached_fields_applies_and_rolls_back() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+
+        let (_, project_id) = seed_user_and_project(pool).await;
+        sqlx::query(
+            r#"
+            INSERT INTO cached_issues (
+                project_id, repo_full_name, github_issue_id, number, title, state, status,
+                labels_json
+            )
+            VALUES (?, 'octo/app', 700, 7, 'Fix login', 'open', 'todo', '["status:todo"]')
+            "#,
+        )
+        .bind(project_id)
+        .execute(pool)
+        .await
+        .expect("seed issue");
+
+        let before = fetch_cached_issue(pool, project_id, "octo/app", 7)
+            .await
+            .unwrap();
+        let plan = plan_bulk_edit(
+            &BulkIssueAction::Close {
+                reason: "completed".into(),
+            },
+            &before,
+        );
+        let planned = CachedIssueFields::planned(&before, &plan);
+        write_cached_fields(pool, &[(before.id, planned)], "2026-10-02T00:00:00Z")
+            .await
+            .unwrap();
+
+        let after = fetch_cached_issue(pool, project_id, "octo/app", 7)
+            .await
+            .unwrap();
+        assert_eq!(after.status, "done");
+        assert_eq!(after.state, "closed");
+        assert_eq!(after.get_labels(), vec!["status:done".to_string()]);
+
+        write_cached_fields(
+            pool,
+            &[(before.id, CachedIssueFields::of(&before))],
+            "2026-10-02T00:00:00Z",
+        )
+        .await
+        .unwrap();
+        let restored = fetch_cached_issue(pool, project_id, "octo/app", 7)
+            .await
+            .unwrap();
+        assert_eq!(
+            CachedIssueFields::of(&restored),
+            CachedIssueFields::of(&before)
+        );
+    }
+}
@@ -0,0 +1,437 @@
+//! Issue comment commands
+//!
+//! `get_issue_comments` reads an issue's discussion one page at a time for
+//! the detail modal; `create_issue_comment` posts a reply. Pages are cached
+//! in `issue_comments_cache` (migration v22) for
+//! `cache_durations::ISSUE_COMMENTS` minutes. When GitHub can't be reached
+//! an expired page is still returned with `from_cache: true`, the same
+//! contract as the other `CachedResponse` commands.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   └─ src-tauri/src/commands/mod.rs
+//! Dependencies:
+//!   ├─ src-tauri/src/auth/session.rs      (map_github_result)
+//!   ├─ src-tauri/src/commands/github.rs   (CachedResponse, is_network_error)
+//!   ├─ src-tauri/src/commands/issues.rs   (get_project, get_access_token,
+//!   │                                      resolve_project_repository)
+//!   ├─ src-tauri/src/database/models/issue_comment.rs
+//!   └─ src-tauri/src/github/issues.rs     (IssuesClient, GitHubComment)
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{Pool, Sqlite};
+use tauri::{AppHandle, State};
+
+use crate::auth::map_github_result;
+use crate::commands::github::{is_network_error, CachedResponse};
+use crate::commands::issues::{get_access_token, get_project, resolve_project_repository};
+use crate::commands::AppState;
+use crate::database::cache_durations;
+use crate::database::models::issue_comment::{
+    IssueComment, IssueCommentsPage, ReactionCount, ISSUE_COMMENTS_PER_PAGE,
+};
+use crate::github::issues::{GitHubComment, GitHubReactions, IssuesClient};
+
+/// Non-zero reactions in the order GitHub shows them.
+pub(crate) fn reaction_counts(reactions: &GitHubReactions) -> Vec<ReactionCount> {
+    [
+        ("+1", "👍", reactions.plus_one),
+        ("-1", "👎", reactions.minus_one),
+        ("laugh", "😄", reactions.laugh),
+        ("hooray", "🎉", reactions.hooray),
+        ("confused", "😕", reactions.confused),
+        ("heart", "❤️", reactions.heart),
+        ("rocket", "🚀", reactions.rocket),
+        ("eyes", "👀", reactions.eyes),
+    ]
+    .into_iter()
+    .filter(|(_, _, count)| *count > 0)
+    .map(|(content, emoji, count)| ReactionCount {
+        content: content.to_string(),
+        emoji: emoji.to_string(),
+        count,
+    })
+    .collect()
+}
+
+pub(crate) fn to_issue_comment(comment: &GitHubComment) -> IssueComment {
+    IssueComment {
+        id: comment.id,
+        author_login: comment.user.as_ref().map(|u| u.login.clone()),
+        author_avatar_url: comment.user.as_ref().map(|u| u.avatar_url.clone()),
+        author_association: comment.author_association.clone(),
+        body: comment.body.clone().unwrap_or_default(),
+        html_url: comment.html_url.clone(),
+        created_at: comment.created_at.to_rfc3339(),
+        updated_at: comment.updated_at.to_rfc3339(),
+        reactions: comment
+            .reactions
+            .as_ref()
+            .map(reaction_counts)
+            .unwrap_or_default(),
+    }
+}
+
+/// A cached page with its `fetched_at` / `expires_at`, fresh or not.
+pub(crate) async fn load_cached_comments_page(
+    pool: &Pool<Sqlite>,
+    project_id: i64,
+    repo_full_name: &str,
+    issue_number: i32,
+    page: i32,
+) -> Result<Option<(IssueCommentsPage, String, String)>, String> {
+    let row: Option<(String, String, String)> = sqlx::query_as(
+        r#"
+        SELECT data_json, fetched_at, expires_at
+        FROM issue_comments_cache
+        WHERE project_id = ? AND repo_full_name = ? AND issue_number = ? AND page = ?
+        "#,
+    )
+    .bind(project_id)
+    .bind(repo_full_name)
+    .bind(issue_number)
+    .bind(page)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to load cached comments: {}", e))?;
+
+    let Some((data_json, fetched_at, expires_at)) = row else {
+        return Ok(None);
+    };
+    let data: IssueCommentsPage = serde_json::from_str(&data_json)
+        .map_err(|e| format!("Failed to parse cached comments: {}", e))?;
+    Ok(Some((data, fetched_at, expires_at)))
+}
+
+/// Store a freshly fetched page. Returns its `expires_at`.
+pub(crate) async fn save_cached_comments_page(
+    pool: &Pool<Sqlite>,
+    project_id: i64,
+    repo_full_name: &str,
+    issue_number: i32,
+    data: &IssueCommentsPage,
+    now: DateTime<Utc>,
+) -> Result<String, String> {
+    let data_json =
+        serde_json::to_string(data).map_err(|e| format!("Failed to serialize comments: {}", e))?;
+    let expires_at = (now + Duration::minutes(cache_durations::ISSUE_COMMENTS)).to_rfc3339();
+
+    sqlx::query(
+        r#"
+        INSERT INTO issue_comments_cache
+            (project_id, repo_full_name, issue_number, page, data_json, fetched_at, expires_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT(project_id, repo_full_name, issue_number, page) DO UPDATE SET
+            data_json = excluded.data_json,
+            fetched_at = excluded.fetched_at,
+            expires_at = excluded.expires_at
+        "#,
+    )
+    .bind(project_id)
+    .bind(repo_full_name)
+    .bind(issue_number)
+    .bind(data.page)
+    .bind(data_json)
+    .bind(now.to_rfc3339())
+    .bind(&expires_at)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to cache comments: {}", e))?;
+
+    Ok(expires_at)
+}
+
+/// Drop every cached page of an issue (after posting a comment).
+pub(crate) async fn clear_cached_comments(
+    pool: &Pool<Sqlite>,
+    project_id: i64,
+    repo_full_name: &str,
+    issue_number: i32,
+) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        DELETE FROM issue_comments_cache
+        WHERE project_id = ? AND repo_full_name = ? AND issue_number = ?
+        "#,
+    )
+    .bind(project_id)
+    .bind(repo_full_name)
+    .bind(issue_number)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to clear cached comments: {}", e))?;
+    Ok(())
+}
+
+fn is_fresh(expires_at: &str, now: DateTime<Utc>) -> bool {
+    DateTime::parse_from_rfc3339(expires_at)
+        .map(|t| t.with_timezone(&Utc) > now)
+        .unwrap_or(false)
+}
+
+fn cached_response(
+    (data, fetched_at, expires_at): (IssueCommentsPage, String, String),
+) -> CachedResponse<IssueCommentsPage> {
+    CachedResponse {
+        data,
+        from_cache: true,
+        cached_at: Some(fetched_at),
+        expires_at: Some(expires_at),
+    }
+}
+
+/// Get one page (1-based, default 1) of an issue's comments, oldest first
+#[tauri::command]
+pub async fn get_issue_comments(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    project_id: i64,
+    issue_number: i32,
+    page: Option<i32>,
+    force_refresh: Option<bool>,
+    repo_full_name: Option<String>,
+) -> Result<CachedResponse<IssueCommentsPage>, String> {
+    let page = page.unwrap_or(1).max(1);
+
+    // Issues created offline have no GitHub thread yet.
+    if issue_number <= 0 {
+        return Ok(CachedResponse {
+            data: IssueCommentsPage {
+                comments: Vec::new(),
+                page,
+                has_more: false,
+            },
+            from_cache: false,
+            cached_at: None,
+            expires_at: None,
+        });
+    }
+
+    let pool = state.db.pool();
+    let now = Utc::now();
+    let project = get_project(state.clone(), project_id).await?;
+    let repository = resolve_project_repository(pool, &project, repo_full_name.as_deref()).await?;
+    let full_name = repository.repo_full_name.as_str();
+    let cached = load_cached_comments_page(pool, project_id, full_name, issue_number, page).await?;
+
+    if !force_refresh.unwrap_or(false) {
+        if let Some(hit) = cached.as_ref().filter(|(_, _, exp)| is_fresh(exp, now)) {
+            return Ok(cached_response(hit.clone()));
+        }
+    }
+
+    // The linked repository is gone; whatever was cached is all there is.
+    if repository.is_archived {
+        return cached.map(cached_response).ok_or_else(|| {
+            "リポジトリはアーカイブ状態のため、コメントを取得できません。".to_string()
+        });
+    }
+    let owner = repository.repo_owner.as_str();
+    let repo = repository.repo_name.as_str();
+
+    let access_token = get_access_token(&state).await?;
+    let client = IssuesClient::new(access_token);
+
+    let comments = match client
+        .get_issue_comments(owner, repo, issue_number, ISSUE_COMMENTS_PER_PAGE, page)
+        .await
+    {
+        Err(e) if is_network_error(&e) => {
+            eprintln!("Comment fetch failed, falling back to cache: {}", e);
+            return cached.map(cached_response).ok_or_else(|| {
+                "オフラインでキャッシュされたコメントがありません。オンライン時に一度開いてください。"
+                    .to_string()
+            });
+        }
+        result => map_github_result(&app, state.inner(), result).await?,
+    };
+
+    let data = IssueCommentsPage {
+        has_more: comments.len() as i32 >= ISSUE_COMMENTS_PER_PAGE,
+        comments: comments.iter().map(to_issue_comment).collect(),
+        page,
+    };
+    let expires_at =
+        save_cached_comments_page(pool, project_id, full_name, issue_number, &data, now).await?;
+
+    Ok(CachedResponse {
+        data,
+        from_cache: false,
+        cached_at: Some(now.to_rfc3339()),
+        expires_at: Some(expires_at),
+    })
+}
+
+/// Post a comment (Markdown) on an issue
+#[tauri::command]
+pub async fn create_issue_comment(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    project_id: i64,
+    issue_number: i32,
+    body: String,
+    repo_full_name: Option<String>,
+) -> Result<IssueComment, String> {
+    if body.trim().is_empty() {
+        return Err("コメントを入力してください".to_string());
+    }
+    if issue_number <= 0 {
+        return Err(
+            "この Issue はまだ GitHub に作成されていません。同期が完了してからコメントしてください。"
+                .to_string(),
+        );
+    }
+
+    let project = get_project(state.clone(), project_id).await?;
+    let repository =
+        resolve_project_repository(state.db.pool(), &project, repo_full_name.as_deref()).await?;
+    if repository.is_archived {
+        return Err("リポジトリはアーカイブ状態のため、コメントできません。".to_string());
+    }
+    let owner = repository.repo_owner.as_str();
+    let repo = repository.repo_name.as_str();
+
+    let access_token = get_access_token(&state).await?;
+    let client = IssuesClient::new(access_token);
+
+    let result = client
+        .create_issue_comment(owner, repo, issue_number, &body)
+        .await;
+    let comment = map_github_result(&app, state.inner(), result).await?;
+
+    // The new comment lands on the last page; drop them all rather than
+    // working out which one.
+    clear_cached_comments(
+        state.db.pool(),
+        project_id,
+        &repository.repo_full_name,
+        issue_number,
+    )
+    .await?;
+
+    Ok(to_issue_comment(&comment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::connection::Database;
+    use crate::database::test_support::seed_user_and_project;
+
+    fn page(n: i32, bodies: &[&str]) -> IssueCommentsPage {
+        IssueCommentsPage {
+            comments: bodies
+                .iter()
+                .enumerate()
+                .map(|(i, body)| IssueComment {
+                    id: i as i64 + 1,
+                    author_login: Some("alice".to_string()),
+                    author_avatar_url: None,
+                    author_association: None,
+                    body: body.to_string(),
+                    html_url: String::new(),
+                    created_at: "2024-01-01T00:00:00+00:00".to_string(),
+                    updated_at: "2024-01-01T00:00:00+00:00".to_string(),
+                    reactions: Vec::new(),
+                })
+                .collect(),
+            page: n,
+            has_more: false,
+        }
+    }
+
+    #[test]
+    fn reaction_counts_skip_zero_and_keep_github_order() {
+        let reactions = GitHubReactions {
+            total_count: 4,
+            heart: 1,
+            plus_one: 2,
+            eyes: 1,
+            ..Default::default()
+        };
+        let contents: Vec<(String, i64)> = reaction_counts(&reactions)
+            .into_iter()
+            .map(|r| (r.content, r.count))
+            .collect();
+        assert_eq!(
+            contents,
+            vec![
+                ("+1".to_string(), 2),
+                ("heart".to_string(), 1),
+                ("eyes".to_string(), 1)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn comments_cache_round_trip_and_expiry() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (_, project_id) = seed_user_and_project(pool).await;
+        let now = Utc::now();
+
+        let expires_at =
+            save_cached_comments_page(pool, project_id, "octo/app", 7, &page(1, &["first"]), now)
+                .await
+                .unwrap();
+        assert!(is_fresh(&expires_at, now));
+        assert!(!is_fresh(
+            &expires_at,
+            now + Duration::minutes(cache_durations::ISSUE_COMMENTS + 1)
+        ));
+
+        // Re-saving the same page overwrites it.
+        save_cached_comments_page(
+            pool,
+            project_id,
+            "octo/app",
+            7,
+            &page(1, &["first", "second"]),
+            now,
+        )
+        .await
+        .unwrap();
+        let (cached, _, _) = load_cached_comments_page(pool, project_id, "octo/app", 7, 1)
+            .await
+            .unwrap()
+            .expect("cached page");
+        assert_eq!(cached.comments.len(), 2);
+        assert!(
+            load_cached_comments_page(pool, project_id, "octo/app", 7, 2)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn clearing_an_issue_keeps_other_issues() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (_, project_id) = seed_user_and_project(pool).await;
+        let now = Utc::now();
+
+        for (issue, n) in [(7, 1), (7, 2), (8, 1)] {
+            save_cached_comments_page(pool, project_id, "octo/app", issue, &page(n, &["x"]), now)
+                .await
+                .unwrap();
+        }
+        clear_cached_comments(pool, project_id, "octo/app", 7)
+            .await
+            .unwrap();
+
+        assert!(
+            load_cached_comments_page(pool, project_id, "octo/app", 7, 1)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            load_cached_comments_page(pool, project_id, "octo/app", 8, 1)
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+}
@@ -0,0 +1,359 @@
+//! Issue editing commands
+//!
+//! Editing beyond kanban status: title, body, assignees, milestone and
+//! labels (`update_issue_fields`), closing with a reason (`close_issue`)
+//! and reopening (`reopen_issue`). Each command sends a single PATCH to
+//! GitHub and writes the returned issue back into `cached_issues` through
+//! the same upsert the sync uses, so the cache never drifts from what
+//! GitHub accepted.
+//!
+//! `status:*` labels stay owned by the kanban: label edits keep whatever
+//! status label the issue already has, and close / reopen swap it the same
+//! way `update_issue_status` does.
+//!
+//! Unlike status changes these edits are not queued while offline — the
+//! command fails and the modal keeps the user's input.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   └─ src-tauri/src/commands/mod.rs
+//! Dependencies:
+//!   ├─ src-tauri/src/auth/session.rs      (map_github_result)
+//!   ├─ src-tauri/src/commands/issues.rs   (get_project, resolve_project_repository,
+//!   │                                      upsert_cached_issue, fetch_cached_issue)
+//!   ├─ src-tauri/src/commands/issue_outbox.rs (has_pending_operation)
+//!   ├─ src-tauri/src/database/models/project.rs
+//!   └─ src-tauri/src/github/issues.rs     (IssuesClient, IssueEdit)
+
+use chrono::Utc;
+use tauri::{AppHandle, State};
+
+use crate::auth::map_github_result;
+use crate::commands::issue_outbox::has_pending_operation;
+use crate::commands::issues::{
+    archived_repository_error, fetch_cached_issue, get_access_token, get_project,
+    resolve_project_repository, upsert_cached_issue,
+};
+use crate::commands::AppState;
+use crate::database::models::project::{
+    AssigneeOption, CachedIssue, IssueEditOptions, IssueStatus, LabelOption, MilestoneOption,
+    UpdateIssueFieldsRequest,
+};
+use crate::github::issues::{IssueEdit, IssuesClient};
+
+/// Linked repository of an editable issue.
+struct EditTarget {
+    owner: String,
+    repo: String,
+    full_name: String,
+    issue: CachedIssue,
+}
+
+/// Resolve and validate the issue an edit command targets.
+async fn edit_target(
+    state: &State<'_, AppState>,
+    project_id: i64,
+    repo_full_name: Option<&str>,
+    issue_number: i32,
+) -> Result<EditTarget, String> {
+    let project = get_project(state.clone(), project_id).await?;
+
+    if project.is_archived {
+        return Err(
+            "プロジェクトはアーカイブ状態です。Issue を編集するには、まず Re-link Repository でリポジトリを再リンクしてください。"
+                .to_string(),
+        );
+    }
+
+    let repository = resolve_project_repository(state.db.pool(), &project, repo_full_name).await?;
+    if repository.is_archived {
+        return Err(archived_repository_error(&repository));
+    }
+
+    // A queued create has no GitHub issue yet, and a queued status change
+    // would be replayed on top of (and possibly conflict with) this edit.
+    if issue_number < 0
+        || has_pending_operation(
+            state.db.pool(),
+            project_id,
+            &repository.repo_full_name,
+            issue_number,
+        )
+        .await?
+    {
+        return Err(
+            "この Issue にはオフラインで行った変更が同期待ちです。同期が完了してから編集してください。"
+                .to_string(),
+        );
+    }
+
+    let issue = fetch_cached_issue(
+        state.db.pool(),
+        project_id,
+        &repository.repo_full_name,
+        issue_number,
+    )
+    .await?;
+
+    Ok(EditTarget {
+        owner: repository.repo_owner,
+        repo: repository.repo_name,
+        full_name: repository.repo_full_name,
+        issue,
+    })
+}
+
+/// PATCH the issue on GitHub and refresh its cache row from the response.
+async fn apply_edit(
+    app: &AppHandle,
+    state: &State<'_, AppState>,
+    project_id: i64,
+    target: &EditTarget,
+    edit: &IssueEdit,
+) -> Result<CachedIssue, String> {
+    let access_token = get_access_token(state).await?;
+    let client = IssuesClient::new(access_token);
+
+    let result = client
+        .edit_issue(&target.owner, &target.repo, target.issue.number, edit)
+        .await;
+    let updated = map_github_result(app, state.inner(), result).await?;
+
+    let now = Utc::now().to_rfc3339();
+    upsert_cached_issue(
+        state.db.pool(),
+        project_id,
+        &target.full_name,
+        &updated,
+        &now,
+    )
+    .await?;
+
+    fetch_cached_issue(
+        state.db.pool(),
+        project_id,
+        &target.full_name,
+        target.issue.number,
+    )
+    .await
+}
+
+/// Requested labels with any `status:*` label replaced by the status
+/// labels the issue currently has.
+pub(crate) fn merge_status_labels(requested: &[String], current: &[String]) -> Vec<String> {
+    let mut labels: Vec<String> = requested
+        .iter()
+        .filter(|l| IssueStatus::from_label(l).is_none())
+        .cloned()
+        .collect();
+    labels.extend(
+        current
+            .iter()
+            .filter(|l| IssueStatus::from_label(l).is_some())
+            .cloned(),
+    );
+    let mut seen = std::collections::HashSet::new();
+    labels.retain(|l| seen.insert(l.clone()));
+    labels
+}
+
+/// `labels` with every status label swapped for `status`'s.
+pub(crate) fn with_status_label(labels: &[String], status: IssueStatus) -> Vec<String> {
+    let mut result: Vec<String> = labels
+        .iter()
+        .filter(|l| IssueStatus::from_label(l).is_none())
+        .cloned()
+        .collect();
+    result.push(status.to_label().to_string());
+    result
+}
+
+/// Kanban status a close reason maps to (mirrors
+/// `IssuesClient::extract_status_with_state`).
+pub(crate) fn status_for_close_reason(reason: &str) -> Result<IssueStatus, String> {
+    match reason {
+        "completed" => Ok(IssueStatus::Done),
+        "not_planned" => Ok(IssueStatus::Cancelled),
+        other => Err(format!("Invalid close reason: {}", other)),
+    }
+}
+
+/// Edit title, body, assignees, milestone and/or labels of an issue
+#[tauri::command]
+pub async fn update_issue_fields(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    project_id: i64,
+    issue_number: i32,
+    update: UpdateIssueFieldsRequest,
+    repo_full_name: Option<String>,
+) -> Result<CachedIssue, String> {
+    let target = edit_target(&state, project_id, repo_full_name.as_deref(), issue_number).await?;
+
+    let title = match update.title {
+        Some(t) if t.trim().is_empty() => return Err("タイトルを入力してください".to_string()),
+        Some(t) => Some(t.trim().to_string()),
+        None => None,
+    };
+
+    let edit = IssueEdit {
+        title,
+        body: update.body,
+        assignees: update.assignees,
+        milestone: update.milestone,
+        labels: update
+            .labels
+            .map(|requested| merge_status_labels(&requested, &target.issue.get_labels())),
+        ..Default::default()
+    };
+
+    apply_edit(&app, &state, project_id, &target, &edit).await
+}
+
+/// Close an issue with a reason (`completed` → Done, `not_planned` →
+/// Cancelled)
+#[tauri::command]
+pub async fn close_issue(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    project_id: i64,
+    issue_number: i32,
+    reason: String,
+    repo_full_name: Option<String>,
+) -> Result<CachedIssue, String> {
+    let status = status_for_close_reason(&reason)?;
+    let target = edit_target(&state, project_id, repo_full_name.as_deref(), issue_number).await?;
+
+    let edit = IssueEdit {
+        state: Some("closed".to_string()),
+        state_reason: Some(reason),
+        labels: Some(with_status_label(&target.issue.get_labels(), status)),
+        ..Default::default()
+    };
+
+    apply_edit(&app, &state, project_id, &target, &edit).await
+}
+
+/// Reopen a closed issue. It lands in `status` (default: Backlog).
+#[tauri::command]
+pub async fn reopen_issue(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    project_id: i64,
+    issue_number: i32,
+    status: Option<String>,
+    repo_full_name: Option<String>,
+) -> Result<CachedIssue, String> {
+    let status: IssueStatus = match status {
+        Some(s) => s.parse().map_err(|_| format!("Invalid status: {}", s))?,
+        None => IssueStatus::Backlog,
+    };
+    if matches!(status, IssueStatus::Done | IssueStatus::Cancelled) {
+        return Err(format!("Invalid status for an open issue: {}", status));
+    }
+    let target = edit_target(&state, project_id, repo_full_name.as_deref(), issue_number).await?;
+
+    let edit = IssueEdit {
+        state: Some("open".to_string()),
+        state_reason: Some("reopened".to_string()),
+        labels: Some(with_status_label(&target.issue.get_labels(), status)),
+        ..Default::default()
+    };
+
+    apply_edit(&app, &state, project_id, &target, &edit).await
+}
+
+/// Labels, assignable users and open milestones of one of the project's
+/// repositories (the primary one by default), for the issue edit form
+#[tauri::command]
+pub async fn get_issue_edit_options(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    project_id: i64,
+    repo_full_name: Option<String>,
+) -> Result<IssueEditOptions, String> {
+    let project = get_project(state.clone(), project_id).await?;
+    let repository =
+        resolve_project_repository(state.db.pool(), &project, repo_full_name.as_deref()).await?;
+    let owner = repository.repo_owner;
+    let repo = repository.repo_name;
+
+    let access_token = get_access_token(&state).await?;
+    let client = IssuesClient::new(access_token);
+
+    let (labels, assignees, milestones) = tokio::join!(
+        client.get_labels(&owner, &repo),
+        client.get_assignees(&owner, &repo),
+        client.get_milestones(&owner, &repo),
+    );
+    let labels = map_github_result(&app, state.inner(), labels).await?;
+    let assignees = map_github_result(&app, state.inner(), assignees).await?;
+    let milestones = map_github_result(&app, state.inner(), milestones).await?;
+
+    Ok(IssueEditOptions {
+        // Status labels are driven by the kanban columns, not the form.
+        labels: labels
+            .into_iter()
+            .filter(|l| IssueStatus::from_label(&l.name).is_none())
+            .map(|l| LabelOption {
+                name: l.name,
+                color: l.color,
+            })
+            .collect(),
+        assignees: assignees
+            .into_iter()
+            .map(|a| AssigneeOption {
+                login: a.login,
+                avatar_url: a.avatar_url,
+            })
+            .collect(),
+        milestones: milestones
+            .into_iter()
+            .map(|m| MilestoneOption {
+                number: m.number,
+                title: m.title,
+                due_on: m.due_on.map(|d| d.to_rfc3339()),
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn merge_status_labels_keeps_current_status_label() {
+        let merged = merge_status_labels(
+            &labels(&["bug", "status:done", "priority:high"]),
+            &labels(&["status:in-progress", "enhancement"]),
+        );
+        assert_eq!(
+            merged,
+            labels(&["bug", "priority:high", "status:in-progress"])
+        );
+    }
+
+    #[test]
+    fn with_status_label_replaces_existing_status() {
+        assert_eq!(
+            with_status_label(&labels(&["status:todo", "bug"]), IssueStatus::Cancelled),
+            labels(&["bug", "status:cancelled"])
+        );
+    }
+
+    #[test]
+    fn close_reason_maps_to_status() {
+        assert_eq!(status_for_close_reason("completed"), Ok(IssueStatus::Done));
+        assert_eq!(
+            status_for_close_reason("not_planned"),
+            Ok(IssueStatus::Cancelled)
+        );
+        assert!(status_for_close_reason("duplicate").is_err());
+    }
+}
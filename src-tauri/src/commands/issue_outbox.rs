@@ -0,0 +1,1089 @@
+//! Offline write queue (outbox) for issue mutations
+//!
+//! When GitHub is unreachable, `update_issue_status` and
+//! `create_github_issue` record the mutation in `issue_outbox` and apply it
+//! optimistically to `cached_issues` instead of failing. The frontend calls
+//! `replay_issue_outbox` once connectivity returns (and
+//! `sync_project_issues` flushes the queue before pulling), pushing each
+//! queued operation to GitHub in order.
+//!
+//! Conflict detection: a queued status change remembers the issue's
+//! `github_updated_at` at enqueue time. If GitHub reports a newer
+//! `updated_at` on replay, somebody else touched the issue while we were
+//! offline — the operation is parked as `conflict` for the user to force
+//! or discard rather than blindly overwriting the remote edit.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/commands/mod.rs
+//!   └─ src-tauri/src/commands/issues.rs   (enqueue_* on network failure)
+//! Dependencies:
+//!   ├─ src-tauri/src/auth/session.rs      (map_github_result)
+//!   ├─ src-tauri/src/commands/issues.rs   (get_current_user_id, get_access_token)
+//!   ├─ src-tauri/src/database/models/issue_outbox.rs
+//!   ├─ src-tauri/src/database/models/project.rs
+//!   └─ src-tauri/src/github/issues.rs     (IssuesClient)
+
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use tauri::{AppHandle, State};
+
+use crate::auth::map_github_result;
+use crate::commands::issues::{get_access_token, get_current_user_id};
+use crate::commands::AppState;
+use crate::database::models::issue_outbox::{
+    outbox_operations, outbox_statuses, CreateIssuePayload, OutboxOperation, OutboxReplayResult,
+    UpdateStatusPayload,
+};
+use crate::database::models::project::{IssuePriority, IssueStatus};
+use crate::github::client::GitHubError;
+use crate::github::issues::IssuesClient;
+
+/// True when the error means "GitHub could not be reached at all"
+/// (DNS failure, refused connection, timeout) as opposed to GitHub
+/// answering with an error. Only the former is worth queueing — a 422 or
+/// 403 would fail identically on replay.
+pub(crate) fn is_offline_error(error: &GitHubError) -> bool {
+    match error {
+        GitHubError::HttpRequest(e) => e.is_connect() || e.is_timeout() || e.is_request(),
+        _ => false,
+    }
+}
+
+/// GitHub state implied by a kanban status (Done / Cancelled close the
+/// issue, everything else keeps it open). Mirrors
+/// `IssuesClient::update_issue_status` so the optimistic cache row matches
+/// what the replay will eventually produce.
+fn state_for_status(status: IssueStatus) -> &'static str {
+    match status {
+        IssueStatus::Done | IssueStatus::Cancelled => "closed",
+        _ => "open",
+    }
+}
+
+/// True when GitHub's `updated_at` is strictly newer than the value we saw
+/// when the operation was queued. A missing or unparsable base means the
+/// caller opted out of the check (forced retry, or a row that was never
+/// synced) and is treated as "not newer".
+pub(crate) fn is_remote_newer(base_updated_at: Option<&str>, remote: DateTime<Utc>) -> bool {
+    base_updated_at
+        .and_then(|base| DateTime::parse_from_rfc3339(base).ok())
+        .map(|base| remote > base.with_timezone(&Utc))
+        .unwrap_or(false)
+}
+
+/// True when the issue still has a queued (pending) operation. Used by
+/// `update_issue_status` to route later edits through the queue too, so
+/// an online edit can't overtake an older offline one on replay.
+pub(crate) async fn has_pending_operation(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    project_id: i64,
+    repo_full_name: &str,
+    issue_number: i32,
+) -> Result<bool, String> {
+    let row = sqlx::query(
+        r#"
+        SELECT COUNT(*) AS cnt FROM issue_outbox
+        WHERE project_id = ? AND repo_full_name = ? AND issue_number = ? AND status = ?
+        "#,
+    )
+    .bind(project_id)
+    .bind(repo_full_name)
+    .bind(issue_number)
+    .bind(outbox_statuses::PENDING)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| format!("Failed to query outbox: {}", e))?;
+    Ok(row.get::<i64, _>("cnt") > 0)
+}
+
+/// Queue a status change and apply it optimistically to `cached_issues`.
+///
+/// Consecutive offline moves of the same card coalesce into one pending
+/// operation (only the final column matters to GitHub), keeping the
+/// original `previous_*` values so a discard still rolls back to the last
+/// synced state. Moves of a not-yet-created issue (negative placeholder
+/// number) rewrite the queued `create_issue` payload instead.
+///
+/// The outbox and cache writes share one transaction, so a failure
+/// never leaves a card moved without its queued operation.
+pub(crate) async fn enqueue_status_change(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    user_id: i64,
+    project_id: i64,
+    repo_full_name: &str,
+    issue_number: i32,
+    new_status: IssueStatus,
+) -> Result<(), String> {
+    let now = Utc::now().to_rfc3339();
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    let current = sqlx::query(
+        r#"
+        SELECT status, state, github_updated_at FROM cached_issues
+        WHERE project_id = ? AND repo_full_name = ? AND number = ?
+        "#,
+    )
+    .bind(project_id)
+    .bind(repo_full_name)
+    .bind(issue_number)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to fetch issue: {}", e))?
+    .ok_or_else(|| "Issue not found".to_string())?;
+
+    if issue_number < 0 {
+        let op = sqlx::query_as::<_, OutboxOperation>(
+            r#"
+            SELECT id, user_id, project_id, repo_full_name, operation, issue_number, payload_json,
+                   base_updated_at, status, attempts, last_error, created_at, updated_at
+            FROM issue_outbox
+            WHERE project_id = ? AND repo_full_name = ? AND issue_number = ? AND operation = ?
+            "#,
+        )
+        .bind(project_id)
+        .bind(repo_full_name)
+        .bind(issue_number)
+        .bind(outbox_operations::CREATE_ISSUE)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to query outbox: {}", e))?
+        .ok_or_else(|| "Queued issue not found".to_string())?;
+
+        let mut payload = op
+            .create_issue_payload()
+            .ok_or_else(|| "Corrupted outbox payload".to_string())?;
+        payload.status = new_status.to_string();
+        let payload_json = serde_json::to_string(&payload)
+            .map_err(|e| format!("Failed to serialize payload: {}", e))?;
+
+        sqlx::query("UPDATE issue_outbox SET payload_json = ?, updated_at = ? WHERE id = ?")
+            .bind(&payload_json)
+            .bind(&now)
+            .bind(op.id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to update outbox: {}", e))?;
+    } else {
+        let existing = sqlx::query_as::<_, OutboxOperation>(
+            r#"
+            SELECT id, user_id, project_id, repo_full_name, operation, issue_number, payload_json,
+                   base_updated_at, status, attempts, last_error, created_at, updated_at
+            FROM issue_outbox
+            WHERE project_id = ? AND repo_full_name = ? AND issue_number = ? AND operation = ?
+              AND status = ?
+            ORDER BY id DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(project_id)
+        .bind(repo_full_name)
+        .bind(issue_number)
+        .bind(outbox_operations::UPDATE_STATUS)
+        .bind(outbox_statuses::PENDING)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to query outbox: {}", e))?;
+
+        match existing.and_then(|op| op.update_status_payload().map(|p| (op.id, p))) {
+            Some((op_id, mut payload)) => {
+                payload.new_status = new_status.to_string();
+                let payload_json = serde_json::to_string(&payload)
+                    .map_err(|e| format!("Failed to serialize payload: {}", e))?;
+                sqlx::query(
+                    "UPDATE issue_outbox SET payload_json = ?, updated_at = ? WHERE id = ?",
+                )
+                .bind(&payload_json)
+                .bind(&now)
+                .bind(op_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| format!("Failed to update outbox: {}", e))?;
+            }
+            None => {
+                let payload = UpdateStatusPayload {
+                    new_status: new_status.to_string(),
+                    previous_status: current.get::<String, _>("status"),
+                    previous_state: current.get::<String, _>("state"),
+                };
+                let payload_json = serde_json::to_string(&payload)
+                    .map_err(|e| format!("Failed to serialize payload: {}", e))?;
+                sqlx::query(
+                    r#"
+                    INSERT INTO issue_outbox (
+                        user_id, project_id, repo_full_name, operation, issue_number,
+                        payload_json, base_updated_at, status, created_at, updated_at
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                    "#,
+                )
+                .bind(user_id)
+                .bind(project_id)
+                .bind(repo_full_name)
+                .bind(outbox_operations::UPDATE_STATUS)
+                .bind(issue_number)
+                .bind(&payload_json)
+                .bind(current.get::<Option<String>, _>("github_updated_at"))
+                .bind(outbox_statuses::PENDING)
+                .bind(&now)
+                .bind(&now)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| format!("Failed to enqueue operation: {}", e))?;
+            }
+        }
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE cached_issues SET status = ?, state = ?, pending_sync = 1, cached_at = ?
+        WHERE project_id = ? AND repo_full_name = ? AND number = ?
+        "#,
+    )
+    .bind(new_status.to_string())
+    .bind(state_for_status(new_status))
+    .bind(&now)
+    .bind(project_id)
+    .bind(repo_full_name)
+    .bind(issue_number)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to update cache: {}", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to commit status change: {}", e))?;
+
+    Ok(())
+}
+
+/// GitHub labels of a queued creation: status, priority, then the extra
+/// (template) labels.
+fn creation_labels(payload: &CreateIssuePayload) -> Vec<String> {
+    let status: IssueStatus = payload.status.parse().unwrap_or_default();
+    let mut labels = vec![status.to_label().to_string()];
+    if let Some(p) = payload
+        .priority
+        .as_ref()
+        .and_then(|p| p.parse::<IssuePriority>().ok())
+    {
+        labels.push(p.to_label().to_string());
+    }
+    for label in &payload.labels {
+        if !labels.contains(label) {
+            labels.push(label.clone());
+        }
+    }
+    labels
+}
+
+/// Queue an issue creation and insert an optimistic placeholder row.
+///
+/// The placeholder uses `-outbox_id` for both `github_issue_id` and
+/// `number`: negative values can never collide with real GitHub ids, and
+/// the UI can recognise a queued issue by its negative number. Returns the
+/// placeholder number.
+///
+/// The outbox entry and the placeholder are written in one transaction.
+pub(crate) async fn enqueue_issue_creation(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    user_id: i64,
+    project_id: i64,
+    repo_full_name: &str,
+    payload: &CreateIssuePayload,
+) -> Result<i32, String> {
+    let now = Utc::now().to_rfc3339();
+    let payload_json = serde_json::to_string(payload)
+        .map_err(|e| format!("Failed to serialize payload: {}", e))?;
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    let op_id: i64 = sqlx::query(
+        r#"
+        INSERT INTO issue_outbox (
+            user_id, project_id, repo_full_name, operation, issue_number, payload_json,
+            status, created_at, updated_at
+        )
+        VALUES (?, ?, ?, ?, 0, ?, ?, ?, ?)
+        RETURNING id
+        "#,
+    )
+    .bind(user_id)
+    .bind(project_id)
+    .bind(repo_full_name)
+    .bind(outbox_operations::CREATE_ISSUE)
+    .bind(&payload_json)
+    .bind(outbox_statuses::PENDING)
+    .bind(&now)
+    .bind(&now)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to enqueue operation: {}", e))?
+    .get::<i64, _>("id");
+
+    let placeholder = -(op_id as i32);
+    sqlx::query("UPDATE issue_outbox SET issue_number = ? WHERE id = ?")
+        .bind(placeholder)
+        .bind(op_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to update outbox: {}", e))?;
+
+    let status: IssueStatus = payload.status.parse().unwrap_or_default();
+    let labels_json =
+        serde_json::to_string(&creation_labels(payload)).unwrap_or_else(|_| "[]".to_string());
+
+    sqlx::query(
+        r#"
+        INSERT INTO cached_issues (
+            project_id, repo_full_name, github_issue_id, number, title, body, state, status,
+            priority, labels_json, github_created_at, cached_at, pending_sync
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 1)
+        "#,
+    )
+    .bind(project_id)
+    .bind(repo_full_name)
+    .bind(placeholder as i64)
+    .bind(placeholder)
+    .bind(&payload.title)
+    .bind(&payload.body)
+    .bind(state_for_status(status))
+    .bind(status.to_string())
+    .bind(&payload.priority)
+    .bind(&labels_json)
+    .bind(&now)
+    .bind(&now)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to cache issue: {}", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to commit issue creation: {}", e))?;
+
+    Ok(placeholder)
+}
+
+/// List the user's queued operations, oldest first.
+pub(crate) async fn list_outbox_operations(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    user_id: i64,
+    project_id: Option<i64>,
+) -> Result<Vec<OutboxOperation>, String> {
+    sqlx::query_as(
+        r#"
+        SELECT id, user_id, project_id, repo_full_name, operation, issue_number, payload_json,
+               base_updated_at, status, attempts, last_error, created_at, updated_at
+        FROM issue_outbox
+        WHERE user_id = ? AND (? IS NULL OR project_id = ?)
+        ORDER BY id ASC
+        "#,
+    )
+    .bind(user_id)
+    .bind(project_id)
+    .bind(project_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch outbox: {}", e))
+}
+
+async fn get_outbox_operation(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    user_id: i64,
+    operation_id: i64,
+) -> Result<OutboxOperation, String> {
+    sqlx::query_as(
+        r#"
+        SELECT id, user_id, project_id, repo_full_name, operation, issue_number, payload_json,
+               base_updated_at, status, attempts, last_error, created_at, updated_at
+        FROM issue_outbox
+        WHERE id = ? AND user_id = ?
+        "#,
+    )
+    .bind(operation_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch outbox operation: {}", e))?
+    .ok_or_else(|| "Outbox operation not found".to_string())
+}
+
+/// Record a non-retryable outcome (`failed` / `conflict`) on an operation.
+async fn park_operation(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    operation_id: i64,
+    status: &str,
+    message: &str,
+) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        UPDATE issue_outbox
+        SET status = ?, last_error = ?, attempts = attempts + 1, updated_at = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(status)
+    .bind(message)
+    .bind(Utc::now().to_rfc3339())
+    .bind(operation_id)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to update outbox: {}", e))?;
+    Ok(())
+}
+
+/// Clear `pending_sync` on an issue once no queued operation references it.
+async fn settle_pending_flag(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    project_id: i64,
+    repo_full_name: Option<&str>,
+    issue_number: i32,
+) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        UPDATE cached_issues SET pending_sync = 0
+        WHERE project_id = ? AND repo_full_name IS ? AND number = ?
+          AND NOT EXISTS (
+              SELECT 1 FROM issue_outbox
+              WHERE issue_outbox.project_id = cached_issues.project_id
+                AND issue_outbox.repo_full_name IS cached_issues.repo_full_name
+                AND issue_outbox.issue_number = cached_issues.number
+          )
+        "#,
+    )
+    .bind(project_id)
+    .bind(repo_full_name)
+    .bind(issue_number)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to update cache: {}", e))?;
+    Ok(())
+}
+
+/// Drop an operation and roll its optimistic change back.
+///
+/// Queued creates lose their placeholder row; queued status changes
+/// restore the `previous_*` values captured at enqueue time. The next
+/// sync then brings the row fully in line with GitHub.
+pub(crate) async fn discard_operation(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    user_id: i64,
+    operation_id: i64,
+) -> Result<(), String> {
+    let op = get_outbox_operation(pool, user_id, operation_id).await?;
+
+    sqlx::query("DELETE FROM issue_outbox WHERE id = ?")
+        .bind(op.id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to delete outbox operation: {}", e))?;
+
+    if op.operation == outbox_operations::CREATE_ISSUE {
+        sqlx::query(
+            "DELETE FROM cached_issues WHERE project_id = ? AND repo_full_name IS ? AND number = ?",
+        )
+        .bind(op.project_id)
+        .bind(&op.repo_full_name)
+        .bind(op.issue_number)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to delete placeholder issue: {}", e))?;
+        return Ok(());
+    }
+
+    if let Some(payload) = op.update_status_payload() {
+        sqlx::query(
+            r#"
+            UPDATE cached_issues SET status = ?, state = ?
+            WHERE project_id = ? AND repo_full_name IS ? AND number = ?
+            "#,
+        )
+        .bind(&payload.previous_status)
+        .bind(&payload.previous_state)
+        .bind(op.project_id)
+        .bind(&op.repo_full_name)
+        .bind(op.issue_number)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to update cache: {}", e))?;
+    }
+    settle_pending_flag(
+        pool,
+        op.project_id,
+        op.repo_full_name.as_deref(),
+        op.issue_number,
+    )
+    .await
+}
+
+/// Put a `failed` / `conflict` operation back in the queue. `force` drops
+/// the conflict baseline so the next replay overwrites the remote edit.
+pub(crate) async fn requeue_operation(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    user_id: i64,
+    operation_id: i64,
+    force: bool,
+) -> Result<OutboxOperation, String> {
+    let op = get_outbox_operation(pool, user_id, operation_id).await?;
+
+    sqlx::query(
+        r#"
+        UPDATE issue_outbox
+        SET status = ?, last_error = NULL, updated_at = ?,
+            base_updated_at = CASE WHEN ? THEN NULL ELSE base_updated_at END
+        WHERE id = ?
+        "#,
+    )
+    .bind(outbox_statuses::PENDING)
+    .bind(Utc::now().to_rfc3339())
+    .bind(force)
+    .bind(op.id)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to requeue operation: {}", e))?;
+
+    get_outbox_operation(pool, user_id, operation_id).await
+}
+
+/// Result of pushing a single operation to GitHub.
+enum ReplayStep {
+    Applied,
+    Parked(&'static str, String),
+    /// GitHub is still unreachable — stop replaying and keep everything
+    /// that's left pending for the next attempt.
+    Offline,
+}
+
+/// Map a GitHub error from a replay call to the next step. Unauthorized is
+/// funnelled through `map_github_result` so the auth-expired flow fires
+/// exactly as it would for a direct command call.
+async fn classify_replay_error(
+    app: &AppHandle,
+    state: &AppState,
+    error: GitHubError,
+) -> Result<ReplayStep, String> {
+    if is_offline_error(&error) {
+        return Ok(ReplayStep::Offline);
+    }
+    if matches!(error, GitHubError::Unauthorized) {
+        return map_github_result(app, state, Err(error)).await;
+    }
+    Ok(ReplayStep::Parked(
+        outbox_statuses::FAILED,
+        error.to_string(),
+    ))
+}
+
+async fn replay_update_status(
+    app: &AppHandle,
+    state: &AppState,
+    client: &IssuesClient,
+    op: &OutboxOperation,
+    owner: &str,
+    repo: &str,
+) -> Result<ReplayStep, String> {
+    let pool = state.db.pool();
+    let Some(payload) = op.update_status_payload() else {
+        return Ok(ReplayStep::Parked(
+            outbox_statuses::FAILED,
+            "Corrupted outbox payload".to_string(),
+        ));
+    };
+    let Ok(status) = payload.new_status.parse::<IssueStatus>() else {
+        return Ok(ReplayStep::Parked(
+            outbox_statuses::FAILED,
+            format!("Invalid status: {}", payload.new_status),
+        ));
+    };
+
+    let remote = match client.get_issue(owner, repo, op.issue_number).await {
+        Ok(issue) => issue,
+        Err(e) => return classify_replay_error(app, state, e).await,
+    };
+    if is_remote_newer(op.base_updated_at.as_deref(), remote.updated_at) {
+        return Ok(ReplayStep::Parked(
+            outbox_statuses::CONFLICT,
+            format!(
+                "オフライン中に GitHub 側で Issue #{} が更新されました ({})。",
+                op.issue_number,
+                remote.updated_at.to_rfc3339()
+            ),
+        ));
+    }
+
+    let updated = match client
+        .update_issue_status(owner, repo, op.issue_number, status)
+        .await
+    {
+        Ok(issue) => issue,
+        Err(e) => return classify_replay_error(app, state, e).await,
+    };
+
+    sqlx::query(
+        r#"
+        UPDATE cached_issues SET status = ?, state = ?, github_updated_at = ?, cached_at = ?
+        WHERE project_id = ? AND repo_full_name IS ? AND number = ?
+        "#,
+    )
+    .bind(status.to_string())
+    .bind(&updated.state)
+    .bind(updated.updated_at.to_rfc3339())
+    .bind(Utc::now().to_rfc3339())
+    .bind(op.project_id)
+    .bind(&op.repo_full_name)
+    .bind(op.issue_number)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to update cache: {}", e))?;
+
+    Ok(ReplayStep::Applied)
+}
+
+async fn replay_create_issue(
+    app: &AppHandle,
+    state: &AppState,
+    client: &IssuesClient,
+    op: &OutboxOperation,
+    owner: &str,
+    repo: &str,
+) -> Result<ReplayStep, String> {
+    let pool = state.db.pool();
+    let Some(payload) = op.create_issue_payload() else {
+        return Ok(ReplayStep::Parked(
+            outbox_statuses::FAILED,
+            "Corrupted outbox payload".to_string(),
+        ));
+    };
+
+    let labels = creation_labels(&payload);
+
+    let created = match client
+        .create_issue(
+            owner,
+            repo,
+            &payload.title,
+            payload.body.as_deref(),
+            labels,
+            payload.assignees.clone(),
+        )
+        .await
+    {
+        Ok(issue) => issue,
+        Err(e) => return classify_replay_error(app, state, e).await,
+    };
+
+    let labels_json =
+        serde_json::to_string(&created.labels.iter().map(|l| &l.name).collect::<Vec<_>>())
+            .unwrap_or_else(|_| "[]".to_string());
+
+    // Promote the placeholder in place so the card keeps its position and
+    // any open detail view keeps pointing at the same local row id.
+    sqlx::query(
+        r#"
+        UPDATE cached_issues
+        SET github_issue_id = ?, number = ?, state = ?, labels_json = ?, html_url = ?,
+            assignee_login = ?, assignee_avatar_url = ?,
+            github_created_at = ?, github_updated_at = ?, cached_at = ?
+        WHERE project_id = ? AND repo_full_name IS ? AND number = ?
+        "#,
+    )
+    .bind(created.id)
+    .bind(created.number)
+    .bind(&created.state)
+    .bind(&labels_json)
+    .bind(&created.html_url)
+    .bind(created.assignee.as_ref().map(|a| &a.login))
+    .bind(created.assignee.as_ref().map(|a| &a.avatar_url))
+    .bind(created.created_at.to_rfc3339())
+    .bind(created.updated_at.to_rfc3339())
+    .bind(Utc::now().to_rfc3339())
+    .bind(op.project_id)
+    .bind(&op.repo_full_name)
+    .bind(op.issue_number)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to cache issue: {}", e))?;
+
+    // The placeholder number no longer exists; clear the flag on the
+    // promoted row directly rather than via `settle_pending_flag`.
+    sqlx::query(
+        r#"
+        UPDATE cached_issues SET pending_sync = 0
+        WHERE project_id = ? AND repo_full_name IS ? AND number = ?
+        "#,
+    )
+    .bind(op.project_id)
+    .bind(&op.repo_full_name)
+    .bind(created.number)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to update cache: {}", e))?;
+
+    Ok(ReplayStep::Applied)
+}
+
+/// Replay every pending operation for the user, oldest first.
+///
+/// Stops at the first offline error so the queue order is preserved;
+/// GitHub-side rejections park the single operation and move on.
+pub(crate) async fn replay_outbox(
+    app: &AppHandle,
+    state: &State<'_, AppState>,
+    user_id: i64,
+    project_id: Option<i64>,
+) -> Result<OutboxReplayResult, String> {
+    let pool = state.db.pool();
+    let pending: Vec<OutboxOperation> = list_outbox_operations(pool, user_id, project_id)
+        .await?
+        .into_iter()
+        .filter(|op| op.status == outbox_statuses::PENDING)
+        .collect();
+
+    let mut result = OutboxReplayResult::default();
+    if pending.is_empty() {
+        return Ok(result);
+    }
+
+    let client = IssuesClient::new(get_access_token(state).await?);
+    let total = pending.len() as i32;
+
+    for op in &pending {
+        // The operation targets the linked repository it was queued
+        // against, which must still be linked and reachable.
+        let repository = sqlx::query(
+            r#"
+            SELECT r.repo_owner, r.repo_name, r.is_archived
+            FROM project_repositories r
+            JOIN projects p ON p.id = r.project_id
+            WHERE r.project_id = ? AND r.repo_full_name = ? AND p.user_id = ?
+            "#,
+        )
+        .bind(op.project_id)
+        .bind(&op.repo_full_name)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("Failed to fetch repository: {}", e))?;
+
+        let target = repository
+            .filter(|row| !row.get::<bool, _>("is_archived"))
+            .map(|row| {
+                (
+                    row.get::<String, _>("repo_owner"),
+                    row.get::<String, _>("repo_name"),
+                )
+            });
+
+        let step = match target {
+            None => ReplayStep::Parked(
+                outbox_statuses::FAILED,
+                "リポジトリがプロジェクトにリンクされていないか、アーカイブ状態です。".to_string(),
+            ),
+            Some((owner, repo)) => {
+                if op.operation == outbox_operations::CREATE_ISSUE {
+                    replay_create_issue(app, state.inner(), &client, op, &owner, &repo).await?
+                } else {
+                    replay_update_status(app, state.inner(), &client, op, &owner, &repo).await?
+                }
+            }
+        };
+
+        match step {
+            ReplayStep::Applied => {
+                sqlx::query("DELETE FROM issue_outbox WHERE id = ?")
+                    .bind(op.id)
+                    .execute(pool)
+                    .await
+                    .map_err(|e| format!("Failed to delete outbox operation: {}", e))?;
+                if op.operation == outbox_operations::UPDATE_STATUS {
+                    settle_pending_flag(
+                        pool,
+                        op.project_id,
+                        op.repo_full_name.as_deref(),
+                        op.issue_number,
+                    )
+                    .await?;
+                }
+                result.applied += 1;
+            }
+            ReplayStep::Parked(status, message) => {
+                park_operation(pool, op.id, status, &message).await?;
+                if status == outbox_statuses::CONFLICT {
+                    result.conflicts += 1;
+                } else {
+                    result.failed += 1;
+                }
+            }
+            ReplayStep::Offline => break,
+        }
+    }
+
+    result.remaining = total - result.applied - result.failed - result.conflicts;
+    Ok(result)
+}
+
+/// List queued issue operations (pending, failed and conflicting) so the
+/// UI can show what is waiting to be pushed to GitHub.
+#[tauri::command]
+pub async fn get_issue_outbox(
+    state: State<'_, AppState>,
+    project_id: Option<i64>,
+) -> Result<Vec<OutboxOperation>, String> {
+    let user_id = get_current_user_id(&state).await?;
+    list_outbox_operations(state.db.pool(), user_id, project_id).await
+}
+
+/// Push queued operations to GitHub. Called by the frontend when the
+/// network comes back online.
+#[tauri::command]
+pub async fn replay_issue_outbox(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    project_id: Option<i64>,
+) -> Result<OutboxReplayResult, String> {
+    let user_id = get_current_user_id(&state).await?;
+    replay_outbox(&app, &state, user_id, project_id).await
+}
+
+/// Move a failed / conflicting operation back to pending. With
+/// `force = true` a conflict is resolved in favour of the local change.
+#[tauri::command]
+pub async fn retry_outbox_operation(
+    state: State<'_, AppState>,
+    operation_id: i64,
+    force: bool,
+) -> Result<OutboxOperation, String> {
+    let user_id = get_current_user_id(&state).await?;
+    requeue_operation(state.db.pool(), user_id, operation_id, force).await
+}
+
+/// Drop a queued operation and roll back its optimistic cache change.
+#[tauri::command]
+pub async fn discard_outbox_operation(
+    state: State<'_, AppState>,
+    operation_id: i64,
+) -> Result<(), String> {
+    let user_id = get_current_user_id(&state).await?;
+    discard_operation(state.db.pool(), user_id, operation_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::connection::Database;
+    use crate::database::test_support::{seed_project, seed_user};
+    use chrono::TimeZone;
+
+    /// Seed a user + project + one synced issue (#1, backlog). Returns
+    /// `(user_id, project_id)`.
+    async fn seed(pool: &sqlx::Pool<sqlx::Sqlite>) -> (i64, i64) {
+        let user_id = seed_user(pool).await;
+        let project_id = seed_project(pool, user_id, "octo", "demo").await;
+
+        sqlx::query(
+            r#"
+            INSERT INTO cached_issues (
+                project_id, repo_full_name, github_issue_id, number, title, state, status,
+                github_updated_at, cached_at
+            )
+            VALUES (?, 'octo/demo', 5001, 1, 'Existing', 'open', 'backlog',
+                    '2026-04-01T00:00:00+00:00', '2026-04-01T00:00:00Z')
+            "#,
+        )
+        .bind(project_id)
+        .execute(pool)
+        .await
+        .expect("seed issue");
+
+        (user_id, project_id)
+    }
+
+    async fn issue_row(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+        project_id: i64,
+        number: i32,
+    ) -> Option<(String, String, bool)> {
+        sqlx::query(
+            "SELECT status, state, pending_sync FROM cached_issues WHERE project_id = ? AND number = ?",
+        )
+        .bind(project_id)
+        .bind(number)
+        .fetch_optional(pool)
+        .await
+        .expect("issue row")
+        .map(|row| (row.get("status"), row.get("state"), row.get("pending_sync")))
+    }
+
+    #[test]
+    fn remote_newer_only_when_strictly_after_base() {
+        let remote = Utc.with_ymd_and_hms(2026, 4, 2, 0, 0, 0).unwrap();
+        assert!(is_remote_newer(Some("2026-04-01T00:00:00+00:00"), remote));
+        assert!(!is_remote_newer(Some("2026-04-02T00:00:00+00:00"), remote));
+        // Forced retries / never-synced rows skip the check entirely.
+        assert!(!is_remote_newer(None, remote));
+        assert!(!is_remote_newer(Some("not a date"), remote));
+    }
+
+    // A queued status change must be visible on the board immediately and
+    // survive as a single coalesced operation across repeated moves.
+    #[tokio::test]
+    async fn enqueue_status_change_applies_optimistically_and_coalesces() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (user_id, project_id) = seed(pool).await;
+
+        enqueue_status_change(
+            pool,
+            user_id,
+            project_id,
+            "octo/demo",
+            1,
+            IssueStatus::InProgress,
+        )
+        .await
+        .expect("enqueue 1");
+        enqueue_status_change(pool, user_id, project_id, "octo/demo", 1, IssueStatus::Done)
+            .await
+            .expect("enqueue 2");
+
+        let (status, state, pending) = issue_row(pool, project_id, 1).await.expect("row");
+        assert_eq!(status, "done");
+        assert_eq!(state, "closed");
+        assert!(pending);
+
+        let ops = list_outbox_operations(pool, user_id, Some(project_id))
+            .await
+            .expect("list");
+        assert_eq!(ops.len(), 1);
+        let payload = ops[0].update_status_payload().expect("payload");
+        assert_eq!(payload.new_status, "done");
+        // The rollback target is still the last synced state, not the
+        // intermediate in-progress move.
+        assert_eq!(payload.previous_status, "backlog");
+        assert_eq!(payload.previous_state, "open");
+        assert_eq!(
+            ops[0].base_updated_at.as_deref(),
+            Some("2026-04-01T00:00:00+00:00")
+        );
+        assert!(has_pending_operation(pool, project_id, "octo/demo", 1)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn discard_status_change_restores_previous_values() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (user_id, project_id) = seed(pool).await;
+
+        enqueue_status_change(
+            pool,
+            user_id,
+            project_id,
+            "octo/demo",
+            1,
+            IssueStatus::Cancelled,
+        )
+        .await
+        .expect("enqueue");
+        let op_id = list_outbox_operations(pool, user_id, None).await.unwrap()[0].id;
+
+        discard_operation(pool, user_id, op_id)
+            .await
+            .expect("discard");
+
+        let (status, state, pending) = issue_row(pool, project_id, 1).await.expect("row");
+        assert_eq!(status, "backlog");
+        assert_eq!(state, "open");
+        assert!(!pending);
+        assert!(list_outbox_operations(pool, user_id, None)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    // Queued creates get a negative placeholder row; moving that card
+    // rewrites the create payload rather than queueing a status change
+    // against an issue number GitHub has never seen.
+    #[tokio::test]
+    async fn queued_create_uses_placeholder_and_absorbs_status_moves() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (user_id, project_id) = seed(pool).await;
+
+        let payload = CreateIssuePayload {
+            title: "Offline idea".into(),
+            body: None,
+            status: "backlog".into(),
+            priority: Some("high".into()),
+            labels: vec![],
+            assignees: vec![],
+        };
+        let number = enqueue_issue_creation(pool, user_id, project_id, "octo/demo", &payload)
+            .await
+            .expect("enqueue create");
+        assert!(number < 0);
+
+        enqueue_status_change(
+            pool,
+            user_id,
+            project_id,
+            "octo/demo",
+            number,
+            IssueStatus::Todo,
+        )
+        .await
+        .expect("move placeholder");
+
+        let ops = list_outbox_operations(pool, user_id, None).await.unwrap();
+        assert_eq!(ops.len(), 1);
+        assert_eq!(ops[0].operation, outbox_operations::CREATE_ISSUE);
+        assert_eq!(ops[0].repo_full_name.as_deref(), Some("octo/demo"));
+        assert_eq!(ops[0].issue_number, number);
+        assert_eq!(ops[0].create_issue_payload().unwrap().status, "todo");
+
+        let (status, _, pending) = issue_row(pool, project_id, number).await.expect("row");
+        assert_eq!(status, "todo");
+        assert!(pending);
+
+        discard_operation(pool, user_id, ops[0].id)
+            .await
+            .expect("discard");
+        assert!(issue_row(pool, project_id, number).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn forced_requeue_clears_conflict_baseline() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (user_id, project_id) = seed(pool).await;
+
+        enqueue_status_change(pool, user_id, project_id, "octo/demo", 1, IssueStatus::Todo)
+            .await
+            .expect("enqueue");
+        let op_id = list_outbox_operations(pool, user_id, None).await.unwrap()[0].id;
+        park_operation(pool, op_id, outbox_statuses::CONFLICT, "remote edit")
+            .await
+            .expect("park");
+
+        // A pending-only check must not count the parked operation.
+        assert!(!has_pending_operation(pool, project_id, "octo/demo", 1)
+            .await
+            .unwrap());
+
+        let op = requeue_operation(pool, user_id, op_id, true)
+            .await
+            .expect("requeue");
+        assert_eq!(op.status, outbox_statuses::PENDING);
+        assert!(op.base_updated_at.is_none());
+        assert!(op.last_error.is_none());
+        assert_eq!(op.attempts, 1);
+    }
+}
@@ -0,0 +1,416 @@
+//! Local full-text search over cached issues
+//!
+//! `search_issues_local` queries the `cached_issues_fts` FTS5 index
+//! (migration v20) instead of GitHub, so it answers instantly, spans every
+//! project of the user and keeps working offline. Free-text terms go
+//! through FTS5 `MATCH` (bm25 ranking, highlighted title, body snippet);
+//! `status:` / `priority:` / `label:` / `assignee:` / `repo:` filters are
+//! plain SQL predicates on the joined rows.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   └─ src-tauri/src/commands/mod.rs
+//! Dependencies:
+//!   ├─ src-tauri/src/commands/issues.rs   (get_current_user_id)
+//!   ├─ src-tauri/src/database/migrations.rs (v20: cached_issues_fts)
+//!   └─ src-tauri/src/database/models/issue_search.rs
+
+use sqlx::{FromRow, Row};
+use tauri::State;
+
+use crate::commands::issues::get_current_user_id;
+use crate::commands::AppState;
+use crate::database::models::issue_search::{IssueSearchQuery, IssueSearchResult, HIGHLIGHT_START};
+use crate::database::models::project::CachedIssue;
+
+/// Default number of hits returned when the caller doesn't pass `limit`.
+const DEFAULT_SEARCH_LIMIT: i64 = 50;
+/// Hard cap on `limit`.
+const MAX_SEARCH_LIMIT: i64 = 200;
+/// The trigram tokenizer can't match anything shorter than this; such
+/// terms fall back to a LIKE scan.
+const MIN_FTS_TERM_CHARS: usize = 3;
+
+/// Build the FTS5 `MATCH` expression for the terms long enough for the
+/// trigram index. Each term is quoted as a phrase (so `-`, `:` or `*`
+/// typed by the user are never parsed as FTS5 syntax) and the phrases are
+/// implicitly AND-ed. `None` when no term qualifies.
+pub(crate) fn fts_match_expression(terms: &[String]) -> Option<String> {
+    let phrases: Vec<String> = terms
+        .iter()
+        .filter(|t| t.chars().count() >= MIN_FTS_TERM_CHARS)
+        .map(|t| format!("\"{}\"", t.replace('"', "\"\"")))
+        .collect();
+    if phrases.is_empty() {
+        None
+    } else {
+        Some(phrases.join(" "))
+    }
+}
+
+/// `%term%` LIKE pattern with `\` as the escape character.
+fn like_pattern(term: &str) -> String {
+    let escaped = term
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+/// Run a parsed search against the user's cached issues.
+pub(crate) async fn search_cached_issues(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    user_id: i64,
+    query: &IssueSearchQuery,
+    limit: i64,
+) -> Result<Vec<IssueSearchResult>, String> {
+    let match_expression = fts_match_expression(&query.terms);
+
+    // The highlight markers are spelled as char(2) / char(3) so they match
+    // HIGHLIGHT_START / HIGHLIGHT_END without binding extra parameters.
+    let mut sql = String::from(
+        r#"
+        SELECT ci.id, ci.project_id, ci.github_issue_id, ci.number, ci.title, ci.body,
+               ci.state, ci.status, ci.priority, ci.assignee_login, ci.assignee_avatar_url,
+               ci.labels_json, ci.html_url, ci.github_created_at, ci.github_updated_at,
+               ci.cached_at, ci.is_archived, ci.archived_at, ci.pending_sync,
+               ci.assignees_json, ci.milestone_number, ci.milestone_title, ci.state_reason,
+               ci.linked_prs_json,
+               p.name AS project_name,
+               COALESCE(ci.repo_full_name, p.repo_full_name) AS repo_full_name,
+        "#,
+    );
+    if match_expression.is_some() {
+        sql.push_str(
+            r#"
+               highlight(cached_issues_fts, 0, char(2), char(3)) AS title_highlight,
+               snippet(cached_issues_fts, 1, char(2), char(3), '…', 16) AS body_snippet,
+               bm25(cached_issues_fts, 10.0, 1.0, 5.0, 5.0) AS search_rank
+        FROM cached_issues_fts
+        JOIN cached_issues ci ON ci.id = cached_issues_fts.rowid
+        JOIN projects p ON p.id = ci.project_id
+        WHERE cached_issues_fts MATCH ? AND p.user_id = ?
+            "#,
+        );
+    } else {
+        sql.push_str(
+            r#"
+               ci.title AS title_highlight,
+               NULL AS body_snippet,
+               0.0 AS search_rank
+        FROM cached_issues ci
+        JOIN projects p ON p.id = ci.project_id
+        WHERE p.user_id = ?
+            "#,
+        );
+    }
+
+    // Every remaining placeholder binds a string, in push order.
+    let mut binds: Vec<String> = Vec::new();
+
+    for term in query
+        .terms
+        .iter()
+        .filter(|t| t.chars().count() < MIN_FTS_TERM_CHARS)
+    {
+        sql.push_str(
+            r#"
+            AND (ci.title LIKE ? ESCAPE '\' OR ci.body LIKE ? ESCAPE '\'
+                 OR ci.labels_json LIKE ? ESCAPE '\' OR ci.assignee_login LIKE ? ESCAPE '\')
+            "#,
+        );
+        let pattern = like_pattern(term);
+        binds.extend([pattern.clone(), pattern.clone(), pattern.clone(), pattern]);
+    }
+    if let Some(status) = &query.status {
+        sql.push_str(" AND ci.status = ?");
+        binds.push(status.clone());
+    }
+    if let Some(priority) = &query.priority {
+        sql.push_str(" AND ci.priority = ?");
+        binds.push(priority.clone());
+    }
+    for label in &query.labels {
+        sql.push_str(
+            " AND EXISTS (SELECT 1 FROM json_each(ci.labels_json) WHERE lower(json_each.value) = lower(?))",
+        );
+        binds.push(label.clone());
+    }
+    if let Some(assignee) = &query.assignee {
+        sql.push_str(" AND lower(ci.assignee_login) = lower(?)");
+        binds.push(assignee.clone());
+    }
+    if let Some(repo) = &query.repo {
+        // Issues of multi-repo projects carry their own repository.
+        sql.push_str(
+            r#"
+            AND (lower(COALESCE(ci.repo_full_name, p.repo_full_name)) = lower(?)
+                 OR lower(substr(COALESCE(ci.repo_full_name, p.repo_full_name),
+                                 instr(COALESCE(ci.repo_full_name, p.repo_full_name), '/') + 1))
+                    = lower(?))
+            "#,
+        );
+        binds.push(repo.clone());
+        binds.push(repo.clone());
+    }
+
+    if match_expression.is_some() {
+        sql.push_str(" ORDER BY search_rank, ci.github_updated_at DESC");
+    } else {
+        sql.push_str(" ORDER BY ci.github_updated_at DESC, ci.id DESC");
+    }
+    sql.push_str(" LIMIT ?");
+
+    // Built from fixed fragments only; user input is always bound.
+    let mut statement = sqlx::query(sqlx::AssertSqlSafe(sql));
+    if let Some(expression) = &match_expression {
+        statement = statement.bind(expression);
+    }
+    statement = statement.bind(user_id);
+    for value in &binds {
+        statement = statement.bind(value);
+    }
+    let rows = statement
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("Failed to search issues: {}", e))?;
+
+    rows.iter()
+        .map(|row| {
+            let issue =
+                CachedIssue::from_row(row).map_err(|e| format!("Failed to read issue: {}", e))?;
+            // snippet() returns the head of the column even when only the
+            // title matched; only keep excerpts that contain a hit.
+            let body_snippet = row
+                .get::<Option<String>, _>("body_snippet")
+                .filter(|s| s.contains(HIGHLIGHT_START));
+            Ok(IssueSearchResult {
+                issue,
+                project_name: row.get("project_name"),
+                repo_full_name: row.get("repo_full_name"),
+                title_highlight: row.get("title_highlight"),
+                body_snippet,
+                rank: row.get("search_rank"),
+            })
+        })
+        .collect()
+}
+
+/// Search cached issues across all of the user's projects.
+///
+/// `query` accepts free text plus `status:`, `priority:`, `label:`,
+/// `assignee:` and `repo:` filters. Reads only the local cache, so it
+/// works offline; results are as fresh as the last sync.
+#[tauri::command]
+pub async fn search_issues_local(
+    state: State<'_, AppState>,
+    query: String,
+    limit: Option<i64>,
+) -> Result<Vec<IssueSearchResult>, String> {
+    let user_id = get_current_user_id(&state).await?;
+
+    let parsed = IssueSearchQuery::parse(&query);
+    if parsed.is_empty() {
+        return Ok(Vec::new());
+    }
+    let limit = limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_SEARCH_LIMIT);
+
+    search_cached_issues(state.db.pool(), user_id, &parsed, limit).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::connection::Database;
+    use crate::database::models::issue_search::HIGHLIGHT_END;
+    use crate::database::test_support::seed_user;
+
+    /// Seed a user with two projects and a handful of issues. Returns the
+    /// user id.
+    async fn seed(pool: &sqlx::Pool<sqlx::Sqlite>) -> i64 {
+        let user_id = seed_user(pool).await;
+
+        for (github_repo_id, name) in [(1000, "app"), (2000, "docs")] {
+            let project_id: i64 = sqlx::query(
+                r#"
+                INSERT INTO projects (user_id, name, github_repo_id, repo_owner, repo_name, repo_full_name)
+                VALUES (?, ?, ?, 'octo', ?, 'octo/' || ?)
+                RETURNING id
+                "#,
+            )
+            .bind(user_id)
+            .bind(name)
+            .bind(github_repo_id)
+            .bind(name)
+            .bind(name)
+            .fetch_one(pool)
+            .await
+            .expect("seed project")
+            .get("id");
+
+            let issues = [
+                (
+                    1,
+                    "Login fails on Safari",
+                    "OAuth redirect loop",
+                    "in-progress",
+                    Some("high"),
+                    r#"["bug","status:in-progress"]"#,
+                    Some("alice"),
+                ),
+                (
+                    2,
+                    "ダークモード対応",
+                    "設定画面にテーマ切り替えを追加する",
+                    "todo",
+                    None,
+                    r#"["enhancement"]"#,
+                    None,
+                ),
+                (
+                    3,
+                    "Update README",
+                    "Mention the login flow",
+                    "done",
+                    Some("low"),
+                    r#"["docs"]"#,
+                    Some("bob"),
+                ),
+            ];
+            for (number, title, body, status, priority, labels, assignee) in issues {
+                sqlx::query(
+                    r#"
+                    INSERT INTO cached_issues (
+                        project_id, github_issue_id, number, title, body, state, status,
+                        priority, labels_json, assignee_login, github_updated_at
+                    )
+                    VALUES (?, ?, ?, ?, ?, 'open', ?, ?, ?, ?, ?)
+                    "#,
+                )
+                .bind(project_id)
+                .bind(github_repo_id + number as i64)
+                .bind(number)
+                .bind(title)
+                .bind(body)
+                .bind(status)
+                .bind(priority)
+                .bind(labels)
+                .bind(assignee)
+                .bind(format!("2026-05-0{}T00:00:00Z", number))
+                .execute(pool)
+                .await
+                .expect("seed issue");
+            }
+        }
+
+        user_id
+    }
+
+    async fn search(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+        user_id: i64,
+        q: &str,
+    ) -> Vec<IssueSearchResult> {
+        search_cached_issues(pool, user_id, &IssueSearchQuery::parse(q), 50)
+            .await
+            .expect("search")
+    }
+
+    #[test]
+    fn fts_match_expression_quotes_terms_and_skips_short_ones() {
+        let terms = vec![
+            "login".to_string(),
+            "ab".to_string(),
+            r#"say "hi""#.to_string(),
+        ];
+        assert_eq!(
+            fts_match_expression(&terms).as_deref(),
+            Some(r#""login" "say ""hi""""#)
+        );
+        assert_eq!(fts_match_expression(&["ab".to_string()]), None);
+    }
+
+    #[tokio::test]
+    async fn search_ranks_title_matches_and_highlights() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let user_id = seed(pool).await;
+
+        let hits = search(pool, user_id, "login repo:octo/app").await;
+        assert_eq!(hits.len(), 2);
+        // Title match outranks the body-only match.
+        assert_eq!(hits[0].issue.number, 1);
+        assert_eq!(
+            hits[0].title_highlight,
+            format!("{}Login{} fails on Safari", HIGHLIGHT_START, HIGHLIGHT_END)
+        );
+        assert!(hits[0].body_snippet.is_none());
+        assert_eq!(hits[1].issue.number, 3);
+        assert!(hits[1]
+            .body_snippet
+            .as_deref()
+            .unwrap()
+            .contains(HIGHLIGHT_START));
+        assert_eq!(hits[1].repo_full_name.as_deref(), Some("octo/app"));
+    }
+
+    #[tokio::test]
+    async fn search_matches_japanese_substrings() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let user_id = seed(pool).await;
+
+        let hits = search(pool, user_id, "テーマ切り").await;
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|h| h.issue.number == 2));
+        let hits = search(pool, user_id, "ダーク repo:docs").await;
+        assert_eq!(hits.len(), 1);
+
+        // Two characters: below the trigram minimum, served by LIKE.
+        let hits = search(pool, user_id, "設定 repo:docs").await;
+        assert_eq!(hits.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn search_applies_filters_without_terms() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let user_id = seed(pool).await;
+
+        let hits = search(pool, user_id, "status:in_progress priority:high").await;
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|h| h.issue.number == 1 && h.rank == 0.0));
+
+        let hits = search(pool, user_id, "label:BUG assignee:@Alice repo:app").await;
+        assert_eq!(hits.len(), 1);
+
+        let hits = search(pool, user_id, "label:docs label:bug").await;
+        assert!(hits.is_empty());
+    }
+
+    #[tokio::test]
+    async fn search_index_follows_updates_and_deletes() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let user_id = seed(pool).await;
+
+        sqlx::query("UPDATE cached_issues SET title = 'Safari crash' WHERE number = 1")
+            .execute(pool)
+            .await
+            .expect("update");
+        assert!(search(pool, user_id, "login status:in-progress")
+            .await
+            .is_empty());
+        assert_eq!(search(pool, user_id, "crash").await.len(), 2);
+
+        sqlx::query("DELETE FROM projects WHERE repo_name = 'docs'")
+            .execute(pool)
+            .await
+            .expect("delete project");
+        assert_eq!(search(pool, user_id, "crash").await.len(), 1);
+    }
+}
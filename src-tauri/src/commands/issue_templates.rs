@@ -0,0 +1,732 @@
+//! Issue template commands
+//!
+//! `get_issue_templates` reads `.github/ISSUE_TEMPLATE/` of a linked
+//! repository: Markdown templates (`*.md`, YAML front-matter with `name`,
+//! `about`, `title`, `labels`, `assignees`) and issue forms (`*.yml` /
+//! `*.yaml` with a `body` of `markdown` / `input` / `textarea` / `dropdown`
+//! / `checkboxes` elements). `config.yml` only contributes
+//! `blank_issues_enabled`. A file that fails to fetch or parse is reported in
+//! `IssueTemplateSet::invalid` instead of failing the whole call.
+//!
+//! `build_issue_from_template` validates the values entered into a form
+//! and renders the issue body the way GitHub does (`### <label>` sections,
+//! `_No response_` for empty answers, `- [X]` task lists for checkboxes),
+//! so `create_github_issue` can post it with the template's labels and
+//! assignees.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   └─ src-tauri/src/commands/mod.rs
+//! Dependencies:
+//!   ├─ src-tauri/src/auth/session.rs      (map_github_result)
+//!   ├─ src-tauri/src/commands/issues.rs   (get_project, get_access_token,
+//!   │                                      resolve_project_repository)
+//!   ├─ src-tauri/src/database/models/issue_template.rs
+//!   └─ src-tauri/src/github/issues.rs     (IssuesClient)
+
+use std::collections::HashSet;
+
+use serde::Deserialize;
+use tauri::{AppHandle, State};
+
+use crate::auth::map_github_result;
+use crate::commands::issues::{get_access_token, get_project, resolve_project_repository};
+use crate::commands::AppState;
+use crate::database::models::issue_template::{
+    issue_form_field_types, issue_template_kinds, IssueDraft, IssueFormCheckbox, IssueFormField,
+    IssueFormValues, IssueTemplate, IssueTemplateSet,
+};
+use crate::github::client::GitHubError;
+use crate::github::issues::IssuesClient;
+
+/// Directory GitHub reads templates from.
+const TEMPLATE_DIR: &str = ".github/ISSUE_TEMPLATE";
+
+/// What GitHub writes for an unanswered form element.
+const NO_RESPONSE: &str = "_No response_";
+
+/// `labels` / `assignees` may be a list or a comma-separated string.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum StringOrList {
+    List(Vec<String>),
+    Single(String),
+}
+
+impl StringOrList {
+    fn into_vec(self) -> Vec<String> {
+        let items = match self {
+            StringOrList::List(items) => items,
+            StringOrList::Single(s) => s.split(',').map(str::to_string).collect(),
+        };
+        items
+            .into_iter()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+}
+
+fn string_list(value: Option<StringOrList>) -> Vec<String> {
+    value.map(StringOrList::into_vec).unwrap_or_default()
+}
+
+/// Front-matter of a Markdown template.
+#[derive(Debug, Deserialize)]
+struct MarkdownFrontMatter {
+    name: Option<String>,
+    about: Option<String>,
+    title: Option<String>,
+    labels: Option<StringOrList>,
+    assignees: Option<StringOrList>,
+}
+
+/// Top level of an issue form.
+#[derive(Debug, Deserialize)]
+struct IssueFormFile {
+    name: Option<String>,
+    description: Option<String>,
+    title: Option<String>,
+    labels: Option<StringOrList>,
+    assignees: Option<StringOrList>,
+    #[serde(default)]
+    body: Vec<IssueFormElement>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueFormElement {
+    #[serde(rename = "type")]
+    element_type: String,
+    id: Option<String>,
+    #[serde(default)]
+    attributes: IssueFormAttributes,
+    #[serde(default)]
+    validations: IssueFormValidations,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct IssueFormAttributes {
+    label: Option<String>,
+    description: Option<String>,
+    placeholder: Option<String>,
+    value: Option<String>,
+    /// Strings for `dropdown`, `{ label, required }` for `checkboxes`.
+    #[serde(default)]
+    options: Vec<IssueFormOption>,
+    #[serde(default)]
+    multiple: bool,
+    default: Option<usize>,
+    render: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum IssueFormOption {
+    Checkbox {
+        label: String,
+        #[serde(default)]
+        required: bool,
+    },
+    Plain(String),
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct IssueFormValidations {
+    #[serde(default)]
+    required: bool,
+}
+
+/// `config.yml` (only the part the create modal uses).
+#[derive(Debug, Deserialize)]
+struct TemplateChooserConfig {
+    #[serde(default = "default_true")]
+    blank_issues_enabled: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Split `---`-delimited YAML front-matter from the Markdown after it.
+fn split_front_matter(content: &str) -> Option<(&str, &str)> {
+    let content = content.trim_start_matches('\u{feff}');
+    let rest = content
+        .strip_prefix("---\r\n")
+        .or_else(|| content.strip_prefix("---\n"))?;
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            let front = &rest[..offset];
+            let body = &rest[offset + line.len()..];
+            return Some((front, body));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Parse a Markdown template (`*.md`).
+pub(crate) fn parse_markdown_template(
+    file_name: &str,
+    content: &str,
+) -> Result<IssueTemplate, String> {
+    let (front, body) =
+        split_front_matter(content).ok_or_else(|| "front-matter がありません".to_string())?;
+    let meta: MarkdownFrontMatter = serde_yaml_ng::from_str(front)
+        .map_err(|e| format!("front-matter を解析できません: {}", e))?;
+    let name = meta
+        .name
+        .filter(|n| !n.trim().is_empty())
+        .ok_or_else(|| "name がありません".to_string())?;
+
+    Ok(IssueTemplate {
+        file_name: file_name.to_string(),
+        kind: issue_template_kinds::MARKDOWN.to_string(),
+        name,
+        description: meta.about,
+        title: meta.title.filter(|t| !t.is_empty()),
+        labels: string_list(meta.labels),
+        assignees: string_list(meta.assignees),
+        body: Some(body.trim_start_matches(['\r', '\n']).to_string()),
+        fields: Vec::new(),
+    })
+}
+
+/// Parse an issue form (`*.yml` / `*.yaml`).
+pub(crate) fn parse_issue_form(file_name: &str, content: &str) -> Result<IssueTemplate, String> {
+    let form: IssueFormFile =
+        serde_yaml_ng::from_str(content).map_err(|e| format!("YAML を解析できません: {}", e))?;
+    let name = form
+        .name
+        .filter(|n| !n.trim().is_empty())
+        .ok_or_else(|| "name がありません".to_string())?;
+    if form.body.is_empty() {
+        return Err("body がありません".to_string());
+    }
+
+    let mut seen_ids = HashSet::new();
+    let mut fields = Vec::with_capacity(form.body.len());
+    for (index, element) in form.body.into_iter().enumerate() {
+        fields.push(parse_form_element(index, element, &mut seen_ids)?);
+    }
+    if fields
+        .iter()
+        .all(|f| f.field_type == issue_form_field_types::MARKDOWN)
+    {
+        return Err("入力項目がありません".to_string());
+    }
+
+    Ok(IssueTemplate {
+        file_name: file_name.to_string(),
+        kind: issue_template_kinds::FORM.to_string(),
+        name,
+        description: form.description,
+        title: form.title.filter(|t| !t.is_empty()),
+        labels: string_list(form.labels),
+        assignees: string_list(form.assignees),
+        body: None,
+        fields,
+    })
+}
+
+fn parse_form_element(
+    index: usize,
+    element: IssueFormElement,
+    seen_ids: &mut HashSet<String>,
+) -> Result<IssueFormField, String> {
+    let position = index + 1;
+    let field_type = element.element_type.as_str();
+    let attributes = element.attributes;
+
+    let known = [
+        issue_form_field_types::MARKDOWN,
+        issue_form_field_types::TEXTAREA,
+        issue_form_field_types::INPUT,
+        issue_form_field_types::DROPDOWN,
+        issue_form_field_types::CHECKBOXES,
+    ];
+    if !known.contains(&field_type) {
+        return Err(format!(
+            "body[{}]: 未対応の type です: {}",
+            position, field_type
+        ));
+    }
+
+    if let Some(id) = &element.id {
+        if !seen_ids.insert(id.clone()) {
+            return Err(format!("body[{}]: id が重複しています: {}", position, id));
+        }
+    }
+    let key = element
+        .id
+        .clone()
+        .unwrap_or_else(|| format!("field-{}", index));
+
+    let is_markdown = field_type == issue_form_field_types::MARKDOWN;
+    if is_markdown && attributes.value.is_none() {
+        return Err(format!(
+            "body[{}]: markdown には value が必要です",
+            position
+        ));
+    }
+    if !is_markdown && attributes.label.as_deref().unwrap_or("").trim().is_empty() {
+        return Err(format!("body[{}]: label がありません", position));
+    }
+
+    let mut options = Vec::new();
+    let mut checkboxes = Vec::new();
+    for option in attributes.options {
+        match (field_type, option) {
+            (issue_form_field_types::DROPDOWN, IssueFormOption::Plain(label)) => {
+                options.push(label)
+            }
+            (issue_form_field_types::CHECKBOXES, IssueFormOption::Checkbox { label, required }) => {
+                checkboxes.push(IssueFormCheckbox { label, required })
+            }
+            (issue_form_field_types::CHECKBOXES, IssueFormOption::Plain(label)) => {
+                checkboxes.push(IssueFormCheckbox {
+                    label,
+                    required: false,
+                })
+            }
+            _ => return Err(format!("body[{}]: options の形式が不正です", position)),
+        }
+    }
+    if field_type == issue_form_field_types::DROPDOWN && options.is_empty() {
+        return Err(format!(
+            "body[{}]: dropdown には options が必要です",
+            position
+        ));
+    }
+    if field_type == issue_form_field_types::CHECKBOXES && checkboxes.is_empty() {
+        return Err(format!(
+            "body[{}]: checkboxes には options が必要です",
+            position
+        ));
+    }
+    if let Some(default) = attributes.default {
+        if default >= options.len() {
+            return Err(format!(
+                "body[{}]: default が options の範囲外です",
+                position
+            ));
+        }
+    }
+
+    Ok(IssueFormField {
+        key,
+        field_type: field_type.to_string(),
+        label: attributes.label,
+        description: attributes.description,
+        placeholder: attributes.placeholder,
+        value: attributes.value,
+        options,
+        checkboxes,
+        multiple: attributes.multiple,
+        default_option: attributes.default,
+        render: attributes.render.filter(|r| !r.is_empty()),
+        required: !is_markdown && element.validations.required,
+    })
+}
+
+/// `blank_issues_enabled` of `config.yml` (true when unreadable, as on
+/// GitHub).
+pub(crate) fn parse_chooser_config(content: &str) -> bool {
+    serde_yaml_ng::from_str::<TemplateChooserConfig>(content)
+        .map(|c| c.blank_issues_enabled)
+        .unwrap_or(true)
+}
+
+/// Values of one field, with blank entries dropped.
+fn field_values<'a>(values: &'a IssueFormValues, key: &str) -> Vec<&'a str> {
+    values
+        .get(key)
+        .map(|v| {
+            v.iter()
+                .map(|s| s.as_str())
+                .filter(|s| !s.trim().is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Validate form values and render the body GitHub would produce.
+pub(crate) fn render_issue_form(
+    template: &IssueTemplate,
+    values: &IssueFormValues,
+) -> Result<String, String> {
+    let mut errors = Vec::new();
+    let mut sections = Vec::new();
+
+    for field in &template.fields {
+        let label = field.label.as_deref().unwrap_or_default();
+        let answer = match field.field_type.as_str() {
+            issue_form_field_types::MARKDOWN => continue,
+            issue_form_field_types::INPUT | issue_form_field_types::TEXTAREA => {
+                let text = field_values(values, &field.key).join("\n");
+                let text = text.trim();
+                if text.is_empty() {
+                    if field.required {
+                        errors.push(format!("「{}」は必須です", label));
+                    }
+                    NO_RESPONSE.to_string()
+                } else if let Some(lang) = &field.render {
+                    format!("```{}\n{}\n```", lang, text)
+                } else {
+                    text.to_string()
+                }
+            }
+            issue_form_field_types::DROPDOWN => {
+                let selected = field_values(values, &field.key);
+                if let Some(unknown) = selected
+                    .iter()
+                    .find(|s| !field.options.iter().any(|o| o == *s))
+                {
+                    errors.push(format!("「{}」に存在しない選択肢です: {}", label, unknown));
+                }
+                if !field.multiple && selected.len() > 1 {
+                    errors.push(format!("「{}」は 1 つだけ選択できます", label));
+                }
+                if selected.is_empty() {
+                    if field.required {
+                        errors.push(format!("「{}」は必須です", label));
+                    }
+                    NO_RESPONSE.to_string()
+                } else {
+                    selected.join(", ")
+                }
+            }
+            issue_form_field_types::CHECKBOXES => {
+                let checked = field_values(values, &field.key);
+                field
+                    .checkboxes
+                    .iter()
+                    .map(|option| {
+                        let is_checked = checked.contains(&option.label.as_str());
+                        if option.required && !is_checked {
+                            errors.push(format!("「{}」: {} は必須です", label, option.label));
+                        }
+                        format!(
+                            "- [{}] {}",
+                            if is_checked { "X" } else { " " },
+                            option.label
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            other => {
+                errors.push(format!("未対応の type です: {}", other));
+                continue;
+            }
+        };
+        sections.push(format!("### {}\n\n{}", label, answer));
+    }
+
+    if errors.is_empty() {
+        Ok(sections.join("\n\n"))
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+/// Fetch and parse the issue templates of a project's repository
+/// (`None` = the primary one). A repository without templates returns an
+/// empty set.
+#[tauri::command]
+pub async fn get_issue_templates(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    project_id: i64,
+    repo_full_name: Option<String>,
+) -> Result<IssueTemplateSet, String> {
+    let project = get_project(state.clone(), project_id).await?;
+    let repository =
+        resolve_project_repository(state.db.pool(), &project, repo_full_name.as_deref()).await?;
+    let owner = repository.repo_owner;
+    let repo = repository.repo_name;
+
+    let access_token = get_access_token(&state).await?;
+    let client = IssuesClient::new(access_token);
+
+    let mut set = IssueTemplateSet {
+        blank_issues_enabled: true,
+        ..Default::default()
+    };
+
+    let entries = match client
+        .get_directory_contents(&owner, &repo, TEMPLATE_DIR)
+        .await
+    {
+        Err(GitHubError::NotFound(_)) => return Ok(set),
+        result => map_github_result(&app, state.inner(), result).await?,
+    };
+
+    let files: Vec<_> = entries
+        .into_iter()
+        .filter(|e| e.entry_type == "file")
+        .filter(|e| {
+            let name = e.name.to_lowercase();
+            name.ends_with(".md") || name.ends_with(".yml") || name.ends_with(".yaml")
+        })
+        .collect();
+    let contents = futures::future::join_all(
+        files
+            .iter()
+            .map(|f| client.get_file_contents(&owner, &repo, &f.path)),
+    )
+    .await;
+
+    for (file, content) in files.iter().zip(contents) {
+        // A token problem fails the call (and signs out); any other fetch
+        // failure only loses that one file.
+        let content = match content {
+            Ok(content) => content,
+            Err(GitHubError::Unauthorized) => {
+                return map_github_result(&app, state.inner(), Err(GitHubError::Unauthorized))
+                    .await;
+            }
+            Err(e) => {
+                set.invalid.push(format!("{}: {}", file.name, e));
+                continue;
+            }
+        };
+        let lower = file.name.to_lowercase();
+        if lower == "config.yml" || lower == "config.yaml" {
+            set.blank_issues_enabled = parse_chooser_config(&content);
+            continue;
+        }
+        let parsed = if lower.ends_with(".md") {
+            parse_markdown_template(&file.name, &content)
+        } else {
+            parse_issue_form(&file.name, &content)
+        };
+        match parsed {
+            Ok(template) => set.templates.push(template),
+            Err(e) => set.invalid.push(format!("{}: {}", file.name, e)),
+        }
+    }
+
+    set.templates.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+    Ok(set)
+}
+
+/// Turn a template and the entered values into the issue to create. For
+/// Markdown templates the edited body is passed as the `body` value.
+#[tauri::command]
+pub fn build_issue_from_template(
+    template: IssueTemplate,
+    title: String,
+    values: IssueFormValues,
+) -> Result<IssueDraft, String> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err("タイトルを入力してください".to_string());
+    }
+
+    let body = if template.kind == issue_template_kinds::FORM {
+        render_issue_form(&template, &values)?
+    } else {
+        values
+            .get("body")
+            .map(|v| v.join("\n"))
+            .or(template.body)
+            .unwrap_or_default()
+    };
+
+    Ok(IssueDraft {
+        title: title.to_string(),
+        body,
+        labels: template.labels,
+        assignees: template.assignees,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUG_FORM: &str = r#"
+name: Bug report
+description: File a bug report
+title: "[Bug]: "
+labels: ["bug", "triage"]
+assignees:
+  - octocat
+body:
+  - type: markdown
+    attributes:
+      value: Thanks for taking the time!
+  - type: input
+    id: contact
+    attributes:
+      label: Contact details
+      placeholder: ex. email@example.com
+  - type: textarea
+    id: what-happened
+    attributes:
+      label: What happened?
+    validations:
+      required: true
+  - type: dropdown
+    id: version
+    attributes:
+      label: Version
+      options:
+        - 1.0.2 (Default)
+        - 1.0.3 (Edge)
+      default: 0
+    validations:
+      required: true
+  - type: textarea
+    id: logs
+    attributes:
+      label: Relevant log output
+      render: shell
+  - type: checkboxes
+    id: terms
+    attributes:
+      label: Code of Conduct
+      options:
+        - label: I agree to follow this project's Code of Conduct
+          required: true
+        - label: I searched existing issues
+"#;
+
+    fn values(pairs: &[(&str, &[&str])]) -> IssueFormValues {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.iter().map(|s| s.to_string()).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_markdown_template_front_matter() {
+        let content = "---\nname: Feature request\nabout: Suggest an idea\ntitle: '[FEAT] '\nlabels: enhancement, needs-triage\nassignees: ''\n---\n\n## Summary\n\n";
+        let template = parse_markdown_template("feature.md", content).unwrap();
+        assert_eq!(template.kind, issue_template_kinds::MARKDOWN);
+        assert_eq!(template.name, "Feature request");
+        assert_eq!(template.description.as_deref(), Some("Suggest an idea"));
+        assert_eq!(template.title.as_deref(), Some("[FEAT] "));
+        assert_eq!(template.labels, vec!["enhancement", "needs-triage"]);
+        assert!(template.assignees.is_empty());
+        assert_eq!(template.body.as_deref(), Some("## Summary\n\n"));
+    }
+
+    #[test]
+    fn markdown_template_without_front_matter_is_invalid() {
+        assert!(parse_markdown_template("plain.md", "## Just text").is_err());
+        assert!(parse_markdown_template("noname.md", "---\nabout: x\n---\nbody").is_err());
+    }
+
+    #[test]
+    fn parses_issue_form_fields() {
+        let template = parse_issue_form("bug.yml", BUG_FORM).unwrap();
+        assert_eq!(template.kind, issue_template_kinds::FORM);
+        assert_eq!(template.labels, vec!["bug", "triage"]);
+        assert_eq!(template.assignees, vec!["octocat"]);
+        assert_eq!(template.fields.len(), 6);
+
+        let intro = &template.fields[0];
+        assert_eq!(intro.field_type, issue_form_field_types::MARKDOWN);
+        assert_eq!(intro.key, "field-0");
+        assert!(!intro.required);
+
+        let version = &template.fields[3];
+        assert_eq!(version.options.len(), 2);
+        assert_eq!(version.default_option, Some(0));
+        assert!(version.required);
+
+        let terms = &template.fields[5];
+        assert_eq!(terms.checkboxes.len(), 2);
+        assert!(terms.checkboxes[0].required);
+        assert!(!terms.checkboxes[1].required);
+    }
+
+    #[test]
+    fn rejects_malformed_issue_forms() {
+        // Missing label on an input
+        let no_label = "name: X\nbody:\n  - type: input\n    id: a\n";
+        assert!(parse_issue_form("a.yml", no_label).is_err());
+        // Duplicate ids
+        let dup = "name: X\nbody:\n  - type: input\n    id: a\n    attributes: {label: A}\n  - type: input\n    id: a\n    attributes: {label: B}\n";
+        assert!(parse_issue_form("b.yml", dup).is_err());
+        // Dropdown without options
+        let dropdown = "name: X\nbody:\n  - type: dropdown\n    attributes: {label: A}\n";
+        assert!(parse_issue_form("c.yml", dropdown).is_err());
+        // Unknown element type
+        let unknown = "name: X\nbody:\n  - type: slider\n    attributes: {label: A}\n";
+        assert!(parse_issue_form("d.yml", unknown).is_err());
+    }
+
+    #[test]
+    fn renders_body_like_github() {
+        let template = parse_issue_form("bug.yml", BUG_FORM).unwrap();
+        let body = render_issue_form(
+            &template,
+            &values(&[
+                ("what-happened", &["It crashed"]),
+                ("version", &["1.0.3 (Edge)"]),
+                ("logs", &["panic at main.rs:1"]),
+                (
+                    "terms",
+                    &["I agree to follow this project's Code of Conduct"],
+                ),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            body,
+            "### Contact details\n\n_No response_\n\n\
+             ### What happened?\n\nIt crashed\n\n\
+             ### Version\n\n1.0.3 (Edge)\n\n\
+             ### Relevant log output\n\n```shell\npanic at main.rs:1\n```\n\n\
+             ### Code of Conduct\n\n\
+             - [X] I agree to follow this project's Code of Conduct\n\
+             - [ ] I searched existing issues"
+        );
+    }
+
+    #[test]
+    fn reports_every_missing_required_value() {
+        let template = parse_issue_form("bug.yml", BUG_FORM).unwrap();
+        let err = render_issue_form(&template, &values(&[("version", &["9.9"])])).unwrap_err();
+        assert!(err.contains("What happened?"));
+        assert!(err.contains("存在しない選択肢"));
+        assert!(err.contains("Code of Conduct"));
+    }
+
+    #[test]
+    fn build_uses_edited_markdown_body_and_template_metadata() {
+        let content =
+            "---\nname: Task\nabout: x\nlabels: [chore]\nassignees: [octocat]\n---\nDefault body\n";
+        let template = parse_markdown_template("task.md", content).unwrap();
+
+        let draft = build_issue_from_template(
+            template.clone(),
+            " Update deps ".into(),
+            values(&[("body", &["Edited body"])]),
+        )
+        .unwrap();
+        assert_eq!(draft.title, "Update deps");
+        assert_eq!(draft.body, "Edited body");
+        assert_eq!(draft.labels, vec!["chore"]);
+        assert_eq!(draft.assignees, vec!["octocat"]);
+
+        let untouched =
+            build_issue_from_template(template.clone(), "T".into(), IssueFormValues::new())
+                .unwrap();
+        assert_eq!(untouched.body, "Default body\n");
+
+        assert!(build_issue_from_template(template, "  ".into(), IssueFormValues::new()).is_err());
+    }
+
+    #[test]
+    fn chooser_config_defaults_to_blank_issues_enabled() {
+        assert!(parse_chooser_config("contact_links: []\n"));
+        assert!(!parse_chooser_config("blank_issues_enabled: false\n"));
+        assert!(parse_chooser_config(": not yaml"));
+    }
+}
@@ -11,26 +11,39 @@
 //!   ├─ src-tauri/src/auth/session.rs       (handle_unauthorized, map_github_result, reasons)
 //!   ├─ src-tauri/src/commands/auth.rs      (for auth state)
 //!   ├─ src-tauri/src/commands/github.rs    (CachedResponse envelope reused by *_with_cache)
+//!   ├─ src-tauri/src/commands/issue_outbox.rs (offline queue for status changes / creates)
+//!   ├─ src-tauri/src/commands/milestones.rs (milestone sync after the issue pull)
+//!   ├─ src-tauri/src/commands/workflow_settings.rs (settings for the Actions template)
 //!   ├─ src-tauri/src/database/models/cache.rs (cache_types / cache_durations for Issue #183)
 //!   ├─ src-tauri/src/database/models/project.rs
 //!   ├─ src-tauri/src/database/repository/cache.rs (save_cache / get_any_cache)
 //!   ├─ src-tauri/src/github/client.rs      (GitHubError variants)
 //!   └─ src-tauri/src/github/issues.rs      (IssuesClient + Search API types)
 
-use chrono::Utc;
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
 use tauri::{AppHandle, State};
 
 use crate::auth::{handle_unauthorized, map_github_result, reasons};
 use crate::commands::github::CachedResponse;
+use crate::commands::issue_outbox::{
+    enqueue_issue_creation, enqueue_status_change, has_pending_operation, is_offline_error,
+    replay_outbox,
+};
+use crate::commands::milestones::sync_project_milestones;
+use crate::commands::workflow_settings::load_workflow_settings;
 use crate::commands::AppState;
+use crate::database::models::issue_outbox::CreateIssuePayload;
 use crate::database::models::project::{
-    CachedIssue, IssueStatus, KanbanBoard, Project, ProjectWithStats, RepositoryInfo,
+    CachedIssue, IssuePriority, IssueStatus, KanbanBoard, LinkedPullRequest, Project,
+    ProjectRepository, ProjectSyncCursor, ProjectWithStats, RepositoryInfo,
 };
+use crate::database::models::workflow_settings::WORKFLOW_FILE_PATH;
 use crate::github::client::{GitHubError, GitHubResult};
 use crate::github::issues::{
-    generate_actions_template, GitHubRepository, GitHubSearchItem, IssuesClient,
+    generate_actions_template, GitHubIssue, GitHubRepository, GitHubSearchItem, IssueProbe,
+    IssuesClient, IssuesPageResponse,
 };
 use crate::github::{GitHubClient, PrProgress};
 
@@ -105,6 +118,72 @@ pub async fn get_project(state: State<'_, AppState>, project_id: i64) -> Result<
     .ok_or_else(|| "Project not found".to_string())
 }
 
+/// Repositories linked to a project, primary (oldest link) first.
+pub(crate) async fn list_project_repositories(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    project_id: i64,
+) -> Result<Vec<ProjectRepository>, String> {
+    sqlx::query_as(
+        r#"
+        SELECT id, project_id, github_repo_id, repo_owner, repo_name, repo_full_name,
+               is_archived, archived_at, archived_reason, last_synced_at, created_at
+        FROM project_repositories
+        WHERE project_id = ?
+        ORDER BY id
+        "#,
+    )
+    .bind(project_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch repositories: {}", e))
+}
+
+/// The linked repository named `repo_full_name`, or the project's primary
+/// repository when `None` (callers written before multi-repo projects).
+pub(crate) async fn resolve_project_repository(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    project: &Project,
+    repo_full_name: Option<&str>,
+) -> Result<ProjectRepository, String> {
+    let full_name = repo_full_name
+        .or(project.repo_full_name.as_deref())
+        .ok_or("Repository not linked")?;
+
+    sqlx::query_as(
+        r#"
+        SELECT id, project_id, github_repo_id, repo_owner, repo_name, repo_full_name,
+               is_archived, archived_at, archived_reason, last_synced_at, created_at
+        FROM project_repositories
+        WHERE project_id = ? AND repo_full_name = ?
+        "#,
+    )
+    .bind(project.id)
+    .bind(full_name)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch repository: {}", e))?
+    .ok_or_else(|| format!("Repository {} is not linked to this project", full_name))
+}
+
+/// Error for a mutation against a repository archived by Issue #190.
+pub(crate) fn archived_repository_error(repository: &ProjectRepository) -> String {
+    format!(
+        "リポジトリ {} はアーカイブ状態です。Re-link Repository で再リンクするか、プロジェクトから外してください。",
+        repository.repo_full_name
+    )
+}
+
+/// Get the repositories linked to a project
+#[tauri::command]
+pub async fn get_project_repositories(
+    state: State<'_, AppState>,
+    project_id: i64,
+) -> Result<Vec<ProjectRepository>, String> {
+    // Verify project belongs to user
+    let _project = get_project(state.clone(), project_id).await?;
+    list_project_repositories(state.db.pool(), project_id).await
+}
+
 /// Create a new project
 #[tauri::command]
 pub async fn create_project(
@@ -175,6 +254,12 @@ pub async fn delete_project(state: State<'_, AppState>, project_id: i64) -> Resu
         .await
         .map_err(|e| format!("Failed to delete cached issues: {}", e))?;
 
+    sqlx::query("DELETE FROM project_repositories WHERE project_id = ?")
+        .bind(project_id)
+        .execute(state.db.pool())
+        .await
+        .map_err(|e| format!("Failed to delete repositories: {}", e))?;
+
     // Delete project
     let result = sqlx::query("DELETE FROM projects WHERE id = ? AND user_id = ?")
         .bind(project_id)
@@ -218,20 +303,23 @@ pub async fn get_user_repositories(
 }
 
 /// Apply the database-side effects of linking (or re-linking) a repository
-/// to a project. Pulled out so unit tests can exercise the
-/// re-link-clears-stale-cache invariant without spinning up the full
-/// Tauri runtime + GitHub HTTP client.
+/// to a project. Pulled out so unit tests can exercise the cache
+/// invariants without spinning up the full Tauri runtime + GitHub HTTP
+/// client.
 ///
-/// Re-linking to a *different* repository (previous `github_repo_id` is
-/// non-null and differs from the new one) hard-deletes the project's
-/// `cached_issues`. Without this, the kanban would keep showing cards
-/// from the old repo, and `update_issue_status` — which looks up by
-/// `(project_id, number)` — would route a drag on a stale card to a
-/// coincidentally-numbered issue in the newly linked repository
-/// (PR #213 P1 review). The whole operation runs in a single
-/// transaction so a crash between the UPDATE and the DELETE can't
-/// leave the project pointing at the new repo with old cache still
-/// queryable.
+/// Linking adds the repository next to the ones already linked; the first
+/// one becomes the project's primary repository (`projects.repo_*`).
+/// Re-linking a repository that is already linked clears its archive flag
+/// and keeps its cache.
+///
+/// The one case that still hard-deletes cache is a *different* repository
+/// (new `github_repo_id`) under a name that is already linked — a
+/// repository deleted and recreated, or a rename onto an old name. Its
+/// `cached_issues` belong to the old repository, and `update_issue_status`
+/// — which looks up by `(project_id, repo_full_name, number)` — would
+/// route a drag on a stale card to a coincidentally-numbered issue in the
+/// new one (PR #213 P1 review). Everything runs in a single transaction so
+/// a crash can't leave the new link with the old cache still queryable.
 pub(crate) async fn apply_repository_link(
     pool: &sqlx::Pool<sqlx::Sqlite>,
     project_id: i64,
@@ -242,31 +330,74 @@ pub(crate) async fn apply_repository_link(
     full_name: &str,
     now: &str,
 ) -> Result<(), String> {
-    let previous_repo_id: Option<i64> =
-        sqlx::query_scalar("SELECT github_repo_id FROM projects WHERE id = ? AND user_id = ?")
+    let owned: Option<i64> =
+        sqlx::query_scalar("SELECT id FROM projects WHERE id = ? AND user_id = ?")
             .bind(project_id)
             .bind(user_id)
             .fetch_optional(pool)
             .await
-            .map_err(|e| format!("Failed to read project: {}", e))?
-            .flatten();
-
-    let is_relink_to_different_repo = previous_repo_id
-        .map(|prev| prev != new_repo_id)
-        .unwrap_or(false);
+            .map_err(|e| format!("Failed to read project: {}", e))?;
+    if owned.is_none() {
+        return Err("Project not found".to_string());
+    }
 
     let mut tx = pool
         .begin()
         .await
         .map_err(|e| format!("Failed to begin link transaction: {}", e))?;
 
+    let replaced = sqlx::query(
+        r#"
+        DELETE FROM project_repositories
+        WHERE project_id = ? AND repo_full_name = ? AND github_repo_id <> ?
+        "#,
+    )
+    .bind(project_id)
+    .bind(full_name)
+    .bind(new_repo_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to replace repository: {}", e))?
+    .rows_affected()
+        > 0;
+
+    if replaced {
+        clear_repository_cache(&mut tx, project_id, full_name).await?;
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO project_repositories (
+            project_id, github_repo_id, repo_owner, repo_name, repo_full_name, created_at
+        )
+        VALUES (?, ?, ?, ?, ?, ?)
+        ON CONFLICT(project_id, github_repo_id) DO UPDATE SET
+            repo_owner = excluded.repo_owner,
+            repo_name = excluded.repo_name,
+            repo_full_name = excluded.repo_full_name,
+            is_archived = 0,
+            archived_at = NULL,
+            archived_reason = NULL
+        "#,
+    )
+    .bind(project_id)
+    .bind(new_repo_id)
+    .bind(owner)
+    .bind(repo)
+    .bind(full_name)
+    .bind(now)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to link repository: {}", e))?;
+
+    // First repository (or the primary one, re-linked) becomes / stays the
+    // primary.
     sqlx::query(
         r#"
         UPDATE projects
         SET github_repo_id = ?, repo_owner = ?, repo_name = ?, repo_full_name = ?,
-            is_archived = 0, archived_at = NULL, archived_reason = NULL,
             updated_at = ?
-        WHERE id = ? AND user_id = ?
+        WHERE id = ? AND (github_repo_id IS NULL OR repo_full_name = ?)
         "#,
     )
     .bind(new_repo_id)
@@ -275,27 +406,199 @@ pub(crate) async fn apply_repository_link(
     .bind(full_name)
     .bind(now)
     .bind(project_id)
-    .bind(user_id)
+    .bind(full_name)
     .execute(&mut *tx)
     .await
     .map_err(|e| format!("Failed to link repository: {}", e))?;
 
-    if is_relink_to_different_repo {
-        sqlx::query("DELETE FROM cached_issues WHERE project_id = ?")
+    // Any (re-)link starts that repository over with a full listing: the
+    // cursor and ETag describe the previous link's issue stream.
+    sqlx::query("DELETE FROM project_sync_cursors WHERE project_id = ? AND repo_full_name = ?")
+        .bind(project_id)
+        .bind(full_name)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to reset sync cursor: {}", e))?;
+
+    refresh_project_archive_state(&mut tx, project_id, now).await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to commit link transaction: {}", e))?;
+
+    Ok(())
+}
+
+/// Drop everything cached for one repository of a project: issues, queued
+/// offline edits (they target its issue numbers), comment pages and the
+/// sync cursor.
+async fn clear_repository_cache(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    project_id: i64,
+    full_name: &str,
+) -> Result<(), String> {
+    for (table, what) in [
+        ("cached_issues", "cached issues"),
+        ("issue_outbox", "outbox"),
+        ("issue_comments_cache", "comments"),
+        ("project_sync_cursors", "sync cursor"),
+    ] {
+        sqlx::query(sqlx::AssertSqlSafe(format!(
+            "DELETE FROM {} WHERE project_id = ? AND repo_full_name = ?",
+            table
+        )))
+        .bind(project_id)
+        .bind(full_name)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| format!("Failed to clear stale {}: {}", what, e))?;
+    }
+    Ok(())
+}
+
+/// Derive `projects.is_archived` from its repositories: archived once every
+/// linked repository is, active again as soon as one is reachable (or none
+/// is linked any more).
+async fn refresh_project_archive_state(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    project_id: i64,
+    now: &str,
+) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        UPDATE projects
+        SET is_archived = 1,
+            archived_at = COALESCE(archived_at, ?),
+            archived_reason = ?,
+            updated_at = ?
+        WHERE id = ?
+          AND EXISTS (SELECT 1 FROM project_repositories WHERE project_id = projects.id)
+          AND NOT EXISTS (
+              SELECT 1 FROM project_repositories
+              WHERE project_id = projects.id AND is_archived = 0
+          )
+        "#,
+    )
+    .bind(now)
+    .bind(crate::database::models::project::archive_reasons::REPOSITORY_GONE)
+    .bind(now)
+    .bind(project_id)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| format!("Failed to archive project: {}", e))?;
+
+    sqlx::query(
+        r#"
+        UPDATE projects
+        SET is_archived = 0, archived_at = NULL, archived_reason = NULL, updated_at = ?
+        WHERE id = ? AND is_archived = 1
+          AND (
+              NOT EXISTS (SELECT 1 FROM project_repositories WHERE project_id = projects.id)
+              OR EXISTS (
+                  SELECT 1 FROM project_repositories
+                  WHERE project_id = projects.id AND is_archived = 0
+              )
+          )
+        "#,
+    )
+    .bind(now)
+    .bind(project_id)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| format!("Failed to unarchive project: {}", e))?;
+
+    Ok(())
+}
+
+/// Remove one repository from a project together with its cache. When it
+/// was the primary repository the oldest remaining link takes over, and
+/// the milestones (synced from the primary only) are dropped.
+pub(crate) async fn apply_repository_unlink(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    project_id: i64,
+    user_id: i64,
+    full_name: &str,
+    now: &str,
+) -> Result<(), String> {
+    let primary: Option<Option<String>> =
+        sqlx::query_scalar("SELECT repo_full_name FROM projects WHERE id = ? AND user_id = ?")
+            .bind(project_id)
+            .bind(user_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("Failed to read project: {}", e))?;
+    let Some(primary) = primary else {
+        return Err("Project not found".to_string());
+    };
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to begin unlink transaction: {}", e))?;
+
+    let removed =
+        sqlx::query("DELETE FROM project_repositories WHERE project_id = ? AND repo_full_name = ?")
+            .bind(project_id)
+            .bind(full_name)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to unlink repository: {}", e))?
+            .rows_affected();
+    if removed == 0 {
+        return Err(format!(
+            "Repository {} is not linked to this project",
+            full_name
+        ));
+    }
+
+    clear_repository_cache(&mut tx, project_id, full_name).await?;
+
+    if primary.as_deref() == Some(full_name) {
+        // NULLs when no repository is left: the project is unlinked.
+        sqlx::query(
+            r#"
+            UPDATE projects
+            SET github_repo_id = (SELECT github_repo_id FROM project_repositories
+                                  WHERE project_id = projects.id ORDER BY id LIMIT 1),
+                repo_owner = (SELECT repo_owner FROM project_repositories
+                              WHERE project_id = projects.id ORDER BY id LIMIT 1),
+                repo_name = (SELECT repo_name FROM project_repositories
+                             WHERE project_id = projects.id ORDER BY id LIMIT 1),
+                repo_full_name = (SELECT repo_full_name FROM project_repositories
+                                  WHERE project_id = projects.id ORDER BY id LIMIT 1),
+                is_actions_setup = 0,
+                updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(now)
+        .bind(project_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to update primary repository: {}", e))?;
+
+        for table in ["project_milestones", "milestone_daily_snapshots"] {
+            sqlx::query(sqlx::AssertSqlSafe(format!(
+                "DELETE FROM {} WHERE project_id = ?",
+                table
+            )))
             .bind(project_id)
             .execute(&mut *tx)
             .await
-            .map_err(|e| format!("Failed to clear stale cached issues: {}", e))?;
+            .map_err(|e| format!("Failed to clear milestones: {}", e))?;
+        }
     }
 
+    refresh_project_archive_state(&mut tx, project_id, now).await?;
+
     tx.commit()
         .await
-        .map_err(|e| format!("Failed to commit link transaction: {}", e))?;
+        .map_err(|e| format!("Failed to commit unlink transaction: {}", e))?;
 
     Ok(())
 }
 
-/// Link a repository to a project
+/// Link a repository to a project (in addition to the ones already linked)
 #[tauri::command]
 pub async fn link_repository(
     app: AppHandle,
@@ -316,13 +619,9 @@ pub async fn link_repository(
     )
     .await?;
 
-    // Detect whether this is a re-link to a *different* repository — the
-    // previous github_repo_id is non-null and doesn't match the new one.
-    // We need this signal to clear stale `cached_issues` rows (PR #213
-    // P1 review): otherwise the kanban would keep showing cards from the
-    // old repo, and `update_issue_status` matches on `(project_id,
-    // number)` while calling GitHub with the new owner/repo — so a drag
-    // could mutate an unrelated issue in the newly linked repository.
+    // `apply_repository_link` clears stale cache when a *different*
+    // repository (new github_repo_id) arrives under an already linked
+    // name (PR #213 P1 review).
     let now = Utc::now().to_rfc3339();
     let full_name = format!("{}/{}", owner, repo);
 
@@ -347,6 +646,22 @@ pub async fn link_repository(
     get_project(state, project_id).await
 }
 
+/// Remove a repository from a project. Its cached issues, queued offline
+/// edits and comment pages are deleted; nothing changes on GitHub.
+#[tauri::command]
+pub async fn unlink_repository(
+    state: State<'_, AppState>,
+    project_id: i64,
+    repo_full_name: String,
+) -> Result<Project, String> {
+    let user_id = get_current_user_id(&state).await?;
+    let now = Utc::now().to_rfc3339();
+
+    apply_repository_unlink(state.db.pool(), project_id, user_id, &repo_full_name, &now).await?;
+
+    get_project(state, project_id).await
+}
+
 /// Setup GitHub Actions for automatic status updates
 #[tauri::command]
 pub async fn setup_github_actions(
@@ -358,15 +673,16 @@ pub async fn setup_github_actions(
     let owner = project.repo_owner.ok_or("Repository not linked")?;
     let repo = project.repo_name.ok_or("Repository not linked")?;
 
-    // Generate the workflow content
-    let workflow_content = generate_actions_template();
+    // Generate the workflow content from the project's settings
+    let settings = load_workflow_settings(state.db.pool(), project_id).await?;
+    let workflow_content = generate_actions_template(&settings);
 
     // Return instructions for the user to create the workflow
     // (Creating files via GitHub API requires a separate implementation)
     Ok(format!(
         r#"To enable automatic status updates, create the following file in your repository:
 
-File: .github/workflows/issue-status-sync.yml
+File: {}
 
 Content:
 {}
@@ -375,29 +691,32 @@ You can also create this file manually or through a Pull Request.
 
 Repository: {}/{}
 "#,
-        workflow_content, owner, repo
+        WORKFLOW_FILE_PATH, workflow_content, owner, repo
     ))
 }
 
-/// Mark a project as archived because GitHub returned 404 for its linked
-/// repository. Idempotent — running twice on the same project is a no-op
-/// (the `COALESCE(archived_at, ?)` preserves the original timestamp).
+/// Mark one linked repository as archived because GitHub returned 404 for
+/// it. Idempotent — running twice on the same repository is a no-op (the
+/// `COALESCE(archived_at, ?)` preserves the original timestamp). The
+/// project itself is archived once none of its repositories is reachable.
 ///
-/// Both updates run inside a single transaction so a crash / connection
-/// drop between them can never leave a project flagged archived while
+/// All updates run inside a single transaction so a crash / connection
+/// drop between them can never leave a repository flagged archived while
 /// its issues still appear active (or vice versa).
 ///
 /// The cached_issues are flagged but kept (not deleted) so the user can
 /// still browse the historical kanban board after a repo deletion.
-/// `delete_project` remains the explicit physical-delete path.
+/// `delete_project` / `unlink_repository` remain the explicit
+/// physical-delete paths.
 ///
 /// Takes the pool directly (rather than `State<AppState>`) so unit tests
 /// can exercise it against `Database::in_memory()` without spinning up a
 /// full Tauri runtime.
-pub(crate) async fn mark_project_repository_gone(
+pub(crate) async fn mark_repository_gone(
     pool: &sqlx::Pool<sqlx::Sqlite>,
     project_id: i64,
     user_id: i64,
+    full_name: &str,
     now: &str,
 ) -> Result<(), String> {
     let mut tx = pool
@@ -405,39 +724,46 @@ pub(crate) async fn mark_project_repository_gone(
         .await
         .map_err(|e| format!("Failed to begin archive transaction: {}", e))?;
 
-    sqlx::query(
+    let archived = sqlx::query(
         r#"
-        UPDATE projects
+        UPDATE project_repositories
         SET is_archived = 1,
             archived_at = COALESCE(archived_at, ?),
-            archived_reason = ?,
-            updated_at = ?
-        WHERE id = ? AND user_id = ?
+            archived_reason = ?
+        WHERE project_id = ? AND repo_full_name = ?
+          AND project_id IN (SELECT id FROM projects WHERE user_id = ?)
         "#,
     )
     .bind(now)
     .bind(crate::database::models::project::archive_reasons::REPOSITORY_GONE)
-    .bind(now)
     .bind(project_id)
+    .bind(full_name)
     .bind(user_id)
     .execute(&mut *tx)
     .await
-    .map_err(|e| format!("Failed to archive project: {}", e))?;
+    .map_err(|e| format!("Failed to archive repository: {}", e))?
+    .rows_affected();
+    if archived == 0 {
+        return Ok(());
+    }
 
     sqlx::query(
         r#"
         UPDATE cached_issues
         SET is_archived = 1,
             archived_at = COALESCE(archived_at, ?)
-        WHERE project_id = ?
+        WHERE project_id = ? AND repo_full_name = ?
         "#,
     )
     .bind(now)
     .bind(project_id)
+    .bind(full_name)
     .execute(&mut *tx)
     .await
     .map_err(|e| format!("Failed to archive cached issues: {}", e))?;
 
+    refresh_project_archive_state(&mut tx, project_id, now).await?;
+
     tx.commit()
         .await
         .map_err(|e| format!("Failed to commit archive transaction: {}", e))?;
@@ -445,15 +771,16 @@ pub(crate) async fn mark_project_repository_gone(
     Ok(())
 }
 
-/// Outcome of a single project sync, returned by [`sync_project_issues_inner`]
-/// so callers (e.g. `sync_all_projects`) can bucket results without
-/// re-querying the database for the archive flag.
+/// Outcome of a single project (or repository) sync, returned by
+/// [`sync_project_issues_inner`] so callers (e.g. `sync_all_projects`) can
+/// bucket results without re-querying the database for the archive flag.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum ProjectSyncOutcome {
     /// Issues were fetched and cached successfully.
     Synced,
-    /// GitHub returned 404 for the linked repository — the project is
-    /// now archived. The on-disk `cached_issues` are flagged but kept.
+    /// GitHub returned 404 for the linked repository — it is now
+    /// archived (and the project too, once no repository is left). The
+    /// on-disk `cached_issues` are flagged but kept.
     Archived,
 }
 
@@ -508,26 +835,117 @@ pub(crate) async fn confirm_repository_gone(
 /// archived without doing a follow-up DB read for every project — the
 /// Tauri-facing `sync_project_issues` is a thin wrapper that discards
 /// the outcome to preserve its existing `Vec<CachedIssue>` signature.
+///
+/// Fans out over every linked repository. A failing repository doesn't
+/// stop the others; its error is reported once they have all run.
+/// `include_archived` retries repositories archived by an earlier 404
+/// (explicit single-project sync) instead of skipping them (background
+/// `sync_all_projects`).
 async fn sync_project_issues_inner(
     app: &AppHandle,
     state: &State<'_, AppState>,
     project_id: i64,
+    include_archived: bool,
 ) -> Result<ProjectSyncOutcome, String> {
     let project = get_project(state.clone(), project_id).await?;
-    let user_id = project.user_id;
+    let repositories = list_project_repositories(state.db.pool(), project_id).await?;
+    if repositories.is_empty() {
+        return Err("Repository not linked".to_string());
+    }
 
-    let owner = project.repo_owner.ok_or("Repository not linked")?;
-    let repo = project.repo_name.ok_or("Repository not linked")?;
+    // Flush offline edits first so the pull below sees (and caches) the
+    // post-replay state. Best-effort: a replay failure is parked on the
+    // operation itself and must not block the sync.
+    if let Err(e) = replay_outbox(app, state, project.user_id, Some(project_id)).await {
+        eprintln!(
+            "Project {}: outbox replay before sync failed: {}",
+            project_id, e
+        );
+    }
+
+    let mut any_synced = false;
+    let mut errors = Vec::new();
+    for repository in repositories
+        .iter()
+        .filter(|r| include_archived || !r.is_archived)
+    {
+        match sync_repository_issues(app, state, &project, repository).await {
+            Ok(ProjectSyncOutcome::Synced) => any_synced = true,
+            Ok(ProjectSyncOutcome::Archived) => {}
+            Err(e) => errors.push(format!("{}: {}", repository.repo_full_name, e)),
+        }
+    }
+
+    if any_synced {
+        let now = Utc::now().to_rfc3339();
+        sqlx::query("UPDATE projects SET last_synced_at = ?, updated_at = ? WHERE id = ?")
+            .bind(&now)
+            .bind(&now)
+            .bind(project_id)
+            .execute(state.db.pool())
+            .await
+            .map_err(|e| format!("Failed to update project: {}", e))?;
+    }
+
+    if !errors.is_empty() {
+        return Err(errors.join("\n"));
+    }
+
+    let archived: bool = sqlx::query_scalar("SELECT is_archived FROM projects WHERE id = ?")
+        .bind(project_id)
+        .fetch_one(state.db.pool())
+        .await
+        .map_err(|e| format!("Failed to read project: {}", e))?;
+
+    Ok(if archived {
+        ProjectSyncOutcome::Archived
+    } else {
+        ProjectSyncOutcome::Synced
+    })
+}
+
+/// Sync one linked repository of a project into `cached_issues`.
+async fn sync_repository_issues(
+    app: &AppHandle,
+    state: &State<'_, AppState>,
+    project: &Project,
+    repository: &ProjectRepository,
+) -> Result<ProjectSyncOutcome, String> {
+    let project_id = project.id;
+    let user_id = project.user_id;
+    let owner = repository.repo_owner.as_str();
+    let repo = repository.repo_name.as_str();
+    let full_name = repository.repo_full_name.as_str();
+    let is_primary = project.repo_full_name.as_deref() == Some(full_name);
 
     let access_token = get_access_token(state).await?;
-    let client = IssuesClient::new(access_token);
+    let client = IssuesClient::new(access_token.clone());
+
+    // Incremental by default: only issues updated since the stored cursor,
+    // with the last ETag as If-None-Match. A periodic full listing (no
+    // `since`, no ETag) is what lets us notice deleted / transferred issues.
+    let cursor = load_project_sync_cursor(state.db.pool(), project_id, full_name).await?;
+    let full_sync = cursor.needs_full_sync(Utc::now());
+    let since = if full_sync {
+        None
+    } else {
+        cursor.since_cursor.clone()
+    };
 
-    // Fetch all issues (open and closed)
     let mut all_issues = Vec::new();
     let mut page = 1;
+    let mut new_etag = None;
+    let mut truncated = false;
 
     loop {
-        let result = client.get_issues(&owner, &repo, "all", 100, page).await;
+        let etag = if page == 1 && !full_sync {
+            cursor.etag.as_deref()
+        } else {
+            None
+        };
+        let result = client
+            .get_issues_since(owner, repo, since.as_deref(), etag, 100, page)
+            .await;
 
         // A 404 on `/repos/{owner}/{repo}/issues` is *not* by itself a
         // reliable "repository was deleted" signal — the same status code
@@ -540,14 +958,14 @@ async fn sync_project_issues_inner(
         // canonical `/repos/{owner}/{repo}` endpoint before mutating
         // state — see PR #213 review (chatgpt-codex P2).
         if let Err(GitHubError::NotFound(_)) = &result {
-            match confirm_repository_gone(&client, &owner, &repo).await {
+            match confirm_repository_gone(&client, owner, repo).await {
                 RepositoryGoneCheck::Confirmed => {
                     let now = Utc::now().to_rfc3339();
-                    mark_project_repository_gone(state.db.pool(), project_id, user_id, &now)
+                    mark_repository_gone(state.db.pool(), project_id, user_id, full_name, &now)
                         .await?;
                     eprintln!(
-                        "Project {} ({}/{}) archived: repository returned 404 (confirmed via /repos)",
-                        project_id, owner, repo
+                        "Project {} ({}) archived repository: returned 404 (confirmed via /repos)",
+                        project_id, full_name
                     );
                     return Ok(ProjectSyncOutcome::Archived);
                 }
@@ -574,17 +992,32 @@ async fn sync_project_issues_inner(
             }
         }
 
-        let issues = map_github_result(app, state.inner(), result).await?;
+        let issues = match map_github_result(app, state.inner(), result).await? {
+            // Nothing changed since the last run. Only page 1 is ever
+            // conditional, so this can't cut a pagination short.
+            IssuesPageResponse::NotModified => break,
+            IssuesPageResponse::Modified { issues, etag } => {
+                if page == 1 {
+                    new_etag = etag;
+                }
+                issues
+            }
+        };
 
         if issues.is_empty() {
             break;
         }
 
+        let last_page = issues.len() < 100;
         all_issues.extend(issues);
         page += 1;
 
         // Limit to 1000 issues for safety
         if all_issues.len() >= 1000 {
+            truncated = true;
+            break;
+        }
+        if last_page {
             break;
         }
     }
@@ -593,88 +1026,397 @@ async fn sync_project_issues_inner(
     let now = Utc::now().to_rfc3339();
 
     for issue in &all_issues {
-        // Use extract_status_with_state to properly handle closed issues
-        let status = IssuesClient::extract_status_with_state(
-            &issue.labels,
-            &issue.state,
-            issue.state_reason.as_deref(),
-        );
-        let priority = IssuesClient::extract_priority(&issue.labels);
-        let labels_json =
-            serde_json::to_string(&issue.labels.iter().map(|l| &l.name).collect::<Vec<_>>())
-                .unwrap_or_else(|_| "[]".to_string());
-
-        // `is_archived = 0` on conflict so a re-link followed by a successful
-        // sync rebinds historical rows back to the active set; otherwise
-        // they would stay dimmed in the kanban forever even though
-        // GitHub clearly returned them just now.
+        upsert_cached_issue(state.db.pool(), project_id, full_name, issue, &now).await?;
+    }
+
+    // Linked PRs and their CI / review state change without touching the
+    // issue's `updated_at`, so they are re-read for every open issue on
+    // each run instead of following the incremental cursor. Issues closed
+    // since the previous run get one last refresh so a PR that merged and
+    // closed them doesn't stay cached as open. GraphQL-only and
+    // best-effort: a failure here must not fail the issue sync.
+    match GitHubClient::new(access_token)
+        .get_issue_linked_pull_requests(owner, repo, cursor.since_cursor.as_deref())
+        .await
+    {
+        Ok(links) => {
+            save_linked_pull_requests(state.db.pool(), project_id, full_name, &links).await?
+        }
+        Err(e) => eprintln!(
+            "Project {} ({}): linked PR refresh failed: {}",
+            project_id, full_name, e
+        ),
+    }
+
+    // Milestones (and today's burndown snapshot) are small enough to
+    // re-read in full on every run. Best-effort like the linked PRs, and
+    // only for the primary repository: milestone numbers are per
+    // repository.
+    if is_primary {
+        if let Err(e) =
+            sync_project_milestones(state.db.pool(), &client, project_id, owner, repo).await
+        {
+            eprintln!("Project {}: milestone sync failed: {}", project_id, e);
+        }
+    }
+
+    // Reconcile deletions / transfers, but only against a complete full
+    // listing — with a truncated or incremental result, "not in the list"
+    // doesn't mean "gone".
+    if full_sync && !truncated {
+        reconcile_missing_issues(app, state, &client, project_id, owner, repo, &all_issues).await?;
+    }
+
+    save_project_sync_cursor(
+        state.db.pool(),
+        &ProjectSyncCursor {
+            project_id,
+            repo_full_name: full_name.to_string(),
+            since_cursor: next_since_cursor(cursor.since_cursor.as_deref(), &all_issues),
+            // The full listing's ETag belongs to a different URL (no
+            // `since`), so it would never match the next incremental call.
+            etag: if full_sync {
+                None
+            } else {
+                new_etag.or(cursor.etag)
+            },
+            last_full_sync_at: if full_sync {
+                Some(now.clone())
+            } else {
+                cursor.last_full_sync_at
+            },
+            last_incremental_sync_at: Some(now.clone()),
+        },
+    )
+    .await?;
+
+    // Reset archive flags so a repository that was archived (e.g.
+    // transient 404 due to a private-repo permission glitch)
+    // automatically rejoins the active set as soon as the sync succeeds
+    // again — and with it the project.
+    let mut tx = state
+        .db
+        .pool()
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+    sqlx::query(
+        r#"
+        UPDATE project_repositories
+        SET last_synced_at = ?, is_archived = 0, archived_at = NULL, archived_reason = NULL
+        WHERE id = ?
+        "#,
+    )
+    .bind(&now)
+    .bind(repository.id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| format!("Failed to update repository: {}", e))?;
+    refresh_project_archive_state(&mut tx, project_id, &now).await?;
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+    Ok(ProjectSyncOutcome::Synced)
+}
+
+/// Write one GitHub issue into `cached_issues` (insert or refresh).
+///
+/// Shared by the sync loop and the edit commands so both map labels,
+/// status and assignees the same way.
+pub(crate) async fn upsert_cached_issue(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    project_id: i64,
+    repo_full_name: &str,
+    issue: &GitHubIssue,
+    now: &str,
+) -> Result<(), String> {
+    // Use extract_status_with_state to properly handle closed issues
+    let status = IssuesClient::extract_status_with_state(
+        &issue.labels,
+        &issue.state,
+        issue.state_reason.as_deref(),
+    );
+    let priority = IssuesClient::extract_priority(&issue.labels);
+    let labels_json =
+        serde_json::to_string(&issue.labels.iter().map(|l| &l.name).collect::<Vec<_>>())
+            .unwrap_or_else(|_| "[]".to_string());
+    let assignees_json =
+        serde_json::to_string(&issue.assignees.iter().map(|a| &a.login).collect::<Vec<_>>())
+            .unwrap_or_else(|_| "[]".to_string());
+
+    // `is_archived = 0` on conflict so a re-link followed by a successful
+    // sync rebinds historical rows back to the active set; otherwise
+    // they would stay dimmed in the kanban forever even though
+    // GitHub clearly returned them just now. Rows with `pending_sync = 1`
+    // are skipped: they carry an offline change still queued in
+    // `issue_outbox`, and the remote copy is by definition stale.
+    sqlx::query(
+        r#"
+        INSERT INTO cached_issues (
+            project_id, repo_full_name, github_issue_id, number, title, body, state, status,
+            priority, assignee_login, assignee_avatar_url, labels_json, html_url,
+            github_created_at, github_updated_at, cached_at,
+            is_archived, archived_at,
+            assignees_json, milestone_number, milestone_title, state_reason,
+            github_closed_at
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 0, NULL, ?, ?, ?, ?, ?)
+        ON CONFLICT(project_id, github_issue_id) DO UPDATE SET
+            repo_full_name = excluded.repo_full_name,
+            number = excluded.number,
+            title = excluded.title,
+            body = excluded.body,
+            state = excluded.state,
+            status = excluded.status,
+            priority = excluded.priority,
+            assignee_login = excluded.assignee_login,
+            assignee_avatar_url = excluded.assignee_avatar_url,
+            labels_json = excluded.labels_json,
+            html_url = excluded.html_url,
+            github_created_at = excluded.github_created_at,
+            github_updated_at = excluded.github_updated_at,
+            cached_at = excluded.cached_at,
+            is_archived = 0,
+            archived_at = NULL,
+            assignees_json = excluded.assignees_json,
+            milestone_number = excluded.milestone_number,
+            milestone_title = excluded.milestone_title,
+            state_reason = excluded.state_reason,
+            github_closed_at = excluded.github_closed_at
+        WHERE cached_issues.pending_sync = 0
+        "#,
+    )
+    .bind(project_id)
+    .bind(repo_full_name)
+    .bind(issue.id)
+    .bind(issue.number)
+    .bind(&issue.title)
+    .bind(&issue.body)
+    .bind(&issue.state)
+    .bind(status.to_string())
+    .bind(priority.map(|p| p.to_string()))
+    .bind(issue.assignee.as_ref().map(|a| &a.login))
+    .bind(issue.assignee.as_ref().map(|a| &a.avatar_url))
+    .bind(&labels_json)
+    .bind(&issue.html_url)
+    .bind(issue.created_at.to_rfc3339())
+    .bind(issue.updated_at.to_rfc3339())
+    .bind(now)
+    .bind(&assignees_json)
+    .bind(issue.milestone.as_ref().map(|m| m.number))
+    .bind(issue.milestone.as_ref().map(|m| &m.title))
+    .bind(&issue.state_reason)
+    .bind(issue.closed_at.map(|t| t.to_rfc3339()))
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to cache issue: {}", e))?;
+    Ok(())
+}
+
+/// Store the linked PRs fetched for one repository's issues. Issues
+/// with no links get NULL; issues absent from `links` are left as they are.
+pub(crate) async fn save_linked_pull_requests(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    project_id: i64,
+    repo_full_name: &str,
+    links: &[(i32, Vec<LinkedPullRequest>)],
+) -> Result<(), String> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    for (number, prs) in links {
+        let json = if prs.is_empty() {
+            None
+        } else {
+            Some(
+                serde_json::to_string(prs)
+                    .map_err(|e| format!("Failed to serialize linked PRs: {}", e))?,
+            )
+        };
         sqlx::query(
             r#"
-            INSERT INTO cached_issues (
-                project_id, github_issue_id, number, title, body, state, status, priority,
-                assignee_login, assignee_avatar_url, labels_json, html_url,
-                github_created_at, github_updated_at, cached_at,
-                is_archived, archived_at
-            )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 0, NULL)
-            ON CONFLICT(project_id, github_issue_id) DO UPDATE SET
-                number = excluded.number,
-                title = excluded.title,
-                body = excluded.body,
-                state = excluded.state,
-                status = excluded.status,
-                priority = excluded.priority,
-                assignee_login = excluded.assignee_login,
-                assignee_avatar_url = excluded.assignee_avatar_url,
-                labels_json = excluded.labels_json,
-                html_url = excluded.html_url,
-                github_created_at = excluded.github_created_at,
-                github_updated_at = excluded.github_updated_at,
-                cached_at = excluded.cached_at,
-                is_archived = 0,
-                archived_at = NULL
+            UPDATE cached_issues SET linked_prs_json = ?
+            WHERE project_id = ? AND repo_full_name = ? AND number = ?
             "#,
         )
+        .bind(json)
         .bind(project_id)
-        .bind(issue.id)
-        .bind(issue.number)
-        .bind(&issue.title)
-        .bind(&issue.body)
-        .bind(&issue.state)
-        .bind(status.to_string())
-        .bind(priority.map(|p| p.to_string()))
-        .bind(issue.assignee.as_ref().map(|a| &a.login))
-        .bind(issue.assignee.as_ref().map(|a| &a.avatar_url))
-        .bind(&labels_json)
-        .bind(&issue.html_url)
-        .bind(issue.created_at.to_rfc3339())
-        .bind(issue.updated_at.to_rfc3339())
-        .bind(&now)
-        .execute(state.db.pool())
+        .bind(repo_full_name)
+        .bind(number)
+        .execute(&mut *tx)
         .await
-        .map_err(|e| format!("Failed to cache issue: {}", e))?;
+        .map_err(|e| format!("Failed to cache linked PRs: {}", e))?;
     }
 
-    // Update last synced timestamp; reset archive flags so a project that
-    // was archived (e.g. transient 404 due to a private-repo permission
-    // glitch) automatically rejoins the active set as soon as the sync
-    // succeeds again.
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to commit linked PRs: {}", e))
+}
+
+/// Upper bound on per-sync `probe_issue` calls. A repository that lost
+/// hundreds of issues at once (bulk delete, mass transfer) is reconciled
+/// over several full passes instead of burning the rate limit in one go.
+const MAX_RECONCILE_PROBES: usize = 50;
+
+/// Load the incremental sync cursor for one repository of a project
+/// (default = never synced).
+pub(crate) async fn load_project_sync_cursor(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    project_id: i64,
+    repo_full_name: &str,
+) -> Result<ProjectSyncCursor, String> {
+    let cursor = sqlx::query_as::<_, ProjectSyncCursor>(
+        r#"
+        SELECT project_id, repo_full_name, since_cursor, etag, last_full_sync_at,
+               last_incremental_sync_at
+        FROM project_sync_cursors
+        WHERE project_id = ? AND repo_full_name = ?
+        "#,
+    )
+    .bind(project_id)
+    .bind(repo_full_name)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to load sync cursor: {}", e))?;
+
+    Ok(cursor.unwrap_or(ProjectSyncCursor {
+        project_id,
+        repo_full_name: repo_full_name.to_string(),
+        ..Default::default()
+    }))
+}
+
+/// Persist the incremental sync cursor for one repository of a project.
+pub(crate) async fn save_project_sync_cursor(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    cursor: &ProjectSyncCursor,
+) -> Result<(), String> {
     sqlx::query(
         r#"
-        UPDATE projects
-        SET last_synced_at = ?, updated_at = ?,
-            is_archived = 0, archived_at = NULL, archived_reason = NULL
-        WHERE id = ?
+        INSERT INTO project_sync_cursors (
+            project_id, repo_full_name, since_cursor, etag, last_full_sync_at,
+            last_incremental_sync_at, updated_at
+        )
+        VALUES (?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
+        ON CONFLICT(project_id, repo_full_name) DO UPDATE SET
+            since_cursor = excluded.since_cursor,
+            etag = excluded.etag,
+            last_full_sync_at = excluded.last_full_sync_at,
+            last_incremental_sync_at = excluded.last_incremental_sync_at,
+            updated_at = CURRENT_TIMESTAMP
+        "#,
+    )
+    .bind(cursor.project_id)
+    .bind(&cursor.repo_full_name)
+    .bind(&cursor.since_cursor)
+    .bind(&cursor.etag)
+    .bind(&cursor.last_full_sync_at)
+    .bind(&cursor.last_incremental_sync_at)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to save sync cursor: {}", e))?;
+    Ok(())
+}
+
+/// Advance the `since` cursor to the newest `updated_at` in `issues`.
+///
+/// Never moves backwards: an empty or older page keeps the previous value.
+/// Formatted as `YYYY-MM-DDTHH:MM:SSZ`, the shape GitHub documents for
+/// `since`.
+pub(crate) fn next_since_cursor(previous: Option<&str>, issues: &[GitHubIssue]) -> Option<String> {
+    let previous = previous
+        .and_then(|p| DateTime::parse_from_rfc3339(p).ok())
+        .map(|p| p.with_timezone(&Utc));
+    let newest = issues.iter().map(|i| i.updated_at).max();
+    previous
+        .into_iter()
+        .chain(newest)
+        .max()
+        .map(|t| t.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+/// Cached `(github_issue_id, number)` pairs that a complete listing did not
+/// return. Candidates for deletion / transfer — still probed individually
+/// before anything is removed.
+pub(crate) fn missing_cached_issues(
+    cached: &[(i64, i32)],
+    fetched: &[GitHubIssue],
+) -> Vec<(i64, i32)> {
+    let seen: std::collections::HashSet<i64> = fetched.iter().map(|i| i.id).collect();
+    cached
+        .iter()
+        .filter(|(github_issue_id, _)| !seen.contains(github_issue_id))
+        .copied()
+        .collect()
+}
+
+/// Drop cached issues that were deleted or transferred out of a linked
+/// repository. Rows with a queued offline change and queued-create
+/// placeholders (negative ids) are left alone.
+async fn reconcile_missing_issues(
+    app: &AppHandle,
+    state: &State<'_, AppState>,
+    client: &IssuesClient,
+    project_id: i64,
+    owner: &str,
+    repo: &str,
+    fetched: &[GitHubIssue],
+) -> Result<(), String> {
+    let cached: Vec<(i64, i32)> = sqlx::query(
+        r#"
+        SELECT github_issue_id, number FROM cached_issues
+        WHERE project_id = ? AND repo_full_name = ? AND pending_sync = 0
+          AND github_issue_id > 0
         "#,
     )
-    .bind(&now)
-    .bind(&now)
     .bind(project_id)
-    .execute(state.db.pool())
+    .bind(format!("{}/{}", owner, repo))
+    .fetch_all(state.db.pool())
     .await
-    .map_err(|e| format!("Failed to update project: {}", e))?;
+    .map_err(|e| format!("Failed to read cached issues: {}", e))?
+    .iter()
+    .map(|row| (row.get("github_issue_id"), row.get("number")))
+    .collect();
 
-    Ok(ProjectSyncOutcome::Synced)
+    for (github_issue_id, number) in missing_cached_issues(&cached, fetched)
+        .into_iter()
+        .take(MAX_RECONCILE_PROBES)
+    {
+        match client.probe_issue(owner, repo, number).await {
+            Ok(IssueProbe::Present) => {}
+            Ok(probe) => {
+                eprintln!(
+                    "Project {}: removing issue #{} from cache ({:?})",
+                    project_id, number, probe
+                );
+                sqlx::query(
+                    "DELETE FROM cached_issues WHERE project_id = ? AND github_issue_id = ?",
+                )
+                .bind(project_id)
+                .bind(github_issue_id)
+                .execute(state.db.pool())
+                .await
+                .map_err(|e| format!("Failed to remove issue: {}", e))?;
+            }
+            Err(GitHubError::Unauthorized) => {
+                return map_github_result(app, state.inner(), Err(GitHubError::Unauthorized)).await;
+            }
+            Err(e) => {
+                // Reconciliation is housekeeping; a transient failure just
+                // leaves the remaining candidates for the next full pass.
+                eprintln!(
+                    "Project {}: issue reconciliation stopped: {}",
+                    project_id, e
+                );
+                break;
+            }
+        }
+    }
+
+    Ok(())
 }
 
 /// Response payload for [`sync_project_issues`]. Surfaces both the resulting
@@ -693,11 +1435,12 @@ pub struct SyncProjectIssuesResponse {
     pub archived: bool,
 }
 
-/// Sync issues from GitHub to local cache.
+/// Sync issues from every linked repository to local cache.
 ///
-/// Behaviour for a 404 from the linked repository (Issue #190): the project
-/// is marked archived and the response carries `archived: true` together
-/// with the (now-stale) cache. The command does NOT return `Err` for the
+/// Behaviour for a 404 from a linked repository (Issue #190): that
+/// repository is marked archived; once none is left the project is too and
+/// the response carries `archived: true` together with the (now-stale)
+/// cache. The command does NOT return `Err` for the
 /// archive case — that lets `sync_all_projects` and the scheduler keep
 /// running across every other project the user has linked, while still
 /// giving single-project callers an explicit signal.
@@ -707,7 +1450,7 @@ pub async fn sync_project_issues(
     state: State<'_, AppState>,
     project_id: i64,
 ) -> Result<SyncProjectIssuesResponse, String> {
-    let outcome = sync_project_issues_inner(&app, &state, project_id).await?;
+    let outcome = sync_project_issues_inner(&app, &state, project_id, true).await?;
     let issues = get_project_issues(state, project_id, None).await?;
     Ok(SyncProjectIssuesResponse {
         issues,
@@ -727,9 +1470,10 @@ pub async fn sync_all_projects(
     let user_id = get_current_user_id(&state).await?;
 
     // Only sync projects that are (a) linked to a repo and (b) not already
-    // archived. Skipping archived rows here is what makes the partial-sync
-    // promise hold: a previously-gone repo never wedges the loop again
-    // until the user explicitly re-links it.
+    // archived. Skipping archived rows here — and archived repositories
+    // inside a project — is what makes the partial-sync promise hold: a
+    // previously-gone repo never wedges the loop again until the user
+    // explicitly re-links it.
     //
     // `is_archived` is `NOT NULL DEFAULT 0` (migration v13), so `COALESCE`
     // is unnecessary — the column is always 0 or 1 on every row.
@@ -760,7 +1504,7 @@ pub async fn sync_all_projects(
         // directly so we don't need a follow-up SELECT to bucket
         // archived projects. (Issue #190 review feedback: avoid an
         // N+1 over linked projects.)
-        match sync_project_issues_inner(&app, &state, project_id).await {
+        match sync_project_issues_inner(&app, &state, project_id, false).await {
             Ok(ProjectSyncOutcome::Synced) => synced.push(project_id),
             Ok(ProjectSyncOutcome::Archived) => archived.push(project_id),
             Err(message) => {
@@ -779,11 +1523,13 @@ pub async fn sync_all_projects(
     })
 }
 
-/// Re-link an archived project to the same or a different repository.
+/// Re-link an archived repository to the same or a different repository.
 ///
 /// This is a thin wrapper around `link_repository` that exists so the
 /// frontend can call a verb that matches the user-facing action ("Re-link")
 /// without shipping its own knowledge of the archive flag reset rules.
+/// `replaces` names the archived repository being swapped out; it is
+/// unlinked once the new one is linked (a no-op for a same-name re-link).
 #[tauri::command]
 pub async fn relink_repository(
     app: AppHandle,
@@ -791,8 +1537,20 @@ pub async fn relink_repository(
     project_id: i64,
     owner: String,
     repo: String,
+    replaces: Option<String>,
 ) -> Result<Project, String> {
-    link_repository(app, state, project_id, owner, repo).await
+    let full_name = format!("{}/{}", owner, repo);
+    let project = link_repository(app, state.clone(), project_id, owner, repo).await?;
+
+    match replaces {
+        Some(old) if old != full_name => {
+            let now = Utc::now().to_rfc3339();
+            apply_repository_unlink(state.db.pool(), project_id, project.user_id, &old, &now)
+                .await?;
+            get_project(state, project_id).await
+        }
+        _ => Ok(project),
+    }
 }
 
 /// Result payload for [`sync_all_projects`]: which projects synced, which
@@ -829,13 +1587,16 @@ pub async fn get_project_issues(
     let issues = if let Some(s) = status {
         sqlx::query_as(
             r#"
-            SELECT id, project_id, github_issue_id, number, title, body, state, status, priority,
+            SELECT id, project_id, repo_full_name, github_issue_id, number, title, body, state,
+                   status, priority,
                    assignee_login, assignee_avatar_url, labels_json, html_url,
                    github_created_at, github_updated_at, cached_at,
-                   is_archived, archived_at
+                   is_archived, archived_at, pending_sync,
+                   assignees_json, milestone_number, milestone_title, state_reason,
+                   linked_prs_json
             FROM cached_issues
             WHERE project_id = ? AND status = ?
-            ORDER BY number DESC
+            ORDER BY number DESC, repo_full_name
             "#,
         )
         .bind(project_id)
@@ -845,13 +1606,16 @@ pub async fn get_project_issues(
     } else {
         sqlx::query_as(
             r#"
-            SELECT id, project_id, github_issue_id, number, title, body, state, status, priority,
+            SELECT id, project_id, repo_full_name, github_issue_id, number, title, body, state,
+                   status, priority,
                    assignee_login, assignee_avatar_url, labels_json, html_url,
                    github_created_at, github_updated_at, cached_at,
-                   is_archived, archived_at
+                   is_archived, archived_at, pending_sync,
+                   assignees_json, milestone_number, milestone_title, state_reason,
+                   linked_prs_json
             FROM cached_issues
             WHERE project_id = ?
-            ORDER BY number DESC
+            ORDER BY number DESC, repo_full_name
             "#,
         )
         .bind(project_id)
@@ -862,13 +1626,28 @@ pub async fn get_project_issues(
     issues.map_err(|e| format!("Failed to fetch issues: {}", e))
 }
 
-/// Get issues as kanban board
+/// Get issues as kanban board, optionally limited to one linked repository
+/// and / or one milestone (`milestone_number: 0` = issues without a
+/// milestone). Milestones are synced from the primary repository only, so
+/// a milestone filter only matches that repository's issues.
 #[tauri::command]
 pub async fn get_kanban_board(
     state: State<'_, AppState>,
     project_id: i64,
+    milestone_number: Option<i32>,
+    repo_full_name: Option<String>,
 ) -> Result<KanbanBoard, String> {
-    let issues = get_project_issues(state, project_id, None).await?;
+    let project = get_project(state.clone(), project_id).await?;
+    let mut issues = get_project_issues(state, project_id, None).await?;
+    if let Some(repo) = repo_full_name {
+        issues.retain(|issue| issue.repo_full_name.as_deref() == Some(repo.as_str()));
+    }
+    if let Some(number) = milestone_number {
+        issues.retain(|issue| {
+            issue.milestone_number.unwrap_or(0) == number
+                && (number == 0 || issue.repo_full_name == project.repo_full_name)
+        });
+    }
     Ok(KanbanBoard::from_issues(issues))
 }
 
@@ -880,6 +1659,7 @@ pub async fn update_issue_status(
     project_id: i64,
     issue_number: i32,
     new_status: String,
+    repo_full_name: Option<String>,
 ) -> Result<CachedIssue, String> {
     let project = get_project(state.clone(), project_id).await?;
 
@@ -897,60 +1677,82 @@ pub async fn update_issue_status(
         );
     }
 
-    let owner = project.repo_owner.ok_or("Repository not linked")?;
-    let repo = project.repo_name.ok_or("Repository not linked")?;
+    let repository =
+        resolve_project_repository(state.db.pool(), &project, repo_full_name.as_deref()).await?;
+    if repository.is_archived {
+        return Err(archived_repository_error(&repository));
+    }
+    let full_name = repository.repo_full_name.as_str();
+    let owner = repository.repo_owner.as_str();
+    let repo = repository.repo_name.as_str();
 
     let status: IssueStatus = new_status
         .parse()
         .map_err(|_| format!("Invalid status: {}", new_status))?;
 
-    let access_token = get_access_token(&state).await?;
-    let client = IssuesClient::new(access_token);
+    // Queued issues (negative placeholder number) and issues with an
+    // earlier offline edit still pending go straight to the outbox: the
+    // former don't exist on GitHub yet, and for the latter a direct call
+    // would be overwritten by the older queued change on replay.
+    let queue_only = issue_number < 0
+        || has_pending_operation(state.db.pool(), project_id, full_name, issue_number).await?;
 
-    // Update status on GitHub
-    let updated_issue = map_github_result(
-        &app,
-        state.inner(),
-        client
-            .update_issue_status(&owner, &repo, issue_number, status)
-            .await,
-    )
-    .await?;
+    let result = if queue_only {
+        None
+    } else {
+        let access_token = get_access_token(&state).await?;
+        let client = IssuesClient::new(access_token);
+        Some(
+            client
+                .update_issue_status(owner, repo, issue_number, status)
+                .await,
+        )
+    };
 
-    // Update local cache
-    let now = Utc::now().to_rfc3339();
-    sqlx::query(
-        "UPDATE cached_issues SET status = ?, state = ?, cached_at = ? WHERE project_id = ? AND number = ?",
-    )
-    .bind(status.to_string())
-    .bind(&updated_issue.state)
-    .bind(&now)
-    .bind(project_id)
-    .bind(issue_number)
-    .execute(state.db.pool())
-    .await
-    .map_err(|e| format!("Failed to update cache: {}", e))?;
+    match result {
+        Some(Err(e)) if !is_offline_error(&e) => {
+            map_github_result::<()>(&app, state.inner(), Err(e)).await?;
+        }
+        Some(Ok(updated_issue)) => {
+            // Update local cache
+            let now = Utc::now().to_rfc3339();
+            sqlx::query(
+                r#"
+                UPDATE cached_issues SET status = ?, state = ?, cached_at = ?
+                WHERE project_id = ? AND repo_full_name = ? AND number = ?
+                "#,
+            )
+            .bind(status.to_string())
+            .bind(&updated_issue.state)
+            .bind(&now)
+            .bind(project_id)
+            .bind(full_name)
+            .bind(issue_number)
+            .execute(state.db.pool())
+            .await
+            .map_err(|e| format!("Failed to update cache: {}", e))?;
+        }
+        // Offline (or queue-only): record the change and apply it
+        // optimistically so the card stays where the user dropped it.
+        _ => {
+            enqueue_status_change(
+                state.db.pool(),
+                project.user_id,
+                project_id,
+                full_name,
+                issue_number,
+                status,
+            )
+            .await?;
+        }
+    }
 
     // Fetch and return updated issue
-    sqlx::query_as(
-        r#"
-        SELECT id, project_id, github_issue_id, number, title, body, state, status, priority,
-               assignee_login, assignee_avatar_url, labels_json, html_url,
-               github_created_at, github_updated_at, cached_at,
-               is_archived, archived_at
-        FROM cached_issues
-        WHERE project_id = ? AND number = ?
-        "#,
-    )
-    .bind(project_id)
-    .bind(issue_number)
-    .fetch_optional(state.db.pool())
-    .await
-    .map_err(|e| format!("Failed to fetch issue: {}", e))?
-    .ok_or_else(|| "Issue not found".to_string())
+    fetch_cached_issue(state.db.pool(), project_id, full_name, issue_number).await
 }
 
-/// Create a new issue (on GitHub and cache locally)
+/// Create a new issue (on GitHub and cache locally) in one of the project's
+/// repositories (the primary one when `repo_full_name` is omitted)
 #[tauri::command]
 pub async fn create_github_issue(
     app: AppHandle,
@@ -960,6 +1762,9 @@ pub async fn create_github_issue(
     body: Option<String>,
     status: Option<String>,
     priority: Option<String>,
+    repo_full_name: Option<String>,
+    labels: Option<Vec<String>>,
+    assignees: Option<Vec<String>>,
 ) -> Result<CachedIssue, String> {
     let project = get_project(state.clone(), project_id).await?;
 
@@ -973,35 +1778,78 @@ pub async fn create_github_issue(
         );
     }
 
-    let owner = project.repo_owner.ok_or("Repository not linked")?;
-    let repo = project.repo_name.ok_or("Repository not linked")?;
+    let repository =
+        resolve_project_repository(state.db.pool(), &project, repo_full_name.as_deref()).await?;
+    if repository.is_archived {
+        return Err(archived_repository_error(&repository));
+    }
+    let full_name = repository.repo_full_name.as_str();
 
     let issue_status: IssueStatus = status
         .as_ref()
         .and_then(|s| s.parse().ok())
         .unwrap_or(IssueStatus::Backlog);
 
-    let issue_priority: Option<crate::database::models::project::IssuePriority> =
-        priority.as_ref().and_then(|p| p.parse().ok());
+    let issue_priority: Option<IssuePriority> = priority.as_ref().and_then(|p| p.parse().ok());
+
+    // Template labels / assignees come on top of the status and priority
+    // labels (status / priority labels among them are dropped so the
+    // kanban columns stay authoritative).
+    let extra_labels: Vec<String> = labels
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|l| IssueStatus::from_label(l).is_none() && IssuePriority::from_label(l).is_none())
+        .collect();
+    let assignees = assignees.unwrap_or_default();
 
     // Build labels
     let mut labels = vec![issue_status.to_label().to_string()];
     if let Some(p) = &issue_priority {
         labels.push(p.to_label().to_string());
     }
+    for label in &extra_labels {
+        if !labels.contains(label) {
+            labels.push(label.clone());
+        }
+    }
 
     let access_token = get_access_token(&state).await?;
     let client = IssuesClient::new(access_token);
 
-    // Create issue on GitHub
-    let github_issue = map_github_result(
-        &app,
-        state.inner(),
-        client
-            .create_issue(&owner, &repo, &title, body.as_deref(), labels)
-            .await,
-    )
-    .await?;
+    // Create issue on GitHub. When GitHub is unreachable the creation is
+    // queued in `issue_outbox` and a placeholder row is returned instead.
+    let result = client
+        .create_issue(
+            &repository.repo_owner,
+            &repository.repo_name,
+            &title,
+            body.as_deref(),
+            labels,
+            assignees.clone(),
+        )
+        .await;
+    if let Err(e) = &result {
+        if is_offline_error(e) {
+            let payload = CreateIssuePayload {
+                title,
+                body,
+                status: issue_status.to_string(),
+                priority: issue_priority.map(|p| p.to_string()),
+                labels: extra_labels,
+                assignees,
+            };
+            let placeholder = enqueue_issue_creation(
+                state.db.pool(),
+                project.user_id,
+                project_id,
+                full_name,
+                &payload,
+            )
+            .await?;
+            return fetch_cached_issue(state.db.pool(), project_id, full_name, placeholder).await;
+        }
+    }
+    let github_issue = map_github_result(&app, state.inner(), result).await?;
 
     // Cache the new issue
     let now = Utc::now().to_rfc3339();
@@ -1017,14 +1865,15 @@ pub async fn create_github_issue(
     sqlx::query(
         r#"
         INSERT INTO cached_issues (
-            project_id, github_issue_id, number, title, body, state, status, priority,
-            assignee_login, assignee_avatar_url, labels_json, html_url,
+            project_id, repo_full_name, github_issue_id, number, title, body, state, status,
+            priority, assignee_login, assignee_avatar_url, labels_json, html_url,
             github_created_at, github_updated_at, cached_at
         )
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         "#,
     )
     .bind(project_id)
+    .bind(full_name)
     .bind(github_issue.id)
     .bind(github_issue.number)
     .bind(&github_issue.title)
@@ -1044,22 +1893,7 @@ pub async fn create_github_issue(
     .map_err(|e| format!("Failed to cache issue: {}", e))?;
 
     // Fetch and return the cached issue
-    sqlx::query_as(
-        r#"
-        SELECT id, project_id, github_issue_id, number, title, body, state, status, priority,
-               assignee_login, assignee_avatar_url, labels_json, html_url,
-               github_created_at, github_updated_at, cached_at,
-               is_archived, archived_at
-        FROM cached_issues
-        WHERE project_id = ? AND number = ?
-        "#,
-    )
-    .bind(project_id)
-    .bind(github_issue.number)
-    .fetch_optional(state.db.pool())
-    .await
-    .map_err(|e| format!("Failed to fetch issue: {}", e))?
-    .ok_or_else(|| "Issue not found".to_string())
+    fetch_cached_issue(state.db.pool(), project_id, full_name, github_issue.number).await
 }
 
 // ============================================================================
@@ -1432,7 +2266,7 @@ pub async fn get_my_pr_progress_with_cache(
 // ============================================================================
 
 /// Get the current user's ID from the database
-async fn get_current_user_id(state: &State<'_, AppState>) -> Result<i64, String> {
+pub(crate) async fn get_current_user_id(state: &State<'_, AppState>) -> Result<i64, String> {
     let user = state
         .token_manager
         .get_current_user()
@@ -1450,8 +2284,36 @@ async fn get_current_user_id(state: &State<'_, AppState>) -> Result<i64, String>
     Ok(row.get::<i64, _>("id"))
 }
 
+/// Load a single cached issue by `(project_id, repo_full_name, number)`.
+pub(crate) async fn fetch_cached_issue(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    project_id: i64,
+    repo_full_name: &str,
+    number: i32,
+) -> Result<CachedIssue, String> {
+    sqlx::query_as(
+        r#"
+        SELECT id, project_id, repo_full_name, github_issue_id, number, title, body, state,
+               status, priority, assignee_login, assignee_avatar_url, labels_json, html_url,
+               github_created_at, github_updated_at, cached_at,
+               is_archived, archived_at, pending_sync,
+               assignees_json, milestone_number, milestone_title, state_reason,
+               linked_prs_json
+        FROM cached_issues
+        WHERE project_id = ? AND repo_full_name = ? AND number = ?
+        "#,
+    )
+    .bind(project_id)
+    .bind(repo_full_name)
+    .bind(number)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch issue: {}", e))?
+    .ok_or_else(|| "Issue not found".to_string())
+}
+
 /// Get the current user's access token
-async fn get_access_token(state: &State<'_, AppState>) -> Result<String, String> {
+pub(crate) async fn get_access_token(state: &State<'_, AppState>) -> Result<String, String> {
     state
         .token_manager
         .get_access_token()
@@ -1707,16 +2569,35 @@ mod tests {
         .expect("seed project")
         .get::<i64, _>("id");
 
+        sqlx::query(
+            r#"
+            INSERT INTO project_repositories (
+                project_id, github_repo_id, repo_owner, repo_name, repo_full_name, created_at
+            )
+            VALUES (?, ?, ?, ?, ?, '2026-04-01T00:00:00Z')
+            "#,
+        )
+        .bind(project_id)
+        .bind(github_id * 1000)
+        .bind(owner)
+        .bind(repo)
+        .bind(format!("{}/{}", owner, repo))
+        .execute(pool)
+        .await
+        .expect("seed repository");
+
         for n in 1..=2 {
             sqlx::query(
                 r#"
                 INSERT INTO cached_issues (
-                    project_id, github_issue_id, number, title, state, status, cached_at
+                    project_id, repo_full_name, github_issue_id, number, title, state, status,
+                    cached_at
                 )
-                VALUES (?, ?, ?, ?, 'open', 'backlog', '2026-04-01T00:00:00Z')
+                VALUES (?, ?, ?, ?, ?, 'open', 'backlog', '2026-04-01T00:00:00Z')
                 "#,
             )
             .bind(project_id)
+            .bind(format!("{}/{}", owner, repo))
             .bind(github_id * 10_000 + n)
             .bind(n as i32)
             .bind(format!("Test issue {}", n))
@@ -1791,7 +2672,7 @@ mod tests {
     // cached_issues into archived state without touching other projects'
     // rows. This is the partial-sync invariant from Issue #190.
     #[tokio::test]
-    async fn mark_project_repository_gone_archives_target_only() {
+    async fn mark_repository_gone_archives_target_only() {
         let db = Database::in_memory().await.expect("db");
         let pool = db.pool();
 
@@ -1799,7 +2680,7 @@ mod tests {
         let (_user_b, project_b) = seed_project_with_issues(pool, 201, "octo", "alive").await;
 
         let now = "2026-05-10T12:00:00Z";
-        mark_project_repository_gone(pool, project_a, user_a, now)
+        mark_repository_gone(pool, project_a, user_a, "octo/deleted", now)
             .await
             .expect("archive");
 
@@ -1826,17 +2707,17 @@ mod tests {
     // just now" forever and analytics on "time-to-cleanup" would be
     // meaningless.
     #[tokio::test]
-    async fn mark_project_repository_gone_is_idempotent_on_archived_at() {
+    async fn mark_repository_gone_is_idempotent_on_archived_at() {
         let db = Database::in_memory().await.expect("db");
         let pool = db.pool();
         let (user_id, project_id) = seed_project_with_issues(pool, 300, "octo", "deleted").await;
 
         let first = "2026-05-10T12:00:00Z";
         let second = "2026-05-11T15:30:00Z";
-        mark_project_repository_gone(pool, project_id, user_id, first)
+        mark_repository_gone(pool, project_id, user_id, "octo/deleted", first)
             .await
             .expect("first archive");
-        mark_project_repository_gone(pool, project_id, user_id, second)
+        mark_repository_gone(pool, project_id, user_id, "octo/deleted", second)
             .await
             .expect("second archive");
 
@@ -1937,58 +2818,153 @@ mod tests {
         ));
     }
 
-    // PR #213 review feedback (chatgpt-codex P1, third pass):
-    // re-linking to a *different* repository must clear the project's
-    // `cached_issues` rows. Otherwise the kanban keeps showing the
-    // old repo's cards, and `update_issue_status` (which matches on
-    // `(project_id, number)` and calls GitHub with the *new*
-    // owner/repo) could mutate a coincidentally-numbered issue in
-    // the newly linked repository.
+    // Multiple repositories per project: linking a second repository must
+    // add it next to the first — the existing cache stays, and the first
+    // repository remains the primary one on `projects`.
     #[tokio::test]
-    async fn relink_to_different_repo_clears_cached_issues() {
+    async fn linking_second_repo_keeps_cache_and_primary() {
         let db = Database::in_memory().await.expect("db");
         let pool = db.pool();
         let (user_id, project_id) = seed_project_with_issues(pool, 500, "octo", "old").await;
 
-        // Sanity: the seed produced two cached issues against the old
-        // repo (its `github_repo_id` is set to `github_id * 1000` per
-        // the helper — i.e. 500_000).
-        let (_, total) = issues_archive_count(pool, project_id).await;
-        assert_eq!(total, 2);
-
-        // Re-link to a *different* repo (different github_repo_id).
         apply_repository_link(
             pool,
             project_id,
             user_id,
-            999_999, // new repo id, distinct from 500 * 1000
+            999_999,
             "octo",
             "new",
             "octo/new",
             "2026-05-10T20:00:00Z",
         )
         .await
-        .expect("relink");
+        .expect("link second");
+
+        let repositories = list_project_repositories(pool, project_id)
+            .await
+            .expect("repositories");
+        let names: Vec<&str> = repositories
+            .iter()
+            .map(|r| r.repo_full_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["octo/old", "octo/new"]);
+
+        let primary: String =
+            sqlx::query_scalar("SELECT repo_full_name FROM projects WHERE id = ?")
+                .bind(project_id)
+                .fetch_one(pool)
+                .await
+                .expect("project");
+        assert_eq!(primary, "octo/old");
+
+        let (_, total) = issues_archive_count(pool, project_id).await;
+        assert_eq!(total, 2, "first repository's cache must be kept");
+    }
+
+    // PR #213 review feedback (chatgpt-codex P1, third pass), kept for
+    // multi-repo projects: a *different* repository (new github_repo_id)
+    // under an already linked name must clear that name's cached_issues.
+    // Otherwise `update_issue_status` (which matches on `(project_id,
+    // repo_full_name, number)`) could mutate a coincidentally-numbered
+    // issue in the recreated repository.
+    #[tokio::test]
+    async fn recreated_repo_under_same_name_clears_its_cache() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (user_id, project_id) = seed_project_with_issues(pool, 510, "octo", "app").await;
 
-        // The project must now point at the new repo and be unarchived.
-        let project_row = sqlx::query(
-            "SELECT github_repo_id, repo_owner, repo_name, is_archived FROM projects WHERE id = ?",
+        apply_repository_link(
+            pool,
+            project_id,
+            user_id,
+            777_777, // distinct from the seeded 510 * 1000
+            "octo",
+            "app",
+            "octo/app",
+            "2026-05-10T20:00:00Z",
         )
-        .bind(project_id)
-        .fetch_one(pool)
         .await
-        .expect("project");
-        assert_eq!(project_row.get::<i64, _>("github_repo_id"), 999_999);
-        assert_eq!(project_row.get::<String, _>("repo_owner"), "octo");
-        assert_eq!(project_row.get::<String, _>("repo_name"), "new");
+        .expect("relink");
+
+        let project_row =
+            sqlx::query("SELECT github_repo_id, is_archived FROM projects WHERE id = ?")
+                .bind(project_id)
+                .fetch_one(pool)
+                .await
+                .expect("project");
+        assert_eq!(project_row.get::<i64, _>("github_repo_id"), 777_777);
         assert_eq!(project_row.get::<i64, _>("is_archived"), 0);
 
-        // The stale cached_issues from the old repo must be gone so a
-        // drag on a stale card can't be routed to the new repo.
+        let repositories = list_project_repositories(pool, project_id)
+            .await
+            .expect("repositories");
+        assert_eq!(repositories.len(), 1);
+        assert_eq!(repositories[0].github_repo_id, 777_777);
+
         let (_, total_after) = issues_archive_count(pool, project_id).await;
         assert_eq!(total_after, 0, "stale cached_issues must be cleared");
     }
 
+    // Issue #190 per repository: a gone repository archives its own issues
+    // only; the project stays active while another repository is live, and
+    // is archived once the last one goes.
+    #[tokio::test]
+    async fn archive_state_is_tracked_per_repository() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (user_id, project_id) = seed_project_with_issues(pool, 520, "octo", "api").await;
+        apply_repository_link(
+            pool,
+            project_id,
+            user_id,
+            888_888,
+            "octo",
+            "web",
+            "octo/web",
+            "2026-05-10T20:00:00Z",
+        )
+        .await
+        .expect("link second");
+
+        let now = "2026-05-11T12:00:00Z";
+        mark_repository_gone(pool, project_id, user_id, "octo/api", now)
+            .await
+            .expect("archive api");
+        let (is_archived, _, _) = project_archive_state(pool, project_id).await;
+        assert!(!is_archived, "project stays active while octo/web is live");
+        let (archived, total) = issues_archive_count(pool, project_id).await;
+        assert_eq!((archived, total), (2, 2));
+
+        mark_repository_gone(pool, project_id, user_id, "octo/web", now)
+            .await
+            .expect("archive web");
+        let (is_archived, archived_at, _) = project_archive_state(pool, project_id).await;
+        assert!(is_archived);
+        assert_eq!(archived_at.as_deref(), Some(now));
+
+        // Unlinking the gone repository leaves a project with one
+        // (archived) repository; unlinking that too leaves it unlinked
+        // and active.
+        apply_repository_unlink(pool, project_id, user_id, "octo/api", now)
+            .await
+            .expect("unlink api");
+        let primary: Option<String> =
+            sqlx::query_scalar("SELECT repo_full_name FROM projects WHERE id = ?")
+                .bind(project_id)
+                .fetch_one(pool)
+                .await
+                .expect("project");
+        assert_eq!(primary.as_deref(), Some("octo/web"));
+        let (_, total) = issues_archive_count(pool, project_id).await;
+        assert_eq!(total, 0, "unlinked repository's issues are removed");
+
+        apply_repository_unlink(pool, project_id, user_id, "octo/web", now)
+            .await
+            .expect("unlink web");
+        let (is_archived, _, _) = project_archive_state(pool, project_id).await;
+        assert!(!is_archived);
+    }
+
     // First-time link (`github_repo_id` was null) must NOT delete cache —
     // there is no old repo to be stale, and an idempotent flow needs to
     // be safe to call.
@@ -2082,7 +3058,7 @@ mod tests {
     // refused on the backend side too, not just hidden from the UI.
     // We can't easily exercise the full Tauri command (`update_issue_
     // status` needs a Tauri runtime), so we cover the underlying
-    // invariant: after `mark_project_repository_gone`, the same
+    // invariant: after `mark_repository_gone`, the same
     // `get_project`-style SELECT used by the command sees
     // `is_archived = true`. The command's check is a single
     // `if project.is_archived { return Err(...); }`, so the SELECT
@@ -2093,9 +3069,15 @@ mod tests {
         let pool = db.pool();
         let (user_id, project_id) = seed_project_with_issues(pool, 800, "octo", "deleted").await;
 
-        mark_project_repository_gone(pool, project_id, user_id, "2026-05-10T20:00:00Z")
-            .await
-            .expect("archive");
+        mark_repository_gone(
+            pool,
+            project_id,
+            user_id,
+            "octo/deleted",
+            "2026-05-10T20:00:00Z",
+        )
+        .await
+        .expect("archive");
 
         // Mirror the SELECT shape used by `get_project` — the command's
         // archive guard reads `project.is_archived` from this row. A
@@ -2194,4 +3176,196 @@ mod tests {
         assert!(!issues[0].is_archived);
         assert!(issues[0].archived_at.is_none());
     }
+
+    fn make_github_issue(id: i64, number: i32, updated_at: &str) -> GitHubIssue {
+        let updated = DateTime::parse_from_rfc3339(updated_at)
+            .unwrap()
+            .with_timezone(&Utc);
+        GitHubIssue {
+            id,
+            number,
+            title: format!("issue {}", number),
+            body: None,
+            state: "open".into(),
+            state_reason: None,
+            html_url: format!("https://github.com/octo/test/issues/{}", number),
+            labels: vec![],
+            assignee: None,
+            assignees: vec![],
+            milestone: None,
+            created_at: updated,
+            updated_at: updated,
+            closed_at: None,
+        }
+    }
+
+    #[test]
+    fn next_since_cursor_tracks_newest_update() {
+        let issues = vec![
+            make_github_issue(1, 1, "2026-05-01T10:00:00Z"),
+            make_github_issue(2, 2, "2026-05-03T08:30:00+09:00"),
+        ];
+        assert_eq!(
+            next_since_cursor(None, &issues).as_deref(),
+            Some("2026-05-02T23:30:00Z")
+        );
+        // An empty page keeps the previous cursor.
+        assert_eq!(
+            next_since_cursor(Some("2026-05-02T23:30:00Z"), &[]).as_deref(),
+            Some("2026-05-02T23:30:00Z")
+        );
+        assert_eq!(next_since_cursor(None, &[]), None);
+    }
+
+    #[test]
+    fn next_since_cursor_never_moves_backwards() {
+        let issues = vec![make_github_issue(1, 1, "2026-04-01T00:00:00Z")];
+        assert_eq!(
+            next_since_cursor(Some("2026-05-01T00:00:00Z"), &issues).as_deref(),
+            Some("2026-05-01T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn missing_cached_issues_returns_rows_absent_from_listing() {
+        let fetched = vec![
+            make_github_issue(10, 1, "2026-05-01T00:00:00Z"),
+            make_github_issue(30, 3, "2026-05-01T00:00:00Z"),
+        ];
+        let cached = vec![(10, 1), (20, 2), (30, 3), (40, 4)];
+        assert_eq!(
+            missing_cached_issues(&cached, &fetched),
+            vec![(20, 2), (40, 4)]
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_cursor_round_trips_and_defaults() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (_user_id, project_id) = seed_project_with_issues(pool, 600, "octo", "cursor").await;
+
+        let initial = load_project_sync_cursor(pool, project_id, "octo/cursor")
+            .await
+            .expect("load");
+        assert_eq!(initial.project_id, project_id);
+        assert_eq!(initial.repo_full_name, "octo/cursor");
+        assert!(initial.since_cursor.is_none());
+
+        let cursor = ProjectSyncCursor {
+            project_id,
+            repo_full_name: "octo/cursor".into(),
+            since_cursor: Some("2026-05-01T00:00:00Z".into()),
+            etag: Some("W/\"abc\"".into()),
+            last_full_sync_at: Some("2026-05-01T00:00:00Z".into()),
+            last_incremental_sync_at: Some("2026-05-01T00:00:00Z".into()),
+        };
+        save_project_sync_cursor(pool, &cursor).await.expect("save");
+        save_project_sync_cursor(
+            pool,
+            &ProjectSyncCursor {
+                etag: None,
+                ..cursor.clone()
+            },
+        )
+        .await
+        .expect("upsert");
+
+        let loaded = load_project_sync_cursor(pool, project_id, "octo/cursor")
+            .await
+            .expect("reload");
+        assert_eq!(loaded.since_cursor, cursor.since_cursor);
+        assert!(loaded.etag.is_none());
+
+        // Cursors are per repository.
+        let other = load_project_sync_cursor(pool, project_id, "octo/other")
+            .await
+            .expect("load other");
+        assert!(other.since_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn relink_resets_sync_cursor() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (user_id, project_id) = seed_project_with_issues(pool, 700, "octo", "same").await;
+
+        save_project_sync_cursor(
+            pool,
+            &ProjectSyncCursor {
+                project_id,
+                repo_full_name: "octo/same".into(),
+                since_cursor: Some("2026-05-01T00:00:00Z".into()),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("save");
+
+        // Re-linking even the same repo must force the next sync to be a
+        // full listing.
+        apply_repository_link(
+            pool,
+            project_id,
+            user_id,
+            700_000,
+            "octo",
+            "same",
+            "octo/same",
+            "2026-05-10T20:00:00Z",
+        )
+        .await
+        .expect("relink");
+
+        let cursor = load_project_sync_cursor(pool, project_id, "octo/same")
+            .await
+            .expect("load");
+        assert!(cursor.since_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn linked_pull_requests_round_trip_and_clear() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (_, project_id) = seed_project_with_issues(pool, 800, "octo", "prs").await;
+
+        let pr = LinkedPullRequest {
+            number: 40,
+            title: "Fix it".into(),
+            url: "https://github.com/octo/prs/pull/40".into(),
+            repo_full_name: "octo/prs".into(),
+            state: "OPEN".into(),
+            is_draft: false,
+            mergeable: "MERGEABLE".into(),
+            review_decision: Some("APPROVED".into()),
+            checks_state: Some("SUCCESS".into()),
+            closes_issue: true,
+        };
+        save_linked_pull_requests(
+            pool,
+            project_id,
+            "octo/prs",
+            &[(1, vec![pr.clone()]), (2, vec![])],
+        )
+        .await
+        .expect("save");
+
+        let issue = fetch_cached_issue(pool, project_id, "octo/prs", 1)
+            .await
+            .expect("fetch");
+        assert_eq!(issue.get_linked_prs(), vec![pr]);
+        let issue = fetch_cached_issue(pool, project_id, "octo/prs", 2)
+            .await
+            .expect("fetch");
+        assert!(issue.linked_prs_json.is_none());
+
+        // A later run where the link is gone clears it.
+        save_linked_pull_requests(pool, project_id, "octo/prs", &[(1, vec![])])
+            .await
+            .expect("clear");
+        let issue = fetch_cached_issue(pool, project_id, "octo/prs", 1)
+            .await
+            .expect("fetch");
+        assert!(issue.get_linked_prs().is_empty());
+    }
 }
@@ -0,0 +1,494 @@
+//! Project milestone commands
+//!
+//! `sync_project_issues` calls [`sync_project_milestones`] to mirror the
+//! repository's milestones into `project_milestones` and record today's
+//! counts in `milestone_daily_snapshots` (migration v24). The dashboard
+//! reads them back with `get_project_milestones` and
+//! `get_milestone_burndown`; the kanban filter by milestone lives in
+//! `get_kanban_board`. Milestone numbers are per repository, so only the
+//! project's primary repository is mirrored.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/commands/mod.rs
+//!   └─ src-tauri/src/commands/issues.rs   (sync_project_issues_inner)
+//! Dependencies:
+//!   ├─ src-tauri/src/commands/issues.rs   (get_project)
+//!   ├─ src-tauri/src/database/models/milestone.rs
+//!   └─ src-tauri/src/github/issues.rs     (IssuesClient, GitHubMilestone)
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{Pool, Sqlite};
+use tauri::State;
+
+use crate::commands::issues::get_project;
+use crate::commands::AppState;
+use crate::database::models::milestone::{
+    compute_burndown, MilestoneBurndown, MilestoneIssueDates, MilestoneSnapshot, ProjectMilestone,
+};
+use crate::github::client::GitHubResult;
+use crate::github::issues::{GitHubMilestone, IssuesClient};
+
+/// Milestone pages fetched per sync (100 each).
+const MAX_MILESTONE_PAGES: i32 = 3;
+
+/// Date part of a stored RFC 3339 timestamp.
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc).date_naive())
+        .ok()
+}
+
+/// Fetch every milestone of the repository. The flag is false when the
+/// page cap cut the list short.
+async fn fetch_all_milestones(
+    client: &IssuesClient,
+    owner: &str,
+    repo: &str,
+) -> GitHubResult<(Vec<GitHubMilestone>, bool)> {
+    let mut milestones = Vec::new();
+    for page in 1..=MAX_MILESTONE_PAGES {
+        let batch = client.get_all_milestones(owner, repo, 100, page).await?;
+        let last_page = batch.len() < 100;
+        milestones.extend(batch);
+        if last_page {
+            return Ok((milestones, true));
+        }
+    }
+    Ok((milestones, false))
+}
+
+/// Replace the project's milestones with `milestones` (deleting the ones
+/// that are gone when the list is `complete`) and snapshot today's counts.
+pub(crate) async fn save_project_milestones(
+    pool: &Pool<Sqlite>,
+    project_id: i64,
+    milestones: &[GitHubMilestone],
+    complete: bool,
+    now: DateTime<Utc>,
+) -> Result<(), String> {
+    let synced_at = now.to_rfc3339();
+    let today = now.date_naive().format("%Y-%m-%d").to_string();
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    for m in milestones {
+        sqlx::query(
+            r#"
+            INSERT INTO project_milestones (
+                project_id, github_milestone_id, number, title, description, state, due_on,
+                open_issues, closed_issues, github_created_at, github_closed_at, synced_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(project_id, number) DO UPDATE SET
+                github_milestone_id = excluded.github_milestone_id,
+                title = excluded.title,
+                description = excluded.description,
+                state = excluded.state,
+                due_on = excluded.due_on,
+                open_issues = excluded.open_issues,
+                closed_issues = excluded.closed_issues,
+                github_created_at = excluded.github_created_at,
+                github_closed_at = excluded.github_closed_at,
+                synced_at = excluded.synced_at
+            "#,
+        )
+        .bind(project_id)
+        .bind(m.id)
+        .bind(m.number)
+        .bind(&m.title)
+        .bind(&m.description)
+        .bind(&m.state)
+        .bind(m.due_on.map(|t| t.to_rfc3339()))
+        .bind(m.open_issues)
+        .bind(m.closed_issues)
+        .bind(m.created_at.map(|t| t.to_rfc3339()))
+        .bind(m.closed_at.map(|t| t.to_rfc3339()))
+        .bind(&synced_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to save milestone: {}", e))?;
+
+        // Closed milestones keep the snapshots they have; their counts no
+        // longer move.
+        if m.state == "open" {
+            sqlx::query(
+                r#"
+                INSERT INTO milestone_daily_snapshots
+                    (project_id, milestone_number, snapshot_date, open_issues, closed_issues)
+                VALUES (?, ?, ?, ?, ?)
+                ON CONFLICT(project_id, milestone_number, snapshot_date) DO UPDATE SET
+                    open_issues = excluded.open_issues,
+                    closed_issues = excluded.closed_issues
+                "#,
+            )
+            .bind(project_id)
+            .bind(m.number)
+            .bind(&today)
+            .bind(m.open_issues)
+            .bind(m.closed_issues)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to save milestone snapshot: {}", e))?;
+        }
+    }
+
+    if complete {
+        // Rows not touched by this run were deleted on GitHub.
+        sqlx::query("DELETE FROM project_milestones WHERE project_id = ? AND synced_at <> ?")
+            .bind(project_id)
+            .bind(&synced_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to prune milestones: {}", e))?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to commit milestones: {}", e))
+}
+
+/// Mirror the repository's milestones and record today's snapshot. Called
+/// from `sync_project_issues`; the caller treats errors as non-fatal.
+pub(crate) async fn sync_project_milestones(
+    pool: &Pool<Sqlite>,
+    client: &IssuesClient,
+    project_id: i64,
+    owner: &str,
+    repo: &str,
+) -> Result<(), String> {
+    let (milestones, complete) = fetch_all_milestones(client, owner, repo)
+        .await
+        .map_err(|e| e.to_string())?;
+    save_project_milestones(pool, project_id, &milestones, complete, Utc::now()).await
+}
+
+/// Load one milestone of a project.
+async fn load_milestone(
+    pool: &Pool<Sqlite>,
+    project_id: i64,
+    number: i32,
+) -> Result<ProjectMilestone, String> {
+    sqlx::query_as(
+        r#"
+        SELECT id, project_id, github_milestone_id, number, title, description, state, due_on,
+               open_issues, closed_issues, github_created_at, github_closed_at, synced_at
+        FROM project_milestones
+        WHERE project_id = ? AND number = ?
+        "#,
+    )
+    .bind(project_id)
+    .bind(number)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to load milestone: {}", e))?
+    .ok_or_else(|| format!("Milestone {} not found", number))
+}
+
+/// Build the burndown for a milestone from the database.
+pub(crate) async fn load_milestone_burndown(
+    pool: &Pool<Sqlite>,
+    project_id: i64,
+    number: i32,
+    today: NaiveDate,
+) -> Result<MilestoneBurndown, String> {
+    let milestone = load_milestone(pool, project_id, number).await?;
+
+    let issue_rows: Vec<(Option<String>, Option<String>, Option<String>, String)> = sqlx::query_as(
+        r#"
+        SELECT github_created_at, github_closed_at, github_updated_at, state
+        FROM cached_issues
+        WHERE project_id = ? AND milestone_number = ?
+          AND repo_full_name IS (SELECT repo_full_name FROM projects WHERE id = ?)
+        "#,
+    )
+    .bind(project_id)
+    .bind(number)
+    .bind(project_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to load milestone issues: {}", e))?;
+
+    let issues: Vec<MilestoneIssueDates> = issue_rows
+        .into_iter()
+        .filter_map(|(created, closed, updated, state)| {
+            Some(MilestoneIssueDates {
+                created: parse_date(created.as_deref()?)?,
+                // Rows cached before migration v24 have no close time; the
+                // last update is the closest known date (closing an issue
+                // updates it). Without either the row is left out rather
+                // than counted as closed today.
+                closed: match state.as_str() {
+                    "closed" => Some(
+                        closed
+                            .as_deref()
+                            .or(updated.as_deref())
+                            .and_then(parse_date)?,
+                    ),
+                    _ => None,
+                },
+            })
+        })
+        .collect();
+
+    let snapshot_rows: Vec<(String, i64, i64)> = sqlx::query_as(
+        r#"
+        SELECT snapshot_date, open_issues, closed_issues
+        FROM milestone_daily_snapshots
+        WHERE project_id = ? AND milestone_number = ?
+        ORDER BY snapshot_date
+        "#,
+    )
+    .bind(project_id)
+    .bind(number)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to load milestone snapshots: {}", e))?;
+
+    let snapshots: Vec<MilestoneSnapshot> = snapshot_rows
+        .into_iter()
+        .filter_map(|(date, open_issues, closed_issues)| {
+            Some(MilestoneSnapshot {
+                date: NaiveDate::parse_from_str(&date, "%Y-%m-%d").ok()?,
+                open_issues,
+                closed_issues,
+            })
+        })
+        .collect();
+
+    let due = milestone.due_on.as_deref().and_then(parse_date);
+    // Actual values stop when the milestone was closed (or today).
+    let last_observed = milestone
+        .github_closed_at
+        .as_deref()
+        .and_then(parse_date)
+        .map_or(today, |closed| closed.min(today));
+    // The milestone's own start, not its oldest issue: issues are often
+    // filed long before they're scheduled. Its first snapshot (or issue)
+    // only stands in when GitHub didn't report a creation time.
+    let start = milestone
+        .github_created_at
+        .as_deref()
+        .and_then(parse_date)
+        .or_else(|| snapshots.first().map(|s| s.date))
+        .or_else(|| issues.iter().map(|i| i.created).min())
+        .unwrap_or(today)
+        .min(last_observed);
+    let end = due.map_or(last_observed, |due| due.max(last_observed));
+
+    let points = compute_burndown(start, end, last_observed, due, &issues, &snapshots);
+
+    Ok(MilestoneBurndown { milestone, points })
+}
+
+/// Get the project's milestones (open first, by due date)
+#[tauri::command]
+pub async fn get_project_milestones(
+    state: State<'_, AppState>,
+    project_id: i64,
+) -> Result<Vec<ProjectMilestone>, String> {
+    // Verify project belongs to user
+    let _project = get_project(state.clone(), project_id).await?;
+
+    sqlx::query_as(
+        r#"
+        SELECT id, project_id, github_milestone_id, number, title, description, state, due_on,
+               open_issues, closed_issues, github_created_at, github_closed_at, synced_at
+        FROM project_milestones
+        WHERE project_id = ?
+        ORDER BY state = 'closed', due_on IS NULL, due_on, number
+        "#,
+    )
+    .bind(project_id)
+    .fetch_all(state.db.pool())
+    .await
+    .map_err(|e| format!("Failed to get milestones: {}", e))
+}
+
+/// Get the daily burndown / burnup series of a milestone
+#[tauri::command]
+pub async fn get_milestone_burndown(
+    state: State<'_, AppState>,
+    project_id: i64,
+    milestone_number: i32,
+) -> Result<MilestoneBurndown, String> {
+    // Verify project belongs to user
+    let _project = get_project(state.clone(), project_id).await?;
+
+    load_milestone_burndown(
+        state.db.pool(),
+        project_id,
+        milestone_number,
+        Utc::now().date_naive(),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::connection::Database;
+    use crate::database::test_support::seed_user_and_project;
+    use chrono::TimeZone;
+
+    fn milestone(number: i32, state: &str, open: i32, closed: i32) -> GitHubMilestone {
+        GitHubMilestone {
+            id: 1000 + number as i64,
+            number,
+            title: format!("Sprint {}", number),
+            state: state.to_string(),
+            due_on: Some(Utc.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap()),
+            description: None,
+            open_issues: open,
+            closed_issues: closed,
+            created_at: Some(Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap()),
+            closed_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn save_milestones_snapshots_open_ones_and_prunes_deleted() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (_, project_id) = seed_user_and_project(pool).await;
+
+        let day1 = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+        save_project_milestones(
+            pool,
+            project_id,
+            &[milestone(1, "open", 3, 1), milestone(2, "closed", 0, 2)],
+            true,
+            day1,
+        )
+        .await
+        .expect("save");
+
+        let day2 = Utc.with_ymd_and_hms(2026, 3, 3, 9, 0, 0).unwrap();
+        save_project_milestones(pool, project_id, &[milestone(1, "open", 2, 2)], true, day2)
+            .await
+            .expect("save");
+
+        let numbers: Vec<i32> =
+            sqlx::query_scalar("SELECT number FROM project_milestones WHERE project_id = ?")
+                .bind(project_id)
+                .fetch_all(pool)
+                .await
+                .unwrap();
+        assert_eq!(numbers, vec![1]);
+
+        let snapshots: Vec<(i32, String, i64)> = sqlx::query_as(
+            r#"
+            SELECT milestone_number, snapshot_date, open_issues
+            FROM milestone_daily_snapshots ORDER BY snapshot_date
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+        .unwrap();
+        assert_eq!(
+            snapshots,
+            vec![
+                (1, "2026-03-02".to_string(), 3),
+                (1, "2026-03-03".to_string(), 2)
+            ]
+        );
+
+        let burndown = load_milestone_burndown(
+            pool,
+            project_id,
+            1,
+            NaiveDate::from_ymd_opt(2026, 3, 3).unwrap(),
+        )
+        .await
+        .expect("burndown");
+        // 2026-03-01 (created) through 2026-03-10 (due).
+        assert_eq!(burndown.points.len(), 10);
+        assert_eq!(burndown.points[1].open, Some(3));
+        assert_eq!(burndown.points[2].open, Some(2));
+        assert_eq!(burndown.points[3].open, None);
+    }
+
+    #[tokio::test]
+    async fn burndown_starts_at_milestone_and_dates_legacy_closed_rows() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (_, project_id) = seed_user_and_project(pool).await;
+
+        // Created 2026-03-01, due 2026-03-10; closed, so no snapshot is taken.
+        let synced = Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+        save_project_milestones(
+            pool,
+            project_id,
+            &[milestone(1, "closed", 0, 3)],
+            true,
+            synced,
+        )
+        .await
+        .expect("save");
+
+        for (id, created, closed, updated, state) in [
+            // Filed months before the milestone existed
+            (1, "2025-11-01T00:00:00Z", None, None, "open"),
+            // Closed before migration v24: no close time, dated by its last update
+            (
+                2,
+                "2026-03-01T00:00:00Z",
+                None,
+                Some("2026-03-03T12:00:00Z"),
+                "closed",
+            ),
+            // Closed with no date at all: left out
+            (3, "2026-03-01T00:00:00Z", None, None, "closed"),
+            (
+                4,
+                "2026-03-01T00:00:00Z",
+                Some("2026-03-02T08:00:00Z"),
+                Some("2026-03-02T08:00:00Z"),
+                "closed",
+            ),
+        ] {
+            sqlx::query(
+                r#"
+                INSERT INTO cached_issues (
+                    project_id, repo_full_name, github_issue_id, number, title, state, status,
+                    milestone_number, github_created_at, github_closed_at, github_updated_at
+                )
+                VALUES (?, 'octo/app', ?, ?, 'Issue', ?, 'backlog', 1, ?, ?, ?)
+                "#,
+            )
+            .bind(project_id)
+            .bind(9000 + id)
+            .bind(id)
+            .bind(state)
+            .bind(created)
+            .bind(closed)
+            .bind(updated)
+            .execute(pool)
+            .await
+            .expect("seed issue");
+        }
+
+        let burndown = load_milestone_burndown(
+            pool,
+            project_id,
+            1,
+            NaiveDate::from_ymd_opt(2026, 3, 5).unwrap(),
+        )
+        .await
+        .expect("burndown");
+        assert_eq!(burndown.points[0].date, "2026-03-01");
+        assert_eq!(burndown.points.len(), 10);
+
+        let on = |date: &str| {
+            let point = burndown.points.iter().find(|p| p.date == date).unwrap();
+            (point.open, point.closed)
+        };
+        assert_eq!(on("2026-03-01"), (Some(3), Some(0)));
+        assert_eq!(on("2026-03-02"), (Some(2), Some(1)));
+        assert_eq!(on("2026-03-03"), (Some(1), Some(2)));
+        assert_eq!(on("2026-03-05"), (Some(1), Some(2)));
+    }
+}
@@ -1,19 +1,47 @@
 pub mod activity;
 pub mod auth;
 pub mod challenge;
+pub mod contribution_history;
 pub mod gamification;
 pub mod github;
+pub mod heatmap;
+pub mod issue_bulk;
+pub mod issue_comments;
+pub mod issue_edit;
+pub mod issue_outbox;
+pub mod issue_search;
+pub mod issue_templates;
 pub mod issues;
+pub mod milestones;
 pub mod notifications;
+pub mod period_comparison;
+pub mod repo_filter;
 pub mod scheduler;
 pub mod settings;
+pub mod summary_report;
+pub mod time_tracking;
+pub mod workflow_settings;
 
 pub use activity::*;
 pub use auth::*;
 pub use challenge::*;
+pub use contribution_history::*;
 pub use gamification::*;
 pub use github::*;
+pub use heatmap::*;
+pub use issue_bulk::*;
+pub use issue_comments::*;
+pub use issue_edit::*;
+pub use issue_outbox::*;
+pub use issue_search::*;
+pub use issue_templates::*;
 pub use issues::*;
+pub use milestones::*;
 pub use notifications::*;
+pub use period_comparison::*;
+pub use repo_filter::*;
 pub use scheduler::*;
 pub use settings::*;
+pub use summary_report::*;
+pub use time_tracking::*;
+pub use workflow_settings::*;
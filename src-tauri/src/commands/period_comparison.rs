@@ -0,0 +1,112 @@
+//! Period comparison commands
+//!
+//! `get_period_comparison` builds the period-over-period report
+//! shown on the home page: this week vs last week, this month vs last
+//! month or the same month last year, or two custom ranges. Everything is
+//! read from the local database — `github_stats_snapshots`,
+//! `daily_code_stats` and `xp_history` — so the report works offline and
+//! only covers days the app has synced. Snapshot-based metrics are left
+//! unknown when the snapshots cannot be diffed; see
+//! [`PeriodTotals::from_sources`].
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/commands/mod.rs
+//!   ├─ src-tauri/src/commands/github.rs (challenge targets)
+//!   └─ src-tauri/src/commands/summary_report.rs
+//! Dependencies:
+//!   ├─ src-tauri/src/database/models/period_comparison.rs
+//!   ├─ src-tauri/src/database/repository/github_stats_snapshot.rs
+//!   ├─ src-tauri/src/database/repository/code_stats.rs
+//!   └─ src-tauri/src/database/repository/xp_history.rs
+
+use chrono::{Duration, NaiveTime, Utc};
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::database::models::code_stats::CodeStatsSummary;
+use crate::database::models::period_comparison::{
+    PeriodComparison, PeriodComparisonRequest, PeriodRange, PeriodTotals,
+};
+use crate::database::repository::XP_HISTORY_SOURCE_LIVE;
+use crate::database::Database;
+
+/// Collect the totals of one period for a user.
+pub(crate) async fn load_period_totals(
+    db: &Database,
+    user_id: i64,
+    range: &PeriodRange,
+) -> Result<PeriodTotals, String> {
+    let start = range.start.format("%Y-%m-%d").to_string();
+    let end = range.end.format("%Y-%m-%d").to_string();
+
+    let base = db
+        .get_previous_github_stats_snapshot(user_id, &start)
+        .await
+        .map_err(|e| e.to_string())?;
+    let last = db
+        .get_github_stats_snapshot_on_or_before(user_id, &end)
+        .await
+        .map_err(|e| e.to_string())?;
+    let rebaselined = match &base {
+        Some(base) => db
+            .has_github_stats_rebaseline_between(user_id, &base.snapshot_date, &end)
+            .await
+            .map_err(|e| e.to_string())?,
+        None => false,
+    };
+
+    let daily = db
+        .get_daily_code_stats_range(user_id, range.start, range.end)
+        .await
+        .map_err(|e| e.to_string())?;
+    let code = CodeStatsSummary::from_daily_stats(&daily);
+
+    // Whole UTC days, like the snapshot and code stats dates
+    let since = range.start.and_time(NaiveTime::MIN).and_utc();
+    let until = (range.end + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc()
+        - Duration::nanoseconds(1);
+    let xp = db
+        .get_xp_total_in_range(user_id, since, until, XP_HISTORY_SOURCE_LIVE)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(PeriodTotals::from_sources(
+        range,
+        base.as_ref(),
+        last.as_ref(),
+        rebaselined,
+        &code,
+        xp as i64,
+    ))
+}
+
+/// Compare two periods of the current user's activity
+#[tauri::command]
+pub async fn get_period_comparison(
+    state: State<'_, AppState>,
+    request: PeriodComparisonRequest,
+) -> Result<PeriodComparison, String> {
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    let (current, previous) = request.resolve(Utc::now().date_naive())?;
+
+    let current_totals = load_period_totals(&state.db, user.id, &current).await?;
+    let previous_totals = load_period_totals(&state.db, user.id, &previous).await?;
+
+    Ok(PeriodComparison::new(
+        &request.preset,
+        current,
+        previous,
+        &current_totals,
+        &previous_totals,
+    ))
+}
@@ -0,0 +1,326 @@
+//! Repository filter commands
+//!
+//! Per-user repository / organization filter (`repository_filters`,
+//! migration v33) applied to every stats path: XP, badges, code stats,
+//! the language breakdown, today's commits and the activity feed. The
+//! stats commands build their client with [`filtered_github_client`].
+//!
+//! Changing the filter changes the cumulative totals the XP diff is
+//! computed from, so every save marks the filter `rebaseline_pending`:
+//! the next `run_github_sync` stores the filtered totals as the new
+//! baseline without awarding XP for the jump (switching presets back and
+//! forth would otherwise re-award the same activity).
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/commands/mod.rs
+//!   ├─ src-tauri/src/commands/github.rs      (filtered_github_client, rebaseline)
+//!   ├─ src-tauri/src/commands/gamification.rs (filtered_github_client)
+//!   └─ src-tauri/src/commands/activity.rs    (load_repository_filter)
+//! Dependencies:
+//!   ├─ src-tauri/src/database/models/repo_filter.rs
+//!   └─ src-tauri/src/github/client.rs        (GitHubClient::with_repository_filter)
+
+use chrono::Utc;
+use sqlx::{Pool, Row, Sqlite};
+use tauri::State;
+
+use crate::commands::AppState;
+use crate::database::cache_types;
+use crate::database::models::repo_filter::RepositoryFilter;
+use crate::github::GitHubClient;
+
+/// Caches holding filtered data, dropped when the filter changes.
+const FILTERED_CACHE_TYPES: [&str; 5] = [
+    cache_types::GITHUB_STATS,
+    cache_types::LANGUAGES,
+    cache_types::ACTIVITY_FEED,
+    cache_types::TODAY_COMMITS,
+    cache_types::LANGUAGE_BREAKDOWN,
+];
+
+fn parse_list(json: &str, what: &str) -> Result<Vec<String>, String> {
+    serde_json::from_str(json).map_err(|e| format!("Invalid {}: {}", what, e))
+}
+
+fn to_json(list: &[String], what: &str) -> Result<String, String> {
+    serde_json::to_string(list).map_err(|e| format!("Failed to serialize {}: {}", what, e))
+}
+
+/// Saved filter of a user, or the defaults.
+pub(crate) async fn load_repository_filter(
+    pool: &Pool<Sqlite>,
+    user_id: i64,
+) -> Result<RepositoryFilter, String> {
+    let row = sqlx::query(
+        r#"
+        SELECT preset, work_owners_json, include_repositories_json, exclude_repositories_json,
+               include_owners_json, exclude_owners_json, include_forks, include_archived,
+               include_private, updated_at
+        FROM repository_filters
+        WHERE user_id = ?
+        "#,
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to load repository filter: {}", e))?;
+
+    let Some(row) = row else {
+        return Ok(RepositoryFilter::default());
+    };
+
+    Ok(RepositoryFilter {
+        preset: row.get("preset"),
+        work_owners: parse_list(&row.get::<String, _>("work_owners_json"), "work owners")?,
+        include_repositories: parse_list(
+            &row.get::<String, _>("include_repositories_json"),
+            "included repositories",
+        )?,
+        exclude_repositories: parse_list(
+            &row.get::<String, _>("exclude_repositories_json"),
+            "excluded repositories",
+        )?,
+        include_owners: parse_list(
+            &row.get::<String, _>("include_owners_json"),
+            "included owners",
+        )?,
+        exclude_owners: parse_list(
+            &row.get::<String, _>("exclude_owners_json"),
+            "excluded owners",
+        )?,
+        include_forks: row.get("include_forks"),
+        include_archived: row.get("include_archived"),
+        include_private: row.get("include_private"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+/// Save a filter and mark the XP baseline for re-baselining.
+async fn save_repository_filter(
+    pool: &Pool<Sqlite>,
+    user_id: i64,
+    filter: &RepositoryFilter,
+    now: &str,
+) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        INSERT INTO repository_filters (
+            user_id, preset, work_owners_json, include_repositories_json,
+            exclude_repositories_json, include_owners_json, exclude_owners_json,
+            include_forks, include_archived, include_private, rebaseline_pending, updated_at
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 1, ?)
+        ON CONFLICT(user_id) DO UPDATE SET
+            preset = excluded.preset,
+            work_owners_json = excluded.work_owners_json,
+            include_repositories_json = excluded.include_repositories_json,
+            exclude_repositories_json = excluded.exclude_repositories_json,
+            include_owners_json = excluded.include_owners_json,
+            exclude_owners_json = excluded.exclude_owners_json,
+            include_forks = excluded.include_forks,
+            include_archived = excluded.include_archived,
+            include_private = excluded.include_private,
+            rebaseline_pending = 1,
+            updated_at = excluded.updated_at
+        "#,
+    )
+    .bind(user_id)
+    .bind(&filter.preset)
+    .bind(to_json(&filter.work_owners, "work owners")?)
+    .bind(to_json(
+        &filter.include_repositories,
+        "included repositories",
+    )?)
+    .bind(to_json(
+        &filter.exclude_repositories,
+        "excluded repositories",
+    )?)
+    .bind(to_json(&filter.include_owners, "included owners")?)
+    .bind(to_json(&filter.exclude_owners, "excluded owners")?)
+    .bind(filter.include_forks)
+    .bind(filter.include_archived)
+    .bind(filter.include_private)
+    .bind(now)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to save repository filter: {}", e))?;
+    Ok(())
+}
+
+/// Whether the filter changed since the last sync stored its baseline.
+pub(crate) async fn is_rebaseline_pending(
+    pool: &Pool<Sqlite>,
+    user_id: i64,
+) -> Result<bool, String> {
+    let pending: Option<bool> =
+        sqlx::query_scalar("SELECT rebaseline_pending FROM repository_filters WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("Failed to load repository filter: {}", e))?;
+    Ok(pending.unwrap_or(false))
+}
+
+/// Called once the sync has stored the filtered baseline.
+pub(crate) async fn clear_rebaseline_pending(
+    pool: &Pool<Sqlite>,
+    user_id: i64,
+) -> Result<(), String> {
+    sqlx::query("UPDATE repository_filters SET rebaseline_pending = 0 WHERE user_id = ?")
+        .bind(user_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to update repository filter: {}", e))?;
+    Ok(())
+}
+
+/// A GitHub client counting only the repositories the user's filter
+/// allows. Best-effort: an unreadable filter falls back to counting
+/// everything rather than failing the stats command.
+pub(crate) async fn filtered_github_client(
+    state: &AppState,
+    user_id: i64,
+    token: String,
+) -> GitHubClient {
+    let client = GitHubClient::new(token);
+    match load_repository_filter(state.db.pool(), user_id).await {
+        Ok(filter) => client.with_repository_filter(filter),
+        Err(e) => {
+            eprintln!(
+                "Failed to load repository filter, counting all repositories: {}",
+                e
+            );
+            client
+        }
+    }
+}
+
+/// Validate, save and drop the caches holding data from the old filter.
+async fn store_repository_filter(
+    state: &AppState,
+    user_id: i64,
+    filter: RepositoryFilter,
+) -> Result<RepositoryFilter, String> {
+    let filter = filter.normalized();
+    filter.validate()?;
+    let now = Utc::now().to_rfc3339();
+    save_repository_filter(state.db.pool(), user_id, &filter, &now).await?;
+
+    for cache_type in FILTERED_CACHE_TYPES {
+        if let Err(e) = state.db.delete_cache_entry(user_id, cache_type).await {
+            eprintln!("Failed to clear {} cache: {}", cache_type, e);
+        }
+    }
+
+    load_repository_filter(state.db.pool(), user_id).await
+}
+
+async fn current_user_id(state: &AppState) -> Result<i64, String> {
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+    Ok(user.id)
+}
+
+/// Get the user's repository filter (defaults until saved)
+#[tauri::command]
+pub async fn get_repository_filter(state: State<'_, AppState>) -> Result<RepositoryFilter, String> {
+    let user_id = current_user_id(&state).await?;
+    load_repository_filter(state.db.pool(), user_id).await
+}
+
+/// Validate and save the user's repository filter
+#[tauri::command]
+pub async fn update_repository_filter(
+    state: State<'_, AppState>,
+    filter: RepositoryFilter,
+) -> Result<RepositoryFilter, String> {
+    let user_id = current_user_id(&state).await?;
+    store_repository_filter(&state, user_id, filter).await
+}
+
+/// Switch between the "all" / "work" / "personal" presets, keeping the
+/// rest of the filter
+#[tauri::command]
+pub async fn set_repository_filter_preset(
+    state: State<'_, AppState>,
+    preset: String,
+) -> Result<RepositoryFilter, String> {
+    let user_id = current_user_id(&state).await?;
+    let current = load_repository_filter(state.db.pool(), user_id).await?;
+    if current.preset == preset {
+        return Ok(current);
+    }
+    store_repository_filter(&state, user_id, RepositoryFilter { preset, ..current }).await
+}
+
+/// Go back to counting every repository
+#[tauri::command]
+pub async fn reset_repository_filter(
+    state: State<'_, AppState>,
+) -> Result<RepositoryFilter, String> {
+    let user_id = current_user_id(&state).await?;
+    let current = load_repository_filter(state.db.pool(), user_id).await?;
+    let unchanged = RepositoryFilter {
+        updated_at: None,
+        ..current.clone()
+    } == RepositoryFilter::default();
+    if unchanged {
+        return Ok(current);
+    }
+    store_repository_filter(&state, user_id, RepositoryFilter::default()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::connection::Database;
+    use crate::database::models::repo_filter::repo_filter_presets;
+    use crate::database::test_support::seed_user;
+
+    #[tokio::test]
+    async fn test_repository_filter_round_trip_and_rebaseline() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let user_id = seed_user(pool).await;
+
+        let loaded = load_repository_filter(pool, user_id).await.unwrap();
+        assert_eq!(loaded, RepositoryFilter::default());
+        assert!(!is_rebaseline_pending(pool, user_id).await.unwrap());
+
+        let filter = RepositoryFilter {
+            preset: repo_filter_presets::WORK.to_string(),
+            work_owners: vec!["acme".to_string()],
+            exclude_repositories: vec!["acme/legacy".to_string()],
+            include_forks: false,
+            ..Default::default()
+        };
+        save_repository_filter(pool, user_id, &filter, "2026-10-01T00:00:00Z")
+            .await
+            .unwrap();
+        assert!(is_rebaseline_pending(pool, user_id).await.unwrap());
+        clear_rebaseline_pending(pool, user_id).await.unwrap();
+        assert!(!is_rebaseline_pending(pool, user_id).await.unwrap());
+
+        // Upsert keeps a single row and marks the baseline again
+        save_repository_filter(pool, user_id, &filter, "2026-10-02T00:00:00Z")
+            .await
+            .unwrap();
+        assert!(is_rebaseline_pending(pool, user_id).await.unwrap());
+
+        let loaded = load_repository_filter(pool, user_id).await.unwrap();
+        assert_eq!(loaded.updated_at.as_deref(), Some("2026-10-02T00:00:00Z"));
+        assert_eq!(
+            RepositoryFilter {
+                updated_at: None,
+                ..loaded
+            },
+            filter
+        );
+    }
+}
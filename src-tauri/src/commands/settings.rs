@@ -5,7 +5,10 @@
 use serde::{Deserialize, Serialize};
 use tauri::Manager;
 
-use crate::database::models::{ClearCacheResult, DatabaseInfo, NotificationMethod, UserSettings};
+use crate::database::models::{
+    settings_defaults, ChallengeDifficulty, ClearCacheResult, DatabaseInfo, NotificationMethod,
+    UserSettings,
+};
 use crate::sync_scheduler::SyncSchedulerHandle;
 
 use super::AppState;
@@ -31,10 +34,68 @@ pub struct UpdateSettingsRequest {
     pub notify_badge_earned: bool,
     pub notify_streak_update: bool,
     pub notify_streak_milestone: bool,
+    /// Omitted by older clients; keeps the stored preference.
+    #[serde(default)]
+    pub notify_weekly_report: Option<bool>,
     pub sync_interval_minutes: i32,
     pub background_sync: bool,
     pub sync_on_startup: bool,
     pub animations_enabled: bool,
+    /// Omitted by older clients; keeps the stored preference.
+    #[serde(default)]
+    pub challenge_difficulty: Option<String>,
+    /// Omitted by older clients; keeps the stored preference.
+    #[serde(default)]
+    pub prestige_level: Option<i32>,
+    /// Omitted by older clients; keeps the stored preference.
+    #[serde(default)]
+    pub xp_daily_commit_cap: Option<i32>,
+    /// Omitted by older clients; keeps the stored preference.
+    #[serde(default)]
+    pub xp_min_commit_lines: Option<i32>,
+    /// Omitted by older clients; keeps the stored preference.
+    #[serde(default)]
+    pub xp_exclude_bot_commits: Option<bool>,
+    /// Omitted by older clients; keeps the stored preference.
+    #[serde(default)]
+    pub xp_excluded_repositories: Option<Vec<String>>,
+}
+
+/// Validate the excluded repositories setting.
+///
+/// Entries are trimmed and must look like "owner/name"; blank lines and
+/// case-insensitive duplicates are dropped.
+fn normalize_excluded_repositories(repositories: &[String]) -> Result<Vec<String>, String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for repository in repositories {
+        let repository = repository.trim();
+        if repository.is_empty() {
+            continue;
+        }
+        let valid = matches!(
+            repository.split_once('/'),
+            Some((owner, name)) if !owner.is_empty() && !name.is_empty() && !name.contains('/')
+        );
+        if !valid {
+            return Err(format!(
+                "除外リポジトリは owner/name の形式で指定してください: {}",
+                repository
+            ));
+        }
+        if !normalized
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(repository))
+        {
+            normalized.push(repository.to_string());
+        }
+    }
+    if normalized.len() > settings_defaults::MAX_XP_EXCLUDED_REPOSITORIES {
+        return Err(format!(
+            "除外リポジトリは{}件まで指定できます",
+            settings_defaults::MAX_XP_EXCLUDED_REPOSITORIES
+        ));
+    }
+    Ok(normalized)
 }
 
 /// Get user settings
@@ -87,10 +148,40 @@ pub async fn update_settings(
     existing.notify_badge_earned = settings.notify_badge_earned;
     existing.notify_streak_update = settings.notify_streak_update;
     existing.notify_streak_milestone = settings.notify_streak_milestone;
+    if let Some(notify) = settings.notify_weekly_report {
+        existing.notify_weekly_report = notify;
+    }
     existing.sync_interval_minutes = settings.sync_interval_minutes;
     existing.background_sync = settings.background_sync;
     existing.sync_on_startup = settings.sync_on_startup;
     existing.animations_enabled = settings.animations_enabled;
+    if let Some(difficulty) = &settings.challenge_difficulty {
+        existing.challenge_difficulty = ChallengeDifficulty::from_str(difficulty);
+    }
+    if let Some(prestige_level) = settings.prestige_level {
+        if !settings_defaults::PRESTIGE_LEVELS.contains(&prestige_level) {
+            return Err(format!("プレステージレベルが不正です: {}", prestige_level));
+        }
+        existing.prestige_level = prestige_level;
+    }
+    if let Some(cap) = settings.xp_daily_commit_cap {
+        if !(0..=settings_defaults::MAX_XP_DAILY_COMMIT_CAP).contains(&cap) {
+            return Err(format!("1日のコミット上限が不正です: {}", cap));
+        }
+        existing.xp_daily_commit_cap = cap;
+    }
+    if let Some(lines) = settings.xp_min_commit_lines {
+        if !(0..=settings_defaults::MAX_XP_MIN_COMMIT_LINES).contains(&lines) {
+            return Err(format!("最小差分行数が不正です: {}", lines));
+        }
+        existing.xp_min_commit_lines = lines;
+    }
+    if let Some(exclude) = settings.xp_exclude_bot_commits {
+        existing.xp_exclude_bot_commits = exclude;
+    }
+    if let Some(repositories) = &settings.xp_excluded_repositories {
+        existing.xp_excluded_repositories = normalize_excluded_repositories(repositories)?;
+    }
 
     // Save
     let updated = state
@@ -341,4 +432,27 @@ mod tests {
             NotificationMethod::Both
         ); // default
     }
+
+    #[test]
+    fn test_normalize_excluded_repositories() {
+        let input = vec![
+            " octocat/dotfiles ".to_string(),
+            "".to_string(),
+            "Octocat/Dotfiles".to_string(),
+            "org/bot-sandbox".to_string(),
+        ];
+        assert_eq!(
+            normalize_excluded_repositories(&input).unwrap(),
+            vec!["octocat/dotfiles", "org/bot-sandbox"]
+        );
+
+        for invalid in ["dotfiles", "/dotfiles", "octocat/", "a/b/c"] {
+            assert!(normalize_excluded_repositories(&[invalid.to_string()]).is_err());
+        }
+
+        let too_many: Vec<String> = (0..=settings_defaults::MAX_XP_EXCLUDED_REPOSITORIES)
+            .map(|i| format!("octocat/repo-{}", i))
+            .collect();
+        assert!(normalize_excluded_repositories(&too_many).is_err());
+    }
 }
@@ -0,0 +1,334 @@
+//! Summary report commands
+//!
+//! Weekly / monthly "your week in code" reports:
+//! `get_summary_report` returns the report for the home page preview and
+//! `export_summary_report` renders it as Markdown or standalone HTML and
+//! saves it through the dialog plugin. When `notify_weekly_report` is on,
+//! the sync scheduler calls [`deliver_weekly_report_if_due`] so last week's
+//! summary arrives as a notification once per week, from Monday on.
+//!
+//! Reports are built from the local database only (XP history, badges,
+//! challenges, snapshots, daily code stats and the cached language
+//! breakdown), so they work offline.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/commands/mod.rs
+//!   └─ src-tauri/src/sync_scheduler/runner.rs
+//! Dependencies:
+//!   ├─ src-tauri/src/database/models/summary_report.rs
+//!   ├─ src-tauri/src/commands/period_comparison.rs (load_period_totals)
+//!   ├─ src-tauri/src/database/repository/xp_history.rs
+//!   └─ src-tauri/src/utils/notifications.rs
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc};
+use tauri::{AppHandle, State};
+use tauri_plugin_dialog::DialogExt;
+
+use super::period_comparison::load_period_totals;
+use crate::commands::AppState;
+use crate::database::models::summary_report::{
+    level_change, rank_repositories, report_formats, report_periods, report_range, ReportBadge,
+    ReportChallenge, SummaryReport,
+};
+use crate::database::models::UserSettings;
+use crate::database::repository::XP_HISTORY_SOURCE_LIVE;
+use crate::database::Database;
+use crate::database::{badge, cache_types};
+use crate::utils::notifications::send_notification;
+
+/// `sync_metadata.sync_type` recording the last weekly report delivery
+pub const WEEKLY_REPORT_SYNC_TYPE: &str = "weekly_report";
+
+/// Start of a UTC day
+fn day_start(date: NaiveDate) -> DateTime<Utc> {
+    date.and_time(NaiveTime::MIN).and_utc()
+}
+
+/// Build the report of the last complete `period` before `today`.
+pub(crate) async fn build_summary_report(
+    db: &Database,
+    user_id: i64,
+    username: &str,
+    period: &str,
+    today: NaiveDate,
+) -> Result<SummaryReport, String> {
+    let range = report_range(period, today).ok_or_else(|| {
+        format!(
+            "不明なレポート期間です: {}（{} のいずれかを指定してください）",
+            period,
+            report_periods::PERIODS.join(", ")
+        )
+    })?;
+
+    let since = day_start(range.start);
+    let until = day_start(range.end + Duration::days(1)) - Duration::nanoseconds(1);
+    let now = Utc::now();
+
+    let totals = load_period_totals(db, user_id, &range).await?;
+    let xp_by_source = db
+        .get_xp_totals_by_action_in_range(user_id, since, until, XP_HISTORY_SOURCE_LIVE)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let stats = db
+        .get_user_stats(user_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let (total_xp, prestige_xp_offset, current_streak, longest_streak) = stats
+        .map(|s| {
+            (
+                s.total_xp,
+                s.prestige_xp_offset,
+                s.current_streak,
+                s.longest_streak,
+            )
+        })
+        .unwrap_or_default();
+    let xp_since_start = db
+        .get_xp_total_in_range(user_id, since, now, XP_HISTORY_SOURCE_LIVE)
+        .await
+        .map_err(|e| e.to_string())?;
+    let xp_after_end = db
+        .get_xp_total_in_range(user_id, until, now, XP_HISTORY_SOURCE_LIVE)
+        .await
+        .map_err(|e| e.to_string())?;
+    let (level_start, level_end) =
+        level_change(total_xp, prestige_xp_offset, xp_since_start, xp_after_end);
+
+    let in_range = |at: &DateTime<Utc>| *at >= since && *at <= until;
+
+    let definitions = badge::get_all_badge_definitions();
+    let mut badges: Vec<ReportBadge> = db
+        .get_user_badges(user_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|b| in_range(&b.earned_at))
+        .map(|b| {
+            let definition = definitions.iter().find(|d| d.id == b.badge_id);
+            ReportBadge {
+                name: definition
+                    .map(|d| d.name.clone())
+                    .unwrap_or_else(|| b.badge_id.clone()),
+                icon: definition
+                    .map(|d| d.icon.clone())
+                    .unwrap_or_else(|| "🏅".to_string()),
+                rarity: definition
+                    .map(|d| d.rarity.clone())
+                    .unwrap_or_else(|| b.badge_type.clone()),
+                badge_id: b.badge_id,
+                earned_at: b.earned_at,
+            }
+        })
+        .collect();
+    badges.sort_by_key(|b| b.earned_at);
+
+    let mut challenges_completed: Vec<ReportChallenge> = db
+        .get_all_challenges(user_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|c| c.status == "completed")
+        .filter_map(|c| {
+            let completed_at = c.completed_at.filter(|at| in_range(at))?;
+            Some(ReportChallenge {
+                challenge_type: c.challenge_type,
+                target_metric: c.target_metric,
+                target_value: c.target_value,
+                reward_xp: c.reward_xp,
+                completed_at,
+            })
+        })
+        .collect();
+    challenges_completed.sort_by_key(|c| c.completed_at);
+
+    let daily = db
+        .get_daily_code_stats_range(user_id, range.start, range.end)
+        .await
+        .map_err(|e| e.to_string())?;
+    // Primary languages from the cached breakdown, like challenge language
+    // metrics; repositories missing from it are listed without a language
+    let repo_languages: HashMap<String, String> = match db
+        .get_any_cache(user_id, cache_types::LANGUAGE_BREAKDOWN)
+        .await
+    {
+        Ok(Some((json, _, _))) => {
+            serde_json::from_str::<crate::github::types::LanguageBreakdownResponse>(&json)
+                .map(|breakdown| {
+                    breakdown
+                        .repositories
+                        .into_iter()
+                        .filter_map(|r| r.primary_language.map(|l| (r.name_with_owner, l)))
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+        _ => HashMap::new(),
+    };
+    let (top_repositories, languages) = rank_repositories(&daily, &repo_languages);
+
+    Ok(SummaryReport {
+        period: period.to_string(),
+        range,
+        username: username.to_string(),
+        generated_at: now,
+        xp_total: totals.xp,
+        xp_by_source,
+        level_start,
+        level_end,
+        badges,
+        current_streak,
+        longest_streak,
+        active_days: totals.active_days,
+        additions: totals.additions,
+        deletions: totals.deletions,
+        commits: totals.commits,
+        pull_requests_merged: totals.pull_requests_merged,
+        reviews: totals.reviews,
+        top_repositories,
+        languages,
+        challenges_completed,
+    })
+}
+
+/// Get the summary report of the last complete week or month
+#[tauri::command]
+pub async fn get_summary_report(
+    state: State<'_, AppState>,
+    period: Option<String>,
+) -> Result<SummaryReport, String> {
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    let period = period.as_deref().unwrap_or(report_periods::WEEK);
+    build_summary_report(
+        &state.db,
+        user.id,
+        &user.username,
+        period,
+        Utc::now().date_naive(),
+    )
+    .await
+}
+
+/// Render the summary report and save it via a save dialog.
+///
+/// Returns the saved path, or `None` when the dialog was cancelled.
+#[tauri::command]
+pub async fn export_summary_report(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    period: Option<String>,
+    format: String,
+) -> Result<Option<String>, String> {
+    let user = state
+        .token_manager
+        .get_current_user()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Not logged in")?;
+
+    if !report_formats::FORMATS.contains(&format.as_str()) {
+        return Err(format!(
+            "不明なレポート形式です: {}（{} のいずれかを指定してください）",
+            format,
+            report_formats::FORMATS.join(", ")
+        ));
+    }
+
+    let period = period.as_deref().unwrap_or(report_periods::WEEK);
+    let report = build_summary_report(
+        &state.db,
+        user.id,
+        &user.username,
+        period,
+        Utc::now().date_naive(),
+    )
+    .await?;
+    let contents = report.render(&format)?;
+
+    let (filter_name, extension) = if format == report_formats::HTML {
+        ("HTML", "html")
+    } else {
+        ("Markdown", "md")
+    };
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    app.dialog()
+        .file()
+        .set_title("レポートを保存")
+        .set_file_name(report.file_name(&format))
+        .add_filter(filter_name, &[extension])
+        .save_file(move |path| {
+            let _ = tx.send(path);
+        });
+
+    let Some(path) = rx.await.map_err(|e| e.to_string())? else {
+        return Ok(None);
+    };
+    let path = path.into_path().map_err(|e| e.to_string())?;
+    std::fs::write(&path, contents)
+        .map_err(|e| format!("レポートを保存できませんでした: {}", e))?;
+
+    Ok(Some(path.display().to_string()))
+}
+
+/// Send last week's report as a notification once per week.
+///
+/// Called by the sync scheduler on every run; delivers on the first run
+/// from Monday (UTC) on and records the delivery in `sync_metadata`, so
+/// restarts and extra runs the same week do not notify again.
+pub(crate) async fn deliver_weekly_report_if_due(
+    app: &AppHandle,
+    db: &Database,
+    user_id: i64,
+    username: &str,
+    settings: &UserSettings,
+) -> Result<(), String> {
+    if !settings.notify_weekly_report {
+        return Ok(());
+    }
+
+    let now = Utc::now();
+    let today = now.date_naive();
+    let this_monday =
+        day_start(today - Duration::days(today.weekday().num_days_from_monday() as i64));
+
+    let metadata = db
+        .get_or_create_sync_metadata(user_id, WEEKLY_REPORT_SYNC_TYPE)
+        .await
+        .map_err(|e| e.to_string())?;
+    if metadata
+        .last_sync_at_parsed()
+        .is_some_and(|delivered| delivered >= this_monday)
+    {
+        return Ok(());
+    }
+
+    let report = build_summary_report(db, user_id, username, report_periods::WEEK, today).await?;
+    send_notification(
+        app,
+        settings,
+        &format!("📅 {}", report.title()),
+        &report.notification_body(),
+    )?;
+
+    db.update_sync_metadata(
+        user_id,
+        WEEKLY_REPORT_SYNC_TYPE,
+        Some(now.to_rfc3339()),
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .map_err(|e| e.to_string())
+}
@@ -0,0 +1,760 @@
+//! Issue time tracking commands
+//!
+//! Start/stop timers on kanban cards record sessions in
+//! `issue_time_entries` (migration v26). A user has at most one running
+//! timer: starting another one stops it first. Stopping a timer
+//!
+//! - grants time-based XP (`time_tracked_xp`, capped per UTC day), and
+//! - advances active `focus_minutes` challenges,
+//!
+//! the same way the GitHub sync does for its own metrics. Totals per
+//! issue, project and day come from `get_time_summary`, and
+//! `post_issue_time_summary` posts an issue's log as a comment.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   └─ src-tauri/src/commands/mod.rs
+//! Dependencies:
+//!   ├─ src-tauri/src/auth/session.rs          (map_github_result)
+//!   ├─ src-tauri/src/commands/issues.rs       (get_project, get_current_user_id,
+//!   │                                          resolve_project_repository,
+//!   │                                          fetch_cached_issue, get_access_token)
+//!   ├─ src-tauri/src/commands/issue_comments.rs (to_issue_comment, clear_cached_comments)
+//!   ├─ src-tauri/src/database/challenge.rs    (FOCUS_MINUTES_METRIC)
+//!   ├─ src-tauri/src/database/models/time_entry.rs
+//!   ├─ src-tauri/src/database/models/xp.rs    (time_tracked_xp)
+//!   └─ src-tauri/src/github/issues.rs         (IssuesClient)
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, NaiveDate, SecondsFormat, Utc};
+use sqlx::{Pool, Sqlite};
+use tauri::{AppHandle, State};
+
+use crate::auth::map_github_result;
+use crate::commands::issue_comments::{clear_cached_comments, to_issue_comment};
+use crate::commands::issues::{
+    fetch_cached_issue, get_access_token, get_current_user_id, get_project,
+    resolve_project_repository,
+};
+use crate::commands::AppState;
+use crate::database::challenge::FOCUS_MINUTES_METRIC;
+use crate::database::models::issue_comment::IssueComment;
+use crate::database::models::project::CachedIssue;
+use crate::database::models::time_entry::{
+    format_duration, format_time_summary_comment, overlap_seconds, summarize_time_entries,
+    IssueTimeLog, TimeEntry, TimeSummary, MAX_TIME_SUMMARY_DAYS,
+};
+use crate::database::models::xp::{time_tracked_xp, XpActionType};
+use crate::github::issues::IssuesClient;
+
+/// Period `get_time_summary` covers when `days` is omitted.
+const DEFAULT_TIME_SUMMARY_DAYS: i64 = 14;
+
+/// Timestamps are stored as `...Z` so they sort and slice (`substr`) as
+/// plain strings.
+fn timestamp(value: DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Load one entry with the linked issue's current number / title.
+async fn load_time_entry(pool: &Pool<Sqlite>, entry_id: i64) -> Result<TimeEntry, String> {
+    sqlx::query_as(
+        r#"
+        SELECT te.id, te.user_id, te.project_id, te.cached_issue_id,
+               COALESCE(ci.repo_full_name, te.repo_full_name) AS repo_full_name,
+               COALESCE(ci.number, te.issue_number) AS issue_number,
+               COALESCE(ci.title, te.issue_title) AS issue_title,
+               te.started_at, te.ended_at, te.duration_seconds, te.note, te.xp_awarded
+        FROM issue_time_entries te
+        LEFT JOIN cached_issues ci ON ci.id = te.cached_issue_id
+        WHERE te.id = ?
+        "#,
+    )
+    .bind(entry_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch time entry: {}", e))?
+    .ok_or_else(|| "Time entry not found".to_string())
+}
+
+/// The user's running timer, if any.
+pub(crate) async fn load_running_entry(
+    pool: &Pool<Sqlite>,
+    user_id: i64,
+) -> Result<Option<TimeEntry>, String> {
+    sqlx::query_as(
+        r#"
+        SELECT te.id, te.user_id, te.project_id, te.cached_issue_id,
+               COALESCE(ci.repo_full_name, te.repo_full_name) AS repo_full_name,
+               COALESCE(ci.number, te.issue_number) AS issue_number,
+               COALESCE(ci.title, te.issue_title) AS issue_title,
+               te.started_at, te.ended_at, te.duration_seconds, te.note, te.xp_awarded
+        FROM issue_time_entries te
+        LEFT JOIN cached_issues ci ON ci.id = te.cached_issue_id
+        WHERE te.user_id = ? AND te.ended_at IS NULL
+        ORDER BY te.started_at DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch running timer: {}", e))
+}
+
+/// Every entry of one cached issue, newest first.
+pub(crate) async fn load_issue_entries(
+    pool: &Pool<Sqlite>,
+    user_id: i64,
+    cached_issue_id: i64,
+) -> Result<Vec<TimeEntry>, String> {
+    sqlx::query_as(
+        r#"
+        SELECT te.id, te.user_id, te.project_id, te.cached_issue_id,
+               COALESCE(ci.repo_full_name, te.repo_full_name) AS repo_full_name,
+               COALESCE(ci.number, te.issue_number) AS issue_number,
+               COALESCE(ci.title, te.issue_title) AS issue_title,
+               te.started_at, te.ended_at, te.duration_seconds, te.note, te.xp_awarded
+        FROM issue_time_entries te
+        LEFT JOIN cached_issues ci ON ci.id = te.cached_issue_id
+        WHERE te.user_id = ? AND te.cached_issue_id = ?
+        ORDER BY te.started_at DESC
+        "#,
+    )
+    .bind(user_id)
+    .bind(cached_issue_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch time entries: {}", e))
+}
+
+/// Entries overlapping `[from, to)`, optionally of one project. Running
+/// timers are included.
+pub(crate) async fn load_entries_overlapping(
+    pool: &Pool<Sqlite>,
+    user_id: i64,
+    project_id: Option<i64>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<TimeEntry>, String> {
+    sqlx::query_as(
+        r#"
+        SELECT te.id, te.user_id, te.project_id, te.cached_issue_id,
+               COALESCE(ci.repo_full_name, te.repo_full_name) AS repo_full_name,
+               COALESCE(ci.number, te.issue_number) AS issue_number,
+               COALESCE(ci.title, te.issue_title) AS issue_title,
+               te.started_at, te.ended_at, te.duration_seconds, te.note, te.xp_awarded
+        FROM issue_time_entries te
+        LEFT JOIN cached_issues ci ON ci.id = te.cached_issue_id
+        WHERE te.user_id = ? AND (? IS NULL OR te.project_id = ?)
+          AND te.started_at < ? AND (te.ended_at IS NULL OR te.ended_at > ?)
+        ORDER BY te.started_at
+        "#,
+    )
+    .bind(user_id)
+    .bind(project_id)
+    .bind(project_id)
+    .bind(timestamp(to))
+    .bind(timestamp(from))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch time entries: {}", e))
+}
+
+/// Start a timer on `issue`.
+pub(crate) async fn insert_time_entry(
+    pool: &Pool<Sqlite>,
+    user_id: i64,
+    issue: &CachedIssue,
+    now: DateTime<Utc>,
+) -> Result<TimeEntry, String> {
+    let id: i64 = sqlx::query_scalar(
+        r#"
+        INSERT INTO issue_time_entries (
+            user_id, project_id, cached_issue_id, repo_full_name, issue_number, issue_title,
+            started_at
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        RETURNING id
+        "#,
+    )
+    .bind(user_id)
+    .bind(issue.project_id)
+    .bind(issue.id)
+    .bind(&issue.repo_full_name)
+    .bind(issue.number)
+    .bind(&issue.title)
+    .bind(timestamp(now))
+    .fetch_one(pool)
+    .await
+    .map_err(|e| format!("Failed to start timer: {}", e))?;
+
+    load_time_entry(pool, id).await
+}
+
+/// Close a running entry at `now`.
+pub(crate) async fn finish_time_entry(
+    pool: &Pool<Sqlite>,
+    entry: &TimeEntry,
+    note: Option<&str>,
+    now: DateTime<Utc>,
+) -> Result<TimeEntry, String> {
+    let duration_seconds = entry
+        .span(now)
+        .map(|(s, e)| (e - s).num_seconds())
+        .unwrap_or(0);
+    let note = note.map(str::trim).filter(|n| !n.is_empty());
+
+    sqlx::query(
+        r#"
+        UPDATE issue_time_entries
+        SET ended_at = ?, duration_seconds = ?, note = COALESCE(?, note)
+        WHERE id = ? AND ended_at IS NULL
+        "#,
+    )
+    .bind(timestamp(now))
+    .bind(duration_seconds)
+    .bind(note)
+    .bind(entry.id)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to stop timer: {}", e))?;
+
+    load_time_entry(pool, entry.id).await
+}
+
+/// Tracked seconds of the entries that ended on `day` (UTC), and the
+/// time-based XP already granted that day. A session running past midnight
+/// counts for the day it stopped.
+///
+/// Granted XP is read from `xp_history`, which keeps its rows when an
+/// entry is deleted, so deleting entries never frees up cap that was
+/// already paid out.
+pub(crate) async fn tracked_on_day(
+    pool: &Pool<Sqlite>,
+    user_id: i64,
+    day: NaiveDate,
+) -> Result<(i64, i32), String> {
+    sqlx::query_as(
+        r#"
+        SELECT
+            (SELECT COALESCE(SUM(duration_seconds), 0)
+             FROM issue_time_entries
+             WHERE user_id = ?1 AND ended_at IS NOT NULL AND substr(ended_at, 1, 10) = ?2),
+            (SELECT COALESCE(SUM(xp_amount), 0)
+             FROM xp_history
+             WHERE user_id = ?1 AND action_type = ?3 AND substr(created_at, 1, 10) = ?2)
+        "#,
+    )
+    .bind(user_id)
+    .bind(day.to_string())
+    .bind(XpActionType::TimeTracked.as_str())
+    .fetch_one(pool)
+    .await
+    .map_err(|e| format!("Failed to total tracked time: {}", e))
+}
+
+/// Grant the time-based XP a just-finished entry adds to its day.
+async fn award_time_tracked_xp(
+    state: &AppState,
+    entry: &TimeEntry,
+    now: DateTime<Utc>,
+) -> Result<i32, String> {
+    let pool = state.db.pool();
+    let (seconds, awarded) = tracked_on_day(pool, entry.user_id, now.date_naive()).await?;
+    let xp = (time_tracked_xp(seconds / 60) - awarded).max(0);
+    if xp == 0 {
+        return Ok(0);
+    }
+
+    sqlx::query("UPDATE issue_time_entries SET xp_awarded = ? WHERE id = ?")
+        .bind(xp)
+        .bind(entry.id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to record time XP: {}", e))?;
+
+    state
+        .db
+        .record_xp_gain(
+            entry.user_id,
+            XpActionType::TimeTracked.as_str(),
+            xp,
+            Some(&format!(
+                "Tracked {} on #{}",
+                format_duration(entry.duration_seconds),
+                entry.issue_number
+            )),
+            None,
+            None,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    state
+        .db
+        .add_xp(entry.user_id, xp)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(xp)
+}
+
+/// Recount active `focus_minutes` challenges from the entries inside
+/// their period, completing (and rewarding) the ones that reach target.
+async fn update_focus_challenges(
+    state: &AppState,
+    user_id: i64,
+    now: DateTime<Utc>,
+) -> Result<(), String> {
+    let challenges = state
+        .db
+        .get_active_challenges(user_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    for ch in challenges
+        .into_iter()
+        .filter(|c| c.target_metric == FOCUS_MINUTES_METRIC)
+    {
+        let end = ch.end_date.min(now);
+        let entries =
+            load_entries_overlapping(state.db.pool(), user_id, None, ch.start_date, end).await?;
+        let seconds: i64 = entries
+            .iter()
+            .filter(|e| !e.is_running())
+            .filter_map(|e| e.span(now))
+            .map(|(start, stop)| overlap_seconds(start, stop, ch.start_date, end))
+            .sum();
+        let minutes = (seconds / 60) as i32;
+        if minutes <= ch.current_value {
+            continue;
+        }
+
+        let updated = state
+            .db
+            .update_challenge_progress(ch.id, minutes)
+            .await
+            .map_err(|e| e.to_string())?;
+        // Award XP for completing the challenge (active -> completed transition)
+        if ch.status == "active" && updated.status == "completed" {
+            state
+                .db
+                .record_xp_gain(
+                    user_id,
+                    "challenge_completed",
+                    updated.reward_xp,
+                    Some(&format!("Completed {} challenge", updated.challenge_type)),
+                    None,
+                    None,
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+            state
+                .db
+                .add_xp(user_id, updated.reward_xp)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+/// Stop the user's running timer (if any), then grant XP and advance
+/// challenges. Gamification failures are logged, not returned: the time
+/// itself is already saved.
+async fn stop_running_timer(
+    state: &AppState,
+    user_id: i64,
+    note: Option<&str>,
+    now: DateTime<Utc>,
+) -> Result<Option<TimeEntry>, String> {
+    let pool = state.db.pool();
+    let Some(running) = load_running_entry(pool, user_id).await? else {
+        return Ok(None);
+    };
+    let finished = finish_time_entry(pool, &running, note, now).await?;
+
+    if let Err(e) = award_time_tracked_xp(state, &finished, now).await {
+        eprintln!("Failed to award time tracking XP: {}", e);
+    }
+    if let Err(e) = update_focus_challenges(state, user_id, now).await {
+        eprintln!("Failed to update focus challenges: {}", e);
+    }
+
+    load_time_entry(pool, finished.id).await.map(Some)
+}
+
+/// Resolve the cached issue a timer command targets.
+async fn resolve_cached_issue(
+    state: &State<'_, AppState>,
+    project_id: i64,
+    issue_number: i32,
+    repo_full_name: Option<&str>,
+) -> Result<CachedIssue, String> {
+    let pool = state.db.pool();
+    let project = get_project(state.clone(), project_id).await?;
+    let repository = resolve_project_repository(pool, &project, repo_full_name).await?;
+    fetch_cached_issue(pool, project_id, &repository.repo_full_name, issue_number).await
+}
+
+/// Start a timer on an issue. A timer running on another issue is stopped
+/// first; one already running on this issue is returned as-is.
+#[tauri::command]
+pub async fn start_issue_timer(
+    state: State<'_, AppState>,
+    project_id: i64,
+    issue_number: i32,
+    repo_full_name: Option<String>,
+) -> Result<TimeEntry, String> {
+    let user_id = get_current_user_id(&state).await?;
+    let issue =
+        resolve_cached_issue(&state, project_id, issue_number, repo_full_name.as_deref()).await?;
+    let pool = state.db.pool();
+    let now = Utc::now();
+
+    if let Some(running) = load_running_entry(pool, user_id).await? {
+        if running.cached_issue_id == Some(issue.id) {
+            return Ok(running);
+        }
+        stop_running_timer(state.inner(), user_id, None, now).await?;
+    }
+
+    insert_time_entry(pool, user_id, &issue, now).await
+}
+
+/// Stop the running timer. Returns the finished entry (with the XP it
+/// earned), or `None` when no timer was running.
+#[tauri::command]
+pub async fn stop_issue_timer(
+    state: State<'_, AppState>,
+    note: Option<String>,
+) -> Result<Option<TimeEntry>, String> {
+    let user_id = get_current_user_id(&state).await?;
+    stop_running_timer(state.inner(), user_id, note.as_deref(), Utc::now()).await
+}
+
+/// The running timer, if any (restored after an app restart).
+#[tauri::command]
+pub async fn get_running_timer(state: State<'_, AppState>) -> Result<Option<TimeEntry>, String> {
+    let user_id = get_current_user_id(&state).await?;
+    load_running_entry(state.db.pool(), user_id).await
+}
+
+/// Time log of one issue
+#[tauri::command]
+pub async fn get_issue_time_entries(
+    state: State<'_, AppState>,
+    project_id: i64,
+    issue_number: i32,
+    repo_full_name: Option<String>,
+) -> Result<IssueTimeLog, String> {
+    let user_id = get_current_user_id(&state).await?;
+    let issue =
+        resolve_cached_issue(&state, project_id, issue_number, repo_full_name.as_deref()).await?;
+    let entries = load_issue_entries(state.db.pool(), user_id, issue.id).await?;
+    let total_seconds = entries.iter().map(|e| e.duration_seconds).sum();
+
+    Ok(IssueTimeLog {
+        entries,
+        total_seconds,
+    })
+}
+
+/// Delete a time entry (a running one discards the timer). XP already
+/// granted for it is kept, like other XP history, and still counts
+/// against that day's cap (see [`tracked_on_day`]).
+#[tauri::command]
+pub async fn delete_time_entry(state: State<'_, AppState>, entry_id: i64) -> Result<(), String> {
+    let user_id = get_current_user_id(&state).await?;
+    let result = sqlx::query("DELETE FROM issue_time_entries WHERE id = ? AND user_id = ?")
+        .bind(entry_id)
+        .bind(user_id)
+        .execute(state.db.pool())
+        .await
+        .map_err(|e| format!("Failed to delete time entry: {}", e))?;
+
+    if result.rows_affected() == 0 {
+        return Err("Time entry not found".to_string());
+    }
+    Ok(())
+}
+
+/// Totals per issue, project and day over the last `days` days (default
+/// 14, today included). Omit `project_id` for every project.
+#[tauri::command]
+pub async fn get_time_summary(
+    state: State<'_, AppState>,
+    project_id: Option<i64>,
+    days: Option<i64>,
+) -> Result<TimeSummary, String> {
+    let user_id = get_current_user_id(&state).await?;
+    let pool = state.db.pool();
+    let now = Utc::now();
+
+    let days = days
+        .unwrap_or(DEFAULT_TIME_SUMMARY_DAYS)
+        .clamp(1, MAX_TIME_SUMMARY_DAYS);
+    let to = now.date_naive();
+    let from = to - Duration::days(days - 1);
+    let from_start = from
+        .and_hms_opt(0, 0, 0)
+        .map(|t| t.and_utc())
+        .ok_or("Invalid date")?;
+
+    let entries = load_entries_overlapping(pool, user_id, project_id, from_start, now).await?;
+
+    let project_names: HashMap<i64, String> =
+        sqlx::query_as::<_, (i64, String)>("SELECT id, name FROM projects WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| format!("Failed to fetch projects: {}", e))?
+            .into_iter()
+            .collect();
+
+    Ok(summarize_time_entries(
+        &entries,
+        &project_names,
+        from,
+        to,
+        now,
+    ))
+}
+
+/// Post the issue's time log (total and per-day breakdown) as a comment
+#[tauri::command]
+pub async fn post_issue_time_summary(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    project_id: i64,
+    issue_number: i32,
+    repo_full_name: Option<String>,
+) -> Result<IssueComment, String> {
+    if issue_number <= 0 {
+        return Err(
+            "この Issue はまだ GitHub に作成されていません。同期が完了してからコメントしてください。"
+                .to_string(),
+        );
+    }
+
+    let user_id = get_current_user_id(&state).await?;
+    let pool = state.db.pool();
+    let project = get_project(state.clone(), project_id).await?;
+    let repository = resolve_project_repository(pool, &project, repo_full_name.as_deref()).await?;
+    if repository.is_archived {
+        return Err("リポジトリはアーカイブ状態のため、コメントできません。".to_string());
+    }
+    let issue =
+        fetch_cached_issue(pool, project_id, &repository.repo_full_name, issue_number).await?;
+
+    let entries = load_issue_entries(pool, user_id, issue.id).await?;
+    if entries.iter().all(|e| e.is_running()) {
+        return Err("記録された作業時間がありません".to_string());
+    }
+    let body = format_time_summary_comment(&entries);
+
+    let access_token = get_access_token(&state).await?;
+    let client = IssuesClient::new(access_token);
+    let result = client
+        .create_issue_comment(
+            &repository.repo_owner,
+            &repository.repo_name,
+            issue_number,
+            &body,
+        )
+        .await;
+    let comment = map_github_result(&app, state.inner(), result).await?;
+
+    clear_cached_comments(pool, project_id, &repository.repo_full_name, issue_number).await?;
+
+    Ok(to_issue_comment(&comment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::connection::Database;
+    use crate::database::test_support::seed_user_and_project;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    /// Seed a user, a project and issue #7; returns (user_id, project_id).
+    async fn seed(pool: &Pool<Sqlite>) -> (i64, i64) {
+        let (user_id, project_id) = seed_user_and_project(pool).await;
+
+        sqlx::query(
+            r#"
+            INSERT INTO cached_issues (
+                project_id, repo_full_name, github_issue_id, number, title, state, status
+            )
+            VALUES (?, 'octo/app', 700, 7, 'Fix login', 'open', 'todo')
+            "#,
+        )
+        .bind(project_id)
+        .execute(pool)
+        .await
+        .expect("seed issue");
+
+        (user_id, project_id)
+    }
+
+    #[tokio::test]
+    async fn timer_start_stop_round_trip() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (user_id, project_id) = seed(pool).await;
+        let issue = fetch_cached_issue(pool, project_id, "octo/app", 7)
+            .await
+            .unwrap();
+
+        let started = insert_time_entry(pool, user_id, &issue, at("2024-03-01T09:00:00Z"))
+            .await
+            .unwrap();
+        assert!(started.is_running());
+        assert_eq!(started.issue_title, "Fix login");
+        let running = load_running_entry(pool, user_id).await.unwrap().unwrap();
+        assert_eq!(running.id, started.id);
+
+        let finished = finish_time_entry(
+            pool,
+            &running,
+            Some("  pairing  "),
+            at("2024-03-01T09:45:00Z"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(finished.duration_seconds, 45 * 60);
+        assert_eq!(finished.note.as_deref(), Some("pairing"));
+        assert!(load_running_entry(pool, user_id).await.unwrap().is_none());
+
+        let (seconds, xp) = tracked_on_day(pool, user_id, at("2024-03-01T00:00:00Z").date_naive())
+            .await
+            .unwrap();
+        assert_eq!((seconds, xp), (45 * 60, 0));
+    }
+
+    #[tokio::test]
+    async fn deleted_entries_keep_their_xp_counted_against_the_daily_cap() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (user_id, project_id) = seed(pool).await;
+        let issue = fetch_cached_issue(pool, project_id, "octo/app", 7)
+            .await
+            .unwrap();
+
+        // XP rows are stamped with the real clock, so track today.
+        let now = Utc::now();
+        let entry = insert_time_entry(pool, user_id, &issue, now - Duration::minutes(90))
+            .await
+            .unwrap();
+        finish_time_entry(pool, &entry, None, now).await.unwrap();
+        let day = now.date_naive();
+        db.record_xp_gain(
+            user_id,
+            XpActionType::TimeTracked.as_str(),
+            30,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            tracked_on_day(pool, user_id, day).await.unwrap(),
+            (90 * 60, 30)
+        );
+
+        sqlx::query("DELETE FROM issue_time_entries WHERE id = ?")
+            .bind(entry.id)
+            .execute(pool)
+            .await
+            .unwrap();
+        assert_eq!(tracked_on_day(pool, user_id, day).await.unwrap(), (0, 30));
+    }
+
+    #[tokio::test]
+    async fn entries_follow_renumbered_issue_and_survive_its_removal() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (user_id, project_id) = seed(pool).await;
+        let issue = fetch_cached_issue(pool, project_id, "octo/app", 7)
+            .await
+            .unwrap();
+        let entry = insert_time_entry(pool, user_id, &issue, at("2024-03-01T09:00:00Z"))
+            .await
+            .unwrap();
+        finish_time_entry(pool, &entry, None, at("2024-03-01T10:00:00Z"))
+            .await
+            .unwrap();
+
+        // Placeholder promotion renumbers the row in place.
+        sqlx::query("UPDATE cached_issues SET number = 42, title = 'Fix login flow' WHERE id = ?")
+            .bind(issue.id)
+            .execute(pool)
+            .await
+            .unwrap();
+        let entries = load_issue_entries(pool, user_id, issue.id).await.unwrap();
+        assert_eq!(entries[0].issue_number, 42);
+        assert_eq!(entries[0].issue_title, "Fix login flow");
+
+        // Dropping the cached row keeps the time with the copied number.
+        sqlx::query("DELETE FROM cached_issues WHERE id = ?")
+            .bind(issue.id)
+            .execute(pool)
+            .await
+            .unwrap();
+        let entries = load_entries_overlapping(
+            pool,
+            user_id,
+            Some(project_id),
+            at("2024-03-01T00:00:00Z"),
+            at("2024-03-02T00:00:00Z"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].cached_issue_id, None);
+        assert_eq!(entries[0].issue_number, 7);
+        assert_eq!(entries[0].duration_seconds, 60 * 60);
+    }
+
+    #[tokio::test]
+    async fn overlapping_query_includes_running_and_skips_outside() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (user_id, project_id) = seed(pool).await;
+        let issue = fetch_cached_issue(pool, project_id, "octo/app", 7)
+            .await
+            .unwrap();
+
+        let old = insert_time_entry(pool, user_id, &issue, at("2024-02-01T09:00:00Z"))
+            .await
+            .unwrap();
+        finish_time_entry(pool, &old, None, at("2024-02-01T10:00:00Z"))
+            .await
+            .unwrap();
+        let running = insert_time_entry(pool, user_id, &issue, at("2024-03-01T09:00:00Z"))
+            .await
+            .unwrap();
+
+        let entries = load_entries_overlapping(
+            pool,
+            user_id,
+            None,
+            at("2024-03-01T00:00:00Z"),
+            at("2024-03-01T12:00:00Z"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            entries.iter().map(|e| e.id).collect::<Vec<_>>(),
+            vec![running.id]
+        );
+    }
+}
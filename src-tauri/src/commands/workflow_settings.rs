@@ -0,0 +1,320 @@
+//! Actions workflow settings commands
+//!
+//! Per-project settings for the generated "Issue Status Sync" workflow
+//! (`project_workflow_settings`, migration v27): branch naming pattern,
+//! excluded branches, which event moves the issue to which column, and
+//! custom labels. `setup_github_actions` renders the YAML from them.
+//!
+//! `check_workflow_drift` reads the workflow file committed to a linked
+//! repository and compares it with what the current settings generate,
+//! so a hand-edited or outdated file shows up on the dashboard. While a
+//! project has no saved settings, the fixed workflow older versions
+//! committed (`LEGACY_ACTIONS_TEMPLATE`) also counts as in sync.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   └─ src-tauri/src/commands/mod.rs
+//! Dependencies:
+//!   ├─ src-tauri/src/auth/session.rs      (map_github_result)
+//!   ├─ src-tauri/src/commands/issues.rs   (get_project, get_access_token,
+//!   │                                      resolve_project_repository)
+//!   ├─ src-tauri/src/database/models/workflow_settings.rs
+//!   └─ src-tauri/src/github/issues.rs     (IssuesClient, generate_actions_template,
+//!                                          LEGACY_ACTIONS_TEMPLATE)
+
+use chrono::Utc;
+use sqlx::{Pool, Row, Sqlite};
+use tauri::{AppHandle, State};
+
+use crate::auth::map_github_result;
+use crate::commands::issues::{get_access_token, get_project, resolve_project_repository};
+use crate::commands::AppState;
+use crate::database::models::workflow_settings::{
+    diff_workflow, workflow_drift_states, WorkflowDrift, WorkflowEventRule, WorkflowSettings,
+    WORKFLOW_FILE_PATH,
+};
+use crate::github::client::GitHubError;
+use crate::github::issues::{generate_actions_template, IssuesClient, LEGACY_ACTIONS_TEMPLATE};
+
+/// Saved settings of a project, or the defaults.
+pub(crate) async fn load_workflow_settings(
+    pool: &Pool<Sqlite>,
+    project_id: i64,
+) -> Result<WorkflowSettings, String> {
+    let row = sqlx::query(
+        r#"
+        SELECT branch_pattern, issue_number_group, excluded_branches_json, rules_json,
+               close_on_merge, updated_at
+        FROM project_workflow_settings
+        WHERE project_id = ?
+        "#,
+    )
+    .bind(project_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to load workflow settings: {}", e))?;
+
+    let Some(row) = row else {
+        return Ok(WorkflowSettings::default());
+    };
+    let excluded_branches: Vec<String> =
+        serde_json::from_str(&row.get::<String, _>("excluded_branches_json"))
+            .map_err(|e| format!("Invalid excluded branches: {}", e))?;
+    let rules: Vec<WorkflowEventRule> = serde_json::from_str(&row.get::<String, _>("rules_json"))
+        .map_err(|e| format!("Invalid workflow rules: {}", e))?;
+
+    Ok(WorkflowSettings {
+        branch_pattern: row.get("branch_pattern"),
+        issue_number_group: row.get("issue_number_group"),
+        excluded_branches,
+        rules,
+        close_on_merge: row.get("close_on_merge"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+async fn save_workflow_settings(
+    pool: &Pool<Sqlite>,
+    project_id: i64,
+    settings: &WorkflowSettings,
+    now: &str,
+) -> Result<(), String> {
+    let excluded_branches = serde_json::to_string(&settings.excluded_branches)
+        .map_err(|e| format!("Failed to serialize excluded branches: {}", e))?;
+    let rules = serde_json::to_string(&settings.rules)
+        .map_err(|e| format!("Failed to serialize workflow rules: {}", e))?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO project_workflow_settings (
+            project_id, branch_pattern, issue_number_group, excluded_branches_json,
+            rules_json, close_on_merge, updated_at
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT(project_id) DO UPDATE SET
+            branch_pattern = excluded.branch_pattern,
+            issue_number_group = excluded.issue_number_group,
+            excluded_branches_json = excluded.excluded_branches_json,
+            rules_json = excluded.rules_json,
+            close_on_merge = excluded.close_on_merge,
+            updated_at = excluded.updated_at
+        "#,
+    )
+    .bind(project_id)
+    .bind(&settings.branch_pattern)
+    .bind(settings.issue_number_group)
+    .bind(excluded_branches)
+    .bind(rules)
+    .bind(settings.close_on_merge)
+    .bind(now)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to save workflow settings: {}", e))?;
+    Ok(())
+}
+
+/// Get a project's workflow settings (defaults until saved)
+#[tauri::command]
+pub async fn get_workflow_settings(
+    state: State<'_, AppState>,
+    project_id: i64,
+) -> Result<WorkflowSettings, String> {
+    // Ownership check
+    get_project(state.clone(), project_id).await?;
+    load_workflow_settings(state.db.pool(), project_id).await
+}
+
+/// Validate and save a project's workflow settings
+#[tauri::command]
+pub async fn update_workflow_settings(
+    state: State<'_, AppState>,
+    project_id: i64,
+    settings: WorkflowSettings,
+) -> Result<WorkflowSettings, String> {
+    get_project(state.clone(), project_id).await?;
+
+    let settings = settings.normalized();
+    settings.validate()?;
+    let now = Utc::now().to_rfc3339();
+    save_workflow_settings(state.db.pool(), project_id, &settings, &now).await?;
+
+    load_workflow_settings(state.db.pool(), project_id).await
+}
+
+/// Drop a project's saved settings so it uses the defaults again
+#[tauri::command]
+pub async fn reset_workflow_settings(
+    state: State<'_, AppState>,
+    project_id: i64,
+) -> Result<WorkflowSettings, String> {
+    get_project(state.clone(), project_id).await?;
+
+    sqlx::query("DELETE FROM project_workflow_settings WHERE project_id = ?")
+        .bind(project_id)
+        .execute(state.db.pool())
+        .await
+        .map_err(|e| format!("Failed to reset workflow settings: {}", e))?;
+    Ok(WorkflowSettings::default())
+}
+
+/// Render the workflow YAML for unsaved settings (live preview)
+#[tauri::command]
+pub fn preview_workflow(settings: WorkflowSettings) -> Result<String, String> {
+    let settings = settings.normalized();
+    settings.validate()?;
+    Ok(generate_actions_template(&settings))
+}
+
+/// Compare the workflow committed to a linked repository with the one
+/// the project's settings generate
+#[tauri::command]
+pub async fn check_workflow_drift(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    project_id: i64,
+    repo_full_name: Option<String>,
+) -> Result<WorkflowDrift, String> {
+    let project = get_project(state.clone(), project_id).await?;
+    let repository =
+        resolve_project_repository(state.db.pool(), &project, repo_full_name.as_deref()).await?;
+
+    let settings = load_workflow_settings(state.db.pool(), project_id).await?;
+    let expected = generate_actions_template(&settings);
+
+    let access_token = get_access_token(&state).await?;
+    let client = IssuesClient::new(access_token);
+    let actual = match client
+        .get_file_contents(
+            &repository.repo_owner,
+            &repository.repo_name,
+            WORKFLOW_FILE_PATH,
+        )
+        .await
+    {
+        Err(GitHubError::NotFound(_)) => None,
+        result => Some(map_github_result(&app, state.inner(), result).await?),
+    };
+
+    Ok(build_workflow_drift(
+        repository.repo_full_name,
+        expected,
+        actual,
+        settings.updated_at.is_none(),
+    ))
+}
+
+/// `accept_legacy`: the project uses the default settings, so the legacy
+/// fixed workflow is not drift either.
+fn build_workflow_drift(
+    repo_full_name: String,
+    expected: String,
+    actual: Option<String>,
+    accept_legacy: bool,
+) -> WorkflowDrift {
+    let (state, diff) = match actual.as_deref() {
+        None => (workflow_drift_states::MISSING, Vec::new()),
+        Some(actual) => match diff_workflow(&expected, actual) {
+            None => (workflow_drift_states::IN_SYNC, Vec::new()),
+            Some(_)
+                if accept_legacy && diff_workflow(LEGACY_ACTIONS_TEMPLATE, actual).is_none() =>
+            {
+                (workflow_drift_states::IN_SYNC, Vec::new())
+            }
+            Some(diff) => (workflow_drift_states::DRIFTED, diff),
+        },
+    };
+    WorkflowDrift {
+        repo_full_name,
+        path: WORKFLOW_FILE_PATH.to_string(),
+        state: state.to_string(),
+        expected,
+        actual,
+        diff,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::connection::Database;
+    use crate::database::models::project::IssueStatus;
+    use crate::database::test_support::seed_user_and_project;
+
+    #[tokio::test]
+    async fn test_workflow_settings_round_trip() {
+        let db = Database::in_memory().await.expect("db");
+        let pool = db.pool();
+        let (_, project_id) = seed_user_and_project(pool).await;
+
+        let loaded = load_workflow_settings(pool, project_id).await.unwrap();
+        assert_eq!(loaded, WorkflowSettings::default());
+
+        let mut settings = WorkflowSettings {
+            branch_pattern: r"^([0-9]+)/".to_string(),
+            issue_number_group: 1,
+            excluded_branches: vec!["develop".to_string()],
+            close_on_merge: false,
+            ..Default::default()
+        };
+        settings.rules[0].status = Some(IssueStatus::Todo);
+        settings.rules[0].labels = vec!["wip".to_string()];
+        save_workflow_settings(pool, project_id, &settings, "2026-10-01T00:00:00Z")
+            .await
+            .unwrap();
+        // Upsert keeps a single row
+        save_workflow_settings(pool, project_id, &settings, "2026-10-02T00:00:00Z")
+            .await
+            .unwrap();
+
+        let loaded = load_workflow_settings(pool, project_id).await.unwrap();
+        assert_eq!(loaded.updated_at.as_deref(), Some("2026-10-02T00:00:00Z"));
+        assert_eq!(
+            WorkflowSettings {
+                updated_at: None,
+                ..loaded
+            },
+            settings
+        );
+    }
+
+    #[test]
+    fn test_build_workflow_drift_states() {
+        let expected = generate_actions_template(&WorkflowSettings::default());
+
+        let missing = build_workflow_drift("octo/app".into(), expected.clone(), None, true);
+        assert_eq!(missing.state, workflow_drift_states::MISSING);
+
+        let in_sync = build_workflow_drift(
+            "octo/app".into(),
+            expected.clone(),
+            Some(format!("{}\n\n", expected)),
+            true,
+        );
+        assert_eq!(in_sync.state, workflow_drift_states::IN_SYNC);
+        assert!(in_sync.diff.is_empty());
+
+        let edited = expected.replace("status:in-review", "status:review");
+        let drifted = build_workflow_drift("octo/app".into(), expected, Some(edited), true);
+        assert_eq!(drifted.state, workflow_drift_states::DRIFTED);
+        assert!(drifted
+            .diff
+            .iter()
+            .any(|l| l.kind == "removed" && l.text.contains("status:review")));
+    }
+
+    #[test]
+    fn test_legacy_workflow_is_in_sync_only_with_default_settings() {
+        let expected = generate_actions_template(&WorkflowSettings::default());
+        let legacy = Some(LEGACY_ACTIONS_TEMPLATE.to_string());
+
+        let defaults =
+            build_workflow_drift("octo/app".into(), expected.clone(), legacy.clone(), true);
+        assert_eq!(defaults.state, workflow_drift_states::IN_SYNC);
+        assert!(defaults.diff.is_empty());
+
+        // Once settings are saved the file should match what they render
+        let saved = build_workflow_drift("octo/app".into(), expected, legacy, false);
+        assert_eq!(saved.state, workflow_drift_states::DRIFTED);
+    }
+}
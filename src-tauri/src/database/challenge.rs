@@ -3,16 +3,51 @@
 //! This module handles automatic challenge generation based on user activity
 //! and progress updates during GitHub sync.
 
-use chrono::{DateTime, Datelike, Duration, Utc};
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
-/// Current GitHub stats used for challenge progress tracking
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use super::models::{
+    get_challenge_templates, ChallengeDifficulty, ChallengeType, DailyCodeStats, PeriodTotals,
+};
+
+/// Current GitHub stats used for challenge progress tracking.
+///
+/// Stored as the challenge's `start_stats_json` baseline; fields added
+/// later default to zero so older baselines still parse.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ChallengeStats {
     pub commits: i32,
     pub prs: i32,
     pub reviews: i32,
     pub issues: i32,
+    #[serde(default)]
+    pub prs_merged: i32,
+    #[serde(default)]
+    pub issues_closed: i32,
+    /// `daily_code_stats` of the start day as it stood when the challenge
+    /// began, so lines / repositories from earlier that day don't count.
+    #[serde(default)]
+    pub start_day: CodeDayBaseline,
+}
+
+/// Start-day slice of `daily_code_stats` (see `ChallengeStats::start_day`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CodeDayBaseline {
+    pub additions: i32,
+    pub deletions: i32,
+    pub repositories: Vec<String>,
+}
+
+impl From<&DailyCodeStats> for CodeDayBaseline {
+    fn from(day: &DailyCodeStats) -> Self {
+        Self {
+            additions: day.additions,
+            deletions: day.deletions,
+            repositories: day.repositories(),
+        }
+    }
 }
 
 impl ChallengeStats {
@@ -23,6 +58,7 @@ impl ChallengeStats {
             prs,
             reviews,
             issues,
+            ..Default::default()
         }
     }
 
@@ -33,6 +69,8 @@ impl ChallengeStats {
             "prs" => self.prs,
             "reviews" => self.reviews,
             "issues" => self.issues,
+            PRS_MERGED_METRIC => self.prs_merged,
+            ISSUES_CLOSED_METRIC => self.issues_closed,
             _ => 0,
         }
     }
@@ -44,6 +82,10 @@ pub struct ChallengeGeneratorConfig {
     pub weekly_target_multiplier: f32,
     /// Multiplier for daily target
     pub daily_target_multiplier: f32,
+    /// Multiplier for monthly target (based on the 4-week total)
+    pub monthly_target_multiplier: f32,
+    /// Multiplier for quarterly target
+    pub quarterly_target_multiplier: f32,
     /// Minimum target values
     pub min_commits: i32,
     pub min_prs: i32,
@@ -56,6 +98,9 @@ impl Default for ChallengeGeneratorConfig {
         Self {
             weekly_target_multiplier: 1.1,
             daily_target_multiplier: 1.0,
+            monthly_target_multiplier: 1.1,
+            // A quarter is long enough that a flat pace is already a stretch.
+            quarterly_target_multiplier: 1.0,
             min_commits: 1,
             min_prs: 1,
             min_reviews: 1,
@@ -75,6 +120,11 @@ pub struct RecommendedTargets {
     pub weekly_reviews: i32,
     pub daily_issues: i32,
     pub weekly_issues: i32,
+    pub monthly_commits: i32,
+    pub monthly_prs: i32,
+    pub monthly_reviews: i32,
+    pub quarterly_commits: i32,
+    pub quarterly_prs: i32,
 }
 
 /// Challenge template for auto-generation
@@ -84,6 +134,8 @@ pub struct ChallengeTemplate {
     pub target_metric: String,
     pub target_value: i32,
     pub reward_xp: i32,
+    /// Why the target was chosen, stored as the challenge's `target_reason`
+    pub reason: Option<String>,
 }
 
 /// Historical stats for calculating recommended targets
@@ -101,7 +153,44 @@ pub struct HistoricalStats {
     pub active_days_4w: i32,
 }
 
+/// Days of local history `HistoricalStats` is built from
+pub const HISTORY_WINDOW_DAYS: i64 = 28;
+
 impl HistoricalStats {
+    /// Build from the totals of the last `HISTORY_WINDOW_DAYS` days.
+    ///
+    /// Snapshot metrics that couldn't be diffed count as zero. Returns
+    /// `None` when neither the snapshots nor the code stats sync cover the
+    /// window, so callers fall back to the minimum targets.
+    pub fn from_period_totals(totals: &PeriodTotals) -> Option<Self> {
+        let snapshot_metrics = [
+            totals.commits,
+            totals.pull_requests,
+            totals.reviews,
+            totals.issues,
+        ];
+        if snapshot_metrics.iter().all(Option::is_none) && totals.active_days == 0 {
+            return None;
+        }
+        let count = |value: Option<i64>| value.unwrap_or(0).clamp(0, i32::MAX as i64) as i32;
+        let commits_4w = count(totals.commits);
+
+        Some(Self {
+            commits_4w,
+            prs_4w: count(totals.pull_requests),
+            reviews_4w: count(totals.reviews),
+            issues_4w: count(totals.issues),
+            // Without the code stats sync there's no per-day breakdown;
+            // spread the activity over the whole window rather than
+            // treating it as a single day's worth
+            active_days_4w: if totals.active_days == 0 && commits_4w > 0 {
+                HISTORY_WINDOW_DAYS as i32
+            } else {
+                totals.active_days as i32
+            },
+        })
+    }
+
     /// Calculate average daily activity
     pub fn avg_daily(&self) -> (f32, f32, f32, f32) {
         let days = self.active_days_4w.max(1) as f32;
@@ -123,6 +212,17 @@ impl HistoricalStats {
             self.issues_4w as f32 / weeks,
         )
     }
+
+    /// Extrapolate the 4-week totals to a window of `days` days
+    pub fn avg_over_days(&self, days: i64) -> (f32, f32, f32, f32) {
+        let over = |total: i32| (total as i64 * days) as f32 / 28.0;
+        (
+            over(self.commits_4w),
+            over(self.prs_4w),
+            over(self.reviews_4w),
+            over(self.issues_4w),
+        )
+    }
 }
 
 /// Calculate recommended challenge targets based on historical stats
@@ -132,6 +232,9 @@ pub fn calculate_recommended_targets(
 ) -> RecommendedTargets {
     let (daily_commits, daily_prs, daily_reviews, daily_issues) = historical.avg_daily();
     let (weekly_commits, weekly_prs, weekly_reviews, weekly_issues) = historical.avg_weekly();
+    let (monthly_commits, monthly_prs, monthly_reviews, _) =
+        historical.avg_over_days(MONTHLY_PERIOD_DAYS);
+    let (quarterly_commits, quarterly_prs, _, _) = historical.avg_over_days(QUARTERLY_PERIOD_DAYS);
 
     RecommendedTargets {
         daily_commits: ((daily_commits * config.daily_target_multiplier).ceil() as i32)
@@ -149,6 +252,16 @@ pub fn calculate_recommended_targets(
             .max(config.min_issues),
         weekly_issues: ((weekly_issues * config.weekly_target_multiplier).ceil() as i32)
             .max(config.min_issues),
+        monthly_commits: ((monthly_commits * config.monthly_target_multiplier).ceil() as i32)
+            .max(config.min_commits),
+        monthly_prs: ((monthly_prs * config.monthly_target_multiplier).ceil() as i32)
+            .max(config.min_prs),
+        monthly_reviews: ((monthly_reviews * config.monthly_target_multiplier).ceil() as i32)
+            .max(config.min_reviews),
+        quarterly_commits: ((quarterly_commits * config.quarterly_target_multiplier).ceil() as i32)
+            .max(config.min_commits),
+        quarterly_prs: ((quarterly_prs * config.quarterly_target_multiplier).ceil() as i32)
+            .max(config.min_prs),
     }
 }
 
@@ -161,18 +274,21 @@ pub fn generate_default_weekly_challenges() -> Vec<ChallengeTemplate> {
             target_metric: "commits".to_string(),
             target_value: 5,
             reward_xp: 50,
+            reason: None,
         },
         ChallengeTemplate {
             challenge_type: "weekly".to_string(),
             target_metric: "prs".to_string(),
             target_value: 2,
             reward_xp: 80,
+            reason: None,
         },
         ChallengeTemplate {
             challenge_type: "weekly".to_string(),
             target_metric: "reviews".to_string(),
             target_value: 3,
             reward_xp: 60,
+            reason: None,
         },
     ]
 }
@@ -185,6 +301,7 @@ pub fn generate_default_daily_challenges() -> Vec<ChallengeTemplate> {
         target_metric: "commits".to_string(),
         target_value: 1,
         reward_xp: 10,
+        reason: None,
     }]
 }
 
@@ -196,18 +313,21 @@ pub fn generate_weekly_challenges(targets: &RecommendedTargets) -> Vec<Challenge
             target_metric: "commits".to_string(),
             target_value: targets.weekly_commits,
             reward_xp: calculate_reward_xp("commits", targets.weekly_commits),
+            reason: None,
         },
         ChallengeTemplate {
             challenge_type: "weekly".to_string(),
             target_metric: "prs".to_string(),
             target_value: targets.weekly_prs,
             reward_xp: calculate_reward_xp("prs", targets.weekly_prs),
+            reason: None,
         },
         ChallengeTemplate {
             challenge_type: "weekly".to_string(),
             target_metric: "reviews".to_string(),
             target_value: targets.weekly_reviews,
             reward_xp: calculate_reward_xp("reviews", targets.weekly_reviews),
+            reason: None,
         },
     ]
 }
@@ -219,9 +339,156 @@ pub fn generate_daily_challenges(targets: &RecommendedTargets) -> Vec<ChallengeT
         target_metric: "commits".to_string(),
         target_value: targets.daily_commits,
         reward_xp: calculate_reward_xp("commits", targets.daily_commits),
+        reason: None,
     }]
 }
 
+/// Scale a full-period target down to the `window` left of the period
+/// (at least 1). A full period keeps the target as is.
+pub fn prorate_target(target: i32, window: Duration, period_days: i64) -> i32 {
+    let period = Duration::days(period_days).num_seconds();
+    let left = window.num_seconds().clamp(0, period);
+    // Rounded up in integers so an exact share stays exact
+    let prorated = (target as i64 * left + period - 1) / period;
+    (prorated as i32).max(1)
+}
+
+/// Long-term template with its target prorated to `window`
+fn period_template(
+    challenge_type: &str,
+    target_metric: &str,
+    target: i32,
+    window: Duration,
+    period_days: i64,
+) -> ChallengeTemplate {
+    let target_value = prorate_target(target, window, period_days);
+    ChallengeTemplate {
+        challenge_type: challenge_type.to_string(),
+        target_metric: target_metric.to_string(),
+        target_value,
+        reward_xp: calculate_period_reward_xp(target_metric, target_value, window),
+        reason: None,
+    }
+}
+
+/// Generate monthly challenges based on recommended targets.
+///
+/// `window` is what is left of the month (see [`challenge_window`]); a
+/// challenge started late in the month gets a smaller target and reward.
+pub fn generate_monthly_challenges(
+    targets: &RecommendedTargets,
+    window: Duration,
+) -> Vec<ChallengeTemplate> {
+    let template = |metric: &str, target: i32| {
+        period_template("monthly", metric, target, window, MONTHLY_PERIOD_DAYS)
+    };
+    vec![
+        template("commits", targets.monthly_commits),
+        template("prs", targets.monthly_prs),
+        template("reviews", targets.monthly_reviews),
+    ]
+}
+
+/// Generate quarterly challenges based on recommended targets, prorated to
+/// what is left of the quarter like [`generate_monthly_challenges`]
+pub fn generate_quarterly_challenges(
+    targets: &RecommendedTargets,
+    window: Duration,
+) -> Vec<ChallengeTemplate> {
+    let template = |metric: &str, target: i32| {
+        period_template("quarterly", metric, target, window, QUARTERLY_PERIOD_DAYS)
+    };
+    vec![
+        template("commits", targets.quarterly_commits),
+        template("prs", targets.quarterly_prs),
+    ]
+}
+
+/// Length of a full monthly challenge, used for targets and rewards
+pub const MONTHLY_PERIOD_DAYS: i64 = 30;
+
+/// Length of a full quarterly challenge
+pub const QUARTERLY_PERIOD_DAYS: i64 = 91;
+
+/// Longest window a custom challenge may span
+pub const MAX_CUSTOM_PERIOD_DAYS: i64 = 366;
+
+/// Challenge metric counted from issue time entries (minutes tracked in
+/// the challenge period) rather than from GitHub stats. Progress is
+/// updated when a timer stops, not by the GitHub sync.
+pub const FOCUS_MINUTES_METRIC: &str = "focus_minutes";
+
+/// Pull requests merged (GitHub stats `total_prs_merged`)
+pub const PRS_MERGED_METRIC: &str = "prs_merged";
+
+/// Issues closed (GitHub stats `total_issues_closed`)
+pub const ISSUES_CLOSED_METRIC: &str = "issues_closed";
+
+/// Lines added, from `daily_code_stats`
+pub const LINES_ADDED_METRIC: &str = "lines_added";
+
+/// Lines deleted, from `daily_code_stats`
+pub const LINES_DELETED_METRIC: &str = "lines_deleted";
+
+/// Distinct repositories committed to, from `daily_code_stats`
+pub const REPOSITORIES_METRIC: &str = "repositories";
+
+/// Reviews submitted within `FAST_REVIEW_WINDOW_HOURS` of the pull
+/// request being opened
+pub const FAST_REVIEWS_METRIC: &str = "fast_reviews";
+
+/// How soon after a pull request opens a review counts as fast
+pub const FAST_REVIEW_WINDOW_HOURS: i64 = 24;
+
+/// Prefix of the per-language metric, e.g. `language:Rust`: days with a
+/// commit to a repository whose primary language is that language.
+pub const LANGUAGE_METRIC_PREFIX: &str = "language:";
+
+/// Metrics `create_challenge` accepts besides `language:<name>`
+pub const CHALLENGE_METRICS: &[&str] = &[
+    "commits",
+    "prs",
+    "reviews",
+    "issues",
+    FOCUS_MINUTES_METRIC,
+    PRS_MERGED_METRIC,
+    ISSUES_CLOSED_METRIC,
+    LINES_ADDED_METRIC,
+    LINES_DELETED_METRIC,
+    REPOSITORIES_METRIC,
+    FAST_REVIEWS_METRIC,
+];
+
+/// Language name of a `language:<name>` metric
+pub fn language_of_metric(metric: &str) -> Option<&str> {
+    metric
+        .strip_prefix(LANGUAGE_METRIC_PREFIX)
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+}
+
+/// Check a requested target metric
+pub fn validate_target_metric(metric: &str) -> Result<(), String> {
+    if CHALLENGE_METRICS.contains(&metric) || language_of_metric(metric).is_some() {
+        return Ok(());
+    }
+    if metric.starts_with(LANGUAGE_METRIC_PREFIX) {
+        return Err("言語チャレンジには言語名を指定してください (例: language:Rust)".to_string());
+    }
+    Err(format!(
+        "Invalid target metric. Must be one of: {}, language:<name>",
+        CHALLENGE_METRICS.join(", ")
+    ))
+}
+
+/// Whether progress on `metric` needs `daily_code_stats`
+pub fn uses_code_stats(metric: &str) -> bool {
+    matches!(
+        metric,
+        LINES_ADDED_METRIC | LINES_DELETED_METRIC | REPOSITORIES_METRIC
+    ) || language_of_metric(metric).is_some()
+}
+
 /// Calculate reward XP based on target metric and value
 pub fn calculate_reward_xp(target_metric: &str, target_value: i32) -> i32 {
     let base_xp = match target_metric {
@@ -229,11 +496,291 @@ pub fn calculate_reward_xp(target_metric: &str, target_value: i32) -> i32 {
         "prs" => 40,
         "reviews" => 20,
         "issues" => 25,
+        // Time tracked on issues; targets are in minutes.
+        FOCUS_MINUTES_METRIC => 1,
+        PRS_MERGED_METRIC => 50,
+        ISSUES_CLOSED_METRIC => 25,
+        REPOSITORIES_METRIC => 30,
+        FAST_REVIEWS_METRIC => 30,
+        // Line targets run into the hundreds: 1 XP per 10 lines.
+        LINES_ADDED_METRIC | LINES_DELETED_METRIC => return (target_value / 10).max(1),
+        m if language_of_metric(m).is_some() => 20,
         _ => 10,
     };
     base_xp * target_value
 }
 
+/// Bonus multiplier for keeping a goal up over a longer window.
+///
+/// Targets already grow with the window, so this only rewards the
+/// sustained effort: nothing for a week or less, up to 1.5x for a quarter
+/// or more.
+pub fn period_reward_multiplier(period: Duration) -> f32 {
+    let days = period.num_days();
+    if days <= 7 {
+        1.0
+    } else if days <= 31 {
+        1.2
+    } else if days < QUARTERLY_PERIOD_DAYS {
+        1.35
+    } else {
+        1.5
+    }
+}
+
+/// `calculate_reward_xp` scaled by `period_reward_multiplier`
+pub fn calculate_period_reward_xp(target_metric: &str, target_value: i32, period: Duration) -> i32 {
+    (calculate_reward_xp(target_metric, target_value) as f32 * period_reward_multiplier(period))
+        .round() as i32
+}
+
+/// How long an auto-generated challenge of `challenge_type` created at
+/// `now` runs (see [`calculate_challenge_period`])
+pub fn challenge_window(challenge_type: &str, now: DateTime<Utc>) -> Duration {
+    let (start, end) = calculate_challenge_period(challenge_type, now);
+    end - start
+}
+
+/// How many of the most recent finished challenges of one type and metric
+/// feed their difficulty adjustment
+pub const DIFFICULTY_HISTORY_WINDOW: i32 = 5;
+
+/// Fewer finished challenges than this leave the target unadjusted
+const MIN_HISTORY_FOR_ADJUSTMENT: i32 = 2;
+
+/// Recent completed / failed challenges of one type and metric
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MetricRecord {
+    pub completed: i32,
+    pub failed: i32,
+}
+
+impl MetricRecord {
+    pub fn total(&self) -> i32 {
+        self.completed + self.failed
+    }
+
+    /// Share of completed challenges, or `None` with too little history
+    pub fn completion_rate(&self) -> Option<f32> {
+        if self.total() < MIN_HISTORY_FOR_ADJUSTMENT {
+            return None;
+        }
+        Some(self.completed as f32 / self.total() as f32)
+    }
+}
+
+/// Tally `(challenge_type, target_metric, status)` outcomes, newest first,
+/// into records of at most `DIFFICULTY_HISTORY_WINDOW` challenges per
+/// `(challenge_type, target_metric)`.
+///
+/// Types are kept apart: clearing daily commit goals says little about a
+/// monthly one.
+pub fn tally_challenge_outcomes(
+    outcomes: &[(String, String, String)],
+) -> HashMap<(String, String), MetricRecord> {
+    let mut records: HashMap<(String, String), MetricRecord> = HashMap::new();
+    for (challenge_type, metric, status) in outcomes {
+        let record = records
+            .entry((challenge_type.clone(), metric.clone()))
+            .or_default();
+        if record.total() >= DIFFICULTY_HISTORY_WINDOW {
+            continue;
+        }
+        match status.as_str() {
+            "completed" => record.completed += 1,
+            "failed" => record.failed += 1,
+            _ => {}
+        }
+    }
+    records
+}
+
+/// Target scale from a type and metric's record: raise it when the user keeps
+/// clearing it, ease off when they keep missing it
+fn history_adjustment(record: &MetricRecord) -> f32 {
+    match record.completion_rate() {
+        Some(rate) if rate >= 0.8 => 1.15,
+        Some(rate) if rate <= 0.4 => 0.85,
+        _ => 1.0,
+    }
+}
+
+/// Apply the difficulty preference and the completion record of the
+/// template's type and metric to
+/// a generated template, recomputing its reward and recording the reason.
+///
+/// `has_history` tells whether the template's base target came from
+/// `HistoricalStats` or from the minimum targets, for the reason text.
+/// `window` is how long the challenge will run, for the reward bonus.
+pub fn adapt_template(
+    template: ChallengeTemplate,
+    record: MetricRecord,
+    difficulty: ChallengeDifficulty,
+    has_history: bool,
+    window: Duration,
+) -> ChallengeTemplate {
+    let adjustment = history_adjustment(&record);
+    let target_value =
+        ((template.target_value as f32 * adjustment * difficulty.target_multiplier()).ceil()
+            as i32)
+            .max(1);
+    let base_xp = calculate_period_reward_xp(&template.target_metric, target_value, window);
+    let reward_xp = ((base_xp as f32 * difficulty.reward_multiplier()).round() as i32).max(1);
+
+    let history = match record.completion_rate() {
+        None => "達成履歴が少ないため調整なし".to_string(),
+        Some(_) if adjustment > 1.0 => format!(
+            "直近{}回中{}回達成のため目標を引き上げ",
+            record.total(),
+            record.completed
+        ),
+        Some(_) if adjustment < 1.0 => format!(
+            "直近{}回中{}回達成のため目標を引き下げ",
+            record.total(),
+            record.completed
+        ),
+        Some(_) => format!(
+            "直近{}回中{}回達成のため据え置き",
+            record.total(),
+            record.completed
+        ),
+    };
+    let base = if has_history {
+        format!("過去4週間の実績から基準値{}を算出", template.target_value)
+    } else {
+        format!(
+            "過去4週間の実績がないため最低目標{}から開始",
+            template.target_value
+        )
+    };
+    let reason = format!("{} / {} / 難易度: {}", base, history, difficulty.label());
+
+    ChallengeTemplate {
+        target_value,
+        reward_xp,
+        reason: Some(reason),
+        ..template
+    }
+}
+
+/// `adapt_template` over a batch, looking up each template's
+/// `(challenge_type, target_metric)` record and the window it would run
+/// for if created at `now`
+pub fn adapt_templates(
+    templates: Vec<ChallengeTemplate>,
+    records: &HashMap<(String, String), MetricRecord>,
+    difficulty: ChallengeDifficulty,
+    has_history: bool,
+    now: DateTime<Utc>,
+) -> Vec<ChallengeTemplate> {
+    templates
+        .into_iter()
+        .map(|template| {
+            let record = records
+                .get(&(
+                    template.challenge_type.clone(),
+                    template.target_metric.clone(),
+                ))
+                .copied()
+                .unwrap_or_default();
+            let window = challenge_window(&template.challenge_type, now);
+            adapt_template(template, record, difficulty, has_history, window)
+        })
+        .collect()
+}
+
+/// Challenge types a reroll / skip token can be spent on
+pub const TOKEN_CHALLENGE_TYPES: &[&str] = &["daily", "weekly"];
+
+/// Pick the new goal for a rerolled challenge from `get_challenge_templates`.
+///
+/// Candidates share the challenge type and use a different metric, and
+/// metrics already covered by another active challenge of that type are
+/// avoided while there is any other choice. `seed` selects among them.
+pub fn pick_reroll_template(
+    challenge_type: &str,
+    current_metric: &str,
+    active_metrics: &[&str],
+    seed: usize,
+) -> Option<ChallengeTemplate> {
+    let challenge_type = ChallengeType::parse(challenge_type)?;
+    let candidates: Vec<_> = get_challenge_templates()
+        .into_iter()
+        .filter(|template| template.challenge_type == challenge_type)
+        .filter_map(|template| {
+            let (metric, value) = template.requirement.target()?;
+            (metric != current_metric).then_some((template, metric, value))
+        })
+        .collect();
+    let fresh: Vec<_> = candidates
+        .iter()
+        .filter(|(_, metric, _)| !active_metrics.contains(metric))
+        .collect();
+    let choices: Vec<_> = if fresh.is_empty() {
+        candidates.iter().collect()
+    } else {
+        fresh
+    };
+    if choices.is_empty() {
+        return None;
+    }
+
+    let (template, metric, value) = choices[seed % choices.len()];
+    Some(ChallengeTemplate {
+        challenge_type: challenge_type.to_string(),
+        target_metric: metric.to_string(),
+        target_value: *value,
+        reward_xp: template.base_xp_reward,
+        reason: Some(format!("リロールで入れ替え ({})", template.title)),
+    })
+}
+
+/// First day of the month after `date`
+fn next_month_start(date: NaiveDate) -> NaiveDate {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1).unwrap()
+}
+
+/// First day of the calendar quarter containing `date`
+fn quarter_start(date: NaiveDate) -> NaiveDate {
+    let month = (date.month0() / 3) * 3 + 1;
+    NaiveDate::from_ymd_opt(date.year(), month, 1).unwrap()
+}
+
+/// Resolve the window of a custom challenge from inclusive calendar dates.
+///
+/// The challenge runs from the start of `start` (or `now`, if that is
+/// already past) to the end of `end`, in UTC.
+pub fn calculate_custom_challenge_period(
+    start: NaiveDate,
+    end: NaiveDate,
+    now: DateTime<Utc>,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), String> {
+    if end < start {
+        return Err("終了日は開始日以降の日付を指定してください".to_string());
+    }
+    if (end - start).num_days() + 1 > MAX_CUSTOM_PERIOD_DAYS {
+        return Err(format!(
+            "カスタム期間は最長{}日までです",
+            MAX_CUSTOM_PERIOD_DAYS
+        ));
+    }
+
+    let start_at = start.and_hms_opt(0, 0, 0).unwrap().and_utc().max(now);
+    let end_at = (end + Duration::days(1))
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_utc();
+    if end_at <= now {
+        return Err("終了日が過去の日付です".to_string());
+    }
+    Ok((start_at, end_at))
+}
+
 /// Calculate challenge start and end dates based on type
 pub fn calculate_challenge_period(
     challenge_type: &str,
@@ -256,6 +803,23 @@ pub fn calculate_challenge_period(
             let end = next_monday.and_hms_opt(0, 0, 0).unwrap().and_utc();
             (now, end)
         }
+        "monthly" => {
+            // Monthly challenge: from now to the 1st of next month 00:00 UTC
+            let end = next_month_start(now.date_naive())
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc();
+            (now, end)
+        }
+        "quarterly" => {
+            // Quarterly challenge: from now to the start of the next quarter
+            let this_quarter = quarter_start(now.date_naive());
+            let next_quarter = next_month_start(next_month_start(next_month_start(this_quarter)));
+            let end = next_quarter.and_hms_opt(0, 0, 0).unwrap().and_utc();
+            (now, end)
+        }
+        // Custom challenges carry their own dates; see
+        // `calculate_custom_challenge_period`.
         _ => (now, now + Duration::days(7)), // Default to 7 days
     }
 }
@@ -289,34 +853,173 @@ pub fn should_generate_weekly_challenges(
     }
 }
 
-/// Context for updating challenge progress
-#[derive(Debug, Clone)]
-#[allow(dead_code)]
-pub struct ChallengeProgressContext {
-    pub total_commits: i32,
-    pub total_prs: i32,
-    pub total_reviews: i32,
-    pub total_issues: i32,
+/// Check if it's time to generate new monthly challenges (1st of the month)
+pub fn should_generate_monthly_challenges(
+    last_monthly_challenge_date: Option<NaiveDate>,
+    now: DateTime<Utc>,
+) -> bool {
+    let today = now.date_naive();
+
+    match last_monthly_challenge_date {
+        Some(date) => date < today.with_day(1).unwrap(),
+        None => true, // No challenges yet, generate them
+    }
 }
 
-/// Calculate the current value for a challenge based on GitHub stats diff
-#[allow(dead_code)]
+/// Check if it's time to generate new quarterly challenges (1st of Jan/Apr/Jul/Oct)
+pub fn should_generate_quarterly_challenges(
+    last_quarterly_challenge_date: Option<NaiveDate>,
+    now: DateTime<Utc>,
+) -> bool {
+    match last_quarterly_challenge_date {
+        Some(date) => date < quarter_start(now.date_naive()),
+        None => true, // No challenges yet, generate them
+    }
+}
+
+/// One day of `daily_code_stats`, as used for progress
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeActivityDay {
+    pub date: NaiveDate,
+    pub additions: i32,
+    pub deletions: i32,
+    pub repositories: Vec<String>,
+}
+
+impl CodeActivityDay {
+    pub fn from_daily(day: &DailyCodeStats) -> Option<Self> {
+        Some(Self {
+            date: day.date_as_naive()?,
+            additions: day.additions,
+            deletions: day.deletions,
+            repositories: day.repositories(),
+        })
+    }
+}
+
+/// A review with the time its pull request was opened
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewTurnaround {
+    pub pull_request_created_at: DateTime<Utc>,
+    pub reviewed_at: DateTime<Utc>,
+}
+
+impl ReviewTurnaround {
+    pub fn is_fast(&self) -> bool {
+        self.reviewed_at - self.pull_request_created_at <= Duration::hours(FAST_REVIEW_WINDOW_HOURS)
+    }
+}
+
+/// Period-scoped data that progress on the richer metrics is counted
+/// from. Only loaded when an active challenge needs it; anything left
+/// empty simply counts as no progress.
+#[derive(Debug, Clone, Default)]
+pub struct ChallengeActivity {
+    /// `daily_code_stats` rows covering the challenge periods
+    pub code_days: Vec<CodeActivityDay>,
+    /// Repository full name -> primary language
+    pub repo_languages: HashMap<String, String>,
+    /// Reviews submitted since the earliest challenge start
+    pub reviews: Vec<ReviewTurnaround>,
+}
+
+impl ChallengeActivity {
+    /// Days from the challenge's start day on, with the repositories
+    /// committed to on each. The start day drops repositories already in
+    /// the baseline, i.e. committed to before the challenge began.
+    fn repositories_by_day<'a>(
+        &'a self,
+        start_date: DateTime<Utc>,
+        start: &'a ChallengeStats,
+    ) -> impl Iterator<Item = Vec<&'a str>> + 'a {
+        let start_day = start_date.date_naive();
+        self.code_days
+            .iter()
+            .filter(move |d| d.date >= start_day)
+            .map(move |d| {
+                d.repositories
+                    .iter()
+                    .filter(|r| d.date > start_day || !start.start_day.repositories.contains(r))
+                    .map(String::as_str)
+                    .collect()
+            })
+    }
+
+    fn lines_since(&self, start_date: DateTime<Utc>, field: fn(&CodeActivityDay) -> i32) -> i32 {
+        let start_day = start_date.date_naive();
+        self.code_days
+            .iter()
+            .filter(|d| d.date >= start_day)
+            .map(field)
+            .sum()
+    }
+}
+
+/// Start-day baseline for a challenge starting at `start_date`.
+///
+/// Only a challenge that began part-way through today needs one; a start
+/// at midnight (custom periods) or on an earlier day counts the whole
+/// start day.
+pub fn start_day_baseline(
+    start_date: DateTime<Utc>,
+    now: DateTime<Utc>,
+    today: Option<&DailyCodeStats>,
+) -> CodeDayBaseline {
+    let starts_mid_today =
+        start_date.date_naive() == now.date_naive() && start_date.time() != chrono::NaiveTime::MIN;
+    match today {
+        Some(day) if starts_mid_today => CodeDayBaseline::from(day),
+        _ => CodeDayBaseline::default(),
+    }
+}
+
+/// Calculate the current value of a challenge.
+///
+/// Counter metrics are the difference between the current stats and the
+/// challenge's start-stats baseline; code-stats metrics are summed over
+/// the days since the start day, minus what the start day already held;
+/// fast reviews are counted from `reviewed_at >= start_date`.
 pub fn calculate_progress_for_metric(
     metric: &str,
-    prev_stats: &ChallengeProgressContext,
-    current_stats: &ChallengeProgressContext,
-    challenge_start_stats: Option<&ChallengeProgressContext>,
+    start_date: DateTime<Utc>,
+    start_stats: &ChallengeStats,
+    current_stats: &ChallengeStats,
+    activity: &ChallengeActivity,
 ) -> i32 {
-    // If we have start stats, calculate progress since challenge started
-    // Otherwise, calculate diff from previous sync
-    let base_stats = challenge_start_stats.unwrap_or(prev_stats);
-
     match metric {
-        "commits" => (current_stats.total_commits - base_stats.total_commits).max(0),
-        "prs" => (current_stats.total_prs - base_stats.total_prs).max(0),
-        "reviews" => (current_stats.total_reviews - base_stats.total_reviews).max(0),
-        "issues" => (current_stats.total_issues - base_stats.total_issues).max(0),
-        _ => 0,
+        "commits" | "prs" | "reviews" | "issues" | PRS_MERGED_METRIC | ISSUES_CLOSED_METRIC => {
+            (current_stats.get_metric(metric) - start_stats.get_metric(metric)).max(0)
+        }
+        LINES_ADDED_METRIC => (activity.lines_since(start_date, |d| d.additions)
+            - start_stats.start_day.additions)
+            .max(0),
+        LINES_DELETED_METRIC => (activity.lines_since(start_date, |d| d.deletions)
+            - start_stats.start_day.deletions)
+            .max(0),
+        REPOSITORIES_METRIC => activity
+            .repositories_by_day(start_date, start_stats)
+            .flatten()
+            .collect::<HashSet<_>>()
+            .len() as i32,
+        FAST_REVIEWS_METRIC => activity
+            .reviews
+            .iter()
+            .filter(|r| r.reviewed_at >= start_date && r.is_fast())
+            .count() as i32,
+        m => match language_of_metric(m) {
+            Some(language) => activity
+                .repositories_by_day(start_date, start_stats)
+                .filter(|repos| {
+                    repos.iter().any(|r| {
+                        activity
+                            .repo_languages
+                            .get(*r)
+                            .is_some_and(|l| l.eq_ignore_ascii_case(language))
+                    })
+                })
+                .count() as i32,
+            None => 0,
+        },
     }
 }
 
@@ -337,6 +1040,15 @@ mod tests {
     use super::*;
     use chrono::Timelike;
 
+    /// Midnight UTC of a date
+    fn at(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc()
+    }
+
     #[test]
     fn test_calculate_recommended_targets() {
         let historical = HistoricalStats {
@@ -362,6 +1074,9 @@ mod tests {
         assert_eq!(calculate_reward_xp("prs", 2), 80);
         assert_eq!(calculate_reward_xp("reviews", 3), 60);
         assert_eq!(calculate_reward_xp("issues", 4), 100);
+        assert_eq!(calculate_reward_xp(FOCUS_MINUTES_METRIC, 120), 120);
+        assert_eq!(calculate_reward_xp(LINES_ADDED_METRIC, 500), 50);
+        assert_eq!(calculate_reward_xp("language:Rust", 5), 100);
     }
 
     #[test]
@@ -397,37 +1112,154 @@ mod tests {
 
     #[test]
     fn test_calculate_progress_for_metric() {
-        let prev = ChallengeProgressContext {
-            total_commits: 100,
-            total_prs: 10,
-            total_reviews: 20,
-            total_issues: 5,
+        let start = ChallengeStats::new(100, 10, 20, 5);
+        let current = ChallengeStats::new(105, 12, 23, 6);
+        let activity = ChallengeActivity::default();
+        let progress =
+            |metric| calculate_progress_for_metric(metric, Utc::now(), &start, &current, &activity);
+
+        assert_eq!(progress("commits"), 5);
+        assert_eq!(progress("prs"), 2);
+        assert_eq!(progress("reviews"), 3);
+        assert_eq!(progress("issues"), 1);
+    }
+
+    fn day(date: NaiveDate, additions: i32, deletions: i32, repos: &[&str]) -> CodeActivityDay {
+        CodeActivityDay {
+            date,
+            additions,
+            deletions,
+            repositories: repos.iter().map(|r| r.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_progress_for_code_stats_metrics() {
+        let d = |n: u32| NaiveDate::from_ymd_opt(2026, 6, n).unwrap();
+        let start_date = d(10).and_hms_opt(15, 0, 0).unwrap().and_utc();
+        let start = ChallengeStats {
+            start_day: CodeDayBaseline {
+                additions: 40,
+                deletions: 5,
+                repositories: vec!["octo/api".into()],
+            },
+            ..Default::default()
         };
-        let current = ChallengeProgressContext {
-            total_commits: 105,
-            total_prs: 12,
-            total_reviews: 23,
-            total_issues: 6,
+        let activity = ChallengeActivity {
+            code_days: vec![
+                day(d(9), 500, 500, &["octo/old"]),
+                day(d(10), 100, 15, &["octo/api", "octo/web"]),
+                day(d(11), 60, 10, &["octo/api"]),
+                day(d(12), 0, 0, &["octo/cli"]),
+            ],
+            repo_languages: HashMap::from([
+                ("octo/api".to_string(), "Rust".to_string()),
+                ("octo/web".to_string(), "TypeScript".to_string()),
+                ("octo/cli".to_string(), "Rust".to_string()),
+            ]),
+            reviews: vec![],
         };
+        let current = ChallengeStats::default();
+        let progress =
+            |metric| calculate_progress_for_metric(metric, start_date, &start, &current, &activity);
 
+        // (100 + 60 + 0) - 40 already on the start day
+        assert_eq!(progress(LINES_ADDED_METRIC), 120);
+        assert_eq!(progress(LINES_DELETED_METRIC), 20);
+        // octo/web (start day, new), octo/api (day 11), octo/cli
+        assert_eq!(progress(REPOSITORIES_METRIC), 3);
+        // Days 11 and 12; octo/api on the start day predates the challenge
+        assert_eq!(progress("language:rust"), 2);
+        assert_eq!(progress("language:TypeScript"), 1);
+        assert_eq!(progress("language:Go"), 0);
+    }
+
+    #[test]
+    fn test_progress_for_merged_closed_and_fast_reviews() {
+        let start_date = Utc::now() - Duration::days(3);
+        let start = ChallengeStats {
+            prs_merged: 4,
+            issues_closed: 10,
+            ..Default::default()
+        };
+        let current = ChallengeStats {
+            prs_merged: 7,
+            issues_closed: 9,
+            ..Default::default()
+        };
+        let review = |opened_days_ago: i64, reviewed_hours_later: i64| {
+            let opened = Utc::now() - Duration::days(opened_days_ago);
+            ReviewTurnaround {
+                pull_request_created_at: opened,
+                reviewed_at: opened + Duration::hours(reviewed_hours_later),
+            }
+        };
+        let activity = ChallengeActivity {
+            reviews: vec![review(2, 3), review(2, 30), review(10, 1), review(1, 24)],
+            ..Default::default()
+        };
+        let progress =
+            |metric| calculate_progress_for_metric(metric, start_date, &start, &current, &activity);
+
+        assert_eq!(progress(PRS_MERGED_METRIC), 3);
+        assert_eq!(progress(ISSUES_CLOSED_METRIC), 0);
+        // Too slow (30h) and before the start (10 days ago) don't count
+        assert_eq!(progress(FAST_REVIEWS_METRIC), 2);
+    }
+
+    #[test]
+    fn test_start_day_baseline() {
+        let now = NaiveDate::from_ymd_opt(2026, 6, 10)
+            .unwrap()
+            .and_hms_opt(18, 0, 0)
+            .unwrap()
+            .and_utc();
+        let today = DailyCodeStats {
+            id: 1,
+            user_id: 1,
+            date: "2026-06-10".to_string(),
+            additions: 30,
+            deletions: 2,
+            commits_count: 3,
+            repositories_json: Some(r#"["octo/api"]"#.to_string()),
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+
+        let baseline = start_day_baseline(now - Duration::hours(2), now, Some(&today));
+        assert_eq!(baseline.additions, 30);
+        assert_eq!(baseline.repositories, vec!["octo/api".to_string()]);
+
+        let midnight = now.date_naive().and_hms_opt(0, 0, 0).unwrap().and_utc();
         assert_eq!(
-            calculate_progress_for_metric("commits", &prev, &current, None),
-            5
-        );
-        assert_eq!(
-            calculate_progress_for_metric("prs", &prev, &current, None),
-            2
-        );
-        assert_eq!(
-            calculate_progress_for_metric("reviews", &prev, &current, None),
-            3
+            start_day_baseline(midnight, now, Some(&today)),
+            CodeDayBaseline::default()
         );
         assert_eq!(
-            calculate_progress_for_metric("issues", &prev, &current, None),
-            1
+            start_day_baseline(now - Duration::days(1), now, Some(&today)),
+            CodeDayBaseline::default()
         );
     }
 
+    #[test]
+    fn test_validate_target_metric() {
+        assert!(validate_target_metric("commits").is_ok());
+        assert!(validate_target_metric(LINES_ADDED_METRIC).is_ok());
+        assert!(validate_target_metric("language:Rust").is_ok());
+        assert!(validate_target_metric("language: ").is_err());
+        assert!(validate_target_metric("stars").is_err());
+        assert!(uses_code_stats("language:Go"));
+        assert!(!uses_code_stats(FAST_REVIEWS_METRIC));
+    }
+
+    #[test]
+    fn test_old_start_stats_still_parse() {
+        let stats: ChallengeStats =
+            serde_json::from_str(r#"{"commits":1,"prs":2,"reviews":3,"issues":4}"#).unwrap();
+        assert_eq!(stats.prs_merged, 0);
+        assert_eq!(stats.start_day, CodeDayBaseline::default());
+    }
+
     #[test]
     fn test_should_generate_daily_challenges() {
         let now = Utc::now();
@@ -482,6 +1314,11 @@ mod tests {
             weekly_reviews: 10,
             daily_issues: 1,
             weekly_issues: 3,
+            monthly_commits: 80,
+            monthly_prs: 20,
+            monthly_reviews: 40,
+            quarterly_commits: 240,
+            quarterly_prs: 60,
         };
 
         let challenges = generate_daily_challenges(&targets);
@@ -501,6 +1338,11 @@ mod tests {
             weekly_reviews: 10,
             daily_issues: 1,
             weekly_issues: 3,
+            monthly_commits: 80,
+            monthly_prs: 20,
+            monthly_reviews: 40,
+            quarterly_commits: 240,
+            quarterly_prs: 60,
         };
 
         let challenges = generate_weekly_challenges(&targets);
@@ -509,6 +1351,455 @@ mod tests {
         assert!(challenges.iter().all(|c| c.challenge_type == "weekly"));
     }
 
+    #[test]
+    fn test_calculate_challenge_period_monthly_and_quarterly() {
+        let now = NaiveDate::from_ymd_opt(2026, 11, 18)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap()
+            .and_utc();
+
+        let (start, end) = calculate_challenge_period("monthly", now);
+        assert_eq!(start, now);
+        assert_eq!(
+            end.date_naive(),
+            NaiveDate::from_ymd_opt(2026, 12, 1).unwrap()
+        );
+        assert_eq!(end.time().hour(), 0);
+
+        let (_, end) = calculate_challenge_period("quarterly", now);
+        assert_eq!(
+            end.date_naive(),
+            NaiveDate::from_ymd_opt(2027, 1, 1).unwrap()
+        );
+
+        // December rolls the monthly window over into the next year
+        let december = NaiveDate::from_ymd_opt(2026, 12, 31)
+            .unwrap()
+            .and_hms_opt(23, 0, 0)
+            .unwrap()
+            .and_utc();
+        let (_, end) = calculate_challenge_period("monthly", december);
+        assert_eq!(
+            end.date_naive(),
+            NaiveDate::from_ymd_opt(2027, 1, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_calculate_custom_challenge_period() {
+        let now = NaiveDate::from_ymd_opt(2026, 10, 18)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+            .and_utc();
+        let date = |m: u32, d: u32| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+
+        // Future window: whole days, end date inclusive
+        let (start, end) =
+            calculate_custom_challenge_period(date(11, 1), date(11, 30), now).unwrap();
+        assert_eq!(start, date(11, 1).and_hms_opt(0, 0, 0).unwrap().and_utc());
+        assert_eq!(end, date(12, 1).and_hms_opt(0, 0, 0).unwrap().and_utc());
+
+        // A start date already under way begins now
+        let (start, _) = calculate_custom_challenge_period(date(10, 1), date(10, 31), now).unwrap();
+        assert_eq!(start, now);
+
+        assert!(calculate_custom_challenge_period(date(11, 30), date(11, 1), now).is_err());
+        assert!(calculate_custom_challenge_period(date(10, 1), date(10, 17), now).is_err());
+        assert!(calculate_custom_challenge_period(
+            date(11, 1),
+            date(11, 1) + Duration::days(MAX_CUSTOM_PERIOD_DAYS),
+            now
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_should_generate_monthly_and_quarterly_challenges() {
+        let now = NaiveDate::from_ymd_opt(2026, 5, 3)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap()
+            .and_utc();
+        let date = |m: u32, d: u32| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+
+        assert!(should_generate_monthly_challenges(None, now));
+        assert!(!should_generate_monthly_challenges(Some(date(5, 1)), now));
+        assert!(should_generate_monthly_challenges(Some(date(4, 30)), now));
+
+        assert!(should_generate_quarterly_challenges(None, now));
+        assert!(!should_generate_quarterly_challenges(Some(date(4, 1)), now));
+        assert!(should_generate_quarterly_challenges(Some(date(3, 31)), now));
+    }
+
+    #[test]
+    fn test_period_reward_scaling() {
+        assert_eq!(
+            calculate_period_reward_xp("commits", 5, Duration::days(7)),
+            calculate_reward_xp("commits", 5)
+        );
+        assert_eq!(
+            calculate_period_reward_xp("commits", 50, Duration::days(30)),
+            600
+        );
+        assert_eq!(
+            calculate_period_reward_xp("prs", 10, Duration::days(91)),
+            600
+        );
+        assert!(
+            period_reward_multiplier(Duration::days(60))
+                > period_reward_multiplier(Duration::days(30))
+        );
+    }
+
+    #[test]
+    fn test_generate_monthly_and_quarterly_challenges() {
+        let historical = HistoricalStats {
+            commits_4w: 28,
+            prs_4w: 7,
+            reviews_4w: 14,
+            issues_4w: 0,
+            active_days_4w: 20,
+        };
+        let targets =
+            calculate_recommended_targets(&historical, &ChallengeGeneratorConfig::default());
+        // 1 commit/day over 30 days * 1.1
+        assert_eq!(targets.monthly_commits, 33);
+        assert_eq!(targets.quarterly_commits, 91);
+
+        // Generated at the start of October: full month and quarter
+        let now = at(2026, 10, 1);
+        let monthly = generate_monthly_challenges(&targets, challenge_window("monthly", now));
+        assert_eq!(monthly.len(), 3);
+        assert!(monthly.iter().all(|c| c.challenge_type == "monthly"));
+        assert_eq!(monthly[0].target_value, 33);
+        assert_eq!(monthly[0].reward_xp, 396); // 33 * 10 * 1.2
+        let quarterly = generate_quarterly_challenges(&targets, challenge_window("quarterly", now));
+        assert!(quarterly.iter().all(|c| c.challenge_type == "quarterly"));
+        assert_eq!(quarterly[0].reward_xp, 1365); // 91 * 10 * 1.5
+    }
+
+    #[test]
+    fn test_mid_period_challenges_are_prorated() {
+        let targets = calculate_recommended_targets(
+            &HistoricalStats {
+                commits_4w: 28,
+                prs_4w: 7,
+                reviews_4w: 14,
+                issues_4w: 0,
+                active_days_4w: 20,
+            },
+            &ChallengeGeneratorConfig::default(),
+        );
+
+        // 7 days left of October: ceil(33 * 7 / 30), and no long-period bonus
+        let now = at(2026, 10, 25);
+        assert_eq!(challenge_window("monthly", now), Duration::days(7));
+        let monthly = generate_monthly_challenges(&targets, challenge_window("monthly", now));
+        assert_eq!(monthly[0].target_value, 8);
+        assert_eq!(monthly[0].reward_xp, 80);
+
+        // 68 days left of the quarter
+        let quarterly = generate_quarterly_challenges(&targets, challenge_window("quarterly", now));
+        assert_eq!(quarterly[0].target_value, 68);
+        assert_eq!(quarterly[0].reward_xp, 918); // 68 * 10 * 1.35
+
+        // The difficulty pass keeps the prorated reward
+        let adapted = adapt_templates(
+            monthly.clone(),
+            &HashMap::new(),
+            ChallengeDifficulty::Normal,
+            true,
+            now,
+        );
+        assert_eq!(adapted[0].reward_xp, monthly[0].reward_xp);
+
+        assert_eq!(
+            prorate_target(33, Duration::hours(1), MONTHLY_PERIOD_DAYS),
+            1
+        );
+        assert_eq!(
+            prorate_target(33, Duration::days(31), MONTHLY_PERIOD_DAYS),
+            33
+        );
+    }
+
+    fn outcome(challenge_type: &str, metric: &str, status: &str) -> (String, String, String) {
+        (
+            challenge_type.to_string(),
+            metric.to_string(),
+            status.to_string(),
+        )
+    }
+
+    fn key(challenge_type: &str, metric: &str) -> (String, String) {
+        (challenge_type.to_string(), metric.to_string())
+    }
+
+    fn weekly_commits_template(target_value: i32) -> ChallengeTemplate {
+        ChallengeTemplate {
+            challenge_type: "weekly".to_string(),
+            target_metric: "commits".to_string(),
+            target_value,
+            reward_xp: calculate_reward_xp("commits", target_value),
+            reason: None,
+        }
+    }
+
+    #[test]
+    fn test_tally_challenge_outcomes() {
+        let mut outcomes = vec![
+            outcome("weekly", "commits", "completed"),
+            outcome("weekly", "prs", "failed"),
+            outcome("weekly", "commits", "failed"),
+            // Another type of the same metric is tallied separately
+            outcome("daily", "commits", "failed"),
+        ];
+        // Older than the window: ignored
+        outcomes.extend((0..6).map(|_| outcome("weekly", "commits", "completed")));
+
+        let records = tally_challenge_outcomes(&outcomes);
+        let commits = records[&key("weekly", "commits")];
+        assert_eq!(commits.total(), DIFFICULTY_HISTORY_WINDOW);
+        assert_eq!(commits.failed, 1);
+        assert_eq!(commits.completed, 4);
+        assert_eq!(
+            records[&key("weekly", "prs")],
+            MetricRecord {
+                completed: 0,
+                failed: 1
+            }
+        );
+        assert_eq!(
+            records[&key("daily", "commits")],
+            MetricRecord {
+                completed: 0,
+                failed: 1
+            }
+        );
+        // One outcome isn't enough to judge
+        assert_eq!(records[&key("weekly", "prs")].completion_rate(), None);
+    }
+
+    #[test]
+    fn test_adapt_template_uses_history() {
+        let normal = ChallengeDifficulty::Normal;
+
+        let strong = MetricRecord {
+            completed: 4,
+            failed: 1,
+        };
+        let raised = adapt_template(
+            weekly_commits_template(10),
+            strong,
+            normal,
+            true,
+            Duration::days(7),
+        );
+        assert_eq!(raised.target_value, 12); // ceil(10 * 1.15)
+        assert_eq!(raised.reward_xp, calculate_reward_xp("commits", 12));
+        assert!(raised.reason.as_deref().unwrap().contains("引き上げ"));
+
+        let weak = MetricRecord {
+            completed: 1,
+            failed: 3,
+        };
+        let lowered = adapt_template(
+            weekly_commits_template(10),
+            weak,
+            normal,
+            true,
+            Duration::days(7),
+        );
+        assert_eq!(lowered.target_value, 9); // ceil(10 * 0.85)
+        assert!(lowered.reason.as_deref().unwrap().contains("引き下げ"));
+
+        let unknown = adapt_template(
+            weekly_commits_template(10),
+            MetricRecord::default(),
+            normal,
+            true,
+            Duration::days(7),
+        );
+        assert_eq!(unknown.target_value, 10);
+        let reason = unknown.reason.as_deref().unwrap();
+        assert!(reason.contains("調整なし"));
+        assert!(reason.contains("過去4週間の実績から基準値10を算出"));
+
+        // Minimum targets don't claim to come from the user's history
+        let fresh = adapt_template(
+            weekly_commits_template(5),
+            MetricRecord::default(),
+            normal,
+            false,
+            Duration::days(7),
+        );
+        let reason = fresh.reason.as_deref().unwrap();
+        assert!(reason.contains("実績がないため最低目標5から開始"));
+        assert!(!reason.contains("基準値"));
+    }
+
+    #[test]
+    fn test_adapt_template_uses_difficulty() {
+        let record = MetricRecord::default();
+
+        let easy = adapt_template(
+            weekly_commits_template(10),
+            record,
+            ChallengeDifficulty::Easy,
+            true,
+            Duration::days(7),
+        );
+        assert_eq!(easy.target_value, 8);
+        assert_eq!(easy.reward_xp, 64); // 80 XP * 0.8
+
+        let hard = adapt_template(
+            weekly_commits_template(10),
+            record,
+            ChallengeDifficulty::Hard,
+            true,
+            Duration::days(7),
+        );
+        assert_eq!(hard.target_value, 13); // ceil(12.5)
+        assert_eq!(hard.reward_xp, 169); // 130 XP * 1.3
+        assert!(hard.reason.as_deref().unwrap().contains("むずかしい"));
+
+        // Never drops below one
+        let tiny = adapt_template(
+            weekly_commits_template(1),
+            MetricRecord {
+                completed: 0,
+                failed: 5,
+            },
+            ChallengeDifficulty::Easy,
+            true,
+            Duration::days(7),
+        );
+        assert_eq!(tiny.target_value, 1);
+    }
+
+    #[test]
+    fn test_adapt_templates_keeps_period_reward() {
+        let targets = calculate_recommended_targets(
+            &HistoricalStats {
+                commits_4w: 40,
+                prs_4w: 8,
+                reviews_4w: 12,
+                issues_4w: 0,
+                active_days_4w: 20,
+            },
+            &ChallengeGeneratorConfig::default(),
+        );
+        let now = at(2026, 11, 1);
+        let templates = generate_monthly_challenges(&targets, challenge_window("monthly", now));
+        let adapted = adapt_templates(
+            templates.clone(),
+            &HashMap::new(),
+            ChallengeDifficulty::Normal,
+            true,
+            now,
+        );
+
+        for (before, after) in templates.iter().zip(&adapted) {
+            assert_eq!(before.target_value, after.target_value);
+            assert_eq!(before.reward_xp, after.reward_xp);
+            assert!(after.reason.is_some());
+        }
+
+        // Records are looked up by type as well as metric
+        let weekly_only = HashMap::from([(
+            key("weekly", "commits"),
+            MetricRecord {
+                completed: 5,
+                failed: 0,
+            },
+        )]);
+        let monthly = adapt_templates(
+            templates.clone(),
+            &weekly_only,
+            ChallengeDifficulty::Normal,
+            true,
+            now,
+        );
+        assert_eq!(monthly[0].target_value, templates[0].target_value);
+        let weekly = adapt_templates(
+            vec![weekly_commits_template(10)],
+            &weekly_only,
+            ChallengeDifficulty::Normal,
+            true,
+            now,
+        );
+        assert_eq!(weekly[0].target_value, 12);
+    }
+
+    #[test]
+    fn test_historical_stats_from_period_totals() {
+        assert!(HistoricalStats::from_period_totals(&PeriodTotals::default()).is_none());
+
+        let stats = HistoricalStats::from_period_totals(&PeriodTotals {
+            commits: Some(40),
+            pull_requests: Some(8),
+            reviews: None,
+            issues: Some(2),
+            active_days: 16,
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(stats.commits_4w, 40);
+        assert_eq!(stats.prs_4w, 8);
+        assert_eq!(stats.reviews_4w, 0);
+        assert_eq!(stats.issues_4w, 2);
+        assert_eq!(stats.active_days_4w, 16);
+
+        // No code stats: activity is spread over the window
+        let stats = HistoricalStats::from_period_totals(&PeriodTotals {
+            commits: Some(28),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(stats.active_days_4w, HISTORY_WINDOW_DAYS as i32);
+        let targets = calculate_recommended_targets(&stats, &ChallengeGeneratorConfig::default());
+        assert_eq!(targets.daily_commits, 1); // not 28 from a single active day
+
+        // Code stats alone are still history
+        let stats = HistoricalStats::from_period_totals(&PeriodTotals {
+            active_days: 3,
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(stats.commits_4w, 0);
+    }
+
+    #[test]
+    fn test_pick_reroll_template() {
+        for seed in 0..20 {
+            let template = pick_reroll_template("daily", "reviews", &[], seed).unwrap();
+            assert_eq!(template.challenge_type, "daily");
+            assert_ne!(template.target_metric, "reviews");
+            assert!(template.reason.is_some());
+        }
+
+        // Metrics with another active daily challenge are avoided...
+        for seed in 0..20 {
+            let template =
+                pick_reroll_template("daily", "reviews", &["commits", "prs"], seed).unwrap();
+            assert_eq!(template.target_metric, "issues");
+        }
+        // ...unless nothing else is left
+        let template =
+            pick_reroll_template("daily", "reviews", &["commits", "prs", "issues"], 0).unwrap();
+        assert_ne!(template.target_metric, "reviews");
+
+        // Weekly streak templates have no metric and are never picked
+        for seed in 0..20 {
+            let template = pick_reroll_template("weekly", "commits", &[], seed).unwrap();
+            assert!(["prs", "reviews"].contains(&template.target_metric.as_str()));
+        }
+
+        assert!(pick_reroll_template("monthly", "commits", &[], 0).is_none());
+    }
+
     #[test]
     fn test_challenge_stats_serialization() {
         let stats = ChallengeStats::new(100, 20, 15, 5);
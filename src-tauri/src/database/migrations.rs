@@ -549,6 +549,575 @@ UPDATE xp_history
 -- Default 1 for any pre-existing row so we know to migrate it; freshly
 -- created users (post-deploy) are written as version 2 directly.
 ALTER TABLE users ADD COLUMN encryption_version INTEGER NOT NULL DEFAULT 1;
+"#,
+    },
+    Migration {
+        version: 18,
+        name: "add_issue_outbox",
+        sql: r#"
+-- Offline write queue for issue mutations.
+--
+-- `update_issue_status` / `create_github_issue` used to fail outright when
+-- GitHub was unreachable, so dragging a card while offline snapped it back
+-- to its old column. Now the mutation is recorded here, `cached_issues` is
+-- updated optimistically, and `replay_issue_outbox` pushes the queue to
+-- GitHub once the network returns.
+--
+-- operation:       'update_status' | 'create_issue'
+-- issue_number:    target issue; negative placeholder for queued creates
+-- payload_json:    operation-specific arguments (see models/issue_outbox.rs)
+-- base_updated_at: cached `github_updated_at` at enqueue time, compared
+--                  against GitHub on replay to detect concurrent edits
+-- status:          'pending' | 'failed' | 'conflict' (applied rows are deleted)
+CREATE TABLE IF NOT EXISTS issue_outbox (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    project_id INTEGER NOT NULL,
+    operation TEXT NOT NULL,
+    issue_number INTEGER NOT NULL,
+    payload_json TEXT NOT NULL,
+    base_updated_at DATETIME,
+    status TEXT NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_issue_outbox_user_status ON issue_outbox(user_id, status);
+CREATE INDEX IF NOT EXISTS idx_issue_outbox_issue ON issue_outbox(project_id, issue_number);
+
+-- Marks cached rows carrying an optimistic, not-yet-replayed change so
+-- `sync_project_issues` does not overwrite them with the stale remote copy.
+ALTER TABLE cached_issues ADD COLUMN pending_sync INTEGER NOT NULL DEFAULT 0;
+"#,
+    },
+    Migration {
+        version: 19,
+        name: "add_project_sync_cursors",
+        sql: r#"
+-- Per-project cursor for incremental issue sync.
+--
+-- `sync_project_issues` used to page through every issue of the linked
+-- repository on each run. It now asks GitHub only for issues updated
+-- since `since_cursor` (the newest `updated_at` seen so far) and sends the
+-- stored `etag` as If-None-Match, so an unchanged repository costs a
+-- single 304 that does not count against the rate limit.
+--
+-- last_full_sync_at: a periodic full listing is still needed to notice
+--                    issues that were deleted or transferred — those never
+--                    show up in a `since` query.
+--
+-- One row per project (not `sync_metadata`, which is keyed per user) so
+-- deleting the project cascades the cursor away with it.
+CREATE TABLE IF NOT EXISTS project_sync_cursors (
+    project_id INTEGER PRIMARY KEY,
+    since_cursor TEXT,
+    etag TEXT,
+    last_full_sync_at DATETIME,
+    last_incremental_sync_at DATETIME,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+"#,
+    },
+    Migration {
+        version: 20,
+        name: "add_cached_issues_fts",
+        sql: r#"
+-- Full-text index over cached issues for `search_issues_local`.
+--
+-- External-content FTS5 table: the text itself stays in `cached_issues`
+-- and the index only stores tokens, so the column names must match the
+-- content table exactly (snippet()/highlight() read them back from it).
+--
+-- tokenize='trigram': issue titles are frequently Japanese, which has no
+-- word separators for unicode61 to split on. Trigrams give substring
+-- matching for any script; queries shorter than three characters are
+-- handled with LIKE by the command instead.
+CREATE VIRTUAL TABLE IF NOT EXISTS cached_issues_fts USING fts5(
+    title,
+    body,
+    labels_json,
+    assignee_login,
+    content='cached_issues',
+    content_rowid='id',
+    tokenize='trigram'
+);
+
+-- Keep the index in sync with every write path (sync upsert, offline
+-- outbox, archive/relink cleanup, project delete cascade).
+CREATE TRIGGER IF NOT EXISTS cached_issues_fts_ai AFTER INSERT ON cached_issues BEGIN
+    INSERT INTO cached_issues_fts(rowid, title, body, labels_json, assignee_login)
+    VALUES (new.id, new.title, new.body, new.labels_json, new.assignee_login);
+END;
+
+CREATE TRIGGER IF NOT EXISTS cached_issues_fts_ad AFTER DELETE ON cached_issues BEGIN
+    INSERT INTO cached_issues_fts(cached_issues_fts, rowid, title, body, labels_json, assignee_login)
+    VALUES ('delete', old.id, old.title, old.body, old.labels_json, old.assignee_login);
+END;
+
+CREATE TRIGGER IF NOT EXISTS cached_issues_fts_au
+AFTER UPDATE OF title, body, labels_json, assignee_login ON cached_issues BEGIN
+    INSERT INTO cached_issues_fts(cached_issues_fts, rowid, title, body, labels_json, assignee_login)
+    VALUES ('delete', old.id, old.title, old.body, old.labels_json, old.assignee_login);
+    INSERT INTO cached_issues_fts(rowid, title, body, labels_json, assignee_login)
+    VALUES (new.id, new.title, new.body, new.labels_json, new.assignee_login);
+END;
+
+-- Index the issues that were cached before this migration.
+INSERT INTO cached_issues_fts(cached_issues_fts) VALUES ('rebuild');
+"#,
+    },
+    Migration {
+        version: 21,
+        name: "add_issue_edit_columns_to_cached_issues",
+        sql: r#"
+-- Fields the issue detail modal can now edit (`update_issue_fields`,
+-- `close_issue`, `reopen_issue`).
+--
+-- assignees_json:   JSON array of logins. `assignee_login` keeps the first
+--                   one for the kanban card avatar.
+-- milestone_number: GitHub milestone number (per repository), NULL = none.
+-- state_reason:     completed / not_planned / reopened, as reported by
+--                   GitHub; drives Done vs Cancelled for closed issues.
+--
+-- Existing rows get NULLs and are filled in by the next sync.
+ALTER TABLE cached_issues ADD COLUMN assignees_json TEXT;
+ALTER TABLE cached_issues ADD COLUMN milestone_number INTEGER;
+ALTER TABLE cached_issues ADD COLUMN milestone_title TEXT;
+ALTER TABLE cached_issues ADD COLUMN state_reason TEXT;
+"#,
+    },
+    Migration {
+        version: 22,
+        name: "add_issue_comments_cache",
+        sql: r#"
+-- Cached pages of issue comments for the issue detail modal.
+--
+-- Comments are fetched on demand (not by `sync_project_issues`), one
+-- `IssueCommentsPage` JSON per (project, issue, page). `expires_at` is the
+-- freshness bound (cache_durations::ISSUE_COMMENTS); expired rows are
+-- still served when GitHub is unreachable. Posting a comment deletes the
+-- issue's rows so the next read refetches.
+CREATE TABLE IF NOT EXISTS issue_comments_cache (
+    project_id INTEGER NOT NULL,
+    issue_number INTEGER NOT NULL,
+    page INTEGER NOT NULL,
+    data_json TEXT NOT NULL,
+    fetched_at DATETIME NOT NULL,
+    expires_at DATETIME NOT NULL,
+    PRIMARY KEY (project_id, issue_number, page),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+"#,
+    },
+    Migration {
+        version: 23,
+        name: "add_linked_prs_to_cached_issues",
+        sql: r#"
+-- Pull requests linked to each issue, with review decision, checks rollup
+-- and merge state, for the kanban card badges.
+--
+-- Stored as a JSON array of `LinkedPullRequest` next to the issue rather
+-- than in a table of its own: it is only ever read together with the issue
+-- and replaced wholesale from one GraphQL query per sync. Not part of the
+-- REST issue payload, so `upsert_cached_issue` leaves it untouched.
+ALTER TABLE cached_issues ADD COLUMN linked_prs_json TEXT;
+"#,
+    },
+    Migration {
+        version: 24,
+        name: "add_project_milestones",
+        sql: r#"
+-- Milestones (sprints) of a project's repository and their daily progress.
+--
+-- project_milestones:          synced from GET /repos/{o}/{r}/milestones on
+--                              every `sync_project_issues`. open_issues /
+--                              closed_issues are GitHub's own counts, so
+--                              they include issues beyond the 1000-issue
+--                              sync cap.
+-- milestone_daily_snapshots:   one row per milestone per (UTC) day, written
+--                              by the same sync. Issue timestamps alone
+--                              can't tell when an issue was *added to* or
+--                              *removed from* a milestone, so the burndown
+--                              prefers a snapshot wherever one exists.
+-- cached_issues.github_closed_at: close timestamp for days without a
+--                              snapshot (before the first sync, or gaps).
+CREATE TABLE IF NOT EXISTS project_milestones (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    project_id INTEGER NOT NULL,
+    github_milestone_id INTEGER NOT NULL,
+    number INTEGER NOT NULL,
+    title TEXT NOT NULL,
+    description TEXT,
+    state TEXT NOT NULL,
+    due_on DATETIME,
+    open_issues INTEGER NOT NULL DEFAULT 0,
+    closed_issues INTEGER NOT NULL DEFAULT 0,
+    github_created_at DATETIME,
+    github_closed_at DATETIME,
+    synced_at DATETIME NOT NULL,
+    UNIQUE (project_id, number),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS milestone_daily_snapshots (
+    project_id INTEGER NOT NULL,
+    milestone_number INTEGER NOT NULL,
+    snapshot_date TEXT NOT NULL,
+    open_issues INTEGER NOT NULL,
+    closed_issues INTEGER NOT NULL,
+    PRIMARY KEY (project_id, milestone_number, snapshot_date),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+ALTER TABLE cached_issues ADD COLUMN github_closed_at DATETIME;
+"#,
+    },
+    Migration {
+        version: 25,
+        name: "add_project_repositories",
+        sql: r#"
+-- Several repositories per project (e.g. frontend / backend / infra).
+--
+-- project_repositories: one row per linked repository. `link_repository`
+--                       now adds a row instead of replacing the project's
+--                       repo columns, and `sync_project_issues` fans out
+--                       over every row. Archive state (Issue #190) moves
+--                       here too: a 404 archives only that repository;
+--                       the project itself is archived once all of its
+--                       repositories are.
+--
+-- projects.repo_* / github_repo_id keep the *primary* repository (the
+-- first one linked) so single-repo code paths — GitHub Actions setup,
+-- milestone sync — keep working unchanged.
+--
+-- cached_issues / issue_outbox get the repository of their issue, since
+-- issue numbers are only unique within one repository. Existing rows all
+-- belong to the primary repository.
+--
+-- issue_comments_cache and project_sync_cursors are rebuilt with the
+-- repository in their keys. Both are disposable (a page refetch, one full
+-- listing), so the old rows are dropped rather than copied.
+CREATE TABLE IF NOT EXISTS project_repositories (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    project_id INTEGER NOT NULL,
+    github_repo_id INTEGER NOT NULL,
+    repo_owner TEXT NOT NULL,
+    repo_name TEXT NOT NULL,
+    repo_full_name TEXT NOT NULL,
+    is_archived INTEGER NOT NULL DEFAULT 0,
+    archived_at DATETIME,
+    archived_reason TEXT,
+    last_synced_at DATETIME,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (project_id, github_repo_id),
+    UNIQUE (project_id, repo_full_name),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_repositories_project
+    ON project_repositories(project_id);
+
+INSERT INTO project_repositories (
+    project_id, github_repo_id, repo_owner, repo_name, repo_full_name,
+    is_archived, archived_at, archived_reason, last_synced_at, created_at
+)
+SELECT id, github_repo_id, repo_owner, repo_name, repo_full_name,
+       is_archived, archived_at, archived_reason, last_synced_at, updated_at
+FROM projects
+WHERE github_repo_id IS NOT NULL AND repo_owner IS NOT NULL
+  AND repo_name IS NOT NULL AND repo_full_name IS NOT NULL;
+
+ALTER TABLE cached_issues ADD COLUMN repo_full_name TEXT;
+UPDATE cached_issues SET repo_full_name =
+    (SELECT repo_full_name FROM projects WHERE projects.id = cached_issues.project_id);
+CREATE INDEX IF NOT EXISTS idx_cached_issues_repo_number
+    ON cached_issues(project_id, repo_full_name, number);
+
+ALTER TABLE issue_outbox ADD COLUMN repo_full_name TEXT;
+UPDATE issue_outbox SET repo_full_name =
+    (SELECT repo_full_name FROM projects WHERE projects.id = issue_outbox.project_id);
+
+DROP TABLE IF EXISTS issue_comments_cache;
+CREATE TABLE issue_comments_cache (
+    project_id INTEGER NOT NULL,
+    repo_full_name TEXT NOT NULL,
+    issue_number INTEGER NOT NULL,
+    page INTEGER NOT NULL,
+    data_json TEXT NOT NULL,
+    fetched_at DATETIME NOT NULL,
+    expires_at DATETIME NOT NULL,
+    PRIMARY KEY (project_id, repo_full_name, issue_number, page),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+DROP TABLE IF EXISTS project_sync_cursors;
+CREATE TABLE project_sync_cursors (
+    project_id INTEGER NOT NULL,
+    repo_full_name TEXT NOT NULL,
+    since_cursor TEXT,
+    etag TEXT,
+    last_full_sync_at DATETIME,
+    last_incremental_sync_at DATETIME,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (project_id, repo_full_name),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+"#,
+    },
+    Migration {
+        version: 26,
+        name: "add_issue_time_entries",
+        sql: r#"
+-- Time tracked on issues with the kanban card timers.
+--
+-- One row per start/stop session; `ended_at IS NULL` marks the running
+-- timer (at most one per user, enforced by `start_issue_timer`).
+-- `duration_seconds` is filled in on stop.
+--
+-- Entries point at the cached issue row (`cached_issue_id`), which keeps
+-- its id when an offline-created placeholder is promoted to the real
+-- GitHub issue. The issue number / title are copied in as well, so the
+-- per-project and per-day totals survive the cached row being dropped
+-- (repository unlinked, issue deleted on GitHub): the link is then set
+-- to NULL instead of deleting the time.
+--
+-- `xp_awarded` is the time-based XP granted when the entry stopped; the
+-- daily cap is applied against the sum of it (TIME_TRACKED_DAILY_CAP_MINUTES).
+CREATE TABLE IF NOT EXISTS issue_time_entries (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    project_id INTEGER NOT NULL,
+    cached_issue_id INTEGER,
+    repo_full_name TEXT,
+    issue_number INTEGER NOT NULL,
+    issue_title TEXT NOT NULL,
+    started_at DATETIME NOT NULL,
+    ended_at DATETIME,
+    duration_seconds INTEGER NOT NULL DEFAULT 0,
+    note TEXT,
+    xp_awarded INTEGER NOT NULL DEFAULT 0,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    FOREIGN KEY (cached_issue_id) REFERENCES cached_issues(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_issue_time_entries_user_started
+    ON issue_time_entries(user_id, started_at);
+CREATE INDEX IF NOT EXISTS idx_issue_time_entries_issue
+    ON issue_time_entries(cached_issue_id);
+"#,
+    },
+    Migration {
+        version: 27,
+        name: "add_project_workflow_settings",
+        sql: r#"
+-- Settings for the generated "Issue Status Sync" Actions workflow, one
+-- row per project (no row = WorkflowSettings::default). The excluded
+-- branches and per-event rules are JSON arrays.
+CREATE TABLE IF NOT EXISTS project_workflow_settings (
+    project_id INTEGER PRIMARY KEY,
+    branch_pattern TEXT NOT NULL,
+    issue_number_group INTEGER NOT NULL,
+    excluded_branches_json TEXT NOT NULL DEFAULT '[]',
+    rules_json TEXT NOT NULL DEFAULT '[]',
+    close_on_merge INTEGER NOT NULL DEFAULT 1,
+    updated_at DATETIME NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+"#,
+    },
+    Migration {
+        version: 28,
+        name: "add_challenge_difficulty",
+        sql: r#"
+-- Adaptive challenge difficulty: the user's easy / normal / hard
+-- preference, and the explanation of how each generated challenge's
+-- target was chosen (NULL for manual challenges).
+ALTER TABLE user_settings ADD COLUMN challenge_difficulty TEXT NOT NULL DEFAULT 'normal';
+ALTER TABLE challenges ADD COLUMN target_reason TEXT;
+"#,
+    },
+    Migration {
+        version: 29,
+        name: "add_challenge_tokens",
+        sql: r#"
+-- Reroll / skip tokens for auto-generated challenges, one row per user
+-- (created on first read). Users start with one of each and earn more by
+-- levelling up (reroll) and reaching streak milestones (skip). Skipped
+-- challenges get status 'skipped', which counts as neither completed
+-- nor failed.
+CREATE TABLE IF NOT EXISTS challenge_tokens (
+    user_id INTEGER PRIMARY KEY,
+    reroll_tokens INTEGER NOT NULL DEFAULT 1,
+    skip_tokens INTEGER NOT NULL DEFAULT 1,
+    updated_at DATETIME NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+"#,
+    },
+    Migration {
+        version: 30,
+        name: "add_prestige",
+        sql: r#"
+-- Prestige: resetting the level keeps total_xp (and the XP history) intact.
+-- prestige_xp_offset holds total_xp at the last prestige, so the displayed
+-- level is derived from total_xp - prestige_xp_offset. prestige_level is
+-- the level a user must reach before they can prestige.
+ALTER TABLE user_stats ADD COLUMN prestige_rank INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE user_stats ADD COLUMN prestige_xp_offset INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE user_settings ADD COLUMN prestige_level INTEGER NOT NULL DEFAULT 50;
+"#,
+    },
+    Migration {
+        version: 31,
+        name: "add_seasons",
+        sql: r#"
+-- Seasons: season XP is tracked next to the lifetime total_xp and reset at
+-- the start of every calendar quarter. season_id is NULL until the first
+-- XP gain after this migration. seasons_completed / best_season_percentile
+-- feed the season badges without re-reading season_history.
+ALTER TABLE user_stats ADD COLUMN season_id TEXT;
+ALTER TABLE user_stats ADD COLUMN season_xp INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE user_stats ADD COLUMN season_best_streak INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE user_stats ADD COLUMN seasons_completed INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE user_stats ADD COLUMN best_season_percentile INTEGER NOT NULL DEFAULT 0;
+
+-- One archived summary per finished season
+CREATE TABLE IF NOT EXISTS season_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    season_id TEXT NOT NULL,
+    starts_on TEXT NOT NULL,
+    ends_on TEXT NOT NULL,
+    season_xp INTEGER NOT NULL,
+    season_level INTEGER NOT NULL,
+    best_streak INTEGER NOT NULL,
+    top_badges_json TEXT NOT NULL DEFAULT '[]',
+    percentile INTEGER,
+    archived_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE(user_id, season_id)
+);
+CREATE INDEX IF NOT EXISTS idx_season_history_user ON season_history(user_id, season_id);
+"#,
+    },
+    Migration {
+        version: 32,
+        name: "add_xp_safeguards",
+        sql: r#"
+-- Anti-gaming safeguards for commit XP. 0 disables the daily cap and the
+-- minimum diff size; excluded repositories are a JSON array of "owner/name".
+ALTER TABLE user_settings ADD COLUMN xp_daily_commit_cap INTEGER NOT NULL DEFAULT 20;
+ALTER TABLE user_settings ADD COLUMN xp_min_commit_lines INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE user_settings ADD COLUMN xp_exclude_bot_commits INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE user_settings ADD COLUMN xp_excluded_repositories_json TEXT NOT NULL DEFAULT '[]';
+"#,
+    },
+    Migration {
+        version: 33,
+        name: "add_repository_filters",
+        sql: r#"
+-- Repository / organization filter applied to every stats path, one row
+-- per user (no row = RepositoryFilter::default, which counts everything).
+-- The include / exclude lists and work owners are JSON arrays.
+-- rebaseline_pending is set when the filter changes so the next sync
+-- re-baselines the stats snapshot instead of awarding XP for the jump.
+CREATE TABLE IF NOT EXISTS repository_filters (
+    user_id INTEGER PRIMARY KEY,
+    preset TEXT NOT NULL DEFAULT 'all',
+    work_owners_json TEXT NOT NULL DEFAULT '[]',
+    include_repositories_json TEXT NOT NULL DEFAULT '[]',
+    exclude_repositories_json TEXT NOT NULL DEFAULT '[]',
+    include_owners_json TEXT NOT NULL DEFAULT '[]',
+    exclude_owners_json TEXT NOT NULL DEFAULT '[]',
+    include_forks INTEGER NOT NULL DEFAULT 1,
+    include_archived INTEGER NOT NULL DEFAULT 1,
+    include_private INTEGER NOT NULL DEFAULT 1,
+    rebaseline_pending INTEGER NOT NULL DEFAULT 0,
+    updated_at DATETIME NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+"#,
+    },
+    Migration {
+        version: 34,
+        name: "add_weekly_report_notification",
+        sql: r#"
+-- Opt-in "your week in code" notification, delivered once per week on the
+-- first scheduler run from Monday on. The last delivery is tracked in
+-- sync_metadata (sync_type 'weekly_report').
+ALTER TABLE user_settings ADD COLUMN notify_weekly_report INTEGER NOT NULL DEFAULT 0;
+"#,
+    },
+    Migration {
+        version: 35,
+        name: "add_contribution_history",
+        sql: r#"
+-- Every contribution day fetched from GitHub's contribution calendar, so
+-- history older than the rolling one-year window is kept locally.
+CREATE TABLE IF NOT EXISTS contribution_days (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    date TEXT NOT NULL,
+    contribution_count INTEGER NOT NULL DEFAULT 0,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE(user_id, date)
+);
+CREATE INDEX IF NOT EXISTS idx_contribution_days_user_date ON contribution_days(user_id, date);
+
+-- Past years fetched in full by the backfill; they are not fetched again.
+-- The backfill run itself is tracked in sync_metadata
+-- (sync_type 'contribution_history').
+CREATE TABLE IF NOT EXISTS contribution_years (
+    user_id INTEGER NOT NULL,
+    year INTEGER NOT NULL,
+    backfilled_at TEXT NOT NULL,
+    PRIMARY KEY (user_id, year),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+"#,
+    },
+    Migration {
+        version: 36,
+        name: "add_snapshot_reliability_flags",
+        sql: r#"
+-- Snapshot totals that cannot be diffed across periods.
+-- search_fallback: a Search API count (total / merged PRs, closed issues)
+-- was replaced by a fallback value in the sync that wrote the row.
+-- filter_rebaseline: the row was written by the first sync after a
+-- repository filter change, so its totals are not comparable with older
+-- rows.
+ALTER TABLE github_stats_snapshots ADD COLUMN search_fallback INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE github_stats_snapshots ADD COLUMN filter_rebaseline INTEGER NOT NULL DEFAULT 0;
+"#,
+    },
+    Migration {
+        version: 37,
+        name: "add_daily_repository_code_stats",
+        sql: r#"
+-- Per-repository split of daily_code_stats, written by the same code stats
+-- sync, so the activity heatmap can filter lines / commits by repository.
+-- Days synced before this migration only have the daily totals.
+CREATE TABLE IF NOT EXISTS daily_repository_code_stats (
+    user_id INTEGER NOT NULL,
+    date TEXT NOT NULL,
+    repo_full_name TEXT NOT NULL,
+    additions INTEGER NOT NULL DEFAULT 0,
+    deletions INTEGER NOT NULL DEFAULT 0,
+    commits_count INTEGER NOT NULL DEFAULT 0,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (user_id, date, repo_full_name),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS idx_daily_repository_code_stats_repo
+    ON daily_repository_code_stats(user_id, repo_full_name COLLATE NOCASE, date);
 "#,
     },
 ];
@@ -8,14 +8,19 @@ pub mod connection;
 pub mod migrations;
 pub mod models;
 pub mod repository;
+#[cfg(test)]
+pub(crate) mod test_support;
 
 // Re-export challenge types used by commands/github.rs
 #[allow(unused_imports)]
 pub use challenge::{
-    calculate_challenge_period, calculate_recommended_targets, calculate_reward_xp,
-    generate_daily_challenges, generate_weekly_challenges, should_generate_daily_challenges,
-    should_generate_weekly_challenges, ChallengeGeneratorConfig, ChallengeStats, ChallengeTemplate,
-    HistoricalStats, RecommendedTargets,
+    calculate_challenge_period, calculate_custom_challenge_period, calculate_period_reward_xp,
+    calculate_recommended_targets, calculate_reward_xp, generate_daily_challenges,
+    generate_monthly_challenges, generate_quarterly_challenges, generate_weekly_challenges,
+    should_generate_daily_challenges, should_generate_monthly_challenges,
+    should_generate_quarterly_challenges, should_generate_weekly_challenges,
+    ChallengeGeneratorConfig, ChallengeStats, ChallengeTemplate, HistoricalStats,
+    RecommendedTargets,
 };
 pub use connection::{Database, DatabaseError, DbResult};
 pub use models::*;
@@ -87,6 +87,13 @@ pub mod badge {
         Languages { count: i32 },
         /// Level reached
         Level { threshold: i32 },
+        /// Prestige rank reached
+        Prestige { rank: i32 },
+        /// Seasons completed (archived)
+        SeasonsCompleted { count: i32 },
+        /// A season's XP beat at least `min_percentile`% of the user's
+        /// own earlier seasons
+        SeasonPercentile { min_percentile: i32 },
         /// Stars received on repositories
         StarsReceived { threshold: i32 },
     }
@@ -106,6 +113,9 @@ pub mod badge {
         pub languages_count: i32,
         pub current_level: i32,
         pub total_stars_received: i32,
+        pub prestige_rank: i32,
+        pub seasons_completed: i32,
+        pub best_season_percentile: i32,
     }
 
     /// All badge definitions
@@ -300,6 +310,73 @@ pub mod badge {
                 icon: "👑".to_string(),
                 condition: BadgeCondition::Level { threshold: 100 },
             },
+            // Prestige badges
+            BadgeDefinition {
+                id: "prestige_1".to_string(),
+                name: "Reborn".to_string(),
+                description: "Prestige for the first time".to_string(),
+                badge_type: "prestige".to_string(),
+                rarity: "silver".to_string(),
+                icon: "🔄".to_string(),
+                condition: BadgeCondition::Prestige { rank: 1 },
+            },
+            BadgeDefinition {
+                id: "prestige_3".to_string(),
+                name: "Ascendant".to_string(),
+                description: "Reach prestige rank 3".to_string(),
+                badge_type: "prestige".to_string(),
+                rarity: "gold".to_string(),
+                icon: "🌀".to_string(),
+                condition: BadgeCondition::Prestige { rank: 3 },
+            },
+            BadgeDefinition {
+                id: "prestige_10".to_string(),
+                name: "Eternal".to_string(),
+                description: "Reach prestige rank 10".to_string(),
+                badge_type: "prestige".to_string(),
+                rarity: "platinum".to_string(),
+                icon: "♾️".to_string(),
+                condition: BadgeCondition::Prestige { rank: 10 },
+            },
+            // Season badges (awarded when a season is archived)
+            BadgeDefinition {
+                id: "season_1".to_string(),
+                name: "Season Finisher".to_string(),
+                description: "Complete your first season".to_string(),
+                badge_type: "season".to_string(),
+                rarity: "bronze".to_string(),
+                icon: "🏁".to_string(),
+                condition: BadgeCondition::SeasonsCompleted { count: 1 },
+            },
+            BadgeDefinition {
+                id: "season_4".to_string(),
+                name: "Season Veteran".to_string(),
+                description: "Complete 4 seasons".to_string(),
+                badge_type: "season".to_string(),
+                rarity: "silver".to_string(),
+                icon: "🗂️".to_string(),
+                condition: BadgeCondition::SeasonsCompleted { count: 4 },
+            },
+            BadgeDefinition {
+                id: "season_top_quartile".to_string(),
+                name: "Top Quartile Season".to_string(),
+                description: "Finish a season ahead of 75% of your past seasons".to_string(),
+                badge_type: "season".to_string(),
+                rarity: "gold".to_string(),
+                icon: "📈".to_string(),
+                condition: BadgeCondition::SeasonPercentile { min_percentile: 75 },
+            },
+            BadgeDefinition {
+                id: "season_personal_best".to_string(),
+                name: "Personal Best Season".to_string(),
+                description: "Finish a season with more XP than any past season".to_string(),
+                badge_type: "season".to_string(),
+                rarity: "platinum".to_string(),
+                icon: "🏆".to_string(),
+                condition: BadgeCondition::SeasonPercentile {
+                    min_percentile: 100,
+                },
+            },
             // Star badges
             BadgeDefinition {
                 id: "star_1".to_string(),
@@ -411,6 +488,11 @@ pub mod badge {
             }
             BadgeCondition::Languages { count } => context.languages_count >= *count,
             BadgeCondition::Level { threshold } => context.current_level >= *threshold,
+            BadgeCondition::Prestige { rank } => context.prestige_rank >= *rank,
+            BadgeCondition::SeasonsCompleted { count } => context.seasons_completed >= *count,
+            BadgeCondition::SeasonPercentile { min_percentile } => {
+                context.best_season_percentile >= *min_percentile
+            }
             BadgeCondition::StarsReceived { threshold } => {
                 context.total_stars_received >= *threshold
             }
@@ -528,6 +610,27 @@ pub mod badge {
                 target_value: *threshold,
                 progress_percent: calculate_percent(context.current_level, *threshold),
             },
+            BadgeCondition::Prestige { rank } => BadgeProgress {
+                badge_id: badge_id.to_string(),
+                current_value: context.prestige_rank,
+                target_value: *rank,
+                progress_percent: calculate_percent(context.prestige_rank, *rank),
+            },
+            BadgeCondition::SeasonsCompleted { count } => BadgeProgress {
+                badge_id: badge_id.to_string(),
+                current_value: context.seasons_completed,
+                target_value: *count,
+                progress_percent: calculate_percent(context.seasons_completed, *count),
+            },
+            BadgeCondition::SeasonPercentile { min_percentile } => BadgeProgress {
+                badge_id: badge_id.to_string(),
+                current_value: context.best_season_percentile,
+                target_value: *min_percentile,
+                progress_percent: calculate_percent(
+                    context.best_season_percentile,
+                    *min_percentile,
+                ),
+            },
             BadgeCondition::StarsReceived { threshold } => BadgeProgress {
                 badge_id: badge_id.to_string(),
                 current_value: context.total_stars_received,
@@ -819,6 +922,32 @@ pub mod badge {
             assert!(!results.iter().any(|r| r.badge_id == "level_5"));
         }
 
+        #[test]
+        fn test_prestige_badges() {
+            let context = BadgeEvalContext {
+                prestige_rank: 3,
+                ..Default::default()
+            };
+            let results = evaluate_badges(&context, &[]);
+            assert!(results.iter().any(|r| r.badge_id == "prestige_1"));
+            assert!(results.iter().any(|r| r.badge_id == "prestige_3"));
+            assert!(!results.iter().any(|r| r.badge_id == "prestige_10"));
+        }
+
+        #[test]
+        fn test_season_badges() {
+            let context = BadgeEvalContext {
+                seasons_completed: 4,
+                best_season_percentile: 80,
+                ..Default::default()
+            };
+            let results = evaluate_badges(&context, &[]);
+            assert!(results.iter().any(|r| r.badge_id == "season_1"));
+            assert!(results.iter().any(|r| r.badge_id == "season_4"));
+            assert!(results.iter().any(|r| r.badge_id == "season_top_quartile"));
+            assert!(!results.iter().any(|r| r.badge_id == "season_personal_best"));
+        }
+
         #[test]
         fn test_stars_badge_first_star() {
             let context = BadgeEvalContext {
@@ -52,6 +52,19 @@ pub mod cache_types {
     /// Language / repository breakdown payload (per-language byte share +
     /// per-repository additions/deletions). See Issue #193.
     pub const LANGUAGE_BREAKDOWN: &str = "language_breakdown";
+    /// Per-repository drill-down. Stored one row per
+    /// repository and window under [`repository_detail_key`].
+    pub const REPOSITORY_DETAIL: &str = "repository_detail";
+
+    /// `data_type` of one repository's drill-down over a `days` window
+    pub fn repository_detail_key(name_with_owner: &str, days: i32) -> String {
+        format!(
+            "{}:{}:{}",
+            REPOSITORY_DETAIL,
+            name_with_owner.to_lowercase(),
+            days
+        )
+    }
 }
 
 /// Default cache durations in minutes
@@ -98,4 +111,13 @@ pub mod cache_durations {
     /// #193 requirement and prevents focus revalidations from burning
     /// the GraphQL budget on what is fundamentally slow-moving data.
     pub const LANGUAGE_BREAKDOWN: i64 = 1440;
+    /// Issue comment page cache duration (10 minutes), stored in
+    /// `issue_comments_cache` rather than `activity_cache` because it is
+    /// keyed per issue. Posting a comment drops the issue's pages anyway,
+    /// so the TTL only bounds how long other people's replies stay hidden.
+    pub const ISSUE_COMMENTS: i64 = 10;
+    /// Repository drill-down cache duration (1 hour). One GraphQL call
+    /// plus up to ten commit pages per repository — cheap enough to
+    /// refresh hourly while the detail view is open.
+    pub const REPOSITORY_DETAIL: i64 = 60;
 }
@@ -9,6 +9,24 @@ use serde::{Deserialize, Serialize};
 pub enum ChallengeType {
     Daily,
     Weekly,
+    Monthly,
+    Quarterly,
+    /// User-chosen date range
+    Custom,
+}
+
+impl ChallengeType {
+    /// Parse a stored / requested type string, rejecting unknown values
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "daily" => Some(ChallengeType::Daily),
+            "weekly" => Some(ChallengeType::Weekly),
+            "monthly" => Some(ChallengeType::Monthly),
+            "quarterly" => Some(ChallengeType::Quarterly),
+            "custom" => Some(ChallengeType::Custom),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for ChallengeType {
@@ -16,15 +34,69 @@ impl std::fmt::Display for ChallengeType {
         match self {
             ChallengeType::Daily => write!(f, "daily"),
             ChallengeType::Weekly => write!(f, "weekly"),
+            ChallengeType::Monthly => write!(f, "monthly"),
+            ChallengeType::Quarterly => write!(f, "quarterly"),
+            ChallengeType::Custom => write!(f, "custom"),
         }
     }
 }
 
 impl From<String> for ChallengeType {
     fn from(s: String) -> Self {
-        match s.as_str() {
-            "weekly" => ChallengeType::Weekly,
-            _ => ChallengeType::Daily,
+        ChallengeType::parse(&s).unwrap_or(ChallengeType::Daily)
+    }
+}
+
+/// Difficulty preference for auto-generated challenges
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ChallengeDifficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl ChallengeDifficulty {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChallengeDifficulty::Easy => "easy",
+            ChallengeDifficulty::Normal => "normal",
+            ChallengeDifficulty::Hard => "hard",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "easy" => ChallengeDifficulty::Easy,
+            "hard" => ChallengeDifficulty::Hard,
+            _ => ChallengeDifficulty::Normal, // default
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChallengeDifficulty::Easy => "やさしい",
+            ChallengeDifficulty::Normal => "ふつう",
+            ChallengeDifficulty::Hard => "むずかしい",
+        }
+    }
+
+    /// Scale applied to the recommended target
+    pub fn target_multiplier(&self) -> f32 {
+        match self {
+            ChallengeDifficulty::Easy => 0.8,
+            ChallengeDifficulty::Normal => 1.0,
+            ChallengeDifficulty::Hard => 1.25,
+        }
+    }
+
+    /// Scale applied to the reward on top of the (already target-based) XP
+    pub fn reward_multiplier(&self) -> f32 {
+        match self {
+            ChallengeDifficulty::Easy => 0.8,
+            ChallengeDifficulty::Normal => 1.0,
+            ChallengeDifficulty::Hard => 1.3,
         }
     }
 }
@@ -40,6 +112,44 @@ pub enum ChallengeRequirement {
     Streak { days: i32 },
 }
 
+impl ChallengeRequirement {
+    /// Target metric and value the GitHub sync tracks this requirement by.
+    /// Streaks aren't a challenge metric, so they have none.
+    pub fn target(&self) -> Option<(&'static str, i32)> {
+        match self {
+            ChallengeRequirement::Commits { count } => Some(("commits", *count)),
+            ChallengeRequirement::Reviews { count } => Some(("reviews", *count)),
+            ChallengeRequirement::PullRequests { count } => Some(("prs", *count)),
+            ChallengeRequirement::Issues { count } => Some(("issues", *count)),
+            ChallengeRequirement::Streak { .. } => None,
+        }
+    }
+}
+
+/// Most reroll (or skip) tokens a user can hold at once
+pub const MAX_CHALLENGE_TOKENS: i32 = 3;
+
+/// Reroll / skip token balance
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeTokens {
+    /// Replace an active daily/weekly challenge with another template
+    pub reroll_tokens: i32,
+    /// Retire an active daily/weekly challenge without failing it
+    pub skip_tokens: i32,
+}
+
+/// Outcome of spending a reroll / skip token on a challenge
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenSpend {
+    /// The token was spent and the challenge updated
+    Spent,
+    /// No token of that kind left; nothing changed
+    NoToken,
+    /// The challenge is no longer active; the token was kept
+    ChallengeInactive,
+}
+
 /// Challenge model - active challenges
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -53,8 +163,10 @@ pub struct Challenge {
     pub reward_xp: i32,
     pub start_date: DateTime<Utc>,
     pub end_date: DateTime<Utc>,
-    pub status: String, // "active", "completed", "failed"
+    pub status: String, // "active", "completed", "failed", "skipped"
     pub completed_at: Option<DateTime<Utc>>,
+    /// Why the target was chosen (auto-generated challenges only)
+    pub target_reason: Option<String>,
 }
 
 impl Challenge {
@@ -43,6 +43,20 @@ impl DailyCodeStats {
     }
 }
 
+/// One repository's share of a day's code statistics
+/// (`daily_repository_code_stats`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryCodeStats {
+    /// Date in YYYY-MM-DD format
+    pub date: String,
+    /// `owner/name`
+    pub repository: String,
+    pub additions: i32,
+    pub deletions: i32,
+    pub commits_count: i32,
+}
+
 /// Sync metadata for tracking incremental data fetching
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
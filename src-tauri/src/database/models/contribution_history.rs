@@ -0,0 +1,180 @@
+//! Contribution history models
+//!
+//! GitHub's `contributionCalendar` only covers one year per request, so
+//! every fetched contribution day is persisted in `contribution_days`.
+//! Past years are backfilled once through
+//! `contributionsCollection(from:, to:)` and recorded in
+//! `contribution_years`; the contribution graph can then switch between
+//! years and show lifetime totals without further API calls.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/models/mod.rs
+//!   ├─ src-tauri/src/database/repository/contribution_history.rs
+//!   └─ src-tauri/src/commands/contribution_history.rs
+
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Contribution count of one day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionDayCount {
+    pub date: NaiveDate,
+    pub count: i32,
+}
+
+/// Stored contributions of one calendar year
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionYearSummary {
+    pub year: i32,
+    pub total_contributions: i64,
+    /// Days with at least one contribution
+    pub active_days: i64,
+    /// Whether the whole year was fetched by the backfill. Years only seen
+    /// through the rolling one-year calendar can be incomplete.
+    pub backfilled: bool,
+}
+
+/// Lifetime contribution history of a user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionHistory {
+    /// Newest year first
+    pub years: Vec<ContributionYearSummary>,
+    pub lifetime_contributions: i64,
+    pub lifetime_active_days: i64,
+    /// When the backfill last ran (RFC3339), `None` if it never did
+    pub backfilled_at: Option<String>,
+}
+
+impl ContributionHistory {
+    /// Combine per-year totals with the backfilled years
+    pub fn new(
+        mut years: Vec<ContributionYearSummary>,
+        backfilled_years: &[i32],
+        backfilled_at: Option<String>,
+    ) -> Self {
+        for summary in &mut years {
+            summary.backfilled = backfilled_years.contains(&summary.year);
+        }
+        years.sort_by(|a, b| b.year.cmp(&a.year));
+
+        Self {
+            lifetime_contributions: years.iter().map(|y| y.total_contributions).sum(),
+            lifetime_active_days: years.iter().map(|y| y.active_days).sum(),
+            years,
+            backfilled_at,
+        }
+    }
+}
+
+/// Stored days of `year` up to `today`, or `None` for a future year
+pub fn year_range(year: i32, today: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+    if year > today.year() {
+        return None;
+    }
+    let start = NaiveDate::from_ymd_opt(year, 1, 1)?;
+    let end = NaiveDate::from_ymd_opt(year, 12, 31)?.min(today);
+    Some((start, end))
+}
+
+/// Lay out `[start, end]` in Sunday-first weeks like GitHub's calendar.
+///
+/// Days without a stored count are filled in with zero; the first and last
+/// weeks are partial, as in the calendar GitHub returns.
+pub fn calendar_weeks(
+    start: NaiveDate,
+    end: NaiveDate,
+    days: &[ContributionDayCount],
+) -> Vec<Vec<ContributionDayCount>> {
+    let counts: std::collections::HashMap<NaiveDate, i32> =
+        days.iter().map(|d| (d.date, d.count)).collect();
+
+    let mut weeks: Vec<Vec<ContributionDayCount>> = Vec::new();
+    let mut date = start;
+    while date <= end {
+        if weeks.is_empty() || date.weekday().num_days_from_sunday() == 0 {
+            weeks.push(Vec::with_capacity(7));
+        }
+        if let Some(week) = weeks.last_mut() {
+            week.push(ContributionDayCount {
+                date,
+                count: counts.get(&date).copied().unwrap_or(0),
+            });
+        }
+        date += Duration::days(1);
+    }
+    weeks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn summary(year: i32, total: i64, active: i64) -> ContributionYearSummary {
+        ContributionYearSummary {
+            year,
+            total_contributions: total,
+            active_days: active,
+            backfilled: false,
+        }
+    }
+
+    #[test]
+    fn test_history_totals_and_order() {
+        let history = ContributionHistory::new(
+            vec![
+                summary(2024, 300, 100),
+                summary(2026, 50, 20),
+                summary(2025, 400, 150),
+            ],
+            &[2024, 2025],
+            Some("2026-01-02T00:00:00+00:00".to_string()),
+        );
+
+        let years: Vec<i32> = history.years.iter().map(|y| y.year).collect();
+        assert_eq!(years, vec![2026, 2025, 2024]);
+        assert!(!history.years[0].backfilled);
+        assert!(history.years[1].backfilled);
+        assert_eq!(history.lifetime_contributions, 750);
+        assert_eq!(history.lifetime_active_days, 270);
+    }
+
+    #[test]
+    fn test_year_range() {
+        let today = date(2026, 10, 18);
+        assert_eq!(
+            year_range(2025, today),
+            Some((date(2025, 1, 1), date(2025, 12, 31)))
+        );
+        assert_eq!(
+            year_range(2026, today),
+            Some((date(2026, 1, 1), date(2026, 10, 18)))
+        );
+        assert_eq!(year_range(2027, today), None);
+    }
+
+    #[test]
+    fn test_calendar_weeks_are_sunday_first() {
+        // 2025-01-01 is a Wednesday
+        let days = [ContributionDayCount {
+            date: date(2025, 1, 5),
+            count: 4,
+        }];
+        let weeks = calendar_weeks(date(2025, 1, 1), date(2025, 12, 31), &days);
+
+        assert_eq!(weeks[0].len(), 4);
+        assert_eq!(weeks[0][0].date, date(2025, 1, 1));
+        assert_eq!(weeks[1][0].date, date(2025, 1, 5));
+        assert_eq!(weeks[1][0].count, 4);
+        assert_eq!(weeks.iter().map(Vec::len).sum::<usize>(), 365);
+        assert!(weeks[1..weeks.len() - 1].iter().all(|w| w.len() == 7));
+    }
+}
@@ -41,6 +41,14 @@ pub struct GitHubStatsSnapshot {
     /// Snapshot date in YYYY-MM-DD format
     pub snapshot_date: String,
     pub created_at: String,
+    /// The PR / closed-issue counts are Search API fallback values, not
+    /// real totals (see `GitHubStats::search_fallback`)
+    #[serde(default)]
+    pub search_fallback: bool,
+    /// Written by the first sync after a repository filter change; older
+    /// snapshots counted a different set of repositories
+    #[serde(default)]
+    pub filter_rebaseline: bool,
 }
 
 /// Statistics difference between two snapshots
@@ -88,9 +96,18 @@ impl GitHubStatsSnapshot {
             total_contributions,
             snapshot_date: snapshot_date.to_string(),
             created_at: String::new(), // Will be set by database
+            search_fallback: false,
+            filter_rebaseline: false,
         }
     }
 
+    /// Mark the totals that cannot be diffed across periods
+    pub fn with_reliability(mut self, search_fallback: bool, filter_rebaseline: bool) -> Self {
+        self.search_fallback = search_fallback;
+        self.filter_rebaseline = filter_rebaseline;
+        self
+    }
+
     /// Calculate the difference between this snapshot and a previous one
     ///
     /// If `previous` is None (first sync), returns a default diff with all zeros.
@@ -181,6 +198,8 @@ mod tests {
             total_contributions: 180,
             snapshot_date: "2025-11-29".to_string(),
             created_at: "2025-11-29T00:00:00Z".to_string(),
+            search_fallback: false,
+            filter_rebaseline: false,
         };
 
         let current = GitHubStatsSnapshot::new(
@@ -285,6 +304,8 @@ mod tests {
             total_contributions: 200,
             snapshot_date: "2025-11-29".to_string(),
             created_at: "2025-11-29T00:00:00Z".to_string(),
+            search_fallback: false,
+            filter_rebaseline: false,
         };
 
         // Stars decreased (e.g., repository deleted)
@@ -0,0 +1,340 @@
+//! Activity heatmap models
+//!
+//! Alternative contribution heatmaps driven by local data:
+//! GitHub contribution counts (`contribution_days`), XP per day
+//! (`xp_history`), lines changed and commits (`daily_code_stats`).
+//! Lines and commits can be narrowed to one repository
+//! (`daily_repository_code_stats`); contributions and XP are only stored
+//! per day, so they can't.
+//!
+//! Every metric is normalised onto the same five colour levels so the
+//! graph, its legend and its tooltips work the same for all of them.
+//! Levels follow GitHub's approach: 0 for no activity, then the quartiles
+//! of the non-zero days in the range.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/models/mod.rs
+//!   └─ src-tauri/src/commands/heatmap.rs
+//! Dependencies:
+//!   └─ src-tauri/src/database/models/contribution_history.rs (year_range, calendar_weeks)
+
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::contribution_history::{calendar_weeks, year_range, ContributionDayCount};
+use super::repo_filter::split_repository_name;
+
+/// Heatmap metric values
+pub mod heatmap_metrics {
+    /// GitHub contribution count (stored contribution calendar)
+    pub const CONTRIBUTIONS: &str = "contributions";
+    /// Live XP earned per day
+    pub const XP: &str = "xp";
+    /// Lines changed (additions + deletions)
+    pub const LINES: &str = "lines";
+    /// Commits counted by the code stats sync
+    pub const COMMITS: &str = "commits";
+
+    pub const METRICS: [&str; 4] = [CONTRIBUTIONS, XP, LINES, COMMITS];
+    /// Metrics with a per-repository split
+    pub const REPOSITORY_METRICS: [&str; 2] = [LINES, COMMITS];
+}
+
+/// Number of colour levels, including level 0 (no activity)
+pub const HEATMAP_LEVELS: u8 = 5;
+
+/// Days of the rolling range used when no year is requested
+pub const HEATMAP_DEFAULT_DAYS: i64 = 365;
+
+/// Heatmap request from the frontend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapRequest {
+    /// One of [`heatmap_metrics`]
+    pub metric: String,
+    /// Calendar year; `None` for the last 365 days
+    #[serde(default)]
+    pub year: Option<i32>,
+    /// `owner/name`; only that repository's share of each day. Lines and
+    /// commits only
+    #[serde(default)]
+    pub repository: Option<String>,
+}
+
+impl HeatmapRequest {
+    /// Validate the request and resolve its date range
+    pub fn resolve(&self, today: NaiveDate) -> Result<(NaiveDate, NaiveDate), String> {
+        if !heatmap_metrics::METRICS.contains(&self.metric.as_str()) {
+            return Err(format!(
+                "不明なヒートマップ指標です: {}（{} のいずれかを指定してください）",
+                self.metric,
+                heatmap_metrics::METRICS.join(", ")
+            ));
+        }
+        if let Some(repository) = &self.repository {
+            if !heatmap_metrics::REPOSITORY_METRICS.contains(&self.metric.as_str()) {
+                return Err(format!(
+                    "{} はリポジトリ別に集計できません（{} のみ対応しています）",
+                    self.metric,
+                    heatmap_metrics::REPOSITORY_METRICS.join(", ")
+                ));
+            }
+            if split_repository_name(repository).is_none() {
+                return Err(format!(
+                    "リポジトリは owner/name 形式で指定してください: {}",
+                    repository
+                ));
+            }
+        }
+
+        match self.year {
+            Some(year) => {
+                year_range(year, today).ok_or_else(|| format!("{}年のデータはまだありません", year))
+            }
+            None => Ok((today - Duration::days(HEATMAP_DEFAULT_DAYS - 1), today)),
+        }
+    }
+}
+
+/// Value of one day before normalisation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeatmapValue {
+    pub value: i64,
+    /// Lines metric only, for the tooltip
+    pub additions: Option<i64>,
+    pub deletions: Option<i64>,
+}
+
+/// One normalised heatmap cell
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapDay {
+    pub date: NaiveDate,
+    pub value: i64,
+    /// Colour level, `0..HEATMAP_LEVELS`
+    pub level: u8,
+    pub additions: Option<i64>,
+    pub deletions: Option<i64>,
+}
+
+/// Normalised calendar for one metric
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityHeatmap {
+    pub metric: String,
+    pub repository: Option<String>,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    /// Sunday-first weeks, oldest first; first and last weeks are partial
+    pub weeks: Vec<Vec<HeatmapDay>>,
+    pub total: i64,
+    pub active_days: i64,
+    pub max: i64,
+    /// Upper bounds of levels 1–3; values above the last are level 4
+    pub thresholds: [i64; 3],
+    /// Repositories with commits in the range, for the repository filter
+    pub repositories: Vec<String>,
+}
+
+/// Quartile upper bounds of the non-zero values
+pub fn level_thresholds(values: &[i64]) -> [i64; 3] {
+    let mut active: Vec<i64> = values.iter().copied().filter(|v| *v > 0).collect();
+    if active.is_empty() {
+        return [0; 3];
+    }
+    active.sort_unstable();
+    let quantile = |q: usize| active[(active.len() - 1) * q / 4];
+    [quantile(1), quantile(2), quantile(3)]
+}
+
+/// Colour level of a value
+pub fn level_for(value: i64, thresholds: &[i64; 3]) -> u8 {
+    if value <= 0 {
+        return 0;
+    }
+    thresholds
+        .iter()
+        .position(|bound| value <= *bound)
+        .map(|i| i as u8 + 1)
+        .unwrap_or(HEATMAP_LEVELS - 1)
+}
+
+impl ActivityHeatmap {
+    /// Lay out and normalise the values of `[start, end]`.
+    ///
+    /// Days missing from `values` are zero.
+    pub fn build(
+        metric: &str,
+        repository: Option<String>,
+        start: NaiveDate,
+        end: NaiveDate,
+        values: &HashMap<NaiveDate, HeatmapValue>,
+        repositories: Vec<String>,
+    ) -> Self {
+        let in_range: Vec<i64> = values
+            .iter()
+            .filter(|(date, _)| **date >= start && **date <= end)
+            .map(|(_, v)| v.value)
+            .collect();
+        let thresholds = level_thresholds(&in_range);
+
+        // Only the layout is reused; values stay i64
+        let weeks = calendar_weeks(start, end, &[])
+            .into_iter()
+            .map(|week| {
+                week.into_iter()
+                    .map(|ContributionDayCount { date, .. }| {
+                        let day = values.get(&date).copied().unwrap_or_default();
+                        HeatmapDay {
+                            date,
+                            value: day.value,
+                            level: level_for(day.value, &thresholds),
+                            additions: day.additions,
+                            deletions: day.deletions,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            metric: metric.to_string(),
+            repository,
+            start,
+            end,
+            weeks,
+            total: in_range.iter().filter(|v| **v > 0).sum(),
+            active_days: in_range.iter().filter(|v| **v > 0).count() as i64,
+            max: in_range.iter().copied().max().unwrap_or(0).max(0),
+            thresholds,
+            repositories,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn request(metric: &str, year: Option<i32>, repository: Option<&str>) -> HeatmapRequest {
+        HeatmapRequest {
+            metric: metric.to_string(),
+            year,
+            repository: repository.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_resolve_ranges() {
+        let today = date(2026, 10, 18);
+        assert_eq!(
+            request(heatmap_metrics::XP, None, None).resolve(today),
+            Ok((date(2025, 10, 19), today))
+        );
+        assert_eq!(
+            request(heatmap_metrics::LINES, Some(2024), None).resolve(today),
+            Ok((date(2024, 1, 1), date(2024, 12, 31)))
+        );
+        assert!(request(heatmap_metrics::LINES, Some(2027), None)
+            .resolve(today)
+            .is_err());
+    }
+
+    #[test]
+    fn test_resolve_rejects_bad_input() {
+        let today = date(2026, 10, 18);
+        assert!(request("stars", None, None).resolve(today).is_err());
+        assert!(request(heatmap_metrics::COMMITS, None, Some("not a repo"))
+            .resolve(today)
+            .is_err());
+        assert!(request(heatmap_metrics::COMMITS, None, Some("octo/app"))
+            .resolve(today)
+            .is_ok());
+        // Only stored per day, so no repository split
+        assert!(request(heatmap_metrics::XP, None, Some("octo/app"))
+            .resolve(today)
+            .is_err());
+        assert!(
+            request(heatmap_metrics::CONTRIBUTIONS, None, Some("octo/app"))
+                .resolve(today)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_level_thresholds_and_levels() {
+        let thresholds = level_thresholds(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 0]);
+        assert_eq!(thresholds, [2, 4, 6]);
+
+        assert_eq!(level_for(0, &thresholds), 0);
+        assert_eq!(level_for(-5, &thresholds), 0);
+        assert_eq!(level_for(1, &thresholds), 1);
+        assert_eq!(level_for(4, &thresholds), 2);
+        assert_eq!(level_for(6, &thresholds), 3);
+        assert_eq!(level_for(100, &thresholds), 4);
+
+        // A single active day still gets a visible level
+        let single = level_thresholds(&[0, 9]);
+        assert_eq!(level_for(9, &single), 1);
+        assert_eq!(level_thresholds(&[]), [0; 3]);
+    }
+
+    #[test]
+    fn test_build_heatmap() {
+        let values = HashMap::from([
+            (
+                date(2026, 10, 12),
+                HeatmapValue {
+                    value: 30,
+                    additions: Some(20),
+                    deletions: Some(10),
+                },
+            ),
+            (
+                date(2026, 10, 14),
+                HeatmapValue {
+                    value: 5,
+                    ..Default::default()
+                },
+            ),
+            // Outside the range
+            (
+                date(2026, 9, 1),
+                HeatmapValue {
+                    value: 1000,
+                    ..Default::default()
+                },
+            ),
+        ]);
+
+        let heatmap = ActivityHeatmap::build(
+            heatmap_metrics::LINES,
+            None,
+            date(2026, 10, 11),
+            date(2026, 10, 17),
+            &values,
+            vec!["octo/app".to_string()],
+        );
+
+        // 2026-10-11 is a Sunday: one full week
+        assert_eq!(heatmap.weeks.len(), 1);
+        assert_eq!(heatmap.weeks[0].len(), 7);
+        assert_eq!(heatmap.total, 35);
+        assert_eq!(heatmap.active_days, 2);
+        assert_eq!(heatmap.max, 30);
+
+        let monday = &heatmap.weeks[0][1];
+        assert_eq!(monday.date, date(2026, 10, 12));
+        assert_eq!((monday.additions, monday.deletions), (Some(20), Some(10)));
+        assert!(monday.level > heatmap.weeks[0][3].level);
+        assert_eq!(heatmap.weeks[0][0].level, 0);
+    }
+}
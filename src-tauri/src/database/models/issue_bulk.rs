@@ -0,0 +1,207 @@
+//! Bulk issue operation models
+//!
+//! Request and result types of `bulk_update_issues`: one action (move,
+//! add / remove labels, set assignees, close) applied to many kanban
+//! cards at once. Failures are reported per issue instead of aborting the
+//! whole batch.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/models/mod.rs
+//!   └─ src-tauri/src/commands/issue_bulk.rs
+//! Dependencies:
+//!   └─ src-tauri/src/database/models/project.rs (CachedIssue, IssueStatus)
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::project::{CachedIssue, IssueStatus};
+
+/// Most issues one bulk call may touch.
+pub const MAX_BULK_ISSUES: usize = 100;
+
+/// GitHub requests in flight at once during a bulk call. Low enough to
+/// stay clear of the secondary (abuse) rate limit on write endpoints.
+pub const BULK_CONCURRENCY: usize = 4;
+
+/// One selected card. `repo_full_name` defaults to the primary repository.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BulkIssueTarget {
+    pub issue_number: i32,
+    #[serde(default)]
+    pub repo_full_name: Option<String>,
+}
+
+/// What to do with every selected issue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BulkIssueAction {
+    /// Move to a kanban column (closes / reopens like a drag would).
+    Move {
+        status: IssueStatus,
+    },
+    AddLabels {
+        labels: Vec<String>,
+    },
+    RemoveLabels {
+        labels: Vec<String>,
+    },
+    /// Replace the assignees (empty = unassign everyone).
+    SetAssignees {
+        assignees: Vec<String>,
+    },
+    /// Close with a reason (`completed` / `not_planned`).
+    Close {
+        reason: String,
+    },
+}
+
+impl BulkIssueAction {
+    /// Trim names and drop empty / duplicate entries.
+    pub fn normalized(self) -> Self {
+        fn clean(values: Vec<String>) -> Vec<String> {
+            let mut seen = HashSet::new();
+            values
+                .into_iter()
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty() && seen.insert(v.clone()))
+                .collect()
+        }
+
+        match self {
+            BulkIssueAction::AddLabels { labels } => BulkIssueAction::AddLabels {
+                labels: clean(labels),
+            },
+            BulkIssueAction::RemoveLabels { labels } => BulkIssueAction::RemoveLabels {
+                labels: clean(labels),
+            },
+            BulkIssueAction::SetAssignees { assignees } => BulkIssueAction::SetAssignees {
+                assignees: clean(assignees),
+            },
+            other => other,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            BulkIssueAction::AddLabels { labels } | BulkIssueAction::RemoveLabels { labels } => {
+                if labels.is_empty() {
+                    return Err("ラベルを1つ以上指定してください".to_string());
+                }
+                // Status labels belong to the kanban columns; use Move.
+                if labels.iter().any(|l| IssueStatus::from_label(l).is_some()) {
+                    return Err(
+                        "status: ラベルは一括ラベル操作では変更できません。移動を使ってください。"
+                            .to_string(),
+                    );
+                }
+                Ok(())
+            }
+            BulkIssueAction::Close { reason } => match reason.as_str() {
+                "completed" | "not_planned" => Ok(()),
+                other => Err(format!("Invalid close reason: {}", other)),
+            },
+            BulkIssueAction::Move { .. } | BulkIssueAction::SetAssignees { .. } => Ok(()),
+        }
+    }
+}
+
+/// Drop repeated targets, keeping the first occurrence's order.
+pub fn dedupe_bulk_targets(targets: Vec<BulkIssueTarget>) -> Vec<BulkIssueTarget> {
+    let mut seen = HashSet::new();
+    targets
+        .into_iter()
+        .filter(|t| seen.insert(t.clone()))
+        .collect()
+}
+
+/// An issue the bulk action could not be applied to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkIssueFailure {
+    pub issue_number: i32,
+    pub repo_full_name: Option<String>,
+    pub error: String,
+}
+
+/// Result of `bulk_update_issues`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulkIssueResult {
+    /// Issues as cached after the batch, including queued offline moves.
+    pub updated: Vec<CachedIssue>,
+    /// How many of `updated` are queued in the outbox (offline moves).
+    pub queued: usize,
+    pub failed: Vec<BulkIssueFailure>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bulk_action_normalize_and_validate() {
+        let action = BulkIssueAction::AddLabels {
+            labels: vec![" bug ".into(), "".into(), "bug".into(), "ui".into()],
+        }
+        .normalized();
+        assert_eq!(
+            action,
+            BulkIssueAction::AddLabels {
+                labels: vec!["bug".into(), "ui".into()]
+            }
+        );
+        assert!(action.validate().is_ok());
+
+        assert!(BulkIssueAction::RemoveLabels { labels: vec![] }
+            .validate()
+            .is_err());
+        assert!(BulkIssueAction::AddLabels {
+            labels: vec!["status:done".into()]
+        }
+        .validate()
+        .is_err());
+        assert!(BulkIssueAction::Close {
+            reason: "duplicate".into()
+        }
+        .validate()
+        .is_err());
+        assert!(BulkIssueAction::SetAssignees { assignees: vec![] }
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_bulk_action_wire_format() {
+        let action: BulkIssueAction =
+            serde_json::from_str(r#"{"type":"move","status":"in-review"}"#).unwrap();
+        assert_eq!(
+            action,
+            BulkIssueAction::Move {
+                status: IssueStatus::InReview
+            }
+        );
+    }
+
+    #[test]
+    fn test_dedupe_bulk_targets() {
+        let target = |n: i32, repo: Option<&str>| BulkIssueTarget {
+            issue_number: n,
+            repo_full_name: repo.map(String::from),
+        };
+        let deduped = dedupe_bulk_targets(vec![
+            target(1, None),
+            target(2, Some("octo/api")),
+            target(1, None),
+            target(2, Some("octo/web")),
+        ]);
+        assert_eq!(
+            deduped,
+            vec![
+                target(1, None),
+                target(2, Some("octo/api")),
+                target(2, Some("octo/web")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,54 @@
+//! Issue comment models
+//!
+//! Comments are not synced with the issue list; `get_issue_comments`
+//! fetches them page by page when the detail modal opens and keeps each
+//! page in `issue_comments_cache` for `cache_durations::ISSUE_COMMENTS`
+//! minutes (longer as an offline fallback).
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/models/mod.rs
+//!   └─ src-tauri/src/commands/issue_comments.rs
+//! Dependencies:
+//!   (none)
+
+use serde::{Deserialize, Serialize};
+
+/// Comments requested per page.
+pub const ISSUE_COMMENTS_PER_PAGE: i32 = 30;
+
+/// Count of one reaction kind on a comment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReactionCount {
+    /// GitHub reaction content (`+1`, `heart`, `rocket`, ...).
+    pub content: String,
+    pub emoji: String,
+    pub count: i64,
+}
+
+/// One comment of an issue thread.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueComment {
+    pub id: i64,
+    /// `None` for comments of deleted accounts.
+    pub author_login: Option<String>,
+    pub author_avatar_url: Option<String>,
+    pub author_association: Option<String>,
+    pub body: String,
+    pub html_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+    /// Non-zero reactions only, in GitHub's display order.
+    pub reactions: Vec<ReactionCount>,
+}
+
+/// One page of an issue's comments (oldest first).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueCommentsPage {
+    pub comments: Vec<IssueComment>,
+    /// 1-based page number.
+    pub page: i32,
+    /// True when the page was full, i.e. another page may follow.
+    pub has_more: bool,
+}
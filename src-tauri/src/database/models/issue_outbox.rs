@@ -0,0 +1,121 @@
+//! Offline write queue (outbox) models
+//!
+//! Issue mutations issued while GitHub is unreachable are recorded in the
+//! `issue_outbox` table and replayed once connectivity returns. The cached
+//! issue row is updated optimistically at enqueue time so the kanban board
+//! reflects the user's intent immediately.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/models/mod.rs
+//!   └─ src-tauri/src/commands/issue_outbox.rs
+//! Dependencies:
+//!   └─ (none)
+
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Operation kinds stored in `issue_outbox.operation`.
+pub mod outbox_operations {
+    /// Kanban status change (`update_issue_status`).
+    pub const UPDATE_STATUS: &str = "update_status";
+    /// New issue (`create_github_issue`).
+    pub const CREATE_ISSUE: &str = "create_issue";
+}
+
+/// Lifecycle states stored in `issue_outbox.status`.
+///
+/// Successfully replayed operations are deleted rather than kept with an
+/// `applied` state — the outbox only ever holds work that still needs the
+/// user's (or the network's) attention.
+pub mod outbox_statuses {
+    /// Waiting for the next replay.
+    pub const PENDING: &str = "pending";
+    /// GitHub rejected the operation (validation error, permission, etc.).
+    pub const FAILED: &str = "failed";
+    /// The issue changed on GitHub after the operation was queued.
+    pub const CONFLICT: &str = "conflict";
+}
+
+/// One queued issue mutation.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboxOperation {
+    pub id: i64,
+    pub user_id: i64,
+    pub project_id: i64,
+    /// Repository of the target issue (migration v25).
+    #[sqlx(default)]
+    pub repo_full_name: Option<String>,
+    /// See [`outbox_operations`].
+    pub operation: String,
+    /// Target issue number. For `create_issue` this is the negative
+    /// placeholder number of the optimistic cache row.
+    pub issue_number: i32,
+    /// Operation-specific JSON payload ([`UpdateStatusPayload`] /
+    /// [`CreateIssuePayload`]).
+    pub payload_json: String,
+    /// `cached_issues.github_updated_at` observed when the operation was
+    /// queued. Replay compares it with GitHub's current `updated_at` to
+    /// detect edits made elsewhere in the meantime. `None` for creates.
+    pub base_updated_at: Option<String>,
+    /// See [`outbox_statuses`].
+    pub status: String,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl OutboxOperation {
+    /// Parse the payload of an `update_status` operation.
+    pub fn update_status_payload(&self) -> Option<UpdateStatusPayload> {
+        serde_json::from_str(&self.payload_json).ok()
+    }
+
+    /// Parse the payload of a `create_issue` operation.
+    pub fn create_issue_payload(&self) -> Option<CreateIssuePayload> {
+        serde_json::from_str(&self.payload_json).ok()
+    }
+}
+
+/// Payload for [`outbox_operations::UPDATE_STATUS`].
+///
+/// `previous_*` keep the pre-optimistic values so discarding the operation
+/// can roll the cache back without waiting for the next sync.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UpdateStatusPayload {
+    pub new_status: String,
+    pub previous_status: String,
+    pub previous_state: String,
+}
+
+/// Payload for [`outbox_operations::CREATE_ISSUE`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CreateIssuePayload {
+    pub title: String,
+    pub body: Option<String>,
+    pub status: String,
+    pub priority: Option<String>,
+    /// Extra labels (e.g. from an issue template), besides status / priority.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub assignees: Vec<String>,
+}
+
+/// Summary returned by `replay_issue_outbox`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboxReplayResult {
+    /// Operations pushed to GitHub and removed from the queue.
+    pub applied: i32,
+    /// Operations GitHub rejected; kept for the user to retry or discard.
+    pub failed: i32,
+    /// Operations skipped because the issue changed on GitHub.
+    pub conflicts: i32,
+    /// Operations still pending (replay stopped because GitHub is still
+    /// unreachable).
+    pub remaining: i32,
+}
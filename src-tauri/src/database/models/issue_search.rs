@@ -0,0 +1,170 @@
+//! Local issue search models
+//!
+//! `search_issues_local` runs against the `cached_issues_fts` index, so it
+//! works offline and across every project of the user. The query string
+//! mixes free-text terms with `key:value` filters, e.g.
+//! `login bug status:in-progress label:"good first issue" repo:octo/app`.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/models/mod.rs
+//!   └─ src-tauri/src/commands/issue_search.rs
+//! Dependencies:
+//!   └─ src-tauri/src/database/models/project.rs (CachedIssue)
+
+use serde::{Deserialize, Serialize};
+
+use super::project::CachedIssue;
+
+/// Marker inserted before a matched fragment in snippets. Control
+/// characters rather than HTML so the frontend can split the text into
+/// highlighted spans without ever rendering issue content as markup.
+pub const HIGHLIGHT_START: &str = "\u{2}";
+/// Marker inserted after a matched fragment in snippets.
+pub const HIGHLIGHT_END: &str = "\u{3}";
+
+/// Parsed form of a search box query.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IssueSearchQuery {
+    /// Free-text terms (quoted phrases are kept as one term).
+    pub terms: Vec<String>,
+    /// Kanban status in kebab-case (`in-progress`).
+    pub status: Option<String>,
+    pub priority: Option<String>,
+    /// Every listed label must be present.
+    pub labels: Vec<String>,
+    /// Assignee login without a leading `@`.
+    pub assignee: Option<String>,
+    /// `owner/name` or just `name`.
+    pub repo: Option<String>,
+}
+
+impl IssueSearchQuery {
+    /// Parse a raw search string.
+    ///
+    /// Unknown `key:value` tokens are treated as free text so searching
+    /// for e.g. `http://` or `TODO:` still works.
+    pub fn parse(input: &str) -> Self {
+        let mut query = Self::default();
+
+        for token in tokenize(input) {
+            let Some((key, value)) = token.split_once(':') else {
+                query.terms.push(token);
+                continue;
+            };
+            let value = value.trim_matches('"').trim();
+            if value.is_empty() {
+                query.terms.push(token);
+                continue;
+            }
+            match key.to_ascii_lowercase().as_str() {
+                "status" => {
+                    query.status = Some(value.to_ascii_lowercase().replace('_', "-"));
+                }
+                "priority" => query.priority = Some(value.to_ascii_lowercase()),
+                "label" => query.labels.push(value.to_string()),
+                "assignee" => {
+                    query.assignee = Some(value.trim_start_matches('@').to_string());
+                }
+                "repo" => query.repo = Some(value.to_string()),
+                _ => query.terms.push(token),
+            }
+        }
+
+        query
+    }
+
+    /// True when neither terms nor filters were given.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+            && self.status.is_none()
+            && self.priority.is_none()
+            && self.labels.is_empty()
+            && self.assignee.is_none()
+            && self.repo.is_none()
+    }
+}
+
+/// Split on whitespace, keeping `"double quoted"` runs (including
+/// `label:"good first issue"`) together. Surrounding quotes of plain
+/// phrases are dropped.
+fn tokenize(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut keep_quotes = false;
+
+    for c in input.chars() {
+        match c {
+            '"' => {
+                if !in_quotes {
+                    // Keep the quotes of `key:"value"`; parse() strips them.
+                    keep_quotes = current.contains(':');
+                }
+                in_quotes = !in_quotes;
+                if keep_quotes {
+                    current.push(c);
+                }
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+/// One hit returned by `search_issues_local`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueSearchResult {
+    #[serde(flatten)]
+    pub issue: CachedIssue,
+    pub project_name: String,
+    pub repo_full_name: Option<String>,
+    /// Title with matches wrapped in [`HIGHLIGHT_START`] / [`HIGHLIGHT_END`].
+    pub title_highlight: String,
+    /// Short excerpt of the body around the first match, same markers.
+    /// `None` when the body didn't match (or no free-text terms were given).
+    pub body_snippet: Option<String>,
+    /// bm25 score (lower is better); 0.0 for filter-only searches.
+    pub rank: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_terms_and_filters() {
+        let query = IssueSearchQuery::parse(
+            r#"login "dark mode" status:in_progress priority:HIGH label:bug label:"good first issue" assignee:@alice repo:octo/app"#,
+        );
+        assert_eq!(query.terms, vec!["login", "dark mode"]);
+        assert_eq!(query.status.as_deref(), Some("in-progress"));
+        assert_eq!(query.priority.as_deref(), Some("high"));
+        assert_eq!(query.labels, vec!["bug", "good first issue"]);
+        assert_eq!(query.assignee.as_deref(), Some("alice"));
+        assert_eq!(query.repo.as_deref(), Some("octo/app"));
+    }
+
+    #[test]
+    fn test_parse_unknown_or_empty_filters_are_terms() {
+        let query = IssueSearchQuery::parse("TODO: http://example.com status:");
+        assert_eq!(query.terms, vec!["TODO:", "http://example.com", "status:"]);
+        assert!(query.status.is_none());
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert!(IssueSearchQuery::parse("   ").is_empty());
+        assert!(!IssueSearchQuery::parse("label:bug").is_empty());
+    }
+}
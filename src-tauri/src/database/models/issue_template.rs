@@ -0,0 +1,134 @@
+//! Issue template models
+//!
+//! A repository's `.github/ISSUE_TEMPLATE/` holds Markdown templates
+//! (`*.md` with YAML front-matter) and issue forms (`*.yml` / `*.yaml`).
+//! `get_issue_templates` parses both into [`IssueTemplate`] for the
+//! create-issue modal, and `build_issue_from_template` turns the filled-in
+//! form back into the issue body GitHub would produce. Templates are not
+//! stored; they are fetched when the modal opens.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/models/mod.rs
+//!   └─ src-tauri/src/commands/issue_templates.rs
+//! Dependencies:
+//!   (none)
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Template kinds (`IssueTemplate::kind`).
+pub mod issue_template_kinds {
+    /// `*.md` with front-matter; the body is prefilled as-is.
+    pub const MARKDOWN: &str = "markdown";
+    /// `*.yml` issue form; the body is rendered from `fields`.
+    pub const FORM: &str = "form";
+}
+
+/// Issue form element types (`IssueFormField::field_type`).
+pub mod issue_form_field_types {
+    /// Static Markdown shown in the form, never part of the body.
+    pub const MARKDOWN: &str = "markdown";
+    pub const TEXTAREA: &str = "textarea";
+    pub const INPUT: &str = "input";
+    pub const DROPDOWN: &str = "dropdown";
+    pub const CHECKBOXES: &str = "checkboxes";
+}
+
+/// One option of a `checkboxes` element.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueFormCheckbox {
+    pub label: String,
+    /// The box must be ticked before the issue can be submitted.
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// One element of an issue form.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueFormField {
+    /// Key the submitted values are keyed by: the element's `id`, or
+    /// `field-<index>` when the form doesn't give one.
+    pub key: String,
+    /// One of [`issue_form_field_types`].
+    pub field_type: String,
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub placeholder: Option<String>,
+    /// Default value (`input` / `textarea`) or the text of a `markdown`
+    /// element.
+    #[serde(default)]
+    pub value: Option<String>,
+    /// `dropdown` choices.
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// `checkboxes` choices.
+    #[serde(default)]
+    pub checkboxes: Vec<IssueFormCheckbox>,
+    /// `dropdown` accepts several choices.
+    #[serde(default)]
+    pub multiple: bool,
+    /// Index of the preselected `dropdown` choice.
+    #[serde(default)]
+    pub default_option: Option<usize>,
+    /// `textarea` content is wrapped in a code block of this language.
+    #[serde(default)]
+    pub render: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// A parsed issue template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueTemplate {
+    /// File name inside `.github/ISSUE_TEMPLATE/`.
+    pub file_name: String,
+    /// One of [`issue_template_kinds`].
+    pub kind: String,
+    pub name: String,
+    /// `about` (Markdown) or `description` (form).
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Default issue title.
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub assignees: Vec<String>,
+    /// Markdown templates only: the body to prefill.
+    #[serde(default)]
+    pub body: Option<String>,
+    /// Issue forms only: the elements to render.
+    #[serde(default)]
+    pub fields: Vec<IssueFormField>,
+}
+
+/// Templates of one repository (`get_issue_templates`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IssueTemplateSet {
+    pub templates: Vec<IssueTemplate>,
+    /// `blank_issues_enabled` from `config.yml` (GitHub's default: true).
+    pub blank_issues_enabled: bool,
+    /// Files that could not be fetched or parsed, as `"<file>: <reason>"`.
+    pub invalid: Vec<String>,
+}
+
+/// Values entered into an issue form, keyed by [`IssueFormField::key`].
+/// `input` / `textarea` hold one entry, `dropdown` the selected choices and
+/// `checkboxes` the ticked labels.
+pub type IssueFormValues = HashMap<String, Vec<String>>;
+
+/// Issue ready to be created (`build_issue_from_template`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IssueDraft {
+    pub title: String,
+    pub body: String,
+    pub labels: Vec<String>,
+    pub assignees: Vec<String>,
+}
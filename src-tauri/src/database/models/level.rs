@@ -4,6 +4,11 @@
 //! 各レベルに必要なXPは `BASE_XP * GROWTH_FACTOR^(level-2)` で計算され、
 //! レベルが上がるほど必要XPが緩やかに増加します。
 //!
+//! プレステージ: 規定レベルに到達したユーザーはレベルを1に
+//! リセットし、プレステージランクを1つ上げられます。`total_xp` と XP 履歴は
+//! そのまま残し、リセット時点の `total_xp` を `prestige_xp_offset` として保存して
+//! `level_xp` からレベルを計算します。ランクごとに恒久的なXPボーナスが付きます。
+//!
 //! Note: レベルやXPは意味的にはu32が適切ですが、SQLiteのINTEGER型が符号あり整数であり、
 //! sqlxがi32としてマッピングするため、DB層との整合性を保つためにi32で統一しています。
 
@@ -11,6 +16,10 @@
 const BASE_XP: i32 = 100;
 /// XP growth factor per level
 const GROWTH_FACTOR: f64 = 1.15;
+/// Highest prestige rank a user can reach
+pub const MAX_PRESTIGE_RANK: i32 = 10;
+/// Permanent XP bonus granted per prestige rank (+5%)
+const PRESTIGE_XP_BONUS_PER_RANK: f64 = 0.05;
 
 /// Calculate total XP required to reach a specific level
 pub fn xp_for_level(level: i32) -> i32 {
@@ -73,11 +82,49 @@ pub fn level_title(level: i32) -> &'static str {
     }
 }
 
+/// XP counted towards the current level (XP earned since the last prestige)
+pub fn level_xp(total_xp: i32, prestige_xp_offset: i32) -> i32 {
+    (total_xp - prestige_xp_offset).max(0)
+}
+
+/// Whether a user at `level` with `prestige_rank` may prestige, given the
+/// level they configured as the prestige threshold
+pub fn can_prestige(level: i32, prestige_rank: i32, prestige_level: i32) -> bool {
+    prestige_rank < MAX_PRESTIGE_RANK && level >= prestige_level
+}
+
+/// Permanent XP multiplier for a prestige rank
+pub fn prestige_xp_multiplier(prestige_rank: i32) -> f64 {
+    1.0 + prestige_rank.clamp(0, MAX_PRESTIGE_RANK) as f64 * PRESTIGE_XP_BONUS_PER_RANK
+}
+
+/// Bonus XP earned on top of `base_xp` at a prestige rank
+pub fn prestige_bonus_xp(base_xp: i32, prestige_rank: i32) -> i32 {
+    if base_xp <= 0 {
+        return 0;
+    }
+    (base_xp as f64 * (prestige_xp_multiplier(prestige_rank) - 1.0)).round() as i32
+}
+
+/// Get the cosmetic prestige title shown next to the level
+pub fn prestige_title(prestige_rank: i32) -> Option<&'static str> {
+    match prestige_rank {
+        i32::MIN..=0 => None,
+        1 => Some("Bronze Prestige"),
+        2 => Some("Silver Prestige"),
+        3 => Some("Gold Prestige"),
+        4..=6 => Some("Platinum Prestige"),
+        7..=9 => Some("Diamond Prestige"),
+        _ => Some("Eternal Prestige"),
+    }
+}
+
 /// Level system module (for backward compatibility)
 pub mod level {
     pub use super::{
-        level_from_xp, level_title, progress_to_next_level, xp_for_level, xp_progress_in_level,
-        xp_to_next_level,
+        can_prestige, level_from_xp, level_title, level_xp, prestige_bonus_xp, prestige_title,
+        prestige_xp_multiplier, progress_to_next_level, xp_for_level, xp_progress_in_level,
+        xp_to_next_level, MAX_PRESTIGE_RANK,
     };
 }
 
@@ -170,4 +217,48 @@ mod tests {
         assert_eq!(level_title(80), "Legend");
         assert_eq!(level_title(100), "Mythic");
     }
+
+    #[test]
+    fn test_level_xp_after_prestige() {
+        assert_eq!(level_xp(5000, 0), 5000);
+        assert_eq!(level_xp(5000, 4900), 100);
+        assert_eq!(level_from_xp(level_xp(5000, 5000)), 1);
+        // An offset above total_xp never yields negative XP
+        assert_eq!(level_xp(100, 200), 0);
+    }
+
+    #[test]
+    fn test_can_prestige() {
+        assert!(!can_prestige(49, 0, 50));
+        assert!(can_prestige(50, 0, 50));
+        assert!(can_prestige(80, 3, 75));
+        assert!(!can_prestige(100, MAX_PRESTIGE_RANK, 50));
+    }
+
+    #[test]
+    fn test_prestige_xp_multiplier() {
+        assert_eq!(prestige_xp_multiplier(0), 1.0);
+        assert!((prestige_xp_multiplier(2) - 1.1).abs() < f64::EPSILON);
+        // Capped at MAX_PRESTIGE_RANK
+        assert_eq!(
+            prestige_xp_multiplier(MAX_PRESTIGE_RANK + 5),
+            prestige_xp_multiplier(MAX_PRESTIGE_RANK)
+        );
+    }
+
+    #[test]
+    fn test_prestige_bonus_xp() {
+        assert_eq!(prestige_bonus_xp(100, 0), 0);
+        assert_eq!(prestige_bonus_xp(100, 1), 5);
+        assert_eq!(prestige_bonus_xp(250, 3), 38); // 37.5 rounds up
+        assert_eq!(prestige_bonus_xp(0, 5), 0);
+    }
+
+    #[test]
+    fn test_prestige_title() {
+        assert_eq!(prestige_title(0), None);
+        assert_eq!(prestige_title(1), Some("Bronze Prestige"));
+        assert_eq!(prestige_title(5), Some("Platinum Prestige"));
+        assert_eq!(prestige_title(MAX_PRESTIGE_RANK), Some("Eternal Prestige"));
+    }
 }
@@ -0,0 +1,228 @@
+//! Project milestone models
+//!
+//! Milestones are synced from the linked repository into
+//! `project_milestones`, and each sync records today's open / closed counts
+//! in `milestone_daily_snapshots`. [`compute_burndown`] turns those plus
+//! the cached issues' created / closed dates into the burndown / burnup
+//! series shown on the project dashboard.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/models/mod.rs
+//!   └─ src-tauri/src/commands/milestones.rs
+//! Dependencies:
+//!   (none)
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Longest series `compute_burndown` returns; older days are dropped.
+pub const MAX_BURNDOWN_DAYS: i64 = 180;
+
+/// Milestone of a project's repository (`project_milestones`).
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ProjectMilestone {
+    pub id: i64,
+    pub project_id: i64,
+    pub github_milestone_id: i64,
+    pub number: i32,
+    pub title: String,
+    pub description: Option<String>,
+    /// `open` / `closed`
+    pub state: String,
+    pub due_on: Option<String>,
+    pub open_issues: i32,
+    pub closed_issues: i32,
+    pub github_created_at: Option<String>,
+    pub github_closed_at: Option<String>,
+    pub synced_at: String,
+}
+
+impl ProjectMilestone {
+    /// Completion in percent (0 for an empty milestone).
+    pub fn progress_percent(&self) -> f64 {
+        let total = self.open_issues + self.closed_issues;
+        if total == 0 {
+            0.0
+        } else {
+            self.closed_issues as f64 * 100.0 / total as f64
+        }
+    }
+}
+
+/// Open / closed counts recorded for one day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MilestoneSnapshot {
+    pub date: NaiveDate,
+    pub open_issues: i64,
+    pub closed_issues: i64,
+}
+
+/// Created / closed dates of one issue currently in the milestone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MilestoneIssueDates {
+    pub created: NaiveDate,
+    pub closed: Option<NaiveDate>,
+}
+
+/// One day of the burndown / burnup series. Actual values are `None` for
+/// days after the last observed day (the rest of a running sprint).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BurndownPoint {
+    /// `YYYY-MM-DD` (UTC)
+    pub date: String,
+    /// Remaining open issues (burndown).
+    pub open: Option<i64>,
+    /// Closed issues so far (burnup).
+    pub closed: Option<i64>,
+    /// Total issues in the milestone that day (burnup scope line).
+    pub scope: Option<i64>,
+    /// Ideal remaining work, linear from the first day's open count to 0
+    /// on the due date. `None` without a due date.
+    pub ideal: Option<f64>,
+}
+
+/// Response of `get_milestone_burndown`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MilestoneBurndown {
+    pub milestone: ProjectMilestone,
+    pub points: Vec<BurndownPoint>,
+}
+
+/// Build the daily series from `start` to `end` (inclusive), with actual
+/// values up to `last_observed`.
+///
+/// A day with a snapshot uses it as-is; other days are reconstructed from
+/// the issues now in the milestone (which misses issues that were moved
+/// out, hence snapshots win).
+pub fn compute_burndown(
+    start: NaiveDate,
+    end: NaiveDate,
+    last_observed: NaiveDate,
+    due: Option<NaiveDate>,
+    issues: &[MilestoneIssueDates],
+    snapshots: &[MilestoneSnapshot],
+) -> Vec<BurndownPoint> {
+    if end < start {
+        return Vec::new();
+    }
+    let start = start.max(end - chrono::Duration::days(MAX_BURNDOWN_DAYS - 1));
+
+    let actual = |day: NaiveDate| -> (i64, i64) {
+        if let Some(s) = snapshots.iter().find(|s| s.date == day) {
+            return (s.open_issues, s.closed_issues);
+        }
+        let scope = issues.iter().filter(|i| i.created <= day).count() as i64;
+        let closed = issues
+            .iter()
+            .filter(|i| i.created <= day && i.closed.is_some_and(|c| c <= day))
+            .count() as i64;
+        (scope - closed, closed)
+    };
+
+    let (start_open, _) = actual(start);
+    let ideal_days = due.map(|d| (d - start).num_days()).filter(|d| *d > 0);
+
+    start
+        .iter_days()
+        .take_while(|day| *day <= end)
+        .map(|day| {
+            let observed = (day <= last_observed).then(|| actual(day));
+            let ideal = ideal_days.map(|total| {
+                let elapsed = (day - start).num_days().min(total);
+                start_open as f64 * (1.0 - elapsed as f64 / total as f64)
+            });
+            BurndownPoint {
+                date: day.format("%Y-%m-%d").to_string(),
+                open: observed.map(|(open, _)| open),
+                closed: observed.map(|(_, closed)| closed),
+                scope: observed.map(|(open, closed)| open + closed),
+                ideal,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn issue(created: &str, closed: Option<&str>) -> MilestoneIssueDates {
+        MilestoneIssueDates {
+            created: d(created),
+            closed: closed.map(d),
+        }
+    }
+
+    #[test]
+    fn test_burndown_from_issue_dates_with_ideal_line() {
+        let issues = vec![
+            issue("2026-03-01", Some("2026-03-02")),
+            issue("2026-03-01", None),
+            issue("2026-03-03", Some("2026-03-03")),
+        ];
+        let points = compute_burndown(
+            d("2026-03-01"),
+            d("2026-03-05"),
+            d("2026-03-03"),
+            Some(d("2026-03-05")),
+            &issues,
+            &[],
+        );
+
+        assert_eq!(points.len(), 5);
+        assert_eq!(
+            points.iter().map(|p| p.open).collect::<Vec<_>>(),
+            vec![Some(2), Some(1), Some(1), None, None]
+        );
+        assert_eq!(points[2].closed, Some(2));
+        assert_eq!(points[2].scope, Some(3));
+        // Ideal: 2 → 0 over four days.
+        assert_eq!(points[0].ideal, Some(2.0));
+        assert_eq!(points[2].ideal, Some(1.0));
+        assert_eq!(points[4].ideal, Some(0.0));
+    }
+
+    #[test]
+    fn test_burndown_prefers_snapshots() {
+        let issues = vec![issue("2026-03-01", None)];
+        let snapshots = vec![MilestoneSnapshot {
+            date: d("2026-03-02"),
+            open_issues: 4,
+            closed_issues: 1,
+        }];
+        let points = compute_burndown(
+            d("2026-03-01"),
+            d("2026-03-02"),
+            d("2026-03-02"),
+            None,
+            &issues,
+            &snapshots,
+        );
+
+        assert_eq!(points[0].open, Some(1));
+        assert_eq!(points[1].open, Some(4));
+        assert_eq!(points[1].scope, Some(5));
+        assert!(points.iter().all(|p| p.ideal.is_none()));
+    }
+
+    #[test]
+    fn test_burndown_caps_length() {
+        let points = compute_burndown(
+            d("2025-01-01"),
+            d("2026-03-01"),
+            d("2026-03-01"),
+            None,
+            &[],
+            &[],
+        );
+        assert_eq!(points.len() as i64, MAX_BURNDOWN_DAYS);
+        assert_eq!(points.last().unwrap().date, "2026-03-01");
+    }
+}
@@ -7,12 +7,26 @@ pub mod badge;
 mod cache;
 pub mod challenge;
 pub mod code_stats;
+pub mod contribution_history;
 pub mod github_stats_snapshot;
+pub mod heatmap;
+pub mod issue_bulk;
+pub mod issue_comment;
+pub mod issue_outbox;
+pub mod issue_search;
+pub mod issue_template;
 pub mod level;
+pub mod milestone;
+pub mod period_comparison;
 pub mod project;
+pub mod repo_filter;
+pub mod season;
 mod settings;
 pub mod streak;
+pub mod summary_report;
+pub mod time_entry;
 mod user;
+pub mod workflow_settings;
 pub mod xp;
 
 // Re-export all models and utilities
@@ -20,10 +34,24 @@ pub use badge::*;
 pub use cache::*;
 pub use challenge::*;
 pub use code_stats::*;
+pub use contribution_history::*;
 pub use github_stats_snapshot::{GitHubStatsSnapshot, StatsDiff};
+pub use heatmap::*;
+pub use issue_bulk::*;
+pub use issue_comment::*;
+pub use issue_outbox::*;
+pub use issue_search::*;
+pub use issue_template::*;
 pub use level::*;
+pub use milestone::*;
+pub use period_comparison::*;
 pub use project::*;
+pub use repo_filter::*;
+pub use season::*;
 pub use settings::*;
 pub use streak::*;
+pub use summary_report::*;
+pub use time_entry::*;
 pub use user::*;
+pub use workflow_settings::*;
 pub use xp::*;
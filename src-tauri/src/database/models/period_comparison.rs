@@ -0,0 +1,479 @@
+//! Period comparison models
+//!
+//! Period-over-period comparison reports: unlike
+//! `GitHubStatsSnapshot::calculate_diff`, which only looks at the previous
+//! snapshot, these compare two arbitrary date ranges — this week vs last
+//! week, this month vs the same month last year, or custom ranges.
+//!
+//! Each period's totals come from three sources:
+//! - `github_stats_snapshots`: the activity of a period is the latest
+//!   snapshot on or before its last day minus the latest snapshot before
+//!   its first day. The totals are not truly cumulative: commits, issues,
+//!   reviews and contributions cover GitHub's rolling one-year
+//!   contribution window, so activity leaving the window offsets new
+//!   activity and a delta can only underestimate. Deltas are clamped at 0
+//!   like the XP diff in `run_github_sync`. A metric is unknown (`None`)
+//!   without both snapshots, when a repository filter change
+//!   lies between them, and — for the Search API counts (PRs, merged PRs,
+//!   closed issues) — when either snapshot holds a rate-limit fallback.
+//! - `daily_code_stats`: additions, deletions and active days summed
+//!   over the period.
+//! - `xp_history`: live XP earned during the period.
+//!
+//! Preset ranges are like-for-like "to date": on a Wednesday, this week is
+//! Monday–Wednesday and is compared against last Monday–Wednesday.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/models/mod.rs
+//!   └─ src-tauri/src/commands/period_comparison.rs
+//! Dependencies:
+//!   ├─ src-tauri/src/database/models/github_stats_snapshot.rs
+//!   └─ src-tauri/src/database/models/code_stats.rs
+
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::code_stats::CodeStatsSummary;
+use super::github_stats_snapshot::GitHubStatsSnapshot;
+
+/// Longest custom period, in days
+pub const MAX_COMPARISON_PERIOD_DAYS: i64 = 366;
+
+/// Comparison preset values
+pub mod comparison_presets {
+    /// This week (Monday to today) vs the same days last week.
+    pub const WEEK_OVER_WEEK: &str = "week_over_week";
+    /// This month to date vs the same days last month.
+    pub const MONTH_OVER_MONTH: &str = "month_over_month";
+    /// This month to date vs the same days of the month a year ago.
+    pub const MONTH_YEAR_OVER_YEAR: &str = "month_year_over_year";
+    /// Explicit `current` / `previous` ranges.
+    pub const CUSTOM: &str = "custom";
+
+    pub const PRESETS: [&str; 4] = [
+        WEEK_OVER_WEEK,
+        MONTH_OVER_MONTH,
+        MONTH_YEAR_OVER_YEAR,
+        CUSTOM,
+    ];
+}
+
+/// Metric identifiers of [`MetricComparison::metric`]
+pub mod comparison_metrics {
+    pub const COMMITS: &str = "commits";
+    pub const PULL_REQUESTS: &str = "pullRequests";
+    pub const PULL_REQUESTS_MERGED: &str = "pullRequestsMerged";
+    pub const REVIEWS: &str = "reviews";
+    pub const ISSUES: &str = "issues";
+    pub const ISSUES_CLOSED: &str = "issuesClosed";
+    pub const STARS_RECEIVED: &str = "starsReceived";
+    pub const CONTRIBUTIONS: &str = "contributions";
+    pub const ADDITIONS: &str = "additions";
+    pub const DELETIONS: &str = "deletions";
+    pub const NET_CHANGE: &str = "netChange";
+    pub const ACTIVE_DAYS: &str = "activeDays";
+    pub const XP: &str = "xp";
+}
+
+/// Inclusive date range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodRange {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl PeriodRange {
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Self {
+        Self { start, end }
+    }
+
+    /// Number of days in the range
+    pub fn days(&self) -> i64 {
+        (self.end - self.start).num_days() + 1
+    }
+
+    /// Check the range is ordered and not longer than
+    /// [`MAX_COMPARISON_PERIOD_DAYS`]
+    pub fn validate(&self, what: &str) -> Result<(), String> {
+        if self.end < self.start {
+            return Err(format!("{}の終了日が開始日より前です", what));
+        }
+        if self.days() > MAX_COMPARISON_PERIOD_DAYS {
+            return Err(format!(
+                "{}は{}日以内で指定してください",
+                what, MAX_COMPARISON_PERIOD_DAYS
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Comparison requested by the frontend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodComparisonRequest {
+    /// One of [`comparison_presets`]
+    pub preset: String,
+    /// Required for [`comparison_presets::CUSTOM`], ignored otherwise
+    #[serde(default)]
+    pub current: Option<PeriodRange>,
+    /// Required for [`comparison_presets::CUSTOM`], ignored otherwise
+    #[serde(default)]
+    pub previous: Option<PeriodRange>,
+}
+
+impl PeriodComparisonRequest {
+    /// Resolve the `(current, previous)` ranges, with presets relative to
+    /// `today`
+    pub fn resolve(&self, today: NaiveDate) -> Result<(PeriodRange, PeriodRange), String> {
+        let (current, previous) = match self.preset.as_str() {
+            comparison_presets::CUSTOM => match (self.current, self.previous) {
+                (Some(current), Some(previous)) => (current, previous),
+                _ => return Err("比較する2つの期間を指定してください".to_string()),
+            },
+            preset => preset_ranges(preset, today)
+                .ok_or_else(|| format!("Unknown comparison preset: {}", preset))?,
+        };
+        current.validate("今期")?;
+        previous.validate("比較期間")?;
+        Ok((current, previous))
+    }
+}
+
+/// Same day of month in another month, clamped to that month's last day
+fn same_day_in_month(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    (1..=day)
+        .rev()
+        .find_map(|d| NaiveDate::from_ymd_opt(year, month, d))
+}
+
+/// `(current, previous)` ranges of a preset, or `None` for an unknown
+/// preset (including [`comparison_presets::CUSTOM`])
+pub fn preset_ranges(preset: &str, today: NaiveDate) -> Option<(PeriodRange, PeriodRange)> {
+    match preset {
+        comparison_presets::WEEK_OVER_WEEK => {
+            let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+            let current = PeriodRange::new(monday, today);
+            let previous =
+                PeriodRange::new(monday - Duration::weeks(1), today - Duration::weeks(1));
+            Some((current, previous))
+        }
+        comparison_presets::MONTH_OVER_MONTH | comparison_presets::MONTH_YEAR_OVER_YEAR => {
+            let current = PeriodRange::new(today.with_day(1)?, today);
+            let (year, month) = if preset == comparison_presets::MONTH_OVER_MONTH {
+                if today.month() == 1 {
+                    (today.year() - 1, 12)
+                } else {
+                    (today.year(), today.month() - 1)
+                }
+            } else {
+                (today.year() - 1, today.month())
+            };
+            let previous = PeriodRange::new(
+                NaiveDate::from_ymd_opt(year, month, 1)?,
+                same_day_in_month(year, month, today.day())?,
+            );
+            Some((current, previous))
+        }
+        _ => None,
+    }
+}
+
+/// Activity of one period
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodTotals {
+    // From snapshot deltas; `None` without snapshots around the period
+    pub commits: Option<i64>,
+    pub pull_requests: Option<i64>,
+    pub pull_requests_merged: Option<i64>,
+    pub reviews: Option<i64>,
+    pub issues: Option<i64>,
+    pub issues_closed: Option<i64>,
+    pub stars_received: Option<i64>,
+    pub contributions: Option<i64>,
+    // From daily_code_stats
+    pub additions: i64,
+    pub deletions: i64,
+    pub active_days: i64,
+    // From xp_history (live rows only)
+    pub xp: i64,
+}
+
+impl PeriodTotals {
+    /// Combine the sources of one period.
+    ///
+    /// `base` is the latest snapshot before the period and `end` the latest
+    /// snapshot on or before its last day. Snapshot metrics stay `None`
+    /// unless both exist, `end` was taken within `range` and no repository
+    /// filter change (`rebaselined`) happened after `base`.
+    pub fn from_sources(
+        range: &PeriodRange,
+        base: Option<&GitHubStatsSnapshot>,
+        end: Option<&GitHubStatsSnapshot>,
+        rebaselined: bool,
+        code: &CodeStatsSummary,
+        xp: i64,
+    ) -> Self {
+        let start = range.start.format("%Y-%m-%d").to_string();
+        let snapshots = match (base, end) {
+            (Some(base), Some(end)) if end.snapshot_date >= start && !rebaselined => {
+                Some((base, end))
+            }
+            _ => None,
+        };
+        // A total shrinking (window roll-off, lost star) is not activity
+        let delta = |field: fn(&GitHubStatsSnapshot) -> i32| {
+            snapshots.map(|(base, end)| (field(end) as i64 - field(base) as i64).max(0))
+        };
+        let search_delta = |field: fn(&GitHubStatsSnapshot) -> i32| {
+            delta(field).filter(|_| {
+                snapshots.is_some_and(|(base, end)| !base.search_fallback && !end.search_fallback)
+            })
+        };
+
+        Self {
+            commits: delta(|s| s.total_commits),
+            pull_requests: search_delta(|s| s.total_prs),
+            pull_requests_merged: search_delta(|s| s.total_prs_merged),
+            reviews: delta(|s| s.total_reviews),
+            issues: delta(|s| s.total_issues),
+            issues_closed: search_delta(|s| s.total_issues_closed),
+            stars_received: delta(|s| s.total_stars_received),
+            contributions: delta(|s| s.total_contributions),
+            additions: code.additions as i64,
+            deletions: code.deletions as i64,
+            active_days: code.active_days as i64,
+            xp,
+        }
+    }
+}
+
+/// One metric of both periods
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricComparison {
+    /// One of [`comparison_metrics`]
+    pub metric: String,
+    pub current: Option<i64>,
+    pub previous: Option<i64>,
+    /// `current - previous`; `None` if either is unknown
+    pub delta: Option<i64>,
+    /// Change relative to `previous`, in percent; `None` if either value is
+    /// unknown or `previous` is 0
+    pub percent_change: Option<f64>,
+}
+
+impl MetricComparison {
+    pub fn new(metric: &str, current: Option<i64>, previous: Option<i64>) -> Self {
+        let delta = current.zip(previous).map(|(c, p)| c - p);
+        let percent_change = delta
+            .zip(previous)
+            .filter(|&(_, p)| p != 0)
+            .map(|(d, p)| d as f64 / p.abs() as f64 * 100.0);
+        Self {
+            metric: metric.to_string(),
+            current,
+            previous,
+            delta,
+            percent_change,
+        }
+    }
+}
+
+/// Period-over-period comparison report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodComparison {
+    pub preset: String,
+    pub current: PeriodRange,
+    pub previous: PeriodRange,
+    pub metrics: Vec<MetricComparison>,
+}
+
+impl PeriodComparison {
+    pub fn new(
+        preset: &str,
+        current: PeriodRange,
+        previous: PeriodRange,
+        current_totals: &PeriodTotals,
+        previous_totals: &PeriodTotals,
+    ) -> Self {
+        use comparison_metrics as m;
+
+        let (c, p) = (current_totals, previous_totals);
+        let net_change = |t: &PeriodTotals| Some(t.additions - t.deletions);
+        let metrics = vec![
+            MetricComparison::new(m::COMMITS, c.commits, p.commits),
+            MetricComparison::new(m::PULL_REQUESTS, c.pull_requests, p.pull_requests),
+            MetricComparison::new(
+                m::PULL_REQUESTS_MERGED,
+                c.pull_requests_merged,
+                p.pull_requests_merged,
+            ),
+            MetricComparison::new(m::REVIEWS, c.reviews, p.reviews),
+            MetricComparison::new(m::ISSUES, c.issues, p.issues),
+            MetricComparison::new(m::ISSUES_CLOSED, c.issues_closed, p.issues_closed),
+            MetricComparison::new(m::STARS_RECEIVED, c.stars_received, p.stars_received),
+            MetricComparison::new(m::CONTRIBUTIONS, c.contributions, p.contributions),
+            MetricComparison::new(m::ADDITIONS, Some(c.additions), Some(p.additions)),
+            MetricComparison::new(m::DELETIONS, Some(c.deletions), Some(p.deletions)),
+            MetricComparison::new(m::NET_CHANGE, net_change(c), net_change(p)),
+            MetricComparison::new(m::ACTIVE_DAYS, Some(c.active_days), Some(p.active_days)),
+            MetricComparison::new(m::XP, Some(c.xp), Some(p.xp)),
+        ];
+
+        Self {
+            preset: preset.to_string(),
+            current,
+            previous,
+            metrics,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn range(start: NaiveDate, end: NaiveDate) -> PeriodRange {
+        PeriodRange::new(start, end)
+    }
+
+    fn snapshot(commits: i32, date: &str) -> GitHubStatsSnapshot {
+        GitHubStatsSnapshot::new(1, commits, 0, 0, 0, 0, 0, 0, commits, date)
+    }
+
+    #[test]
+    fn test_week_over_week_is_to_date() {
+        // 2026-10-14 is a Wednesday
+        let (current, previous) =
+            preset_ranges(comparison_presets::WEEK_OVER_WEEK, date(2026, 10, 14)).unwrap();
+        assert_eq!(current, range(date(2026, 10, 12), date(2026, 10, 14)));
+        assert_eq!(previous, range(date(2026, 10, 5), date(2026, 10, 7)));
+    }
+
+    #[test]
+    fn test_month_over_month_clamps_and_wraps() {
+        let (current, previous) =
+            preset_ranges(comparison_presets::MONTH_OVER_MONTH, date(2026, 3, 31)).unwrap();
+        assert_eq!(current, range(date(2026, 3, 1), date(2026, 3, 31)));
+        assert_eq!(previous, range(date(2026, 2, 1), date(2026, 2, 28)));
+
+        let (_, previous) =
+            preset_ranges(comparison_presets::MONTH_OVER_MONTH, date(2026, 1, 10)).unwrap();
+        assert_eq!(previous, range(date(2025, 12, 1), date(2025, 12, 10)));
+    }
+
+    #[test]
+    fn test_month_year_over_year() {
+        let (current, previous) =
+            preset_ranges(comparison_presets::MONTH_YEAR_OVER_YEAR, date(2028, 2, 29)).unwrap();
+        assert_eq!(current, range(date(2028, 2, 1), date(2028, 2, 29)));
+        assert_eq!(previous, range(date(2027, 2, 1), date(2027, 2, 28)));
+    }
+
+    #[test]
+    fn test_resolve_custom_and_validation() {
+        let today = date(2026, 10, 14);
+        let mut request = PeriodComparisonRequest {
+            preset: comparison_presets::CUSTOM.to_string(),
+            current: None,
+            previous: None,
+        };
+        assert!(request.resolve(today).is_err());
+
+        request.current = Some(range(date(2026, 9, 1), date(2026, 9, 30)));
+        request.previous = Some(range(date(2026, 8, 31), date(2026, 8, 1)));
+        assert!(request.resolve(today).is_err());
+
+        request.previous = Some(range(date(2026, 8, 1), date(2026, 8, 31)));
+        assert!(request.resolve(today).is_ok());
+
+        request.preset = "fortnight".to_string();
+        assert!(request.resolve(today).is_err());
+    }
+
+    #[test]
+    fn test_metric_comparison_percent_change() {
+        let up = MetricComparison::new(comparison_metrics::COMMITS, Some(15), Some(10));
+        assert_eq!(up.delta, Some(5));
+        assert_eq!(up.percent_change, Some(50.0));
+
+        let from_zero = MetricComparison::new(comparison_metrics::COMMITS, Some(3), Some(0));
+        assert_eq!(from_zero.delta, Some(3));
+        assert_eq!(from_zero.percent_change, None);
+
+        let unknown = MetricComparison::new(comparison_metrics::COMMITS, Some(3), None);
+        assert_eq!(unknown.delta, None);
+        assert_eq!(unknown.percent_change, None);
+
+        // Relative to the magnitude, so a smaller loss reads as an increase
+        let net = MetricComparison::new(comparison_metrics::NET_CHANGE, Some(-50), Some(-100));
+        assert_eq!(net.percent_change, Some(50.0));
+    }
+
+    #[test]
+    fn test_totals_from_snapshot_deltas() {
+        let period = range(date(2026, 10, 12), date(2026, 10, 14));
+        let base = snapshot(100, "2026-10-11");
+        let end = snapshot(112, "2026-10-14");
+        let code = CodeStatsSummary {
+            additions: 40,
+            deletions: 10,
+            net_change: 30,
+            commits_count: 12,
+            active_days: 2,
+        };
+
+        let totals = PeriodTotals::from_sources(&period, Some(&base), Some(&end), false, &code, 75);
+        assert_eq!(totals.commits, Some(12));
+        assert_eq!(totals.contributions, Some(12));
+        assert_eq!(totals.additions, 40);
+        assert_eq!(totals.xp, 75);
+
+        // No snapshot before the period: lifetime totals are not a delta
+        let totals = PeriodTotals::from_sources(&period, None, Some(&end), false, &code, 0);
+        assert_eq!(totals.commits, None);
+
+        // Latest snapshot predates the period: nothing is known about it
+        let totals = PeriodTotals::from_sources(&period, Some(&base), Some(&base), false, &code, 0);
+        assert_eq!(totals.commits, None);
+
+        // A repository filter change in between: totals are not comparable
+        let totals = PeriodTotals::from_sources(&period, Some(&base), Some(&end), true, &code, 0);
+        assert_eq!(totals.commits, None);
+        assert_eq!(totals.additions, 40);
+    }
+
+    #[test]
+    fn test_totals_clamp_and_skip_search_fallbacks() {
+        let period = range(date(2026, 10, 12), date(2026, 10, 14));
+        let code = CodeStatsSummary::default();
+
+        // Contributions leaving the rolling window shrink the total
+        let base = GitHubStatsSnapshot::new(1, 100, 20, 10, 30, 15, 8, 50, 200, "2026-10-11");
+        let end = GitHubStatsSnapshot::new(1, 95, 22, 11, 30, 15, 9, 49, 198, "2026-10-14");
+        let totals = PeriodTotals::from_sources(&period, Some(&base), Some(&end), false, &code, 0);
+        assert_eq!(totals.commits, Some(0));
+        assert_eq!(totals.stars_received, Some(0));
+        assert_eq!(totals.pull_requests_merged, Some(1));
+
+        // Rate-limited Search API counts fell back to 0
+        let fallback = GitHubStatsSnapshot::new(1, 104, 21, 0, 31, 16, 0, 50, 204, "2026-10-14")
+            .with_reliability(true, false);
+        let totals =
+            PeriodTotals::from_sources(&period, Some(&base), Some(&fallback), false, &code, 0);
+        assert_eq!(totals.commits, Some(4));
+        assert_eq!(totals.pull_requests, None);
+        assert_eq!(totals.pull_requests_merged, None);
+        assert_eq!(totals.issues_closed, None);
+    }
+}
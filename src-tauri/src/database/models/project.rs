@@ -191,7 +191,9 @@ impl std::str::FromStr for IssuePriority {
     }
 }
 
-/// Project model (1 project = 1 repository)
+/// Project model. A project can link several repositories
+/// ([`ProjectRepository`]); the `repo_*` columns hold the primary one (the
+/// first linked), used where a single repository is needed.
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Project {
     pub id: i64,
@@ -205,10 +207,11 @@ pub struct Project {
     #[sqlx(rename = "is_actions_setup")]
     pub is_actions_setup: bool,
     pub last_synced_at: Option<String>,
-    /// True when the linked GitHub repository can no longer be reached
+    /// True when every linked GitHub repository can no longer be reached
     /// (deleted / renamed). Set by `sync_project_issues` on 404 so the
     /// rest of the sync run can continue and the UI can offer re-link
-    /// or delete actions. See Issue #190.
+    /// or delete actions. See Issue #190. Per-repository state lives in
+    /// [`ProjectRepository::is_archived`].
     ///
     /// `#[sqlx(default)]` lets legacy SELECT statements (those written
     /// before migration v13) still decode rows; otherwise every
@@ -235,6 +238,25 @@ pub mod archive_reasons {
     pub const REPOSITORY_GONE: &str = "repository_gone";
 }
 
+/// Repository linked to a project (`project_repositories`, migration v25).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct ProjectRepository {
+    pub id: i64,
+    pub project_id: i64,
+    pub github_repo_id: i64,
+    pub repo_owner: String,
+    pub repo_name: String,
+    pub repo_full_name: String,
+    /// True when GitHub returned 404 for this repository (Issue #190).
+    /// Its cached issues are kept but flagged, and syncs skip it until it
+    /// is re-linked.
+    pub is_archived: bool,
+    pub archived_at: Option<String>,
+    pub archived_reason: Option<String>,
+    pub last_synced_at: Option<String>,
+    pub created_at: String,
+}
+
 /// Project with additional info for display
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectWithStats {
@@ -271,6 +293,11 @@ pub struct LinkRepositoryRequest {
 pub struct CachedIssue {
     pub id: i64,
     pub project_id: i64,
+    /// Repository the issue belongs to (`owner/name`, migration v25).
+    /// Issue numbers are only unique within it.
+    #[serde(default)]
+    #[sqlx(default)]
+    pub repo_full_name: Option<String>,
     pub github_issue_id: i64,
     pub number: i32,
     pub title: String,
@@ -285,7 +312,7 @@ pub struct CachedIssue {
     pub github_created_at: Option<String>,
     pub github_updated_at: Option<String>,
     pub cached_at: String,
-    /// True when the parent project's repository disappeared from GitHub.
+    /// True when the issue's repository disappeared from GitHub.
     /// Issues stay in cache (not deleted) so the UI can keep history
     /// readable; the flag lets the kanban board dim or hide them.
     /// See Issue #190.
@@ -300,6 +327,32 @@ pub struct CachedIssue {
     #[serde(default)]
     #[sqlx(default)]
     pub archived_at: Option<String>,
+    /// True while the row carries an optimistic change that is still
+    /// queued in `issue_outbox` (offline edit not yet pushed to GitHub).
+    /// `sync_project_issues` leaves such rows untouched so a refresh does
+    /// not silently revert the user's pending change.
+    #[serde(default)]
+    #[sqlx(default)]
+    pub pending_sync: bool,
+    /// JSON array of assignee logins (migration v21).
+    #[serde(default)]
+    #[sqlx(default)]
+    pub assignees_json: Option<String>,
+    #[serde(default)]
+    #[sqlx(default)]
+    pub milestone_number: Option<i32>,
+    #[serde(default)]
+    #[sqlx(default)]
+    pub milestone_title: Option<String>,
+    /// GitHub `state_reason` (`completed`, `not_planned`, `reopened`).
+    #[serde(default)]
+    #[sqlx(default)]
+    pub state_reason: Option<String>,
+    /// JSON array of [`LinkedPullRequest`] (migration v23). Refreshed from
+    /// GraphQL for open issues on every `sync_project_issues`.
+    #[serde(default)]
+    #[sqlx(default)]
+    pub linked_prs_json: Option<String>,
 }
 
 impl CachedIssue {
@@ -320,6 +373,85 @@ impl CachedIssue {
             .and_then(|json| serde_json::from_str(json).ok())
             .unwrap_or_default()
     }
+
+    /// Get parsed assignee logins (falls back to `assignee_login` for rows
+    /// cached before migration v21)
+    pub fn get_assignees(&self) -> Vec<String> {
+        self.assignees_json
+            .as_ref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_else(|| self.assignee_login.iter().cloned().collect())
+    }
+
+    /// Get parsed linked pull requests
+    pub fn get_linked_prs(&self) -> Vec<LinkedPullRequest> {
+        self.linked_prs_json
+            .as_ref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Pull request linked to an issue, either as a closing reference
+/// (`Fixes #12`, or linked from the sidebar) or as a cross-reference
+/// mentioning it. Cached in `cached_issues.linked_prs_json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinkedPullRequest {
+    pub number: i32,
+    pub title: String,
+    pub url: String,
+    pub repo_full_name: String,
+    /// `OPEN` / `CLOSED` / `MERGED`.
+    pub state: String,
+    pub is_draft: bool,
+    /// GraphQL `MergeableState` — `MERGEABLE` / `CONFLICTING` / `UNKNOWN`.
+    pub mergeable: String,
+    /// `APPROVED` / `CHANGES_REQUESTED` / `REVIEW_REQUIRED`, `None` when no
+    /// review is required.
+    pub review_decision: Option<String>,
+    /// Head commit `statusCheckRollup.state` (`SUCCESS` / `FAILURE` /
+    /// `PENDING` / `ERROR` / `EXPECTED`), `None` without checks.
+    pub checks_state: Option<String>,
+    /// True for a closing reference, false for a plain mention.
+    pub closes_issue: bool,
+}
+
+/// Incremental sync cursor for one repository of a project
+/// (`project_sync_cursors`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, FromRow)]
+pub struct ProjectSyncCursor {
+    pub project_id: i64,
+    pub repo_full_name: String,
+    /// Newest GitHub `updated_at` (RFC 3339) seen so far; passed as
+    /// `since` on the next incremental fetch.
+    pub since_cursor: Option<String>,
+    /// ETag of the last page-1 response, sent as If-None-Match.
+    pub etag: Option<String>,
+    pub last_full_sync_at: Option<String>,
+    pub last_incremental_sync_at: Option<String>,
+}
+
+impl ProjectSyncCursor {
+    /// Hours between full listings. Incremental `since` queries never
+    /// return deleted or transferred issues, so a periodic full pass is
+    /// what reconciles those away.
+    pub const FULL_SYNC_INTERVAL_HOURS: i64 = 24;
+
+    /// Whether the next sync must be a full listing: no cursor yet, or the
+    /// last full pass is older than [`Self::FULL_SYNC_INTERVAL_HOURS`].
+    pub fn needs_full_sync(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        if self.since_cursor.is_none() {
+            return true;
+        }
+        self.last_full_sync_at
+            .as_deref()
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| {
+                now.signed_duration_since(t.with_timezone(&chrono::Utc))
+                    >= chrono::Duration::hours(Self::FULL_SYNC_INTERVAL_HOURS)
+            })
+            .unwrap_or(true)
+    }
 }
 
 /// Request to update issue status
@@ -330,6 +462,66 @@ pub struct UpdateIssueStatusRequest {
     pub status: IssueStatus,
 }
 
+/// Request to edit issue fields (`update_issue_fields`). Every field is
+/// optional; only the ones present are sent to GitHub.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateIssueFieldsRequest {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub body: Option<String>,
+    /// Replaces all assignees (logins).
+    #[serde(default)]
+    pub assignees: Option<Vec<String>>,
+    /// Absent = unchanged, `null` = clear, number = set.
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub milestone: Option<Option<i32>>,
+    /// Replaces all labels. `status:*` labels are managed by the kanban
+    /// and ignored here.
+    #[serde(default)]
+    pub labels: Option<Vec<String>>,
+}
+
+/// Deserialize a field that is present (even as `null`) into `Some(_)`, so
+/// `Option<Option<T>>` can tell "absent" from "explicitly null".
+fn deserialize_present<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+/// Choices for the issue edit form (`get_issue_edit_options`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IssueEditOptions {
+    pub labels: Vec<LabelOption>,
+    pub assignees: Vec<AssigneeOption>,
+    pub milestones: Vec<MilestoneOption>,
+}
+
+/// Repository label offered in the edit form.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LabelOption {
+    pub name: String,
+    pub color: String,
+}
+
+/// Assignable user offered in the edit form.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssigneeOption {
+    pub login: String,
+    pub avatar_url: String,
+}
+
+/// Open milestone offered in the edit form.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MilestoneOption {
+    pub number: i32,
+    pub title: String,
+    pub due_on: Option<String>,
+}
+
 /// Request to create a new issue
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateIssueRequest {
@@ -508,6 +700,7 @@ mod tests {
             CachedIssue {
                 id: 1,
                 project_id: 1,
+                repo_full_name: Some("octo/app".to_string()),
                 github_issue_id: 100,
                 number: 1,
                 title: "Test 1".to_string(),
@@ -524,10 +717,17 @@ mod tests {
                 cached_at: "2025-01-01".to_string(),
                 is_archived: false,
                 archived_at: None,
+                pending_sync: false,
+                assignees_json: None,
+                milestone_number: None,
+                milestone_title: None,
+                state_reason: None,
+                linked_prs_json: None,
             },
             CachedIssue {
                 id: 2,
                 project_id: 1,
+                repo_full_name: Some("octo/app".to_string()),
                 github_issue_id: 101,
                 number: 2,
                 title: "Test 2".to_string(),
@@ -544,6 +744,12 @@ mod tests {
                 cached_at: "2025-01-01".to_string(),
                 is_archived: false,
                 archived_at: None,
+                pending_sync: false,
+                assignees_json: None,
+                milestone_number: None,
+                milestone_title: None,
+                state_reason: None,
+                linked_prs_json: None,
             },
         ];
 
@@ -552,4 +758,66 @@ mod tests {
         assert_eq!(board.in_progress.len(), 1);
         assert_eq!(board.todo.len(), 0);
     }
+
+    #[test]
+    fn test_sync_cursor_needs_full_sync() {
+        let now = chrono::DateTime::parse_from_rfc3339("2026-05-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        // Never synced.
+        assert!(ProjectSyncCursor::default().needs_full_sync(now));
+
+        let recent = ProjectSyncCursor {
+            project_id: 1,
+            repo_full_name: "octo/app".to_string(),
+            since_cursor: Some("2026-05-10T11:00:00Z".to_string()),
+            etag: None,
+            last_full_sync_at: Some("2026-05-10T00:00:00Z".to_string()),
+            last_incremental_sync_at: None,
+        };
+        assert!(!recent.needs_full_sync(now));
+
+        let stale = ProjectSyncCursor {
+            last_full_sync_at: Some("2026-05-09T12:00:00Z".to_string()),
+            ..recent.clone()
+        };
+        assert!(stale.needs_full_sync(now));
+
+        let unparsable = ProjectSyncCursor {
+            last_full_sync_at: Some("yesterday".to_string()),
+            ..recent
+        };
+        assert!(unparsable.needs_full_sync(now));
+    }
+
+    #[test]
+    fn test_update_issue_fields_request_milestone_presence() {
+        let absent: UpdateIssueFieldsRequest = serde_json::from_str(r#"{"title":"x"}"#).unwrap();
+        assert_eq!(absent.milestone, None);
+        assert_eq!(absent.title.as_deref(), Some("x"));
+
+        let cleared: UpdateIssueFieldsRequest =
+            serde_json::from_str(r#"{"milestone":null}"#).unwrap();
+        assert_eq!(cleared.milestone, Some(None));
+
+        let set: UpdateIssueFieldsRequest = serde_json::from_str(r#"{"milestone":4}"#).unwrap();
+        assert_eq!(set.milestone, Some(Some(4)));
+    }
+
+    #[test]
+    fn test_cached_issue_assignees_fallback() {
+        let mut issue: CachedIssue = serde_json::from_value(serde_json::json!({
+            "id": 1, "project_id": 1, "github_issue_id": 1, "number": 1,
+            "title": "t", "body": null, "state": "open", "status": "backlog",
+            "priority": null, "assignee_login": "alice", "assignee_avatar_url": null,
+            "labels_json": null, "html_url": null, "github_created_at": null,
+            "github_updated_at": null, "cached_at": "2025-01-01"
+        }))
+        .unwrap();
+        assert_eq!(issue.get_assignees(), vec!["alice"]);
+
+        issue.assignees_json = Some(r#"["alice","bob"]"#.to_string());
+        assert_eq!(issue.get_assignees(), vec!["alice", "bob"]);
+    }
 }
@@ -0,0 +1,490 @@
+//! Repository filter models
+//!
+//! Per-user include / exclude filter for the repositories counted by the
+//! stats paths (`repository_filters`, migration v33): XP, badges, code
+//! stats, the language breakdown, today's commits and the activity feed.
+//! A repository is matched by `owner/name`, its owner (user or
+//! organization), and whether it is a fork, archived or private.
+//!
+//! The "work" / "personal" presets split repositories by owner: work is
+//! any owner listed in `work_owners`, or — while that list is empty —
+//! any owner other than the signed-in user. Users without a saved row get
+//! [`RepositoryFilter::default`], which counts every repository.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/models/mod.rs
+//!   ├─ src-tauri/src/commands/repo_filter.rs
+//!   ├─ src-tauri/src/commands/activity.rs
+//!   └─ src-tauri/src/github/client.rs
+//! Dependencies:
+//!   └─ (none)
+
+use serde::{Deserialize, Serialize};
+
+/// Maximum entries per include / exclude list.
+pub const MAX_REPOSITORY_FILTER_ENTRIES: usize = 100;
+
+/// Preset switcher values
+pub mod repo_filter_presets {
+    /// Every repository (subject to the include / exclude lists).
+    pub const ALL: &str = "all";
+    /// Repositories owned by a work owner.
+    pub const WORK: &str = "work";
+    /// Repositories not owned by a work owner.
+    pub const PERSONAL: &str = "personal";
+
+    pub const PRESETS: [&str; 3] = [ALL, WORK, PERSONAL];
+}
+
+/// Repository / organization filter of one user.
+///
+/// Names are compared case-insensitively, like GitHub does.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryFilter {
+    /// One of [`repo_filter_presets`].
+    pub preset: String,
+    /// Owners whose repositories count as work (empty = every owner other
+    /// than the user).
+    pub work_owners: Vec<String>,
+    /// `owner/name`; when this or `include_owners` is non-empty, only the
+    /// listed repositories and owners are counted.
+    pub include_repositories: Vec<String>,
+    /// `owner/name`, never counted.
+    pub exclude_repositories: Vec<String>,
+    pub include_owners: Vec<String>,
+    pub exclude_owners: Vec<String>,
+    pub include_forks: bool,
+    pub include_archived: bool,
+    pub include_private: bool,
+    /// `None` while the user has no saved filter.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl Default for RepositoryFilter {
+    fn default() -> Self {
+        Self {
+            preset: repo_filter_presets::ALL.to_string(),
+            work_owners: Vec::new(),
+            include_repositories: Vec::new(),
+            exclude_repositories: Vec::new(),
+            include_owners: Vec::new(),
+            exclude_owners: Vec::new(),
+            include_forks: true,
+            include_archived: true,
+            include_private: true,
+            updated_at: None,
+        }
+    }
+}
+
+/// What the filter knows about a repository. Flags are `None` when the
+/// source doesn't report them (e.g. activity events only carry the name);
+/// unknown flags never exclude a repository.
+#[derive(Debug, Clone, Copy)]
+pub struct RepositoryRef<'a> {
+    /// `owner/name`
+    pub name_with_owner: &'a str,
+    pub is_fork: Option<bool>,
+    pub is_archived: Option<bool>,
+    pub is_private: Option<bool>,
+}
+
+impl<'a> RepositoryRef<'a> {
+    /// A repository known only by name.
+    pub fn named(name_with_owner: &'a str) -> Self {
+        Self {
+            name_with_owner,
+            is_fork: None,
+            is_archived: None,
+            is_private: None,
+        }
+    }
+
+    /// A repository with all flags known.
+    pub fn with_flags(
+        name_with_owner: &'a str,
+        is_fork: bool,
+        is_archived: bool,
+        is_private: bool,
+    ) -> Self {
+        Self {
+            name_with_owner,
+            is_fork: Some(is_fork),
+            is_archived: Some(is_archived),
+            is_private: Some(is_private),
+        }
+    }
+
+    /// The owner part of `owner/name`.
+    pub fn owner(&self) -> &'a str {
+        self.name_with_owner
+            .split('/')
+            .next()
+            .unwrap_or(self.name_with_owner)
+    }
+}
+
+impl RepositoryFilter {
+    /// Whether the filter can exclude anything. Inactive filters let the
+    /// GitHub client skip the extra queries.
+    pub fn is_active(&self) -> bool {
+        self.preset != repo_filter_presets::ALL
+            || !self.include_repositories.is_empty()
+            || !self.exclude_repositories.is_empty()
+            || !self.include_owners.is_empty()
+            || !self.exclude_owners.is_empty()
+            || !self.include_forks
+            || !self.include_archived
+            || !self.include_private
+    }
+
+    /// Whether `owner`'s repositories count as work for `viewer`.
+    pub fn is_work_owner(&self, owner: &str, viewer: &str) -> bool {
+        if self.work_owners.is_empty() {
+            !owner.eq_ignore_ascii_case(viewer)
+        } else {
+            contains_ignore_case(&self.work_owners, owner)
+        }
+    }
+
+    fn preset_allows_owner(&self, owner: &str, viewer: &str) -> bool {
+        match self.preset.as_str() {
+            repo_filter_presets::WORK => self.is_work_owner(owner, viewer),
+            repo_filter_presets::PERSONAL => !self.is_work_owner(owner, viewer),
+            _ => true,
+        }
+    }
+
+    fn has_include_lists(&self) -> bool {
+        !self.include_repositories.is_empty() || !self.include_owners.is_empty()
+    }
+
+    /// Whether `repo` is counted for `viewer` (the signed-in user).
+    pub fn allows(&self, repo: &RepositoryRef<'_>, viewer: &str) -> bool {
+        let name = repo.name_with_owner;
+        let owner = repo.owner();
+
+        if contains_ignore_case(&self.exclude_repositories, name)
+            || contains_ignore_case(&self.exclude_owners, owner)
+        {
+            return false;
+        }
+        if (!self.include_forks && repo.is_fork == Some(true))
+            || (!self.include_archived && repo.is_archived == Some(true))
+            || (!self.include_private && repo.is_private == Some(true))
+        {
+            return false;
+        }
+        if self.has_include_lists()
+            && !contains_ignore_case(&self.include_repositories, name)
+            && !contains_ignore_case(&self.include_owners, owner)
+        {
+            return false;
+        }
+        self.preset_allows_owner(owner, viewer)
+    }
+
+    /// The filter as GitHub Search qualifiers, for the search-based counts
+    /// (pull requests, merged pull requests, closed issues).
+    ///
+    /// Search ORs `repo:` / `user:` qualifiers together, so the include
+    /// lists are narrowed by the preset here instead of being combined
+    /// with it. Forks can't be filtered in issue search. Returns `None`
+    /// when no repository can match, so callers can skip the request.
+    pub fn search_qualifiers(&self, viewer: &str) -> Option<Vec<String>> {
+        let mut qualifiers: Vec<String> = Vec::new();
+
+        if self.has_include_lists() {
+            let repos = self
+                .include_repositories
+                .iter()
+                .filter(|r| self.preset_allows_owner(RepositoryRef::named(r).owner(), viewer))
+                .map(|r| format!("repo:{}", r));
+            let owners = self
+                .include_owners
+                .iter()
+                .filter(|o| self.preset_allows_owner(o, viewer))
+                .map(|o| format!("user:{}", o));
+            qualifiers.extend(repos.chain(owners));
+            if qualifiers.is_empty() {
+                return None;
+            }
+        } else {
+            match (self.preset.as_str(), self.work_owners.is_empty()) {
+                (repo_filter_presets::WORK, true) => qualifiers.push(format!("-user:{}", viewer)),
+                (repo_filter_presets::WORK, false) => {
+                    qualifiers.extend(self.work_owners.iter().map(|o| format!("user:{}", o)))
+                }
+                (repo_filter_presets::PERSONAL, true) => {
+                    qualifiers.push(format!("user:{}", viewer))
+                }
+                (repo_filter_presets::PERSONAL, false) => {
+                    qualifiers.extend(self.work_owners.iter().map(|o| format!("-user:{}", o)))
+                }
+                _ => {}
+            }
+        }
+
+        qualifiers.extend(
+            self.exclude_repositories
+                .iter()
+                .map(|r| format!("-repo:{}", r)),
+        );
+        qualifiers.extend(self.exclude_owners.iter().map(|o| format!("-user:{}", o)));
+        if !self.include_archived {
+            qualifiers.push("archived:false".to_string());
+        }
+        if !self.include_private {
+            qualifiers.push("is:public".to_string());
+        }
+        Some(qualifiers)
+    }
+
+    /// Trim and lowercase the names and drop empty or duplicate entries.
+    pub fn normalized(mut self) -> Self {
+        self.preset = self.preset.trim().to_lowercase();
+        self.work_owners = clean_names(self.work_owners);
+        self.include_repositories = clean_names(self.include_repositories);
+        self.exclude_repositories = clean_names(self.exclude_repositories);
+        self.include_owners = clean_names(self.include_owners);
+        self.exclude_owners = clean_names(self.exclude_owners);
+        self
+    }
+
+    /// Reject unknown presets and malformed names (they end up in search
+    /// queries, so only GitHub's name characters are allowed).
+    pub fn validate(&self) -> Result<(), String> {
+        if !repo_filter_presets::PRESETS.contains(&self.preset.as_str()) {
+            return Err(format!("不明なプリセットです: {}", self.preset));
+        }
+
+        let owner_lists = [
+            &self.work_owners,
+            &self.include_owners,
+            &self.exclude_owners,
+        ];
+        let repository_lists = [&self.include_repositories, &self.exclude_repositories];
+        for list in owner_lists.iter().chain(repository_lists.iter()) {
+            if list.len() > MAX_REPOSITORY_FILTER_ENTRIES {
+                return Err(format!(
+                    "1つのリストに登録できるのは{}件までです",
+                    MAX_REPOSITORY_FILTER_ENTRIES
+                ));
+            }
+        }
+        for owner in owner_lists.iter().flat_map(|l| l.iter()) {
+            if !is_valid_name(owner) {
+                return Err(format!("オーナー名が不正です: {}", owner));
+            }
+        }
+        for repository in repository_lists.iter().flat_map(|l| l.iter()) {
+            if split_repository_name(repository).is_none() {
+                return Err(format!(
+                    "リポジトリは owner/name の形式で入力してください: {}",
+                    repository
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn contains_ignore_case(list: &[String], value: &str) -> bool {
+    list.iter().any(|item| item.eq_ignore_ascii_case(value))
+}
+
+/// Split a well-formed "owner/name" into its parts
+pub fn split_repository_name(value: &str) -> Option<(&str, &str)> {
+    value
+        .split_once('/')
+        .filter(|(owner, name)| is_valid_name(owner) && is_valid_name(name))
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+fn clean_names(items: Vec<String>) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for item in items {
+        let item = item.trim().to_lowercase();
+        if !item.is_empty() && !out.contains(&item) {
+            out.push(item);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_default_filter_allows_everything() {
+        let filter = RepositoryFilter::default();
+        assert!(!filter.is_active());
+        assert!(filter.validate().is_ok());
+        assert!(filter.allows(
+            &RepositoryRef::with_flags("someone/fork", true, true, true),
+            "octocat"
+        ));
+        assert_eq!(filter.search_qualifiers("octocat"), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_exclusions_and_flags() {
+        let filter = RepositoryFilter {
+            exclude_repositories: strings(&["octocat/dotfiles"]),
+            exclude_owners: strings(&["spam-org"]),
+            include_forks: false,
+            include_private: false,
+            ..Default::default()
+        };
+        assert!(filter.is_active());
+        assert!(!filter.allows(&RepositoryRef::named("OctoCat/Dotfiles"), "octocat"));
+        assert!(!filter.allows(&RepositoryRef::named("spam-org/app"), "octocat"));
+        assert!(!filter.allows(
+            &RepositoryRef::with_flags("octocat/fork", true, false, false),
+            "octocat"
+        ));
+        assert!(!filter.allows(
+            &RepositoryRef::with_flags("octocat/secret", false, false, true),
+            "octocat"
+        ));
+        // Archived repositories are still included; unknown flags never exclude
+        assert!(filter.allows(
+            &RepositoryRef::with_flags("octocat/old", false, true, false),
+            "octocat"
+        ));
+        assert!(filter.allows(&RepositoryRef::named("octocat/unknown"), "octocat"));
+    }
+
+    #[test]
+    fn test_include_lists_limit_repositories() {
+        let filter = RepositoryFilter {
+            include_repositories: strings(&["octocat/app"]),
+            include_owners: strings(&["acme"]),
+            ..Default::default()
+        };
+        assert!(filter.allows(&RepositoryRef::named("octocat/app"), "octocat"));
+        assert!(filter.allows(&RepositoryRef::named("ACME/api"), "octocat"));
+        assert!(!filter.allows(&RepositoryRef::named("octocat/blog"), "octocat"));
+    }
+
+    #[test]
+    fn test_presets_split_by_owner() {
+        let work = RepositoryFilter {
+            preset: repo_filter_presets::WORK.to_string(),
+            ..Default::default()
+        };
+        assert!(work.allows(&RepositoryRef::named("acme/api"), "octocat"));
+        assert!(!work.allows(&RepositoryRef::named("octocat/blog"), "octocat"));
+
+        let personal = RepositoryFilter {
+            preset: repo_filter_presets::PERSONAL.to_string(),
+            work_owners: strings(&["acme"]),
+            ..Default::default()
+        };
+        assert!(!personal.allows(&RepositoryRef::named("acme/api"), "octocat"));
+        // Not a listed work owner, so it counts as personal
+        assert!(personal.allows(&RepositoryRef::named("rust-lang/rust"), "octocat"));
+        assert!(personal.allows(&RepositoryRef::named("octocat/blog"), "octocat"));
+    }
+
+    #[test]
+    fn test_search_qualifiers() {
+        let work = RepositoryFilter {
+            preset: repo_filter_presets::WORK.to_string(),
+            exclude_repositories: strings(&["acme/legacy"]),
+            include_archived: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            work.search_qualifiers("octocat"),
+            Some(strings(&[
+                "-user:octocat",
+                "-repo:acme/legacy",
+                "archived:false"
+            ]))
+        );
+
+        let personal = RepositoryFilter {
+            preset: repo_filter_presets::PERSONAL.to_string(),
+            work_owners: strings(&["acme"]),
+            include_private: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            personal.search_qualifiers("octocat"),
+            Some(strings(&["-user:acme", "is:public"]))
+        );
+
+        // Include lists are narrowed by the preset
+        let mut narrowed = RepositoryFilter {
+            preset: repo_filter_presets::PERSONAL.to_string(),
+            include_repositories: strings(&["octocat/app", "acme/api"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            narrowed.search_qualifiers("octocat"),
+            Some(strings(&["repo:octocat/app"]))
+        );
+        narrowed.include_repositories = strings(&["acme/api"]);
+        assert_eq!(narrowed.search_qualifiers("octocat"), None);
+    }
+
+    #[test]
+    fn test_normalize_and_validate() {
+        let filter = RepositoryFilter {
+            preset: " Work ".to_string(),
+            work_owners: strings(&[" ACME ", "acme", ""]),
+            exclude_repositories: strings(&["Octocat/Dotfiles"]),
+            ..Default::default()
+        }
+        .normalized();
+        assert_eq!(filter.preset, repo_filter_presets::WORK);
+        assert_eq!(filter.work_owners, strings(&["acme"]));
+        assert_eq!(filter.exclude_repositories, strings(&["octocat/dotfiles"]));
+        assert!(filter.validate().is_ok());
+
+        let bad_preset = RepositoryFilter {
+            preset: "team".to_string(),
+            ..Default::default()
+        };
+        assert!(bad_preset.validate().is_err());
+
+        let bad_repository = RepositoryFilter {
+            include_repositories: strings(&["dotfiles"]),
+            ..Default::default()
+        };
+        assert!(bad_repository.validate().is_err());
+
+        let bad_owner = RepositoryFilter {
+            exclude_owners: strings(&["acme corp"]),
+            ..Default::default()
+        };
+        assert!(bad_owner.validate().is_err());
+    }
+
+    #[test]
+    fn test_split_repository_name() {
+        assert_eq!(
+            split_repository_name("octocat/hello-world"),
+            Some(("octocat", "hello-world"))
+        );
+        assert_eq!(split_repository_name("octocat"), None);
+        assert_eq!(split_repository_name("octocat/a/b"), None);
+        assert_eq!(split_repository_name("/hello"), None);
+    }
+}
@@ -0,0 +1,166 @@
+//! Season models
+//!
+//! シーズン: 累計XP (`total_xp`) とは別に、カレンダー四半期
+//! ごとのシーズンXPとシーズンレベルを追跡します。シーズンが切り替わると前シーズンの
+//! サマリー（XP・レベル・ベストストリーク・上位バッジ）を `season_history` に保存し、
+//! ユーザー自身の過去シーズンと比較したパーセンタイルに応じてシーズンバッジを付与します。
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::level::level_from_xp;
+
+/// Past seasons required before a season's percentile is meaningful
+pub const MIN_PAST_SEASONS_FOR_PERCENTILE: usize = 3;
+/// Number of badges kept in a season archive's highlights
+pub const SEASON_TOP_BADGES: usize = 3;
+
+/// Archived summary of a finished season
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonArchive {
+    pub id: i64,
+    pub user_id: i64,
+    /// Season identifier, e.g. "2026-Q3"
+    pub season_id: String,
+    /// First day of the season (inclusive)
+    pub starts_on: NaiveDate,
+    /// First day of the next season (exclusive)
+    pub ends_on: NaiveDate,
+    pub season_xp: i32,
+    pub season_level: i32,
+    pub best_streak: i32,
+    /// Rarest badges earned during the season, rarest first
+    pub top_badges: Vec<String>,
+    /// Share of the user's earlier seasons with less XP (0-100); `None`
+    /// until `MIN_PAST_SEASONS_FOR_PERCENTILE` seasons are archived
+    pub percentile: Option<i32>,
+    pub archived_at: DateTime<Utc>,
+}
+
+/// Season identifier ("YYYY-Qn") for the calendar quarter containing `date`
+pub fn season_id_for(date: NaiveDate) -> String {
+    format!("{}-Q{}", date.year(), date.month0() / 3 + 1)
+}
+
+/// Start (inclusive) and end (exclusive) dates of a season identifier
+pub fn season_bounds(season_id: &str) -> Option<(NaiveDate, NaiveDate)> {
+    let (year, quarter) = season_id.split_once("-Q")?;
+    let year: i32 = year.parse().ok()?;
+    let quarter: u32 = quarter.parse().ok()?;
+    if !(1..=4).contains(&quarter) {
+        return None;
+    }
+    let start = NaiveDate::from_ymd_opt(year, (quarter - 1) * 3 + 1, 1)?;
+    let end = if quarter == 4 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(year, quarter * 3 + 1, 1)?
+    };
+    Some((start, end))
+}
+
+/// Level reached with the XP earned during a season
+pub fn season_level(season_xp: i32) -> i32 {
+    level_from_xp(season_xp.max(0))
+}
+
+/// Percentile of `season_xp` against the user's own earlier seasons.
+///
+/// Returns the share (0-100) of `past_season_xp` that `season_xp` beat,
+/// so 100 means a personal best. `None` while there is too little history.
+pub fn season_percentile(season_xp: i32, past_season_xp: &[i32]) -> Option<i32> {
+    if past_season_xp.len() < MIN_PAST_SEASONS_FOR_PERCENTILE {
+        return None;
+    }
+    let beaten = past_season_xp.iter().filter(|&&xp| xp < season_xp).count();
+    Some((beaten * 100 / past_season_xp.len()) as i32)
+}
+
+/// Pick the season's highlight badges: rarest first, then most recent.
+///
+/// `earned` is `(badge_id, rarity)` in the order the badges were earned.
+pub fn top_season_badges(earned: &[(String, String)]) -> Vec<String> {
+    let mut ranked: Vec<(usize, &(String, String))> = earned.iter().enumerate().collect();
+    ranked.sort_by(|(a_idx, a), (b_idx, b)| {
+        rarity_rank(&b.1)
+            .cmp(&rarity_rank(&a.1))
+            .then(b_idx.cmp(a_idx))
+    });
+    ranked
+        .into_iter()
+        .take(SEASON_TOP_BADGES)
+        .map(|(_, (id, _))| id.clone())
+        .collect()
+}
+
+/// Sort key for badge rarity (higher is rarer)
+fn rarity_rank(rarity: &str) -> u8 {
+    match rarity {
+        "platinum" => 4,
+        "gold" => 3,
+        "silver" => 2,
+        "bronze" => 1,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_season_id_for() {
+        assert_eq!(season_id_for(date(2026, 1, 1)), "2026-Q1");
+        assert_eq!(season_id_for(date(2026, 3, 31)), "2026-Q1");
+        assert_eq!(season_id_for(date(2026, 7, 15)), "2026-Q3");
+        assert_eq!(season_id_for(date(2026, 12, 31)), "2026-Q4");
+    }
+
+    #[test]
+    fn test_season_bounds() {
+        assert_eq!(
+            season_bounds("2026-Q2"),
+            Some((date(2026, 4, 1), date(2026, 7, 1)))
+        );
+        assert_eq!(
+            season_bounds("2026-Q4"),
+            Some((date(2026, 10, 1), date(2027, 1, 1)))
+        );
+        assert_eq!(season_bounds("2026-Q5"), None);
+        assert_eq!(season_bounds("garbage"), None);
+    }
+
+    #[test]
+    fn test_season_percentile_needs_history() {
+        assert_eq!(season_percentile(500, &[]), None);
+        assert_eq!(season_percentile(500, &[100, 200]), None);
+    }
+
+    #[test]
+    fn test_season_percentile() {
+        assert_eq!(season_percentile(500, &[100, 200, 300]), Some(100));
+        assert_eq!(season_percentile(250, &[100, 200, 300, 400]), Some(50));
+        assert_eq!(season_percentile(0, &[100, 200, 300]), Some(0));
+        // Ties do not count as beaten
+        assert_eq!(season_percentile(300, &[300, 300, 300]), Some(0));
+    }
+
+    #[test]
+    fn test_top_season_badges_prefers_rarity_then_recency() {
+        let earned = vec![
+            ("first_blood".to_string(), "bronze".to_string()),
+            ("century".to_string(), "silver".to_string()),
+            ("level_50".to_string(), "gold".to_string()),
+            ("week_warrior".to_string(), "silver".to_string()),
+        ];
+        assert_eq!(
+            top_season_badges(&earned),
+            vec!["level_50", "week_warrior", "century"]
+        );
+    }
+}
@@ -3,6 +3,9 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::challenge::ChallengeDifficulty;
+use super::xp::XpSafeguards;
+
 /// Notification method options
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -49,6 +52,8 @@ pub struct UserSettings {
     pub notify_badge_earned: bool,
     pub notify_streak_update: bool,
     pub notify_streak_milestone: bool,
+    /// Weekly summary report notification (off by default)
+    pub notify_weekly_report: bool,
 
     // Sync settings
     pub sync_interval_minutes: i32,
@@ -58,6 +63,18 @@ pub struct UserSettings {
     // Appearance settings
     pub animations_enabled: bool,
 
+    // Challenge settings
+    pub challenge_difficulty: ChallengeDifficulty,
+
+    // Prestige settings: level required before the user can prestige
+    pub prestige_level: i32,
+
+    // XP safeguard settings (0 disables the cap / minimum)
+    pub xp_daily_commit_cap: i32,
+    pub xp_min_commit_lines: i32,
+    pub xp_exclude_bot_commits: bool,
+    pub xp_excluded_repositories: Vec<String>,
+
     // Metadata
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -74,10 +91,17 @@ impl Default for UserSettings {
             notify_badge_earned: true,
             notify_streak_update: true,
             notify_streak_milestone: true,
+            notify_weekly_report: false,
             sync_interval_minutes: 60,
             background_sync: true,
             sync_on_startup: true,
             animations_enabled: true,
+            challenge_difficulty: ChallengeDifficulty::Normal,
+            prestige_level: 50,
+            xp_daily_commit_cap: 20,
+            xp_min_commit_lines: 0,
+            xp_exclude_bot_commits: true,
+            xp_excluded_repositories: Vec::new(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -86,7 +110,7 @@ impl Default for UserSettings {
 
 /// Settings defaults as constants
 pub mod settings_defaults {
-    use super::NotificationMethod;
+    use super::{ChallengeDifficulty, NotificationMethod};
 
     pub const NOTIFICATION_METHOD: NotificationMethod = NotificationMethod::Both;
     pub const NOTIFY_XP_GAIN: bool = true;
@@ -94,10 +118,24 @@ pub mod settings_defaults {
     pub const NOTIFY_BADGE_EARNED: bool = true;
     pub const NOTIFY_STREAK_UPDATE: bool = true;
     pub const NOTIFY_STREAK_MILESTONE: bool = true;
+    pub const NOTIFY_WEEKLY_REPORT: bool = false;
     pub const SYNC_INTERVAL_MINUTES: i32 = 60;
     pub const BACKGROUND_SYNC: bool = true;
     pub const SYNC_ON_STARTUP: bool = true;
     pub const ANIMATIONS_ENABLED: bool = true;
+    pub const CHALLENGE_DIFFICULTY: ChallengeDifficulty = ChallengeDifficulty::Normal;
+    pub const PRESTIGE_LEVEL: i32 = 50;
+    pub const XP_DAILY_COMMIT_CAP: i32 = 20;
+    pub const XP_MIN_COMMIT_LINES: i32 = 0;
+    pub const XP_EXCLUDE_BOT_COMMITS: bool = true;
+
+    /// Upper bounds accepted for the XP safeguard settings
+    pub const MAX_XP_DAILY_COMMIT_CAP: i32 = 500;
+    pub const MAX_XP_MIN_COMMIT_LINES: i32 = 1000;
+    pub const MAX_XP_EXCLUDED_REPOSITORIES: usize = 50;
+
+    /// Levels the user can choose as their prestige threshold
+    pub const PRESTIGE_LEVELS: &[i32] = &[30, 50, 75, 100];
 
     /// Available sync interval options (minutes, label)
     /// This is the single source of truth - frontend should fetch this via command
@@ -111,6 +149,18 @@ pub mod settings_defaults {
     ];
 }
 
+impl UserSettings {
+    /// XP safeguards configured by these settings
+    pub fn xp_safeguards(&self) -> XpSafeguards {
+        XpSafeguards {
+            daily_commit_cap: self.xp_daily_commit_cap,
+            min_commit_lines: self.xp_min_commit_lines,
+            exclude_bot_commits: self.xp_exclude_bot_commits,
+            excluded_repositories: self.xp_excluded_repositories.clone(),
+        }
+    }
+}
+
 /// Database info for display in settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -0,0 +1,824 @@
+//! Summary report models
+//!
+//! Weekly / monthly "your week in code" reports built from the
+//! local database only: XP gained by source, level change, badges earned,
+//! streak, top repositories and languages, PRs merged, reviews given and
+//! challenges completed. A report always covers the last *complete* week
+//! (Monday–Sunday) or calendar month in UTC, so the Monday notification
+//! and a report saved later that week describe the same days.
+//!
+//! Rendering is pure so it can be tested here: [`SummaryReport::to_markdown`]
+//! and [`SummaryReport::to_html`] (a standalone page with inline styles).
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/models/mod.rs
+//!   ├─ src-tauri/src/database/repository/xp_history.rs (XpSourceTotal)
+//!   └─ src-tauri/src/commands/summary_report.rs
+//! Dependencies:
+//!   ├─ src-tauri/src/database/models/period_comparison.rs (PeriodRange)
+//!   ├─ src-tauri/src/database/models/code_stats.rs
+//!   └─ src-tauri/src/database/challenge.rs (language_of_metric)
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::code_stats::DailyCodeStats;
+use super::period_comparison::PeriodRange;
+use crate::database::challenge::language_of_metric;
+
+/// Repositories listed in a report
+pub const REPORT_TOP_REPOSITORIES: usize = 5;
+/// Languages listed in a report
+pub const REPORT_TOP_LANGUAGES: usize = 5;
+
+/// Report period values
+pub mod report_periods {
+    /// Last complete Monday–Sunday week
+    pub const WEEK: &str = "week";
+    /// Last complete calendar month
+    pub const MONTH: &str = "month";
+
+    pub const PERIODS: [&str; 2] = [WEEK, MONTH];
+}
+
+/// Report output formats
+pub mod report_formats {
+    pub const MARKDOWN: &str = "markdown";
+    pub const HTML: &str = "html";
+
+    pub const FORMATS: [&str; 2] = [MARKDOWN, HTML];
+}
+
+/// Range of the last complete period before `today`, or `None` for an
+/// unknown period
+pub fn report_range(period: &str, today: NaiveDate) -> Option<PeriodRange> {
+    match period {
+        report_periods::WEEK => {
+            let this_monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+            Some(PeriodRange::new(
+                this_monday - Duration::weeks(1),
+                this_monday - Duration::days(1),
+            ))
+        }
+        report_periods::MONTH => {
+            let first_of_month = today.with_day(1)?;
+            let end = first_of_month - Duration::days(1);
+            Some(PeriodRange::new(end.with_day(1)?, end))
+        }
+        _ => None,
+    }
+}
+
+/// Level before and after a period.
+///
+/// Replays the live XP earned during and after the period backwards from
+/// the current total. A prestige during the period is not replayed, so the
+/// start level is then an approximation.
+pub fn level_change(
+    total_xp: i32,
+    prestige_xp_offset: i32,
+    xp_since_start: i32,
+    xp_after_end: i32,
+) -> (i32, i32) {
+    use super::level::{level_from_xp, level_xp};
+
+    let level_at = |xp_after: i32| level_from_xp(level_xp(total_xp - xp_after, prestige_xp_offset));
+    (level_at(xp_since_start), level_at(xp_after_end))
+}
+
+/// XP of one `xp_history.action_type` within a period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XpSourceTotal {
+    pub action_type: String,
+    pub xp: i64,
+    /// Number of XP entries
+    pub count: i64,
+}
+
+/// Badge earned within a period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportBadge {
+    pub badge_id: String,
+    pub name: String,
+    pub icon: String,
+    pub rarity: String,
+    pub earned_at: DateTime<Utc>,
+}
+
+/// Challenge completed within a period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportChallenge {
+    pub challenge_type: String,
+    pub target_metric: String,
+    pub target_value: i32,
+    pub reward_xp: i32,
+    pub completed_at: DateTime<Utc>,
+}
+
+/// Repository with commits within a period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportRepository {
+    pub name_with_owner: String,
+    /// Days with commits to the repository
+    pub active_days: i64,
+    /// From the cached language breakdown, when known
+    pub primary_language: Option<String>,
+}
+
+/// Language of the period's repositories
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportLanguage {
+    pub name: String,
+    /// Repository-days with commits in this (primary) language
+    pub active_days: i64,
+}
+
+/// Repositories of the period ranked by active days, and the primary
+/// languages of those repositories weighted the same way.
+///
+/// `repo_languages` maps `owner/name` to its primary language.
+pub fn rank_repositories(
+    daily: &[DailyCodeStats],
+    repo_languages: &HashMap<String, String>,
+) -> (Vec<ReportRepository>, Vec<ReportLanguage>) {
+    let mut days_by_repo: HashMap<String, i64> = HashMap::new();
+    for day in daily {
+        for repository in day.repositories() {
+            *days_by_repo.entry(repository).or_insert(0) += 1;
+        }
+    }
+
+    let mut days_by_language: HashMap<String, i64> = HashMap::new();
+    for (repository, days) in &days_by_repo {
+        if let Some(language) = repo_languages.get(repository) {
+            *days_by_language.entry(language.clone()).or_insert(0) += days;
+        }
+    }
+
+    let mut repositories: Vec<ReportRepository> = days_by_repo
+        .into_iter()
+        .map(|(name_with_owner, active_days)| ReportRepository {
+            primary_language: repo_languages.get(&name_with_owner).cloned(),
+            name_with_owner,
+            active_days,
+        })
+        .collect();
+    repositories.sort_by(|a, b| {
+        b.active_days
+            .cmp(&a.active_days)
+            .then_with(|| a.name_with_owner.cmp(&b.name_with_owner))
+    });
+    repositories.truncate(REPORT_TOP_REPOSITORIES);
+
+    let mut languages: Vec<ReportLanguage> = days_by_language
+        .into_iter()
+        .map(|(name, active_days)| ReportLanguage { name, active_days })
+        .collect();
+    languages.sort_by(|a, b| {
+        b.active_days
+            .cmp(&a.active_days)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    languages.truncate(REPORT_TOP_LANGUAGES);
+
+    (repositories, languages)
+}
+
+/// Weekly / monthly summary report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SummaryReport {
+    /// One of [`report_periods`]
+    pub period: String,
+    pub range: PeriodRange,
+    pub username: String,
+    pub generated_at: DateTime<Utc>,
+    /// Live XP earned during the period
+    pub xp_total: i64,
+    pub xp_by_source: Vec<XpSourceTotal>,
+    pub level_start: i32,
+    pub level_end: i32,
+    pub badges: Vec<ReportBadge>,
+    /// Streaks at generation time
+    pub current_streak: i32,
+    pub longest_streak: i32,
+    /// Days with commits (daily code stats)
+    pub active_days: i64,
+    pub additions: i64,
+    pub deletions: i64,
+    // Snapshot deltas from `load_period_totals`; `None` when they cannot be
+    // trusted (see `PeriodTotals::from_sources`)
+    pub commits: Option<i64>,
+    pub pull_requests_merged: Option<i64>,
+    pub reviews: Option<i64>,
+    pub top_repositories: Vec<ReportRepository>,
+    pub languages: Vec<ReportLanguage>,
+    pub challenges_completed: Vec<ReportChallenge>,
+}
+
+/// Display name of an `xp_history.action_type`
+fn action_label(action_type: &str) -> &str {
+    match action_type {
+        "commit" => "コミット",
+        "pull_request" => "PR作成",
+        "pull_request_merged" => "PRマージ",
+        "review" => "レビュー",
+        "issue" => "Issue作成",
+        "issue_closed" => "Issueクローズ",
+        "time_tracked" => "タイムトラッキング",
+        "streak_bonus" => "ストリークボーナス",
+        "prestige_bonus" => "プレステージボーナス",
+        "github_sync" => "GitHub同期",
+        "xp_adjusted" => "XP調整",
+        "star" => "スター獲得",
+        other => other,
+    }
+}
+
+/// Display name of a challenge type
+fn challenge_type_label(challenge_type: &str) -> &str {
+    match challenge_type {
+        "daily" => "デイリー",
+        "weekly" => "ウィークリー",
+        "monthly" => "マンスリー",
+        "quarterly" => "四半期",
+        other => other,
+    }
+}
+
+/// Display name of a challenge target metric
+fn metric_label(metric: &str) -> String {
+    if let Some(language) = language_of_metric(metric) {
+        return format!("{} を使った日数", language);
+    }
+    match metric {
+        "commits" => "コミット",
+        "prs" => "PR作成",
+        "prs_merged" => "PRマージ",
+        "reviews" => "レビュー",
+        "issues" => "Issue作成",
+        "issues_closed" => "Issueクローズ",
+        "focus_minutes" => "集中時間（分）",
+        "lines_added" => "追加行",
+        "lines_deleted" => "削除行",
+        "repositories" => "リポジトリ数",
+        "fast_reviews" => "24時間以内のレビュー",
+        other => other,
+    }
+    .to_string()
+}
+
+/// Integer with thousands separators
+fn format_number(value: i64) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    if value < 0 {
+        format!("-{}", grouped)
+    } else {
+        grouped
+    }
+}
+
+/// Footnote for the metrics shown as "—"
+const UNKNOWN_METRIC_NOTE: &str = "— は同期データから算出できない値です（期間の前後に同期がない、\
+     API制限による代替値、リポジトリフィルターの変更など）";
+
+fn format_optional(value: Option<i64>) -> String {
+    value.map(format_number).unwrap_or_else(|| "—".to_string())
+}
+
+/// Escape text for HTML element content and attribute values
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escape text for a Markdown table cell or list item
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '|' | '*' | '_' | '`' | '[' | ']' | '<' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Rendered sections shared by the Markdown and HTML outputs
+struct ReportSections {
+    summary: Vec<(&'static str, String)>,
+    xp_rows: Vec<[String; 3]>,
+    badges: Vec<String>,
+    challenges: Vec<String>,
+    repositories: Vec<String>,
+    languages: Vec<String>,
+}
+
+impl SummaryReport {
+    /// Report title, e.g. "週間レポート 2026-10-12 〜 2026-10-18"
+    pub fn title(&self) -> String {
+        let kind = if self.period == report_periods::MONTH {
+            "月間レポート"
+        } else {
+            "週間レポート"
+        };
+        format!("{} {} 〜 {}", kind, self.range.start, self.range.end)
+    }
+
+    /// Suggested file name for a format
+    pub fn file_name(&self, format: &str) -> String {
+        let extension = if format == report_formats::HTML {
+            "html"
+        } else {
+            "md"
+        };
+        let kind = if self.period == report_periods::MONTH {
+            "monthly"
+        } else {
+            "weekly"
+        };
+        format!("{}-report-{}.{}", kind, self.range.start, extension)
+    }
+
+    /// One-line summary for the OS notification
+    pub fn notification_body(&self) -> String {
+        let mut parts = vec![format!("+{} XP", format_number(self.xp_total))];
+        if self.level_end > self.level_start {
+            parts.push(format!("Lv.{} → Lv.{}", self.level_start, self.level_end));
+        }
+        if let Some(merged) = self.pull_requests_merged {
+            parts.push(format!("PRマージ {}", merged));
+        }
+        if let Some(reviews) = self.reviews {
+            parts.push(format!("レビュー {}", reviews));
+        }
+        if !self.badges.is_empty() {
+            parts.push(format!("バッジ {}個", self.badges.len()));
+        }
+        parts.push(format!("ストリーク {}日", self.current_streak));
+        parts.join(" / ")
+    }
+
+    fn sections(&self) -> ReportSections {
+        let level = if self.level_end == self.level_start {
+            format!("Lv.{}", self.level_end)
+        } else {
+            format!("Lv.{} → Lv.{}", self.level_start, self.level_end)
+        };
+        let summary = vec![
+            ("獲得XP", format_number(self.xp_total)),
+            ("レベル", level),
+            (
+                "ストリーク",
+                format!(
+                    "{}日（最長 {}日）",
+                    self.current_streak, self.longest_streak
+                ),
+            ),
+            ("活動日数", format!("{}日", self.active_days)),
+            ("コミット", format_optional(self.commits)),
+            ("マージされたPR", format_optional(self.pull_requests_merged)),
+            ("レビュー", format_optional(self.reviews)),
+            (
+                "追加 / 削除行",
+                format!(
+                    "+{} / -{}",
+                    format_number(self.additions),
+                    format_number(self.deletions)
+                ),
+            ),
+            (
+                "完了したチャレンジ",
+                self.challenges_completed.len().to_string(),
+            ),
+        ];
+
+        let xp_rows = self
+            .xp_by_source
+            .iter()
+            .map(|source| {
+                [
+                    action_label(&source.action_type).to_string(),
+                    format_number(source.xp),
+                    format_number(source.count),
+                ]
+            })
+            .collect();
+
+        let badges = self
+            .badges
+            .iter()
+            .map(|badge| {
+                format!(
+                    "{} {}（{}）— {}",
+                    badge.icon,
+                    badge.name,
+                    badge.rarity,
+                    badge.earned_at.format("%Y-%m-%d")
+                )
+            })
+            .collect();
+
+        let challenges = self
+            .challenges_completed
+            .iter()
+            .map(|challenge| {
+                format!(
+                    "{}: {} {}（+{} XP）",
+                    challenge_type_label(&challenge.challenge_type),
+                    metric_label(&challenge.target_metric),
+                    challenge.target_value,
+                    challenge.reward_xp
+                )
+            })
+            .collect();
+
+        let repositories = self
+            .top_repositories
+            .iter()
+            .map(|repository| match &repository.primary_language {
+                Some(language) => format!(
+                    "{} — {}日（{}）",
+                    repository.name_with_owner, repository.active_days, language
+                ),
+                None => format!(
+                    "{} — {}日",
+                    repository.name_with_owner, repository.active_days
+                ),
+            })
+            .collect();
+
+        let languages = self
+            .languages
+            .iter()
+            .map(|language| format!("{} — {}日", language.name, language.active_days))
+            .collect();
+
+        ReportSections {
+            summary,
+            xp_rows,
+            badges,
+            challenges,
+            repositories,
+            languages,
+        }
+    }
+
+    /// Whether a snapshot metric is shown as "—"
+    fn has_unknown_metrics(&self) -> bool {
+        self.commits.is_none() || self.pull_requests_merged.is_none() || self.reviews.is_none()
+    }
+
+    fn subtitle(&self) -> String {
+        format!(
+            "@{} ・ {} 生成",
+            self.username,
+            self.generated_at.format("%Y-%m-%d %H:%M UTC")
+        )
+    }
+
+    /// Render the report as Markdown
+    pub fn to_markdown(&self) -> String {
+        let sections = self.sections();
+        let mut md = format!(
+            "# {}\n\n{}\n\n## サマリー\n\n| 指標 | 値 |\n| --- | --- |\n",
+            self.title(),
+            escape_markdown(&self.subtitle())
+        );
+        for (label, value) in &sections.summary {
+            md.push_str(&format!("| {} | {} |\n", label, escape_markdown(value)));
+        }
+        if self.has_unknown_metrics() {
+            md.push_str(&format!("\n{}\n", UNKNOWN_METRIC_NOTE));
+        }
+
+        md.push_str("\n## XPの内訳\n\n");
+        if sections.xp_rows.is_empty() {
+            md.push_str("この期間に獲得したXPはありません\n");
+        } else {
+            md.push_str("| ソース | XP | 回数 |\n| --- | ---: | ---: |\n");
+            for [source, xp, count] in &sections.xp_rows {
+                md.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    escape_markdown(source),
+                    xp,
+                    count
+                ));
+            }
+        }
+
+        let lists = [
+            ("獲得したバッジ", &sections.badges, false),
+            ("完了したチャレンジ", &sections.challenges, false),
+            ("よく使ったリポジトリ", &sections.repositories, true),
+            ("言語", &sections.languages, true),
+        ];
+        for (heading, items, numbered) in lists {
+            md.push_str(&format!("\n## {}\n\n", heading));
+            if items.is_empty() {
+                md.push_str("なし\n");
+            }
+            for (i, item) in items.iter().enumerate() {
+                if numbered {
+                    md.push_str(&format!("{}. {}\n", i + 1, escape_markdown(item)));
+                } else {
+                    md.push_str(&format!("- {}\n", escape_markdown(item)));
+                }
+            }
+        }
+
+        md
+    }
+
+    /// Render the report as a standalone HTML page
+    pub fn to_html(&self) -> String {
+        let sections = self.sections();
+        let title = escape_html(&self.title());
+
+        let mut body = format!(
+            "<h1>{}</h1>\n<p class=\"sub\">{}</p>\n<h2>サマリー</h2>\n<table>\n",
+            title,
+            escape_html(&self.subtitle())
+        );
+        for (label, value) in &sections.summary {
+            body.push_str(&format!(
+                "<tr><th>{}</th><td>{}</td></tr>\n",
+                label,
+                escape_html(value)
+            ));
+        }
+        body.push_str("</table>\n");
+        if self.has_unknown_metrics() {
+            body.push_str(&format!(
+                "<p class=\"sub\">{}</p>\n",
+                escape_html(UNKNOWN_METRIC_NOTE)
+            ));
+        }
+        body.push_str("<h2>XPの内訳</h2>\n");
+        if sections.xp_rows.is_empty() {
+            body.push_str("<p>この期間に獲得したXPはありません</p>\n");
+        } else {
+            body.push_str("<table>\n<tr><th>ソース</th><th>XP</th><th>回数</th></tr>\n");
+            for [source, xp, count] in &sections.xp_rows {
+                body.push_str(&format!(
+                    "<tr><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td></tr>\n",
+                    escape_html(source),
+                    xp,
+                    count
+                ));
+            }
+            body.push_str("</table>\n");
+        }
+
+        let lists = [
+            ("獲得したバッジ", &sections.badges, "ul"),
+            ("完了したチャレンジ", &sections.challenges, "ul"),
+            ("よく使ったリポジトリ", &sections.repositories, "ol"),
+            ("言語", &sections.languages, "ol"),
+        ];
+        for (heading, items, tag) in lists {
+            body.push_str(&format!("<h2>{}</h2>\n", heading));
+            if items.is_empty() {
+                body.push_str("<p>なし</p>\n");
+                continue;
+            }
+            body.push_str(&format!("<{}>\n", tag));
+            for item in items {
+                body.push_str(&format!("<li>{}</li>\n", escape_html(item)));
+            }
+            body.push_str(&format!("</{}>\n", tag));
+        }
+
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="ja">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title}</title>
+<style>
+body {{ font-family: -apple-system, "Segoe UI", "Hiragino Sans", "Noto Sans JP", sans-serif; max-width: 720px; margin: 2rem auto; padding: 0 1rem; color: #1e293b; line-height: 1.6; }}
+h1 {{ font-size: 1.5rem; margin-bottom: 0.25rem; }}
+h2 {{ font-size: 1.1rem; margin-top: 2rem; border-bottom: 1px solid #e2e8f0; padding-bottom: 0.25rem; }}
+.sub {{ color: #64748b; margin-top: 0; }}
+table {{ border-collapse: collapse; width: 100%; }}
+th, td {{ text-align: left; padding: 0.35rem 0.5rem; border-bottom: 1px solid #f1f5f9; }}
+th {{ font-weight: 600; color: #475569; }}
+.num {{ text-align: right; font-variant-numeric: tabular-nums; }}
+</style>
+</head>
+<body>
+{body}</body>
+</html>
+"#
+        )
+    }
+
+    /// Render in one of [`report_formats`]
+    pub fn render(&self, format: &str) -> Result<String, String> {
+        match format {
+            report_formats::MARKDOWN => Ok(self.to_markdown()),
+            report_formats::HTML => Ok(self.to_html()),
+            other => Err(format!("Unknown report format: {}", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn daily(date: &str, repositories: &[&str]) -> DailyCodeStats {
+        DailyCodeStats {
+            id: 0,
+            user_id: 1,
+            date: date.to_string(),
+            additions: 10,
+            deletions: 2,
+            commits_count: 1,
+            repositories_json: Some(serde_json::to_string(repositories).unwrap()),
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    fn sample_report() -> SummaryReport {
+        SummaryReport {
+            period: report_periods::WEEK.to_string(),
+            range: PeriodRange::new(date(2026, 10, 5), date(2026, 10, 11)),
+            username: "octocat".to_string(),
+            generated_at: DateTime::parse_from_rfc3339("2026-10-12T09:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            xp_total: 1234,
+            xp_by_source: vec![XpSourceTotal {
+                action_type: "commit".to_string(),
+                xp: 1234,
+                count: 12,
+            }],
+            level_start: 11,
+            level_end: 12,
+            badges: vec![],
+            current_streak: 5,
+            longest_streak: 20,
+            active_days: 5,
+            additions: 1200,
+            deletions: 300,
+            commits: Some(42),
+            pull_requests_merged: Some(3),
+            reviews: None,
+            top_repositories: vec![ReportRepository {
+                name_with_owner: "octocat/<script>".to_string(),
+                active_days: 4,
+                primary_language: Some("Rust".to_string()),
+            }],
+            languages: vec![],
+            challenges_completed: vec![],
+        }
+    }
+
+    #[test]
+    fn test_report_range_week_is_last_complete_week() {
+        // 2026-10-14 is a Wednesday
+        assert_eq!(
+            report_range(report_periods::WEEK, date(2026, 10, 14)),
+            Some(PeriodRange::new(date(2026, 10, 5), date(2026, 10, 11)))
+        );
+        // On Monday the week that just ended is reported
+        assert_eq!(
+            report_range(report_periods::WEEK, date(2026, 10, 12)),
+            Some(PeriodRange::new(date(2026, 10, 5), date(2026, 10, 11)))
+        );
+    }
+
+    #[test]
+    fn test_report_range_month() {
+        assert_eq!(
+            report_range(report_periods::MONTH, date(2026, 3, 15)),
+            Some(PeriodRange::new(date(2026, 2, 1), date(2026, 2, 28)))
+        );
+        assert_eq!(
+            report_range(report_periods::MONTH, date(2026, 1, 1)),
+            Some(PeriodRange::new(date(2025, 12, 1), date(2025, 12, 31)))
+        );
+        assert_eq!(report_range("year", date(2026, 1, 1)), None);
+    }
+
+    #[test]
+    fn test_level_change() {
+        let (start, end) = level_change(1000, 0, 400, 0);
+        assert!(start <= end);
+        assert_eq!(end, super::super::level::level_from_xp(1000));
+        assert_eq!(start, super::super::level::level_from_xp(600));
+
+        // No XP in or after the period
+        assert_eq!(level_change(1000, 0, 0, 0).0, level_change(1000, 0, 0, 0).1);
+    }
+
+    #[test]
+    fn test_rank_repositories() {
+        let stats = vec![
+            daily("2026-10-05", &["a/api", "a/web"]),
+            daily("2026-10-06", &["a/api"]),
+            daily("2026-10-07", &["b/tool"]),
+        ];
+        let languages = HashMap::from([
+            ("a/api".to_string(), "Rust".to_string()),
+            ("b/tool".to_string(), "Rust".to_string()),
+            ("a/web".to_string(), "TypeScript".to_string()),
+        ]);
+
+        let (repositories, languages) = rank_repositories(&stats, &languages);
+        assert_eq!(repositories[0].name_with_owner, "a/api");
+        assert_eq!(repositories[0].active_days, 2);
+        assert_eq!(repositories[0].primary_language.as_deref(), Some("Rust"));
+        assert_eq!(repositories.len(), 3);
+        assert_eq!(
+            languages,
+            vec![
+                ReportLanguage {
+                    name: "Rust".to_string(),
+                    active_days: 3
+                },
+                ReportLanguage {
+                    name: "TypeScript".to_string(),
+                    active_days: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(0), "0");
+        assert_eq!(format_number(999), "999");
+        assert_eq!(format_number(1234567), "1,234,567");
+        assert_eq!(format_number(-1200), "-1,200");
+    }
+
+    #[test]
+    fn test_markdown_report() {
+        let md = sample_report().to_markdown();
+        assert!(md.starts_with("# 週間レポート 2026-10-05 〜 2026-10-11\n"));
+        assert!(md.contains("| 獲得XP | 1,234 |"));
+        assert!(md.contains("| レベル | Lv.11 → Lv.12 |"));
+        assert!(md.contains("| レビュー | — |"));
+        assert!(md.contains(UNKNOWN_METRIC_NOTE));
+        assert!(md.contains("| コミット | 1,234 | 12 |"));
+        assert!(md.contains("1. octocat/\\<script\\> — 4日（Rust）"));
+        assert!(md.contains("## 獲得したバッジ\n\nなし\n"));
+    }
+
+    #[test]
+    fn test_html_report_is_escaped() {
+        let html = sample_report().to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>週間レポート 2026-10-05 〜 2026-10-11</title>"));
+        assert!(html.contains("octocat/&lt;script&gt;"));
+        assert!(!html.contains("<script>"));
+    }
+
+    #[test]
+    fn test_notification_body_and_file_name() {
+        let report = sample_report();
+        assert_eq!(
+            report.notification_body(),
+            "+1,234 XP / Lv.11 → Lv.12 / PRマージ 3 / ストリーク 5日"
+        );
+        assert_eq!(
+            report.file_name(report_formats::HTML),
+            "weekly-report-2026-10-05.html"
+        );
+        assert!(report.render("pdf").is_err());
+    }
+}
@@ -0,0 +1,520 @@
+//! Issue time tracking models
+//!
+//! The kanban card timers record start/stop sessions in
+//! `issue_time_entries` (migration v26). [`summarize_time_entries`] turns
+//! the entries of a period into the per-issue, per-project and per-day
+//! totals shown on the project dashboard, and
+//! [`format_time_summary_comment`] renders the Markdown posted by
+//! "Post time summary".
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/models/mod.rs
+//!   └─ src-tauri/src/commands/time_tracking.rs
+//! Dependencies:
+//!   (none)
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Longest period `get_time_summary` covers.
+pub const MAX_TIME_SUMMARY_DAYS: i64 = 366;
+
+/// One timer session (`issue_time_entries`).
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TimeEntry {
+    pub id: i64,
+    pub user_id: i64,
+    pub project_id: i64,
+    /// `None` once the cached issue row is gone (see migration v26).
+    pub cached_issue_id: Option<i64>,
+    pub repo_full_name: Option<String>,
+    /// Current number of the linked issue, or the number at start time.
+    pub issue_number: i32,
+    pub issue_title: String,
+    /// RFC 3339 (UTC)
+    pub started_at: String,
+    /// `None` while the timer is running.
+    pub ended_at: Option<String>,
+    /// 0 while the timer is running.
+    pub duration_seconds: i64,
+    pub note: Option<String>,
+    pub xp_awarded: i32,
+}
+
+impl TimeEntry {
+    pub fn is_running(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// Start / end of the session; a running timer ends at `now`.
+    pub fn span(&self, now: DateTime<Utc>) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let start = parse_timestamp(&self.started_at)?;
+        let end = match &self.ended_at {
+            Some(ended_at) => parse_timestamp(ended_at)?,
+            None => now,
+        };
+        Some((start, end.max(start)))
+    }
+}
+
+/// Time logged on one issue (`get_issue_time_entries`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueTimeLog {
+    /// Newest first.
+    pub entries: Vec<TimeEntry>,
+    /// Finished entries only; the running one is added by the UI.
+    pub total_seconds: i64,
+}
+
+/// Total of one issue in a period.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueTimeTotal {
+    pub project_id: i64,
+    pub repo_full_name: Option<String>,
+    pub issue_number: i32,
+    pub issue_title: String,
+    pub total_seconds: i64,
+    pub entry_count: i64,
+}
+
+/// Total of one project in a period.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectTimeTotal {
+    pub project_id: i64,
+    pub project_name: String,
+    pub total_seconds: i64,
+}
+
+/// Total of one (UTC) day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyTimeTotal {
+    /// `YYYY-MM-DD` (UTC)
+    pub date: String,
+    pub total_seconds: i64,
+}
+
+/// Response of `get_time_summary`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimeSummary {
+    /// `YYYY-MM-DD` (UTC), inclusive.
+    pub from: String,
+    pub to: String,
+    pub total_seconds: i64,
+    /// Longest first.
+    pub by_issue: Vec<IssueTimeTotal>,
+    /// Longest first.
+    pub by_project: Vec<ProjectTimeTotal>,
+    /// Every day of the period, oldest first (zero days included).
+    pub by_day: Vec<DailyTimeTotal>,
+}
+
+/// Parse a stored RFC 3339 timestamp.
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .ok()
+}
+
+/// Seconds of `[start, end)` falling on each UTC day. Sessions running
+/// past midnight count towards both days.
+pub fn split_by_day(start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<(NaiveDate, i64)> {
+    let mut days = Vec::new();
+    let mut cursor = start;
+    while cursor < end {
+        let day = cursor.date_naive();
+        let next_midnight = (day + Duration::days(1))
+            .and_hms_opt(0, 0, 0)
+            .map(|t| t.and_utc())
+            .unwrap_or(end);
+        let until = next_midnight.min(end);
+        days.push((day, (until - cursor).num_seconds()));
+        cursor = until;
+    }
+    days
+}
+
+/// Seconds of the session overlapping `[from, to)`.
+pub fn overlap_seconds(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> i64 {
+    let lower = start.max(from);
+    let upper = end.min(to);
+    (upper - lower).num_seconds().max(0)
+}
+
+/// Per-issue / per-project / per-day totals of `entries` between `from`
+/// and `to` (inclusive UTC days). Only the part of each session inside
+/// the period is counted; running timers count up to `now`.
+pub fn summarize_time_entries(
+    entries: &[TimeEntry],
+    project_names: &HashMap<i64, String>,
+    from: NaiveDate,
+    to: NaiveDate,
+    now: DateTime<Utc>,
+) -> TimeSummary {
+    let mut summary = TimeSummary {
+        from: from.to_string(),
+        to: to.to_string(),
+        ..Default::default()
+    };
+    if to < from {
+        return summary;
+    }
+
+    let mut by_day: BTreeMap<NaiveDate, i64> = BTreeMap::new();
+    let mut day = from;
+    while day <= to {
+        by_day.insert(day, 0);
+        day += Duration::days(1);
+    }
+
+    // Keyed by (project, repository, number); entries are loaded with the
+    // linked issue's current number, so renumbered placeholders merge.
+    let mut by_issue: Vec<((i64, Option<String>, i32), IssueTimeTotal)> = Vec::new();
+    let mut by_project: BTreeMap<i64, i64> = BTreeMap::new();
+
+    for entry in entries {
+        let Some((start, end)) = entry.span(now) else {
+            continue;
+        };
+        let mut seconds = 0;
+        for (day, secs) in split_by_day(start, end) {
+            if let Some(total) = by_day.get_mut(&day) {
+                *total += secs;
+                seconds += secs;
+            }
+        }
+        if seconds == 0 {
+            continue;
+        }
+
+        summary.total_seconds += seconds;
+        *by_project.entry(entry.project_id).or_default() += seconds;
+
+        let key = (
+            entry.project_id,
+            entry.repo_full_name.clone(),
+            entry.issue_number,
+        );
+        match by_issue.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, total)) => {
+                total.total_seconds += seconds;
+                total.entry_count += 1;
+            }
+            None => by_issue.push((
+                key,
+                IssueTimeTotal {
+                    project_id: entry.project_id,
+                    repo_full_name: entry.repo_full_name.clone(),
+                    issue_number: entry.issue_number,
+                    issue_title: entry.issue_title.clone(),
+                    total_seconds: seconds,
+                    entry_count: 1,
+                },
+            )),
+        }
+    }
+
+    summary.by_issue = by_issue.into_iter().map(|(_, total)| total).collect();
+    summary
+        .by_issue
+        .sort_by(|a, b| b.total_seconds.cmp(&a.total_seconds));
+
+    summary.by_project = by_project
+        .into_iter()
+        .map(|(project_id, total_seconds)| ProjectTimeTotal {
+            project_id,
+            project_name: project_names
+                .get(&project_id)
+                .cloned()
+                .unwrap_or_else(|| format!("Project #{}", project_id)),
+            total_seconds,
+        })
+        .collect();
+    summary
+        .by_project
+        .sort_by(|a, b| b.total_seconds.cmp(&a.total_seconds));
+
+    summary.by_day = by_day
+        .into_iter()
+        .map(|(date, total_seconds)| DailyTimeTotal {
+            date: date.to_string(),
+            total_seconds,
+        })
+        .collect();
+    summary
+}
+
+/// `1h 05m`, `25m`, `0m` (seconds are dropped).
+pub fn format_duration(seconds: i64) -> String {
+    let minutes = seconds.max(0) / 60;
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, m) => format!("{}h {:02}m", h, m),
+    }
+}
+
+/// Markdown comment summarising the finished entries of one issue: the
+/// total, then one row per day (oldest first) with the session notes.
+pub fn format_time_summary_comment(entries: &[TimeEntry]) -> String {
+    let mut by_day: BTreeMap<NaiveDate, (i64, Vec<String>)> = BTreeMap::new();
+    let mut total = 0;
+    let mut sessions = 0;
+
+    for entry in entries.iter().filter(|e| !e.is_running()) {
+        let Some(start) = parse_timestamp(&entry.started_at) else {
+            continue;
+        };
+        total += entry.duration_seconds;
+        sessions += 1;
+        let day = by_day.entry(start.date_naive()).or_default();
+        day.0 += entry.duration_seconds;
+        if let Some(note) = entry
+            .note
+            .as_deref()
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+        {
+            day.1.push(note.replace('|', "\\|").replace('\n', " "));
+        }
+    }
+
+    let mut lines = vec![
+        "### ⏱ Time tracked".to_string(),
+        String::new(),
+        format!(
+            "**{}** in {} session{}",
+            format_duration(total),
+            sessions,
+            if sessions == 1 { "" } else { "s" }
+        ),
+        String::new(),
+        "| Date | Time | Notes |".to_string(),
+        "| --- | --- | --- |".to_string(),
+    ];
+    lines.extend(by_day.into_iter().map(|(date, (seconds, notes))| {
+        format!(
+            "| {} | {} | {} |",
+            date,
+            format_duration(seconds),
+            notes.join("; ")
+        )
+    }));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        parse_timestamp(value).unwrap()
+    }
+
+    fn entry(
+        project_id: i64,
+        number: i32,
+        started_at: &str,
+        ended_at: Option<&str>,
+        note: Option<&str>,
+    ) -> TimeEntry {
+        let duration_seconds = ended_at
+            .map(|end| (at(end) - at(started_at)).num_seconds())
+            .unwrap_or(0);
+        TimeEntry {
+            id: 0,
+            user_id: 1,
+            project_id,
+            cached_issue_id: Some(number as i64),
+            repo_full_name: Some("octo/app".to_string()),
+            issue_number: number,
+            issue_title: format!("Issue {}", number),
+            started_at: started_at.to_string(),
+            ended_at: ended_at.map(str::to_string),
+            duration_seconds,
+            note: note.map(str::to_string),
+            xp_awarded: 0,
+        }
+    }
+
+    #[test]
+    fn split_by_day_breaks_at_utc_midnight() {
+        let days = split_by_day(at("2024-03-01T23:30:00Z"), at("2024-03-02T01:00:00Z"));
+        assert_eq!(
+            days,
+            vec![
+                (NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(), 30 * 60),
+                (NaiveDate::from_ymd_opt(2024, 3, 2).unwrap(), 60 * 60),
+            ]
+        );
+        assert!(split_by_day(at("2024-03-01T10:00:00Z"), at("2024-03-01T10:00:00Z")).is_empty());
+    }
+
+    #[test]
+    fn overlap_is_clipped_to_the_window() {
+        let from = at("2024-03-01T10:00:00Z");
+        let to = at("2024-03-01T12:00:00Z");
+        assert_eq!(
+            overlap_seconds(
+                at("2024-03-01T09:00:00Z"),
+                at("2024-03-01T10:30:00Z"),
+                from,
+                to
+            ),
+            30 * 60
+        );
+        assert_eq!(
+            overlap_seconds(
+                at("2024-03-01T13:00:00Z"),
+                at("2024-03-01T14:00:00Z"),
+                from,
+                to
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn summary_totals_per_issue_project_and_day() {
+        let entries = vec![
+            entry(
+                1,
+                7,
+                "2024-03-01T09:00:00Z",
+                Some("2024-03-01T10:00:00Z"),
+                None,
+            ),
+            entry(
+                1,
+                7,
+                "2024-03-02T09:00:00Z",
+                Some("2024-03-02T09:30:00Z"),
+                None,
+            ),
+            entry(
+                1,
+                8,
+                "2024-03-02T10:00:00Z",
+                Some("2024-03-02T10:15:00Z"),
+                None,
+            ),
+            entry(
+                2,
+                3,
+                "2024-03-02T11:00:00Z",
+                Some("2024-03-02T13:00:00Z"),
+                None,
+            ),
+            // Outside the period
+            entry(
+                1,
+                7,
+                "2024-02-20T09:00:00Z",
+                Some("2024-02-20T10:00:00Z"),
+                None,
+            ),
+        ];
+        let names = HashMap::from([(1, "App".to_string()), (2, "Infra".to_string())]);
+        let summary = summarize_time_entries(
+            &entries,
+            &names,
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 3).unwrap(),
+            at("2024-03-03T12:00:00Z"),
+        );
+
+        assert_eq!(summary.total_seconds, (60 + 30 + 15 + 120) * 60);
+        assert_eq!(
+            summary
+                .by_issue
+                .iter()
+                .map(|t| (
+                    t.project_id,
+                    t.issue_number,
+                    t.total_seconds / 60,
+                    t.entry_count
+                ))
+                .collect::<Vec<_>>(),
+            vec![(2, 3, 120, 1), (1, 7, 90, 2), (1, 8, 15, 1)]
+        );
+        assert_eq!(
+            summary
+                .by_project
+                .iter()
+                .map(|t| (t.project_name.as_str(), t.total_seconds / 60))
+                .collect::<Vec<_>>(),
+            vec![("Infra", 120), ("App", 105)]
+        );
+        assert_eq!(
+            summary
+                .by_day
+                .iter()
+                .map(|d| (d.date.as_str(), d.total_seconds / 60))
+                .collect::<Vec<_>>(),
+            vec![("2024-03-01", 60), ("2024-03-02", 165), ("2024-03-03", 0)]
+        );
+    }
+
+    #[test]
+    fn running_timer_counts_up_to_now() {
+        let entries = vec![entry(1, 7, "2024-03-01T09:00:00Z", None, None)];
+        let day = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let summary = summarize_time_entries(
+            &entries,
+            &HashMap::new(),
+            day,
+            day,
+            at("2024-03-01T09:45:00Z"),
+        );
+        assert_eq!(summary.total_seconds, 45 * 60);
+        assert_eq!(summary.by_project[0].project_name, "Project #1");
+    }
+
+    #[test]
+    fn format_duration_rounds_down_to_minutes() {
+        assert_eq!(format_duration(0), "0m");
+        assert_eq!(format_duration(25 * 60 + 59), "25m");
+        assert_eq!(format_duration(65 * 60), "1h 05m");
+    }
+
+    #[test]
+    fn summary_comment_groups_sessions_by_day() {
+        let entries = vec![
+            entry(
+                1,
+                7,
+                "2024-03-02T09:00:00Z",
+                Some("2024-03-02T09:30:00Z"),
+                Some("review | fixes"),
+            ),
+            entry(
+                1,
+                7,
+                "2024-03-01T09:00:00Z",
+                Some("2024-03-01T10:00:00Z"),
+                Some("investigation"),
+            ),
+            entry(
+                1,
+                7,
+                "2024-03-02T10:00:00Z",
+                Some("2024-03-02T10:20:00Z"),
+                None,
+            ),
+            entry(1, 7, "2024-03-03T10:00:00Z", None, Some("running")),
+        ];
+        let comment = format_time_summary_comment(&entries);
+        assert!(comment.contains("**1h 50m** in 3 sessions"));
+        assert!(comment.contains("| 2024-03-01 | 1h 00m | investigation |"));
+        assert!(comment.contains("| 2024-03-02 | 50m | review \\| fixes |"));
+        assert!(!comment.contains("running"));
+    }
+}
@@ -58,9 +58,28 @@ pub struct UserStats {
     pub total_issues_closed: i32,
     pub languages_count: i32,
     pub total_stars_received: i32,
+    // Prestige: `total_xp` is never reset; the level is derived
+    // from the XP earned since the last prestige.
+    pub prestige_rank: i32,
+    pub prestige_xp_offset: i32,
+    // Seasons: XP earned in the current calendar quarter, kept
+    // next to the lifetime `total_xp`. `season_id` is `None` until the
+    // first season starts.
+    pub season_id: Option<String>,
+    pub season_xp: i32,
+    pub season_best_streak: i32,
+    pub seasons_completed: i32,
+    pub best_season_percentile: i32,
     pub updated_at: DateTime<Utc>,
 }
 
+impl UserStats {
+    /// XP counted towards the current level
+    pub fn level_xp(&self) -> i32 {
+        super::level::level_xp(self.total_xp, self.prestige_xp_offset)
+    }
+}
+
 impl Default for UserStats {
     fn default() -> Self {
         Self {
@@ -81,6 +100,13 @@ impl Default for UserStats {
             total_issues_closed: 0,
             languages_count: 0,
             total_stars_received: 0,
+            prestige_rank: 0,
+            prestige_xp_offset: 0,
+            season_id: None,
+            season_xp: 0,
+            season_best_streak: 0,
+            seasons_completed: 0,
+            best_season_percentile: 0,
             updated_at: Utc::now(),
         }
     }
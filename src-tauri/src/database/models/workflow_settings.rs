@@ -0,0 +1,416 @@
+//! Project workflow settings models
+//!
+//! Per-project settings for the generated "Issue Status Sync" GitHub
+//! Actions workflow (`project_workflow_settings`): the branch naming
+//! pattern the issue number is read from, branches the push trigger
+//! ignores, and which kanban column (plus any custom labels) each push /
+//! pull request event moves the issue to. Projects without a saved row
+//! use [`WorkflowSettings::default`]: the same branch rule, triggers and
+//! column moves as the original fixed template, though the rendered YAML
+//! differs (step names, log lines), so the drift check accepts the
+//! original file too until settings are saved.
+//!
+//! The YAML itself is rendered by `github::issues::generate_actions_template`;
+//! [`diff_workflow`] compares it with the file committed to the repository
+//! for the drift check.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/models/mod.rs
+//!   ├─ src-tauri/src/commands/workflow_settings.rs
+//!   └─ src-tauri/src/github/issues.rs
+//! Dependencies:
+//!   └─ src-tauri/src/database/models/project.rs (IssueStatus)
+
+use serde::{Deserialize, Serialize};
+
+use super::project::IssueStatus;
+
+/// Where the generated workflow is expected in the repository.
+pub const WORKFLOW_FILE_PATH: &str = ".github/workflows/issue-status-sync.yml";
+
+/// Branch naming rule of the original template: `type/<issue>-<description>`.
+pub const DEFAULT_BRANCH_PATTERN: &str =
+    r"^(feat|fix|docs|refactor|test|chore)/([0-9]+)-[a-zA-Z0-9_-]+$";
+
+/// Capture group of [`DEFAULT_BRANCH_PATTERN`] holding the issue number.
+pub const DEFAULT_ISSUE_NUMBER_GROUP: i32 = 2;
+
+/// Events the workflow reacts to
+pub mod workflow_events {
+    pub const PUSH: &str = "push";
+    pub const PULL_REQUEST_OPENED: &str = "pull_request_opened";
+    pub const PULL_REQUEST_MERGED: &str = "pull_request_merged";
+    /// Closed without merging.
+    pub const PULL_REQUEST_CLOSED: &str = "pull_request_closed";
+
+    pub const ALL: [&str; 4] = [
+        PUSH,
+        PULL_REQUEST_OPENED,
+        PULL_REQUEST_MERGED,
+        PULL_REQUEST_CLOSED,
+    ];
+}
+
+/// What one event does to the issue named by the branch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowEventRule {
+    /// One of [`workflow_events`].
+    pub event: String,
+    /// Column to move the issue to (`None` = leave the status alone).
+    pub status: Option<IssueStatus>,
+    /// Extra labels added alongside the status label.
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+impl WorkflowEventRule {
+    fn new(event: &str, status: IssueStatus) -> Self {
+        Self {
+            event: event.to_string(),
+            status: Some(status),
+            labels: Vec::new(),
+        }
+    }
+
+    /// Whether the rule changes anything (inactive rules emit no step).
+    pub fn is_active(&self) -> bool {
+        self.status.is_some() || !self.labels.is_empty()
+    }
+
+    /// Labels the step adds: the status label, then the custom ones.
+    pub fn labels_to_add(&self) -> Vec<String> {
+        self.status
+            .map(|s| s.to_label().to_string())
+            .into_iter()
+            .chain(self.labels.iter().cloned())
+            .collect()
+    }
+}
+
+/// Workflow generator settings of one project.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowSettings {
+    /// Bash (POSIX ERE) pattern a branch name must match.
+    pub branch_pattern: String,
+    /// 1-based capture group of `branch_pattern` holding the issue number.
+    pub issue_number_group: i32,
+    /// Branches the push trigger ignores.
+    pub excluded_branches: Vec<String>,
+    /// One rule per event in [`workflow_events`].
+    pub rules: Vec<WorkflowEventRule>,
+    /// Close the issue (as completed) when its pull request is merged.
+    pub close_on_merge: bool,
+    /// `None` while the project uses the defaults.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl Default for WorkflowSettings {
+    fn default() -> Self {
+        Self {
+            branch_pattern: DEFAULT_BRANCH_PATTERN.to_string(),
+            issue_number_group: DEFAULT_ISSUE_NUMBER_GROUP,
+            excluded_branches: vec!["main".to_string(), "master".to_string()],
+            rules: vec![
+                WorkflowEventRule::new(workflow_events::PUSH, IssueStatus::InProgress),
+                WorkflowEventRule::new(workflow_events::PULL_REQUEST_OPENED, IssueStatus::InReview),
+                WorkflowEventRule::new(workflow_events::PULL_REQUEST_MERGED, IssueStatus::Done),
+                WorkflowEventRule::new(
+                    workflow_events::PULL_REQUEST_CLOSED,
+                    IssueStatus::InProgress,
+                ),
+            ],
+            close_on_merge: true,
+            updated_at: None,
+        }
+    }
+}
+
+impl WorkflowSettings {
+    /// The rule for `event`, if it does anything.
+    pub fn active_rule(&self, event: &str) -> Option<&WorkflowEventRule> {
+        self.rules
+            .iter()
+            .find(|r| r.event == event)
+            .filter(|r| r.is_active())
+    }
+
+    /// Trim the free-text fields and drop empty entries.
+    pub fn normalized(mut self) -> Self {
+        self.branch_pattern = self.branch_pattern.trim().to_string();
+        self.excluded_branches = clean_list(self.excluded_branches);
+        for rule in &mut self.rules {
+            rule.labels = clean_list(std::mem::take(&mut rule.labels));
+        }
+        self
+    }
+
+    /// Reject settings that would render a broken workflow.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.branch_pattern.is_empty() {
+            return Err("ブランチ名のパターンを入力してください".to_string());
+        }
+        let groups = count_capture_groups(&self.branch_pattern)?;
+        if self.issue_number_group < 1 || self.issue_number_group as usize > groups {
+            return Err(format!(
+                "Issue 番号のグループ ({}) がパターンのグループ数 ({}) の範囲外です",
+                self.issue_number_group, groups
+            ));
+        }
+
+        let mut seen = Vec::new();
+        for rule in &self.rules {
+            if !workflow_events::ALL.contains(&rule.event.as_str()) {
+                return Err(format!("不明なイベントです: {}", rule.event));
+            }
+            if seen.contains(&rule.event.as_str()) {
+                return Err(format!("イベントが重複しています: {}", rule.event));
+            }
+            seen.push(rule.event.as_str());
+        }
+        if !self.rules.iter().any(WorkflowEventRule::is_active) {
+            return Err(
+                "少なくとも 1 つのイベントでステータスかラベルを設定してください".to_string(),
+            );
+        }
+
+        let texts = std::iter::once(&self.branch_pattern)
+            .chain(&self.excluded_branches)
+            .chain(self.rules.iter().flat_map(|r| &r.labels));
+        for text in texts {
+            if text.contains('\n') || text.contains('\r') {
+                return Err("改行は使用できません".to_string());
+            }
+            // Would be evaluated as a GitHub Actions expression
+            if text.contains("${{") {
+                return Err(format!("`${{{{` は使用できません: {}", text));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn clean_list(items: Vec<String>) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for item in items {
+        let item = item.trim().to_string();
+        if !item.is_empty() && !out.contains(&item) {
+            out.push(item);
+        }
+    }
+    out
+}
+
+/// Number of capture groups in a POSIX ERE, checking the parentheses
+/// balance. Bash has no `(?...)` groups, so those are rejected.
+pub fn count_capture_groups(pattern: &str) -> Result<usize, String> {
+    let mut groups = 0;
+    let mut depth = 0i32;
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' => {
+                // `]` right after `[` or `[^` is a literal member
+                if chars.peek() == Some(&'^') {
+                    chars.next();
+                }
+                if chars.peek() == Some(&']') {
+                    chars.next();
+                }
+                if !chars.by_ref().any(|c| c == ']') {
+                    return Err("パターンの [ が閉じていません".to_string());
+                }
+            }
+            '(' => {
+                if chars.peek() == Some(&'?') {
+                    return Err("bash の正規表現では (?...) は使用できません".to_string());
+                }
+                groups += 1;
+                depth += 1;
+            }
+            ')' => {
+                depth -= 1;
+                if depth < 0 {
+                    return Err("パターンの ) に対応する ( がありません".to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err("パターンの ( が閉じていません".to_string());
+    }
+    Ok(groups)
+}
+
+/// Drift check states
+pub mod workflow_drift_states {
+    /// No workflow file in the repository.
+    pub const MISSING: &str = "missing";
+    pub const IN_SYNC: &str = "in_sync";
+    pub const DRIFTED: &str = "drifted";
+}
+
+/// One line of the expected-vs-committed comparison.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowDiffLine {
+    /// `same` / `added` (only in the expected output) / `removed` (only in
+    /// the committed file)
+    pub kind: String,
+    pub text: String,
+}
+
+/// Result of `check_workflow_drift`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowDrift {
+    pub repo_full_name: String,
+    pub path: String,
+    /// One of [`workflow_drift_states`].
+    pub state: String,
+    pub expected: String,
+    /// `None` when the file is missing.
+    pub actual: Option<String>,
+    /// Line diff; empty unless drifted.
+    pub diff: Vec<WorkflowDiffLine>,
+}
+
+/// Lines compared by the drift check: trailing whitespace and trailing
+/// blank lines don't count as drift.
+fn comparable_lines(text: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Compare the expected workflow with the committed one. Returns `None`
+/// when they match, otherwise a line diff (longest common subsequence).
+pub fn diff_workflow(expected: &str, actual: &str) -> Option<Vec<WorkflowDiffLine>> {
+    let a = comparable_lines(actual);
+    let b = comparable_lines(expected);
+    if a == b {
+        return None;
+    }
+
+    // lcs[i][j] = LCS length of a[i..] and b[j..]
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let line = |kind: &str, text: &str| WorkflowDiffLine {
+        kind: kind.to_string(),
+        text: text.to_string(),
+    };
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            diff.push(line("same", a[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(line("removed", a[i]));
+            i += 1;
+        } else {
+            diff.push(line("added", b[j]));
+            j += 1;
+        }
+    }
+    diff.extend(a[i..].iter().map(|l| line("removed", l)));
+    diff.extend(b[j..].iter().map(|l| line("added", l)));
+    Some(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_settings_are_valid() {
+        let settings = WorkflowSettings::default();
+        assert!(settings.validate().is_ok());
+        assert_eq!(count_capture_groups(DEFAULT_BRANCH_PATTERN), Ok(2));
+        assert_eq!(
+            settings
+                .active_rule(workflow_events::PUSH)
+                .map(|r| r.labels_to_add()),
+            Some(vec!["status:in-progress".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_count_capture_groups() {
+        assert_eq!(count_capture_groups(r"^([0-9]+)-\(x\)$"), Ok(1));
+        assert_eq!(count_capture_groups(r"^[()]+/([0-9]+)$"), Ok(1));
+        assert_eq!(count_capture_groups(r"^[]a]+(b)$"), Ok(1));
+        assert!(count_capture_groups(r"^(feat/([0-9]+)").is_err());
+        assert!(count_capture_groups(r"^feat)/").is_err());
+        assert!(count_capture_groups(r"^(?:feat)/([0-9]+)").is_err());
+        assert!(count_capture_groups(r"^[abc").is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_settings() {
+        let mut settings = WorkflowSettings {
+            issue_number_group: 3,
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        settings.issue_number_group = 2;
+        settings.rules[0].labels = vec!["${{ secrets.TOKEN }}".to_string()];
+        assert!(settings.validate().is_err());
+
+        settings.rules = vec![WorkflowEventRule {
+            event: workflow_events::PUSH.to_string(),
+            status: None,
+            labels: Vec::new(),
+        }];
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_normalized_trims_and_dedups() {
+        let mut settings = WorkflowSettings::default();
+        settings.branch_pattern = "  ^([0-9]+)$ ".to_string();
+        settings.excluded_branches = vec![" main".into(), "".into(), "main".into()];
+        settings.rules[0].labels = vec!["wip ".into(), " ".into()];
+        let settings = settings.normalized();
+        assert_eq!(settings.branch_pattern, "^([0-9]+)$");
+        assert_eq!(settings.excluded_branches, vec!["main".to_string()]);
+        assert_eq!(settings.rules[0].labels, vec!["wip".to_string()]);
+    }
+
+    #[test]
+    fn test_diff_workflow() {
+        assert_eq!(diff_workflow("a\nb\n", "a  \nb\n\n"), None);
+
+        let diff = diff_workflow("a\nb\nc\n", "a\nx\nc\n").unwrap();
+        let kinds: Vec<_> = diff
+            .iter()
+            .map(|l| (l.kind.as_str(), l.text.as_str()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("same", "a"),
+                ("removed", "x"),
+                ("added", "b"),
+                ("same", "c")
+            ]
+        );
+    }
+}
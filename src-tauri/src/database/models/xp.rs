@@ -4,6 +4,8 @@
 //! SQLiteのINTEGER型が符号あり整数であり、sqlxがi32としてマッピングするため、
 //! DB層との整合性を保つためにi32で統一しています。
 
+use std::collections::{HashMap, HashSet};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -82,7 +84,10 @@ pub enum XpActionType {
     Issue,
     IssueClosed,
     StreakBonus,
+    PrestigeBonus,
     Star,
+    TimeTracked,
+    XpAdjusted,
 }
 
 impl XpActionType {
@@ -95,7 +100,10 @@ impl XpActionType {
             XpActionType::Issue => "issue",
             XpActionType::IssueClosed => "issue_closed",
             XpActionType::StreakBonus => "streak_bonus",
+            XpActionType::PrestigeBonus => "prestige_bonus",
             XpActionType::Star => "star",
+            XpActionType::TimeTracked => "time_tracked",
+            XpActionType::XpAdjusted => "xp_adjusted",
         }
     }
 }
@@ -135,6 +143,22 @@ pub const DAILY_LOGIN_XP: i32 = 5;
 /// Maximum streak days that contribute to `XpBreakdown::calculate` のストリークボーナス。
 /// `min(streak, STREAK_BONUS_CAP_DAYS)` 日まで反映され、上限到達時は base_total の +10% となる。
 pub const STREAK_BONUS_CAP_DAYS: i32 = 10;
+/// XP for every full `TIME_TRACKED_XP_INTERVAL_MINUTES` tracked on issues
+pub const TIME_TRACKED_XP: i32 = 5;
+/// Tracked minutes per `TIME_TRACKED_XP` step
+pub const TIME_TRACKED_XP_INTERVAL_MINUTES: i64 = 30;
+/// 1 日（UTC）のうち XP 対象になるタイムトラッキングの上限（分）。
+/// タイマーの付けっぱなしで XP が膨らまないよう、8 時間で頭打ちにする。
+pub const TIME_TRACKED_DAILY_CAP_MINUTES: i64 = 480;
+
+/// 1 日に計測した合計時間（分）に対する時間ベース XP の総額。
+///
+/// タイマー停止ごとに「停止後の当日総額 − 当日すでに付与した XP」を付与する
+/// ため、細切れのエントリでも 1 本の長いエントリでも同じ XP になる。
+pub fn time_tracked_xp(minutes_today: i64) -> i32 {
+    let capped = minutes_today.clamp(0, TIME_TRACKED_DAILY_CAP_MINUTES);
+    (capped / TIME_TRACKED_XP_INTERVAL_MINUTES) as i32 * TIME_TRACKED_XP
+}
 
 /// XP breakdown for sync result
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -237,11 +261,198 @@ impl XpBreakdown {
     }
 }
 
+impl XpBreakdown {
+    /// コミット XP を `reduction` だけ減らした内訳を返す。
+    ///
+    /// 減額はコミット XP を上限とし、ストリークボーナスと合計は減額後の
+    /// base_total から `calculate` と同じ式で再計算する。
+    pub fn with_commit_reduction(&self, reduction: i32, streak: i32) -> Self {
+        let commits_xp = self.commits_xp - reduction.clamp(0, self.commits_xp);
+        let base_total = [
+            commits_xp,
+            self.prs_created_xp,
+            self.prs_merged_xp,
+            self.issues_created_xp,
+            self.issues_closed_xp,
+            self.reviews_xp,
+            self.stars_xp,
+        ]
+        .iter()
+        .fold(0i32, |acc, xp| acc.saturating_add(*xp));
+        let capped_streak_days = streak.clamp(0, STREAK_BONUS_CAP_DAYS) as i64;
+        let streak_bonus_xp = (base_total as i64 * capped_streak_days / 100) as i32;
+
+        Self {
+            commits_xp,
+            streak_bonus_xp,
+            total_xp: base_total.saturating_add(streak_bonus_xp),
+            ..self.clone()
+        }
+    }
+}
+
+// =============================================================================
+// XP セーフガード
+//
+// マイクロコミットやボットのコミットで XP が膨らまないよう、同期で付与する
+// コミット XP をユーザー設定に応じて減額する。減額分は `xp_adjusted` 行として
+// `xp_history` に記録され、理由（件数）を後から確認できる。
+// =============================================================================
+
+/// Commit XP weight (%) for commits beyond the daily cap, up to twice the cap
+pub const OVER_CAP_COMMIT_XP_PERCENT: i32 = 50;
+/// Commit XP weight (%) for commits beyond twice the daily cap
+pub const FAR_OVER_CAP_COMMIT_XP_PERCENT: i32 = 25;
+
+/// User-configurable safeguards applied to commit XP
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct XpSafeguards {
+    /// Commits per day at full XP; later commits earn diminishing XP (0 = off)
+    pub daily_commit_cap: i32,
+    /// Minimum average changed lines per commit for a day's commits to earn
+    /// XP, based on `daily_code_stats` (0 = off)
+    pub min_commit_lines: i32,
+    /// Give no XP for commits authored or committed by bots / GitHub Actions
+    pub exclude_bot_commits: bool,
+    /// Repositories ("owner/name", case-insensitive) whose commits earn no XP
+    pub excluded_repositories: Vec<String>,
+}
+
+impl XpSafeguards {
+    /// Whether any safeguard is switched on
+    pub fn is_active(&self) -> bool {
+        self.daily_commit_cap > 0
+            || self.min_commit_lines > 0
+            || self.exclude_bot_commits
+            || !self.excluded_repositories.is_empty()
+    }
+}
+
+/// A commit seen by the safeguard check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SafeguardCommit {
+    /// Commit date (YYYY-MM-DD, UTC)
+    pub date: String,
+    /// Repository "owner/name"
+    pub repository: String,
+    /// Authored or committed by a bot / GitHub Actions
+    pub is_bot: bool,
+    /// Committed since the previous sync, i.e. paid out by this sync.
+    /// Older commits of the same day only count towards the daily cap.
+    pub is_new: bool,
+}
+
+/// Commit XP removed by the safeguards in one sync
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitAdjustment {
+    pub bot_commits: i32,
+    pub excluded_repo_commits: i32,
+    pub small_diff_commits: i32,
+    pub over_cap_commits: i32,
+    pub xp_reduction: i32,
+}
+
+impl CommitAdjustment {
+    /// Human readable reason recorded with the `xp_adjusted` history entry
+    pub fn description(&self) -> String {
+        let mut reasons = Vec::new();
+        if self.bot_commits > 0 {
+            reasons.push(format!("ボット {}件", self.bot_commits));
+        }
+        if self.excluded_repo_commits > 0 {
+            reasons.push(format!("除外リポジトリ {}件", self.excluded_repo_commits));
+        }
+        if self.small_diff_commits > 0 {
+            reasons.push(format!("小さな差分 {}件", self.small_diff_commits));
+        }
+        if self.over_cap_commits > 0 {
+            reasons.push(format!("1日上限超過 {}件", self.over_cap_commits));
+        }
+        format!("XP調整: {}", reasons.join(" / "))
+    }
+}
+
+/// Whether a day's commits are too small on average to earn XP
+pub fn is_small_diff_day(additions: i32, deletions: i32, commits: i32, min_lines: i32) -> bool {
+    if min_lines <= 0 || commits <= 0 {
+        return false;
+    }
+    let lines = additions.max(0) as i64 + deletions.max(0) as i64;
+    lines < min_lines as i64 * commits as i64
+}
+
+/// Commit XP weight (%) of the `rank`-th (1-based) commit of a day
+fn daily_commit_weight(rank: i32, daily_cap: i32) -> i32 {
+    if daily_cap <= 0 || rank <= daily_cap {
+        100
+    } else if rank <= daily_cap.saturating_mul(2) {
+        OVER_CAP_COMMIT_XP_PERCENT
+    } else {
+        FAR_OVER_CAP_COMMIT_XP_PERCENT
+    }
+}
+
+/// Work out how much commit XP the safeguards remove from a sync.
+///
+/// `commits` must be in commit order. Each new commit is checked in turn
+/// against the excluded repositories, the bot filter and `small_diff_days`
+/// (dates failing `is_small_diff_day`); the first matching rule removes its
+/// full `COMMIT_XP`. Remaining commits are ranked per day, and those past
+/// `daily_commit_cap` keep only part of their XP.
+pub fn calculate_commit_adjustment(
+    commits: &[SafeguardCommit],
+    small_diff_days: &HashSet<String>,
+    safeguards: &XpSafeguards,
+) -> CommitAdjustment {
+    let excluded: HashSet<String> = safeguards
+        .excluded_repositories
+        .iter()
+        .map(|r| r.trim().to_lowercase())
+        .collect();
+    let mut adjustment = CommitAdjustment::default();
+    let mut daily_rank: HashMap<&str, i32> = HashMap::new();
+
+    for commit in commits {
+        if excluded.contains(&commit.repository.to_lowercase()) {
+            if commit.is_new {
+                adjustment.excluded_repo_commits += 1;
+                adjustment.xp_reduction += COMMIT_XP;
+            }
+            continue;
+        }
+        if safeguards.exclude_bot_commits && commit.is_bot {
+            if commit.is_new {
+                adjustment.bot_commits += 1;
+                adjustment.xp_reduction += COMMIT_XP;
+            }
+            continue;
+        }
+        if small_diff_days.contains(&commit.date) {
+            if commit.is_new {
+                adjustment.small_diff_commits += 1;
+                adjustment.xp_reduction += COMMIT_XP;
+            }
+            continue;
+        }
+
+        let rank = daily_rank.entry(commit.date.as_str()).or_insert(0);
+        *rank += 1;
+        let weight = daily_commit_weight(*rank, safeguards.daily_commit_cap);
+        if commit.is_new && weight < 100 {
+            adjustment.over_cap_commits += 1;
+            adjustment.xp_reduction += COMMIT_XP * (100 - weight) / 100;
+        }
+    }
+
+    adjustment
+}
+
 /// XP values module (for backward compatibility)
 pub mod xp {
     pub use super::{
-        XpActionType, XpBreakdown, COMMIT_XP, DAILY_LOGIN_XP, ISSUE_CLOSED_XP, ISSUE_XP,
-        PR_MERGED_XP, PR_XP, REVIEW_XP, STAR_XP, STREAK_BONUS_CAP_DAYS,
+        calculate_commit_adjustment, is_small_diff_day, CommitAdjustment, SafeguardCommit,
+        XpActionType, XpBreakdown, XpSafeguards, COMMIT_XP, DAILY_LOGIN_XP, ISSUE_CLOSED_XP,
+        ISSUE_XP, PR_MERGED_XP, PR_XP, REVIEW_XP, STAR_XP, STREAK_BONUS_CAP_DAYS, TIME_TRACKED_XP,
     };
 }
 
@@ -263,6 +474,17 @@ mod tests {
         assert_eq!(DAILY_LOGIN_XP, 5);
     }
 
+    #[test]
+    fn test_time_tracked_xp_steps_and_daily_cap() {
+        assert_eq!(time_tracked_xp(0), 0);
+        assert_eq!(time_tracked_xp(29), 0);
+        assert_eq!(time_tracked_xp(30), TIME_TRACKED_XP);
+        assert_eq!(time_tracked_xp(95), 3 * TIME_TRACKED_XP);
+        // 8 時間を超えた分は数えない
+        assert_eq!(time_tracked_xp(480), time_tracked_xp(24 * 60));
+        assert_eq!(time_tracked_xp(-10), 0);
+    }
+
     #[test]
     fn test_breakdown_zero_streak() {
         // 1 commit / 1 PR 作成 / 1 PR マージ / 1 Issue 作成 / 1 Issue 解決 / 1 レビュー / 1 スター
@@ -384,6 +606,133 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_with_commit_reduction_recomputes_streak_bonus() {
+        // base = 175, streak = 10 → bonus 17; 減額 10 → base 165, bonus 16
+        let bd = XpBreakdown::calculate(1, 1, 1, 1, 1, 1, 1, 10);
+        let adjusted = bd.with_commit_reduction(10, 10);
+        assert_eq!(adjusted.commits_xp, 0);
+        assert_eq!(adjusted.streak_bonus_xp, 16);
+        assert_eq!(adjusted.total_xp, 181);
+        assert_eq!(adjusted.prs_created_xp, bd.prs_created_xp);
+
+        // コミット XP を超える減額はコミット XP で頭打ち
+        let capped = bd.with_commit_reduction(1000, 0);
+        assert_eq!(capped.commits_xp, 0);
+        assert_eq!(capped.total_xp, 165);
+    }
+
+    fn commit(date: &str, repository: &str, is_bot: bool, is_new: bool) -> SafeguardCommit {
+        SafeguardCommit {
+            date: date.to_string(),
+            repository: repository.to_string(),
+            is_bot,
+            is_new,
+        }
+    }
+
+    #[test]
+    fn test_is_small_diff_day() {
+        // 平均 5 行 / コミット
+        assert!(is_small_diff_day(8, 2, 2, 10));
+        assert!(!is_small_diff_day(15, 5, 2, 10));
+        // 無効化・コミットなし
+        assert!(!is_small_diff_day(0, 0, 5, 0));
+        assert!(!is_small_diff_day(0, 0, 0, 10));
+    }
+
+    #[test]
+    fn test_commit_adjustment_default_safeguards_change_nothing() {
+        let commits = vec![
+            commit("2026-10-01", "me/app", false, true),
+            commit("2026-10-01", "me/app", true, true),
+        ];
+        let adjustment =
+            calculate_commit_adjustment(&commits, &HashSet::new(), &XpSafeguards::default());
+        assert_eq!(adjustment, CommitAdjustment::default());
+    }
+
+    #[test]
+    fn test_commit_adjustment_bot_and_excluded_repositories() {
+        let safeguards = XpSafeguards {
+            exclude_bot_commits: true,
+            excluded_repositories: vec!["Me/Dotfiles".to_string()],
+            ..Default::default()
+        };
+        let commits = vec![
+            commit("2026-10-01", "me/app", false, true),
+            commit("2026-10-01", "me/app", true, true),
+            commit("2026-10-01", "me/dotfiles", false, true),
+            commit("2026-10-01", "me/dotfiles", true, true),
+        ];
+        let adjustment = calculate_commit_adjustment(&commits, &HashSet::new(), &safeguards);
+        // 除外リポジトリが優先される
+        assert_eq!(adjustment.excluded_repo_commits, 2);
+        assert_eq!(adjustment.bot_commits, 1);
+        assert_eq!(adjustment.xp_reduction, 3 * COMMIT_XP);
+    }
+
+    #[test]
+    fn test_commit_adjustment_small_diff_days() {
+        let small_days: HashSet<String> = ["2026-10-01".to_string()].into_iter().collect();
+        let commits = vec![
+            commit("2026-10-01", "me/app", false, true),
+            commit("2026-10-02", "me/app", false, true),
+        ];
+        let adjustment =
+            calculate_commit_adjustment(&commits, &small_days, &XpSafeguards::default());
+        assert_eq!(adjustment.small_diff_commits, 1);
+        assert_eq!(adjustment.xp_reduction, COMMIT_XP);
+    }
+
+    #[test]
+    fn test_commit_adjustment_daily_cap_diminishing_returns() {
+        let safeguards = XpSafeguards {
+            daily_commit_cap: 2,
+            ..Default::default()
+        };
+        let commits: Vec<_> = (0..6)
+            .map(|_| commit("2026-10-01", "me/app", false, true))
+            .collect();
+        let adjustment = calculate_commit_adjustment(&commits, &HashSet::new(), &safeguards);
+        // 1-2 件目: 100%, 3-4 件目: 50%, 5-6 件目: 25%
+        assert_eq!(adjustment.over_cap_commits, 4);
+        assert_eq!(adjustment.xp_reduction, 2 * 5 + 2 * 7);
+    }
+
+    #[test]
+    fn test_commit_adjustment_only_penalizes_new_commits() {
+        let safeguards = XpSafeguards {
+            daily_commit_cap: 2,
+            exclude_bot_commits: true,
+            ..Default::default()
+        };
+        // 前回の同期で支払い済みのコミットは順位にだけ数える
+        let commits = vec![
+            commit("2026-10-01", "me/app", false, false),
+            commit("2026-10-01", "me/app", false, false),
+            commit("2026-10-01", "me/app", true, false),
+            commit("2026-10-01", "me/app", false, true),
+        ];
+        let adjustment = calculate_commit_adjustment(&commits, &HashSet::new(), &safeguards);
+        assert_eq!(adjustment.bot_commits, 0);
+        assert_eq!(adjustment.over_cap_commits, 1);
+        assert_eq!(adjustment.xp_reduction, COMMIT_XP / 2);
+    }
+
+    #[test]
+    fn test_commit_adjustment_description() {
+        let adjustment = CommitAdjustment {
+            bot_commits: 2,
+            over_cap_commits: 3,
+            ..Default::default()
+        };
+        assert_eq!(
+            adjustment.description(),
+            "XP調整: ボット 2件 / 1日上限超過 3件"
+        );
+    }
+
     // Issue #189: 負のストリーク値が混入しても 0 にクランプされ、
     // streak_bonus_xp が負にならない。
     #[test]
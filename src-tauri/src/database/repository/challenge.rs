@@ -4,7 +4,7 @@ use chrono::{DateTime, Datelike, Utc};
 use sqlx::Row;
 
 use crate::database::connection::{Database, DatabaseError, DbResult};
-use crate::database::models::Challenge;
+use crate::database::models::{Challenge, ChallengeTokens, TokenSpend, MAX_CHALLENGE_TOKENS};
 
 /// Challenge repository operations
 impl Database {
@@ -52,12 +52,14 @@ impl Database {
         start_date: DateTime<Utc>,
         end_date: DateTime<Utc>,
         start_stats_json: &str,
+        target_reason: Option<&str>,
     ) -> DbResult<Challenge> {
         let id = sqlx::query(
             r#"
             INSERT INTO challenges (user_id, challenge_type, target_metric, target_value, 
-                                   current_value, reward_xp, start_date, end_date, status, start_stats_json)
-            VALUES (?, ?, ?, ?, 0, ?, ?, ?, 'active', ?)
+                                   current_value, reward_xp, start_date, end_date, status, start_stats_json,
+                                   target_reason)
+            VALUES (?, ?, ?, ?, 0, ?, ?, ?, 'active', ?, ?)
             "#,
         )
         .bind(user_id)
@@ -68,6 +70,7 @@ impl Database {
         .bind(start_date.to_rfc3339())
         .bind(end_date.to_rfc3339())
         .bind(start_stats_json)
+        .bind(target_reason)
         .execute(self.pool())
         .await
         .map_err(|e| DatabaseError::Query(e.to_string()))?
@@ -132,12 +135,80 @@ impl Database {
         Ok(result.and_then(|s| chrono::NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()))
     }
 
+    /// Get the most recent monthly challenge date for a user
+    pub async fn get_last_monthly_challenge_date(
+        &self,
+        user_id: i64,
+    ) -> DbResult<Option<chrono::NaiveDate>> {
+        let result: Option<String> = sqlx::query_scalar(
+            r#"
+            SELECT DATE(start_date) FROM challenges 
+            WHERE user_id = ? AND challenge_type = 'monthly'
+            ORDER BY start_date DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?
+        .flatten();
+
+        Ok(result.and_then(|s| chrono::NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()))
+    }
+
+    /// Get the most recent quarterly challenge date for a user
+    pub async fn get_last_quarterly_challenge_date(
+        &self,
+        user_id: i64,
+    ) -> DbResult<Option<chrono::NaiveDate>> {
+        let result: Option<String> = sqlx::query_scalar(
+            r#"
+            SELECT DATE(start_date) FROM challenges 
+            WHERE user_id = ? AND challenge_type = 'quarterly'
+            ORDER BY start_date DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?
+        .flatten();
+
+        Ok(result.and_then(|s| chrono::NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()))
+    }
+
+    /// Record the stats baseline of a challenge that was created without
+    /// one (manual challenges, or custom ones starting in the future).
+    /// Never overwrites an existing baseline.
+    pub async fn set_challenge_start_stats(
+        &self,
+        challenge_id: i64,
+        start_stats_json: &str,
+    ) -> DbResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE challenges SET start_stats_json = ?
+            WHERE id = ? AND start_stats_json IS NULL
+            "#,
+        )
+        .bind(start_stats_json)
+        .bind(challenge_id)
+        .execute(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        Ok(())
+    }
+
     /// Get challenge by ID
     pub async fn get_challenge_by_id(&self, id: i64) -> DbResult<Challenge> {
         let row = sqlx::query(
             r#"
             SELECT id, user_id, challenge_type, target_metric, target_value, 
-                   current_value, reward_xp, start_date, end_date, status, completed_at
+                   current_value, reward_xp, start_date, end_date, status, completed_at,
+                   target_reason
             FROM challenges
             WHERE id = ?
             "#,
@@ -169,6 +240,7 @@ impl Database {
                 .get::<Option<&str>, _>("completed_at")
                 .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
                 .map(|dt| dt.with_timezone(&Utc)),
+            target_reason: row.get("target_reason"),
         })
     }
 
@@ -177,7 +249,8 @@ impl Database {
         let rows = sqlx::query(
             r#"
             SELECT id, user_id, challenge_type, target_metric, target_value, 
-                   current_value, reward_xp, start_date, end_date, status, completed_at
+                   current_value, reward_xp, start_date, end_date, status, completed_at,
+                   target_reason
             FROM challenges
             WHERE user_id = ? AND status = 'active'
             ORDER BY end_date ASC
@@ -209,6 +282,7 @@ impl Database {
                     .get::<Option<&str>, _>("completed_at")
                     .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
                     .map(|dt| dt.with_timezone(&Utc)),
+                target_reason: row.get("target_reason"),
             })
             .collect();
 
@@ -224,7 +298,8 @@ impl Database {
         let rows = sqlx::query(
             r#"
             SELECT id, user_id, challenge_type, target_metric, target_value, 
-                   current_value, reward_xp, start_date, end_date, status, completed_at
+                   current_value, reward_xp, start_date, end_date, status, completed_at,
+                   target_reason
             FROM challenges
             WHERE user_id = ? AND challenge_type = ?
             ORDER BY start_date DESC
@@ -257,6 +332,7 @@ impl Database {
                     .get::<Option<&str>, _>("completed_at")
                     .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
                     .map(|dt| dt.with_timezone(&Utc)),
+                target_reason: row.get("target_reason"),
             })
             .collect();
 
@@ -268,7 +344,8 @@ impl Database {
         let rows = sqlx::query(
             r#"
             SELECT id, user_id, challenge_type, target_metric, target_value, 
-                   current_value, reward_xp, start_date, end_date, status, completed_at
+                   current_value, reward_xp, start_date, end_date, status, completed_at,
+                   target_reason
             FROM challenges
             WHERE user_id = ?
             ORDER BY start_date DESC
@@ -300,6 +377,7 @@ impl Database {
                     .get::<Option<&str>, _>("completed_at")
                     .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
                     .map(|dt| dt.with_timezone(&Utc)),
+                target_reason: row.get("target_reason"),
             })
             .collect();
 
@@ -396,7 +474,8 @@ impl Database {
         let rows = sqlx::query(
             r#"
             SELECT id, user_id, challenge_type, target_metric, target_value, 
-                   current_value, reward_xp, start_date, end_date, status, completed_at
+                   current_value, reward_xp, start_date, end_date, status, completed_at,
+                   target_reason
             FROM challenges
             WHERE user_id = ? AND status = 'active' AND end_date < ?
             "#,
@@ -425,6 +504,7 @@ impl Database {
                     .unwrap_or_else(|_| Utc::now()),
                 status: "failed".to_string(), // Will be updated
                 completed_at: None,
+                target_reason: row.get("target_reason"),
             })
             .collect();
 
@@ -467,6 +547,51 @@ impl Database {
         Ok(count > 0)
     }
 
+    /// Type, metric and outcome of the user's finished generated challenges,
+    /// newest first, for adaptive difficulty.
+    ///
+    /// Challenges past their end date that the sync hasn't failed yet count
+    /// as failed, so the period that just ended is included. Manually
+    /// created challenges (no `target_reason`) are left out: their targets
+    /// were picked by the user, not by the generator.
+    pub async fn get_recent_challenge_outcomes(
+        &self,
+        user_id: i64,
+        limit: i64,
+    ) -> DbResult<Vec<(String, String, String)>> {
+        let now = Utc::now().to_rfc3339();
+
+        let rows = sqlx::query(
+            r#"
+            SELECT challenge_type, target_metric,
+                   CASE WHEN status = 'active' THEN 'failed' ELSE status END AS status
+            FROM challenges
+            WHERE user_id = ?
+              AND target_reason IS NOT NULL
+              AND (status IN ('completed', 'failed') OR (status = 'active' AND end_date < ?))
+            ORDER BY end_date DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(user_id)
+        .bind(&now)
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                (
+                    row.get("challenge_type"),
+                    row.get("target_metric"),
+                    row.get("status"),
+                )
+            })
+            .collect())
+    }
+
     /// Get challenge completion count for badge tracking
     pub async fn get_challenge_completion_count(&self, user_id: i64) -> DbResult<i32> {
         let count: i32 = sqlx::query_scalar(
@@ -544,4 +669,190 @@ impl Database {
 
         Ok(consecutive)
     }
+
+    /// Make sure the user has a `challenge_tokens` row (with the starting
+    /// balance)
+    async fn ensure_challenge_tokens(&self, user_id: i64) -> DbResult<()> {
+        sqlx::query("INSERT OR IGNORE INTO challenge_tokens (user_id, updated_at) VALUES (?, ?)")
+            .bind(user_id)
+            .bind(Utc::now().to_rfc3339())
+            .execute(self.pool())
+            .await
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get the user's reroll / skip token balance
+    pub async fn get_challenge_tokens(&self, user_id: i64) -> DbResult<ChallengeTokens> {
+        self.ensure_challenge_tokens(user_id).await?;
+
+        let row = sqlx::query(
+            "SELECT reroll_tokens, skip_tokens FROM challenge_tokens WHERE user_id = ?",
+        )
+        .bind(user_id)
+        .fetch_one(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        Ok(ChallengeTokens {
+            reroll_tokens: row.get("reroll_tokens"),
+            skip_tokens: row.get("skip_tokens"),
+        })
+    }
+
+    /// Add reroll / skip tokens, capped at `MAX_CHALLENGE_TOKENS` each
+    pub async fn grant_challenge_tokens(
+        &self,
+        user_id: i64,
+        reroll_tokens: i32,
+        skip_tokens: i32,
+    ) -> DbResult<ChallengeTokens> {
+        self.ensure_challenge_tokens(user_id).await?;
+
+        sqlx::query(
+            r#"
+            UPDATE challenge_tokens
+            SET reroll_tokens = MIN(reroll_tokens + ?, ?),
+                skip_tokens = MIN(skip_tokens + ?, ?),
+                updated_at = ?
+            WHERE user_id = ?
+            "#,
+        )
+        .bind(reroll_tokens)
+        .bind(MAX_CHALLENGE_TOKENS)
+        .bind(skip_tokens)
+        .bind(MAX_CHALLENGE_TOKENS)
+        .bind(Utc::now().to_rfc3339())
+        .bind(user_id)
+        .execute(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        self.get_challenge_tokens(user_id).await
+    }
+
+    /// Retire an active challenge as `skipped`, spending a skip token.
+    ///
+    /// Changes nothing unless the user has a skip token left and the
+    /// challenge is still active; the result tells which one was missing.
+    pub async fn skip_challenge_with_token(
+        &self,
+        user_id: i64,
+        challenge_id: i64,
+    ) -> DbResult<TokenSpend> {
+        self.ensure_challenge_tokens(user_id).await?;
+
+        let mut tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        let spent = sqlx::query(
+            r#"
+            UPDATE challenge_tokens SET skip_tokens = skip_tokens - 1, updated_at = ?
+            WHERE user_id = ? AND skip_tokens > 0
+            "#,
+        )
+        .bind(Utc::now().to_rfc3339())
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?
+        .rows_affected();
+        if spent == 0 {
+            return Ok(TokenSpend::NoToken);
+        }
+
+        let retired = sqlx::query(
+            r#"
+            UPDATE challenges SET status = 'skipped'
+            WHERE id = ? AND user_id = ? AND status = 'active'
+            "#,
+        )
+        .bind(challenge_id)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?
+        .rows_affected();
+        if retired == 0 {
+            // Dropping the transaction rolls the token back
+            return Ok(TokenSpend::ChallengeInactive);
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
+        Ok(TokenSpend::Spent)
+    }
+
+    /// Swap an active challenge's goal for another, spending a reroll
+    /// token. The period and start stats are kept, so activity earlier in
+    /// the period still counts; progress is recalculated on the next sync.
+    ///
+    /// Changes nothing unless the user has a reroll token left and the
+    /// challenge is still active; the result tells which one was missing.
+    pub async fn reroll_challenge_with_token(
+        &self,
+        user_id: i64,
+        challenge_id: i64,
+        target_metric: &str,
+        target_value: i32,
+        reward_xp: i32,
+        target_reason: Option<&str>,
+    ) -> DbResult<TokenSpend> {
+        self.ensure_challenge_tokens(user_id).await?;
+
+        let mut tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        let spent = sqlx::query(
+            r#"
+            UPDATE challenge_tokens SET reroll_tokens = reroll_tokens - 1, updated_at = ?
+            WHERE user_id = ? AND reroll_tokens > 0
+            "#,
+        )
+        .bind(Utc::now().to_rfc3339())
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?
+        .rows_affected();
+        if spent == 0 {
+            return Ok(TokenSpend::NoToken);
+        }
+
+        let replaced = sqlx::query(
+            r#"
+            UPDATE challenges
+            SET target_metric = ?, target_value = ?, reward_xp = ?, current_value = 0,
+                target_reason = ?
+            WHERE id = ? AND user_id = ? AND status = 'active'
+            "#,
+        )
+        .bind(target_metric)
+        .bind(target_value)
+        .bind(reward_xp)
+        .bind(target_reason)
+        .bind(challenge_id)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?
+        .rows_affected();
+        if replaced == 0 {
+            // Dropping the transaction rolls the token back
+            return Ok(TokenSpend::ChallengeInactive);
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
+        Ok(TokenSpend::Spent)
+    }
 }
@@ -7,7 +7,8 @@ use sqlx::Row;
 
 use crate::database::connection::{Database, DbResult};
 use crate::database::models::code_stats::{
-    CodeStatsResponse, CodeStatsSummary, DailyCodeStats, StatsPeriod, SyncMetadata,
+    CodeStatsResponse, CodeStatsSummary, DailyCodeStats, RepositoryCodeStats, StatsPeriod,
+    SyncMetadata,
 };
 
 impl Database {
@@ -135,6 +136,88 @@ impl Database {
             .collect())
     }
 
+    /// Replace the per-repository split of one day with `repositories`.
+    ///
+    /// The sync re-reads whole days, so repositories no longer present
+    /// that day (filtered out, history rewritten) are dropped.
+    pub async fn replace_daily_repository_code_stats(
+        &self,
+        user_id: i64,
+        date: NaiveDate,
+        repositories: &[RepositoryCodeStats],
+    ) -> DbResult<()> {
+        let date_str = date.format("%Y-%m-%d").to_string();
+        let now = Utc::now().to_rfc3339();
+        let query_error =
+            |e: sqlx::Error| crate::database::connection::DatabaseError::Query(e.to_string());
+
+        let mut tx = self.pool().begin().await.map_err(query_error)?;
+        sqlx::query("DELETE FROM daily_repository_code_stats WHERE user_id = ? AND date = ?")
+            .bind(user_id)
+            .bind(&date_str)
+            .execute(&mut *tx)
+            .await
+            .map_err(query_error)?;
+        for stats in repositories {
+            sqlx::query(
+                r#"
+                INSERT INTO daily_repository_code_stats
+                    (user_id, date, repo_full_name, additions, deletions, commits_count, updated_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(user_id)
+            .bind(&date_str)
+            .bind(&stats.repository)
+            .bind(stats.additions)
+            .bind(stats.deletions)
+            .bind(stats.commits_count)
+            .bind(&now)
+            .execute(&mut *tx)
+            .await
+            .map_err(query_error)?;
+        }
+        tx.commit().await.map_err(query_error)
+    }
+
+    /// One repository's daily code statistics for a date range, oldest
+    /// first. `repository` is matched case-insensitively.
+    pub async fn get_repository_code_stats_range(
+        &self,
+        user_id: i64,
+        repository: &str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> DbResult<Vec<RepositoryCodeStats>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT date, repo_full_name, additions, deletions, commits_count
+            FROM daily_repository_code_stats
+            WHERE user_id = ? AND repo_full_name = ? COLLATE NOCASE
+              AND date >= ? AND date <= ?
+            ORDER BY date
+            "#,
+        )
+        .bind(user_id)
+        .bind(repository)
+        .bind(start_date.format("%Y-%m-%d").to_string())
+        .bind(end_date.format("%Y-%m-%d").to_string())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| crate::database::connection::DatabaseError::Query(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| RepositoryCodeStats {
+                date: r.get("date"),
+                repository: r.get("repo_full_name"),
+                additions: r.get("additions"),
+                deletions: r.get("deletions"),
+                commits_count: r.get("commits_count"),
+            })
+            .collect())
+    }
+
     /// Get code statistics response with summaries for a period
     pub async fn get_code_stats_response(
         &self,
@@ -0,0 +1,150 @@
+//! Contribution history repository operations
+//!
+//! Persisted contribution calendar days and backfilled years.
+
+use chrono::{NaiveDate, Utc};
+
+use crate::database::connection::{Database, DatabaseError, DbResult};
+use crate::database::models::contribution_history::{
+    ContributionDayCount, ContributionYearSummary,
+};
+
+impl Database {
+    /// Save or update contribution counts, returning the number of days written
+    pub async fn upsert_contribution_days(
+        &self,
+        user_id: i64,
+        days: &[ContributionDayCount],
+    ) -> DbResult<usize> {
+        if days.is_empty() {
+            return Ok(0);
+        }
+        let now = Utc::now().to_rfc3339();
+
+        let mut tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
+        for day in days {
+            sqlx::query(
+                r#"
+                INSERT INTO contribution_days (user_id, date, contribution_count, updated_at)
+                VALUES (?, ?, ?, ?)
+                ON CONFLICT(user_id, date) DO UPDATE SET
+                    contribution_count = excluded.contribution_count,
+                    updated_at = excluded.updated_at
+                "#,
+            )
+            .bind(user_id)
+            .bind(day.date.format("%Y-%m-%d").to_string())
+            .bind(day.count)
+            .bind(&now)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        Ok(days.len())
+    }
+
+    /// Stored contribution days in `[start, end]`, oldest first
+    pub async fn get_contribution_days_range(
+        &self,
+        user_id: i64,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> DbResult<Vec<ContributionDayCount>> {
+        let rows: Vec<(String, i32)> = sqlx::query_as(
+            r#"
+            SELECT date, contribution_count
+            FROM contribution_days
+            WHERE user_id = ? AND date >= ? AND date <= ?
+            ORDER BY date
+            "#,
+        )
+        .bind(user_id)
+        .bind(start.format("%Y-%m-%d").to_string())
+        .bind(end.format("%Y-%m-%d").to_string())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(date, count)| {
+                NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+                    .ok()
+                    .map(|date| ContributionDayCount { date, count })
+            })
+            .collect())
+    }
+
+    /// Contribution totals per stored year, oldest first.
+    ///
+    /// `backfilled` is always `false` here; see
+    /// [`Self::get_backfilled_contribution_years`].
+    pub async fn get_contribution_year_totals(
+        &self,
+        user_id: i64,
+    ) -> DbResult<Vec<ContributionYearSummary>> {
+        let rows: Vec<(i32, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT CAST(substr(date, 1, 4) AS INTEGER) AS year,
+                   COALESCE(SUM(contribution_count), 0),
+                   SUM(CASE WHEN contribution_count > 0 THEN 1 ELSE 0 END)
+            FROM contribution_days
+            WHERE user_id = ?
+            GROUP BY year
+            ORDER BY year
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(year, total_contributions, active_days)| ContributionYearSummary {
+                    year,
+                    total_contributions,
+                    active_days,
+                    backfilled: false,
+                },
+            )
+            .collect())
+    }
+
+    /// Record that `year` was fetched in full by the backfill
+    pub async fn mark_contribution_year_backfilled(&self, user_id: i64, year: i32) -> DbResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO contribution_years (user_id, year, backfilled_at)
+            VALUES (?, ?, ?)
+            ON CONFLICT(user_id, year) DO UPDATE SET backfilled_at = excluded.backfilled_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(year)
+        .bind(Utc::now().to_rfc3339())
+        .execute(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Years fetched in full by the backfill, oldest first
+    pub async fn get_backfilled_contribution_years(&self, user_id: i64) -> DbResult<Vec<i32>> {
+        sqlx::query_scalar("SELECT year FROM contribution_years WHERE user_id = ? ORDER BY year")
+            .bind(user_id)
+            .fetch_all(self.pool())
+            .await
+            .map_err(|e| DatabaseError::Query(e.to_string()))
+    }
+}
@@ -29,9 +29,9 @@ impl Database {
             INSERT INTO github_stats_snapshots (
                 user_id, total_commits, total_prs, total_prs_merged, total_reviews,
                 total_issues, total_issues_closed, total_stars_received,
-                total_contributions, snapshot_date
+                total_contributions, snapshot_date, search_fallback, filter_rebaseline
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             ON CONFLICT(user_id, snapshot_date) DO UPDATE SET
                 total_commits = excluded.total_commits,
                 total_prs = excluded.total_prs,
@@ -40,7 +40,11 @@ impl Database {
                 total_issues = excluded.total_issues,
                 total_issues_closed = excluded.total_issues_closed,
                 total_stars_received = excluded.total_stars_received,
-                total_contributions = excluded.total_contributions
+                total_contributions = excluded.total_contributions,
+                search_fallback = excluded.search_fallback,
+                -- A rebaseline earlier in the day still separates the row
+                -- from the days before it
+                filter_rebaseline = MAX(filter_rebaseline, excluded.filter_rebaseline)
             "#,
         )
         .bind(snapshot.user_id)
@@ -53,6 +57,8 @@ impl Database {
         .bind(snapshot.total_stars_received)
         .bind(snapshot.total_contributions)
         .bind(&snapshot.snapshot_date)
+        .bind(snapshot.search_fallback)
+        .bind(snapshot.filter_rebaseline)
         .execute(self.pool())
         .await?;
 
@@ -73,7 +79,7 @@ impl Database {
             SELECT id, user_id, total_commits, total_prs, total_prs_merged,
                    total_reviews, total_issues, total_issues_closed,
                    total_stars_received, total_contributions,
-                   snapshot_date, created_at
+                   snapshot_date, created_at, search_fallback, filter_rebaseline
             FROM github_stats_snapshots
             WHERE user_id = ? AND snapshot_date < ?
             ORDER BY snapshot_date DESC
@@ -88,6 +94,65 @@ impl Database {
         Ok(row.map(map_snapshot_row))
     }
 
+    /// Get the most recent snapshot on or before a given date
+    ///
+    /// Used by period-over-period comparisons: the cumulative
+    /// totals at the end of a period are the latest snapshot taken on or
+    /// before its last day. Returns None if no such snapshot exists.
+    pub async fn get_github_stats_snapshot_on_or_before(
+        &self,
+        user_id: i64,
+        date: &str,
+    ) -> DbResult<Option<GitHubStatsSnapshot>> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, user_id, total_commits, total_prs, total_prs_merged,
+                   total_reviews, total_issues, total_issues_closed,
+                   total_stars_received, total_contributions,
+                   snapshot_date, created_at, search_fallback, filter_rebaseline
+            FROM github_stats_snapshots
+            WHERE user_id = ? AND snapshot_date <= ?
+            ORDER BY snapshot_date DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(user_id)
+        .bind(date)
+        .fetch_optional(self.pool())
+        .await?;
+
+        Ok(row.map(map_snapshot_row))
+    }
+
+    /// Whether a snapshot after `after_date` and on or before `until_date`
+    /// was written by the first sync after a repository filter change.
+    ///
+    /// Used by period comparisons: totals on either side of such
+    /// a snapshot count different repositories and cannot be diffed.
+    pub async fn has_github_stats_rebaseline_between(
+        &self,
+        user_id: i64,
+        after_date: &str,
+        until_date: &str,
+    ) -> DbResult<bool> {
+        let found: Option<i64> = sqlx::query_scalar(
+            r#"
+            SELECT 1
+            FROM github_stats_snapshots
+            WHERE user_id = ? AND snapshot_date > ? AND snapshot_date <= ?
+              AND filter_rebaseline = 1
+            LIMIT 1
+            "#,
+        )
+        .bind(user_id)
+        .bind(after_date)
+        .bind(until_date)
+        .fetch_optional(self.pool())
+        .await?;
+
+        Ok(found.is_some())
+    }
+
     /// Get the most recent snapshot for a user, regardless of date.
     ///
     /// Used as the XP-diff base by `run_github_sync` (Issue #189). When the
@@ -103,7 +168,7 @@ impl Database {
             SELECT id, user_id, total_commits, total_prs, total_prs_merged,
                    total_reviews, total_issues, total_issues_closed,
                    total_stars_received, total_contributions,
-                   snapshot_date, created_at
+                   snapshot_date, created_at, search_fallback, filter_rebaseline
             FROM github_stats_snapshots
             WHERE user_id = ?
             ORDER BY snapshot_date DESC
@@ -130,7 +195,7 @@ impl Database {
             SELECT id, user_id, total_commits, total_prs, total_prs_merged,
                    total_reviews, total_issues, total_issues_closed,
                    total_stars_received, total_contributions,
-                   snapshot_date, created_at
+                   snapshot_date, created_at, search_fallback, filter_rebaseline
             FROM github_stats_snapshots
             WHERE user_id = ? AND snapshot_date = ?
             "#,
@@ -158,6 +223,8 @@ fn map_snapshot_row(r: sqlx::sqlite::SqliteRow) -> GitHubStatsSnapshot {
         total_contributions: r.get("total_contributions"),
         snapshot_date: r.get("snapshot_date"),
         created_at: r.get("created_at"),
+        search_fallback: r.get("search_fallback"),
+        filter_rebaseline: r.get("filter_rebaseline"),
     }
 }
 
@@ -438,4 +505,75 @@ mod tests {
         assert_eq!(user1_snapshot.total_commits, 100);
         assert_eq!(user2_snapshot.total_commits, 50);
     }
+
+    #[tokio::test]
+    async fn test_get_snapshot_on_or_before() {
+        let db = setup_test_db().await;
+
+        for (commits, date) in [(100, "2025-11-28"), (110, "2025-11-30")] {
+            let snap = create_snapshot(1, commits, 0, 0, 0, 0, commits, date);
+            db.save_github_stats_snapshot(&snap)
+                .await
+                .expect("Should save snapshot");
+        }
+
+        // Same-day snapshot is included
+        let on = db
+            .get_github_stats_snapshot_on_or_before(1, "2025-11-30")
+            .await
+            .expect("Should query")
+            .expect("Should have snapshot");
+        assert_eq!(on.total_commits, 110);
+
+        // Gap days fall back to the latest earlier snapshot
+        let gap = db
+            .get_github_stats_snapshot_on_or_before(1, "2025-11-29")
+            .await
+            .expect("Should query")
+            .expect("Should have snapshot");
+        assert_eq!(gap.snapshot_date, "2025-11-28");
+
+        let none = db
+            .get_github_stats_snapshot_on_or_before(1, "2025-11-27")
+            .await
+            .expect("Should query");
+        assert!(none.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reliability_flags_and_rebaseline_lookup() {
+        let db = setup_test_db().await;
+
+        let before = create_snapshot(1, 100, 0, 0, 0, 0, 100, "2025-11-28");
+        db.save_github_stats_snapshot(&before).await.unwrap();
+        let rebaselined =
+            create_snapshot(1, 40, 0, 0, 0, 0, 40, "2025-11-30").with_reliability(true, true);
+        db.save_github_stats_snapshot(&rebaselined).await.unwrap();
+
+        // A later sync the same day keeps the rebaseline mark but replaces
+        // the fallback state with its own
+        let later = create_snapshot(1, 41, 0, 0, 0, 0, 41, "2025-11-30");
+        db.save_github_stats_snapshot(&later).await.unwrap();
+        let stored = db
+            .get_github_stats_snapshot_for_date(1, "2025-11-30")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(stored.filter_rebaseline);
+        assert!(!stored.search_fallback);
+
+        assert!(db
+            .has_github_stats_rebaseline_between(1, "2025-11-28", "2025-11-30")
+            .await
+            .unwrap());
+        // The rebaselined row itself as the base is fine
+        assert!(!db
+            .has_github_stats_rebaseline_between(1, "2025-11-30", "2025-12-05")
+            .await
+            .unwrap());
+        assert!(!db
+            .has_github_stats_rebaseline_between(1, "2025-11-20", "2025-11-29")
+            .await
+            .unwrap());
+    }
 }
@@ -7,7 +7,9 @@ mod badge;
 mod cache;
 mod challenge;
 mod code_stats;
+mod contribution_history;
 mod github_stats_snapshot;
+mod season;
 mod settings;
 mod user;
 mod user_stats;
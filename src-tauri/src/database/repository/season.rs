@@ -0,0 +1,241 @@
+//! Season repository operations
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::FromRow;
+
+use crate::database::connection::{Database, DatabaseError, DbResult};
+use crate::database::models::{
+    get_all_badge_definitions, season_bounds, season_id_for, season_level, season_percentile,
+    top_season_badges, SeasonArchive,
+};
+
+/// Season history row from database
+#[derive(Debug, FromRow)]
+struct SeasonHistoryRow {
+    id: i64,
+    user_id: i64,
+    season_id: String,
+    starts_on: String,
+    ends_on: String,
+    season_xp: i32,
+    season_level: i32,
+    best_streak: i32,
+    top_badges_json: String,
+    percentile: Option<i32>,
+    archived_at: String,
+}
+
+impl TryFrom<SeasonHistoryRow> for SeasonArchive {
+    type Error = DatabaseError;
+
+    fn try_from(row: SeasonHistoryRow) -> Result<Self, Self::Error> {
+        let parse_date = |s: &str| {
+            NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                .map_err(|e| DatabaseError::Query(format!("Invalid season date '{}': {}", s, e)))
+        };
+
+        Ok(SeasonArchive {
+            id: row.id,
+            user_id: row.user_id,
+            starts_on: parse_date(&row.starts_on)?,
+            ends_on: parse_date(&row.ends_on)?,
+            season_id: row.season_id,
+            season_xp: row.season_xp,
+            season_level: row.season_level,
+            best_streak: row.best_streak,
+            top_badges: serde_json::from_str(&row.top_badges_json).unwrap_or_default(),
+            percentile: row.percentile,
+            archived_at: DateTime::parse_from_rfc3339(&row.archived_at)
+                .map(|dt| dt.with_timezone(&Utc))
+                .unwrap_or_else(|_| Utc::now()),
+        })
+    }
+}
+
+/// Season repository operations
+impl Database {
+    /// Move the user into the season containing `today`.
+    ///
+    /// When the stored season has ended, its summary is archived into
+    /// `season_history` and the season counters restart. Returns the
+    /// archive that was written, or `None` when the season is unchanged
+    /// (or another caller already rolled it over).
+    pub async fn roll_over_season(
+        &self,
+        user_id: i64,
+        today: NaiveDate,
+    ) -> DbResult<Option<SeasonArchive>> {
+        let current = self
+            .get_user_stats(user_id)
+            .await?
+            .ok_or_else(|| DatabaseError::Query("User stats not found".to_string()))?;
+
+        let season_id = season_id_for(today);
+        if current.season_id.as_deref() == Some(season_id.as_str()) {
+            return Ok(None);
+        }
+
+        let now = Utc::now().to_rfc3339();
+
+        // No season tracked yet (or an unreadable id): just start one
+        let Some((previous, (starts_on, ends_on))) = current
+            .season_id
+            .as_deref()
+            .and_then(|id| season_bounds(id).map(|bounds| (id, bounds)))
+        else {
+            sqlx::query(
+                r#"
+                UPDATE user_stats
+                SET season_id = ?, season_xp = 0, season_best_streak = current_streak,
+                    updated_at = ?
+                WHERE user_id = ? AND season_id IS ?
+                "#,
+            )
+            .bind(&season_id)
+            .bind(&now)
+            .bind(user_id)
+            .bind(current.season_id.as_deref())
+            .execute(self.pool())
+            .await
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
+            return Ok(None);
+        };
+
+        let past_season_xp: Vec<i32> = sqlx::query_scalar(
+            "SELECT season_xp FROM season_history WHERE user_id = ? AND season_id < ?",
+        )
+        .bind(user_id)
+        .bind(previous)
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        // earned_at is stored as "YYYY-MM-DD HH:MM:SS", so date strings
+        // compare correctly as range bounds
+        let earned_badge_ids: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT badge_id FROM badges
+            WHERE user_id = ? AND earned_at >= ? AND earned_at < ?
+            ORDER BY earned_at, id
+            "#,
+        )
+        .bind(user_id)
+        .bind(starts_on.to_string())
+        .bind(ends_on.to_string())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        let definitions = get_all_badge_definitions();
+        let earned: Vec<(String, String)> = earned_badge_ids
+            .into_iter()
+            .map(|id| {
+                let rarity = definitions
+                    .iter()
+                    .find(|def| def.id == id)
+                    .map(|def| def.rarity.clone())
+                    .unwrap_or_default();
+                (id, rarity)
+            })
+            .collect();
+        let top_badges_json = serde_json::to_string(&top_season_badges(&earned))
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
+        let percentile = season_percentile(current.season_xp, &past_season_xp);
+
+        let mut tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        let archived = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO season_history (user_id, season_id, starts_on, ends_on,
+                                                  season_xp, season_level, best_streak,
+                                                  top_badges_json, percentile, archived_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(previous)
+        .bind(starts_on.to_string())
+        .bind(ends_on.to_string())
+        .bind(current.season_xp)
+        .bind(season_level(current.season_xp))
+        .bind(current.season_best_streak)
+        .bind(&top_badges_json)
+        .bind(percentile)
+        .bind(&now)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?
+        .rows_affected();
+        if archived == 0 {
+            // Already archived by a concurrent caller
+            return Ok(None);
+        }
+
+        let restarted = sqlx::query(
+            r#"
+            UPDATE user_stats
+            SET season_id = ?,
+                season_xp = 0,
+                season_best_streak = current_streak,
+                seasons_completed = seasons_completed + 1,
+                best_season_percentile = MAX(best_season_percentile, ?),
+                updated_at = ?
+            WHERE user_id = ? AND season_id = ?
+            "#,
+        )
+        .bind(&season_id)
+        .bind(percentile.unwrap_or(0))
+        .bind(&now)
+        .bind(user_id)
+        .bind(previous)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?
+        .rows_affected();
+        if restarted == 0 {
+            // Dropping the transaction rolls the archive back
+            return Ok(None);
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        let row: SeasonHistoryRow =
+            sqlx::query_as("SELECT * FROM season_history WHERE user_id = ? AND season_id = ?")
+                .bind(user_id)
+                .bind(previous)
+                .fetch_one(self.pool())
+                .await
+                .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        Ok(Some(row.try_into()?))
+    }
+
+    /// Get archived seasons, most recent first
+    pub async fn get_season_history(
+        &self,
+        user_id: i64,
+        limit: i32,
+    ) -> DbResult<Vec<SeasonArchive>> {
+        let rows: Vec<SeasonHistoryRow> = sqlx::query_as(
+            r#"
+            SELECT * FROM season_history
+            WHERE user_id = ?
+            ORDER BY season_id DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+}
@@ -5,7 +5,7 @@ use sqlx::FromRow;
 
 use crate::database::connection::{Database, DatabaseError, DbResult};
 use crate::database::models::{
-    settings_defaults, ClearCacheResult, NotificationMethod, UserSettings,
+    settings_defaults, ChallengeDifficulty, ClearCacheResult, NotificationMethod, UserSettings,
 };
 
 /// User settings row from database
@@ -23,6 +23,13 @@ pub(crate) struct UserSettingsRow {
     pub background_sync: i32,
     pub sync_on_startup: i32,
     pub animations_enabled: i32,
+    pub challenge_difficulty: String,
+    pub prestige_level: i32,
+    pub xp_daily_commit_cap: i32,
+    pub xp_min_commit_lines: i32,
+    pub xp_exclude_bot_commits: i32,
+    pub xp_excluded_repositories_json: String,
+    pub notify_weekly_report: i32,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -40,10 +47,18 @@ impl TryFrom<UserSettingsRow> for UserSettings {
             notify_badge_earned: row.notify_badge_earned != 0,
             notify_streak_update: row.notify_streak_update != 0,
             notify_streak_milestone: row.notify_streak_milestone != 0,
+            notify_weekly_report: row.notify_weekly_report != 0,
             sync_interval_minutes: row.sync_interval_minutes,
             background_sync: row.background_sync != 0,
             sync_on_startup: row.sync_on_startup != 0,
             animations_enabled: row.animations_enabled != 0,
+            challenge_difficulty: ChallengeDifficulty::from_str(&row.challenge_difficulty),
+            prestige_level: row.prestige_level,
+            xp_daily_commit_cap: row.xp_daily_commit_cap,
+            xp_min_commit_lines: row.xp_min_commit_lines,
+            xp_exclude_bot_commits: row.xp_exclude_bot_commits != 0,
+            xp_excluded_repositories: serde_json::from_str(&row.xp_excluded_repositories_json)
+                .unwrap_or_default(),
             created_at: DateTime::parse_from_rfc3339(&row.created_at)
                 .map(|dt| dt.with_timezone(&Utc))
                 .unwrap_or_else(|_| Utc::now()),
@@ -125,6 +140,8 @@ impl Database {
         settings: &UserSettings,
     ) -> DbResult<UserSettings> {
         let now = Utc::now().to_rfc3339();
+        let excluded_repositories_json = serde_json::to_string(&settings.xp_excluded_repositories)
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
 
         sqlx::query(
             r#"
@@ -135,10 +152,17 @@ impl Database {
                 notify_badge_earned = ?,
                 notify_streak_update = ?,
                 notify_streak_milestone = ?,
+                notify_weekly_report = ?,
                 sync_interval_minutes = ?,
                 background_sync = ?,
                 sync_on_startup = ?,
                 animations_enabled = ?,
+                challenge_difficulty = ?,
+                prestige_level = ?,
+                xp_daily_commit_cap = ?,
+                xp_min_commit_lines = ?,
+                xp_exclude_bot_commits = ?,
+                xp_excluded_repositories_json = ?,
                 updated_at = ?
             WHERE user_id = ?
             "#,
@@ -149,10 +173,17 @@ impl Database {
         .bind(settings.notify_badge_earned as i32)
         .bind(settings.notify_streak_update as i32)
         .bind(settings.notify_streak_milestone as i32)
+        .bind(settings.notify_weekly_report as i32)
         .bind(settings.sync_interval_minutes)
         .bind(settings.background_sync as i32)
         .bind(settings.sync_on_startup as i32)
         .bind(settings.animations_enabled as i32)
+        .bind(settings.challenge_difficulty.as_str())
+        .bind(settings.prestige_level)
+        .bind(settings.xp_daily_commit_cap)
+        .bind(settings.xp_min_commit_lines)
+        .bind(settings.xp_exclude_bot_commits as i32)
+        .bind(&excluded_repositories_json)
         .bind(&now)
         .bind(user_id)
         .execute(self.pool())
@@ -178,10 +209,17 @@ impl Database {
                 notify_badge_earned = ?,
                 notify_streak_update = ?,
                 notify_streak_milestone = ?,
+                notify_weekly_report = ?,
                 sync_interval_minutes = ?,
                 background_sync = ?,
                 sync_on_startup = ?,
                 animations_enabled = ?,
+                challenge_difficulty = ?,
+                prestige_level = ?,
+                xp_daily_commit_cap = ?,
+                xp_min_commit_lines = ?,
+                xp_exclude_bot_commits = ?,
+                xp_excluded_repositories_json = ?,
                 updated_at = ?
             WHERE user_id = ?
             "#,
@@ -192,10 +230,17 @@ impl Database {
         .bind(settings_defaults::NOTIFY_BADGE_EARNED as i32)
         .bind(settings_defaults::NOTIFY_STREAK_UPDATE as i32)
         .bind(settings_defaults::NOTIFY_STREAK_MILESTONE as i32)
+        .bind(settings_defaults::NOTIFY_WEEKLY_REPORT as i32)
         .bind(settings_defaults::SYNC_INTERVAL_MINUTES)
         .bind(settings_defaults::BACKGROUND_SYNC as i32)
         .bind(settings_defaults::SYNC_ON_STARTUP as i32)
         .bind(settings_defaults::ANIMATIONS_ENABLED as i32)
+        .bind(settings_defaults::CHALLENGE_DIFFICULTY.as_str())
+        .bind(settings_defaults::PRESTIGE_LEVEL)
+        .bind(settings_defaults::XP_DAILY_COMMIT_CAP)
+        .bind(settings_defaults::XP_MIN_COMMIT_LINES)
+        .bind(settings_defaults::XP_EXCLUDE_BOT_COMMITS as i32)
+        .bind("[]")
         .bind(&now)
         .bind(user_id)
         .execute(self.pool())
@@ -247,6 +292,13 @@ impl Database {
             .await
             .map_err(|e| DatabaseError::Query(e.to_string()))?;
 
+        // Delete season archives
+        sqlx::query("DELETE FROM season_history WHERE user_id = ?")
+            .bind(user_id)
+            .execute(self.pool())
+            .await
+            .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
         // Delete cache
         sqlx::query("DELETE FROM activity_cache WHERE user_id = ?")
             .bind(user_id)
@@ -273,6 +325,13 @@ impl Database {
                 total_issues_closed = 0,
                 languages_count = 0,
                 total_stars_received = 0,
+                prestige_rank = 0,
+                prestige_xp_offset = 0,
+                season_id = NULL,
+                season_xp = 0,
+                season_best_streak = 0,
+                seasons_completed = 0,
+                best_season_percentile = 0,
                 updated_at = ?
             WHERE user_id = ?
             "#,
@@ -1,6 +1,7 @@
 //! Repository tests
 
 use crate::database::connection::Database;
+use crate::database::models::TokenSpend;
 use chrono::Utc;
 
 async fn setup_test_db() -> Database {
@@ -71,6 +72,102 @@ async fn test_add_xp() {
     assert_eq!(stats.current_level, 2); // 100 XP = level 2
 }
 
+#[tokio::test]
+async fn test_prestige_user() {
+    let db = setup_test_db().await;
+
+    let user = db
+        .create_user(12345, "testuser", None, "token", None, None)
+        .await
+        .expect("Should create user");
+
+    let before = db.add_xp(user.id, 1000).await.expect("Should add XP");
+    assert!(before.current_level > 1);
+
+    let after = db
+        .prestige_user(user.id, &before)
+        .await
+        .expect("Should prestige")
+        .expect("Prestige should apply");
+
+    // Lifetime XP is kept; the level restarts from the new offset
+    assert_eq!(after.total_xp, 1000);
+    assert_eq!(after.prestige_rank, 1);
+    assert_eq!(after.prestige_xp_offset, 1000);
+    assert_eq!(after.current_level, 1);
+    assert_eq!(after.level_xp(), 0);
+
+    // A stale snapshot (double click / concurrent request) does nothing
+    let stale = db
+        .prestige_user(user.id, &before)
+        .await
+        .expect("Should run update");
+    assert!(stale.is_none());
+
+    // XP after prestige levels up from the offset
+    let stats = db.add_xp(user.id, 100).await.expect("Should add XP");
+    assert_eq!(stats.total_xp, 1100);
+    assert_eq!(stats.current_level, 2);
+}
+
+#[tokio::test]
+async fn test_roll_over_season() {
+    use crate::database::models::{season_bounds, season_id_for};
+
+    let db = setup_test_db().await;
+
+    let user = db
+        .create_user(12345, "testuser", None, "token", None, None)
+        .await
+        .expect("Should create user");
+
+    // The first XP gain starts the current season
+    let today = Utc::now().date_naive();
+    let stats = db.add_xp(user.id, 500).await.expect("Should add XP");
+    assert_eq!(stats.season_id, Some(season_id_for(today)));
+    assert_eq!(stats.season_xp, 500);
+
+    db.award_badge(user.id, "milestone", "first_blood")
+        .await
+        .expect("Should award badge");
+
+    // Moving into the next quarter archives the finished season
+    let (_, next_season_start) = season_bounds(&season_id_for(today)).unwrap();
+    let archive = db
+        .roll_over_season(user.id, next_season_start)
+        .await
+        .expect("Should roll over")
+        .expect("Season should be archived");
+    assert_eq!(archive.season_id, season_id_for(today));
+    assert_eq!(archive.season_xp, 500);
+    assert_eq!(archive.top_badges, vec!["first_blood".to_string()]);
+    assert_eq!(archive.percentile, None);
+
+    let stats = db
+        .get_user_stats(user.id)
+        .await
+        .expect("Should fetch stats")
+        .expect("Stats should exist");
+    assert_eq!(stats.season_id, Some(season_id_for(next_season_start)));
+    assert_eq!(stats.season_xp, 0);
+    assert_eq!(stats.seasons_completed, 1);
+    // Lifetime XP is untouched
+    assert_eq!(stats.total_xp, 500);
+
+    // Rolling over again within the same season does nothing
+    let again = db
+        .roll_over_season(user.id, next_season_start)
+        .await
+        .expect("Should run roll over");
+    assert!(again.is_none());
+
+    let history = db
+        .get_season_history(user.id, 10)
+        .await
+        .expect("Should fetch season history");
+    assert_eq!(history.len(), 1);
+}
+
 #[tokio::test]
 async fn test_streak_tracking() {
     let db = setup_test_db().await;
@@ -398,6 +495,267 @@ async fn test_challenge_completion_count() {
     assert_eq!(count, 2);
 }
 
+#[tokio::test]
+async fn test_recent_challenge_outcomes() {
+    let db = setup_test_db().await;
+
+    let user = db
+        .create_user(12345, "testuser", None, "token", None, None)
+        .await
+        .expect("Should create user");
+
+    let start = Utc::now() - chrono::Duration::days(14);
+    let end = start + chrono::Duration::days(7);
+    async fn generated(
+        db: &Database,
+        user_id: i64,
+        challenge_type: &str,
+        metric: &str,
+        start: chrono::DateTime<Utc>,
+        end: chrono::DateTime<Utc>,
+    ) -> crate::database::models::Challenge {
+        db.create_challenge_with_stats(
+            user_id,
+            challenge_type,
+            metric,
+            10,
+            100,
+            start,
+            end,
+            "{}",
+            Some("過去4週間の実績から算出"),
+        )
+        .await
+        .expect("Should create challenge")
+    }
+
+    let completed = generated(&db, user.id, "weekly", "commits", start, end).await;
+    db.complete_challenge(completed.id)
+        .await
+        .expect("Should complete challenge");
+    let failed = generated(&db, user.id, "weekly", "prs", start, end).await;
+    db.fail_challenge(failed.id)
+        .await
+        .expect("Should fail challenge");
+    // Same metric, another type: kept apart
+    let daily = generated(&db, user.id, "daily", "commits", start, end).await;
+    db.fail_challenge(daily.id)
+        .await
+        .expect("Should fail challenge");
+    // Expired but not yet failed by the sync: counts as failed
+    generated(&db, user.id, "weekly", "reviews", start, end).await;
+    // Still active: not an outcome yet
+    db.create_challenge_with_stats(
+        user.id,
+        "daily",
+        "prs",
+        1,
+        10,
+        Utc::now(),
+        Utc::now() + chrono::Duration::days(1),
+        "{}",
+        Some("過去4週間の実績から算出"),
+    )
+    .await
+    .expect("Should create challenge");
+    // Created manually: the user chose the target, so it's left out
+    let manual = db
+        .create_challenge(user.id, "weekly", "commits", 50, 400, start, end)
+        .await
+        .expect("Should create challenge");
+    db.fail_challenge(manual.id)
+        .await
+        .expect("Should fail challenge");
+
+    let mut outcomes = db
+        .get_recent_challenge_outcomes(user.id, 50)
+        .await
+        .expect("Should get outcomes");
+    outcomes.sort();
+
+    let outcome = |challenge_type: &str, metric: &str, status: &str| {
+        (
+            challenge_type.to_string(),
+            metric.to_string(),
+            status.to_string(),
+        )
+    };
+    assert_eq!(
+        outcomes,
+        vec![
+            outcome("daily", "commits", "failed"),
+            outcome("weekly", "commits", "completed"),
+            outcome("weekly", "prs", "failed"),
+            outcome("weekly", "reviews", "failed"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_challenge_tokens_grant_and_cap() {
+    let db = setup_test_db().await;
+
+    let user = db
+        .create_user(12345, "testuser", None, "token", None, None)
+        .await
+        .expect("Should create user");
+
+    let tokens = db
+        .get_challenge_tokens(user.id)
+        .await
+        .expect("Should get tokens");
+    assert_eq!(tokens.reroll_tokens, 1);
+    assert_eq!(tokens.skip_tokens, 1);
+
+    let tokens = db
+        .grant_challenge_tokens(user.id, 1, 0)
+        .await
+        .expect("Should grant tokens");
+    assert_eq!(tokens.reroll_tokens, 2);
+    assert_eq!(tokens.skip_tokens, 1);
+
+    let tokens = db
+        .grant_challenge_tokens(user.id, 10, 10)
+        .await
+        .expect("Should grant tokens");
+    assert_eq!(tokens.reroll_tokens, crate::database::MAX_CHALLENGE_TOKENS);
+    assert_eq!(tokens.skip_tokens, crate::database::MAX_CHALLENGE_TOKENS);
+}
+
+#[tokio::test]
+async fn test_skip_challenge_with_token() {
+    let db = setup_test_db().await;
+
+    let user = db
+        .create_user(12345, "testuser", None, "token", None, None)
+        .await
+        .expect("Should create user");
+
+    let start = Utc::now();
+    let end = start + chrono::Duration::days(1);
+    let first = db
+        .create_challenge(user.id, "daily", "reviews", 1, 20, start, end)
+        .await
+        .expect("Should create challenge");
+    let second = db
+        .create_challenge(user.id, "daily", "prs", 1, 40, start, end)
+        .await
+        .expect("Should create challenge");
+
+    assert_eq!(
+        db.skip_challenge_with_token(user.id, first.id)
+            .await
+            .expect("Should skip challenge"),
+        TokenSpend::Spent
+    );
+    let skipped = db
+        .get_challenge_by_id(first.id)
+        .await
+        .expect("Should get challenge");
+    assert_eq!(skipped.status, "skipped");
+
+    // Neither a completion nor a failure
+    assert_eq!(db.get_challenge_completion_count(user.id).await.unwrap(), 0);
+    assert!(db
+        .get_recent_challenge_outcomes(user.id, 50)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // Out of skip tokens
+    assert_eq!(
+        db.skip_challenge_with_token(user.id, second.id)
+            .await
+            .expect("Should not fail"),
+        TokenSpend::NoToken
+    );
+    let still_active = db
+        .get_challenge_by_id(second.id)
+        .await
+        .expect("Should get challenge");
+    assert_eq!(still_active.status, "active");
+}
+
+#[tokio::test]
+async fn test_reroll_challenge_with_token() {
+    let db = setup_test_db().await;
+
+    let user = db
+        .create_user(12345, "testuser", None, "token", None, None)
+        .await
+        .expect("Should create user");
+
+    let start = Utc::now();
+    let end = start + chrono::Duration::days(1);
+    let challenge = db
+        .create_challenge_with_stats(
+            user.id,
+            "daily",
+            "reviews",
+            1,
+            20,
+            start,
+            end,
+            r#"{"commits":100,"prs":10,"reviews":5,"issues":3}"#,
+            None,
+        )
+        .await
+        .expect("Should create challenge");
+
+    // A finished challenge can't be rerolled, and the token is kept
+    let done = db
+        .create_challenge(user.id, "daily", "prs", 1, 40, start, end)
+        .await
+        .expect("Should create challenge");
+    db.complete_challenge(done.id)
+        .await
+        .expect("Should complete challenge");
+    assert_eq!(
+        db.reroll_challenge_with_token(user.id, done.id, "commits", 3, 50, None)
+            .await
+            .expect("Should not fail"),
+        TokenSpend::ChallengeInactive
+    );
+    assert_eq!(
+        db.get_challenge_tokens(user.id)
+            .await
+            .unwrap()
+            .reroll_tokens,
+        1
+    );
+
+    assert_eq!(
+        db.reroll_challenge_with_token(user.id, challenge.id, "commits", 3, 50, Some("リロール"))
+            .await
+            .expect("Should reroll challenge"),
+        TokenSpend::Spent
+    );
+    let rerolled = db
+        .get_challenge_by_id(challenge.id)
+        .await
+        .expect("Should get challenge");
+    assert_eq!(rerolled.status, "active");
+    assert_eq!(rerolled.target_metric, "commits");
+    assert_eq!(rerolled.target_value, 3);
+    assert_eq!(rerolled.reward_xp, 50);
+    assert_eq!(rerolled.current_value, 0);
+    assert_eq!(rerolled.start_date, challenge.start_date);
+    assert_eq!(rerolled.target_reason.as_deref(), Some("リロール"));
+    // The baseline is kept
+    assert!(db
+        .get_challenge_start_stats(challenge.id)
+        .await
+        .unwrap()
+        .is_some());
+    assert_eq!(
+        db.get_challenge_tokens(user.id)
+            .await
+            .unwrap()
+            .reroll_tokens,
+        0
+    );
+}
+
 #[tokio::test]
 async fn test_create_challenge_with_stats() {
     let db = setup_test_db().await;
@@ -421,12 +779,17 @@ async fn test_create_challenge_with_stats() {
             start,
             end,
             start_stats,
+            Some("直近の達成率から調整"),
         )
         .await
         .expect("Should create challenge with stats");
 
     assert_eq!(challenge.target_metric, "commits");
     assert_eq!(challenge.target_value, 10);
+    assert_eq!(
+        challenge.target_reason.as_deref(),
+        Some("直近の達成率から調整")
+    );
 
     // Verify start stats can be retrieved
     let retrieved_stats = db
@@ -738,6 +1101,82 @@ async fn test_get_xp_total_in_range_filters_by_source_and_window() {
     assert_eq!(past_window_total, 0);
 }
 
+#[tokio::test]
+async fn test_get_xp_totals_by_action_in_range() {
+    let db = setup_test_db().await;
+    let user = db
+        .create_user(12345, "testuser", None, "token", None, None)
+        .await
+        .expect("Should create user");
+
+    db.record_xp_gain(user.id, "commit", 10, None, None, None)
+        .await
+        .unwrap();
+    db.record_xp_gain(user.id, "commit", 15, None, None, None)
+        .await
+        .unwrap();
+    db.record_xp_gain(user.id, "review", 40, None, None, None)
+        .await
+        .unwrap();
+    db.record_xp_recalculation(user.id, 999, None, None)
+        .await
+        .unwrap();
+
+    let yesterday = Utc::now() - chrono::Duration::days(1);
+    let tomorrow = Utc::now() + chrono::Duration::days(1);
+    let totals = db
+        .get_xp_totals_by_action_in_range(user.id, yesterday, tomorrow, "live")
+        .await
+        .expect("Should fetch totals");
+
+    // Largest first, recalculated rows excluded
+    assert_eq!(totals.len(), 2);
+    assert_eq!(totals[0].action_type, "review");
+    assert_eq!((totals[0].xp, totals[0].count), (40, 1));
+    assert_eq!(totals[1].action_type, "commit");
+    assert_eq!((totals[1].xp, totals[1].count), (25, 2));
+}
+
+#[tokio::test]
+async fn test_get_xp_totals_by_day() {
+    let db = setup_test_db().await;
+    let user = db
+        .create_user(12345, "testuser", None, "token", None, None)
+        .await
+        .expect("Should create user");
+
+    db.record_xp_gain(user.id, "commit", 10, None, None, None)
+        .await
+        .unwrap();
+    db.record_xp_gain(user.id, "review", 40, None, None, None)
+        .await
+        .unwrap();
+    db.record_xp_recalculation(user.id, 999, None, None)
+        .await
+        .unwrap();
+
+    let today = Utc::now().date_naive();
+    let days = db
+        .get_xp_totals_by_day(user.id, today - chrono::Duration::days(7), today, "live")
+        .await
+        .expect("Should fetch daily totals");
+
+    // Both live entries land on today, recalculated rows excluded
+    assert_eq!(days, vec![(today, 50)]);
+
+    // The end date is inclusive; a range ending yesterday is empty
+    let past = db
+        .get_xp_totals_by_day(
+            user.id,
+            today - chrono::Duration::days(7),
+            today - chrono::Duration::days(1),
+            "live",
+        )
+        .await
+        .expect("Should fetch past daily totals");
+    assert!(past.is_empty());
+}
+
 /// Regression for PR #217 / migration v16: a directly-inserted legacy
 /// `YYYY-MM-DD HH:MM:SS` row must end up canonical RFC3339 after the v16
 /// backfill SQL runs, so subsequent lexicographic range / ORDER BY
@@ -895,3 +1334,159 @@ async fn test_progress_capped_at_target() {
     assert_eq!(updated.current_value, 10);
     assert_eq!(updated.status, "completed");
 }
+
+// ---------------------------------------------------------------------------
+// Persisted contribution history beyond the one-year window.
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_contribution_days_upsert_and_year_totals() {
+    use crate::database::models::ContributionDayCount;
+    use chrono::NaiveDate;
+
+    let db = setup_test_db().await;
+    let user = db
+        .create_user(12345, "testuser", None, "token", None, None)
+        .await
+        .expect("Should create user");
+    let day = |y, m, d, count| ContributionDayCount {
+        date: NaiveDate::from_ymd_opt(y, m, d).unwrap(),
+        count,
+    };
+
+    db.upsert_contribution_days(
+        user.id,
+        &[day(2024, 12, 31, 3), day(2025, 1, 1, 0), day(2025, 6, 1, 2)],
+    )
+    .await
+    .unwrap();
+    // A later fetch overwrites the count of the same day
+    db.upsert_contribution_days(user.id, &[day(2025, 6, 1, 5)])
+        .await
+        .unwrap();
+
+    let days = db
+        .get_contribution_days_range(
+            user.id,
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 12, 31).unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(days, vec![day(2025, 1, 1, 0), day(2025, 6, 1, 5)]);
+
+    let totals = db.get_contribution_year_totals(user.id).await.unwrap();
+    assert_eq!(totals.len(), 2);
+    assert_eq!(
+        (
+            totals[0].year,
+            totals[0].total_contributions,
+            totals[0].active_days
+        ),
+        (2024, 3, 1)
+    );
+    assert_eq!(
+        (
+            totals[1].year,
+            totals[1].total_contributions,
+            totals[1].active_days
+        ),
+        (2025, 5, 1)
+    );
+}
+
+#[tokio::test]
+async fn test_mark_contribution_year_backfilled() {
+    let db = setup_test_db().await;
+    let user = db
+        .create_user(12345, "testuser", None, "token", None, None)
+        .await
+        .expect("Should create user");
+
+    assert!(db
+        .get_backfilled_contribution_years(user.id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    db.mark_contribution_year_backfilled(user.id, 2023)
+        .await
+        .unwrap();
+    db.mark_contribution_year_backfilled(user.id, 2021)
+        .await
+        .unwrap();
+    // Marking again is idempotent
+    db.mark_contribution_year_backfilled(user.id, 2023)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        db.get_backfilled_contribution_years(user.id).await.unwrap(),
+        vec![2021, 2023]
+    );
+}
+
+#[tokio::test]
+async fn test_replace_daily_repository_code_stats() {
+    use crate::database::models::code_stats::RepositoryCodeStats;
+    use chrono::NaiveDate;
+
+    let db = setup_test_db().await;
+    let user = db
+        .create_user(12345, "testuser", None, "token", None, None)
+        .await
+        .expect("Should create user");
+
+    let split =
+        |date: &str, repository: &str, additions: i32, commits_count: i32| RepositoryCodeStats {
+            date: date.to_string(),
+            repository: repository.to_string(),
+            additions,
+            deletions: 1,
+            commits_count,
+        };
+    let day1 = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+    let day2 = NaiveDate::from_ymd_opt(2026, 10, 2).unwrap();
+
+    db.replace_daily_repository_code_stats(
+        user.id,
+        day1,
+        &[
+            split("2026-10-01", "octo/app", 30, 2),
+            split("2026-10-01", "octo/docs", 5, 1),
+        ],
+    )
+    .await
+    .unwrap();
+    db.replace_daily_repository_code_stats(
+        user.id,
+        day2,
+        &[split("2026-10-02", "octo/app", 10, 1)],
+    )
+    .await
+    .unwrap();
+    // A re-sync of day 1 no longer sees octo/docs
+    db.replace_daily_repository_code_stats(
+        user.id,
+        day1,
+        &[split("2026-10-01", "octo/app", 40, 3)],
+    )
+    .await
+    .unwrap();
+
+    // Matched case-insensitively, oldest first
+    assert_eq!(
+        db.get_repository_code_stats_range(user.id, "Octo/App", day1, day2)
+            .await
+            .unwrap(),
+        vec![
+            split("2026-10-01", "octo/app", 40, 3),
+            split("2026-10-02", "octo/app", 10, 1),
+        ]
+    );
+    assert!(db
+        .get_repository_code_stats_range(user.id, "octo/docs", day1, day2)
+        .await
+        .unwrap()
+        .is_empty());
+}
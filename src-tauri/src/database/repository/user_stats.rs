@@ -26,6 +26,13 @@ pub(crate) struct UserStatsRow {
     pub total_issues_closed: i32,
     pub languages_count: i32,
     pub total_stars_received: i32,
+    pub prestige_rank: i32,
+    pub prestige_xp_offset: i32,
+    pub season_id: Option<String>,
+    pub season_xp: i32,
+    pub season_best_streak: i32,
+    pub seasons_completed: i32,
+    pub best_season_percentile: i32,
     pub updated_at: String,
 }
 
@@ -53,6 +60,13 @@ impl TryFrom<UserStatsRow> for UserStats {
             total_issues_closed: row.total_issues_closed,
             languages_count: row.languages_count,
             total_stars_received: row.total_stars_received,
+            prestige_rank: row.prestige_rank,
+            prestige_xp_offset: row.prestige_xp_offset,
+            season_id: row.season_id,
+            season_xp: row.season_xp,
+            season_best_streak: row.season_best_streak,
+            seasons_completed: row.seasons_completed,
+            best_season_percentile: row.best_season_percentile,
             updated_at: DateTime::parse_from_rfc3339(&row.updated_at)
                 .map(|dt| dt.with_timezone(&Utc))
                 .unwrap_or_else(|_| Utc::now()),
@@ -99,9 +113,14 @@ impl Database {
     }
 
     /// Update user XP and level
+    ///
+    /// The XP also counts towards the current season; a season that has
+    /// ended is archived first so the XP lands in the new one.
     pub async fn add_xp(&self, user_id: i64, xp_amount: i32) -> DbResult<UserStats> {
         let now = Utc::now().to_rfc3339();
 
+        self.roll_over_season(user_id, Utc::now().date_naive()).await?;
+
         // Get current stats
         let current = self
             .get_user_stats(user_id)
@@ -109,17 +128,20 @@ impl Database {
             .ok_or_else(|| DatabaseError::Query("User stats not found".to_string()))?;
 
         let new_total_xp = current.total_xp + xp_amount;
-        let new_level = level::level_from_xp(new_total_xp);
+        let new_level =
+            level::level_from_xp(level::level_xp(new_total_xp, current.prestige_xp_offset));
 
         sqlx::query(
             r#"
             UPDATE user_stats 
-            SET total_xp = ?, current_level = ?, updated_at = ?
+            SET total_xp = ?, current_level = ?, season_xp = MAX(season_xp + ?, 0),
+                updated_at = ?
             WHERE user_id = ?
             "#,
         )
         .bind(new_total_xp)
         .bind(new_level)
+        .bind(xp_amount)
         .bind(now)
         .bind(user_id)
         .execute(self.pool())
@@ -131,6 +153,44 @@ impl Database {
             .ok_or_else(|| DatabaseError::Query("User stats not found after update".to_string()))
     }
 
+    /// Prestige: reset the level to 1 and raise the prestige rank
+    ///
+    /// `total_xp` is left untouched; the current total becomes the new
+    /// `prestige_xp_offset`. The UPDATE only applies while the offset and
+    /// rank still match `current`, so a concurrent sync or a double click
+    /// cannot prestige twice. Returns `None` when nothing was updated.
+    pub async fn prestige_user(
+        &self,
+        user_id: i64,
+        current: &UserStats,
+    ) -> DbResult<Option<UserStats>> {
+        let now = Utc::now().to_rfc3339();
+
+        let result = sqlx::query(
+            r#"
+            UPDATE user_stats
+            SET prestige_rank = prestige_rank + 1,
+                prestige_xp_offset = total_xp,
+                current_level = 1,
+                updated_at = ?
+            WHERE user_id = ? AND prestige_rank = ? AND prestige_xp_offset = ?
+            "#,
+        )
+        .bind(now)
+        .bind(user_id)
+        .bind(current.prestige_rank)
+        .bind(current.prestige_xp_offset)
+        .execute(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        self.get_user_stats(user_id).await
+    }
+
     /// Update streak
     pub async fn update_streak(
         &self,
@@ -166,12 +226,15 @@ impl Database {
         sqlx::query(
             r#"
             UPDATE user_stats 
-            SET current_streak = ?, longest_streak = ?, last_activity_date = ?, updated_at = ?
+            SET current_streak = ?, longest_streak = ?,
+                season_best_streak = MAX(season_best_streak, ?),
+                last_activity_date = ?, updated_at = ?
             WHERE user_id = ?
             "#,
         )
         .bind(new_streak)
         .bind(new_longest)
+        .bind(new_streak)
         .bind(activity_date.to_string())
         .bind(now)
         .bind(user_id)
@@ -215,6 +278,7 @@ impl Database {
             SET 
                 current_streak = ?,
                 longest_streak = MAX(longest_streak, ?),
+                season_best_streak = MAX(season_best_streak, ?),
                 last_activity_date = ?,
                 updated_at = ?
             WHERE user_id = ?
@@ -223,6 +287,7 @@ impl Database {
         )
         .bind(current_streak)
         .bind(longest_streak)
+        .bind(current_streak)
         .bind(last_activity_date)
         .bind(&now)
         .bind(user_id)
@@ -1,10 +1,10 @@
 //! XP History repository operations
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use sqlx::Row;
 
 use crate::database::connection::{Database, DatabaseError, DbResult};
-use crate::database::models::{XpBreakdown, XpHistoryEntry};
+use crate::database::models::{XpBreakdown, XpHistoryEntry, XpSourceTotal};
 
 /// `xp_history.source` value for entries produced by the live sync stream
 /// (`run_github_sync`, streak bonus, manual `add_xp`, …). This is the default
@@ -160,6 +160,88 @@ impl Database {
         Ok(total.unwrap_or(0))
     }
 
+    /// Per-`action_type` XP totals for a `[since, until]` window, largest
+    /// first. Used by the weekly / monthly summary report for
+    /// "XP gained by source"; same lexicographic range semantics as
+    /// [`Self::get_xp_total_in_range`].
+    pub async fn get_xp_totals_by_action_in_range(
+        &self,
+        user_id: i64,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+        source: &str,
+    ) -> DbResult<Vec<XpSourceTotal>> {
+        let rows: Vec<(String, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT action_type, COALESCE(SUM(xp_amount), 0), COUNT(*)
+            FROM xp_history
+            WHERE user_id = ?
+              AND source = ?
+              AND created_at >= ?
+              AND created_at <= ?
+            GROUP BY action_type
+            ORDER BY SUM(xp_amount) DESC, action_type
+            "#,
+        )
+        .bind(user_id)
+        .bind(source)
+        .bind(since.to_rfc3339())
+        .bind(until.to_rfc3339())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(action_type, xp, count)| XpSourceTotal {
+                action_type,
+                xp,
+                count,
+            })
+            .collect())
+    }
+
+    /// XP per UTC day for `[start, end]`, oldest first; days without XP are
+    /// omitted. Feeds the XP heatmap. `created_at` is canonical
+    /// RFC3339 (migration v16), so its first ten characters are the date and
+    /// the bare dates still bound the range lexicographically.
+    pub async fn get_xp_totals_by_day(
+        &self,
+        user_id: i64,
+        start: NaiveDate,
+        end: NaiveDate,
+        source: &str,
+    ) -> DbResult<Vec<(NaiveDate, i64)>> {
+        let rows: Vec<(String, i64)> = sqlx::query_as(
+            r#"
+            SELECT substr(created_at, 1, 10) AS day, COALESCE(SUM(xp_amount), 0)
+            FROM xp_history
+            WHERE user_id = ?
+              AND source = ?
+              AND created_at >= ?
+              AND created_at < ?
+            GROUP BY day
+            ORDER BY day
+            "#,
+        )
+        .bind(user_id)
+        .bind(source)
+        .bind(start.format("%Y-%m-%d").to_string())
+        .bind((end + Duration::days(1)).format("%Y-%m-%d").to_string())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| DatabaseError::Query(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(day, xp)| {
+                NaiveDate::parse_from_str(&day, "%Y-%m-%d")
+                    .ok()
+                    .map(|date| (date, xp))
+            })
+            .collect())
+    }
+
     /// Most-recent recalculation timestamp for a user, used by the
     /// rate-limit guard in `recalculate_xp_history`. `None` means the user
     /// has never run a recalculation.
@@ -0,0 +1,60 @@
+//! Fixtures shared by tests that run against `Database::in_memory()`
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this module):
+//!   ├─ src-tauri/src/database/mod.rs
+//!   └─ src-tauri/src/commands/*.rs (#[cfg(test)] modules)
+//! Dependencies:
+//!   └─ src-tauri/src/database/migrations.rs (users, projects)
+
+use sqlx::{Pool, Row, Sqlite};
+
+/// Insert the `octocat` user and return its id
+pub(crate) async fn seed_user(pool: &Pool<Sqlite>) -> i64 {
+    sqlx::query(
+        r#"
+        INSERT INTO users (github_id, username, access_token_encrypted)
+        VALUES (1, 'octocat', 'enc')
+        RETURNING id
+        "#,
+    )
+    .fetch_one(pool)
+    .await
+    .expect("seed user")
+    .get::<i64, _>("id")
+}
+
+/// Insert a project of `user_id` linked to `owner/repo` (named `repo`) and
+/// return its id
+pub(crate) async fn seed_project(
+    pool: &Pool<Sqlite>,
+    user_id: i64,
+    owner: &str,
+    repo: &str,
+) -> i64 {
+    sqlx::query(
+        r#"
+        INSERT INTO projects (user_id, name, repo_owner, repo_name, repo_full_name)
+        VALUES (?, ?, ?, ?, ? || '/' || ?)
+        RETURNING id
+        "#,
+    )
+    .bind(user_id)
+    .bind(repo)
+    .bind(owner)
+    .bind(repo)
+    .bind(owner)
+    .bind(repo)
+    .fetch_one(pool)
+    .await
+    .expect("seed project")
+    .get::<i64, _>("id")
+}
+
+/// `octocat` with one project on `octo/app`; returns `(user_id, project_id)`
+pub(crate) async fn seed_user_and_project(pool: &Pool<Sqlite>) -> (i64, i64) {
+    let user_id = seed_user(pool).await;
+    let project_id = seed_project(pool, user_id, "octo", "app").await;
+    (user_id, project_id)
+}
@@ -7,11 +7,17 @@ use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, USER_AGENT}
 use thiserror::Error;
 
 use super::types::*;
+use crate::database::challenge::ReviewTurnaround;
+use crate::database::models::project::LinkedPullRequest;
+use crate::database::models::repo_filter::{RepositoryFilter, RepositoryRef};
 
 const GITHUB_API_URL: &str = "https://api.github.com";
 const GITHUB_GRAPHQL_URL: &str = "https://api.github.com/graphql";
 const USER_AGENT_VALUE: &str = "development-tools/1.0";
 
+/// Pages of 100 commits `get_repository_detail` reads per repository
+pub const REPOSITORY_DETAIL_MAX_COMMIT_PAGES: usize = 10;
+
 #[derive(Error, Debug)]
 pub enum GitHubError {
     #[error("HTTP request failed: {0}")]
@@ -58,6 +64,8 @@ pub type GitHubResult<T> = Result<T, GitHubError>;
 pub struct GitHubClient {
     client: reqwest::Client,
     access_token: String,
+    /// Repositories counted by the stats methods (`None` = all)
+    repository_filter: Option<RepositoryFilter>,
 }
 
 impl GitHubClient {
@@ -66,6 +74,40 @@ impl GitHubClient {
         Self {
             client: reqwest::Client::new(),
             access_token,
+            repository_filter: None,
+        }
+    }
+
+    /// Apply the user's repository filter to the stats methods:
+    /// the category totals of `get_contribution_calendar_window`, the
+    /// search counts, stars / languages, code stats, the language
+    /// breakdown, today's commits and commit activity. The contribution
+    /// calendar itself (and so the streaks) stays unfiltered.
+    ///
+    /// The repository-scanning methods filter after fetching their
+    /// `max_repos` most recently pushed repositories, so excluded
+    /// repositories still use up part of that cap.
+    pub fn with_repository_filter(mut self, filter: RepositoryFilter) -> Self {
+        self.repository_filter = filter.is_active().then_some(filter);
+        self
+    }
+
+    /// Whether the repository filter counts `repo` (`username` is the
+    /// signed-in user the work / personal presets are relative to)
+    fn allows_repository(&self, repo: RepositoryRef<'_>, username: &str) -> bool {
+        self.repository_filter
+            .as_ref()
+            .is_none_or(|filter| filter.allows(&repo, username))
+    }
+
+    /// The repository filter as `+`-prefixed Search qualifiers, or `None`
+    /// when the filter leaves nothing to search
+    fn search_scope(&self, username: &str) -> Option<String> {
+        match &self.repository_filter {
+            None => Some(String::new()),
+            Some(filter) => filter
+                .search_qualifiers(username)
+                .map(|qualifiers| qualifiers.iter().map(|q| format!("+{}", q)).collect()),
         }
     }
 
@@ -307,24 +349,250 @@ impl GitHubClient {
         });
         let response: ContributionCollectionResponse = self.graphql(query, Some(variables)).await?;
 
+        let mut collection = response
+            .user
+            .map(|u| u.contributions_collection)
+            .ok_or_else(|| GitHubError::NotFound(format!("User {} not found", username)))?;
+
+        if self.repository_filter.is_some() {
+            self.apply_repository_filter_to_totals(&mut collection, username, from, to)
+                .await?;
+        }
+        Ok(collection)
+    }
+
+    /// Get the contribution calendar of one calendar year (UTC) together
+    /// with every year the user has contributed in.
+    ///
+    /// Used by the contribution history backfill to fetch the
+    /// years before GitHub's default one-year window. The calendar is not
+    /// affected by the repository filter, so no totals are requested.
+    pub async fn get_contribution_history_year(
+        &self,
+        username: &str,
+        year: i32,
+    ) -> GitHubResult<ContributionHistoryCollection> {
+        let query = r#"
+            query($login: String!, $from: DateTime, $to: DateTime) {
+                user(login: $login) {
+                    contributionsCollection(from: $from, to: $to) {
+                        contributionYears
+                        contributionCalendar {
+                            totalContributions
+                            weeks {
+                                contributionDays {
+                                    contributionCount
+                                    date
+                                    weekday
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let from = chrono::NaiveDate::from_ymd_opt(year, 1, 1)
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .ok_or_else(|| GitHubError::ApiError(format!("Invalid year: {}", year)))?
+            .and_utc();
+        let to = chrono::NaiveDate::from_ymd_opt(year, 12, 31)
+            .and_then(|d| d.and_hms_opt(23, 59, 59))
+            .ok_or_else(|| GitHubError::ApiError(format!("Invalid year: {}", year)))?
+            .and_utc();
+
+        let variables = serde_json::json!({
+            "login": username,
+            "from": from.to_rfc3339(),
+            "to": to.to_rfc3339(),
+        });
+        let response: ContributionHistoryResponse = self.graphql(query, Some(variables)).await?;
+
         response
             .user
             .map(|u| u.contributions_collection)
             .ok_or_else(|| GitHubError::NotFound(format!("User {} not found", username)))
     }
 
+    /// Replace the category totals of `collection` with the sums over the
+    /// repositories the filter allows.
+    ///
+    /// `*ContributionsByRepository` lists at most 100 repositories per
+    /// category and omits contributions to repositories the token can't
+    /// see, so the filtered totals can come out lower than GitHub's.
+    async fn apply_repository_filter_to_totals(
+        &self,
+        collection: &mut ContributionsCollection,
+        username: &str,
+        from: Option<chrono::DateTime<Utc>>,
+        to: Option<chrono::DateTime<Utc>>,
+    ) -> GitHubResult<()> {
+        let query = r#"
+            query($login: String!, $from: DateTime, $to: DateTime) {
+                user(login: $login) {
+                    contributionsCollection(from: $from, to: $to) {
+                        commitContributionsByRepository(maxRepositories: 100) {
+                            ...RepositoryContributionCount
+                        }
+                        issueContributionsByRepository(maxRepositories: 100) {
+                            ...RepositoryContributionCount
+                        }
+                        pullRequestContributionsByRepository(maxRepositories: 100) {
+                            ...RepositoryContributionCount
+                        }
+                        pullRequestReviewContributionsByRepository(maxRepositories: 100) {
+                            ...RepositoryContributionCount
+                        }
+                    }
+                }
+            }
+
+            fragment RepositoryContributionCount on RepositoryContributions {
+                contributions { totalCount }
+                repository { nameWithOwner isFork isArchived isPrivate }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "login": username,
+            "from": from.map(|dt| dt.to_rfc3339()),
+            "to": to.map(|dt| dt.to_rfc3339()),
+        });
+        let response: ContributionsByRepositoryResponse =
+            self.graphql(query, Some(variables)).await?;
+        let by_repository = response
+            .user
+            .map(|u| u.contributions_collection)
+            .ok_or_else(|| GitHubError::NotFound(format!("User {} not found", username)))?;
+
+        let sum = |entries: &[RepositoryContributions]| -> i32 {
+            entries
+                .iter()
+                .filter(|e| {
+                    let repo = &e.repository;
+                    self.allows_repository(
+                        RepositoryRef::with_flags(
+                            &repo.name_with_owner,
+                            repo.is_fork,
+                            repo.is_archived,
+                            repo.is_private,
+                        ),
+                        username,
+                    )
+                })
+                .map(|e| e.contributions.total_count)
+                .sum()
+        };
+
+        collection.total_commit_contributions =
+            sum(&by_repository.commit_contributions_by_repository);
+        collection.total_issue_contributions =
+            sum(&by_repository.issue_contributions_by_repository);
+        collection.total_pull_request_contributions =
+            sum(&by_repository.pull_request_contributions_by_repository);
+        collection.total_pull_request_review_contributions =
+            sum(&by_repository.pull_request_review_contributions_by_repository);
+        Ok(())
+    }
+
+    /// Reviews the user submitted in `[from, to]`, each with the time its
+    /// pull request was opened, for the `fast_reviews` challenge metric.
+    ///
+    /// Reads the newest 100 review contributions only; the window is at
+    /// most a challenge period, and GitHub caps it at one year anyway.
+    pub async fn get_review_turnarounds(
+        &self,
+        username: &str,
+        from: chrono::DateTime<Utc>,
+        to: chrono::DateTime<Utc>,
+    ) -> GitHubResult<Vec<ReviewTurnaround>> {
+        let query = r#"
+            query($login: String!, $from: DateTime!, $to: DateTime!) {
+                user(login: $login) {
+                    contributionsCollection(from: $from, to: $to) {
+                        pullRequestReviewContributions(first: 100, orderBy: {direction: DESC}) {
+                            nodes {
+                                occurredAt
+                                pullRequest {
+                                    createdAt
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Response {
+            user: Option<User>,
+        }
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct User {
+            contributions_collection: Collection,
+        }
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Collection {
+            pull_request_review_contributions: Connection,
+        }
+        #[derive(serde::Deserialize)]
+        struct Connection {
+            nodes: Vec<Node>,
+        }
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Node {
+            occurred_at: chrono::DateTime<Utc>,
+            pull_request: PullRequest,
+        }
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PullRequest {
+            created_at: chrono::DateTime<Utc>,
+        }
+
+        // GitHub rejects windows longer than a year.
+        let from = from.max(to - chrono::Duration::days(365));
+        let variables = serde_json::json!({
+            "login": username,
+            "from": from.to_rfc3339(),
+            "to": to.to_rfc3339(),
+        });
+        let response: Response = self.graphql(query, Some(variables)).await?;
+        let user = response
+            .user
+            .ok_or_else(|| GitHubError::NotFound(format!("User {} not found", username)))?;
+
+        Ok(user
+            .contributions_collection
+            .pull_request_review_contributions
+            .nodes
+            .into_iter()
+            .map(|n| ReviewTurnaround {
+                pull_request_created_at: n.pull_request.created_at,
+                reviewed_at: n.occurred_at,
+            })
+            .collect())
+    }
+
     /// Get count of merged PRs for the user
     ///
     /// Note: This uses GitHub's Search API which has stricter rate limits
     /// (30 requests/minute for authenticated users). Call sequentially
     /// and handle rate limit errors gracefully.
     pub async fn get_merged_prs_count(&self, username: &str) -> GitHubResult<i32> {
+        let Some(scope) = self.search_scope(username) else {
+            return Ok(0);
+        };
         crate::github::search_rate_limiter::await_search_slot()
             .await
             .map_err(GitHubError::RateLimited)?;
         let query = format!(
-            "/search/issues?q=type:pr+author:{}+is:merged&per_page=1",
-            username
+            "/search/issues?q=type:pr+author:{}+is:merged{}&per_page=1",
+            username, scope
         );
         let response: serde_json::Value = self.get(&query).await?;
         Ok(response
@@ -339,13 +607,16 @@ impl GitHubClient {
     /// (30 requests/minute for authenticated users). Call sequentially
     /// and handle rate limit errors gracefully.
     pub async fn get_closed_issues_count(&self, username: &str) -> GitHubResult<i32> {
+        let Some(scope) = self.search_scope(username) else {
+            return Ok(0);
+        };
         crate::github::search_rate_limiter::await_search_slot()
             .await
             .map_err(GitHubError::RateLimited)?;
         // Issues created by user that are closed
         let query = format!(
-            "/search/issues?q=type:issue+author:{}+is:closed&per_page=1",
-            username
+            "/search/issues?q=type:issue+author:{}+is:closed{}&per_page=1",
+            username, scope
         );
         let response: serde_json::Value = self.get(&query).await?;
         Ok(response
@@ -356,22 +627,41 @@ impl GitHubClient {
 
     /// Get unique programming languages used across user's repositories
     pub async fn get_languages_count(&self, username: &str) -> GitHubResult<i32> {
-        let repos = self.get_repositories(100, 1).await?;
+        let repos = self.get_filtered_repositories(username).await?;
         let languages: std::collections::HashSet<&str> =
             repos.iter().filter_map(|r| r.language.as_deref()).collect();
         Ok(languages.len() as i32)
     }
 
+    /// The authenticated user's 100 most recently updated repositories
+    /// that pass the repository filter
+    async fn get_filtered_repositories(&self, username: &str) -> GitHubResult<Vec<Repository>> {
+        let mut repos = self.get_repositories(100, 1).await?;
+        repos.retain(|r| {
+            self.allows_repository(
+                RepositoryRef::with_flags(&r.full_name, r.fork, r.archived, r.private),
+                username,
+            )
+        });
+        Ok(repos)
+    }
+
     /// Get count of all PRs (open + closed) for the user
     ///
     /// Note: This uses GitHub's Search API which has stricter rate limits
     /// (30 requests/minute for authenticated users). Call sequentially
     /// and handle rate limit errors gracefully.
     pub async fn get_total_prs_count(&self, username: &str) -> GitHubResult<i32> {
+        let Some(scope) = self.search_scope(username) else {
+            return Ok(0);
+        };
         crate::github::search_rate_limiter::await_search_slot()
             .await
             .map_err(GitHubError::RateLimited)?;
-        let query = format!("/search/issues?q=type:pr+author:{}&per_page=1", username);
+        let query = format!(
+            "/search/issues?q=type:pr+author:{}{}&per_page=1",
+            username, scope
+        );
         let response: serde_json::Value = self.get(&query).await?;
         Ok(response
             .get("total_count")
@@ -591,7 +881,7 @@ impl GitHubClient {
         let streak_info = Self::calculate_streak(&contributions.contribution_calendar);
 
         // Get total stars received and languages count (uses REST API)
-        let repos = self.get_repositories(100, 1).await?;
+        let repos = self.get_filtered_repositories(username).await?;
         let total_stars: i32 = repos.iter().map(|r| r.stargazers_count).sum();
         let languages: std::collections::HashSet<&str> =
             repos.iter().filter_map(|r| r.language.as_deref()).collect();
@@ -599,6 +889,7 @@ impl GitHubClient {
         // Get detailed PR and issue counts using Search API
         // IMPORTANT: Search API has stricter rate limits (30 req/min authenticated)
         // We call these sequentially and use fallback values if rate limited
+        let mut search_fallback = false;
 
         // Total PRs - fallback to GraphQL contributions if rate limited
         let total_prs = match self.get_total_prs_count(username).await {
@@ -608,10 +899,12 @@ impl GitHubClient {
                     "Rate limited fetching total PRs, using GraphQL fallback. Resets at {}",
                     reset
                 );
+                search_fallback = true;
                 contributions.total_pull_request_contributions
             }
             Err(e) => {
                 eprintln!("Error fetching total PRs: {}, using GraphQL fallback", e);
+                search_fallback = true;
                 contributions.total_pull_request_contributions
             }
         };
@@ -624,10 +917,12 @@ impl GitHubClient {
                     "Rate limited fetching merged PRs, using fallback (0). Resets at {}",
                     reset
                 );
+                search_fallback = true;
                 0
             }
             Err(e) => {
                 eprintln!("Error fetching merged PRs: {}, using fallback (0)", e);
+                search_fallback = true;
                 0
             }
         };
@@ -640,10 +935,12 @@ impl GitHubClient {
                     "Rate limited fetching closed issues, using fallback (0). Resets at {}",
                     reset
                 );
+                search_fallback = true;
                 0
             }
             Err(e) => {
                 eprintln!("Error fetching closed issues: {}, using fallback (0)", e);
+                search_fallback = true;
                 0
             }
         };
@@ -668,6 +965,7 @@ impl GitHubClient {
             monthly_streak,
             languages_count: languages.len() as i32,
             streak_info: Some(streak_info),
+            search_fallback,
         })
     }
 
@@ -699,6 +997,9 @@ impl GitHubClient {
                     repositories(first: $maxRepos, orderBy: {field: PUSHED_AT, direction: DESC}) {
                         nodes {
                             nameWithOwner
+                            isFork
+                            isArchived
+                            isPrivate
                             defaultBranchRef {
                                 target {
                                     ... on Commit {
@@ -747,6 +1048,17 @@ impl GitHubClient {
 
         if let Some(user) = response.user {
             for repo in user.repositories.nodes {
+                if !self.allows_repository(
+                    RepositoryRef::with_flags(
+                        &repo.name_with_owner,
+                        repo.is_fork,
+                        repo.is_archived,
+                        repo.is_private,
+                    ),
+                    username,
+                ) {
+                    continue;
+                }
                 let repo_name = repo.name_with_owner.clone();
 
                 if let Some(branch_ref) = repo.default_branch_ref {
@@ -764,10 +1076,7 @@ impl GitHubClient {
                                 let entry = daily_stats.entry(date.clone()).or_insert_with(|| {
                                     DailyCodeStatsAggregated {
                                         date: date.clone(),
-                                        additions: 0,
-                                        deletions: 0,
-                                        commits_count: 0,
-                                        repositories: vec![],
+                                        ..Default::default()
                                     }
                                 });
 
@@ -777,6 +1086,19 @@ impl GitHubClient {
 
                                 if !entry.repositories.contains(&repo_name) {
                                     entry.repositories.push(repo_name.clone());
+                                    entry.repository_stats.push(RepositoryDayCodeStats {
+                                        repository: repo_name.clone(),
+                                        ..Default::default()
+                                    });
+                                }
+                                if let Some(split) = entry
+                                    .repository_stats
+                                    .iter_mut()
+                                    .find(|split| split.repository == repo_name)
+                                {
+                                    split.additions += commit.additions;
+                                    split.deletions += commit.deletions;
+                                    split.commits_count += 1;
                                 }
                             }
                         }
@@ -856,6 +1178,9 @@ impl GitHubClient {
                     ) {
                         nodes {
                             nameWithOwner
+                            isFork
+                            isArchived
+                            isPrivate
                             url
                             languages(first: 10, orderBy: {field: SIZE, direction: DESC}) {
                                 edges {
@@ -908,6 +1233,17 @@ impl GitHubClient {
 
         if let Some(user) = response.user {
             for repo in user.repositories.nodes {
+                if !self.allows_repository(
+                    RepositoryRef::with_flags(
+                        &repo.name_with_owner,
+                        repo.is_fork,
+                        repo.is_archived,
+                        repo.is_private,
+                    ),
+                    username,
+                ) {
+                    continue;
+                }
                 repositories_scanned += 1;
 
                 // Aggregate language sizes across all repositories.
@@ -1014,6 +1350,285 @@ impl GitHubClient {
         })
     }
 
+    /// Get the drill-down for one repository: the user's
+    /// default-branch commits over the last `days` days bucketed by week,
+    /// additions / deletions, their share of all commits, PRs opened /
+    /// merged, PRs reviewed, issues opened / closed and the languages.
+    ///
+    /// One GraphQL call fetches the metadata, languages, the all-author
+    /// commit count and the search counts; the user's commits are then
+    /// paged 100 at a time up to [`REPOSITORY_DETAIL_MAX_COMMIT_PAGES`].
+    /// Past the cap `commits_count` stays exact (`totalCount`) while
+    /// additions / deletions and the timeline cover the newest commits
+    /// and `commits_truncated` is set.
+    ///
+    /// The repository filter is not applied — the user asked for this
+    /// repository explicitly.
+    ///
+    /// # Arguments
+    /// * `username` - GitHub username
+    /// * `owner` / `name` - The repository
+    /// * `days` - Window length in days (1..=365)
+    pub async fn get_repository_detail(
+        &self,
+        username: &str,
+        owner: &str,
+        name: &str,
+        days: i32,
+    ) -> GitHubResult<RepositoryDetail> {
+        let days = days.clamp(1, 365);
+        let now = Utc::now();
+        let since_dt = now - chrono::Duration::days(days as i64);
+        let since = since_dt.to_rfc3339();
+        let since_date = since_dt.format("%Y-%m-%d").to_string();
+        let repo = format!("{}/{}", owner, name);
+
+        let query = r#"
+            query(
+                $login: String!,
+                $owner: String!,
+                $name: String!,
+                $since: GitTimestamp!,
+                $prsOpened: String!,
+                $prsMerged: String!,
+                $reviews: String!,
+                $issuesOpened: String!,
+                $issuesClosed: String!
+            ) {
+                user(login: $login) { id }
+                repository(owner: $owner, name: $name) {
+                    nameWithOwner
+                    url
+                    description
+                    isFork
+                    isArchived
+                    isPrivate
+                    stargazerCount
+                    languages(first: 10, orderBy: {field: SIZE, direction: DESC}) {
+                        edges {
+                            size
+                            node {
+                                name
+                                color
+                            }
+                        }
+                    }
+                    defaultBranchRef {
+                        target {
+                            ... on Commit {
+                                history(since: $since) {
+                                    totalCount
+                                }
+                            }
+                        }
+                    }
+                }
+                pullRequestsOpened: search(query: $prsOpened, type: ISSUE) { issueCount }
+                pullRequestsMerged: search(query: $prsMerged, type: ISSUE) { issueCount }
+                reviewsGiven: search(query: $reviews, type: ISSUE) { issueCount }
+                issuesOpened: search(query: $issuesOpened, type: ISSUE) { issueCount }
+                issuesClosed: search(query: $issuesClosed, type: ISSUE) { issueCount }
+            }
+        "#;
+
+        // "Reviews given" counts other people's PRs the user reviewed that
+        // were updated in the window; search cannot filter on review date.
+        let variables = serde_json::json!({
+            "login": username,
+            "owner": owner,
+            "name": name,
+            "since": since,
+            "prsOpened": format!("repo:{} is:pr author:{} created:>={}", repo, username, since_date),
+            "prsMerged": format!("repo:{} is:pr author:{} is:merged merged:>={}", repo, username, since_date),
+            "reviews": format!("repo:{} is:pr reviewed-by:{} -author:{} updated:>={}", repo, username, username, since_date),
+            "issuesOpened": format!("repo:{} is:issue author:{} created:>={}", repo, username, since_date),
+            "issuesClosed": format!("repo:{} is:issue author:{} is:closed closed:>={}", repo, username, since_date),
+        });
+
+        let response: RepositoryDetailQueryResponse = self.graphql(query, Some(variables)).await?;
+        let author_id = response
+            .user
+            .map(|u| u.id)
+            .ok_or_else(|| GitHubError::NotFound(format!("User {} not found", username)))?;
+        let repository = response
+            .repository
+            .ok_or_else(|| GitHubError::NotFound(format!("Repository {} not found", repo)))?;
+
+        let commits_query = r#"
+            query($owner: String!, $name: String!, $since: GitTimestamp!, $author: ID!, $cursor: String) {
+                repository(owner: $owner, name: $name) {
+                    defaultBranchRef {
+                        target {
+                            ... on Commit {
+                                history(first: 100, after: $cursor, since: $since, author: {id: $author}) {
+                                    totalCount
+                                    pageInfo {
+                                        hasNextPage
+                                        endCursor
+                                    }
+                                    nodes {
+                                        additions
+                                        deletions
+                                        committedDate
+                                        oid
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let mut commits: Vec<CommitNode> = Vec::new();
+        let mut commits_count: i32 = 0;
+        let mut commits_truncated = false;
+        let mut cursor: Option<String> = None;
+
+        for page in 0..REPOSITORY_DETAIL_MAX_COMMIT_PAGES {
+            let variables = serde_json::json!({
+                "owner": owner,
+                "name": name,
+                "since": since,
+                "author": author_id,
+                "cursor": cursor,
+            });
+            let page_response: RepositoryCommitsQueryResponse =
+                self.graphql(commits_query, Some(variables)).await?;
+
+            // An empty repository has no default branch (and no history)
+            let Some(history) = page_response
+                .repository
+                .and_then(|r| r.default_branch_ref)
+                .and_then(|b| b.target)
+                .and_then(|t| t.history)
+            else {
+                break;
+            };
+
+            commits_count = history.total_count;
+            commits.extend(history.nodes);
+
+            if !history.page_info.has_next_page {
+                break;
+            }
+            cursor = history.page_info.end_cursor;
+            if cursor.is_none() || page + 1 == REPOSITORY_DETAIL_MAX_COMMIT_PAGES {
+                commits_truncated = true;
+                break;
+            }
+        }
+
+        let (additions, deletions) = commits.iter().fold((0i32, 0i32), |(adds, dels), c| {
+            (
+                adds.saturating_add(c.additions),
+                dels.saturating_add(c.deletions),
+            )
+        });
+        let commit_timeline =
+            Self::bucket_commits_by_week(&commits, since_dt.date_naive(), now.date_naive());
+
+        let total_commits = repository
+            .default_branch_ref
+            .and_then(|b| b.target)
+            .and_then(|t| t.history)
+            .map(|h| h.total_count)
+            .unwrap_or(0)
+            // The all-author count is fetched first, so a commit pushed in
+            // between could otherwise push the share past 100%
+            .max(commits_count);
+        let contribution_share = if total_commits > 0 {
+            commits_count as f32 / total_commits as f32
+        } else {
+            0.0
+        };
+
+        let edges = repository.languages.map(|l| l.edges).unwrap_or_default();
+        let total_bytes: i64 = edges.iter().map(|e| e.size).sum();
+        let languages = edges
+            .into_iter()
+            .map(|edge| LanguageStats {
+                percentage: if total_bytes > 0 {
+                    edge.size as f32 / total_bytes as f32
+                } else {
+                    0.0
+                },
+                name: edge.node.name,
+                color: edge.node.color,
+                bytes: edge.size,
+            })
+            .collect();
+
+        Ok(RepositoryDetail {
+            name_with_owner: repository.name_with_owner,
+            url: repository.url,
+            description: repository.description,
+            is_fork: repository.is_fork,
+            is_archived: repository.is_archived,
+            is_private: repository.is_private,
+            stargazer_count: repository.stargazer_count,
+            since,
+            days,
+            commits_count,
+            additions,
+            deletions,
+            total_commits,
+            contribution_share,
+            commit_timeline,
+            pull_requests_opened: response.pull_requests_opened.issue_count,
+            pull_requests_merged: response.pull_requests_merged.issue_count,
+            reviews_given: response.reviews_given.issue_count,
+            issues_opened: response.issues_opened.issue_count,
+            issues_closed: response.issues_closed.issue_count,
+            languages,
+            total_bytes,
+            commits_truncated,
+        })
+    }
+
+    /// Bucket commits into Monday-start weeks (UTC) covering `from..=to`,
+    /// oldest first, including empty weeks. Commits outside the range or
+    /// with an unparsable date are dropped.
+    pub fn bucket_commits_by_week(
+        commits: &[CommitNode],
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Vec<RepositoryCommitWeek> {
+        let week_start = |date: chrono::NaiveDate| {
+            date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64)
+        };
+
+        let first_week = week_start(from);
+        let mut weeks: Vec<RepositoryCommitWeek> = Vec::new();
+        let mut current = first_week;
+        while current <= to {
+            weeks.push(RepositoryCommitWeek {
+                week_start: current.format("%Y-%m-%d").to_string(),
+                ..Default::default()
+            });
+            current += chrono::Duration::days(7);
+        }
+
+        for commit in commits {
+            let Ok(committed_at) = chrono::DateTime::parse_from_rfc3339(&commit.committed_date)
+            else {
+                continue;
+            };
+            let date = committed_at.with_timezone(&Utc).date_naive();
+            if date < from || date > to {
+                continue;
+            }
+            let index = ((week_start(date) - first_week).num_days() / 7) as usize;
+            if let Some(week) = weeks.get_mut(index) {
+                week.commits = week.commits.saturating_add(1);
+                week.additions = week.additions.saturating_add(commit.additions);
+                week.deletions = week.deletions.saturating_add(commit.deletions);
+            }
+        }
+
+        weeks
+    }
+
     /// Get total commits authored by the user since `since` across their
     /// `max_repos` most recently pushed repositories.
     ///
@@ -1059,34 +1674,11 @@ impl GitHubClient {
         // a small value (default 30) so this is just defence-in-depth.
         let capped = max_repos.clamp(1, 100);
 
-        // Resolve the user's node id with a one-off lightweight query so
-        // `history(author: {id: $author})` filters to commits actually
-        // authored by them rather than every commit on `defaultBranchRef`
-        // (which would over-count when collaborators push). The id query
-        // costs 1 GraphQL point and lets the main query stay author-scoped.
-        let id_query = r#"
-            query($login: String!) {
-                user(login: $login) { id }
-            }
-        "#;
-
-        #[derive(serde::Deserialize)]
-        #[serde(rename_all = "camelCase")]
-        struct UserIdResponse {
-            user: Option<UserId>,
-        }
-        #[derive(serde::Deserialize)]
-        struct UserId {
-            id: String,
-        }
-
-        let id_resp: UserIdResponse = self
-            .graphql(id_query, Some(serde_json::json!({ "login": username })))
-            .await?;
-        let author_id = id_resp
-            .user
-            .map(|u| u.id)
-            .ok_or_else(|| GitHubError::NotFound(format!("User {} not found", username)))?;
+        // Resolve the user's node id so `history(author: {id: $author})`
+        // filters to commits actually authored by them rather than every
+        // commit on `defaultBranchRef` (which would over-count when
+        // collaborators push).
+        let author_id = self.get_user_node_id(username).await?;
 
         let query = r#"
             query($login: String!, $since: GitTimestamp!, $maxRepos: Int!, $author: ID!) {
@@ -1094,6 +1686,9 @@ impl GitHubClient {
                     repositories(first: $maxRepos, isFork: false, orderBy: {field: PUSHED_AT, direction: DESC}, ownerAffiliations: [OWNER, COLLABORATOR, ORGANIZATION_MEMBER]) {
                         nodes {
                             nameWithOwner
+                            isFork
+                            isArchived
+                            isPrivate
                             defaultBranchRef {
                                 target {
                                     ... on Commit {
@@ -1128,6 +1723,17 @@ impl GitHubClient {
 
         if let Some(user) = response.user {
             for repo in user.repositories.nodes {
+                if !self.allows_repository(
+                    RepositoryRef::with_flags(
+                        &repo.name_with_owner,
+                        repo.is_fork,
+                        repo.is_archived,
+                        repo.is_private,
+                    ),
+                    username,
+                ) {
+                    continue;
+                }
                 scanned += 1;
                 let count = repo
                     .default_branch_ref
@@ -1151,6 +1757,135 @@ impl GitHubClient {
         })
     }
 
+    /// Get the user's commits since `since` with their repository and
+    /// whether a bot or GitHub Actions authored / committed them.
+    ///
+    /// Feeds the XP safeguards in `run_github_sync`. Covers the
+    /// same repositories as [`get_today_commits`] — the default branch of
+    /// the user's `max_repos` most recently pushed non-fork repositories —
+    /// and reads at most 100 commits per repository, so very busy windows
+    /// are undercounted rather than over-penalized.
+    ///
+    /// # Returns
+    /// Commits sorted by `committed_date` ascending.
+    pub async fn get_commit_activity(
+        &self,
+        username: &str,
+        since: &str,
+        max_repos: i32,
+    ) -> GitHubResult<Vec<CommitActivity>> {
+        let capped = max_repos.clamp(1, 100);
+        let author_id = self.get_user_node_id(username).await?;
+
+        let query = r#"
+            query($login: String!, $since: GitTimestamp!, $maxRepos: Int!, $author: ID!) {
+                user(login: $login) {
+                    repositories(first: $maxRepos, isFork: false, orderBy: {field: PUSHED_AT, direction: DESC}, ownerAffiliations: [OWNER, COLLABORATOR, ORGANIZATION_MEMBER]) {
+                        nodes {
+                            nameWithOwner
+                            isFork
+                            isArchived
+                            isPrivate
+                            defaultBranchRef {
+                                target {
+                                    ... on Commit {
+                                        history(first: 100, since: $since, author: {id: $author}) {
+                                            nodes {
+                                                oid
+                                                committedDate
+                                                author { name email user { login } }
+                                                committer { name email user { login } }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                rateLimit {
+                    cost
+                    remaining
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "login": username,
+            "since": since,
+            "maxRepos": capped,
+            "author": author_id,
+        });
+
+        let response: CommitActivityQueryResponse = self.graphql(query, Some(variables)).await?;
+
+        let mut commits: Vec<CommitActivity> = Vec::new();
+        if let Some(user) = response.user {
+            for repo in user.repositories.nodes {
+                if !self.allows_repository(
+                    RepositoryRef::with_flags(
+                        &repo.name_with_owner,
+                        repo.is_fork,
+                        repo.is_archived,
+                        repo.is_private,
+                    ),
+                    username,
+                ) {
+                    continue;
+                }
+                let nodes = repo
+                    .default_branch_ref
+                    .and_then(|r| r.target)
+                    .and_then(|t| t.history)
+                    .map(|h| h.nodes)
+                    .unwrap_or_default();
+                for node in nodes {
+                    let is_bot = node.author.as_ref().is_some_and(GitActor::is_bot)
+                        || node.committer.as_ref().is_some_and(GitActor::is_bot);
+                    commits.push(CommitActivity {
+                        oid: node.oid,
+                        repository: repo.name_with_owner.clone(),
+                        committed_date: node.committed_date,
+                        is_bot,
+                    });
+                }
+            }
+        }
+
+        commits.sort_by(|a, b| a.committed_date.cmp(&b.committed_date));
+        Ok(commits)
+    }
+
+    /// Resolve a user's GraphQL node id with a one-off lightweight query.
+    ///
+    /// Used to scope `history(author: {id: $author})` to commits authored
+    /// by the user. The id query costs 1 GraphQL point.
+    async fn get_user_node_id(&self, username: &str) -> GitHubResult<String> {
+        let id_query = r#"
+            query($login: String!) {
+                user(login: $login) { id }
+            }
+        "#;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct UserIdResponse {
+            user: Option<UserId>,
+        }
+        #[derive(serde::Deserialize)]
+        struct UserId {
+            id: String,
+        }
+
+        let id_resp: UserIdResponse = self
+            .graphql(id_query, Some(serde_json::json!({ "login": username })))
+            .await?;
+        id_resp
+            .user
+            .map(|u| u.id)
+            .ok_or_else(|| GitHubError::NotFound(format!("User {} not found", username)))
+    }
+
     /// Get detailed rate limit information for all API types
     pub async fn get_detailed_rate_limit(&self) -> GitHubResult<RateLimitDetailed> {
         // Get REST API rate limits
@@ -1377,6 +2112,204 @@ impl GitHubClient {
         }
     }
 
+    // ========================================================================
+    // Linked Pull Request Methods (kanban card badges)
+    // ========================================================================
+
+    /// Hard cap on the number of issues whose linked PRs are fetched per
+    /// repository and pass (open / recently closed). Each issue node costs
+    /// roughly one point per linked PR; 200 issues keeps a pass well under
+    /// 1k points.
+    pub const LINKED_PRS_MAX_ISSUES: usize = 200;
+
+    /// Fetch the PRs linked to every open issue of a repository, with
+    /// review decision, head-commit checks and merge state.
+    ///
+    /// Closed issues are settled and skipped, except those updated since
+    /// `closed_since` (the previous sync): closing an issue usually comes
+    /// with its PR merging, and the value cached while it was open would
+    /// otherwise keep showing the PR as open for good.
+    /// Returns `(issue number, linked PRs)` for each issue visited.
+    pub async fn get_issue_linked_pull_requests(
+        &self,
+        owner: &str,
+        repo: &str,
+        closed_since: Option<&str>,
+    ) -> GitHubResult<Vec<(i32, Vec<LinkedPullRequest>)>> {
+        let mut results = self
+            .get_linked_pull_requests_page_set(owner, repo, "OPEN", None)
+            .await?;
+        if let Some(since) = closed_since {
+            results.extend(
+                self.get_linked_pull_requests_page_set(owner, repo, "CLOSED", Some(since))
+                    .await?,
+            );
+        }
+        Ok(results)
+    }
+
+    /// One pass of `get_issue_linked_pull_requests`: the issues in `state`
+    /// updated since `since` (all of them when `None`), newest first, up
+    /// to `LINKED_PRS_MAX_ISSUES`.
+    async fn get_linked_pull_requests_page_set(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: &str,
+        since: Option<&str>,
+    ) -> GitHubResult<Vec<(i32, Vec<LinkedPullRequest>)>> {
+        const PAGE_SIZE: i32 = 50;
+        let query = r#"
+            fragment LinkedPr on PullRequest {
+                number
+                title
+                url
+                state
+                isDraft
+                mergeable
+                reviewDecision
+                repository {
+                    nameWithOwner
+                    url
+                }
+                commits(last: 1) {
+                    nodes {
+                        commit {
+                            statusCheckRollup {
+                                state
+                            }
+                        }
+                    }
+                }
+            }
+
+            query(
+                $owner: String!
+                $name: String!
+                $cursor: String
+                $first: Int!
+                $states: [IssueState!]
+                $since: DateTime
+            ) {
+                repository(owner: $owner, name: $name) {
+                    issues(
+                        first: $first
+                        after: $cursor
+                        states: $states
+                        filterBy: { since: $since }
+                        orderBy: { field: UPDATED_AT, direction: DESC }
+                    ) {
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                        nodes {
+                            number
+                            closedByPullRequestsReferences(first: 5, includeClosedPrs: true) {
+                                nodes {
+                                    ...LinkedPr
+                                }
+                            }
+                            timelineItems(last: 5, itemTypes: [CROSS_REFERENCED_EVENT]) {
+                                nodes {
+                                    ... on CrossReferencedEvent {
+                                        source {
+                                            ...LinkedPr
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let mut results = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let variables = serde_json::json!({
+                "owner": owner,
+                "name": repo,
+                "first": PAGE_SIZE,
+                "cursor": cursor,
+                "states": [state],
+                "since": since,
+            });
+
+            let response: LinkedPrQueryResponse = self.graphql(query, Some(variables)).await?;
+            let connection = response
+                .repository
+                .ok_or_else(|| GitHubError::NotFound(format!("{}/{}", owner, repo)))?
+                .issues;
+
+            results.extend(connection.nodes.into_iter().map(|node| {
+                let number = node.number;
+                (number, Self::collect_linked_prs(node))
+            }));
+
+            if results.len() >= Self::LINKED_PRS_MAX_ISSUES {
+                results.truncate(Self::LINKED_PRS_MAX_ISSUES);
+                break;
+            }
+
+            match connection.page_info {
+                Some(info) if info.has_next_page && info.end_cursor.is_some() => {
+                    cursor = info.end_cursor;
+                }
+                _ => break,
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Pure mapper: closing references first, then PRs that merely mention
+    /// the issue, de-duplicated by URL.
+    pub fn collect_linked_prs(node: LinkedPrIssueNode) -> Vec<LinkedPullRequest> {
+        let closing = node
+            .closed_by_pull_requests_references
+            .map(|c| c.nodes)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|pr| (pr, true));
+        let mentions = node
+            .timeline_items
+            .map(|t| t.nodes)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|event| event.source)
+            // Issue sources deserialize as `{}` and are dropped here.
+            .filter_map(|source| serde_json::from_value::<LinkedPrNode>(source).ok())
+            .map(|pr| (pr, false));
+
+        let mut prs: Vec<LinkedPullRequest> = Vec::new();
+        for (pr, closes_issue) in closing.chain(mentions) {
+            if prs.iter().any(|existing| existing.url == pr.url) {
+                continue;
+            }
+            let checks_state = pr
+                .commits
+                .and_then(|c| c.nodes.into_iter().next())
+                .and_then(|n| n.commit.status_check_rollup)
+                .map(|r| r.state);
+            prs.push(LinkedPullRequest {
+                number: pr.number,
+                title: pr.title,
+                url: pr.url,
+                repo_full_name: pr.repository.name_with_owner,
+                state: pr.state,
+                is_draft: pr.is_draft,
+                mergeable: pr.mergeable,
+                review_decision: pr.review_decision,
+                checks_state,
+                closes_issue,
+            });
+        }
+        prs
+    }
+
     /// Check if rate limit is critical (below 20% remaining)
     pub fn is_rate_limit_critical(rate_limit: &RateLimitDetailed) -> bool {
         let core_critical = rate_limit.core.limit > 0
@@ -1765,4 +2698,106 @@ mod tests {
         );
         assert_eq!(progress.items[1].checks_state.as_deref(), Some("FAILURE"));
     }
+
+    fn linked_pr_json(number: i32, state: &str, rollup: Option<&str>) -> serde_json::Value {
+        serde_json::json!({
+            "number": number,
+            "title": format!("PR #{}", number),
+            "url": format!("https://github.com/octo/test/pull/{}", number),
+            "state": state,
+            "isDraft": false,
+            "mergeable": "MERGEABLE",
+            "reviewDecision": "APPROVED",
+            "repository": { "nameWithOwner": "octo/test", "url": "https://github.com/octo/test" },
+            "commits": { "nodes": rollup.map(|r| vec![serde_json::json!({
+                "commit": { "statusCheckRollup": { "state": r } }
+            })]).unwrap_or_default() }
+        })
+    }
+
+    #[test]
+    fn collect_linked_prs_dedupes_and_keeps_closing_refs_first() {
+        let node: LinkedPrIssueNode = serde_json::from_value(serde_json::json!({
+            "number": 12,
+            "closedByPullRequestsReferences": {
+                "nodes": [linked_pr_json(40, "OPEN", Some("FAILURE"))]
+            },
+            "timelineItems": {
+                "nodes": [
+                    { "source": linked_pr_json(40, "OPEN", Some("FAILURE")) },
+                    // An issue cross-reference: no PullRequest fields selected.
+                    { "source": {} },
+                    // Timeline item of another type matched no fragment.
+                    {},
+                    { "source": linked_pr_json(41, "MERGED", None) }
+                ]
+            }
+        }))
+        .unwrap();
+
+        let prs = GitHubClient::collect_linked_prs(node);
+
+        assert_eq!(
+            prs.iter().map(|p| p.number).collect::<Vec<_>>(),
+            vec![40, 41]
+        );
+        assert!(prs[0].closes_issue);
+        assert_eq!(prs[0].checks_state.as_deref(), Some("FAILURE"));
+        assert!(!prs[1].closes_issue);
+        assert_eq!(prs[1].state, "MERGED");
+        assert!(prs[1].checks_state.is_none());
+    }
+
+    #[test]
+    fn test_git_actor_is_bot() {
+        let actor = |login: Option<&str>, name: &str, email: &str| GitActor {
+            name: Some(name.to_string()),
+            email: Some(email.to_string()),
+            user: login.map(|login| GitActorUser {
+                login: login.to_string(),
+            }),
+        };
+
+        assert!(actor(
+            Some("github-actions[bot]"),
+            "github-actions[bot]",
+            "41898282+github-actions[bot]@users.noreply.github.com"
+        )
+        .is_bot());
+        assert!(actor(None, "github-actions", "action@github.com").is_bot());
+        assert!(actor(Some("dependabot[bot]"), "dependabot[bot]", "x@example.com").is_bot());
+        assert!(!actor(Some("web-flow"), "GitHub", "noreply@github.com").is_bot());
+        assert!(!actor(Some("octocat"), "The Octocat", "octocat@example.com").is_bot());
+        assert!(!GitActor::default().is_bot());
+    }
+
+    #[test]
+    fn test_bucket_commits_by_week() {
+        let commit = |date: &str, additions: i32, deletions: i32| CommitNode {
+            additions,
+            deletions,
+            committed_date: date.to_string(),
+            oid: date.to_string(),
+        };
+        let commits = vec![
+            commit("2026-10-05T09:00:00Z", 10, 2), // Monday
+            commit("2026-10-11T23:00:00Z", 5, 1),  // Sunday, same week
+            commit("2026-10-14T12:00:00+09:00", 3, 3),
+            commit("2026-09-01T00:00:00Z", 100, 100), // before the window
+            commit("not-a-date", 1, 1),
+        ];
+        let from = chrono::NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(); // Thursday
+        let to = chrono::NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+
+        let weeks = GitHubClient::bucket_commits_by_week(&commits, from, to);
+
+        let starts: Vec<&str> = weeks.iter().map(|w| w.week_start.as_str()).collect();
+        assert_eq!(starts, vec!["2026-09-28", "2026-10-05", "2026-10-12"]);
+        assert_eq!(weeks[0].commits, 0);
+        assert_eq!(
+            (weeks[1].commits, weeks[1].additions, weeks[1].deletions),
+            (2, 15, 3)
+        );
+        assert_eq!(weeks[2].commits, 1);
+    }
 }
@@ -7,15 +7,20 @@
 //!
 //! Parents (Files that import this module):
 //!   ├─ src-tauri/src/github/mod.rs
-//!   └─ src-tauri/src/commands/issues.rs
+//!   ├─ src-tauri/src/commands/issues.rs
+//!   └─ src-tauri/src/commands/workflow_settings.rs
 //! Dependencies:
-//!   └─ src-tauri/src/database/models/project.rs
+//!   ├─ src-tauri/src/database/models/project.rs
+//!   └─ src-tauri/src/database/models/workflow_settings.rs
 
 use chrono::{DateTime, Utc};
-use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, USER_AGENT};
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, IF_NONE_MATCH, USER_AGENT};
 use serde::{Deserialize, Serialize};
 
 use crate::database::models::project::{IssuePriority, IssueStatus, LabelDefinition};
+use crate::database::models::workflow_settings::{
+    workflow_events, WorkflowEventRule, WorkflowSettings,
+};
 
 use super::client::{GitHubError, GitHubResult};
 
@@ -36,11 +41,57 @@ pub struct GitHubIssue {
     pub html_url: String,
     pub labels: Vec<GitHubLabel>,
     pub assignee: Option<GitHubAssignee>,
+    /// All assignees (`assignee` is just the first of them).
+    #[serde(default)]
+    pub assignees: Vec<GitHubAssignee>,
+    #[serde(default)]
+    pub milestone: Option<GitHubMilestone>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub closed_at: Option<DateTime<Utc>>,
 }
 
+/// GitHub Milestone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubMilestone {
+    pub id: i64,
+    pub number: i32,
+    pub title: String,
+    pub state: String,
+    pub due_on: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub open_issues: i32,
+    #[serde(default)]
+    pub closed_issues: i32,
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+/// Fields to change with [`IssuesClient::edit_issue`]. `None` leaves the
+/// field untouched; `milestone: Some(None)` clears the milestone.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IssueEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// `completed` / `not_planned` when closing, `reopened` when reopening.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignees: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<Option<i32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+}
+
 /// GitHub Label
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GitHubLabel {
@@ -58,6 +109,65 @@ pub struct GitHubAssignee {
     pub avatar_url: String,
 }
 
+/// Reaction counts GitHub embeds in comment (and issue) payloads
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GitHubReactions {
+    #[serde(default)]
+    pub total_count: i64,
+    #[serde(rename = "+1", default)]
+    pub plus_one: i64,
+    #[serde(rename = "-1", default)]
+    pub minus_one: i64,
+    #[serde(default)]
+    pub laugh: i64,
+    #[serde(default)]
+    pub hooray: i64,
+    #[serde(default)]
+    pub confused: i64,
+    #[serde(default)]
+    pub heart: i64,
+    #[serde(default)]
+    pub rocket: i64,
+    #[serde(default)]
+    pub eyes: i64,
+}
+
+/// Entry of a repository directory listing (contents API)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubContentEntry {
+    pub name: String,
+    pub path: String,
+    /// `file`, `dir`, `symlink` or `submodule`
+    #[serde(rename = "type")]
+    pub entry_type: String,
+}
+
+/// Single file from the contents API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GitHubFileContent {
+    /// Base64 with embedded line breaks
+    #[serde(default)]
+    content: String,
+    #[serde(default)]
+    encoding: String,
+}
+
+/// GitHub Issue comment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubComment {
+    pub id: i64,
+    pub body: Option<String>,
+    /// `None` for comments of deleted ("ghost") accounts.
+    pub user: Option<GitHubAssignee>,
+    pub html_url: String,
+    /// OWNER / MEMBER / CONTRIBUTOR / NONE ...
+    pub author_association: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub reactions: Option<GitHubReactions>,
+}
+
 /// GitHub Repository (for linking)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GitHubRepository {
@@ -145,6 +255,33 @@ pub struct GitHubSearchResponse {
     pub items: Vec<GitHubSearchItem>,
 }
 
+/// Result of a conditional issues-list fetch ([`IssuesClient::get_issues_since`]).
+///
+/// Mirrors `NotificationsResponse`: `NotModified` means the stored ETag is
+/// still fresh and the caller can skip every DB write.
+#[derive(Debug, Clone)]
+pub enum IssuesPageResponse {
+    Modified {
+        issues: Vec<GitHubIssue>,
+        /// ETag to persist for the next conditional request.
+        etag: Option<String>,
+    },
+    NotModified,
+}
+
+/// Outcome of [`IssuesClient::probe_issue`] for a cached issue that no
+/// longer shows up in the repository's issue list.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IssueProbe {
+    /// Still in the repository — keep the cached row.
+    Present,
+    /// GitHub redirected to an issue in another repository. Carries the
+    /// new browser URL.
+    Transferred(String),
+    /// 404 / 410: the issue was deleted.
+    Deleted,
+}
+
 /// Issues API client
 pub struct IssuesClient {
     client: reqwest::Client,
@@ -459,6 +596,129 @@ impl IssuesClient {
         self.get(&url).await
     }
 
+    /// Fetch one page of issues updated at or after `since`, conditional on
+    /// a previously stored ETag.
+    ///
+    /// With `since` set the page is sorted oldest-update first, so when the
+    /// caller stops paginating early the newest issues are the ones left
+    /// for the next run (the cursor never jumps past unseen updates).
+    /// Without `since` this is the full listing in the same newest-first
+    /// order as [`Self::get_issues`]. Only pass `etag` for page 1 — GitHub
+    /// computes ETags per URL, page parameter included.
+    pub async fn get_issues_since(
+        &self,
+        owner: &str,
+        repo: &str,
+        since: Option<&str>,
+        etag: Option<&str>,
+        per_page: i32,
+        page: i32,
+    ) -> GitHubResult<IssuesPageResponse> {
+        let mut url = format!(
+            "{}/repos/{}/{}/issues?state=all&per_page={}&page={}&sort=updated",
+            GITHUB_API_URL, owner, repo, per_page, page
+        );
+        match since {
+            Some(since) => {
+                url.push_str("&direction=asc&since=");
+                url.push_str(&urlencoding::encode(since));
+            }
+            None => url.push_str("&direction=desc"),
+        }
+
+        let mut headers = self.build_headers();
+        if let Some(etag_value) = etag {
+            // Same graceful degradation as NotificationsClient: an
+            // unparsable stored ETag just means an unconditional fetch.
+            if let Ok(header_value) = HeaderValue::from_str(etag_value) {
+                headers.insert(IF_NONE_MATCH, header_value);
+            }
+        }
+
+        let response = self.client.get(&url).headers(headers).send().await?;
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            return Ok(IssuesPageResponse::NotModified);
+        }
+
+        let new_etag = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+
+        match response.status() {
+            status if status.is_success() => Ok(IssuesPageResponse::Modified {
+                issues: response.json().await?,
+                etag: new_etag,
+            }),
+            reqwest::StatusCode::UNAUTHORIZED => Err(GitHubError::Unauthorized),
+            reqwest::StatusCode::NOT_FOUND => Err(GitHubError::NotFound(url)),
+            reqwest::StatusCode::FORBIDDEN
+                if response
+                    .headers()
+                    .get("x-ratelimit-remaining")
+                    .and_then(|v| v.to_str().ok())
+                    .map(|v| v == "0")
+                    .unwrap_or(false) =>
+            {
+                let reset = response
+                    .headers()
+                    .get("x-ratelimit-reset")
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse::<i64>().ok())
+                    .unwrap_or(0);
+                Err(GitHubError::RateLimited(reset))
+            }
+            status => {
+                let error_text = response.text().await.unwrap_or_default();
+                Err(GitHubError::ApiError(format!(
+                    "Status {}: {}",
+                    status, error_text
+                )))
+            }
+        }
+    }
+
+    /// Check what happened to an issue that disappeared from the list.
+    ///
+    /// Transferred issues answer with a 301 to the new repository, which
+    /// reqwest follows transparently — so "transferred" is detected by the
+    /// returned `html_url` no longer living under `owner/repo`. Deleted
+    /// issues answer 410 Gone (404 when the token can't see the tombstone).
+    pub async fn probe_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i32,
+    ) -> GitHubResult<IssueProbe> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}",
+            GITHUB_API_URL, owner, repo, issue_number
+        );
+        let response = self
+            .client
+            .get(&url)
+            .headers(self.build_headers())
+            .send()
+            .await?;
+
+        match response.status() {
+            status if status.is_success() => {
+                let issue: GitHubIssue = response.json().await?;
+                Ok(classify_issue_location(&issue.html_url, owner, repo))
+            }
+            reqwest::StatusCode::NOT_FOUND | reqwest::StatusCode::GONE => Ok(IssueProbe::Deleted),
+            reqwest::StatusCode::UNAUTHORIZED => Err(GitHubError::Unauthorized),
+            status => {
+                let error_text = response.text().await.unwrap_or_default();
+                Err(GitHubError::ApiError(format!(
+                    "Status {}: {}",
+                    status, error_text
+                )))
+            }
+        }
+    }
+
     /// Get a single issue
     pub async fn get_issue(
         &self,
@@ -481,16 +741,54 @@ impl IssuesClient {
         title: &str,
         body: Option<&str>,
         labels: Vec<String>,
+        assignees: Vec<String>,
     ) -> GitHubResult<GitHubIssue> {
         let url = format!("{}/repos/{}/{}/issues", GITHUB_API_URL, owner, repo);
         let payload = serde_json::json!({
             "title": title,
             "body": body,
             "labels": labels,
+            "assignees": assignees,
         });
         self.post(&url, &payload).await
     }
 
+    /// List a directory of the default branch (404 when it doesn't exist)
+    pub async fn get_directory_contents(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+    ) -> GitHubResult<Vec<GitHubContentEntry>> {
+        let url = format!(
+            "{}/repos/{}/{}/contents/{}",
+            GITHUB_API_URL, owner, repo, path
+        );
+        self.get(&url).await
+    }
+
+    /// Read a text file of the default branch
+    pub async fn get_file_contents(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+    ) -> GitHubResult<String> {
+        let url = format!(
+            "{}/repos/{}/{}/contents/{}",
+            GITHUB_API_URL, owner, repo, path
+        );
+        let file: GitHubFileContent = self.get(&url).await?;
+        if file.encoding != "base64" {
+            return Err(GitHubError::ApiError(format!(
+                "Unsupported content encoding for {}: {}",
+                path, file.encoding
+            )));
+        }
+        decode_file_content(&file.content)
+            .map_err(|e| GitHubError::ApiError(format!("Invalid content for {}: {}", path, e)))
+    }
+
     /// Update an issue
     pub async fn update_issue(
         &self,
@@ -524,6 +822,94 @@ impl IssuesClient {
         self.patch(&url, &serde_json::Value::Object(payload)).await
     }
 
+    /// Apply an [`IssueEdit`] in a single PATCH
+    pub async fn edit_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i32,
+        edit: &IssueEdit,
+    ) -> GitHubResult<GitHubIssue> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}",
+            GITHUB_API_URL, owner, repo, issue_number
+        );
+        self.patch(&url, edit).await
+    }
+
+    /// Get one page of comments on an issue (oldest first)
+    pub async fn get_issue_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i32,
+        per_page: i32,
+        page: i32,
+    ) -> GitHubResult<Vec<GitHubComment>> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}/comments?per_page={}&page={}",
+            GITHUB_API_URL, owner, repo, issue_number, per_page, page
+        );
+        self.get(&url).await
+    }
+
+    /// Post a comment on an issue
+    pub async fn create_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i32,
+        body: &str,
+    ) -> GitHubResult<GitHubComment> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}/comments",
+            GITHUB_API_URL, owner, repo, issue_number
+        );
+        let payload = serde_json::json!({ "body": body });
+        self.post(&url, &payload).await
+    }
+
+    /// Get users that issues in the repository can be assigned to
+    pub async fn get_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> GitHubResult<Vec<GitHubAssignee>> {
+        let url = format!(
+            "{}/repos/{}/{}/assignees?per_page=100",
+            GITHUB_API_URL, owner, repo
+        );
+        self.get(&url).await
+    }
+
+    /// Get open milestones of a repository
+    pub async fn get_milestones(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> GitHubResult<Vec<GitHubMilestone>> {
+        let url = format!(
+            "{}/repos/{}/{}/milestones?state=open&sort=due_on&per_page=100",
+            GITHUB_API_URL, owner, repo
+        );
+        self.get(&url).await
+    }
+
+    /// Get one page of all (open and closed) milestones of a repository
+    pub async fn get_all_milestones(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i32,
+        page: i32,
+    ) -> GitHubResult<Vec<GitHubMilestone>> {
+        let url = format!(
+            "{}/repos/{}/{}/milestones?state=all&sort=due_on&per_page={}&page={}",
+            GITHUB_API_URL, owner, repo, per_page, page
+        );
+        self.get(&url).await
+    }
+
     /// Set labels on an issue
     pub async fn set_issue_labels(
         &self,
@@ -800,13 +1186,170 @@ impl IssuesClient {
     }
 }
 
+/// Decode the base64 `content` of the contents API (line-wrapped at 60).
+fn decode_file_content(content: &str) -> Result<String, String> {
+    use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+
+    let compact: String = content.chars().filter(|c| !c.is_whitespace()).collect();
+    let bytes = BASE64.decode(compact).map_err(|e| e.to_string())?;
+    String::from_utf8(bytes).map_err(|e| e.to_string())
+}
+
+/// Decide whether an issue's `html_url` still lives under `owner/repo`.
+///
+/// GitHub treats owner / repo names case-insensitively, so the comparison
+/// does too.
+pub fn classify_issue_location(html_url: &str, owner: &str, repo: &str) -> IssueProbe {
+    let expected = format!("https://github.com/{}/{}/", owner, repo).to_lowercase();
+    if html_url.to_lowercase().starts_with(&expected) {
+        IssueProbe::Present
+    } else {
+        IssueProbe::Transferred(html_url.to_string())
+    }
+}
+
 // ============================================================================
 // GitHub Actions template
 // ============================================================================
 
-/// Generate GitHub Actions workflow YAML for automatic status updates
-pub fn generate_actions_template() -> String {
-    r#"name: Issue Status Sync
+/// Plain YAML scalar when safe, single-quoted otherwise.
+fn yaml_scalar(value: &str) -> String {
+    let plain = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/'));
+    if plain {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "''"))
+    }
+}
+
+/// Single-quoted bash word.
+fn bash_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// JSON string array, which is also a valid JavaScript literal.
+fn js_string_array(values: &[String]) -> String {
+    serde_json::to_string(values).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// `- name: ...` step running `github-script` for one event rule.
+fn push_rule_step(
+    yaml: &mut String,
+    title: &str,
+    condition: &str,
+    rule: &WorkflowEventRule,
+    close_issue: bool,
+    skip_completed: bool,
+) {
+    let status_labels: Vec<String> = IssueStatus::all_labels()
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    let add_labels = rule.labels_to_add();
+    let outcome = rule
+        .status
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| "labeled".to_string());
+
+    yaml.push_str(&format!("\n      - name: {}\n", title));
+    yaml.push_str(&format!(
+        "        if: {} && steps.extract.outputs.issue_number != ''\n",
+        condition
+    ));
+    yaml.push_str("        uses: actions/github-script@v7\n");
+    yaml.push_str("        with:\n");
+    yaml.push_str("          script: |\n");
+
+    let mut script = Vec::new();
+    script.push(
+        "const issueNumber = parseInt('${{ steps.extract.outputs.issue_number }}');".to_string(),
+    );
+    script.push(format!(
+        "const statusLabels = {};",
+        js_string_array(&status_labels)
+    ));
+    script.push(String::new());
+    script.push("try {".to_string());
+    script.push("  const { data: issue } = await github.rest.issues.get({".to_string());
+    script.push("    owner: context.repo.owner,".to_string());
+    script.push("    repo: context.repo.repo,".to_string());
+    script.push("    issue_number: issueNumber".to_string());
+    script.push("  });".to_string());
+    if skip_completed {
+        script.push(String::new());
+        script.push("  // Skip if already done or cancelled".to_string());
+        script.push(
+            "  const currentStatus = issue.labels.find(l => statusLabels.includes(l.name));"
+                .to_string(),
+        );
+        script.push("  if (currentStatus && (currentStatus.name === 'status:done' || currentStatus.name === 'status:cancelled')) {".to_string());
+        script.push(
+            "    console.log(`Issue #${issueNumber} is already ${currentStatus.name}, skipping`);"
+                .to_string(),
+        );
+        script.push("    return;".to_string());
+        script.push("  }".to_string());
+    }
+    if rule.status.is_some() {
+        script.push(String::new());
+        script.push("  // Remove old status labels".to_string());
+        script.push("  for (const label of issue.labels) {".to_string());
+        script.push("    if (statusLabels.includes(label.name)) {".to_string());
+        script.push("      await github.rest.issues.removeLabel({".to_string());
+        script.push("        owner: context.repo.owner,".to_string());
+        script.push("        repo: context.repo.repo,".to_string());
+        script.push("        issue_number: issueNumber,".to_string());
+        script.push("        name: label.name".to_string());
+        script.push("      }).catch(() => {});".to_string());
+        script.push("    }".to_string());
+        script.push("  }".to_string());
+    }
+    script.push(String::new());
+    script.push("  await github.rest.issues.addLabels({".to_string());
+    script.push("    owner: context.repo.owner,".to_string());
+    script.push("    repo: context.repo.repo,".to_string());
+    script.push("    issue_number: issueNumber,".to_string());
+    script.push(format!("    labels: {}", js_string_array(&add_labels)));
+    script.push("  });".to_string());
+    if close_issue {
+        script.push(String::new());
+        script.push("  // Close the issue".to_string());
+        script.push("  await github.rest.issues.update({".to_string());
+        script.push("    owner: context.repo.owner,".to_string());
+        script.push("    repo: context.repo.repo,".to_string());
+        script.push("    issue_number: issueNumber,".to_string());
+        script.push("    state: 'closed',".to_string());
+        script.push("    state_reason: 'completed'".to_string());
+        script.push("  });".to_string());
+    }
+    script.push(String::new());
+    script.push(format!(
+        "  console.log(`Updated issue #${{issueNumber}} to {}{}`);",
+        outcome,
+        if close_issue { " and closed" } else { "" }
+    ));
+    script.push("} catch (error) {".to_string());
+    script.push(
+        "  console.log(`Failed to update issue #${issueNumber}: ${error.message}`);".to_string(),
+    );
+    script.push("}".to_string());
+
+    for line in script {
+        if line.is_empty() {
+            yaml.push('\n');
+        } else {
+            yaml.push_str(&format!("            {}\n", line));
+        }
+    }
+}
+
+/// The fixed workflow `setup_github_actions` committed before the
+/// template became configurable. Its steps match the default settings, so
+/// the drift check accepts it while a project has no saved settings.
+pub const LEGACY_ACTIONS_TEMPLATE: &str = r#"name: Issue Status Sync
 
 on:
   push:
@@ -1009,8 +1552,156 @@ jobs:
             } catch (error) {
               console.log(`Failed to revert issue #${issueNumber}: ${error.message}`);
             }
-"#
-    .to_string()
+"#;
+
+/// Generate the GitHub Actions workflow YAML that moves issues between
+/// kanban columns from branch pushes and pull request events.
+///
+/// The issue number is read from the branch name with
+/// `settings.branch_pattern`; events without an active rule get no step
+/// (and no trigger). A reopened pull request follows the "opened" rule.
+/// Call `settings.validate()` first.
+pub fn generate_actions_template(settings: &WorkflowSettings) -> String {
+    let push = settings.active_rule(workflow_events::PUSH);
+    let opened = settings.active_rule(workflow_events::PULL_REQUEST_OPENED);
+    let merged = settings.active_rule(workflow_events::PULL_REQUEST_MERGED);
+    let closed = settings.active_rule(workflow_events::PULL_REQUEST_CLOSED);
+    let close_on_merge = settings.close_on_merge;
+
+    let mut yaml = String::from("name: Issue Status Sync\n\non:\n");
+    if push.is_some() {
+        yaml.push_str("  push:\n");
+        if !settings.excluded_branches.is_empty() {
+            yaml.push_str("    branches-ignore:\n");
+            for branch in &settings.excluded_branches {
+                yaml.push_str(&format!("      - {}\n", yaml_scalar(branch)));
+            }
+        }
+    }
+    let mut pr_types = Vec::new();
+    if opened.is_some() {
+        pr_types.push("opened");
+    }
+    if merged.is_some() || closed.is_some() || close_on_merge {
+        pr_types.push("closed");
+    }
+    if opened.is_some() {
+        pr_types.push("reopened");
+    }
+    if !pr_types.is_empty() {
+        yaml.push_str("  pull_request:\n");
+        yaml.push_str(&format!("    types: [{}]\n", pr_types.join(", ")));
+    }
+
+    yaml.push_str(
+        r#"
+jobs:
+  update-status:
+    runs-on: ubuntu-latest
+    steps:
+      - name: Validate Branch Name & Extract Issue Number
+        id: extract
+        run: |
+          BRANCH="${{ github.head_ref || github.ref_name }}"
+"#,
+    );
+    yaml.push_str(&format!(
+        "          PATTERN={}\n",
+        bash_quote(&settings.branch_pattern)
+    ));
+    yaml.push_str(
+        r#"          if [[ ! "$BRANCH" =~ $PATTERN ]]; then
+            echo "Branch name does not match required pattern: $PATTERN"
+            echo "issue_number=" >> $GITHUB_OUTPUT
+            exit 0
+          fi
+"#,
+    );
+    yaml.push_str(&format!(
+        "          ISSUE_NUMBER=\"${{BASH_REMATCH[{}]}}\"\n",
+        settings.issue_number_group
+    ));
+    yaml.push_str(
+        r#"          if [[ ! "$ISSUE_NUMBER" =~ ^[0-9]+$ ]]; then
+            echo "Captured issue number is not numeric: $ISSUE_NUMBER"
+            echo "issue_number=" >> $GITHUB_OUTPUT
+            exit 0
+          fi
+          echo "issue_number=$ISSUE_NUMBER" >> $GITHUB_OUTPUT
+          echo "Branch: $BRANCH, Issue: $ISSUE_NUMBER"
+
+      - name: Skip if no issue number
+        if: steps.extract.outputs.issue_number == ''
+        run: echo "No valid issue number found, skipping status update"
+"#,
+    );
+
+    if let Some(rule) = push {
+        push_rule_step(
+            &mut yaml,
+            "Update Status on Push",
+            "github.event_name == 'push'",
+            rule,
+            false,
+            true,
+        );
+    }
+    if let Some(rule) = opened {
+        push_rule_step(
+            &mut yaml,
+            "Update Status on PR Open",
+            "github.event_name == 'pull_request' && (github.event.action == 'opened' || github.event.action == 'reopened')",
+            rule,
+            false,
+            false,
+        );
+    }
+    let merged_condition = "github.event_name == 'pull_request' && github.event.action == 'closed' && github.event.pull_request.merged == true";
+    match merged {
+        Some(rule) => push_rule_step(
+            &mut yaml,
+            "Update Status on PR Merge",
+            merged_condition,
+            rule,
+            close_on_merge,
+            false,
+        ),
+        None if close_on_merge => push_close_step(&mut yaml, merged_condition),
+        None => {}
+    }
+    if let Some(rule) = closed {
+        push_rule_step(
+            &mut yaml,
+            "Update Status on PR Close without Merge",
+            "github.event_name == 'pull_request' && github.event.action == 'closed' && github.event.pull_request.merged == false",
+            rule,
+            false,
+            false,
+        );
+    }
+    yaml
+}
+
+/// Close-only step for "close on merge" without a merge rule.
+fn push_close_step(yaml: &mut String, condition: &str) {
+    yaml.push_str(&format!(
+        r#"
+      - name: Close Issue on PR Merge
+        if: {} && steps.extract.outputs.issue_number != ''
+        uses: actions/github-script@v7
+        with:
+          script: |
+            const issueNumber = parseInt('${{{{ steps.extract.outputs.issue_number }}}}');
+            await github.rest.issues.update({{
+              owner: context.repo.owner,
+              repo: context.repo.repo,
+              issue_number: issueNumber,
+              state: 'closed',
+              state_reason: 'completed'
+            }}).catch(error => console.log(`Failed to close issue #${{issueNumber}}: ${{error.message}}`));
+"#,
+        condition
+    ));
 }
 
 #[cfg(test)]
@@ -1250,4 +1941,121 @@ mod tests {
             IssueStatus::Cancelled
         );
     }
+
+    #[test]
+    fn test_classify_issue_location() {
+        assert_eq!(
+            classify_issue_location("https://github.com/Octo/Demo/issues/3", "octo", "demo"),
+            IssueProbe::Present
+        );
+        // Same prefix but a different repository must not count as present.
+        assert_eq!(
+            classify_issue_location("https://github.com/octo/demo-2/issues/3", "octo", "demo"),
+            IssueProbe::Transferred("https://github.com/octo/demo-2/issues/3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_issue_edit_serializes_only_set_fields() {
+        let edit = IssueEdit {
+            title: Some("New title".to_string()),
+            milestone: Some(None),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&edit).unwrap(),
+            serde_json::json!({ "title": "New title", "milestone": null })
+        );
+
+        let edit = IssueEdit {
+            state: Some("closed".to_string()),
+            state_reason: Some("not_planned".to_string()),
+            milestone: Some(Some(3)),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&edit).unwrap(),
+            serde_json::json!({ "state": "closed", "state_reason": "not_planned", "milestone": 3 })
+        );
+    }
+
+    #[test]
+    fn test_comment_deserializes_reactions() {
+        let comment: GitHubComment = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "body": "LGTM",
+            "user": { "id": 2, "login": "alice", "avatar_url": "https://a" },
+            "html_url": "https://github.com/o/r/issues/1#issuecomment-1",
+            "author_association": "MEMBER",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z",
+            "reactions": { "url": "https://api", "total_count": 3, "+1": 2, "-1": 0, "heart": 1 }
+        }))
+        .unwrap();
+        let reactions = comment.reactions.unwrap();
+        assert_eq!(reactions.total_count, 3);
+        assert_eq!(reactions.plus_one, 2);
+        assert_eq!(reactions.heart, 1);
+        assert_eq!(reactions.rocket, 0);
+    }
+
+    #[test]
+    fn test_decode_file_content_ignores_line_breaks() {
+        // "name: Bug\n" as the contents API wraps it
+        let decoded = decode_file_content("bmFtZTog\nQnVnCg==\n").unwrap();
+        assert_eq!(decoded, "name: Bug\n");
+        assert!(decode_file_content("not base64!").is_err());
+    }
+
+    #[test]
+    fn test_generate_actions_template_defaults() {
+        let yaml = generate_actions_template(&WorkflowSettings::default());
+        let parsed: serde_yaml_ng::Value = serde_yaml_ng::from_str(&yaml).unwrap();
+        let steps = parsed["jobs"]["update-status"]["steps"]
+            .as_sequence()
+            .unwrap();
+        // extract + skip + push / opened / merged / closed
+        assert_eq!(steps.len(), 6);
+        assert_eq!(
+            parsed["on"]["push"]["branches-ignore"],
+            serde_yaml_ng::from_str::<serde_yaml_ng::Value>("[main, master]").unwrap()
+        );
+        assert!(yaml
+            .contains(r"PATTERN='^(feat|fix|docs|refactor|test|chore)/([0-9]+)-[a-zA-Z0-9_-]+$'"));
+        assert!(yaml.contains("ISSUE_NUMBER=\"${BASH_REMATCH[2]}\""));
+        assert!(yaml.contains(r#"labels: ["status:in-review"]"#));
+        assert!(yaml.contains("state_reason: 'completed'"));
+        assert_eq!(
+            parsed["on"]["pull_request"]["types"],
+            serde_yaml_ng::from_str::<serde_yaml_ng::Value>("[opened, closed, reopened]").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_generate_actions_template_custom_settings() {
+        let mut settings = WorkflowSettings {
+            branch_pattern: "^([0-9]+)-it's".to_string(),
+            issue_number_group: 1,
+            excluded_branches: vec!["release/*".to_string(), "gh pages".to_string()],
+            close_on_merge: false,
+            ..Default::default()
+        };
+        settings.rules.retain(|r| r.event == workflow_events::PUSH);
+        settings.rules[0].labels = vec!["wip".to_string()];
+
+        let yaml = generate_actions_template(&settings);
+        let parsed: serde_yaml_ng::Value = serde_yaml_ng::from_str(&yaml).unwrap();
+        assert!(parsed["on"]["pull_request"].is_null());
+        assert_eq!(parsed["on"]["push"]["branches-ignore"][1], "gh pages");
+        assert_eq!(
+            parsed["jobs"]["update-status"]["steps"]
+                .as_sequence()
+                .unwrap()
+                .len(),
+            3
+        );
+        assert!(yaml.contains(r"PATTERN='^([0-9]+)-it'\''s'"));
+        assert!(yaml.contains(r#"labels: ["status:in-progress","wip"]"#));
+        assert!(!yaml.contains("state: 'closed'"));
+    }
 }
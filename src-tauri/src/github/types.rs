@@ -31,6 +31,10 @@ pub struct Repository {
     pub language: Option<String>,
     pub stargazers_count: i32,
     pub forks_count: i32,
+    #[serde(default)]
+    pub fork: bool,
+    #[serde(default)]
+    pub archived: bool,
     pub open_issues_count: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -177,6 +181,74 @@ pub struct ContributionsCollection {
     pub total_pull_request_review_contributions: i32,
 }
 
+/// Years with contributions and the calendar of one window, used by the
+/// contribution history backfill
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionHistoryResponse {
+    pub user: Option<UserContributionHistory>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserContributionHistory {
+    pub contributions_collection: ContributionHistoryCollection,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionHistoryCollection {
+    /// Every year the user contributed in, newest first. Independent of
+    /// the requested window.
+    pub contribution_years: Vec<i32>,
+    pub contribution_calendar: ContributionCalendar,
+}
+
+/// Per-repository contribution counts, used to recompute the
+/// `ContributionsCollection` totals under a repository filter
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionsByRepositoryResponse {
+    pub user: Option<UserContributionsByRepository>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserContributionsByRepository {
+    pub contributions_collection: ContributionsByRepository,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionsByRepository {
+    pub commit_contributions_by_repository: Vec<RepositoryContributions>,
+    pub issue_contributions_by_repository: Vec<RepositoryContributions>,
+    pub pull_request_contributions_by_repository: Vec<RepositoryContributions>,
+    pub pull_request_review_contributions_by_repository: Vec<RepositoryContributions>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryContributions {
+    pub contributions: ContributionCount,
+    pub repository: ContributionRepository,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionCount {
+    pub total_count: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionRepository {
+    pub name_with_owner: String,
+    pub is_fork: bool,
+    pub is_archived: bool,
+    pub is_private: bool,
+}
+
 /// Streak information calculated from contribution calendar
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -209,6 +281,10 @@ pub struct GitHubStats {
     pub languages_count: i32,
     /// Detailed streak information from contribution calendar
     pub streak_info: Option<StreakInfo>,
+    /// Whether a Search API count (total / merged PRs, closed issues) fell
+    /// back to an approximation because the request failed
+    #[serde(default)]
+    pub search_fallback: bool,
 }
 
 // ============================================================================
@@ -230,6 +306,14 @@ pub struct CommitStats {
 #[serde(rename_all = "camelCase")]
 pub struct RepositoryWithCommits {
     pub name_with_owner: String,
+    /// Fork / archived / private flags read by the repository filter;
+    /// default to `false` for queries that don't request them.
+    #[serde(default)]
+    pub is_fork: bool,
+    #[serde(default)]
+    pub is_archived: bool,
+    #[serde(default)]
+    pub is_private: bool,
     pub default_branch_ref: Option<DefaultBranchRef>,
 }
 
@@ -297,6 +381,19 @@ pub struct DailyCodeStatsAggregated {
     pub deletions: i32,
     pub commits_count: i32,
     pub repositories: Vec<String>,
+    /// The same day split by repository, in `repositories` order
+    #[serde(default)]
+    pub repository_stats: Vec<RepositoryDayCodeStats>,
+}
+
+/// One repository's share of a `DailyCodeStatsAggregated` day
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryDayCodeStats {
+    pub repository: String,
+    pub additions: i32,
+    pub deletions: i32,
+    pub commits_count: i32,
 }
 
 // ============================================================================
@@ -329,6 +426,12 @@ pub struct LanguageBreakdownRepositoriesConnection {
 pub struct LanguageBreakdownRepository {
     pub name_with_owner: String,
     pub url: Option<String>,
+    #[serde(default)]
+    pub is_fork: bool,
+    #[serde(default)]
+    pub is_archived: bool,
+    #[serde(default)]
+    pub is_private: bool,
     pub languages: Option<LanguageConnection>,
     pub default_branch_ref: Option<DefaultBranchRef>,
 }
@@ -395,6 +498,154 @@ pub struct LanguageBreakdownResponse {
     pub repositories_scanned: i32,
 }
 
+// ============================================================================
+// Repository Detail Types (per-repository drill-down)
+// ============================================================================
+
+/// GraphQL response for the repository detail query: repository metadata,
+/// languages, the all-author commit count and the user's PR / review /
+/// issue counts (search aliases) for one repository.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryDetailQueryResponse {
+    pub user: Option<RepositoryDetailUser>,
+    pub repository: Option<RepositoryDetailNode>,
+    pub pull_requests_opened: SearchIssueCount,
+    pub pull_requests_merged: SearchIssueCount,
+    pub reviews_given: SearchIssueCount,
+    pub issues_opened: SearchIssueCount,
+    pub issues_closed: SearchIssueCount,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryDetailUser {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchIssueCount {
+    pub issue_count: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryDetailNode {
+    pub name_with_owner: String,
+    pub url: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub is_fork: bool,
+    #[serde(default)]
+    pub is_archived: bool,
+    #[serde(default)]
+    pub is_private: bool,
+    #[serde(default)]
+    pub stargazer_count: i32,
+    pub languages: Option<LanguageConnection>,
+    pub default_branch_ref: Option<RepositoryDetailBranchRef>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryDetailBranchRef {
+    pub target: Option<RepositoryDetailTarget>,
+}
+
+/// Default-branch commits by every author in the window
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryDetailTarget {
+    pub history: Option<ContributionCount>,
+}
+
+/// One page of the user's default-branch commits in a repository
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryCommitsQueryResponse {
+    pub repository: Option<RepositoryCommitsNode>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryCommitsNode {
+    pub default_branch_ref: Option<RepositoryCommitsBranchRef>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryCommitsBranchRef {
+    pub target: Option<RepositoryCommitsTarget>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryCommitsTarget {
+    pub history: Option<RepositoryCommitHistory>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryCommitHistory {
+    pub total_count: i32,
+    pub nodes: Vec<CommitNode>,
+    pub page_info: PageInfo,
+}
+
+/// Commits / additions / deletions of one week (Monday start, UTC)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryCommitWeek {
+    /// YYYY-MM-DD of the Monday starting the week
+    pub week_start: String,
+    pub commits: i32,
+    pub additions: i32,
+    pub deletions: i32,
+}
+
+/// Per-repository drill-down surfaced to the frontend.
+///
+/// Commit figures cover the user's commits on the default branch since
+/// `since`; PR / review / issue counts come from the search API over the
+/// same window.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryDetail {
+    pub name_with_owner: String,
+    pub url: Option<String>,
+    pub description: Option<String>,
+    pub is_fork: bool,
+    pub is_archived: bool,
+    pub is_private: bool,
+    pub stargazer_count: i32,
+    /// ISO8601 lower bound of the window
+    pub since: String,
+    /// Window length in days
+    pub days: i32,
+    /// The user's commits in the window
+    pub commits_count: i32,
+    pub additions: i32,
+    pub deletions: i32,
+    /// Commits by every author in the window
+    pub total_commits: i32,
+    /// `commits_count / total_commits` (0.0..=1.0)
+    pub contribution_share: f32,
+    /// Weekly buckets covering the whole window, oldest first
+    pub commit_timeline: Vec<RepositoryCommitWeek>,
+    pub pull_requests_opened: i32,
+    pub pull_requests_merged: i32,
+    /// Other people's PRs the user reviewed
+    pub reviews_given: i32,
+    pub issues_opened: i32,
+    pub issues_closed: i32,
+    pub languages: Vec<LanguageStats>,
+    pub total_bytes: i64,
+    /// More commits than the page cap: additions / deletions and the
+    /// timeline cover only the newest ones
+    pub commits_truncated: bool,
+}
+
 // ============================================================================
 // Today's Commits Types (Issue #188 — G-01 realtime today commit count)
 // ============================================================================
@@ -426,6 +677,12 @@ pub struct TodayCommitsRepositoriesConnection {
 #[serde(rename_all = "camelCase")]
 pub struct TodayCommitsRepository {
     pub name_with_owner: String,
+    #[serde(default)]
+    pub is_fork: bool,
+    #[serde(default)]
+    pub is_archived: bool,
+    #[serde(default)]
+    pub is_private: bool,
     pub default_branch_ref: Option<TodayCommitsDefaultBranchRef>,
 }
 
@@ -464,6 +721,121 @@ pub struct TodayCommitsSummary {
     pub repositories_with_commits: Vec<String>,
 }
 
+// ============================================================================
+// Commit Activity Types (XP safeguards)
+// ============================================================================
+
+/// GraphQL response for the per-commit activity query used by the XP
+/// safeguards.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitActivityQueryResponse {
+    pub user: Option<CommitActivityUser>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitActivityUser {
+    pub repositories: CommitActivityRepositoriesConnection,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitActivityRepositoriesConnection {
+    pub nodes: Vec<CommitActivityRepository>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitActivityRepository {
+    pub name_with_owner: String,
+    #[serde(default)]
+    pub is_fork: bool,
+    #[serde(default)]
+    pub is_archived: bool,
+    #[serde(default)]
+    pub is_private: bool,
+    pub default_branch_ref: Option<CommitActivityDefaultBranchRef>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitActivityDefaultBranchRef {
+    pub target: Option<CommitActivityTarget>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitActivityTarget {
+    pub history: Option<CommitActivityHistory>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitActivityHistory {
+    pub nodes: Vec<CommitActivityNode>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitActivityNode {
+    pub oid: String,
+    pub committed_date: String,
+    pub author: Option<GitActor>,
+    pub committer: Option<GitActor>,
+}
+
+/// Git author / committer of a commit
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GitActor {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub user: Option<GitActorUser>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitActorUser {
+    pub login: String,
+}
+
+impl GitActor {
+    /// Whether this actor is a bot or GitHub Actions.
+    ///
+    /// GitHub bot accounts carry a `[bot]` suffix in their login and name
+    /// (e.g. `github-actions[bot]`); workflows that configure git
+    /// themselves usually keep the `github-actions` name or the
+    /// `…[bot]@users.noreply.github.com` email. `web-flow`, the committer
+    /// of commits made in the web UI, is not treated as a bot.
+    pub fn is_bot(&self) -> bool {
+        let login = self.user.as_ref().map(|u| u.login.as_str());
+        [login, self.name.as_deref(), self.email.as_deref()]
+            .into_iter()
+            .flatten()
+            .map(str::to_lowercase)
+            .any(|value| {
+                value.contains("[bot]")
+                    || value.starts_with("github-actions")
+                    || value.starts_with("dependabot")
+                    || value.starts_with("renovate")
+            })
+    }
+}
+
+/// A commit authored by the user on a repository's default branch
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitActivity {
+    pub oid: String,
+    /// Repository "owner/name"
+    pub repository: String,
+    /// ISO8601 commit timestamp
+    pub committed_date: String,
+    /// Authored or committed by a bot / GitHub Actions
+    pub is_bot: bool,
+}
+
 /// Rate limit information with detailed breakdown
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -608,3 +980,75 @@ pub struct PrProgress {
     /// with very large PR queues aren't misled.
     pub truncated: bool,
 }
+
+// ============================================================================
+// Linked Pull Request Types (kanban card PR / CI badges)
+// ============================================================================
+
+/// `repository { issues }` response of the linked-PR query.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedPrQueryResponse {
+    pub repository: Option<LinkedPrRepository>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedPrRepository {
+    pub issues: LinkedPrIssueConnection,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedPrIssueConnection {
+    pub nodes: Vec<LinkedPrIssueNode>,
+    pub page_info: Option<PageInfo>,
+}
+
+/// One issue with both kinds of PR links.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedPrIssueNode {
+    pub number: i32,
+    pub closed_by_pull_requests_references: Option<LinkedPrConnection>,
+    /// `CROSS_REFERENCED_EVENT` items only.
+    pub timeline_items: Option<CrossReferenceConnection>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedPrConnection {
+    pub nodes: Vec<LinkedPrNode>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossReferenceConnection {
+    pub nodes: Vec<CrossReferenceNode>,
+}
+
+/// `CrossReferencedEvent`. `source` is an `Issue` or a `PullRequest`; only
+/// the `... on PullRequest` fragment selects fields, so an issue source
+/// arrives as `{}` and is kept opaque here.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossReferenceNode {
+    #[serde(default)]
+    pub source: Option<serde_json::Value>,
+}
+
+/// PR fields shared by both link kinds (`fragment LinkedPr on PullRequest`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedPrNode {
+    pub number: i32,
+    pub title: String,
+    pub url: String,
+    /// `OPEN` / `CLOSED` / `MERGED`.
+    pub state: String,
+    pub is_draft: bool,
+    pub mergeable: String,
+    pub review_decision: Option<String>,
+    pub repository: PrProgressRepository,
+    pub commits: Option<PrProgressCommits>,
+}
@@ -12,24 +12,43 @@ use commands::{
     // Gamification commands
     add_xp,
     award_badge,
+    // Contribution history beyond one year
+    backfill_contribution_history,
+    // Issue templates
+    build_issue_from_template,
+    // Bulk kanban operations
+    bulk_update_issues,
     // Auth commands (Device Flow)
     cancel_device_flow,
+    // Actions workflow settings
+    check_workflow_drift,
     // Cache management commands
     cleanup_expired_cache,
     // Settings commands
     clear_cache,
     clear_user_cache,
+    // Issue editing
+    close_issue,
     // Challenge commands
     create_challenge,
     // Issue management commands (Issue #59)
     create_github_issue,
+    // Issue comments
+    create_issue_comment,
     create_project,
     delete_challenge,
     delete_project,
+    // Issue time tracking
+    delete_time_entry,
+    // Offline issue write queue
+    discard_outbox_operation,
     export_data,
+    export_summary_report,
     get_active_challenges,
     // Activity timeline command (Issue #187)
     get_activity_feed_with_cache,
+    // Activity heatmaps
+    get_activity_heatmap,
     get_all_challenges,
     get_app_info,
     get_auth_state,
@@ -39,20 +58,29 @@ use commands::{
     get_badges_with_progress,
     get_cache_stats,
     get_challenge_stats,
+    get_challenge_tokens,
     get_challenges_by_type,
     // Code Statistics commands (Issue #74)
     get_code_stats_summary,
     get_contribution_calendar,
+    get_contribution_calendar_for_year,
+    get_contribution_history,
     get_current_user,
     get_database_info,
     get_github_stats,
     // Cache fallback commands
     get_github_stats_with_cache,
     get_github_user,
+    get_issue_comments,
+    get_issue_edit_options,
+    get_issue_outbox,
+    get_issue_templates,
+    get_issue_time_entries,
     get_kanban_board,
     // Language / repository breakdown command (Issue #193)
     get_language_breakdown_with_cache,
     get_level_info,
+    get_milestone_burndown,
     // Cross-repository "Today / Inbox" command (Issue #183)
     get_my_open_work_with_cache,
     // PR progress dashboard panel command (Issue #185)
@@ -60,18 +88,33 @@ use commands::{
     get_near_completion_badges,
     // GitHub Notifications commands (Issue #186)
     get_notifications,
+    // Period-over-period comparison
+    get_period_comparison,
     get_project,
     get_project_issues,
+    get_project_milestones,
+    get_project_repositories,
     get_projects,
     get_rate_limit_info,
+    // Per-repository drill-down
+    get_repository_detail_with_cache,
+    // Repository / organization filter
+    get_repository_filter,
+    get_running_timer,
     get_scheduler_status,
+    get_season_history,
+    get_season_info,
     get_settings,
+    // Weekly / monthly summary reports
+    get_summary_report,
     get_sync_intervals,
+    get_time_summary,
     // Realtime "today's commits" command (Issue #188)
     get_today_commits_with_cache,
     get_user_repositories,
     get_user_stats,
     get_user_stats_with_cache,
+    get_workflow_settings,
     get_xp_history,
     link_repository,
     logout,
@@ -79,23 +122,42 @@ use commands::{
     open_external_url,
     open_url,
     poll_device_token,
+    post_issue_time_summary,
+    prestige,
+    preview_workflow,
     // Issue #194: past-year XP recalculation
     recalculate_xp_history,
     // Issue #191: explicit refresh for badge progress (heavy aggregate API call)
     refresh_badges_progress,
     relink_repository,
+    reopen_issue,
+    replay_issue_outbox,
+    reroll_challenge,
     reset_all_data,
+    reset_repository_filter,
     reset_settings,
+    reset_workflow_settings,
+    retry_outbox_operation,
+    // Local issue search
+    search_issues_local,
+    set_repository_filter_preset,
     setup_github_actions,
+    skip_challenge,
     start_device_flow,
+    start_issue_timer,
+    stop_issue_timer,
     sync_all_projects,
     sync_code_stats,
     sync_github_stats,
     sync_project_issues,
+    unlink_repository,
     update_challenge_progress,
+    update_issue_fields,
     update_issue_status,
     update_project,
+    update_repository_filter,
     update_settings,
+    update_workflow_settings,
     validate_token,
     // State
     AppState,
@@ -229,6 +291,12 @@ pub fn run() {
             get_user_stats,
             sync_github_stats,
             get_contribution_calendar,
+            // Contribution history beyond one year
+            get_contribution_history,
+            get_contribution_calendar_for_year,
+            backfill_contribution_history,
+            // Activity heatmaps
+            get_activity_heatmap,
             get_badges_with_progress,
             get_near_completion_badges,
             refresh_badges_progress,
@@ -247,6 +315,13 @@ pub fn run() {
             get_today_commits_with_cache,
             // Language / repository breakdown command (Issue #193)
             get_language_breakdown_with_cache,
+            // Per-repository drill-down
+            get_repository_detail_with_cache,
+            // Period-over-period comparison
+            get_period_comparison,
+            // Weekly / monthly summary reports
+            get_summary_report,
+            export_summary_report,
             // Gamification commands
             get_level_info,
             add_xp,
@@ -254,6 +329,9 @@ pub fn run() {
             award_badge,
             get_xp_history,
             get_badge_definitions,
+            prestige,
+            get_season_info,
+            get_season_history,
             // Past-year XP recalculation (Issue #194)
             recalculate_xp_history,
             // Challenge commands
@@ -264,6 +342,9 @@ pub fn run() {
             delete_challenge,
             update_challenge_progress,
             get_challenge_stats,
+            get_challenge_tokens,
+            reroll_challenge,
+            skip_challenge,
             // Settings commands
             get_settings,
             update_settings,
@@ -275,6 +356,11 @@ pub fn run() {
             get_sync_intervals,
             get_app_info,
             open_external_url,
+            // Repository filter commands
+            get_repository_filter,
+            update_repository_filter,
+            set_repository_filter_preset,
+            reset_repository_filter,
             // Sync scheduler commands
             get_scheduler_status,
             // Issue management commands (Issue #59)
@@ -285,7 +371,15 @@ pub fn run() {
             delete_project,
             get_user_repositories,
             link_repository,
+            unlink_repository,
+            get_project_repositories,
             setup_github_actions,
+            // Actions workflow settings
+            get_workflow_settings,
+            update_workflow_settings,
+            reset_workflow_settings,
+            preview_workflow,
+            check_workflow_drift,
             sync_project_issues,
             sync_all_projects,
             relink_repository,
@@ -293,8 +387,39 @@ pub fn run() {
             get_kanban_board,
             update_issue_status,
             create_github_issue,
+            // Bulk kanban operations
+            bulk_update_issues,
+            // Issue editing
+            update_issue_fields,
+            close_issue,
+            reopen_issue,
+            get_issue_edit_options,
+            // Issue templates
+            get_issue_templates,
+            build_issue_from_template,
+            // Issue time tracking
+            start_issue_timer,
+            stop_issue_timer,
+            get_running_timer,
+            get_issue_time_entries,
+            delete_time_entry,
+            get_time_summary,
+            post_issue_time_summary,
+            // Issue comments
+            get_issue_comments,
+            create_issue_comment,
+            // Milestones
+            get_project_milestones,
+            get_milestone_burndown,
             get_my_open_work_with_cache,
             get_my_pr_progress_with_cache,
+            // Offline issue write queue
+            get_issue_outbox,
+            replay_issue_outbox,
+            retry_outbox_operation,
+            discard_outbox_operation,
+            // Local issue search
+            search_issues_local,
             // GitHub Notifications commands (Issue #186)
             get_notifications,
             mark_notification_read,
@@ -17,6 +17,7 @@ use crate::commands::github::run_github_sync;
 use crate::commands::notifications::{
     run_notifications_sync, NotificationsSyncOutcome, GITHUB_NOTIFICATIONS_SYNC_TYPE,
 };
+use crate::commands::summary_report::deliver_weekly_report_if_due;
 use crate::database::models::code_stats::SyncMetadata;
 use crate::database::models::UserSettings;
 use crate::github::client::GitHubError;
@@ -148,6 +149,15 @@ async fn run_loop(app: AppHandle, notify: Arc<Notify>, status: Arc<RwLock<Schedu
 
         write_status(&status, &settings, metadata.as_ref(), projected_next, true).await;
 
+        // Last week's summary report. Local-only, so it does not
+        // depend on `background_sync`; the helper records the delivery and
+        // is a no-op for the rest of the week.
+        if let Err(e) =
+            deliver_weekly_report_if_due(&app, &state.db, user.id, &user.username, &settings).await
+        {
+            eprintln!("Scheduler: failed to deliver weekly report: {}", e);
+        }
+
         // Poll GitHub Notifications on its own cadence (Issue #186).
         // Independent of the stats `SchedulerAction` so a stats Sleep /
         // Idle / RateLimited doesn't freeze the inbox. The endpoint is
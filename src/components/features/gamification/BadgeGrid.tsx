@@ -202,6 +202,10 @@ const BadgeDetailModal: React.FC<{
         return '🎯 Challenge';
       case 'level':
         return '⭐ Level';
+      case 'prestige':
+        return '🌀 Prestige';
+      case 'season':
+        return '🗓️ Season';
       case 'stars':
         return '🌟 Stars';
       case 'language':
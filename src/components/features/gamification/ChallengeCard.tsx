@@ -15,12 +15,14 @@ import { useCachedFetch } from '../../../hooks/useCachedFetch';
 import { useAuth } from '../../../stores/authStore';
 import { Icon } from '../../icons';
 import { challenges as challengeApi, github } from '../../../lib/tauri/commands';
-import type { ChallengeInfo } from '../../../types';
+import type { ChallengeInfo, ChallengeTokens } from '../../../types';
 import {
+  TOKEN_CHALLENGE_TYPES,
   challengeTypeLabel,
   targetMetricLabel,
   targetMetricIcon,
   remainingTimeLabel,
+  upcomingLabel,
 } from '../../../types/challenge';
 
 // Today-commits cache TTL on the backend is 3 minutes (Issue #188); align
@@ -52,7 +54,21 @@ const ChallengeItem: React.FC<{
    *  error as "no realtime data" so the bar falls back to `currentValue`.
    */
   liveHasError?: boolean;
-}> = ({ challenge, liveTodayCommits, liveFromCache, liveHasError }) => {
+  /** Reroll / skip token balance; buttons are shown for daily/weekly challenges */
+  tokens: ChallengeTokens | null;
+  isOnline: boolean;
+  onReroll: (challengeId: number) => void;
+  onSkip: (challengeId: number) => void;
+}> = ({
+  challenge,
+  liveTodayCommits,
+  liveFromCache,
+  liveHasError,
+  tokens,
+  isOnline,
+  onReroll,
+  onSkip,
+}) => {
   // For daily commits challenges, prefer the realtime count when it's
   // *higher* than the persisted current value. We never lower the bar:
   // the realtime query scans the most recent N repos so it can under-count
@@ -75,6 +91,13 @@ const ChallengeItem: React.FC<{
   const progress = Math.min(effectiveProgress, 100);
   const isCompleted = challenge.isCompleted || effectiveCurrent >= challenge.targetValue;
   const isExpired = challenge.isExpired;
+  const canUseTokens =
+    TOKEN_CHALLENGE_TYPES.includes(challenge.challengeType) &&
+    !isCompleted &&
+    !isExpired &&
+    !challenge.isUpcoming;
+  const rerollTokens = tokens?.rerollTokens ?? 0;
+  const skipTokens = tokens?.skipTokens ?? 0;
 
   // Determine colors based on status
   const getColors = () => {
@@ -108,10 +131,19 @@ const ChallengeItem: React.FC<{
   const colors = getColors();
 
   // Challenge type badge color
-  const typeBadgeClass =
-    challenge.challengeType === 'daily'
-      ? 'bg-gm-accent-cyan/20 text-gm-accent-cyan border-gm-accent-cyan/30'
-      : 'bg-gm-accent-purple/20 text-gm-accent-purple border-gm-accent-purple/30';
+  const typeBadgeClass = (() => {
+    switch (challenge.challengeType) {
+      case 'daily':
+        return 'bg-gm-accent-cyan/20 text-gm-accent-cyan border-gm-accent-cyan/30';
+      case 'monthly':
+      case 'quarterly':
+        return 'bg-gm-accent-gold/20 text-gm-accent-gold border-gm-accent-gold/30';
+      case 'custom':
+        return 'bg-gm-accent-pink/20 text-gm-accent-pink border-gm-accent-pink/30';
+      default:
+        return 'bg-gm-accent-purple/20 text-gm-accent-purple border-gm-accent-purple/30';
+    }
+  })();
 
   return (
     <div
@@ -135,7 +167,9 @@ const ChallengeItem: React.FC<{
             ? '✅ 達成!'
             : isExpired
               ? '⏰ 期限切れ'
-              : remainingTimeLabel(challenge.remainingTimeHours)}
+              : challenge.isUpcoming
+                ? upcomingLabel(challenge.startDate)
+                : remainingTimeLabel(challenge.remainingTimeHours)}
         </span>
       </div>
 
@@ -178,6 +212,33 @@ const ChallengeItem: React.FC<{
           <span className="text-xs text-gm-text-muted">{Math.round(progress)}%</span>
         </div>
       </div>
+
+      {/* Why this target was chosen (auto-generated challenges) */}
+      {challenge.targetReason && (
+        <p className="mt-2 text-xs text-gm-text-muted">💡 {challenge.targetReason}</p>
+      )}
+
+      {/* Reroll / skip with tokens (daily / weekly only) */}
+      {canUseTokens && (
+        <div className="mt-3 flex justify-end gap-2">
+          <button
+            className="px-2 py-1 text-xs rounded-lg bg-gm-bg-secondary/50 hover:bg-gm-bg-secondary text-gm-text-secondary transition-colors disabled:opacity-40 disabled:cursor-not-allowed"
+            title="リロールトークンを使って別のチャレンジに入れ替えます"
+            disabled={rerollTokens === 0 || !isOnline}
+            onClick={() => onReroll(challenge.id)}
+          >
+            🎲 リロール ({rerollTokens})
+          </button>
+          <button
+            className="px-2 py-1 text-xs rounded-lg bg-gm-bg-secondary/50 hover:bg-gm-bg-secondary text-gm-text-secondary transition-colors disabled:opacity-40 disabled:cursor-not-allowed"
+            title="スキップトークンを使って失敗扱いにせず取り下げます"
+            disabled={skipTokens === 0 || !isOnline}
+            onClick={() => onSkip(challenge.id)}
+          >
+            ⏭ スキップ ({skipTokens})
+          </button>
+        </div>
+      )}
     </div>
   );
 };
@@ -210,6 +271,8 @@ export const ChallengeCard: React.FC = () => {
   const [challenges, setChallenges] = useState<ChallengeInfo[] | null>(null);
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
+  const [tokens, setTokens] = useState<ChallengeTokens | null>(null);
+  const [tokenError, setTokenError] = useState<string | null>(null);
 
   const fetchChallenges = useCallback(async () => {
     setLoading(true);
@@ -224,9 +287,30 @@ export const ChallengeCard: React.FC = () => {
     }
   }, []);
 
+  const fetchTokens = useCallback(async () => {
+    try {
+      setTokens(await challengeApi.getTokens());
+    } catch {
+      // Buttons stay disabled without a balance
+    }
+  }, []);
+
   useEffect(() => {
     fetchChallenges();
-  }, [fetchChallenges]);
+    fetchTokens();
+  }, [fetchChallenges, fetchTokens]);
+
+  // Spend a reroll / skip token, then refresh the list and the balance
+  const spendToken = async (challengeId: number, reroll: boolean) => {
+    try {
+      await (reroll ? challengeApi.reroll(challengeId) : challengeApi.skip(challengeId));
+      setTokenError(null);
+      fetchChallenges();
+      fetchTokens();
+    } catch (e) {
+      setTokenError(String(e));
+    }
+  };
 
   // Realtime today's commit count — only fetched when there is at least one
   // active daily/commits challenge to render. Avoids spending a GraphQL
@@ -261,6 +345,7 @@ export const ChallengeCard: React.FC = () => {
   const reloadChallenges = async () => {
     if (!isOnline) return;
     fetchChallenges();
+    fetchTokens();
     // Also kick the realtime today-commits query so the LIVE badge isn't
     // strictly tied to its own focus / staleTime cycle.
     void todayCommitsQuery.revalidate();
@@ -313,6 +398,12 @@ export const ChallengeCard: React.FC = () => {
         <>
           {challenges && challenges.length > 0 ? (
             <div className="space-y-3">
+              {/* Token error (e.g. none left) */}
+              {tokenError && (
+                <div className="p-2 bg-gm-error/20 border border-gm-error/50 rounded-lg text-gm-error text-xs">
+                  {tokenError}
+                </div>
+              )}
               {challenges.map((challenge) => (
                 <ChallengeItem
                   key={`${challenge.challengeType}-${challenge.targetMetric}`}
@@ -320,6 +411,10 @@ export const ChallengeCard: React.FC = () => {
                   liveTodayCommits={liveTodayCommits}
                   liveFromCache={liveFromCache}
                   liveHasError={liveHasError}
+                  tokens={tokens}
+                  isOnline={isOnline}
+                  onReroll={(id) => void spendToken(id, true)}
+                  onSkip={(id) => void spendToken(id, false)}
                 />
               ))}
             </div>
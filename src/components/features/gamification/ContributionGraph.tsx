@@ -4,6 +4,20 @@
  * React implementation of ContributionGraph component.
  * Displays GitHub-style contribution calendar with hover cards.
  *
+ * Years before GitHub's one-year window come from the locally stored
+ * contribution history: the year selector switches between the
+ * live last-year calendar and stored years, and "過去の履歴を取得" backfills
+ * the years that have not been fetched yet. Lifetime totals are shown below
+ * the calendar.
+ *
+ * The metric selector switches the same calendar to XP per day,
+ * lines changed or commits, and for lines and commits the repository
+ * selector shows one repository's share of each day (only recorded by code
+ * stats syncs since the per-repository split was added; contributions and
+ * XP have no split, so the selector is disabled for them). Those views come from
+ * `github.getActivityHeatmap`, which normalises every metric onto the same
+ * five colour levels; the legend shows the value range of each level.
+ *
  * Related Documentation:
  *   - Issue: https://github.com/otomatty/development-tools/issues/149
  *   - Original (Leptos): ./contribution_graph.rs
@@ -12,19 +26,53 @@
 import React, { useState, useEffect, useMemo, useCallback } from 'react';
 import { Icon } from '../../icons';
 import { github } from '../../../lib/tauri/commands';
-import type { GitHubStats, ContributionCalendar, ContributionDay } from '../../../types';
+import type {
+  GitHubStats,
+  ActivityHeatmap,
+  ContributionCalendar,
+  ContributionHistory,
+  HeatmapDay,
+  HeatmapMetric,
+} from '../../../types';
 
 interface ContributionGraphProps {
   githubStats?: GitHubStats | null;
 }
 
+const METRICS: Array<{
+  metric: HeatmapMetric;
+  label: string;
+  unit: string;
+  byRepository: boolean;
+}> = [
+  {
+    metric: 'contributions',
+    label: 'コントリビューション',
+    unit: 'contributions',
+    byRepository: false,
+  },
+  { metric: 'xp', label: 'XP', unit: 'XP', byRepository: false },
+  { metric: 'lines', label: '変更行数', unit: '行', byRepository: true },
+  { metric: 'commits', label: 'コミット', unit: 'commits', byRepository: true },
+];
+
+// Level bounds of GitHub's live calendar
+const CALENDAR_THRESHOLDS: [number, number, number] = [3, 6, 10];
+
 // Get intensity level for contribution count
 const getIntensity = (count: number): number => {
-  if (count === 0) return 0;
-  if (count <= 3) return 1;
-  if (count <= 6) return 2;
-  if (count <= 10) return 3;
-  return 4;
+  if (count <= 0) return 0;
+  const level = CALENDAR_THRESHOLDS.findIndex((bound) => count <= bound);
+  return level === -1 ? 4 : level + 1;
+};
+
+// Value range of a legend level, or null when no value maps to it
+const levelRange = (level: number, thresholds: [number, number, number]): string | null => {
+  if (level === 0) return '0';
+  const lower = level === 1 ? 1 : thresholds[level - 2] + 1;
+  if (level === 4) return `${lower}〜`;
+  const upper = thresholds[level - 1];
+  return lower <= upper ? `${lower}〜${upper}` : null;
 };
 
 // Format date for display
@@ -44,11 +92,28 @@ export const ContributionGraph: React.FC<ContributionGraphProps> = ({ githubStat
   const [calendarLoading, setCalendarLoading] = useState(true);
   const [calendarError, setCalendarError] = useState<string | null>(null);
 
+  // null = the live last-year calendar, otherwise a stored calendar year
+  const [selectedYear, setSelectedYear] = useState<number | null>(null);
+  const [history, setHistory] = useState<ContributionHistory | null>(null);
+  const [isBackfilling, setIsBackfilling] = useState(false);
+
+  // Anything but plain contributions is served by the heatmap command
+  const [metric, setMetric] = useState<HeatmapMetric>('contributions');
+  const [repository, setRepository] = useState<string | null>(null);
+  const [heatmap, setHeatmap] = useState<ActivityHeatmap | null>(null);
+  const [repositories, setRepositories] = useState<string[]>([]);
+  const useHeatmap = metric !== 'contributions' || repository !== null;
+  const metricInfo = METRICS.find((m) => m.metric === metric) ?? METRICS[0];
+
   const fetchCalendar = useCallback(async () => {
     setCalendarLoading(true);
     setCalendarError(null);
     try {
-      if (githubStats?.contributionCalendar) {
+      if (useHeatmap) {
+        setHeatmap(await github.getActivityHeatmap({ metric, year: selectedYear, repository }));
+      } else if (selectedYear !== null) {
+        setCalendar(await github.getContributionCalendarForYear(selectedYear));
+      } else if (githubStats?.contributionCalendar) {
         setCalendar(githubStats.contributionCalendar);
       } else {
         const data = await github.getContributionCalendar();
@@ -59,12 +124,48 @@ export const ContributionGraph: React.FC<ContributionGraphProps> = ({ githubStat
     } finally {
       setCalendarLoading(false);
     }
-  }, [githubStats]);
+  }, [githubStats, selectedYear, useHeatmap, metric, repository]);
+
+  // Repositories with commits in the selected range, for the filter
+  useEffect(() => {
+    github
+      .getActivityHeatmap({ metric: 'commits', year: selectedYear })
+      .then((result) => setRepositories(result.repositories))
+      .catch((e) => console.error('Failed to load heatmap repositories:', e));
+  }, [selectedYear, githubStats]);
+
+  const fetchHistory = useCallback(async () => {
+    try {
+      setHistory(await github.getContributionHistory());
+    } catch (e) {
+      // The graph still works without the stored history
+      console.error('Failed to load contribution history:', e);
+    }
+  }, []);
 
   useEffect(() => {
     fetchCalendar();
   }, [fetchCalendar]);
 
+  // Refresh the totals whenever new stats (and thus calendar days) arrive
+  useEffect(() => {
+    fetchHistory();
+  }, [fetchHistory, githubStats]);
+
+  const handleBackfill = async () => {
+    if (isBackfilling) return;
+    setIsBackfilling(true);
+    setSyncError(null);
+    try {
+      setHistory(await github.backfillContributionHistory());
+      if (selectedYear !== null) await fetchCalendar();
+    } catch (e) {
+      setSyncError(String(e));
+    } finally {
+      setIsBackfilling(false);
+    }
+  };
+
   const handleSync = async () => {
     if (isSyncing) return;
     setIsSyncing(true);
@@ -73,6 +174,7 @@ export const ContributionGraph: React.FC<ContributionGraphProps> = ({ githubStat
       await github.syncCodeStats(false);
       // Refresh calendar after sync
       await fetchCalendar();
+      await fetchHistory();
     } catch (e) {
       setSyncError(String(e));
     } finally {
@@ -89,18 +191,45 @@ export const ContributionGraph: React.FC<ContributionGraphProps> = ({ githubStat
     setHoveredDate(null);
   };
 
-  // Create a memoized map for efficient date lookup
-  const contributionMap = useMemo(() => {
-    if (!calendar) return new Map<string, ContributionDay>();
+  // Both sources rendered as normalised heatmap weeks
+  const weeks = useMemo<HeatmapDay[][] | null>(() => {
+    if (useHeatmap) return heatmap?.weeks ?? null;
+    if (!calendar) return null;
+    return calendar.weeks.map((week) =>
+      week.contributionDays.map((day) => ({
+        date: day.date,
+        value: day.contributionCount,
+        level: getIntensity(day.contributionCount),
+        additions: null,
+        deletions: null,
+      }))
+    );
+  }, [useHeatmap, heatmap, calendar]);
 
-    const map = new Map<string, ContributionDay>();
-    for (const week of calendar.weeks) {
-      for (const day of week.contributionDays) {
+  const thresholds = useHeatmap && heatmap ? heatmap.thresholds : CALENDAR_THRESHOLDS;
+  const total = useHeatmap ? (heatmap?.total ?? 0) : (calendar?.totalContributions ?? 0);
+
+  // Create a memoized map for efficient date lookup
+  const dayMap = useMemo(() => {
+    const map = new Map<string, HeatmapDay>();
+    for (const week of weeks ?? []) {
+      for (const day of week) {
         map.set(day.date, day);
       }
     }
     return map;
-  }, [calendar]);
+  }, [weeks]);
+
+  const formatValue = (day: HeatmapDay | undefined): string => {
+    if (!day) return `0 ${metricInfo.unit}`;
+    if (day.additions !== null && day.deletions !== null) {
+      const net = day.additions - day.deletions;
+      return `+${day.additions.toLocaleString()} / −${day.deletions.toLocaleString()}（差分 ${
+        net >= 0 ? '+' : ''
+      }${net.toLocaleString()}）`;
+    }
+    return `${day.value.toLocaleString()} ${metricInfo.unit}`;
+  };
 
   const getBgClass = (intensity: number): string => {
     switch (intensity) {
@@ -122,7 +251,75 @@ export const ContributionGraph: React.FC<ContributionGraphProps> = ({ githubStat
       <div className="flex items-center justify-between mb-4">
         <h3 className="text-xl font-gaming font-bold text-gm-success">📈 Contribution Graph</h3>
 
-        <div className="flex items-center gap-4">
+        <div className="flex flex-wrap items-center justify-end gap-2">
+          {/* Metric selector */}
+          <select
+            value={metric}
+            onChange={(e) => {
+              const next = e.target.value as HeatmapMetric;
+              setMetric(next);
+              // Only lines and commits are stored per repository
+              if (!METRICS.find((m) => m.metric === next)?.byRepository) setRepository(null);
+            }}
+            aria-label="表示する指標"
+            className="px-2 py-1 text-sm bg-gm-bg-secondary/50 border border-gm-success/30 rounded-lg text-white"
+          >
+            {METRICS.map((option) => (
+              <option key={option.metric} value={option.metric}>
+                {option.label}
+              </option>
+            ))}
+          </select>
+
+          {/* Repository filter */}
+          <select
+            value={repository ?? ''}
+            onChange={(e) => setRepository(e.target.value === '' ? null : e.target.value)}
+            aria-label="リポジトリで絞り込み"
+            disabled={!metricInfo.byRepository}
+            title={
+              metricInfo.byRepository
+                ? undefined
+                : 'リポジトリ別の表示は変更行数とコミットのみ対応しています'
+            }
+            className="disabled:opacity-50 max-w-48 px-2 py-1 text-sm bg-gm-bg-secondary/50 border border-gm-success/30 rounded-lg text-white"
+          >
+            <option value="">すべてのリポジトリ</option>
+            {repository !== null && !repositories.includes(repository) && (
+              <option value={repository}>{repository}</option>
+            )}
+            {repositories.map((name) => (
+              <option key={name} value={name}>
+                {name}
+              </option>
+            ))}
+          </select>
+
+          {/* Year selector */}
+          <select
+            value={selectedYear ?? ''}
+            onChange={(e) => setSelectedYear(e.target.value === '' ? null : Number(e.target.value))}
+            aria-label="表示する年"
+            className="px-2 py-1 text-sm bg-gm-bg-secondary/50 border border-gm-success/30 rounded-lg text-white"
+          >
+            <option value="">直近1年</option>
+            {history?.years.map((summary) => (
+              <option key={summary.year} value={summary.year}>
+                {summary.year}年
+              </option>
+            ))}
+          </select>
+
+          {/* Backfill button */}
+          <button
+            className="px-3 py-1 text-sm rounded-lg bg-gm-bg-secondary/50 hover:bg-gm-bg-secondary text-gm-text-secondary hover:text-gm-text-primary transition-all disabled:opacity-50 disabled:cursor-not-allowed"
+            onClick={handleBackfill}
+            disabled={isBackfilling}
+            title="1年より前のコントリビューションを GitHub から取得して保存します"
+          >
+            {isBackfilling ? '取得中...' : '過去の履歴を取得'}
+          </button>
+
           {/* Sync button */}
           <button
             className={`p-2 rounded-lg transition-all ${
@@ -163,25 +360,20 @@ export const ContributionGraph: React.FC<ContributionGraphProps> = ({ githubStat
       )}
 
       {/* Contribution calendar */}
-      {calendar && !calendarLoading && (
+      {weeks && !calendarLoading && (
         <>
           <div className="flex gap-1 min-w-fit overflow-x-auto pb-2">
-            {calendar.weeks.map((week, wi) => (
+            {weeks.map((week, wi) => (
               <div key={wi} className="flex flex-col gap-1">
-                {week.contributionDays.map((day, di) => {
-                  const intensity = getIntensity(day.contributionCount);
-                  const bgClass = getBgClass(intensity);
-
-                  return (
-                    <div
-                      key={di}
-                      className={`w-3 h-3 rounded-sm ${bgClass} hover:ring-2 hover:ring-gm-accent-cyan transition-all cursor-pointer`}
-                      onMouseEnter={(e) => handleMouseEnter(e, day.date)}
-                      onMouseLeave={handleMouseLeave}
-                      title={`${day.date}: ${day.contributionCount} contributions`}
-                    />
-                  );
-                })}
+                {week.map((day, di) => (
+                  <div
+                    key={di}
+                    className={`w-3 h-3 rounded-sm ${getBgClass(day.level)} hover:ring-2 hover:ring-gm-accent-cyan transition-all cursor-pointer`}
+                    onMouseEnter={(e) => handleMouseEnter(e, day.date)}
+                    onMouseLeave={handleMouseLeave}
+                    title={`${day.date}: ${formatValue(day)}`}
+                  />
+                ))}
               </div>
             ))}
           </div>
@@ -191,18 +383,35 @@ export const ContributionGraph: React.FC<ContributionGraphProps> = ({ githubStat
             <div className="flex items-center gap-2 text-xs text-dt-text-sub">
               <span>Less</span>
               <div className="flex gap-1">
-                <div className="w-3 h-3 rounded-sm bg-gm-bg-secondary"></div>
-                <div className="w-3 h-3 rounded-sm bg-gm-success/20"></div>
-                <div className="w-3 h-3 rounded-sm bg-gm-success/40"></div>
-                <div className="w-3 h-3 rounded-sm bg-gm-success/60"></div>
-                <div className="w-3 h-3 rounded-sm bg-gm-success"></div>
+                {[0, 1, 2, 3, 4].map((level) => {
+                  const range = levelRange(level, thresholds);
+                  return (
+                    <div
+                      key={level}
+                      className={`w-3 h-3 rounded-sm ${getBgClass(level)}`}
+                      title={range === null ? undefined : `${range} ${metricInfo.unit}`}
+                    ></div>
+                  );
+                })}
               </div>
               <span>More</span>
             </div>
             <div className="text-xs text-dt-text-sub">
-              {calendar.totalContributions} contributions in the last year
+              {total.toLocaleString()} {metricInfo.unit}{' '}
+              {selectedYear === null ? 'in the last year' : `in ${selectedYear}`}
+              {repository !== null && `（${repository} の分のみ・リポジトリ別の記録がある日）`}
             </div>
           </div>
+
+          {/* Lifetime totals from the stored history */}
+          {history && history.years.length > 0 && (
+            <div className="mt-2 text-xs text-dt-text-sub text-right">
+              生涯 {history.lifetimeContributions.toLocaleString()} contributions ・{' '}
+              {history.lifetimeActiveDays.toLocaleString()} 日（
+              {history.years[history.years.length - 1].year}年〜）
+              {history.backfilledAt === null && ' ※ 過去の履歴は未取得です'}
+            </div>
+          )}
         </>
       )}
 
@@ -216,9 +425,7 @@ export const ContributionGraph: React.FC<ContributionGraphProps> = ({ githubStat
           }}
         >
           <div className="text-sm text-white font-medium">{formatDate(hoveredDate)}</div>
-          <div className="text-xs text-dt-text-sub">
-            {contributionMap.get(hoveredDate)?.contributionCount ?? 0} contributions
-          </div>
+          <div className="text-xs text-dt-text-sub">{formatValue(dayMap.get(hoveredDate))}</div>
         </div>
       )}
     </div>
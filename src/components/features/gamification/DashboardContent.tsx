@@ -15,6 +15,7 @@ import { useNavigate } from 'react-router-dom';
 import { ProfileCard } from './ProfileCard';
 import { StatsDisplay } from './StatsDisplay';
 import { ChallengeCard } from './ChallengeCard';
+import { SeasonCard } from './SeasonCard';
 import { BadgeGrid } from './BadgeGrid';
 import { ContributionGraph } from './ContributionGraph';
 import { LanguageBreakdownCard } from './LanguageBreakdownCard';
@@ -149,6 +150,9 @@ export const DashboardContent: React.FC<DashboardContentProps> = ({
         </Suspense>
       </div>
 
+      {/* Seasons (loads on its own) */}
+      <SeasonCard />
+
       {/* Badges */}
       <Suspense fallback={<BadgeGridSkeleton />}>
         <BadgeGrid />
@@ -9,6 +9,8 @@
  * the backend. The component is purely presentational: it expects a
  * resolved payload and renders skeletons / empty states locally.
  *
+ * Clicking a repository opens its drill-down (`RepositoryDetailModal`).
+ *
  * Charts are SVG-based on purpose: this app has no chart library
  * dependency and the dataset is small (≤ 10 languages, ≤ 10 repos shown)
  * so handrolled SVG keeps the bundle lean.
@@ -17,7 +19,8 @@
  *   - Issue: https://github.com/otomatty/development-tools/issues/193
  */
 
-import React, { useMemo } from 'react';
+import React, { useMemo, useState } from 'react';
+import { RepositoryDetailModal } from './RepositoryDetailModal';
 import type {
   LanguageBreakdownResponse,
   LanguageStats,
@@ -271,7 +274,10 @@ const LanguagePie: React.FC<{ data: LanguageBreakdownResponse }> = ({ data }) =>
   );
 };
 
-const RepositoryBars: React.FC<{ repositories: RepositoryCodeStats[] }> = ({ repositories }) => {
+const RepositoryBars: React.FC<{
+  repositories: RepositoryCodeStats[];
+  onSelect: (nameWithOwner: string) => void;
+}> = ({ repositories, onSelect }) => {
   const visible = repositories.slice(0, MAX_REPOS_SHOWN);
   const peakChurn = useMemo(() => {
     return visible.reduce((max, r) => Math.max(max, r.additions + r.deletions), 0);
@@ -313,21 +319,14 @@ const RepositoryBars: React.FC<{ repositories: RepositoryCodeStats[] }> = ({ rep
                       aria-label={`主要言語: ${repo.primaryLanguage}`}
                     />
                   )}
-                  {repo.url ? (
-                    <a
-                      href={repo.url}
-                      target="_blank"
-                      rel="noreferrer noopener"
-                      className="truncate text-dt-text-main hover:text-gm-accent-cyan transition-colors"
-                      title={repo.nameWithOwner}
-                    >
-                      {repo.nameWithOwner}
-                    </a>
-                  ) : (
-                    <span className="truncate text-dt-text-main" title={repo.nameWithOwner}>
-                      {repo.nameWithOwner}
-                    </span>
-                  )}
+                  <button
+                    type="button"
+                    onClick={() => onSelect(repo.nameWithOwner)}
+                    className="truncate text-left text-dt-text-main hover:text-gm-accent-cyan transition-colors"
+                    title={`${repo.nameWithOwner} の詳細を表示`}
+                  >
+                    {repo.nameWithOwner}
+                  </button>
                 </span>
                 <span className="text-xs text-dt-text-sub font-gaming-mono whitespace-nowrap">
                   <span className="text-emerald-400">+{formatLines(repo.additions)}</span>
@@ -369,6 +368,8 @@ export const LanguageBreakdownCard: React.FC<LanguageBreakdownCardProps> = ({
   error,
   fromCache,
 }) => {
+  const [selectedRepository, setSelectedRepository] = useState<string | null>(null);
+
   if (isLoading && data === null && error === null) {
     return <SkeletonView />;
   }
@@ -406,8 +407,13 @@ export const LanguageBreakdownCard: React.FC<LanguageBreakdownCardProps> = ({
 
       <div className="grid grid-cols-1 lg:grid-cols-[14rem_1fr] gap-6">
         <LanguagePie data={data} />
-        <RepositoryBars repositories={data.repositories} />
+        <RepositoryBars repositories={data.repositories} onSelect={setSelectedRepository} />
       </div>
+
+      <RepositoryDetailModal
+        nameWithOwner={selectedRepository}
+        onClose={() => setSelectedRepository(null)}
+      />
     </div>
   );
 };
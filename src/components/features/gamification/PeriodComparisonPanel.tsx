@@ -0,0 +1,256 @@
+/**
+ * Period Comparison Panel
+ *
+ * Period-over-period report on the home page: this week vs last
+ * week, this month vs last month or the same month last year, or two
+ * custom ranges. Shows every metric with its delta and percentage change.
+ *
+ * Backed by `gamification.getPeriodComparison`, which reads local
+ * snapshots, code stats and XP history only (no GitHub requests).
+ * Snapshot-based metrics are shown as "—" when the app did not sync
+ * around the period.
+ *
+ * Related Documentation:
+ *   - Backend: src-tauri/src/commands/period_comparison.rs
+ */
+
+import React, { useEffect, useState } from 'react';
+import { gamification } from '../../../lib/tauri/commands';
+import type {
+  ComparisonMetric,
+  ComparisonPreset,
+  MetricComparison,
+  PeriodComparison,
+  PeriodComparisonRequest,
+  PeriodRange,
+} from '../../../types';
+
+const PRESETS: Array<{ preset: ComparisonPreset; label: string }> = [
+  { preset: 'week_over_week', label: '先週比' },
+  { preset: 'month_over_month', label: '先月比' },
+  { preset: 'month_year_over_year', label: '前年同月比' },
+  { preset: 'custom', label: 'カスタム' },
+];
+
+const METRIC_LABELS: Record<ComparisonMetric, string> = {
+  commits: 'コミット',
+  pullRequests: 'PR 作成',
+  pullRequestsMerged: 'PR マージ',
+  reviews: 'レビュー',
+  issues: 'Issue 作成',
+  issuesClosed: 'Issue クローズ',
+  starsReceived: '獲得スター',
+  contributions: 'コントリビューション',
+  additions: '追加行',
+  deletions: '削除行',
+  netChange: '純増減行',
+  activeDays: '活動日数',
+  xp: 'XP',
+};
+
+// Metrics where a decrease is not a regression
+const NEUTRAL_METRICS: ComparisonMetric[] = ['deletions', 'netChange'];
+
+function formatRange(range: PeriodRange): string {
+  const short = (date: string) => date.slice(5).replace('-', '/');
+  return range.start === range.end
+    ? short(range.start)
+    : `${short(range.start)}〜${short(range.end)}`;
+}
+
+function formatDelta(value: number): string {
+  if (value > 0) return `+${value.toLocaleString()}`;
+  if (value < 0) return `−${Math.abs(value).toLocaleString()}`;
+  return '±0';
+}
+
+function formatPercent(value: number): string {
+  const rounded = Math.abs(value) < 10 ? value.toFixed(1) : Math.round(value).toString();
+  return `${value > 0 ? '+' : ''}${rounded}%`;
+}
+
+const MetricRow: React.FC<{ comparison: MetricComparison }> = ({ comparison }) => {
+  const { metric, current, previous, delta, percentChange } = comparison;
+  const tone =
+    delta === null || delta === 0 || NEUTRAL_METRICS.includes(metric)
+      ? 'text-dt-text-sub'
+      : delta > 0
+        ? 'text-emerald-400'
+        : 'text-rose-400';
+
+  return (
+    <tr className="border-t border-slate-700/50">
+      <td className="py-2 pr-3 text-dt-text-main">{METRIC_LABELS[metric] ?? metric}</td>
+      <td className="py-2 px-3 text-right font-gaming-mono text-white">
+        {current === null ? '—' : current.toLocaleString()}
+      </td>
+      <td className="py-2 px-3 text-right font-gaming-mono text-dt-text-sub">
+        {previous === null ? '—' : previous.toLocaleString()}
+      </td>
+      <td className={`py-2 pl-3 text-right font-gaming-mono ${tone}`}>
+        {delta === null ? '—' : formatDelta(delta)}
+        {percentChange !== null && (
+          <span className="ml-1 text-xs">({formatPercent(percentChange)})</span>
+        )}
+      </td>
+    </tr>
+  );
+};
+
+const RangeInputs: React.FC<{
+  label: string;
+  range: PeriodRange;
+  onChange: (range: PeriodRange) => void;
+}> = ({ label, range, onChange }) => (
+  <div className="flex flex-wrap items-center gap-2 text-sm">
+    <span className="w-16 text-dt-text-sub">{label}</span>
+    <input
+      type="date"
+      value={range.start}
+      aria-label={`${label}の開始日`}
+      onChange={(e) => onChange({ ...range, start: e.target.value })}
+      className="px-2 py-1 bg-gm-bg-primary border border-gm-accent-cyan/30 rounded-lg text-white"
+    />
+    <span className="text-dt-text-sub">〜</span>
+    <input
+      type="date"
+      value={range.end}
+      aria-label={`${label}の終了日`}
+      onChange={(e) => onChange({ ...range, end: e.target.value })}
+      className="px-2 py-1 bg-gm-bg-primary border border-gm-accent-cyan/30 rounded-lg text-white"
+    />
+  </div>
+);
+
+export const PeriodComparisonPanel: React.FC = () => {
+  const [preset, setPreset] = useState<ComparisonPreset>('week_over_week');
+  const [customCurrent, setCustomCurrent] = useState<PeriodRange>({ start: '', end: '' });
+  const [customPrevious, setCustomPrevious] = useState<PeriodRange>({ start: '', end: '' });
+  // Custom ranges are only requested when applied, not on every keystroke
+  const [request, setRequest] = useState<PeriodComparisonRequest | null>({
+    preset: 'week_over_week',
+  });
+  const [report, setReport] = useState<PeriodComparison | null>(null);
+  const [isLoading, setIsLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (request === null) return;
+    let cancelled = false;
+    setIsLoading(true);
+    setError(null);
+    gamification
+      .getPeriodComparison(request)
+      .then((result) => {
+        if (!cancelled) setReport(result);
+      })
+      .catch((e) => {
+        if (!cancelled) setError(String(e));
+      })
+      .finally(() => {
+        if (!cancelled) setIsLoading(false);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [request]);
+
+  const selectPreset = (next: ComparisonPreset) => {
+    setPreset(next);
+    // Custom waits for the date inputs; keep the previous report meanwhile
+    setRequest(next === 'custom' ? null : { preset: next });
+  };
+
+  const customReady =
+    customCurrent.start !== '' &&
+    customCurrent.end !== '' &&
+    customPrevious.start !== '' &&
+    customPrevious.end !== '';
+
+  const applyCustom = () => {
+    setRequest({ preset: 'custom', current: customCurrent, previous: customPrevious });
+  };
+
+  return (
+    <div className="p-6 bg-gm-bg-card/80 backdrop-blur-sm rounded-2xl border border-gm-accent-cyan/20">
+      <div className="flex flex-wrap items-center justify-between gap-3 mb-4">
+        <h3 className="text-xl font-gaming font-bold text-gm-accent-cyan">📊 期間比較</h3>
+        <div className="flex p-1 bg-gm-bg-card/50 rounded-lg border border-gm-accent-cyan/20">
+          {PRESETS.map((option) => (
+            <button
+              key={option.preset}
+              type="button"
+              className={`px-3 py-1 text-sm rounded-md font-gaming transition-colors ${
+                preset === option.preset
+                  ? 'bg-gm-accent-cyan/30 text-white'
+                  : 'text-dt-text-sub hover:text-white'
+              }`}
+              onClick={() => selectPreset(option.preset)}
+            >
+              {option.label}
+            </button>
+          ))}
+        </div>
+      </div>
+
+      {preset === 'custom' && (
+        <div className="mb-4 space-y-2 p-3 bg-gm-bg-card/50 rounded-lg border border-slate-700/50">
+          <RangeInputs label="今期" range={customCurrent} onChange={setCustomCurrent} />
+          <RangeInputs label="比較期間" range={customPrevious} onChange={setCustomPrevious} />
+          <div className="flex justify-end">
+            <button
+              type="button"
+              disabled={!customReady || isLoading}
+              onClick={applyCustom}
+              className="px-4 py-1.5 text-sm rounded-lg bg-gm-accent-cyan/30 text-white hover:bg-gm-accent-cyan/40 transition-colors disabled:opacity-50"
+            >
+              比較する
+            </button>
+          </div>
+        </div>
+      )}
+
+      {error && (
+        <div className="mb-4 p-3 bg-red-900/30 border border-red-500/50 rounded-lg text-red-200 text-sm">
+          期間比較を読み込めませんでした: {error}
+        </div>
+      )}
+
+      {!report && isLoading && (
+        <div className="space-y-2 animate-pulse">
+          <div className="h-6 bg-slate-700 rounded"></div>
+          <div className="h-40 bg-slate-700 rounded"></div>
+        </div>
+      )}
+
+      {report && (
+        <div className={isLoading ? 'opacity-60' : ''}>
+          <table className="w-full text-sm">
+            <thead>
+              <tr className="text-xs text-dt-text-sub">
+                <th className="pb-2 pr-3 text-left font-normal">指標</th>
+                <th className="pb-2 px-3 text-right font-normal">
+                  今期
+                  <span className="block font-gaming-mono">{formatRange(report.current)}</span>
+                </th>
+                <th className="pb-2 px-3 text-right font-normal">
+                  比較期間
+                  <span className="block font-gaming-mono">{formatRange(report.previous)}</span>
+                </th>
+                <th className="pb-2 pl-3 text-right font-normal">増減</th>
+              </tr>
+            </thead>
+            <tbody>
+              {report.metrics.map((comparison) => (
+                <MetricRow key={comparison.metric} comparison={comparison} />
+              ))}
+            </tbody>
+          </table>
+          <p className="mt-3 text-xs text-dt-text-sub">
+            ※ 日付は UTC 基準です。コミットや PR などは同期時のスナップショットの差分のため、期間の前後に同期していない場合は「—」と表示されます
+          </p>
+        </div>
+      )}
+    </div>
+  );
+};
@@ -2,7 +2,7 @@
  * Profile Card Component
  *
  * React implementation of ProfileCard component.
- * Displays user profile, level, and XP progress.
+ * Displays user profile, level, prestige rank, and XP progress.
  *
  * Related Documentation:
  *   - Issue: https://github.com/otomatty/development-tools/issues/138
@@ -72,6 +72,13 @@ export const ProfileCard: React.FC<ProfileCardProps> = ({ levelInfo, userStats }
               {user?.username || 'User'}
             </h2>
 
+            {/* Prestige rank (cosmetic title + permanent XP bonus) */}
+            {levelInfo?.prestigeTitle && (
+              <div className="inline-flex items-center gap-1 px-2 py-0.5 rounded-md bg-gm-accent-purple/20 border border-gm-accent-purple/40 text-xs text-gm-accent-purple font-gaming">
+                🌀 {levelInfo.prestigeTitle} · XP x{levelInfo.xpMultiplier.toFixed(2)}
+              </div>
+            )}
+
             {/* XP Progress Bar */}
             {levelInfo && (
               <div className="space-y-1">
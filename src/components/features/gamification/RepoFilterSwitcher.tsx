@@ -0,0 +1,87 @@
+/**
+ * Repository Filter Switcher Component
+ *
+ * React implementation of RepoFilterSwitcher component.
+ * Compact "all / work only / personal only" switcher shown above the
+ * dashboard. Switching saves the repository filter preset; the full
+ * filter is edited in Settings → リポジトリフィルター.
+ *
+ * Related Documentation:
+ *   - Original (Leptos): ./repo_filter_switcher.rs
+ */
+
+import React, { useEffect, useState } from 'react';
+import { settings as settingsApi } from '../../../lib/tauri/commands';
+import {
+  REPO_FILTER_PRESETS,
+  repoFilterPresetDescription,
+  repoFilterPresetLabel,
+  type RepoFilterPreset,
+} from '../../../types';
+
+interface RepoFilterSwitcherProps {
+  /** Runs after the new preset is saved (the backend drops the filtered caches) */
+  onChange: () => void;
+}
+
+export const RepoFilterSwitcher: React.FC<RepoFilterSwitcherProps> = ({ onChange }) => {
+  const [preset, setPreset] = useState<RepoFilterPreset | null>(null);
+  const [saving, setSaving] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  // Hidden until the saved preset is known
+  useEffect(() => {
+    let cancelled = false;
+    settingsApi
+      .getRepositoryFilter()
+      .then((filter) => {
+        if (!cancelled) setPreset(filter.preset);
+      })
+      .catch((e) => console.error('Failed to load repository filter:', e));
+    return () => {
+      cancelled = true;
+    };
+  }, []);
+
+  const select = async (next: RepoFilterPreset) => {
+    if (saving || preset === next) return;
+    setSaving(true);
+    setError(null);
+    try {
+      const filter = await settingsApi.setRepositoryFilterPreset(next);
+      setPreset(filter.preset);
+      onChange();
+    } catch (e) {
+      setError(`切り替えに失敗しました: ${e}`);
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  if (preset === null) return null;
+
+  return (
+    <div className="flex flex-wrap items-center justify-end gap-3 mb-4">
+      {error && <span className="text-xs text-red-300">{error}</span>}
+      <span className="text-xs text-dt-text-sub">集計対象</span>
+      <div className="flex p-1 bg-gm-bg-card/50 rounded-lg border border-gm-accent-cyan/20">
+        {REPO_FILTER_PRESETS.map((option) => (
+          <button
+            key={option}
+            type="button"
+            title={repoFilterPresetDescription(option)}
+            disabled={saving}
+            className={`px-3 py-1 text-sm rounded-md font-gaming transition-colors disabled:opacity-50 ${
+              preset === option
+                ? 'bg-gm-accent-cyan/30 text-white'
+                : 'text-dt-text-sub hover:text-white'
+            }`}
+            onClick={() => void select(option)}
+          >
+            {repoFilterPresetLabel(option)}
+          </button>
+        ))}
+      </div>
+    </div>
+  );
+};
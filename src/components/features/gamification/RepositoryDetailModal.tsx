@@ -0,0 +1,298 @@
+/**
+ * Repository Detail Modal
+ *
+ * Per-repository drill-down opened from the repository bars of
+ * the language / repository breakdown card: the user's commits per week,
+ * additions / deletions, PRs opened / merged, reviews given, issues,
+ * languages and their share of the repository's commits over 30 / 90 /
+ * 365 days.
+ *
+ * Backed by `github.getRepositoryDetailWithCache` (1-hour cache per
+ * repository and window). Charts are handrolled like the breakdown card.
+ *
+ * Related Documentation:
+ *   - Backend: src-tauri/src/commands/github.rs (`get_repository_detail_with_cache`)
+ */
+
+import React, { useEffect, useMemo, useState } from 'react';
+import { Modal, ModalHeader, ModalBody } from '../../ui/dialog';
+import { github } from '../../../lib/tauri/commands';
+import type { RepositoryDetail, RepositoryDetailDays } from '../../../types';
+
+interface RepositoryDetailModalProps {
+  /** `owner/name` of the repository to show; `null` closes the modal */
+  nameWithOwner: string | null;
+  onClose: () => void;
+}
+
+const WINDOWS: Array<{ days: RepositoryDetailDays; label: string }> = [
+  { days: 30, label: '30日' },
+  { days: 90, label: '90日' },
+  { days: 365, label: '1年' },
+];
+
+const FALLBACK_LANGUAGE_COLOR = '#64748b';
+
+function formatLines(value: number): string {
+  if (value < 1000) return value.toString();
+  if (value < 1_000_000) return `${(value / 1000).toFixed(1)}k`;
+  return `${(value / 1_000_000).toFixed(2)}M`;
+}
+
+function formatPercent(value: number): string {
+  if (value <= 0) return '0%';
+  if (value < 0.001) return '<0.1%';
+  return `${(value * 100).toFixed(1)}%`;
+}
+
+const StatTile: React.FC<{ label: string; value: string; accent?: string }> = ({
+  label,
+  value,
+  accent = 'text-white',
+}) => (
+  <div className="p-3 bg-gm-bg-card/50 rounded-lg border border-slate-700/50">
+    <div className="text-xs text-dt-text-sub">{label}</div>
+    <div className={`text-xl font-gaming-mono font-bold ${accent}`}>{value}</div>
+  </div>
+);
+
+const CommitTimeline: React.FC<{ detail: RepositoryDetail }> = ({ detail }) => {
+  const peak = useMemo(
+    () => detail.commitTimeline.reduce((max, week) => Math.max(max, week.commits), 0),
+    [detail.commitTimeline]
+  );
+
+  if (peak === 0) {
+    return (
+      <div className="flex items-center justify-center h-24 text-sm text-dt-text-sub">
+        この期間のコミットはありません
+      </div>
+    );
+  }
+
+  return (
+    <div>
+      <div className="flex items-end gap-px h-24" role="img" aria-label="週ごとのコミット数">
+        {detail.commitTimeline.map((week) => (
+          <div
+            key={week.weekStart}
+            className="flex-1 bg-gm-accent-cyan/70 rounded-t-sm min-h-[1px]"
+            style={{ height: `${(week.commits / peak) * 100}%` }}
+            title={`${week.weekStart} 週: ${week.commits} コミット（+${week.additions} / −${week.deletions}）`}
+          />
+        ))}
+      </div>
+      <div className="flex justify-between mt-1 text-xs text-dt-text-sub font-gaming-mono">
+        <span>{detail.commitTimeline[0]?.weekStart}</span>
+        <span>最大 {peak} コミット / 週</span>
+      </div>
+    </div>
+  );
+};
+
+const LanguageBar: React.FC<{ detail: RepositoryDetail }> = ({ detail }) => {
+  if (detail.languages.length === 0) {
+    return <div className="text-sm text-dt-text-sub">言語情報がありません</div>;
+  }
+
+  return (
+    <div className="space-y-2">
+      <div className="flex h-2 rounded-full overflow-hidden bg-slate-800/60">
+        {detail.languages.map((language) => (
+          <div
+            key={language.name}
+            style={{
+              width: `${language.percentage * 100}%`,
+              backgroundColor: language.color ?? FALLBACK_LANGUAGE_COLOR,
+            }}
+            title={`${language.name} ${formatPercent(language.percentage)}`}
+          />
+        ))}
+      </div>
+      <ul className="flex flex-wrap gap-x-4 gap-y-1 text-xs text-dt-text-sub">
+        {detail.languages.map((language) => (
+          <li key={language.name} className="flex items-center gap-1.5">
+            <span
+              className="inline-block w-2.5 h-2.5 rounded-full"
+              style={{ backgroundColor: language.color ?? FALLBACK_LANGUAGE_COLOR }}
+              aria-hidden="true"
+            />
+            <span className="text-dt-text-main">{language.name}</span>
+            <span className="font-gaming-mono">{formatPercent(language.percentage)}</span>
+          </li>
+        ))}
+      </ul>
+    </div>
+  );
+};
+
+export const RepositoryDetailModal: React.FC<RepositoryDetailModalProps> = ({
+  nameWithOwner,
+  onClose,
+}) => {
+  const [days, setDays] = useState<RepositoryDetailDays>(90);
+  const [detail, setDetail] = useState<RepositoryDetail | null>(null);
+  const [fromCache, setFromCache] = useState(false);
+  const [isLoading, setIsLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (nameWithOwner === null) {
+      setDetail(null);
+      setError(null);
+      return;
+    }
+    let cancelled = false;
+    setIsLoading(true);
+    setError(null);
+    github
+      .getRepositoryDetailWithCache(nameWithOwner, days)
+      .then((response) => {
+        if (cancelled) return;
+        setDetail(response.data);
+        setFromCache(response.fromCache);
+      })
+      .catch((e) => {
+        if (!cancelled) setError(String(e));
+      })
+      .finally(() => {
+        if (!cancelled) setIsLoading(false);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [nameWithOwner, days]);
+
+  // Keep showing the previous window while the next one loads
+  const shown =
+    detail !== null && detail.nameWithOwner.toLowerCase() === nameWithOwner?.toLowerCase()
+      ? detail
+      : null;
+
+  return (
+    <Modal visible={nameWithOwner !== null} onClose={onClose} size="full">
+      <ModalHeader onClose={onClose}>
+        <div className="flex items-center gap-2 min-w-0">
+          {shown?.url ? (
+            <a
+              href={shown.url}
+              target="_blank"
+              rel="noreferrer noopener"
+              className="truncate text-white hover:text-gm-accent-cyan transition-colors"
+            >
+              {nameWithOwner}
+            </a>
+          ) : (
+            <span className="truncate text-white">{nameWithOwner}</span>
+          )}
+          {shown?.isPrivate && (
+            <span className="text-xs px-2 py-0.5 rounded-full bg-slate-800/80 text-dt-text-sub">
+              Private
+            </span>
+          )}
+          {shown?.isFork && (
+            <span className="text-xs px-2 py-0.5 rounded-full bg-slate-800/80 text-dt-text-sub">
+              Fork
+            </span>
+          )}
+          {shown?.isArchived && (
+            <span className="text-xs px-2 py-0.5 rounded-full bg-slate-800/80 text-dt-text-sub">
+              Archived
+            </span>
+          )}
+        </div>
+      </ModalHeader>
+      <ModalBody className="space-y-6">
+        <div className="flex flex-wrap items-center justify-between gap-3">
+          <p className="text-sm text-dt-text-sub min-w-0">{shown?.description ?? ''}</p>
+          <div className="flex items-center gap-3">
+            {fromCache && shown && (
+              <span
+                className="text-xs text-dt-text-sub px-2 py-0.5 rounded-full bg-slate-800/80"
+                title="キャッシュから表示中"
+              >
+                キャッシュ
+              </span>
+            )}
+            <div className="flex p-1 bg-gm-bg-card/50 rounded-lg border border-gm-accent-cyan/20">
+              {WINDOWS.map((option) => (
+                <button
+                  key={option.days}
+                  type="button"
+                  disabled={isLoading}
+                  className={`px-3 py-1 text-sm rounded-md font-gaming transition-colors disabled:opacity-50 ${
+                    days === option.days
+                      ? 'bg-gm-accent-cyan/30 text-white'
+                      : 'text-dt-text-sub hover:text-white'
+                  }`}
+                  onClick={() => setDays(option.days)}
+                >
+                  {option.label}
+                </button>
+              ))}
+            </div>
+          </div>
+        </div>
+
+        {error && (
+          <div className="p-3 bg-red-900/30 border border-red-500/50 rounded-lg text-red-200 text-sm">
+            リポジトリの統計を読み込めませんでした: {error}
+          </div>
+        )}
+
+        {!shown && isLoading && (
+          <div className="space-y-3 animate-pulse">
+            <div className="h-20 bg-slate-700 rounded-lg"></div>
+            <div className="h-24 bg-slate-700 rounded-lg"></div>
+          </div>
+        )}
+
+        {shown && (
+          <div className={`space-y-6 ${isLoading ? 'opacity-60' : ''}`}>
+            <div className="grid grid-cols-2 sm:grid-cols-4 gap-3">
+              <StatTile label="コミット" value={shown.commitsCount.toLocaleString()} />
+              <StatTile
+                label="追加行"
+                value={`+${formatLines(shown.additions)}`}
+                accent="text-emerald-400"
+              />
+              <StatTile
+                label="削除行"
+                value={`−${formatLines(shown.deletions)}`}
+                accent="text-rose-400"
+              />
+              <StatTile
+                label="コミットの割合"
+                value={formatPercent(shown.contributionShare)}
+                accent="text-gm-accent-cyan"
+              />
+              <StatTile label="PR 作成" value={shown.pullRequestsOpened.toLocaleString()} />
+              <StatTile label="PR マージ" value={shown.pullRequestsMerged.toLocaleString()} />
+              <StatTile label="レビューした PR" value={shown.reviewsGiven.toLocaleString()} />
+              <StatTile
+                label="Issue 作成 / クローズ"
+                value={`${shown.issuesOpened} / ${shown.issuesClosed}`}
+              />
+            </div>
+
+            <div className="space-y-2">
+              <h4 className="text-sm font-semibold text-dt-text-main">週ごとのコミット</h4>
+              <CommitTimeline detail={shown} />
+              <p className="text-xs text-dt-text-sub">
+                デフォルトブランチへの自分のコミット（全体 {shown.totalCommits.toLocaleString()} 件中{' '}
+                {shown.commitsCount.toLocaleString()} 件）
+                {shown.commitsTruncated &&
+                  '。コミットが多いため、追加・削除行数とグラフは最新 1,000 件の集計です'}
+              </p>
+            </div>
+
+            <div className="space-y-2">
+              <h4 className="text-sm font-semibold text-dt-text-main">言語</h4>
+              <LanguageBar detail={shown} />
+            </div>
+          </div>
+        )}
+      </ModalBody>
+    </Modal>
+  );
+};
@@ -0,0 +1,139 @@
+/**
+ * Season Card Component
+ *
+ * React implementation of SeasonCard component.
+ * Displays the current season's XP and level (tracked separately from
+ * lifetime XP and reset every quarter) together with archived seasons.
+ *
+ * Related Documentation:
+ *   - Original (Leptos): ./season_card.rs
+ */
+
+import React, { useEffect, useState } from 'react';
+import { gamification } from '../../../lib/tauri/commands';
+import type { BadgeDefinition, SeasonArchive, SeasonInfo } from '../../../types';
+
+// Number of archived seasons shown on the card
+const SEASON_HISTORY_LIMIT = 4;
+
+export const SeasonCard: React.FC = () => {
+  const [season, setSeason] = useState<SeasonInfo | null>(null);
+  const [history, setHistory] = useState<SeasonArchive[]>([]);
+  const [definitions, setDefinitions] = useState<BadgeDefinition[]>([]);
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+
+  // Load the season first: it archives an ended season, so the history
+  // request below already includes it
+  useEffect(() => {
+    let cancelled = false;
+
+    const load = async () => {
+      try {
+        const info = await gamification.getSeasonInfo();
+        if (!cancelled) setSeason(info);
+      } catch (e) {
+        console.error('Failed to load season:', e);
+        if (!cancelled) setError(String(e));
+      }
+
+      const [historyResult, definitionsResult] = await Promise.allSettled([
+        gamification.getSeasonHistory(SEASON_HISTORY_LIMIT),
+        gamification.getBadgeDefinitions(),
+      ]);
+      if (cancelled) return;
+      if (historyResult.status === 'fulfilled') setHistory(historyResult.value);
+      if (definitionsResult.status === 'fulfilled') setDefinitions(definitionsResult.value);
+      setLoading(false);
+    };
+
+    load();
+    return () => {
+      cancelled = true;
+    };
+  }, []);
+
+  const badgeIcon = (badgeId: string) =>
+    definitions.find((d) => d.id === badgeId)?.icon ?? '🏅';
+
+  return (
+    <div className="p-6 bg-gm-bg-card/80 backdrop-blur-sm rounded-2xl border border-gm-accent-purple/20">
+      <h3 className="text-xl font-gaming font-bold text-gm-accent-purple mb-4">🗓️ Season</h3>
+
+      {loading && <div className="text-center py-4 text-dt-text-sub">読み込み中...</div>}
+
+      {error && (
+        <div className="p-3 bg-red-900/30 border border-red-500/50 rounded-lg text-red-200 text-sm">
+          {error}
+        </div>
+      )}
+
+      {/* Current season */}
+      {season && (
+        <div className="space-y-3">
+          {/* Shown once, right after the previous season was archived */}
+          {season.archivedSeason && (
+            <div className="p-3 bg-gm-accent-purple/10 border border-gm-accent-purple/30 rounded-lg text-sm text-white">
+              {season.archivedSeason.seasonId} が終了しました（{season.archivedSeason.seasonXp} XP /
+              Lv.{season.archivedSeason.seasonLevel}）
+              {season.newBadges.length > 0 && (
+                <div className="text-gm-accent-gold mt-1">
+                  新しいバッジ: {season.newBadges.map((b) => `${b.icon} ${b.name}`).join(', ')}
+                </div>
+              )}
+            </div>
+          )}
+
+          <div className="flex items-center justify-between">
+            <span className="text-white font-gaming">{season.seasonId}</span>
+            <span className="text-sm text-dt-text-sub">残り{season.daysRemaining}日</span>
+          </div>
+          <div className="flex items-baseline gap-3">
+            <span className="text-3xl font-gaming-mono font-bold text-gm-accent-purple">
+              Lv.{season.seasonLevel}
+            </span>
+            <span className="text-dt-text-sub">{season.seasonXp} XP</span>
+          </div>
+          <div className="h-2 bg-gm-bg-secondary rounded-full overflow-hidden">
+            <div
+              className="h-full bg-gradient-to-r from-gm-accent-cyan to-gm-accent-purple"
+              style={{ width: `${Math.min(Math.max(season.progressPercent, 0), 100).toFixed(1)}%` }}
+            />
+          </div>
+          <div className="flex justify-between text-xs text-dt-text-sub">
+            <span>次のレベルまで {season.xpToNextLevel} XP</span>
+            <span>シーズン最長ストリーク {season.bestStreak}日</span>
+          </div>
+        </div>
+      )}
+
+      {/* Archived seasons */}
+      {history.length > 0 && (
+        <div className="mt-6 space-y-2">
+          <h4 className="text-sm font-bold text-dt-text-sub">過去のシーズン</h4>
+          {history.map((archive) => (
+            <div
+              key={archive.seasonId}
+              className="flex items-center justify-between p-2 bg-gm-bg-secondary/40 rounded-lg text-sm"
+            >
+              <div className="flex items-center gap-2">
+                <span className="text-white font-gaming-mono">{archive.seasonId}</span>
+                <span className="text-dt-text-sub">
+                  Lv.{archive.seasonLevel} · {archive.seasonXp} XP · 🔥{archive.bestStreak}日
+                </span>
+              </div>
+              <div className="flex items-center gap-2">
+                {archive.percentile !== null && (
+                  <span className="text-xs text-gm-accent-gold">
+                    過去の{archive.percentile}%を上回る
+                  </span>
+                )}
+                <span>{archive.topBadges.map(badgeIcon).join(' ')}</span>
+              </div>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+};
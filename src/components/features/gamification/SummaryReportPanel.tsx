@@ -0,0 +1,233 @@
+/**
+ * Summary Report Panel
+ *
+ * "Your week in code" report on the home page: a preview of the
+ * last complete week or month with buttons to save it as Markdown or a
+ * standalone HTML page. The Monday notification of the same report is
+ * toggled in the notification settings.
+ *
+ * Backed by `gamification.getSummaryReport` / `exportSummaryReport`, which
+ * read the local database only. Saving opens the native save dialog on the
+ * backend side.
+ *
+ * Related Documentation:
+ *   - Backend: src-tauri/src/commands/summary_report.rs
+ */
+
+import React, { useEffect, useState } from 'react';
+import { gamification } from '../../../lib/tauri/commands';
+import type { ReportFormat, ReportPeriod, SummaryReport } from '../../../types';
+
+const PERIODS: Array<{ period: ReportPeriod; label: string }> = [
+  { period: 'week', label: '先週' },
+  { period: 'month', label: '先月' },
+];
+
+const FORMATS: Array<{ format: ReportFormat; label: string }> = [
+  { format: 'markdown', label: 'Markdown で保存' },
+  { format: 'html', label: 'HTML で保存' },
+];
+
+const ACTION_LABELS: Record<string, string> = {
+  commit: 'コミット',
+  pull_request: 'PR作成',
+  pull_request_merged: 'PRマージ',
+  review: 'レビュー',
+  issue: 'Issue作成',
+  issue_closed: 'Issueクローズ',
+  time_tracked: 'タイムトラッキング',
+  streak_bonus: 'ストリークボーナス',
+  prestige_bonus: 'プレステージボーナス',
+  github_sync: 'GitHub同期',
+  xp_adjusted: 'XP調整',
+  star: 'スター獲得',
+};
+
+function formatRange(report: SummaryReport): string {
+  const short = (date: string) => date.slice(5).replace('-', '/');
+  return `${short(report.range.start)}〜${short(report.range.end)}`;
+}
+
+const Stat: React.FC<{ label: string; value: string }> = ({ label, value }) => (
+  <div className="p-3 bg-gm-bg-card/50 rounded-lg border border-slate-700/50">
+    <div className="text-xs text-dt-text-sub">{label}</div>
+    <div className="text-lg font-gaming-mono text-white">{value}</div>
+  </div>
+);
+
+const optional = (value: number | null) => (value === null ? '—' : value.toLocaleString());
+
+export const SummaryReportPanel: React.FC = () => {
+  const [period, setPeriod] = useState<ReportPeriod>('week');
+  const [report, setReport] = useState<SummaryReport | null>(null);
+  const [isLoading, setIsLoading] = useState(false);
+  const [isSaving, setIsSaving] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const [savedPath, setSavedPath] = useState<string | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    setIsLoading(true);
+    setError(null);
+    gamification
+      .getSummaryReport(period)
+      .then((result) => {
+        if (!cancelled) setReport(result);
+      })
+      .catch((e) => {
+        if (!cancelled) setError(String(e));
+      })
+      .finally(() => {
+        if (!cancelled) setIsLoading(false);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [period]);
+
+  const save = async (format: ReportFormat) => {
+    setIsSaving(true);
+    setError(null);
+    setSavedPath(null);
+    try {
+      // null = the save dialog was cancelled
+      const path = await gamification.exportSummaryReport(period, format);
+      if (path) setSavedPath(path);
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  const levelLabel =
+    report === null
+      ? ''
+      : report.levelStart === report.levelEnd
+        ? `Lv.${report.levelEnd}`
+        : `Lv.${report.levelStart} → Lv.${report.levelEnd}`;
+
+  return (
+    <div className="p-6 bg-gm-bg-card/80 backdrop-blur-sm rounded-2xl border border-gm-accent-cyan/20">
+      <div className="flex flex-wrap items-center justify-between gap-3 mb-4">
+        <h3 className="text-xl font-gaming font-bold text-gm-accent-cyan">
+          📅 サマリーレポート
+          {report && (
+            <span className="ml-2 text-sm font-gaming-mono text-dt-text-sub">
+              {formatRange(report)}
+            </span>
+          )}
+        </h3>
+        <div className="flex p-1 bg-gm-bg-card/50 rounded-lg border border-gm-accent-cyan/20">
+          {PERIODS.map((option) => (
+            <button
+              key={option.period}
+              type="button"
+              className={`px-3 py-1 text-sm rounded-md font-gaming transition-colors ${
+                period === option.period
+                  ? 'bg-gm-accent-cyan/30 text-white'
+                  : 'text-dt-text-sub hover:text-white'
+              }`}
+              onClick={() => setPeriod(option.period)}
+            >
+              {option.label}
+            </button>
+          ))}
+        </div>
+      </div>
+
+      {error && (
+        <div className="mb-4 p-3 bg-red-900/30 border border-red-500/50 rounded-lg text-red-200 text-sm">
+          レポートを処理できませんでした: {error}
+        </div>
+      )}
+
+      {!report && isLoading && (
+        <div className="space-y-2 animate-pulse">
+          <div className="h-16 bg-slate-700 rounded"></div>
+          <div className="h-24 bg-slate-700 rounded"></div>
+        </div>
+      )}
+
+      {report && (
+        <div className={`space-y-4 ${isLoading ? 'opacity-60' : ''}`}>
+          <div className="grid grid-cols-2 sm:grid-cols-4 gap-2">
+            <Stat label="獲得XP" value={report.xpTotal.toLocaleString()} />
+            <Stat label="レベル" value={levelLabel} />
+            <Stat label="ストリーク" value={`${report.currentStreak}日`} />
+            <Stat label="活動日数" value={`${report.activeDays}日`} />
+            <Stat label="マージされたPR" value={optional(report.pullRequestsMerged)} />
+            <Stat label="レビュー" value={optional(report.reviews)} />
+            <Stat label="バッジ" value={`${report.badges.length}個`} />
+            <Stat label="チャレンジ達成" value={`${report.challengesCompleted.length}件`} />
+          </div>
+          {(report.commits === null ||
+            report.pullRequestsMerged === null ||
+            report.reviews === null) && (
+            <p className="text-xs text-dt-text-sub">
+              — は同期データから算出できない値です（期間の前後に同期がない、API制限による代替値、リポジトリフィルターの変更など）
+            </p>
+          )}
+
+          <div className="grid gap-4 sm:grid-cols-2 text-sm">
+            <div>
+              <h4 className="mb-2 text-dt-text-sub">XPの内訳</h4>
+              {report.xpBySource.length === 0 ? (
+                <p className="text-dt-text-sub">この期間に獲得したXPはありません</p>
+              ) : (
+                <ul className="space-y-1">
+                  {report.xpBySource.map((source) => (
+                    <li key={source.actionType} className="flex justify-between">
+                      <span className="text-dt-text-main">
+                        {ACTION_LABELS[source.actionType] ?? source.actionType}
+                      </span>
+                      <span className="font-gaming-mono text-white">
+                        {source.xp.toLocaleString()} XP
+                      </span>
+                    </li>
+                  ))}
+                </ul>
+              )}
+            </div>
+            <div>
+              <h4 className="mb-2 text-dt-text-sub">よく使ったリポジトリ</h4>
+              {report.topRepositories.length === 0 ? (
+                <p className="text-dt-text-sub">なし</p>
+              ) : (
+                <ol className="space-y-1">
+                  {report.topRepositories.map((repository) => (
+                    <li key={repository.nameWithOwner} className="flex justify-between gap-2">
+                      <span className="truncate text-dt-text-main">{repository.nameWithOwner}</span>
+                      <span className="shrink-0 font-gaming-mono text-dt-text-sub">
+                        {repository.activeDays}日
+                      </span>
+                    </li>
+                  ))}
+                </ol>
+              )}
+            </div>
+          </div>
+
+          <div className="flex flex-wrap items-center justify-end gap-2">
+            {savedPath && (
+              <span className="mr-auto text-xs text-emerald-400 truncate">
+                保存しました: {savedPath}
+              </span>
+            )}
+            {FORMATS.map((option) => (
+              <button
+                key={option.format}
+                type="button"
+                disabled={isSaving}
+                onClick={() => save(option.format)}
+                className="px-4 py-1.5 text-sm rounded-lg bg-gm-accent-cyan/30 text-white hover:bg-gm-accent-cyan/40 transition-colors disabled:opacity-50"
+              >
+                {option.label}
+              </button>
+            ))}
+          </div>
+        </div>
+      )}
+    </div>
+  );
+};
@@ -34,6 +34,10 @@ export const XpNotification: React.FC<XpNotificationProps> = ({ event, onClose }
     if (bd.reviewsXp > 0) items.push({ label: '👁️ Reviews', value: bd.reviewsXp });
     if (bd.starsXp > 0) items.push({ label: '⭐ Stars', value: bd.starsXp });
     if (bd.streakBonusXp > 0) items.push({ label: '🔥 Streak Bonus', value: bd.streakBonusXp });
+    if (bd.prestigeBonusXp && bd.prestigeBonusXp > 0)
+      items.push({ label: '🌀 Prestige Bonus', value: bd.prestigeBonusXp });
+    if (bd.adjustedXp && bd.adjustedXp < 0)
+      items.push({ label: '⚖️ XP Adjusted', value: bd.adjustedXp });
     return items;
   }, [event]);
 
@@ -79,7 +83,9 @@ export const XpNotification: React.FC<XpNotificationProps> = ({ event, onClose }
             {breakdownItems.map((item, i) => (
               <div key={i} className="flex justify-between">
                 <span>{item.label}</span>
-                <span className="text-gm-accent-cyan">+{item.value}</span>
+                <span className={item.value < 0 ? 'text-slate-400' : 'text-gm-accent-cyan'}>
+                  {item.value < 0 ? item.value : `+${item.value}`}
+                </span>
               </div>
             ))}
           </div>
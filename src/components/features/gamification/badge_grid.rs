@@ -340,6 +340,8 @@ where
                         "quality" => "✨ Quality",
                         "challenge" => "🎯 Challenge",
                         "level" => "⭐ Level",
+                        "prestige" => "🌀 Prestige",
+                        "season" => "🗓️ Season",
                         "stars" => "🌟 Stars",
                         "language" => "🌍 Language",
                         _ => "📌 Other",
@@ -19,7 +19,7 @@ use leptos::task::spawn_local;
 
 use crate::components::network_status::use_is_online;
 use crate::tauri_api;
-use crate::types::ChallengeInfo;
+use crate::types::{ChallengeInfo, ChallengeTokens, TOKEN_CHALLENGE_TYPES};
 
 /// Challenge card component - displays a list of active challenges
 #[component]
@@ -31,9 +31,16 @@ pub fn ChallengeCard() -> impl IntoView {
     let (challenges, set_challenges) = signal::<Vec<ChallengeInfo>>(Vec::new());
     let (loading, set_loading) = signal(true);
     let (error, set_error) = signal::<Option<String>>(None);
+    let (tokens, set_tokens) = signal(ChallengeTokens::default());
+    let (token_error, set_token_error) = signal::<Option<String>>(None);
 
     // Load challenges on mount
     Effect::new(move |_| {
+        spawn_local(async move {
+            if let Ok(t) = tauri_api::get_challenge_tokens().await {
+                set_tokens.set(t);
+            }
+        });
         spawn_local(async move {
             set_loading.set(true);
             match tauri_api::get_active_challenges().await {
@@ -65,8 +72,31 @@ pub fn ChallengeCard() -> impl IntoView {
                     set_error.set(Some(e));
                 }
             }
+            if let Ok(t) = tauri_api::get_challenge_tokens().await {
+                set_tokens.set(t);
+            }
+        });
+    };
+
+    // Spend a reroll / skip token, then refresh the list and the balance
+    let spend_token = move |challenge_id: i64, reroll: bool| {
+        spawn_local(async move {
+            let result = if reroll {
+                tauri_api::reroll_challenge(challenge_id).await
+            } else {
+                tauri_api::skip_challenge(challenge_id).await
+            };
+            match result {
+                Ok(_) => {
+                    set_token_error.set(None);
+                    reload_challenges();
+                }
+                Err(e) => set_token_error.set(Some(e)),
+            }
         });
     };
+    let on_reroll = Callback::new(move |challenge_id: i64| spend_token(challenge_id, true));
+    let on_skip = Callback::new(move |challenge_id: i64| spend_token(challenge_id, false));
 
     view! {
         <div class="p-6 bg-gm-bg-card/80 backdrop-blur-sm rounded-2xl border border-gm-accent-gold/20">
@@ -140,13 +170,24 @@ pub fn ChallengeCard() -> impl IntoView {
                         </div>
                     }
                 >
+                    // Token error (e.g. none left)
+                    <Show when=move || token_error.get().is_some()>
+                        <div class="mb-3 p-2 bg-gm-error/20 border border-gm-error/50 rounded-lg text-gm-error text-xs">
+                            {move || token_error.get().unwrap_or_default()}
+                        </div>
+                    </Show>
                     <div class="space-y-3">
                         <For
                             each=move || challenges.get()
-                            key=|c| c.id
+                            key=|c| (c.id, c.target_metric.clone())
                             children=move |challenge| {
                                 view! {
-                                    <ChallengeItem challenge=challenge />
+                                    <ChallengeItem
+                                        challenge=challenge
+                                        tokens=tokens
+                                        on_reroll=on_reroll
+                                        on_skip=on_skip
+                                    />
                                 }
                             }
                         />
@@ -159,10 +200,23 @@ pub fn ChallengeCard() -> impl IntoView {
 
 /// Single challenge item component
 #[component]
-fn ChallengeItem(challenge: ChallengeInfo) -> impl IntoView {
+fn ChallengeItem(
+    challenge: ChallengeInfo,
+    tokens: ReadSignal<ChallengeTokens>,
+    on_reroll: Callback<i64>,
+    on_skip: Callback<i64>,
+) -> impl IntoView {
+    let is_online = use_is_online();
     let progress = challenge.progress_percent.min(100.0);
     let is_completed = challenge.is_completed;
     let is_expired = challenge.is_expired;
+    let is_upcoming = challenge.is_upcoming;
+    let target_reason = challenge.target_reason.clone();
+    let challenge_id = challenge.id;
+    let can_use_tokens = TOKEN_CHALLENGE_TYPES.contains(&challenge.challenge_type.as_str())
+        && !is_completed
+        && !is_expired
+        && !is_upcoming;
 
     // Determine colors based on status
     let (bg_color, border_color, progress_color) = if is_completed {
@@ -188,10 +242,13 @@ fn ChallengeItem(challenge: ChallengeInfo) -> impl IntoView {
     };
 
     // Challenge type badge color
-    let type_badge_class = if challenge.challenge_type == "daily" {
-        "bg-gm-accent-cyan/20 text-gm-accent-cyan border-gm-accent-cyan/30"
-    } else {
-        "bg-gm-accent-purple/20 text-gm-accent-purple border-gm-accent-purple/30"
+    let type_badge_class = match challenge.challenge_type.as_str() {
+        "daily" => "bg-gm-accent-cyan/20 text-gm-accent-cyan border-gm-accent-cyan/30",
+        "monthly" | "quarterly" => {
+            "bg-gm-accent-gold/20 text-gm-accent-gold border-gm-accent-gold/30"
+        }
+        "custom" => "bg-gm-accent-pink/20 text-gm-accent-pink border-gm-accent-pink/30",
+        _ => "bg-gm-accent-purple/20 text-gm-accent-purple border-gm-accent-purple/30",
     };
 
     view! {
@@ -222,6 +279,8 @@ fn ChallengeItem(challenge: ChallengeInfo) -> impl IntoView {
                         "✅ 達成!".to_string()
                     } else if is_expired {
                         "⏰ 期限切れ".to_string()
+                    } else if is_upcoming {
+                        challenge.upcoming_label()
                     } else {
                         challenge.remaining_time_label()
                     }}
@@ -270,6 +329,33 @@ fn ChallengeItem(challenge: ChallengeInfo) -> impl IntoView {
                     </span>
                 </div>
             </div>
+
+            // Why this target was chosen (auto-generated challenges)
+            {target_reason.map(|reason| {
+                view! { <p class="mt-2 text-xs text-gm-text-muted">"💡 "{reason}</p> }
+            })}
+
+            // Reroll / skip with tokens (daily / weekly only)
+            {can_use_tokens.then(|| view! {
+                <div class="mt-3 flex justify-end gap-2">
+                    <button
+                        class="px-2 py-1 text-xs rounded-lg bg-gm-bg-secondary/50 hover:bg-gm-bg-secondary text-gm-text-secondary transition-colors disabled:opacity-40 disabled:cursor-not-allowed"
+                        title="リロールトークンを使って別のチャレンジに入れ替えます"
+                        disabled=move || tokens.get().reroll_tokens == 0 || !is_online.get()
+                        on:click=move |_| on_reroll.run(challenge_id)
+                    >
+                        {move || format!("🎲 リロール ({})", tokens.get().reroll_tokens)}
+                    </button>
+                    <button
+                        class="px-2 py-1 text-xs rounded-lg bg-gm-bg-secondary/50 hover:bg-gm-bg-secondary text-gm-text-secondary transition-colors disabled:opacity-40 disabled:cursor-not-allowed"
+                        title="スキップトークンを使って失敗扱いにせず取り下げます"
+                        disabled=move || tokens.get().skip_tokens == 0 || !is_online.get()
+                        on:click=move |_| on_skip.run(challenge_id)
+                    >
+                        {move || format!("⏭ スキップ ({})", tokens.get().skip_tokens)}
+                    </button>
+                </div>
+            })}
         </div>
     }
 }
@@ -15,6 +15,7 @@
 //!   ├─ features/gamification/profile_card.rs - ProfileCard
 //!   ├─ features/gamification/stats_display.rs - StatsDisplay
 //!   ├─ features/gamification/challenge_card.rs - ChallengeCard
+//!   ├─ features/gamification/season_card.rs - SeasonCard
 //!   ├─ features/gamification/badge_grid.rs - BadgeGrid
 //!   └─ features/gamification/contribution_graph.rs - ContributionGraph
 //! Related Documentation:
@@ -24,7 +25,7 @@
 use leptos::prelude::*;
 
 use crate::components::features::gamification::{
-    BadgeGrid, ChallengeCard, ContributionGraph, ProfileCard, StatsDisplay,
+    BadgeGrid, ChallengeCard, ContributionGraph, ProfileCard, SeasonCard, StatsDisplay,
 };
 use crate::components::home::skeleton::{
     BadgeGridSkeleton, ChallengeCardSkeleton, ContributionGraphSkeleton, ProfileCardSkeleton,
@@ -90,6 +91,9 @@ pub fn DashboardContent(
             </Suspense>
         </div>
 
+        // Seasons (loads on its own)
+        <SeasonCard />
+
         // Phase 3: Skeleton UI - Section 4: Badges
         // Independent loading with its own skeleton
         <Suspense fallback=move || view! { <BadgeGridSkeleton /> }>
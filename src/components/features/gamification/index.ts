@@ -10,8 +10,13 @@
 export { ProfileCard } from './ProfileCard';
 export { StatsDisplay } from './StatsDisplay';
 export { ChallengeCard } from './ChallengeCard';
+export { SeasonCard } from './SeasonCard';
 export { BadgeGrid } from './BadgeGrid';
 export { ContributionGraph } from './ContributionGraph';
 export { LanguageBreakdownCard } from './LanguageBreakdownCard';
+export { RepositoryDetailModal } from './RepositoryDetailModal';
+export { PeriodComparisonPanel } from './PeriodComparisonPanel';
+export { SummaryReportPanel } from './SummaryReportPanel';
 export { XpNotification } from './XpNotification';
 export { DashboardContent } from './DashboardContent';
+export { RepoFilterSwitcher } from './RepoFilterSwitcher';
@@ -10,6 +10,8 @@ pub mod contribution_graph;
 pub mod dashboard_content;
 pub mod home_data_loader;
 pub mod profile_card;
+pub mod repo_filter_switcher;
+pub mod season_card;
 pub mod stats_display;
 pub mod sync_notifications;
 pub mod xp_notification;
@@ -21,6 +23,8 @@ pub use contribution_graph::ContributionGraph;
 pub use dashboard_content::DashboardContent;
 pub use home_data_loader::load_user_data;
 pub use profile_card::ProfileCard;
+pub use repo_filter_switcher::RepoFilterSwitcher;
+pub use season_card::SeasonCard;
 pub use stats_display::StatsDisplay;
 pub use sync_notifications::handle_sync_result_notifications;
 pub use xp_notification::XpNotification;
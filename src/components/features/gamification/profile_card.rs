@@ -1,6 +1,6 @@
 //! Profile card component
 //!
-//! Displays user profile, level, and XP progress.
+//! Displays user profile, level, prestige rank, and XP progress.
 //!
 //! DEPENDENCY MAP:
 //!
@@ -76,6 +76,17 @@ where
                             }}
                         </h2>
 
+                        // Prestige rank (cosmetic title + permanent XP bonus)
+                        {move || {
+                            level_info.get().and_then(|info| {
+                                info.prestige_title.clone().map(|title| view! {
+                                    <div class="inline-flex items-center gap-1 px-2 py-0.5 rounded-md bg-gm-accent-purple/20 border border-gm-accent-purple/40 text-xs text-gm-accent-purple font-gaming">
+                                        "🌀 " {title} " · XP x" {format!("{:.2}", info.xp_multiplier)}
+                                    </div>
+                                })
+                            })
+                        }}
+
                         // XP Progress Bar
                         {move || {
                             level_info.get().map(|info| view! {
@@ -0,0 +1,92 @@
+//! Repository Filter Switcher Component
+//!
+//! Compact "all / work only / personal only" switcher shown above the
+//! dashboard. Switching saves the repository filter preset; the full
+//! filter is edited in Settings → リポジトリフィルター.
+//!
+//! DEPENDENCY MAP:
+//! Parents (Files that import this component):
+//!   └─ src/components/pages/home/mod.rs
+//! Dependencies:
+//!   └─ src/tauri_api.rs (get_repository_filter, set_repository_filter_preset)
+
+use leptos::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::tauri_api;
+use crate::types::RepoFilterPreset;
+
+/// Repository filter preset switcher
+///
+/// `on_change` runs after the new preset is saved so the page can reload
+/// its stats (the backend drops the filtered caches).
+#[component]
+pub fn RepoFilterSwitcher<F>(on_change: F) -> impl IntoView
+where
+    F: Fn() + Clone + Send + Sync + 'static,
+{
+    let (preset, set_preset) = signal(Option::<RepoFilterPreset>::None);
+    let (saving, set_saving) = signal(false);
+    let (error, set_error) = signal(None::<String>);
+
+    // Hidden until the saved preset is known
+    spawn_local(async move {
+        if let Ok(filter) = tauri_api::get_repository_filter().await {
+            set_preset.set(Some(RepoFilterPreset::from_str(&filter.preset)));
+        }
+    });
+
+    let select = move |next: RepoFilterPreset| {
+        if saving.get_untracked() || preset.get_untracked() == Some(next) {
+            return;
+        }
+        set_saving.set(true);
+        set_error.set(None);
+        let on_change = on_change.clone();
+
+        spawn_local(async move {
+            match tauri_api::set_repository_filter_preset(next.as_str()).await {
+                Ok(filter) => {
+                    set_preset.set(Some(RepoFilterPreset::from_str(&filter.preset)));
+                    on_change();
+                }
+                Err(e) => set_error.set(Some(format!("切り替えに失敗しました: {}", e))),
+            }
+            set_saving.set(false);
+        });
+    };
+
+    view! {
+        <Show when=move || preset.get().is_some()>
+            <div class="flex flex-wrap items-center justify-end gap-3">
+                <Show when=move || error.get().is_some()>
+                    <span class="text-xs text-red-300">{move || error.get().unwrap_or_default()}</span>
+                </Show>
+                <span class="text-xs text-dt-text-sub">"集計対象"</span>
+                <div class="flex p-1 bg-gm-bg-card/50 rounded-lg border border-gm-accent-cyan/20">
+                    {RepoFilterPreset::ALL.into_iter().map(|option| {
+                        let select = select.clone();
+                        view! {
+                            <button
+                                type="button"
+                                title=option.description()
+                                disabled=move || saving.get()
+                                class=move || format!(
+                                    "px-3 py-1 text-sm rounded-md font-gaming transition-colors disabled:opacity-50 {}",
+                                    if preset.get() == Some(option) {
+                                        "bg-gm-accent-cyan/30 text-white"
+                                    } else {
+                                        "text-dt-text-sub hover:text-white"
+                                    }
+                                )
+                                on:click=move |_| select(option)
+                            >
+                                {option.label()}
+                            </button>
+                        }
+                    }).collect::<Vec<_>>()}
+                </div>
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,174 @@
+//! Season card component
+//!
+//! Displays the current season's XP and level (tracked separately from
+//! lifetime XP and reset every quarter) together with archived seasons.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents (Files that import this component):
+//!   └─ src/components/features/gamification/dashboard_content.rs
+//!
+//! Dependencies (Files this module imports):
+//!   └─ src/tauri_api.rs (get_season_info, get_season_history, get_badge_definitions)
+//!
+//! Related Documentation:
+//!   └─ React: ./SeasonCard.tsx
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::tauri_api;
+use crate::types::{BadgeDefinition, SeasonArchive, SeasonInfo};
+
+/// Number of archived seasons shown on the card
+const SEASON_HISTORY_LIMIT: i32 = 4;
+
+/// Season card component - current season progress and season archive
+#[component]
+pub fn SeasonCard() -> impl IntoView {
+    let (season, set_season) = signal(Option::<SeasonInfo>::None);
+    let (history, set_history) = signal(Vec::<SeasonArchive>::new());
+    let (definitions, set_definitions) = signal(Vec::<BadgeDefinition>::new());
+    let (loading, set_loading) = signal(true);
+    let (error, set_error) = signal(None::<String>);
+
+    // Load the season first: it archives an ended season, so the history
+    // request below already includes it
+    Effect::new(move |_| {
+        spawn_local(async move {
+            match tauri_api::get_season_info().await {
+                Ok(info) => set_season.set(Some(info)),
+                Err(e) => {
+                    web_sys::console::error_1(&format!("Failed to load season: {}", e).into());
+                    set_error.set(Some(e));
+                }
+            }
+            let (history_result, definitions_result) = futures::join!(
+                tauri_api::get_season_history(Some(SEASON_HISTORY_LIMIT)),
+                tauri_api::get_badge_definitions()
+            );
+            if let Ok(h) = history_result {
+                set_history.set(h);
+            }
+            if let Ok(d) = definitions_result {
+                set_definitions.set(d);
+            }
+            set_loading.set(false);
+        });
+    });
+
+    let badge_icon = move |badge_id: &str| {
+        definitions
+            .get()
+            .iter()
+            .find(|d| d.id == badge_id)
+            .map(|d| d.icon.clone())
+            .unwrap_or_else(|| "🏅".to_string())
+    };
+
+    view! {
+        <div class="p-6 bg-gm-bg-card/80 backdrop-blur-sm rounded-2xl border border-gm-accent-purple/20">
+            <h3 class="text-xl font-gaming font-bold text-gm-accent-purple mb-4">
+                "🗓️ Season"
+            </h3>
+
+            <Show when=move || loading.get()>
+                <div class="text-center py-4 text-dt-text-sub">"読み込み中..."</div>
+            </Show>
+
+            <Show when=move || error.get().is_some()>
+                <div class="p-3 bg-red-900/30 border border-red-500/50 rounded-lg text-red-200 text-sm">
+                    {move || error.get().unwrap_or_default()}
+                </div>
+            </Show>
+
+            // Current season
+            {move || season.get().map(|info| {
+                let progress = info.progress_percent.clamp(0.0, 100.0);
+                let archived = info.archived_season.clone();
+                let new_badges = info.new_badges.clone();
+
+                view! {
+                    <div class="space-y-3">
+                        // Shown once, right after the previous season was archived
+                        {archived.map(|a| {
+                            let badges = new_badges
+                                .iter()
+                                .map(|b| format!("{} {}", b.icon, b.name))
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            view! {
+                                <div class="p-3 bg-gm-accent-purple/10 border border-gm-accent-purple/30 rounded-lg text-sm text-white">
+                                    {format!("{} が終了しました（{} XP / Lv.{}）", a.season_id, a.season_xp, a.season_level)}
+                                    {(!badges.is_empty()).then(|| view! {
+                                        <div class="text-gm-accent-gold mt-1">{format!("新しいバッジ: {}", badges)}</div>
+                                    })}
+                                </div>
+                            }
+                        })}
+
+                        <div class="flex items-center justify-between">
+                            <span class="text-white font-gaming">{info.season_id.clone()}</span>
+                            <span class="text-sm text-dt-text-sub">
+                                {format!("残り{}日", info.days_remaining)}
+                            </span>
+                        </div>
+                        <div class="flex items-baseline gap-3">
+                            <span class="text-3xl font-gaming-mono font-bold text-gm-accent-purple">
+                                {format!("Lv.{}", info.season_level)}
+                            </span>
+                            <span class="text-dt-text-sub">
+                                {format!("{} XP", info.season_xp)}
+                            </span>
+                        </div>
+                        <div class="h-2 bg-gm-bg-secondary rounded-full overflow-hidden">
+                            <div
+                                class="h-full bg-gradient-to-r from-gm-accent-cyan to-gm-accent-purple"
+                                style=format!("width: {:.1}%", progress)
+                            />
+                        </div>
+                        <div class="flex justify-between text-xs text-dt-text-sub">
+                            <span>{format!("次のレベルまで {} XP", info.xp_to_next_level)}</span>
+                            <span>{format!("シーズン最長ストリーク {}日", info.best_streak)}</span>
+                        </div>
+                    </div>
+                }
+            })}
+
+            // Archived seasons
+            <Show when=move || !history.get().is_empty()>
+                <div class="mt-6 space-y-2">
+                    <h4 class="text-sm font-bold text-dt-text-sub">"過去のシーズン"</h4>
+                    {move || history.get().into_iter().map(|archive| {
+                        let icons = archive
+                            .top_badges
+                            .iter()
+                            .map(|id| badge_icon(id))
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        let percentile = archive
+                            .percentile
+                            .map(|p| format!("過去の{}%を上回る", p));
+
+                        view! {
+                            <div class="flex items-center justify-between p-2 bg-gm-bg-secondary/40 rounded-lg text-sm">
+                                <div class="flex items-center gap-2">
+                                    <span class="text-white font-gaming-mono">{archive.season_id.clone()}</span>
+                                    <span class="text-dt-text-sub">
+                                        {format!("Lv.{} · {} XP · 🔥{}日", archive.season_level, archive.season_xp, archive.best_streak)}
+                                    </span>
+                                </div>
+                                <div class="flex items-center gap-2">
+                                    {percentile.map(|p| view! {
+                                        <span class="text-xs text-gm-accent-gold">{p}</span>
+                                    })}
+                                    <span>{icons}</span>
+                                </div>
+                            </div>
+                        }
+                    }).collect::<Vec<_>>()}
+                </div>
+            </Show>
+        </div>
+    }
+}
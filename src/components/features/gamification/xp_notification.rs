@@ -156,6 +156,30 @@ where
                                                         </Show>
                                                     }
                                                 }
+                                                {
+                                                    let bd = breakdown.clone();
+                                                    let show_prestige = bd.prestige_bonus_xp > 0;
+                                                    view! {
+                                                        <Show when=move || show_prestige>
+                                                            <div class="flex justify-between">
+                                                                <span>"🌀 Prestige Bonus"</span>
+                                                                <span class="text-gm-accent-purple">"+" {bd.prestige_bonus_xp}</span>
+                                                            </div>
+                                                        </Show>
+                                                    }
+                                                }
+                                                {
+                                                    let bd = breakdown.clone();
+                                                    let show_adjusted = bd.adjusted_xp < 0;
+                                                    view! {
+                                                        <Show when=move || show_adjusted>
+                                                            <div class="flex justify-between">
+                                                                <span>"⚖️ XP Adjusted"</span>
+                                                                <span class="text-slate-400">{bd.adjusted_xp}</span>
+                                                            </div>
+                                                        </Show>
+                                                    }
+                                                }
                                             </div>
                                         </Show>
                                     }
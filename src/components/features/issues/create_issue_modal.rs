@@ -1,6 +1,11 @@
 //! Create Issue Modal Component
 //!
-//! Modal dialog for creating a new GitHub issue.
+//! Modal dialog for creating a new GitHub issue. Projects linking several
+//! repositories get a repository picker (default: the primary one).
+//! When the repository has issue templates they can be picked: Markdown
+//! templates prefill the title and body, issue forms replace the body with
+//! their fields (`IssueTemplateForm`). Either way the template's labels and
+//! assignees are applied on creation.
 //!
 //! DEPENDENCY MAP:
 //!
@@ -8,16 +13,19 @@
 //!   └─ src/components/issues/project_dashboard.rs
 //! Dependencies:
 //!   ├─ src/types/issue.rs
-//!   ├─ src/tauri_api.rs
+//!   ├─ src/tauri_api.rs (get_issue_templates, build_issue_from_template,
+//!   │                    create_github_issue)
 //!   ├─ src/components/icons.rs
+//!   ├─ src/components/features/issues/issue_template_form.rs
 //!   └─ src/components/ui/dialog/modal.rs
 
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
+use super::issue_template_form::{initial_template_values, IssueTemplateForm, TemplateValues};
 use crate::components::ui::dialog::{Modal, ModalBody, ModalFooter, ModalHeader, ModalSize};
 use crate::tauri_api;
-use crate::types::issue::CachedIssue;
+use crate::types::issue::{CachedIssue, IssueTemplate, IssueTemplateSet, ProjectRepository};
 
 /// Create issue modal component
 #[component]
@@ -26,15 +34,77 @@ pub fn CreateIssueModal(
     #[prop(into)] visible: Signal<bool>,
     on_close: impl Fn() + 'static + Clone + Send + Sync,
     on_created: impl Fn(CachedIssue) + 'static + Copy,
+    /// Repositories linked to the project, primary first.
+    #[prop(into, optional)]
+    repositories: Signal<Vec<ProjectRepository>>,
 ) -> impl IntoView {
     let (title, set_title) = signal(String::new());
     let (body, set_body) = signal(String::new());
     let (status, set_status) = signal("backlog".to_string());
     let (priority, set_priority) = signal(Option::<String>::None);
+    // `None` = the first repository that isn't archived (the primary one
+    // unless it is gone)
+    let (target_repo, set_target_repo) = signal(Option::<String>::None);
+    let effective_repo = move || {
+        target_repo.get().or_else(|| {
+            repositories.with(|repos| {
+                repos
+                    .iter()
+                    .find(|r| !r.is_archived)
+                    .map(|r| r.repo_full_name.clone())
+            })
+        })
+    };
     let (creating, set_creating) = signal(false);
     let (error, set_error) = signal(Option::<String>::None);
     let (created_issue, set_created_issue) = signal(Option::<CachedIssue>::None);
 
+    // Issue templates of the target repository
+    let (template_set, set_template_set) = signal(IssueTemplateSet::default());
+    let selected_template = RwSignal::new(Option::<IssueTemplate>::None);
+    let form_values = RwSignal::new(TemplateValues::new());
+
+    let select_template = move |template: Option<IssueTemplate>| {
+        match &template {
+            Some(t) => {
+                set_title.set(t.title.clone().unwrap_or_default());
+                set_body.set(t.body.clone().unwrap_or_default());
+                form_values.set(initial_template_values(t));
+            }
+            None => {
+                set_title.set(String::new());
+                set_body.set(String::new());
+                form_values.set(TemplateValues::new());
+            }
+        }
+        selected_template.set(template);
+    };
+
+    // (Re)load the templates when the modal opens or the repository changes.
+    // Without blank issues the first template is preselected, as on GitHub.
+    Effect::new(move |_| {
+        if !visible.get() {
+            return;
+        }
+        let repo = effective_repo();
+        spawn_local(async move {
+            match tauri_api::get_issue_templates(project_id, repo.as_deref()).await {
+                Ok(set) => {
+                    let first = (!set.blank_issues_enabled)
+                        .then(|| set.templates.first().cloned())
+                        .flatten();
+                    set_template_set.set(set);
+                    select_template(first);
+                }
+                Err(e) => {
+                    web_sys::console::log_1(&format!("Issue templates load failed: {}", e).into());
+                    set_template_set.set(IssueTemplateSet::default());
+                    select_template(None);
+                }
+            }
+        });
+    });
+
     // Store on_close for use in ChildrenFn
     let on_close_stored = StoredValue::new(on_close.clone());
     let on_close_callback = Callback::new(move |_: ()| on_close_stored.get_value()());
@@ -57,21 +127,48 @@ pub fn CreateIssueModal(
         set_error.set(None);
 
         let body_val = body.get();
-        let body_opt = if body_val.trim().is_empty() {
-            None
-        } else {
-            Some(body_val.clone())
-        };
         let status_val = status.get();
         let priority_val = priority.get();
+        let repo_val = effective_repo();
+        let template = selected_template.get_untracked();
+        let mut values = form_values.get_untracked();
 
         spawn_local(async move {
+            // With a template, the backend validates the form and renders
+            // the body; labels / assignees come from the template.
+            let (title_val, body_val, labels, assignees) = match template {
+                Some(template) => {
+                    if !template.is_form() {
+                        values.insert("body".to_string(), vec![body_val]);
+                    }
+                    match tauri_api::build_issue_from_template(&template, &title_val, &values).await
+                    {
+                        Ok(draft) => (
+                            draft.title,
+                            draft.body,
+                            Some(draft.labels),
+                            Some(draft.assignees),
+                        ),
+                        Err(e) => {
+                            set_error.set(Some(e));
+                            set_creating.set(false);
+                            return;
+                        }
+                    }
+                }
+                None => (title_val, body_val, None, None),
+            };
+            let body_opt = (!body_val.trim().is_empty()).then_some(body_val);
+
             match tauri_api::create_github_issue(
                 project_id,
                 &title_val,
                 body_opt.as_deref(),
                 Some(&status_val),
                 priority_val.as_deref(),
+                repo_val.as_deref(),
+                labels.as_deref(),
+                assignees.as_deref(),
             )
             .await
             {
@@ -107,6 +204,57 @@ pub fn CreateIssueModal(
                         </div>
                     </Show>
 
+                    // Template picker (only when the repository has templates)
+                    <Show when=move || template_set.with(|set| !set.templates.is_empty())>
+                        <div>
+                            <label class="block text-sm font-medium text-dt-text-sub mb-1">"Template"</label>
+                            <select
+                                class="w-full px-3 py-2 bg-slate-800 border border-slate-700 rounded-lg text-dt-text focus:border-gm-accent-cyan focus:outline-none"
+                                on:change=move |ev| {
+                                    let file_name = event_target_value(&ev);
+                                    let template = template_set.with_untracked(|set| {
+                                        set.templates.iter().find(|t| t.file_name == file_name).cloned()
+                                    });
+                                    select_template(template);
+                                }
+                            >
+                                <Show when=move || template_set.with(|set| set.blank_issues_enabled)>
+                                    <option value="" selected=move || selected_template.with(|t| t.is_none())>"Blank issue"</option>
+                                </Show>
+                                {move || template_set.get().templates.into_iter().map(|t| {
+                                    let file_name = t.file_name.clone();
+                                    let selected_name = file_name.clone();
+                                    view! {
+                                        <option
+                                            value=file_name
+                                            selected=move || selected_template.with(|s| s.as_ref().map(|s| s.file_name == selected_name).unwrap_or(false))
+                                        >
+                                            {t.name}
+                                        </option>
+                                    }
+                                }).collect_view()}
+                            </select>
+                            {move || selected_template.get().and_then(|t| t.description).map(|d| view! {
+                                <p class="mt-1 text-xs text-dt-text-sub">{d}</p>
+                            })}
+                            {move || selected_template.get().filter(|t| !t.labels.is_empty() || !t.assignees.is_empty()).map(|t| view! {
+                                <div class="mt-2 flex flex-wrap gap-1 text-xs">
+                                    {t.labels.into_iter().map(|l| view! {
+                                        <span class="px-2 py-0.5 rounded-full border border-slate-600 text-dt-text-sub">{l}</span>
+                                    }).collect_view()}
+                                    {t.assignees.into_iter().map(|a| view! {
+                                        <span class="px-2 py-0.5 rounded-full border border-gm-accent-cyan/50 text-gm-accent-cyan">{"@"}{a}</span>
+                                    }).collect_view()}
+                                </div>
+                            })}
+                            <Show when=move || template_set.with(|set| !set.invalid.is_empty())>
+                                <p class="mt-1 text-xs text-yellow-400">
+                                    {move || format!("読み込めなかったテンプレート: {}", template_set.get().invalid.join(" / "))}
+                                </p>
+                            </Show>
+                        </div>
+                    </Show>
+
                     // Title
                     <div>
                         <label class="block text-sm font-medium text-dt-text-sub mb-1">"Title" <span class="text-red-400">"*"</span></label>
@@ -119,17 +267,48 @@ pub fn CreateIssueModal(
                         />
                     </div>
 
-                    // Body
-                    <div>
-                        <label class="block text-sm font-medium text-dt-text-sub mb-1">"Description"</label>
-                        <textarea
-                            class="w-full px-3 py-2 bg-slate-800 border border-slate-700 rounded-lg text-dt-text focus:border-gm-accent-cyan focus:outline-none resize-none"
-                            rows="4"
-                            placeholder="Describe the issue..."
-                            prop:value=move || body.get()
-                            on:input=move |ev| set_body.set(event_target_value(&ev))
-                        />
-                    </div>
+                    // Body: issue form fields, or a free-form description
+                    {move || match selected_template.get().filter(|t| t.is_form()) {
+                        Some(template) => view! {
+                            <IssueTemplateForm template=template values=form_values />
+                        }.into_any(),
+                        None => view! {
+                            <div>
+                                <label class="block text-sm font-medium text-dt-text-sub mb-1">"Description"</label>
+                                <textarea
+                                    class="w-full px-3 py-2 bg-slate-800 border border-slate-700 rounded-lg text-dt-text focus:border-gm-accent-cyan focus:outline-none resize-none"
+                                    rows="4"
+                                    placeholder="Describe the issue..."
+                                    prop:value=move || body.get()
+                                    on:input=move |ev| set_body.set(event_target_value(&ev))
+                                />
+                            </div>
+                        }.into_any(),
+                    }}
+
+                    // Repository (only when several are linked)
+                    <Show when=move || repositories.with(|repos| repos.iter().filter(|r| !r.is_archived).count() > 1)>
+                        <div>
+                            <label class="block text-sm font-medium text-dt-text-sub mb-1">"Repository"</label>
+                            <select
+                                class="w-full px-3 py-2 bg-slate-800 border border-slate-700 rounded-lg text-dt-text focus:border-gm-accent-cyan focus:outline-none"
+                                on:change=move |ev| set_target_repo.set(Some(event_target_value(&ev)))
+                            >
+                                {move || repositories.get().into_iter().filter(|r| !r.is_archived).map(|r| {
+                                    let value = r.repo_full_name.clone();
+                                    let selected_value = value.clone();
+                                    view! {
+                                        <option
+                                            value=value
+                                            selected=move || effective_repo().as_deref() == Some(selected_value.as_str())
+                                        >
+                                            {r.repo_full_name}
+                                        </option>
+                                    }
+                                }).collect_view()}
+                            </select>
+                        </div>
+                    </Show>
 
                     // Status and Priority row
                     <div class="grid grid-cols-2 gap-4">
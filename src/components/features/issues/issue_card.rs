@@ -20,6 +20,8 @@ use leptos::prelude::*;
 pub struct StatusChangeEvent {
     pub issue_number: i32,
     pub new_status: String,
+    /// Repository of the issue (`None` = the project's primary one)
+    pub repo_full_name: Option<String>,
 }
 
 /// Issue click event data
@@ -60,6 +62,7 @@ pub fn IssueCard(
     let issue_url = issue.html_url.clone().unwrap_or_default();
     let issue_title = issue.title.clone();
     let issue_number = issue.number;
+    let repo_full_name = StoredValue::new(issue.repo_full_name.clone());
     let issue_body = issue.body.clone();
     let issue_assignee = issue.assignee_login.clone();
 
@@ -199,6 +202,7 @@ pub fn IssueCard(
                                                     Set::set(&signal, Some(StatusChangeEvent {
                                                         issue_number,
                                                         new_status: value.clone(),
+                                                        repo_full_name: repo_full_name.get_value(),
                                                     }));
                                                 }
                                                 set_show_dropdown.set(false);
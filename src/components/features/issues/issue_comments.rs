@@ -0,0 +1,264 @@
+//! Issue Comments Component
+//!
+//! Discussion thread shown under the description in the issue detail
+//! modal: comments (oldest first, paged with "Load more"), a reaction
+//! summary per comment, and a Markdown composer with a Write / Preview
+//! toggle. Pages come from `get_issue_comments`, which serves a cached copy
+//! when GitHub is unreachable.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents:
+//!   └─ src/components/features/issues/issue_detail_modal.rs
+//! Dependencies:
+//!   ├─ src/tauri_api.rs (get_issue_comments, create_issue_comment)
+//!   ├─ src/types/issue.rs (IssueComment)
+//!   └─ src/utils/markdown.rs (render_markdown)
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::tauri_api;
+use crate::types::issue::IssueComment;
+use crate::utils::render_markdown;
+
+/// Date part of an RFC 3339 timestamp.
+fn comment_date(iso: &str) -> String {
+    iso.split('T').next().unwrap_or(iso).to_string()
+}
+
+/// Issue comment thread with composer
+#[component]
+pub fn IssueComments(
+    project_id: i64,
+    issue_number: i32,
+    /// Repository of the issue (`None` = the project's primary one).
+    #[prop(default = None)]
+    repo_full_name: Option<String>,
+) -> impl IntoView {
+    let repo_full_name = StoredValue::new(repo_full_name);
+    let (comments, set_comments) = signal(Vec::<IssueComment>::new());
+    let (next_page, set_next_page) = signal(Option::<i32>::Some(1));
+    let (loading, set_loading) = signal(false);
+    let (from_cache, set_from_cache) = signal(false);
+    let (error, set_error) = signal(Option::<String>::None);
+
+    let (draft, set_draft) = signal(String::new());
+    let (preview, set_preview) = signal(false);
+    let (posting, set_posting) = signal(false);
+
+    // Append the next page (or restart from page 1 with `reset`).
+    let load_page = move |reset: bool| {
+        let page = if reset {
+            1
+        } else {
+            match next_page.get_untracked() {
+                Some(page) => page,
+                None => return,
+            }
+        };
+        set_loading.set(true);
+        set_error.set(None);
+        spawn_local(async move {
+            let repo = repo_full_name.get_value();
+            match tauri_api::get_issue_comments(
+                project_id,
+                issue_number,
+                page,
+                reset,
+                repo.as_deref(),
+            )
+            .await
+            {
+                Ok(response) => {
+                    let data = response.data;
+                    set_comments.update(|list| {
+                        if reset {
+                            list.clear();
+                        }
+                        list.extend(data.comments);
+                    });
+                    set_next_page.set(data.has_more.then_some(data.page + 1));
+                    set_from_cache.set(response.from_cache);
+                }
+                Err(e) => set_error.set(Some(e)),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    // Initial page
+    load_page(false);
+
+    let submit = move |_| {
+        let body = draft.get_untracked();
+        if body.trim().is_empty() {
+            return;
+        }
+        set_posting.set(true);
+        set_error.set(None);
+        spawn_local(async move {
+            let repo = repo_full_name.get_value();
+            match tauri_api::create_issue_comment(project_id, issue_number, &body, repo.as_deref())
+                .await
+            {
+                Ok(comment) => {
+                    set_draft.set(String::new());
+                    set_preview.set(false);
+                    // Only append when the thread is fully loaded; otherwise
+                    // the new comment shows up on the last page anyway.
+                    if next_page.get_untracked().is_none() {
+                        set_comments.update(|list| list.push(comment));
+                    }
+                }
+                Err(e) => set_error.set(Some(e)),
+            }
+            set_posting.set(false);
+        });
+    };
+
+    view! {
+        <div class="space-y-3">
+            <div class="flex items-center justify-between">
+                <label class="text-xs text-dt-text-sub">
+                    {move || format!("Comments ({}{})", comments.get().len(), if next_page.get().is_some() { "+" } else { "" })}
+                </label>
+                <div class="flex items-center gap-2">
+                    <Show when=move || from_cache.get()>
+                        <span class="text-xs text-yellow-400">"cached"</span>
+                    </Show>
+                    <button
+                        class="text-xs text-dt-text-sub hover:text-dt-text disabled:opacity-50"
+                        disabled=move || loading.get()
+                        on:click=move |_| load_page(true)
+                    >
+                        "Refresh"
+                    </button>
+                </div>
+            </div>
+
+            <For
+                each=move || comments.get()
+                key=|c| (c.id, c.updated_at.clone())
+                children=move |comment| {
+                    let author = comment.author_login.clone().unwrap_or_else(|| "ghost".to_string());
+                    let association = comment
+                        .author_association
+                        .clone()
+                        .filter(|a| a != "NONE")
+                        .map(|a| a.to_lowercase());
+                    let html_content = render_markdown(&comment.body);
+                    view! {
+                        <div class="bg-slate-800/50 rounded-lg border border-slate-700/50">
+                            <div class="flex items-center gap-2 px-3 py-2 border-b border-slate-700/50 text-xs text-dt-text-sub">
+                                {comment.author_avatar_url.clone().map(|url| view! {
+                                    <img src=url class="w-5 h-5 rounded-full" alt=author.clone() />
+                                })}
+                                <span class="text-dt-text font-medium">{author.clone()}</span>
+                                {association.map(|a| view! {
+                                    <span class="px-1.5 py-0.5 border border-slate-600 rounded">{a}</span>
+                                })}
+                                <a
+                                    href=comment.html_url.clone()
+                                    target="_blank"
+                                    rel="noopener noreferrer"
+                                    class="ml-auto hover:text-dt-text"
+                                >
+                                    {comment_date(&comment.created_at)}
+                                </a>
+                            </div>
+                            <div class="px-3 py-2 markdown-body text-sm" inner_html=html_content />
+                            {(!comment.reactions.is_empty()).then(|| view! {
+                                <div class="flex flex-wrap gap-1 px-3 pb-2">
+                                    {comment.reactions.iter().map(|r| view! {
+                                        <span
+                                            class="px-2 py-0.5 text-xs bg-slate-700/60 rounded-full"
+                                            title=r.content.clone()
+                                        >
+                                            {format!("{} {}", r.emoji, r.count)}
+                                        </span>
+                                    }).collect_view()}
+                                </div>
+                            })}
+                        </div>
+                    }
+                }
+            />
+
+            <Show when=move || next_page.get().is_some()>
+                <button
+                    class="w-full py-1.5 text-sm text-dt-text-sub border border-slate-700 rounded-lg hover:text-dt-text disabled:opacity-50"
+                    disabled=move || loading.get()
+                    on:click=move |_| load_page(false)
+                >
+                    {move || if loading.get() { "Loading…" } else { "Load more" }}
+                </button>
+            </Show>
+
+            <Show when=move || error.get().is_some()>
+                <div class="p-3 bg-red-500/20 border border-red-500/50 rounded-lg text-sm text-red-400">
+                    {move || error.get().unwrap_or_default()}
+                </div>
+            </Show>
+
+            // Composer
+            <Show when=move || { issue_number > 0 }>
+                <div class="border border-slate-700 rounded-lg">
+                    <div class="flex gap-1 px-2 pt-2 border-b border-slate-700 text-xs">
+                        <button
+                            class=move || format!(
+                                "px-3 py-1 rounded-t {}",
+                                if preview.get() { "text-dt-text-sub" } else { "bg-slate-800 text-dt-text" }
+                            )
+                            on:click=move |_| set_preview.set(false)
+                        >
+                            "Write"
+                        </button>
+                        <button
+                            class=move || format!(
+                                "px-3 py-1 rounded-t {}",
+                                if preview.get() { "bg-slate-800 text-dt-text" } else { "text-dt-text-sub" }
+                            )
+                            on:click=move |_| set_preview.set(true)
+                        >
+                            "Preview"
+                        </button>
+                    </div>
+                    <Show
+                        when=move || preview.get()
+                        fallback=move || view! {
+                            <textarea
+                                rows="4"
+                                class="w-full px-3 py-2 bg-transparent text-dt-text text-sm font-mono focus:outline-none"
+                                placeholder="Leave a comment (Markdown supported)"
+                                prop:value=move || draft.get()
+                                on:input=move |ev| set_draft.set(event_target_value(&ev))
+                            ></textarea>
+                        }
+                    >
+                        <div
+                            class="px-3 py-2 min-h-[6rem] markdown-body text-sm"
+                            inner_html=move || {
+                                let body = draft.get();
+                                if body.trim().is_empty() {
+                                    "<p>Nothing to preview</p>".to_string()
+                                } else {
+                                    render_markdown(&body)
+                                }
+                            }
+                        />
+                    </Show>
+                    <div class="flex justify-end px-2 pb-2">
+                        <button
+                            class="px-4 py-1.5 text-sm bg-gradient-to-r from-gm-accent-cyan to-gm-accent-purple text-white rounded-lg hover:opacity-90 disabled:opacity-50"
+                            disabled=move || posting.get() || draft.get().trim().is_empty()
+                            on:click=submit
+                        >
+                            {move || if posting.get() { "Posting…" } else { "Comment" }}
+                        </button>
+                    </div>
+                </div>
+            </Show>
+        </div>
+    }
+}
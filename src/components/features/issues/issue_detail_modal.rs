@@ -2,7 +2,10 @@
 //!
 //! Modal dialog displaying detailed information about an issue.
 //! Shows title, body, labels, assignee, status, and provides
-//! a link to the GitHub issue.
+//! a link to the GitHub issue. Fields can be edited in place
+//! (`IssueEditForm`), and the issue can be closed with a reason or
+//! reopened. The comment thread and composer live in `IssueComments`,
+//! tracked time and the timer in `IssueTimeLogPanel`.
 //!
 //! DEPENDENCY MAP:
 //!
@@ -10,14 +13,24 @@
 //!   └─ src/components/issues/project_dashboard.rs
 //! Dependencies:
 //!   ├─ src/types/issue.rs
+//!   ├─ src/tauri_api.rs (close_issue, reopen_issue)
 //!   ├─ src/components/icons.rs
+//!   ├─ src/components/features/issues/issue_comments.rs
+//!   ├─ src/components/features/issues/issue_edit_form.rs
+//!   ├─ src/components/features/issues/issue_time_log.rs
 //!   └─ src/components/ui/dialog/modal.rs
 
 use leptos::prelude::*;
+use leptos::task::spawn_local;
 
+use super::issue_comments::IssueComments;
+use super::issue_edit_form::IssueEditForm;
+use super::issue_time_log::IssueTimeLogPanel;
+use super::issue_timer::TimerToggleEvent;
 use crate::components::icons::Icon;
 use crate::components::ui::dialog::{Modal, ModalBody, ModalFooter, ModalHeader, ModalSize};
-use crate::types::issue::{CachedIssue, IssueStatus};
+use crate::tauri_api;
+use crate::types::issue::{CachedIssue, IssueStatus, TimeEntry};
 use crate::utils::render_markdown;
 
 /// Event emitted when issue status is changed from the detail modal
@@ -25,6 +38,7 @@ use crate::utils::render_markdown;
 pub struct IssueDetailStatusChange {
     pub issue_number: i32,
     pub new_status: String,
+    pub repo_full_name: Option<String>,
 }
 
 /// Issue detail modal component
@@ -35,11 +49,25 @@ pub fn IssueDetailModal(
     #[prop(into)] visible: Signal<bool>,
     on_close: impl Fn() + 'static + Clone + Send + Sync,
     status_change_signal: WriteSignal<Option<IssueDetailStatusChange>>,
+    project_id: i64,
+    /// Receives the refreshed issue after an edit / close / reopen.
+    issue_updated_signal: WriteSignal<Option<CachedIssue>>,
+    /// The user's running issue timer.
+    #[prop(into, optional)]
+    running_timer: Signal<Option<TimeEntry>>,
+    #[prop(into, optional)] now_ms: Signal<f64>,
+    /// Receives timer start / stop clicks.
+    #[prop(optional)]
+    timer_toggle_signal: Option<WriteSignal<Option<TimerToggleEvent>>>,
+    /// Bumped by the parent when tracked time changes.
+    #[prop(into, optional)]
+    time_refresh: Signal<u32>,
 ) -> impl IntoView {
     let status = issue.get_status();
     let labels = issue.get_labels();
     let priority = issue.get_priority();
     let issue_number = issue.number;
+    let repo_full_name = StoredValue::new(issue.repo_full_name.clone());
 
     // Status options for dropdown
     let statuses = StoredValue::new(vec![
@@ -52,6 +80,35 @@ pub fn IssueDetailModal(
     ]);
 
     let (show_status_dropdown, set_show_status_dropdown) = signal(false);
+    let (editing, set_editing) = signal(false);
+    let (show_close_menu, set_show_close_menu) = signal(false);
+    let (state_busy, set_state_busy) = signal(false);
+    let (state_error, set_state_error) = signal(Option::<String>::None);
+    let issue_for_edit = StoredValue::new(issue.clone());
+    let on_edit_cancel = Callback::new(move |_: ()| set_editing.set(false));
+
+    // Close (with reason) / reopen
+    let change_state = move |reason: Option<&'static str>| {
+        set_show_close_menu.set(false);
+        set_state_busy.set(true);
+        set_state_error.set(None);
+        spawn_local(async move {
+            let repo = repo_full_name.get_value();
+            let result = match reason {
+                Some(reason) => {
+                    tauri_api::close_issue(project_id, issue_number, reason, repo.as_deref()).await
+                }
+                None => {
+                    tauri_api::reopen_issue(project_id, issue_number, None, repo.as_deref()).await
+                }
+            };
+            match result {
+                Ok(updated) => issue_updated_signal.set(Some(updated)),
+                Err(e) => set_state_error.set(Some(e)),
+            }
+            set_state_busy.set(false);
+        });
+    };
 
     // Format dates - Store in StoredValue for ChildrenFn
     let created_at = StoredValue::new(
@@ -81,6 +138,19 @@ pub fn IssueDetailModal(
     let issue_html_url = StoredValue::new(issue.html_url.clone());
     let assignee_login = StoredValue::new(issue.assignee_login.clone());
     let assignee_avatar_url = StoredValue::new(issue.assignee_avatar_url.clone());
+    let other_assignees = StoredValue::new(
+        issue
+            .get_assignees()
+            .into_iter()
+            .skip(1)
+            .collect::<Vec<_>>(),
+    );
+    let milestone_title = StoredValue::new(issue.milestone_title.clone());
+    let state_display = match (issue.is_open(), issue.state_reason.as_deref()) {
+        (true, _) => "Open",
+        (false, Some("not_planned")) => "Closed (not planned)",
+        (false, _) => "Closed",
+    };
     let labels_stored = StoredValue::new(labels.clone());
 
     view! {
@@ -101,7 +171,7 @@ pub fn IssueDetailModal(
                             "px-2 py-0.5 text-xs rounded-full {}",
                             if issue_is_open { "bg-green-500/20 text-green-400" } else { "bg-purple-500/20 text-purple-400" }
                         )>
-                            {if issue_is_open { "Open" } else { "Closed" }}
+                            {state_display}
                         </span>
                     </div>
                     <h2 class="text-xl font-semibold text-dt-text break-words">
@@ -112,6 +182,15 @@ pub fn IssueDetailModal(
 
             // Content (scrollable)
             <ModalBody class="max-h-[60vh]">
+                <Show when=move || editing.get()>
+                    <IssueEditForm
+                        issue=issue_for_edit.get_value()
+                        project_id=project_id
+                        on_saved=issue_updated_signal
+                        on_cancel=on_edit_cancel
+                    />
+                </Show>
+                <Show when=move || !editing.get()>
                 <div class="space-y-6">
                     // Metadata grid
                     <div class="grid grid-cols-2 gap-4">
@@ -155,6 +234,7 @@ pub fn IssueDetailModal(
                                                             status_change_signal.set(Some(IssueDetailStatusChange {
                                                                 issue_number,
                                                                 new_status: value.clone(),
+                                                                repo_full_name: repo_full_name.get_value(),
                                                             }));
                                                             set_show_status_dropdown.set(false);
                                                         }
@@ -209,6 +289,25 @@ pub fn IssueDetailModal(
                                 }).unwrap_or_else(|| view! {
                                     <span class="text-dt-text-sub">"Unassigned"</span>
                                 }.into_any())}
+                                {move || {
+                                    let others = other_assignees.get_value();
+                                    (!others.is_empty()).then(|| view! {
+                                        <span class="text-xs text-dt-text-sub" title=others.join(", ")>
+                                            {format!("+{}", others.len())}
+                                        </span>
+                                    })
+                                }}
+                            </div>
+                        </div>
+
+                        // Milestone
+                        <div>
+                            <label class="block text-xs text-dt-text-sub mb-1">"Milestone"</label>
+                            <div class="px-3 py-1.5 bg-slate-800 rounded-lg text-sm">
+                                {move || match milestone_title.get_value() {
+                                    Some(title) => view! { <span class="text-dt-text">{title}</span> }.into_any(),
+                                    None => view! { <span class="text-dt-text-sub">"None"</span> }.into_any(),
+                                }}
                             </div>
                         </div>
 
@@ -276,7 +375,33 @@ pub fn IssueDetailModal(
                     <div class="text-xs text-dt-text-sub flex items-center gap-4">
                         <span>"Updated: "{updated_at.get_value()}</span>
                     </div>
+
+                    // Time tracking (not for queued creates)
+                    <Show when=move || { issue_number > 0 }>
+                        <IssueTimeLogPanel
+                            project_id=project_id
+                            issue_number=issue_number
+                            repo_full_name=repo_full_name.get_value()
+                            running_timer=running_timer
+                            now_ms=now_ms
+                            timer_toggle_signal=timer_toggle_signal
+                            refresh=time_refresh
+                        />
+                    </Show>
+
+                    // Discussion
+                    <IssueComments
+                        project_id=project_id
+                        issue_number=issue_number
+                        repo_full_name=repo_full_name.get_value()
+                    />
                 </div>
+                </Show>
+                <Show when=move || state_error.get().is_some()>
+                    <div class="mt-4 p-3 bg-red-500/20 border border-red-500/50 rounded-lg text-sm text-red-400">
+                        {move || state_error.get().unwrap_or_default()}
+                    </div>
+                </Show>
             </ModalBody>
 
             // Footer
@@ -294,6 +419,53 @@ pub fn IssueDetailModal(
                         </a>
                     }
                 })}
+                <Show when=move || !editing.get()>
+                    <button
+                        class="px-4 py-1.5 text-sm border border-slate-700 hover:border-gm-accent-cyan text-dt-text rounded-lg transition-colors"
+                        on:click=move |_| set_editing.set(true)
+                    >
+                        "Edit"
+                    </button>
+                    {if issue_is_open {
+                        view! {
+                            <div class="relative">
+                                <button
+                                    class="px-4 py-1.5 text-sm border border-purple-500/50 text-purple-300 hover:bg-purple-500/10 rounded-lg transition-colors disabled:opacity-50"
+                                    disabled=move || state_busy.get()
+                                    on:click=move |_| set_show_close_menu.update(|v| *v = !*v)
+                                >
+                                    "Close issue"
+                                </button>
+                                <Show when=move || show_close_menu.get()>
+                                    <div class="absolute bottom-full right-0 mb-1 w-48 bg-slate-900 rounded-lg shadow-lg border border-slate-700 py-1 z-10">
+                                        <button
+                                            class="w-full px-3 py-2 text-left text-sm text-white hover:bg-slate-800"
+                                            on:click=move |_| change_state(Some("completed"))
+                                        >
+                                            "Close as completed"
+                                        </button>
+                                        <button
+                                            class="w-full px-3 py-2 text-left text-sm text-white hover:bg-slate-800"
+                                            on:click=move |_| change_state(Some("not_planned"))
+                                        >
+                                            "Close as not planned"
+                                        </button>
+                                    </div>
+                                </Show>
+                            </div>
+                        }.into_any()
+                    } else {
+                        view! {
+                            <button
+                                class="px-4 py-1.5 text-sm border border-green-500/50 text-green-300 hover:bg-green-500/10 rounded-lg transition-colors disabled:opacity-50"
+                                disabled=move || state_busy.get()
+                                on:click=move |_| change_state(None)
+                            >
+                                "Reopen"
+                            </button>
+                        }.into_any()
+                    }}
+                </Show>
                 <button
                     class="px-4 py-1.5 text-sm bg-slate-800 hover:bg-slate-700 text-dt-text rounded-lg transition-colors"
                     on:click=move |_| on_close_stored.get_value()()
@@ -0,0 +1,237 @@
+//! Issue Edit Form Component
+//!
+//! Inline form shown inside the issue detail modal to edit title, body,
+//! assignees, milestone and labels. Choices come from
+//! `get_issue_edit_options`; saving calls `update_issue_fields` and hands
+//! the refreshed issue back to the parent.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents:
+//!   └─ src/components/features/issues/issue_detail_modal.rs
+//! Dependencies:
+//!   ├─ src/tauri_api.rs (get_issue_edit_options, update_issue_fields)
+//!   └─ src/types/issue.rs
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::tauri_api;
+use crate::types::issue::{CachedIssue, IssueEditOptions, UpdateIssueFieldsRequest};
+
+/// Toggle `value` in a list signal.
+fn toggle(list: WriteSignal<Vec<String>>, value: String) {
+    list.update(|items| {
+        if let Some(pos) = items.iter().position(|v| *v == value) {
+            items.remove(pos);
+        } else {
+            items.push(value);
+        }
+    });
+}
+
+/// Issue edit form
+#[component]
+pub fn IssueEditForm(
+    issue: CachedIssue,
+    project_id: i64,
+    /// Receives the updated issue after a successful save.
+    on_saved: WriteSignal<Option<CachedIssue>>,
+    on_cancel: Callback<()>,
+) -> impl IntoView {
+    let issue_number = issue.number;
+    let repo_full_name = StoredValue::new(issue.repo_full_name.clone());
+    let (title, set_title) = signal(issue.title.clone());
+    let (body, set_body) = signal(issue.body.clone().unwrap_or_default());
+    let (assignees, set_assignees) = signal(issue.get_assignees());
+    let (milestone, set_milestone) = signal(issue.milestone_number);
+    // Status labels belong to the kanban columns and are not editable here.
+    let (labels, set_labels) = signal(
+        issue
+            .get_labels()
+            .into_iter()
+            .filter(|l| !l.starts_with("status:"))
+            .collect::<Vec<_>>(),
+    );
+    let (options, set_options) = signal(Option::<IssueEditOptions>::None);
+    let (saving, set_saving) = signal(false);
+    let (error, set_error) = signal(Option::<String>::None);
+
+    spawn_local(async move {
+        match tauri_api::get_issue_edit_options(project_id, repo_full_name.get_value().as_deref())
+            .await
+        {
+            Ok(opts) => set_options.set(Some(opts)),
+            Err(e) => set_error.set(Some(e)),
+        }
+    });
+
+    let save = move |_| {
+        if title.get_untracked().trim().is_empty() {
+            set_error.set(Some("タイトルを入力してください".to_string()));
+            return;
+        }
+        let update = UpdateIssueFieldsRequest {
+            title: Some(title.get_untracked()),
+            body: Some(body.get_untracked()),
+            assignees: Some(assignees.get_untracked()),
+            milestone: Some(milestone.get_untracked()),
+            labels: Some(labels.get_untracked()),
+        };
+        set_saving.set(true);
+        set_error.set(None);
+        spawn_local(async move {
+            let repo = repo_full_name.get_value();
+            match tauri_api::update_issue_fields(project_id, issue_number, &update, repo.as_deref())
+                .await
+            {
+                Ok(updated) => on_saved.set(Some(updated)),
+                Err(e) => set_error.set(Some(e)),
+            }
+            set_saving.set(false);
+        });
+    };
+
+    view! {
+        <div class="space-y-4">
+            <div>
+                <label class="block text-xs text-dt-text-sub mb-1">"Title"</label>
+                <input
+                    type="text"
+                    class="w-full px-3 py-2 bg-slate-800 border border-slate-700 rounded-lg text-dt-text focus:outline-none focus:border-gm-accent-cyan"
+                    prop:value=move || title.get()
+                    on:input=move |ev| set_title.set(event_target_value(&ev))
+                />
+            </div>
+
+            <div>
+                <label class="block text-xs text-dt-text-sub mb-1">"Description"</label>
+                <textarea
+                    rows="8"
+                    class="w-full px-3 py-2 bg-slate-800 border border-slate-700 rounded-lg text-dt-text font-mono text-sm focus:outline-none focus:border-gm-accent-cyan"
+                    prop:value=move || body.get()
+                    on:input=move |ev| set_body.set(event_target_value(&ev))
+                ></textarea>
+            </div>
+
+            <Show
+                when=move || options.get().is_some()
+                fallback=move || view! {
+                    <div class="text-sm text-dt-text-sub">"Loading labels, assignees and milestones…"</div>
+                }
+            >
+                {move || {
+                    let opts = options.get().unwrap_or_default();
+                    view! {
+                        <div>
+                            <label class="block text-xs text-dt-text-sub mb-1">"Milestone"</label>
+                            <select
+                                class="w-full px-3 py-2 bg-slate-800 border border-slate-700 rounded-lg text-dt-text"
+                                on:change=move |ev| {
+                                    set_milestone.set(event_target_value(&ev).parse::<i32>().ok())
+                                }
+                            >
+                                <option value="" selected=move || milestone.get().is_none()>"No milestone"</option>
+                                {opts.milestones.iter().map(|m| {
+                                    let number = m.number;
+                                    view! {
+                                        <option
+                                            value=number.to_string()
+                                            selected=move || milestone.get() == Some(number)
+                                        >
+                                            {m.title.clone()}
+                                        </option>
+                                    }
+                                }).collect_view()}
+                            </select>
+                        </div>
+
+                        <div>
+                            <label class="block text-xs text-dt-text-sub mb-1">"Assignees"</label>
+                            <div class="flex flex-wrap gap-2">
+                                {opts.assignees.iter().map(|a| {
+                                    let login = a.login.clone();
+                                    let login_for_check = login.clone();
+                                    let login_for_toggle = login.clone();
+                                    view! {
+                                        <button
+                                            type="button"
+                                            class=move || format!(
+                                                "flex items-center gap-1 px-2 py-1 rounded-full text-xs border {}",
+                                                if assignees.get().contains(&login_for_check) {
+                                                    "border-gm-accent-cyan text-gm-accent-cyan bg-gm-accent-cyan/10"
+                                                } else {
+                                                    "border-slate-600 text-dt-text-sub"
+                                                }
+                                            )
+                                            on:click=move |_| toggle(set_assignees, login_for_toggle.clone())
+                                        >
+                                            <img src=a.avatar_url.clone() class="w-4 h-4 rounded-full" alt=login.clone() />
+                                            {"@"}{login.clone()}
+                                        </button>
+                                    }
+                                }).collect_view()}
+                            </div>
+                        </div>
+
+                        <div>
+                            <label class="block text-xs text-dt-text-sub mb-1">"Labels"</label>
+                            <div class="flex flex-wrap gap-2">
+                                {opts.labels.iter().map(|l| {
+                                    let name = l.name.clone();
+                                    let name_for_check = name.clone();
+                                    let name_for_toggle = name.clone();
+                                    let color = l.color.clone();
+                                    view! {
+                                        <button
+                                            type="button"
+                                            class=move || format!(
+                                                "flex items-center gap-1 px-2 py-1 rounded-full text-xs border {}",
+                                                if labels.get().contains(&name_for_check) {
+                                                    "border-gm-accent-cyan text-dt-text bg-gm-accent-cyan/10"
+                                                } else {
+                                                    "border-slate-600 text-dt-text-sub"
+                                                }
+                                            )
+                                            on:click=move |_| toggle(set_labels, name_for_toggle.clone())
+                                        >
+                                            <span
+                                                class="w-2.5 h-2.5 rounded-full"
+                                                style=format!("background-color: #{}", color)
+                                            ></span>
+                                            {name}
+                                        </button>
+                                    }
+                                }).collect_view()}
+                            </div>
+                        </div>
+                    }
+                }}
+            </Show>
+
+            <Show when=move || error.get().is_some()>
+                <div class="p-3 bg-red-500/20 border border-red-500/50 rounded-lg text-sm text-red-400">
+                    {move || error.get().unwrap_or_default()}
+                </div>
+            </Show>
+
+            <div class="flex justify-end gap-2">
+                <button
+                    type="button"
+                    class="px-4 py-1.5 text-sm bg-slate-800 hover:bg-slate-700 text-dt-text rounded-lg transition-colors"
+                    on:click=move |_| on_cancel.run(())
+                >
+                    "Cancel"
+                </button>
+                <button
+                    type="button"
+                    class="px-4 py-1.5 text-sm bg-gradient-to-r from-gm-accent-cyan to-gm-accent-purple text-white rounded-lg hover:opacity-90 disabled:opacity-50"
+                    disabled=move || saving.get()
+                    on:click=save
+                >
+                    {move || if saving.get() { "Saving…" } else { "Save" }}
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,159 @@
+//! Issue Search Component
+//!
+//! Search box + result list over the local issue cache (see
+//! `src-tauri/src/commands/issue_search.rs`). Queries never hit GitHub, so
+//! the box keeps working offline. Supports free text and `status:`,
+//! `priority:`, `label:`, `assignee:`, `repo:` filters.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents:
+//!   └─ src/components/pages/projects/mod.rs
+//! Dependencies:
+//!   ├─ src/tauri_api.rs (search_issues_local)
+//!   ├─ src/types/issue.rs (IssueSearchResult, highlight_segments)
+//!   └─ src/components/icons.rs
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::icons::Icon;
+use crate::tauri_api;
+use crate::types::issue::{highlight_segments, IssueSearchResult};
+
+/// Wait this long after the last keystroke before querying.
+const SEARCH_DEBOUNCE_MS: u32 = 250;
+
+/// Render `\u{2}…\u{3}`-marked text with `<mark>` spans.
+fn highlighted(text: &str) -> impl IntoView {
+    highlight_segments(text)
+        .into_iter()
+        .map(|(segment, is_match)| {
+            if is_match {
+                view! { <mark class="bg-gm-accent-cyan/30 text-dt-text rounded px-0.5">{segment}</mark> }
+                    .into_any()
+            } else {
+                view! { <span>{segment}</span> }.into_any()
+            }
+        })
+        .collect_view()
+}
+
+/// Cross-project issue search. `on_select` receives the project id of the
+/// clicked result.
+#[component]
+pub fn IssueSearch(on_select: Callback<i64>) -> impl IntoView {
+    let (query, set_query) = signal(String::new());
+    let (results, set_results) = signal(Vec::<IssueSearchResult>::new());
+    let (searching, set_searching) = signal(false);
+    let (error, set_error) = signal(Option::<String>::None);
+    // Bumped on every keystroke; stale responses are dropped.
+    let (generation, set_generation) = signal(0u32);
+
+    let run_search = move |text: String| {
+        set_generation.update(|g| *g += 1);
+        let current = generation.get_untracked();
+        if text.trim().is_empty() {
+            set_results.set(Vec::new());
+            set_error.set(None);
+            set_searching.set(false);
+            return;
+        }
+        set_searching.set(true);
+        gloo_timers::callback::Timeout::new(SEARCH_DEBOUNCE_MS, move || {
+            if generation.get_untracked() != current {
+                return;
+            }
+            spawn_local(async move {
+                let result = tauri_api::search_issues_local(&text, None).await;
+                if generation.get_untracked() != current {
+                    return;
+                }
+                match result {
+                    Ok(hits) => {
+                        set_results.set(hits);
+                        set_error.set(None);
+                    }
+                    Err(e) => set_error.set(Some(e)),
+                }
+                set_searching.set(false);
+            });
+        })
+        .forget();
+    };
+
+    view! {
+        <div class="mb-6">
+            <div class="relative">
+                <Icon
+                    name="search".to_string()
+                    class="w-4 h-4 absolute left-3 top-1/2 -translate-y-1/2 text-dt-text-sub".to_string()
+                />
+                <input
+                    type="search"
+                    class="w-full pl-9 pr-3 py-2 bg-slate-800/60 border border-slate-700 rounded-lg text-dt-text placeholder-dt-text-sub focus:outline-none focus:border-gm-accent-cyan"
+                    placeholder="Search issues…  status:todo label:bug assignee:alice repo:owner/name"
+                    prop:value=move || query.get()
+                    on:input=move |ev| {
+                        let text = event_target_value(&ev);
+                        set_query.set(text.clone());
+                        run_search(text);
+                    }
+                />
+            </div>
+
+            <Show when=move || error.get().is_some()>
+                <div class="mt-2 text-sm text-red-400">{move || error.get().unwrap_or_default()}</div>
+            </Show>
+
+            <Show when=move || !query.get().trim().is_empty()>
+                <div class="mt-2 bg-dt-card border border-slate-700/50 rounded-lg divide-y divide-slate-700/50 max-h-96 overflow-y-auto">
+                    <Show when=move || !searching.get() && results.get().is_empty()>
+                        <div class="p-3 text-sm text-dt-text-sub">"一致する Issue はありません"</div>
+                    </Show>
+                    <For
+                        each=move || results.get()
+                        key=|hit| (hit.issue.id, hit.title_highlight.clone(), hit.body_snippet.clone())
+                        children=move |hit| {
+                            let project_id = hit.issue.project_id;
+                            let number = if hit.issue.number > 0 {
+                                format!("#{}", hit.issue.number)
+                            } else {
+                                "#—".to_string()
+                            };
+                            let location = hit
+                                .repo_full_name
+                                .clone()
+                                .unwrap_or_else(|| hit.project_name.clone());
+                            let status = hit.issue.get_status();
+                            let is_open = hit.issue.is_open();
+                            let title = hit.title_highlight.clone();
+                            let snippet = hit.body_snippet.clone();
+                            view! {
+                                <button
+                                    class="w-full text-left p-3 hover:bg-slate-800/60 transition-colors"
+                                    on:click=move |_| on_select.run(project_id)
+                                >
+                                    <div class="flex items-center gap-2 text-xs text-dt-text-sub">
+                                        <span>{location}</span>
+                                        <span>{number}</span>
+                                        <span class=format!("px-1.5 py-0.5 rounded text-white {}", status.color_class())>
+                                            {status.display_name()}
+                                        </span>
+                                        <Show when=move || !is_open>
+                                            <span class="text-purple-300">"closed"</span>
+                                        </Show>
+                                    </div>
+                                    <div class="mt-1 text-sm text-dt-text truncate">{highlighted(&title)}</div>
+                                    {snippet.map(|s| view! {
+                                        <div class="mt-1 text-xs text-dt-text-sub line-clamp-2">{highlighted(&s)}</div>
+                                    })}
+                                </button>
+                            }
+                        }
+                    />
+                </div>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,193 @@
+//! Issue Template Form Component
+//!
+//! Renders the elements of a GitHub issue form (`markdown`, `input`,
+//! `textarea`, `dropdown`, `checkboxes`) inside the create-issue modal.
+//! Entered values are written to a map keyed by `IssueFormField::key`;
+//! validation and body rendering happen in `build_issue_from_template`.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents:
+//!   └─ src/components/features/issues/create_issue_modal.rs
+//! Dependencies:
+//!   ├─ src/types/issue.rs (IssueFormField)
+//!   └─ src/utils/markdown.rs (render_markdown)
+
+use std::collections::HashMap;
+
+use leptos::prelude::*;
+
+use crate::types::issue::{IssueFormField, IssueTemplate};
+use crate::utils::render_markdown;
+
+/// Entered values, keyed by field key.
+pub type TemplateValues = HashMap<String, Vec<String>>;
+
+/// Initial values of a form: `value` defaults and the `default` dropdown
+/// choice.
+pub fn initial_template_values(template: &IssueTemplate) -> TemplateValues {
+    template
+        .fields
+        .iter()
+        .filter_map(|field| match field.field_type.as_str() {
+            "input" | "textarea" => field
+                .value
+                .clone()
+                .map(|value| (field.key.clone(), vec![value])),
+            "dropdown" => field
+                .default_option
+                .and_then(|i| field.options.get(i))
+                .map(|option| (field.key.clone(), vec![option.clone()])),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Toggle `value` in the list of `key`.
+fn toggle_value(values: RwSignal<TemplateValues>, key: &str, value: &str, multiple: bool) {
+    values.update(|map| {
+        let list = map.entry(key.to_string()).or_default();
+        if let Some(pos) = list.iter().position(|v| v == value) {
+            list.remove(pos);
+        } else {
+            if !multiple {
+                list.clear();
+            }
+            list.push(value.to_string());
+        }
+    });
+}
+
+fn field_label(field: &IssueFormField) -> impl IntoView {
+    let label = field.label.clone().unwrap_or_default();
+    let required = field.required;
+    let description = field.description.clone();
+    view! {
+        <label class="block text-sm font-medium text-dt-text-sub mb-1">
+            {label}
+            {required.then(|| view! { <span class="text-red-400">" *"</span> })}
+        </label>
+        {description.map(|d| view! {
+            <div class="text-xs text-dt-text-sub mb-1 markdown-body" inner_html=render_markdown(&d)></div>
+        })}
+    }
+}
+
+/// Issue form fields
+#[component]
+pub fn IssueTemplateForm(
+    template: IssueTemplate,
+    values: RwSignal<TemplateValues>,
+) -> impl IntoView {
+    let input_class = "w-full px-3 py-2 bg-slate-800 border border-slate-700 rounded-lg text-dt-text focus:border-gm-accent-cyan focus:outline-none";
+
+    view! {
+        <div class="space-y-4">
+            {template.fields.into_iter().map(|field| {
+                let key = StoredValue::new(field.key.clone());
+                let current = move || {
+                    values.with(|map| {
+                        key.with_value(|k| map.get(k).cloned().unwrap_or_default())
+                    })
+                };
+                match field.field_type.as_str() {
+                    "markdown" => view! {
+                        <div
+                            class="text-sm text-dt-text-sub markdown-body"
+                            inner_html=render_markdown(field.value.as_deref().unwrap_or_default())
+                        ></div>
+                    }.into_any(),
+                    "input" => view! {
+                        <div>
+                            {field_label(&field)}
+                            <input
+                                type="text"
+                                class=input_class
+                                placeholder=field.placeholder.clone().unwrap_or_default()
+                                prop:value=move || current().join("\n")
+                                on:input=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    values.update(|map| { map.insert(key.get_value(), vec![value]); });
+                                }
+                            />
+                        </div>
+                    }.into_any(),
+                    "textarea" => {
+                        let mono = field.render.is_some();
+                        view! {
+                            <div>
+                                {field_label(&field)}
+                                <textarea
+                                    rows="5"
+                                    class=format!("{} resize-y {}", input_class, if mono { "font-mono text-sm" } else { "" })
+                                    placeholder=field.placeholder.clone().unwrap_or_default()
+                                    prop:value=move || current().join("\n")
+                                    on:input=move |ev| {
+                                        let value = event_target_value(&ev);
+                                        values.update(|map| { map.insert(key.get_value(), vec![value]); });
+                                    }
+                                ></textarea>
+                            </div>
+                        }.into_any()
+                    }
+                    "dropdown" => {
+                        let multiple = field.multiple;
+                        view! {
+                            <div>
+                                {field_label(&field)}
+                                <div class="flex flex-wrap gap-2">
+                                    {field.options.iter().map(|option| {
+                                        let option = StoredValue::new(option.clone());
+                                        view! {
+                                            <button
+                                                type="button"
+                                                class=move || format!(
+                                                    "px-2 py-1 rounded-lg text-xs border {}",
+                                                    if option.with_value(|o| current().contains(o)) {
+                                                        "border-gm-accent-cyan text-gm-accent-cyan bg-gm-accent-cyan/10"
+                                                    } else {
+                                                        "border-slate-600 text-dt-text-sub"
+                                                    }
+                                                )
+                                                on:click=move |_| {
+                                                    key.with_value(|k| option.with_value(|o| toggle_value(values, k, o, multiple)))
+                                                }
+                                            >
+                                                {option.get_value()}
+                                            </button>
+                                        }
+                                    }).collect_view()}
+                                </div>
+                            </div>
+                        }.into_any()
+                    }
+                    "checkboxes" => view! {
+                        <div>
+                            {field_label(&field)}
+                            <div class="space-y-1">
+                                {field.checkboxes.iter().map(|option| {
+                                    let label = StoredValue::new(option.label.clone());
+                                    let required = option.required;
+                                    view! {
+                                        <label class="flex items-center gap-2 text-sm text-dt-text">
+                                            <input
+                                                type="checkbox"
+                                                prop:checked=move || label.with_value(|l| current().contains(l))
+                                                on:change=move |_| {
+                                                    key.with_value(|k| label.with_value(|l| toggle_value(values, k, l, true)))
+                                                }
+                                            />
+                                            <span>{label.get_value()}</span>
+                                            {required.then(|| view! { <span class="text-red-400">"*"</span> })}
+                                        </label>
+                                    }
+                                }).collect_view()}
+                            </div>
+                        </div>
+                    }.into_any(),
+                    _ => ().into_any(),
+                }
+            }).collect_view()}
+        </div>
+    }
+}
@@ -0,0 +1,170 @@
+//! Issue Time Log Component
+//!
+//! "Time tracked" section of the issue detail modal: total time, the
+//! timer button, the list of logged sessions (with delete), and a button
+//! that posts the log to GitHub as a summary comment. Entries come from
+//! `get_issue_time_entries` and reload whenever `refresh` changes (the
+//! dashboard bumps it after a timer stops).
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents:
+//!   └─ src/components/features/issues/issue_detail_modal.rs
+//! Dependencies:
+//!   ├─ src/tauri_api.rs (get_issue_time_entries, delete_time_entry, post_issue_time_summary)
+//!   ├─ src/types/issue.rs (IssueTimeLog, TimeEntry, format_duration)
+//!   └─ src/components/features/issues/issue_timer.rs
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use super::issue_timer::{IssueTimerButton, TimerToggleEvent};
+use crate::tauri_api;
+use crate::types::issue::{format_duration, IssueTimeLog, TimeEntry};
+
+/// Time tracked on one issue
+#[component]
+pub fn IssueTimeLogPanel(
+    project_id: i64,
+    issue_number: i32,
+    /// Repository of the issue (`None` = the project's primary one).
+    #[prop(default = None)]
+    repo_full_name: Option<String>,
+    #[prop(into)] running_timer: Signal<Option<TimeEntry>>,
+    #[prop(into)] now_ms: Signal<f64>,
+    /// Receives timer start / stop clicks; no timer button without it.
+    #[prop(default = None)]
+    timer_toggle_signal: Option<WriteSignal<Option<TimerToggleEvent>>>,
+    /// Reload trigger, bumped by the parent after a timer stops.
+    #[prop(into, optional)]
+    refresh: Signal<u32>,
+) -> impl IntoView {
+    let repo_full_name = StoredValue::new(repo_full_name);
+    let (log, set_log) = signal(IssueTimeLog::default());
+    let (error, set_error) = signal(Option::<String>::None);
+    let (posting, set_posting) = signal(false);
+    let (posted, set_posted) = signal(false);
+
+    let load = move || {
+        spawn_local(async move {
+            let repo = repo_full_name.get_value();
+            match tauri_api::get_issue_time_entries(project_id, issue_number, repo.as_deref()).await
+            {
+                Ok(data) => set_log.set(data),
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    };
+
+    Effect::new(move |_| {
+        refresh.track();
+        load();
+    });
+
+    let delete = move |entry_id: i64| {
+        set_error.set(None);
+        spawn_local(async move {
+            match tauri_api::delete_time_entry(entry_id).await {
+                Ok(()) => load(),
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    };
+
+    let post_summary = move |_| {
+        set_posting.set(true);
+        set_posted.set(false);
+        set_error.set(None);
+        spawn_local(async move {
+            let repo = repo_full_name.get_value();
+            match tauri_api::post_issue_time_summary(project_id, issue_number, repo.as_deref())
+                .await
+            {
+                Ok(_) => set_posted.set(true),
+                Err(e) => set_error.set(Some(e)),
+            }
+            set_posting.set(false);
+        });
+    };
+
+    let finished = move || {
+        log.with(|log| {
+            log.entries
+                .iter()
+                .filter(|entry| !entry.is_running())
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+    };
+
+    view! {
+        <div>
+            <div class="flex items-center justify-between mb-2">
+                <label class="block text-xs text-dt-text-sub">
+                    "Time tracked"
+                    <span class="ml-2 text-dt-text font-mono">
+                        {move || format_duration(log.with(|log| log.total_seconds))}
+                    </span>
+                </label>
+                <div class="flex items-center gap-2">
+                    {timer_toggle_signal.map(|toggle| view! {
+                        <IssueTimerButton
+                            issue_number=issue_number
+                            repo_full_name=repo_full_name.get_value()
+                            running_timer=running_timer
+                            now_ms=now_ms
+                            timer_toggle_signal=toggle
+                            labeled=true
+                        />
+                    })}
+                    <button
+                        class="px-3 py-1.5 text-sm border border-slate-700 hover:border-gm-accent-cyan text-dt-text rounded-lg transition-colors disabled:opacity-50"
+                        disabled=move || posting.get() || finished().is_empty()
+                        on:click=post_summary
+                    >
+                        {move || if posting.get() { "Posting..." } else { "Post summary" }}
+                    </button>
+                </div>
+            </div>
+            <Show when=move || posted.get()>
+                <div class="mb-2 text-xs text-green-400">"Summary posted as a comment."</div>
+            </Show>
+            <Show when=move || error.get().is_some()>
+                <div class="mb-2 text-xs text-red-400">{move || error.get().unwrap_or_default()}</div>
+            </Show>
+            {move || {
+                let entries = finished();
+                if entries.is_empty() {
+                    return view! {
+                        <p class="text-sm text-dt-text-sub italic">"No time logged yet."</p>
+                    }.into_any();
+                }
+                view! {
+                    <ul class="divide-y divide-slate-700/50 bg-slate-800/50 rounded-lg border border-slate-700/50">
+                        {entries.into_iter().map(|entry| {
+                            let entry_id = entry.id;
+                            let date = entry.started_at.split('T').next().unwrap_or_default().to_string();
+                            view! {
+                                <li class="flex items-center gap-3 px-3 py-1.5 text-sm">
+                                    <span class="text-dt-text-sub font-mono">{date}</span>
+                                    <span class="text-dt-text font-mono">{format_duration(entry.duration_seconds)}</span>
+                                    <span class="flex-1 text-dt-text-sub truncate">{entry.note.unwrap_or_default()}</span>
+                                    {(entry.xp_awarded > 0).then(|| view! {
+                                        <span class="text-xs text-gm-accent-cyan">{format!("+{} XP", entry.xp_awarded)}</span>
+                                    })}
+                                    <button
+                                        class="text-xs text-dt-text-sub hover:text-red-400 transition-colors"
+                                        title="Delete entry"
+                                        on:click=move |_| delete(entry_id)
+                                    >
+                                        "✕"
+                                    </button>
+                                </li>
+                            }
+                        }).collect_view()}
+                    </ul>
+                }.into_any()
+            }}
+        </div>
+    }
+}
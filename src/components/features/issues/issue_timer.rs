@@ -0,0 +1,91 @@
+//! Issue Timer Components
+//!
+//! Start / stop button for the per-issue timer, shown on kanban cards and
+//! in the issue detail modal. Only one timer runs at a time; the button
+//! reports a `TimerToggleEvent` and the project dashboard talks to the
+//! backend (`start_issue_timer` / `stop_issue_timer`) and owns the
+//! running-timer state.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents:
+//!   ├─ src/components/features/issues/kanban_board.rs
+//!   └─ src/components/features/issues/issue_detail_modal.rs
+//! Dependencies:
+//!   └─ src/types/issue.rs (TimeEntry, format_clock)
+
+use leptos::prelude::*;
+
+use crate::types::issue::{format_clock, TimeEntry};
+
+/// Timer start / stop request for one issue
+#[derive(Clone, Debug)]
+pub struct TimerToggleEvent {
+    pub issue_number: i32,
+    /// Repository of the issue (`None` = the project's primary one)
+    pub repo_full_name: Option<String>,
+    /// `true` = start a timer on this issue, `false` = stop the running one
+    pub start: bool,
+}
+
+/// Play / stop button with the elapsed time while this issue's timer runs
+#[component]
+pub fn IssueTimerButton(
+    issue_number: i32,
+    repo_full_name: Option<String>,
+    running_timer: Signal<Option<TimeEntry>>,
+    now_ms: Signal<f64>,
+    timer_toggle_signal: WriteSignal<Option<TimerToggleEvent>>,
+    /// Larger button with a label (detail modal) instead of the card icon
+    #[prop(optional)]
+    labeled: bool,
+) -> impl IntoView {
+    let repo_full_name = StoredValue::new(repo_full_name);
+
+    let running_here = move || {
+        running_timer.with(|timer| {
+            timer.as_ref().is_some_and(|entry| {
+                repo_full_name.with_value(|repo| entry.is_for(issue_number, repo.as_deref()))
+            })
+        })
+    };
+    let elapsed = move || {
+        running_timer
+            .get()
+            .map(|entry| format_clock(entry.elapsed_seconds(now_ms.get())))
+            .unwrap_or_default()
+    };
+
+    view! {
+        <button
+            class=move || {
+                let size = if labeled { "px-3 py-1.5 text-sm" } else { "p-1 text-xs" };
+                let color = if running_here() {
+                    "text-gm-accent-cyan bg-gm-accent-cyan/10 hover:bg-gm-accent-cyan/20"
+                } else {
+                    "text-gray-400 hover:text-gm-accent-cyan hover:bg-gray-700"
+                };
+                format!("flex items-center gap-1 rounded font-mono transition-all {} {}", size, color)
+            }
+            title=move || if running_here() { "Stop timer" } else { "Start timer" }
+            on:click=move |e: web_sys::MouseEvent| {
+                e.stop_propagation();
+                timer_toggle_signal.set(Some(TimerToggleEvent {
+                    issue_number,
+                    repo_full_name: repo_full_name.get_value(),
+                    start: !running_here(),
+                }));
+            }
+        >
+            <span>{move || if running_here() { "■" } else { "▶" }}</span>
+            <Show when=move || running_here()>
+                <span>{elapsed}</span>
+            </Show>
+            {labeled.then(|| view! {
+                <span class="font-sans">
+                    {move || if running_here() { "Stop" } else { "Start timer" }}
+                </span>
+            })}
+        </button>
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! Displays issues in a Linear-style kanban board layout with columns
 //! for each status. Supports drag and drop for status changes using
-//! mouse events (works in Tauri WebView).
+//! mouse events (works in Tauri WebView), and multi-select for bulk
+//! moves / labels / assignees / close through `bulk_update_issues`.
 //!
 //! DEPENDENCY MAP:
 //!
@@ -10,25 +11,65 @@
 //!   └─ src/components/issues/project_dashboard.rs
 //! Dependencies:
 //!   ├─ src/types/issue.rs
-//!   └─ src/components/issues/issue_card.rs
+//!   ├─ src/components/issues/issue_card.rs
+//!   └─ src/components/issues/issue_timer.rs
 
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
 use crate::components::icons::Icon;
-use crate::components::issues::{IssueClickEvent, StatusChangeEvent};
+use crate::components::issues::{
+    IssueClickEvent, IssueTimerButton, StatusChangeEvent, TimerToggleEvent,
+};
 use crate::tauri_api;
-use crate::types::issue::{IssueStatus, KanbanBoard as KanbanBoardType};
+use crate::types::issue::{
+    BulkIssueAction, BulkIssueTarget, IssueStatus, KanbanBoard as KanbanBoardType, TimeEntry,
+};
 use crate::types::CachedIssue;
 
 /// Drag state for mouse-based drag and drop
 #[derive(Clone, Debug, PartialEq)]
 pub struct MouseDragState {
     pub issue_number: i32,
+    pub repo_full_name: Option<String>,
     pub from_status: String,
     pub issue_title: String,
 }
 
+/// Bulk action on the selected cards
+#[derive(Clone, Debug)]
+pub struct BulkActionEvent {
+    pub targets: Vec<BulkIssueTarget>,
+    pub action: BulkIssueAction,
+}
+
+/// Value of a status as used by the backend (`in-progress`, ...)
+fn status_value(status: IssueStatus) -> &'static str {
+    match status {
+        IssueStatus::Backlog => "backlog",
+        IssueStatus::Todo => "todo",
+        IssueStatus::InProgress => "in-progress",
+        IssueStatus::InReview => "in-review",
+        IssueStatus::Done => "done",
+        IssueStatus::Cancelled => "cancelled",
+    }
+}
+
+fn parse_status(value: &str) -> Option<IssueStatus> {
+    IssueStatus::all()
+        .into_iter()
+        .find(|s| status_value(*s) == value)
+}
+
+/// Split a comma-separated input into trimmed, non-empty entries.
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
 /// Kanban board component with mouse-based drag and drop support
 /// Uses mousedown/mouseup instead of HTML5 drag events for Tauri compatibility
 ///
@@ -38,13 +79,52 @@ pub struct MouseDragState {
 /// GitHub 404 every time and spam the user with errors against a repo
 /// that's gone (PR #213 P2 review). Cards remain clickable for the
 /// detail viewer / external GitHub link — only mutations are blocked.
+///
+/// With `bulk_action_signal` set, cards get a selection checkbox and a
+/// toolbar acts on the selection; dragging a selected card moves the
+/// whole selection.
 #[component]
 pub fn KanbanBoard(
     board: ReadSignal<KanbanBoardType>,
     status_change_signal: WriteSignal<Option<StatusChangeEvent>>,
     issue_click_signal: WriteSignal<Option<IssueClickEvent>>,
     #[prop(into, optional)] read_only: Signal<bool>,
+    /// Show each card's repository (for projects linking several repos).
+    #[prop(into, optional)]
+    show_repo: Signal<bool>,
+    /// The user's running issue timer (shown ticking on its card).
+    #[prop(into, optional)]
+    running_timer: Signal<Option<TimeEntry>>,
+    /// Current time in ms, updated every second while a timer runs.
+    #[prop(into, optional)]
+    now_ms: Signal<f64>,
+    /// Receives timer start / stop clicks; no timer buttons without it.
+    #[prop(optional)]
+    timer_toggle_signal: Option<WriteSignal<Option<TimerToggleEvent>>>,
+    /// Receives bulk actions; no multi-select without it.
+    #[prop(optional)]
+    bulk_action_signal: Option<WriteSignal<Option<BulkActionEvent>>>,
 ) -> impl IntoView {
+    // Selected cards for bulk actions
+    let selection = RwSignal::new(Vec::<BulkIssueTarget>::new());
+    let selectable = Signal::derive(move || bulk_action_signal.is_some() && !read_only.get());
+
+    // Forget selected cards that left the board (filter change, sync)
+    Effect::new(move |_| {
+        let board = board.get();
+        let all_statuses = IssueStatus::all();
+        selection.update(|selected| {
+            selected.retain(|target| {
+                all_statuses.iter().any(|status| {
+                    board.get_issues(*status).iter().any(|issue| {
+                        issue.number == target.issue_number
+                            && issue.repo_full_name == target.repo_full_name
+                    })
+                })
+            })
+        });
+    });
+
     // Track the currently dragged issue using mouse events
     let (dragging, set_dragging) = signal(Option::<MouseDragState>::None);
     // Track which column the mouse is currently hovering over
@@ -80,7 +160,24 @@ pub fn KanbanBoard(
         let mouseup_handler = Closure::wrap(Box::new(move |_: web_sys::MouseEvent| {
             if let Some(drag_state) = dragging_for_mouseup.get_untracked() {
                 if let Some(target_status) = hover_column_for_mouseup.get_untracked() {
-                    if drag_state.from_status != target_status {
+                    // Dragging one of several selected cards moves them all
+                    let selected = selection.get_untracked();
+                    let drags_selection = selected.len() > 1
+                        && selected.iter().any(|t| {
+                            t.issue_number == drag_state.issue_number
+                                && t.repo_full_name == drag_state.repo_full_name
+                        });
+                    if let (true, Some(bulk), Some(status)) = (
+                        drags_selection,
+                        bulk_action_signal,
+                        parse_status(&target_status),
+                    ) {
+                        bulk.set(Some(BulkActionEvent {
+                            targets: selected,
+                            action: BulkIssueAction::Move { status },
+                        }));
+                        selection.set(Vec::new());
+                    } else if drag_state.from_status != target_status {
                         leptos::logging::log!(
                             "🎯 Drop! Moving issue #{} from {} to {}",
                             drag_state.issue_number,
@@ -90,6 +187,7 @@ pub fn KanbanBoard(
                         status_change_signal_for_mouseup.set(Some(StatusChangeEvent {
                             issue_number: drag_state.issue_number,
                             new_status: target_status,
+                            repo_full_name: drag_state.repo_full_name,
                         }));
                     } else {
                         leptos::logging::log!("🔄 Dropped on same column, no change");
@@ -128,6 +226,11 @@ pub fn KanbanBoard(
 
     view! {
         <div class="flex-1 h-full overflow-x-auto p-4 select-none relative">
+            {bulk_action_signal.map(|bulk| view! {
+                <Show when=move || selectable.get() && !selection.with(|s| s.is_empty())>
+                    <BulkActionBar selection=selection bulk_action_signal=bulk />
+                </Show>
+            })}
             <div class="flex gap-4 h-full min-w-max">
                 {IssueStatus::visible()
                     .into_iter()
@@ -143,6 +246,12 @@ pub fn KanbanBoard(
                                 set_hover_column=set_hover_column
                                 hover_column=hover_column
                                 read_only=read_only
+                                show_repo=show_repo
+                                running_timer=running_timer
+                                now_ms=now_ms
+                                timer_toggle_signal=timer_toggle_signal
+                                selection=selection
+                                selectable=selectable
                             />
                         }
                     })
@@ -187,16 +296,15 @@ fn KanbanColumn(
     set_hover_column: WriteSignal<Option<String>>,
     hover_column: ReadSignal<Option<String>>,
     read_only: Signal<bool>,
+    show_repo: Signal<bool>,
+    running_timer: Signal<Option<TimeEntry>>,
+    now_ms: Signal<f64>,
+    timer_toggle_signal: Option<WriteSignal<Option<TimerToggleEvent>>>,
+    selection: RwSignal<Vec<BulkIssueTarget>>,
+    selectable: Signal<bool>,
 ) -> impl IntoView {
     let status_name = status.display_name();
-    let status_value: &'static str = match status {
-        IssueStatus::Backlog => "backlog",
-        IssueStatus::Todo => "todo",
-        IssueStatus::InProgress => "in-progress",
-        IssueStatus::InReview => "in-review",
-        IssueStatus::Done => "done",
-        IssueStatus::Cancelled => "cancelled",
-    };
+    let status_value = status_value(status);
     let status_color = match status {
         IssueStatus::Backlog => "bg-gray-400",
         IssueStatus::Todo => "bg-blue-500",
@@ -217,6 +325,23 @@ fn KanbanColumn(
     // Check if dragging at all
     let is_dragging = move || dragging.get().is_some();
 
+    // Targets of every card in this column
+    let column_targets = move || {
+        board
+            .get()
+            .get_issues(status)
+            .iter()
+            .map(|issue| BulkIssueTarget {
+                issue_number: issue.number,
+                repo_full_name: issue.repo_full_name.clone(),
+            })
+            .collect::<Vec<_>>()
+    };
+    let column_selected = move || {
+        let targets = column_targets();
+        !targets.is_empty() && selection.with(|s| targets.iter().all(|t| s.contains(t)))
+    };
+
     // Check if this column is currently being hovered
     let is_hovered = move || {
         hover_column
@@ -276,6 +401,24 @@ fn KanbanColumn(
             }>
                 <div class="flex items-center justify-between">
                     <div class="flex items-center gap-2">
+                        <Show when=move || { selectable.get() && board.get().count(status) > 0 }>
+                            <input
+                                type="checkbox"
+                                class="accent-gm-accent-cyan"
+                                title="Select all in column"
+                                prop:checked=column_selected
+                                on:change=move |ev| {
+                                    let checked = event_target_checked(&ev);
+                                    let targets = column_targets();
+                                    selection.update(|s| {
+                                        s.retain(|t| !targets.contains(t));
+                                        if checked {
+                                            s.extend(targets);
+                                        }
+                                    });
+                                }
+                            />
+                        </Show>
                         <span class=format!("w-2 h-2 rounded-full {}", status_color) />
                         <span class="font-medium text-dt-text">{status_name}</span>
                     </div>
@@ -295,6 +438,12 @@ fn KanbanColumn(
                 dragging=dragging
                 set_dragging=set_dragging
                 read_only=read_only
+                show_repo=show_repo
+                running_timer=running_timer
+                now_ms=now_ms
+                timer_toggle_signal=timer_toggle_signal
+                selection=selection
+                selectable=selectable
             />
 
             // Drop indicator
@@ -322,6 +471,12 @@ fn CompletedColumnContent(
     dragging: ReadSignal<Option<MouseDragState>>,
     set_dragging: WriteSignal<Option<MouseDragState>>,
     read_only: Signal<bool>,
+    show_repo: Signal<bool>,
+    running_timer: Signal<Option<TimeEntry>>,
+    now_ms: Signal<f64>,
+    timer_toggle_signal: Option<WriteSignal<Option<TimerToggleEvent>>>,
+    selection: RwSignal<Vec<BulkIssueTarget>>,
+    selectable: Signal<bool>,
 ) -> impl IntoView {
     let is_completed_status = matches!(status, IssueStatus::Done | IssueStatus::Cancelled);
     let (show_all, set_show_all) = signal(false);
@@ -389,6 +544,12 @@ fn CompletedColumnContent(
                                             dragging=dragging
                                             set_dragging=set_dragging
                                             read_only=read_only
+                                            show_repo=show_repo
+                                            running_timer=running_timer
+                                            now_ms=now_ms
+                                            timer_toggle_signal=timer_toggle_signal
+                                            selection=selection
+                                            selectable=selectable
                                         />
                                     }
                                 })
@@ -443,6 +604,12 @@ fn CompletedColumnContent(
                                             dragging=dragging
                                             set_dragging=set_dragging
                                             read_only=read_only
+                                            show_repo=show_repo
+                                            running_timer=running_timer
+                                            now_ms=now_ms
+                                            timer_toggle_signal=timer_toggle_signal
+                                            selection=selection
+                                            selectable=selectable
                                         />
                                     }
                                 })
@@ -469,6 +636,12 @@ fn IssueCardDraggable(
     dragging: ReadSignal<Option<MouseDragState>>,
     set_dragging: WriteSignal<Option<MouseDragState>>,
     read_only: Signal<bool>,
+    show_repo: Signal<bool>,
+    running_timer: Signal<Option<TimeEntry>>,
+    now_ms: Signal<f64>,
+    timer_toggle_signal: Option<WriteSignal<Option<TimerToggleEvent>>>,
+    selection: RwSignal<Vec<BulkIssueTarget>>,
+    selectable: Signal<bool>,
 ) -> impl IntoView {
     let (is_mouse_down, set_is_mouse_down) = signal(false);
     let issue_clone = issue.clone();
@@ -478,16 +651,28 @@ fn IssueCardDraggable(
     let issue_title_for_drag = issue.title.clone();
     let issue_title_for_title_drag = issue.title.clone();
     let issue_number = issue.number;
+    let repo_full_name = StoredValue::new(issue.repo_full_name.clone());
+    let issue_pending_sync = issue.pending_sync;
     let issue_body = issue.body.clone();
     let issue_assignee = issue.assignee_login.clone();
+    let linked_prs = issue.get_linked_prs();
     let issue_status_for_drag = issue.status.clone();
     let issue_status_for_title_drag = issue.status.clone();
 
+    let target = StoredValue::new(BulkIssueTarget {
+        issue_number,
+        repo_full_name: issue.repo_full_name.clone(),
+    });
+    let is_selected = move || selection.with(|s| target.with_value(|t| s.contains(t)));
+
     // Check if this card is being dragged
     let is_being_dragged = move || {
         dragging
             .get()
-            .map(|d| d.issue_number == issue_number)
+            .map(|d| {
+                d.issue_number == issue_number
+                    && repo_full_name.with_value(|repo| d.repo_full_name == *repo)
+            })
             .unwrap_or(false)
     };
 
@@ -504,6 +689,8 @@ fn IssueCardDraggable(
                         "cursor-default border-gray-700 opacity-90"
                     } else if is_being_dragged() {
                         "opacity-30 border-gm-accent-cyan scale-95 cursor-grabbing"
+                    } else if is_selected() {
+                        "cursor-grab border-gm-accent-cyan ring-1 ring-gm-accent-cyan/50"
                     } else if is_mouse_down.get() {
                         "cursor-grabbing border-gm-accent-cyan/50"
                     } else {
@@ -537,6 +724,7 @@ fn IssueCardDraggable(
                         set_is_mouse_down.set(true);
                         set_dragging.set(Some(MouseDragState {
                             issue_number,
+                            repo_full_name: repo_full_name.get_value(),
                             from_status: issue_status.clone(),
                             issue_title: title.clone(),
                         }));
@@ -557,8 +745,67 @@ fn IssueCardDraggable(
         >
             // Header with issue number, detail button, and GitHub link
             <div class="flex items-center justify-between mb-2">
-                <span class="text-xs text-gray-400 font-mono">{"#"} {issue_number}</span>
+                <div class="flex items-center gap-1.5">
+                    <Show when=move || selectable.get()>
+                        <input
+                            type="checkbox"
+                            class="accent-gm-accent-cyan"
+                            title="Select for bulk actions"
+                            prop:checked=is_selected
+                            on:mousedown=move |e| e.stop_propagation()
+                            on:change=move |ev| {
+                                let checked = event_target_checked(&ev);
+                                let target = target.get_value();
+                                selection.update(|s| {
+                                    s.retain(|t| *t != target);
+                                    if checked {
+                                        s.push(target);
+                                    }
+                                });
+                            }
+                        />
+                    </Show>
+                    // Queued creates have a negative placeholder number
+                    // until the outbox replays them; don't show "#-3".
+                    {if issue_number > 0 {
+                        view! { <span class="text-xs text-gray-400 font-mono">{"#"} {issue_number}</span> }.into_any()
+                    } else {
+                        view! { <span class="text-xs text-gray-400 font-mono">"#—"</span> }.into_any()
+                    }}
+                    <Show when=move || show_repo.get()>
+                        <span
+                            class="px-1.5 py-0.5 text-[10px] rounded bg-slate-700 text-gray-300 font-mono"
+                            title=move || repo_full_name.get_value().unwrap_or_default()
+                        >
+                            {move || repo_full_name.with_value(|repo| {
+                                repo.as_deref()
+                                    .map(|name| name.rsplit('/').next().unwrap_or(name).to_string())
+                                    .unwrap_or_default()
+                            })}
+                        </span>
+                    </Show>
+                    <Show when=move || issue_pending_sync>
+                        <span
+                            class="px-1.5 py-0.5 text-[10px] rounded bg-amber-500/20 text-amber-300 border border-amber-500/40"
+                            title="オフライン中の変更です。オンライン復帰時に GitHub へ反映されます。"
+                        >
+                            "未同期"
+                        </span>
+                    </Show>
+                </div>
                 <div class="flex items-center gap-1" on:mousedown=move |e| e.stop_propagation()>
+                    // Timer (not for queued creates or archived projects)
+                    {timer_toggle_signal.filter(|_| issue_number > 0).map(|toggle| view! {
+                        <Show when=move || !read_only.get()>
+                            <IssueTimerButton
+                                issue_number=issue_number
+                                repo_full_name=repo_full_name.get_value()
+                                running_timer=running_timer
+                                now_ms=now_ms
+                                timer_toggle_signal=toggle
+                            />
+                        </Show>
+                    })}
                     // Detail button
                     <button
                         class="p-1 text-gray-400 hover:text-gm-accent-cyan hover:bg-gray-700 rounded transition-all"
@@ -618,6 +865,7 @@ fn IssueCardDraggable(
                             set_is_mouse_down.set(true);
                             set_dragging.set(Some(MouseDragState {
                                 issue_number,
+                                repo_full_name: repo_full_name.get_value(),
                                 from_status: issue_status.clone(),
                                 issue_title: title.clone(),
                             }));
@@ -634,6 +882,35 @@ fn IssueCardDraggable(
                 view! { <p class="text-xs text-gray-400 mb-2 line-clamp-2">{body}</p> }
             })}
 
+            // Linked PRs with CI / review / merge state
+            {(!linked_prs.is_empty()).then(|| view! {
+                <div class="flex flex-wrap gap-1 mb-1" on:mousedown=move |e| e.stop_propagation()>
+                    {linked_prs.into_iter().map(|pr| {
+                        let url = pr.url.clone();
+                        view! {
+                            <button
+                                class=format!("flex items-center gap-1 px-1.5 py-0.5 text-[10px] rounded border {}", pr.badge_class())
+                                title=pr.summary()
+                                on:click=move |e: web_sys::MouseEvent| {
+                                    e.stop_propagation();
+                                    let url = url.clone();
+                                    spawn_local(async move {
+                                        if let Err(e) = tauri_api::open_external_url(&url).await {
+                                            leptos::logging::log!("Failed to open URL: {}", e);
+                                        }
+                                    });
+                                }
+                            >
+                                <Icon name="git-branch".to_string() class="w-3 h-3".to_string() />
+                                {format!("#{}", pr.number)}
+                                {pr.checks_icon()}
+                                {(pr.review_decision.as_deref() == Some("APPROVED")).then_some("👍")}
+                            </button>
+                        }
+                    }).collect_view()}
+                </div>
+            })}
+
             // Footer - Assignee only (status changed via drag & drop)
             {issue_assignee.map(|assignee| {
                 let assignee_display = assignee.clone();
@@ -648,3 +925,126 @@ fn IssueCardDraggable(
         </div>
     }
 }
+
+/// Toolbar acting on the selected cards
+#[component]
+fn BulkActionBar(
+    selection: RwSignal<Vec<BulkIssueTarget>>,
+    bulk_action_signal: WriteSignal<Option<BulkActionEvent>>,
+) -> impl IntoView {
+    let (labels_input, set_labels_input) = signal(String::new());
+    let (assignees_input, set_assignees_input) = signal(String::new());
+
+    let emit = move |action: BulkIssueAction| {
+        bulk_action_signal.set(Some(BulkActionEvent {
+            targets: selection.get_untracked(),
+            action,
+        }));
+        selection.set(Vec::new());
+    };
+    let labels = move || split_list(&labels_input.get_untracked());
+
+    let button_class = "px-2 py-1 text-xs text-dt-text-sub hover:text-dt-text border border-slate-700 hover:border-gm-accent-cyan rounded transition-colors disabled:opacity-50";
+    let input_class = "w-36 px-2 py-1 text-xs bg-slate-800 border border-slate-700 rounded text-dt-text focus:border-gm-accent-cyan focus:outline-none";
+
+    view! {
+        <div class="sticky left-0 mb-3 flex flex-wrap items-center gap-3 p-2 bg-slate-800/80 border border-gm-accent-cyan/40 rounded-lg">
+            <span class="text-sm font-medium text-gm-accent-cyan">
+                {move || format!("{} selected", selection.with(|s| s.len()))}
+            </span>
+
+            // Move
+            <select
+                class="px-2 py-1 text-xs bg-slate-800 border border-slate-700 rounded text-dt-text"
+                prop:value=""
+                on:change=move |ev| {
+                    if let Some(status) = parse_status(&event_target_value(&ev)) {
+                        emit(BulkIssueAction::Move { status });
+                    }
+                }
+            >
+                <option value="">"Move to..."</option>
+                {IssueStatus::visible().into_iter().map(|status| view! {
+                    <option value=status_value(status)>{status.display_name()}</option>
+                }).collect_view()}
+            </select>
+
+            // Labels
+            <div class="flex items-center gap-1">
+                <input
+                    type="text"
+                    class=input_class
+                    placeholder="labels, comma-separated"
+                    prop:value=move || labels_input.get()
+                    on:input=move |ev| set_labels_input.set(event_target_value(&ev))
+                />
+                <button
+                    class=button_class
+                    disabled=move || split_list(&labels_input.get()).is_empty()
+                    on:click=move |_| {
+                        emit(BulkIssueAction::AddLabels { labels: labels() });
+                        set_labels_input.set(String::new());
+                    }
+                >
+                    "+ Label"
+                </button>
+                <button
+                    class=button_class
+                    disabled=move || split_list(&labels_input.get()).is_empty()
+                    on:click=move |_| {
+                        emit(BulkIssueAction::RemoveLabels { labels: labels() });
+                        set_labels_input.set(String::new());
+                    }
+                >
+                    "- Label"
+                </button>
+            </div>
+
+            // Assignees (empty = unassign)
+            <div class="flex items-center gap-1">
+                <input
+                    type="text"
+                    class=input_class
+                    placeholder="assignees (empty = none)"
+                    prop:value=move || assignees_input.get()
+                    on:input=move |ev| set_assignees_input.set(event_target_value(&ev))
+                />
+                <button
+                    class=button_class
+                    on:click=move |_| {
+                        emit(BulkIssueAction::SetAssignees {
+                            assignees: split_list(&assignees_input.get_untracked()),
+                        });
+                        set_assignees_input.set(String::new());
+                    }
+                >
+                    "Assign"
+                </button>
+            </div>
+
+            // Close
+            <div class="flex items-center gap-1">
+                <button
+                    class=button_class
+                    on:click=move |_| emit(BulkIssueAction::Close { reason: "completed".to_string() })
+                >
+                    "Close (done)"
+                </button>
+                <button
+                    class=button_class
+                    on:click=move |_| emit(BulkIssueAction::Close { reason: "not_planned".to_string() })
+                >
+                    "Close (not planned)"
+                </button>
+            </div>
+
+            <button
+                class="ml-auto flex items-center gap-1 px-2 py-1 text-xs text-dt-text-sub hover:text-dt-text"
+                on:click=move |_| selection.set(Vec::new())
+            >
+                <Icon name="x".to_string() class="w-3 h-3".to_string() />
+                "Clear"
+            </button>
+        </div>
+    }
+}
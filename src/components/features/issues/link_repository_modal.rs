@@ -1,6 +1,8 @@
 //! Link Repository Modal Component
 //!
-//! Modal dialog for linking a GitHub repository to a project.
+//! Modal dialog for linking a GitHub repository to a project. Linking adds
+//! the repository next to the ones already linked; those are hidden from
+//! the list.
 //!
 //! DEPENDENCY MAP:
 //!
@@ -27,6 +29,9 @@ pub fn LinkRepositoryModal(
     #[prop(into)] visible: Signal<bool>,
     on_close: impl Fn() + 'static + Clone + Send + Sync,
     on_linked: impl Fn(Project) + 'static + Copy,
+    /// `owner/repo` names already linked to the project.
+    #[prop(into, optional)]
+    linked_repos: Signal<Vec<String>>,
 ) -> impl IntoView {
     let (repositories, set_repositories) = signal(Vec::<RepositoryInfo>::new());
     let (loading, set_loading) = signal(true);
@@ -60,21 +65,20 @@ pub fn LinkRepositoryModal(
     // Filter repositories based on search
     let filtered_repos = move || {
         let query = search_query.get().to_lowercase();
-        if query.is_empty() {
-            repositories.get()
-        } else {
-            repositories
-                .get()
-                .into_iter()
-                .filter(|r| {
-                    r.full_name.to_lowercase().contains(&query)
-                        || r.description
-                            .as_ref()
-                            .map(|d| d.to_lowercase().contains(&query))
-                            .unwrap_or(false)
-                })
-                .collect()
-        }
+        let linked = linked_repos.get();
+        repositories
+            .get()
+            .into_iter()
+            .filter(|r| !linked.contains(&r.full_name))
+            .filter(|r| {
+                query.is_empty()
+                    || r.full_name.to_lowercase().contains(&query)
+                    || r.description
+                        .as_ref()
+                        .map(|d| d.to_lowercase().contains(&query))
+                        .unwrap_or(false)
+            })
+            .collect::<Vec<_>>()
     };
 
     // Store on_close for use in ChildrenFn
@@ -0,0 +1,186 @@
+//! Milestone Burndown Component
+//!
+//! Progress panel shown above the kanban board when a milestone is
+//! selected: completion bar, due date, and an SVG chart with the burndown
+//! (remaining open vs. the ideal line to the due date) or the burnup
+//! (closed vs. total scope). Series come from `get_milestone_burndown`.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents:
+//!   └─ src/components/pages/project_dashboard/mod.rs
+//! Dependencies:
+//!   ├─ src/tauri_api.rs (get_milestone_burndown)
+//!   └─ src/types/issue.rs (MilestoneBurndown, BurndownPoint)
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::tauri_api;
+use crate::types::issue::{BurndownPoint, MilestoneBurndown};
+
+const CHART_WIDTH: f64 = 600.0;
+const CHART_HEIGHT: f64 = 160.0;
+const CHART_PADDING: f64 = 8.0;
+
+/// SVG polyline points for `values` (one per day, `None` = gap at the end).
+fn polyline(values: &[Option<f64>], max: f64) -> String {
+    let steps = values.len().saturating_sub(1).max(1) as f64;
+    let inner_w = CHART_WIDTH - CHART_PADDING * 2.0;
+    let inner_h = CHART_HEIGHT - CHART_PADDING * 2.0;
+    values
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| {
+            let v = (*v)?;
+            let x = CHART_PADDING + inner_w * i as f64 / steps;
+            let y = CHART_PADDING + inner_h * (1.0 - v / max.max(1.0));
+            Some(format!("{:.1},{:.1}", x, y))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Highest value drawn on either chart.
+fn chart_max(points: &[BurndownPoint]) -> f64 {
+    points
+        .iter()
+        .flat_map(|p| [p.open, p.closed, p.scope].into_iter().flatten())
+        .map(|v| v as f64)
+        .chain(points.iter().filter_map(|p| p.ideal))
+        .fold(0.0, f64::max)
+}
+
+/// Burndown / burnup panel for one milestone
+#[component]
+pub fn MilestoneBurndownPanel(project_id: i64, milestone_number: i32) -> impl IntoView {
+    let (burndown, set_burndown) = signal(Option::<MilestoneBurndown>::None);
+    let (error, set_error) = signal(Option::<String>::None);
+    let (burnup, set_burnup) = signal(false);
+
+    spawn_local(async move {
+        match tauri_api::get_milestone_burndown(project_id, milestone_number).await {
+            Ok(data) => set_burndown.set(Some(data)),
+            Err(e) => set_error.set(Some(e)),
+        }
+    });
+
+    let tab_class = move |active: bool| {
+        format!(
+            "px-2 py-0.5 rounded {}",
+            if active {
+                "bg-slate-700 text-dt-text"
+            } else {
+                "text-dt-text-sub hover:text-dt-text"
+            }
+        )
+    };
+
+    view! {
+        <div class="mx-4 mt-4 p-4 bg-dt-card/50 border border-slate-700/50 rounded-lg">
+            <Show when=move || error.get().is_some()>
+                <div class="text-sm text-red-400">{move || error.get().unwrap_or_default()}</div>
+            </Show>
+            {move || burndown.get().map(|data| {
+                let milestone = data.milestone.clone();
+                let progress = milestone.progress_percent();
+                let due = milestone.due_date().map(|d| format!("Due {}", d));
+                let first = data.points.first().map(|p| p.date.clone()).unwrap_or_default();
+                let last = data.points.last().map(|p| p.date.clone()).unwrap_or_default();
+                let points = StoredValue::new(data.points);
+                view! {
+                    <div class="flex items-center justify-between gap-3 mb-2">
+                        <div class="flex items-center gap-2 text-sm">
+                            <span class="font-medium text-dt-text">{milestone.title.clone()}</span>
+                            <span class="text-dt-text-sub">
+                                {format!("{} / {} closed", milestone.closed_issues, milestone.open_issues + milestone.closed_issues)}
+                            </span>
+                            {due.map(|d| view! { <span class="text-dt-text-sub">{d}</span> })}
+                            {(milestone.state == "closed").then(|| view! {
+                                <span class="px-1.5 py-0.5 text-xs border border-purple-400/50 text-purple-300 rounded">"closed"</span>
+                            })}
+                        </div>
+                        <div class="flex gap-1 text-xs">
+                            <button class=move || tab_class(!burnup.get()) on:click=move |_| set_burnup.set(false)>
+                                "Burndown"
+                            </button>
+                            <button class=move || tab_class(burnup.get()) on:click=move |_| set_burnup.set(true)>
+                                "Burnup"
+                            </button>
+                        </div>
+                    </div>
+                    <div class="h-1.5 mb-3 bg-slate-700 rounded-full overflow-hidden">
+                        <div
+                            class="h-full bg-gradient-to-r from-gm-accent-cyan to-gm-accent-purple"
+                            style=format!("width: {:.0}%", progress)
+                        ></div>
+                    </div>
+                    {move || points.with_value(|points| {
+                        if points.is_empty() {
+                            return view! {
+                                <div class="text-sm text-dt-text-sub">"No data yet. Sync to record the first snapshot."</div>
+                            }.into_any();
+                        }
+                        let max = chart_max(points);
+                        let (primary, secondary, primary_class, secondary_class, legend) = if burnup.get() {
+                            (
+                                polyline(&points.iter().map(|p| p.closed.map(|v| v as f64)).collect::<Vec<_>>(), max),
+                                polyline(&points.iter().map(|p| p.scope.map(|v| v as f64)).collect::<Vec<_>>(), max),
+                                "stroke-green-400",
+                                "stroke-slate-500",
+                                ("Closed", "Scope"),
+                            )
+                        } else {
+                            (
+                                polyline(&points.iter().map(|p| p.open.map(|v| v as f64)).collect::<Vec<_>>(), max),
+                                polyline(&points.iter().map(|p| p.ideal).collect::<Vec<_>>(), max),
+                                "stroke-gm-accent-cyan",
+                                "stroke-slate-500",
+                                ("Open", "Ideal"),
+                            )
+                        };
+                        view! {
+                            <svg
+                                class="w-full h-40"
+                                viewBox=format!("0 0 {} {}", CHART_WIDTH, CHART_HEIGHT)
+                                preserveAspectRatio="none"
+                            >
+                                <polyline
+                                    points=secondary
+                                    fill="none"
+                                    class=secondary_class
+                                    stroke-width="1.5"
+                                    stroke-dasharray="4 3"
+                                    vector-effect="non-scaling-stroke"
+                                />
+                                <polyline
+                                    points=primary
+                                    fill="none"
+                                    class=primary_class
+                                    stroke-width="2"
+                                    vector-effect="non-scaling-stroke"
+                                />
+                            </svg>
+                            <div class="flex items-center justify-between mt-1 text-xs text-dt-text-sub">
+                                <span>{first.clone()}</span>
+                                <span>{format!("— {}   - - {}", legend.0, legend.1)}</span>
+                                <span>{last.clone()}</span>
+                            </div>
+                        }.into_any()
+                    })}
+                }
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polyline_skips_missing_tail() {
+        let line = polyline(&[Some(4.0), Some(2.0), None], 4.0);
+        assert_eq!(line, "8.0,8.0 300.0,80.0");
+    }
+}
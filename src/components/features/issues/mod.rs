@@ -5,15 +5,35 @@
 pub mod create_issue_modal;
 pub mod create_project_modal;
 pub mod issue_card;
+pub mod issue_comments;
 pub mod issue_detail_modal;
+pub mod issue_edit_form;
+pub mod issue_search;
+pub mod issue_template_form;
+pub mod issue_time_log;
+pub mod issue_timer;
 pub mod kanban_board;
 pub mod link_repository_modal;
+pub mod milestone_burndown;
+pub mod outbox_panel;
 pub mod project_card;
+pub mod time_summary_panel;
+pub mod workflow_settings_modal;
 
 pub use create_issue_modal::CreateIssueModal;
 pub use create_project_modal::CreateProjectModal;
 pub use issue_card::{IssueCard, IssueClickEvent, StatusChangeEvent};
+pub use issue_comments::IssueComments;
 pub use issue_detail_modal::{IssueDetailModal, IssueDetailStatusChange};
-pub use kanban_board::KanbanBoard;
+pub use issue_edit_form::IssueEditForm;
+pub use issue_search::IssueSearch;
+pub use issue_template_form::IssueTemplateForm;
+pub use issue_time_log::IssueTimeLogPanel;
+pub use issue_timer::{IssueTimerButton, TimerToggleEvent};
+pub use kanban_board::{BulkActionEvent, KanbanBoard};
 pub use link_repository_modal::LinkRepositoryModal;
+pub use milestone_burndown::MilestoneBurndownPanel;
+pub use outbox_panel::{OutboxAction, OutboxPanel};
 pub use project_card::{ProjectCard, ProjectsEmptyState};
+pub use time_summary_panel::TimeSummaryPanel;
+pub use workflow_settings_modal::WorkflowSettingsModal;
@@ -0,0 +1,120 @@
+//! Offline Outbox Panel Component
+//!
+//! Lists issue mutations queued while offline (see
+//! `src-tauri/src/commands/issue_outbox.rs`). Pending operations are shown
+//! as a compact counter; failed / conflicting ones get Retry, Overwrite
+//! (force) and Discard actions so the user can resolve them explicitly.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents:
+//!   └─ src/components/pages/project_dashboard/mod.rs
+//! Dependencies:
+//!   ├─ src/types/issue.rs (OutboxOperation)
+//!   └─ src/components/icons.rs
+
+use leptos::prelude::*;
+
+use crate::components::icons::Icon;
+use crate::types::issue::OutboxOperation;
+
+/// User action on a single outbox operation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OutboxAction {
+    /// Put back in the queue; `force` drops the conflict baseline.
+    Retry {
+        operation_id: i64,
+        force: bool,
+    },
+    Discard {
+        operation_id: i64,
+    },
+}
+
+/// Outbox summary + resolution list. Renders nothing when the queue is
+/// empty.
+#[component]
+pub fn OutboxPanel(
+    operations: ReadSignal<Vec<OutboxOperation>>,
+    action_signal: WriteSignal<Option<OutboxAction>>,
+) -> impl IntoView {
+    let pending_count = move || {
+        operations
+            .get()
+            .iter()
+            .filter(|op| op.status == "pending")
+            .count()
+    };
+    let attention = move || {
+        operations
+            .get()
+            .into_iter()
+            .filter(|op| op.needs_attention())
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <Show when=move || !operations.get().is_empty()>
+            <div class="mt-3 p-3 bg-slate-800/60 border border-slate-700 rounded-lg text-sm">
+                <Show when=move || { pending_count() > 0 }>
+                    <div class="flex items-center gap-2 text-amber-300">
+                        <Icon name="refresh".to_string() class="w-4 h-4".to_string() />
+                        <span>
+                            {move || format!("{} 件のオフライン変更が GitHub への反映待ちです。", pending_count())}
+                        </span>
+                    </div>
+                </Show>
+                <For
+                    each=attention
+                    key=|op| (op.id, op.status.clone(), op.updated_at.clone())
+                    children=move |op| {
+                        let operation_id = op.id;
+                        let is_conflict = op.status == "conflict";
+                        let summary = op.summary();
+                        let message = op.last_error.clone().unwrap_or_default();
+                        view! {
+                            <div class="mt-2 flex items-center justify-between gap-3 p-2 rounded bg-slate-900/60 border border-slate-700">
+                                <div class="min-w-0">
+                                    <div class="flex items-center gap-2">
+                                        <span class=if is_conflict {
+                                            "px-1.5 py-0.5 text-[10px] rounded bg-purple-500/20 text-purple-300"
+                                        } else {
+                                            "px-1.5 py-0.5 text-[10px] rounded bg-red-500/20 text-red-300"
+                                        }>
+                                            {if is_conflict { "競合" } else { "失敗" }}
+                                        </span>
+                                        <span class="text-dt-text truncate">{summary}</span>
+                                    </div>
+                                    <div class="text-xs text-dt-text-sub truncate">{message}</div>
+                                </div>
+                                <div class="flex items-center gap-1 shrink-0">
+                                    <button
+                                        class="px-2 py-1 text-xs border border-slate-600 rounded hover:border-gm-accent-cyan"
+                                        on:click=move |_| action_signal.set(Some(OutboxAction::Retry { operation_id, force: false }))
+                                    >
+                                        "Retry"
+                                    </button>
+                                    <Show when=move || is_conflict>
+                                        <button
+                                            class="px-2 py-1 text-xs border border-purple-500/50 text-purple-300 rounded hover:bg-purple-500/10"
+                                            title="GitHub 側の変更を上書きします"
+                                            on:click=move |_| action_signal.set(Some(OutboxAction::Retry { operation_id, force: true }))
+                                        >
+                                            "Overwrite"
+                                        </button>
+                                    </Show>
+                                    <button
+                                        class="px-2 py-1 text-xs border border-red-500/50 text-red-300 rounded hover:bg-red-500/10"
+                                        on:click=move |_| action_signal.set(Some(OutboxAction::Discard { operation_id }))
+                                    >
+                                        "Discard"
+                                    </button>
+                                </div>
+                            </div>
+                        }
+                    }
+                />
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,100 @@
+//! Time Summary Panel Component
+//!
+//! Collapsible panel above the kanban board summarising the time the
+//! user tracked on the project: total for the period, one bar per day
+//! and the issues that took the most time. Data comes from
+//! `get_time_summary` and reloads whenever `refresh` changes.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents:
+//!   └─ src/components/pages/project_dashboard/mod.rs
+//! Dependencies:
+//!   ├─ src/tauri_api.rs (get_time_summary)
+//!   └─ src/types/issue.rs (TimeSummary, format_duration)
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::tauri_api;
+use crate::types::issue::{format_duration, TimeSummary};
+
+/// Days covered by the panel
+const SUMMARY_DAYS: i64 = 14;
+
+/// Issues listed under the chart
+const TOP_ISSUES: usize = 5;
+
+/// Tracked time for one project over the last two weeks
+#[component]
+pub fn TimeSummaryPanel(
+    project_id: i64,
+    /// Reload trigger, bumped by the parent after a timer stops.
+    #[prop(into, optional)]
+    refresh: Signal<u32>,
+) -> impl IntoView {
+    let (summary, set_summary) = signal(TimeSummary::default());
+    let (error, set_error) = signal(Option::<String>::None);
+    let (expanded, set_expanded) = signal(false);
+
+    Effect::new(move |_| {
+        refresh.track();
+        spawn_local(async move {
+            match tauri_api::get_time_summary(Some(project_id), Some(SUMMARY_DAYS)).await {
+                Ok(data) => set_summary.set(data),
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    });
+
+    view! {
+        <Show when=move || summary.with(|s| s.total_seconds > 0) || error.get().is_some()>
+            <div class="mx-4 mt-4 p-3 bg-dt-card/50 border border-slate-700/50 rounded-lg">
+                <button
+                    class="w-full flex items-center justify-between text-sm"
+                    on:click=move |_| set_expanded.update(|v| *v = !*v)
+                >
+                    <span class="flex items-center gap-2">
+                        <span>"⏱"</span>
+                        <span class="font-medium text-dt-text">"Time tracked"</span>
+                        <span class="text-dt-text-sub">{format!("last {} days", SUMMARY_DAYS)}</span>
+                    </span>
+                    <span class="font-mono text-gm-accent-cyan">
+                        {move || format_duration(summary.with(|s| s.total_seconds))}
+                    </span>
+                </button>
+                <Show when=move || error.get().is_some()>
+                    <div class="mt-2 text-sm text-red-400">{move || error.get().unwrap_or_default()}</div>
+                </Show>
+                <Show when=move || expanded.get()>
+                    {move || summary.with(|s| {
+                        let max = s.by_day.iter().map(|d| d.total_seconds).max().unwrap_or(0).max(1);
+                        view! {
+                            <div class="flex items-end gap-1 h-16 mt-3">
+                                {s.by_day.iter().map(|day| {
+                                    let height = day.total_seconds as f64 * 100.0 / max as f64;
+                                    view! {
+                                        <div
+                                            class="flex-1 bg-gm-accent-cyan/60 rounded-t min-h-[2px]"
+                                            style=format!("height: {:.0}%", height)
+                                            title=format!("{}: {}", day.date, format_duration(day.total_seconds))
+                                        ></div>
+                                    }
+                                }).collect_view()}
+                            </div>
+                            <ul class="mt-3 space-y-1 text-sm">
+                                {s.by_issue.iter().take(TOP_ISSUES).map(|issue| view! {
+                                    <li class="flex items-center gap-2">
+                                        <span class="text-dt-text-sub font-mono">{format!("#{}", issue.issue_number)}</span>
+                                        <span class="flex-1 text-dt-text truncate">{issue.issue_title.clone()}</span>
+                                        <span class="text-dt-text-sub font-mono">{format_duration(issue.total_seconds)}</span>
+                                    </li>
+                                }).collect_view()}
+                            </ul>
+                        }
+                    })}
+                </Show>
+            </div>
+        </Show>
+    }
+}
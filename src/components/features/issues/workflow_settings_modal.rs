@@ -0,0 +1,409 @@
+//! Workflow Settings Modal Component
+//!
+//! Edits the per-project settings of the generated "Issue Status Sync"
+//! GitHub Actions workflow (branch pattern, excluded branches, which event
+//! moves the issue to which column, custom labels) with a live YAML
+//! preview, and checks whether the file committed to a linked repository
+//! still matches what the settings generate.
+//!
+//! DEPENDENCY MAP:
+//!
+//! Parents:
+//!   └─ src/components/pages/project_dashboard/mod.rs
+//! Dependencies:
+//!   ├─ src/types/issue.rs
+//!   ├─ src/tauri_api.rs
+//!   ├─ src/components/icons.rs
+//!   └─ src/components/ui/dialog/modal.rs
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::icons::Icon;
+use crate::components::ui::dialog::{Modal, ModalBody, ModalFooter, ModalHeader, ModalSize};
+use crate::tauri_api;
+use crate::types::issue::{
+    IssueStatus, ProjectRepository, WorkflowDrift, WorkflowSettings, WORKFLOW_EVENTS,
+};
+
+/// Path of the generated workflow inside a repository.
+const WORKFLOW_FILE_PATH: &str = ".github/workflows/issue-status-sync.yml";
+
+/// Split a comma-separated input into trimmed, non-empty entries.
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Workflow settings modal component
+#[component]
+pub fn WorkflowSettingsModal(
+    project_id: i64,
+    #[prop(into)] visible: Signal<bool>,
+    on_close: impl Fn() + 'static + Clone + Send + Sync,
+    /// Linked repositories; a picker is shown for the drift check when
+    /// there is more than one.
+    #[prop(into, optional)]
+    repositories: Signal<Vec<ProjectRepository>>,
+) -> impl IntoView {
+    let (settings, set_settings) = signal(Option::<WorkflowSettings>::None);
+    let (preview, set_preview) = signal(String::new());
+    let (error, set_error) = signal(Option::<String>::None);
+    let (saving, set_saving) = signal(false);
+    let (saved, set_saved) = signal(false);
+    let (drift_repo, set_drift_repo) = signal(Option::<String>::None);
+    let (drift, set_drift) = signal(Option::<WorkflowDrift>::None);
+    let (checking, set_checking) = signal(false);
+
+    // Load settings on mount
+    spawn_local(async move {
+        match tauri_api::get_workflow_settings(project_id).await {
+            Ok(loaded) => set_settings.set(Some(loaded)),
+            Err(e) => set_error.set(Some(e)),
+        }
+    });
+
+    // Live preview: re-render the YAML whenever the form changes. Invalid
+    // settings keep the last good preview and show the validation error.
+    Effect::new(move |_| {
+        let Some(current) = settings.get() else {
+            return;
+        };
+        spawn_local(async move {
+            match tauri_api::preview_workflow(&current).await {
+                Ok(yaml) => {
+                    set_preview.set(yaml);
+                    set_error.set(None);
+                }
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    });
+
+    let update = move |f: &dyn Fn(&mut WorkflowSettings)| {
+        set_saved.set(false);
+        set_settings.update(|s| {
+            if let Some(s) = s.as_mut() {
+                f(s);
+            }
+        });
+    };
+
+    let save = move |_| {
+        let Some(current) = settings.get_untracked() else {
+            return;
+        };
+        set_saving.set(true);
+        spawn_local(async move {
+            match tauri_api::update_workflow_settings(project_id, &current).await {
+                Ok(updated) => {
+                    set_settings.set(Some(updated));
+                    set_saved.set(true);
+                    // Drift was measured against the previous settings
+                    set_drift.set(None);
+                }
+                Err(e) => set_error.set(Some(e)),
+            }
+            set_saving.set(false);
+        });
+    };
+
+    let reset = move |_| {
+        set_saving.set(true);
+        spawn_local(async move {
+            match tauri_api::reset_workflow_settings(project_id).await {
+                Ok(defaults) => {
+                    set_settings.set(Some(defaults));
+                    set_saved.set(false);
+                    set_drift.set(None);
+                }
+                Err(e) => set_error.set(Some(e)),
+            }
+            set_saving.set(false);
+        });
+    };
+
+    let check_drift = move |_| {
+        let repo = drift_repo.get_untracked();
+        set_checking.set(true);
+        spawn_local(async move {
+            match tauri_api::check_workflow_drift(project_id, repo.as_deref()).await {
+                Ok(result) => set_drift.set(Some(result)),
+                Err(e) => set_error.set(Some(e)),
+            }
+            set_checking.set(false);
+        });
+    };
+
+    let copy_yaml = move |_| {
+        let yaml = preview.get_untracked();
+        if let Some(window) = web_sys::window() {
+            let clipboard = window.navigator().clipboard();
+            let _ = clipboard.write_text(&yaml);
+        }
+    };
+
+    // Store on_close for use in ChildrenFn
+    let on_close_stored = StoredValue::new(on_close.clone());
+    let on_close_callback = Callback::new(move |_: ()| on_close_stored.get_value()());
+
+    let input_class = "w-full px-3 py-2 bg-slate-800 border border-slate-700 rounded-lg text-sm text-dt-text focus:border-gm-accent-cyan focus:outline-none";
+
+    view! {
+        <Modal
+            visible=visible
+            on_close=on_close.clone()
+            size=ModalSize::TwoXL
+        >
+            <ModalHeader on_close=on_close_callback>
+                <h2 class="text-lg font-semibold text-dt-text">"GitHub Actions Workflow"</h2>
+            </ModalHeader>
+
+            <Show when=move || error.get().is_some()>
+                <div class="mx-4 mt-4 p-3 bg-red-500/20 border border-red-500/50 rounded-lg text-sm text-red-400">
+                    {move || error.get().unwrap_or_default()}
+                </div>
+            </Show>
+
+            <ModalBody class="max-h-[70vh] space-y-6">
+                <Show
+                    when=move || settings.get().is_some()
+                    fallback=|| view! {
+                        <div class="flex items-center justify-center py-8">
+                            <div class="animate-spin w-6 h-6 border-2 border-gm-accent-cyan border-t-transparent rounded-full"/>
+                        </div>
+                    }
+                >
+                    // Branch naming
+                    <section class="space-y-3">
+                        <h3 class="text-sm font-semibold text-dt-text">"Branch naming"</h3>
+                        <div class="grid grid-cols-3 gap-3">
+                            <label class="col-span-2 block">
+                                <span class="block text-xs text-dt-text-sub mb-1">"Pattern (bash regex)"</span>
+                                <input
+                                    type="text"
+                                    class=format!("{} font-mono", input_class)
+                                    prop:value=move || settings.get().map(|s| s.branch_pattern).unwrap_or_default()
+                                    on:change=move |ev| {
+                                        let value = event_target_value(&ev);
+                                        update(&|s| s.branch_pattern = value.clone());
+                                    }
+                                />
+                            </label>
+                            <label class="block">
+                                <span class="block text-xs text-dt-text-sub mb-1">"Issue number group"</span>
+                                <input
+                                    type="number"
+                                    min="1"
+                                    class=input_class
+                                    prop:value=move || settings.get().map(|s| s.issue_number_group.to_string()).unwrap_or_default()
+                                    on:change=move |ev| {
+                                        if let Ok(group) = event_target_value(&ev).parse::<i32>() {
+                                            update(&|s| s.issue_number_group = group);
+                                        }
+                                    }
+                                />
+                            </label>
+                        </div>
+                        <label class="block">
+                            <span class="block text-xs text-dt-text-sub mb-1">"Excluded branches (comma-separated)"</span>
+                            <input
+                                type="text"
+                                class=input_class
+                                placeholder="main, master"
+                                prop:value=move || settings.get().map(|s| s.excluded_branches.join(", ")).unwrap_or_default()
+                                on:change=move |ev| {
+                                    let branches = split_list(&event_target_value(&ev));
+                                    update(&|s| s.excluded_branches = branches.clone());
+                                }
+                            />
+                        </label>
+                    </section>
+
+                    // Event → column mapping
+                    <section class="space-y-2">
+                        <h3 class="text-sm font-semibold text-dt-text">"Events"</h3>
+                        <div class="grid grid-cols-[10rem_12rem_1fr] gap-2 items-center text-xs text-dt-text-sub">
+                            <span>"Event"</span>
+                            <span>"Move to"</span>
+                            <span>"Extra labels (comma-separated)"</span>
+                        </div>
+                        {WORKFLOW_EVENTS.iter().map(|&(event, event_label)| {
+                            let status_index = move || {
+                                settings
+                                    .get()
+                                    .and_then(|s| s.rule(event).and_then(|r| r.status))
+                                    .and_then(|status| IssueStatus::all().iter().position(|s| *s == status))
+                                    .map(|i| i.to_string())
+                                    .unwrap_or_default()
+                            };
+                            view! {
+                                <div class="grid grid-cols-[10rem_12rem_1fr] gap-2 items-center">
+                                    <span class="text-sm text-dt-text">{event_label}</span>
+                                    <select
+                                        class=input_class
+                                        prop:value=status_index
+                                        on:change=move |ev| {
+                                            let status = event_target_value(&ev)
+                                                .parse::<usize>()
+                                                .ok()
+                                                .and_then(|i| IssueStatus::all().get(i).copied());
+                                            update(&|s| s.rule_mut(event).status = status);
+                                        }
+                                    >
+                                        <option value="">"(no change)"</option>
+                                        {IssueStatus::all().into_iter().enumerate().map(|(i, status)| view! {
+                                            <option value=i.to_string()>{status.display_name()}</option>
+                                        }).collect_view()}
+                                    </select>
+                                    <input
+                                        type="text"
+                                        class=input_class
+                                        placeholder="e.g. wip"
+                                        prop:value=move || {
+                                            settings
+                                                .get()
+                                                .and_then(|s| s.rule(event).map(|r| r.labels.join(", ")))
+                                                .unwrap_or_default()
+                                        }
+                                        on:change=move |ev| {
+                                            let labels = split_list(&event_target_value(&ev));
+                                            update(&|s| s.rule_mut(event).labels = labels.clone());
+                                        }
+                                    />
+                                </div>
+                            }
+                        }).collect_view()}
+                        <label class="flex items-center gap-2 pt-2 text-sm text-dt-text">
+                            <input
+                                type="checkbox"
+                                prop:checked=move || settings.get().map(|s| s.close_on_merge).unwrap_or(false)
+                                on:change=move |ev| {
+                                    let checked = event_target_checked(&ev);
+                                    update(&|s| s.close_on_merge = checked);
+                                }
+                            />
+                            "Close the issue when its PR is merged"
+                        </label>
+                    </section>
+
+                    // YAML preview
+                    <section class="space-y-2">
+                        <h3 class="text-sm font-semibold text-dt-text">"Preview"</h3>
+                        <p class="text-xs text-dt-text-sub">
+                            "Commit this file as "
+                            <code class="px-1 py-0.5 bg-slate-800 rounded text-gm-accent-cyan">{WORKFLOW_FILE_PATH}</code>
+                            " in each linked repository."
+                        </p>
+                        <div class="relative">
+                            <pre class="p-4 bg-slate-900 rounded-lg text-xs text-slate-300 overflow-x-auto max-h-80">
+                                <code>{move || preview.get()}</code>
+                            </pre>
+                            <button
+                                class="absolute top-2 right-2 p-2 text-dt-text-sub hover:text-dt-text bg-slate-800 rounded"
+                                title="Copy to clipboard"
+                                on:click=copy_yaml
+                            >
+                                <Icon name="clipboard-copy".to_string() class="w-4 h-4".to_string() />
+                            </button>
+                        </div>
+                    </section>
+
+                    // Drift check
+                    <section class="space-y-2">
+                        <div class="flex items-center justify-between gap-2">
+                            <h3 class="text-sm font-semibold text-dt-text">"Committed workflow"</h3>
+                            <div class="flex items-center gap-2">
+                                <Show when=move || { repositories.get().len() > 1 }>
+                                    <select
+                                        class="px-2 py-1 bg-slate-800 border border-slate-700 rounded-lg text-xs text-dt-text"
+                                        on:change=move |ev| {
+                                            let value = event_target_value(&ev);
+                                            set_drift_repo.set((!value.is_empty()).then_some(value));
+                                        }
+                                    >
+                                        <option value="">"Primary repository"</option>
+                                        {move || repositories.get().into_iter().map(|r| view! {
+                                            <option value=r.repo_full_name.clone()>{r.repo_full_name.clone()}</option>
+                                        }).collect_view()}
+                                    </select>
+                                </Show>
+                                <button
+                                    class="flex items-center gap-1 px-3 py-1 text-xs text-dt-text-sub hover:text-dt-text border border-slate-700 hover:border-gm-accent-cyan rounded-lg transition-colors disabled:opacity-50"
+                                    disabled=move || checking.get()
+                                    on:click=check_drift
+                                >
+                                    {move || if checking.get() {
+                                        view! { <Icon name="refresh".to_string() class="w-3 h-3 animate-spin".to_string() /> }
+                                    } else {
+                                        view! { <Icon name="refresh".to_string() class="w-3 h-3".to_string() /> }
+                                    }}
+                                    "Check drift"
+                                </button>
+                            </div>
+                        </div>
+                        <p class="text-xs text-dt-text-sub">"Check drift compares the file on GitHub with the saved settings."</p>
+                        {move || drift.get().map(|d| {
+                            let (badge_class, badge_label) = match d.state.as_str() {
+                                "in_sync" => ("bg-green-500/20 text-green-400", "In sync"),
+                                "drifted" => ("bg-yellow-500/20 text-yellow-400", "Drifted"),
+                                _ => ("bg-red-500/20 text-red-400", "Not committed"),
+                            };
+                            let has_diff = !d.diff.is_empty();
+                            view! {
+                                <div class="space-y-2">
+                                    <div class="flex items-center gap-2 text-sm">
+                                        <span class=format!("px-2 py-0.5 rounded text-xs font-medium {}", badge_class)>{badge_label}</span>
+                                        <span class="text-dt-text-sub">{format!("{} · {}", d.repo_full_name, d.path)}</span>
+                                    </div>
+                                    <Show when=move || has_diff>
+                                        <p class="text-xs text-dt-text-sub">
+                                            <span class="text-green-400">"+ expected"</span>
+                                            " / "
+                                            <span class="text-red-400">"- committed"</span>
+                                        </p>
+                                    </Show>
+                                    {has_diff.then(|| view! {
+                                        <pre class="p-3 bg-slate-900 rounded-lg text-xs overflow-x-auto max-h-64">
+                                            {d.diff.iter().map(|line| {
+                                                let (prefix, class) = match line.kind.as_str() {
+                                                    "added" => ("+ ", "block text-green-400 bg-green-500/10"),
+                                                    "removed" => ("- ", "block text-red-400 bg-red-500/10"),
+                                                    _ => ("  ", "block text-slate-400"),
+                                                };
+                                                view! { <span class=class>{format!("{}{}", prefix, line.text)}</span> }
+                                            }).collect_view()}
+                                        </pre>
+                                    })}
+                                </div>
+                            }
+                        })}
+                    </section>
+                </Show>
+            </ModalBody>
+
+            <ModalFooter>
+                <Show when=move || saved.get()>
+                    <span class="mr-auto text-sm text-green-400">"Saved"</span>
+                </Show>
+                <button
+                    class="px-4 py-2 text-sm text-dt-text-sub hover:text-dt-text border border-slate-700 rounded-lg transition-colors disabled:opacity-50"
+                    disabled=move || saving.get()
+                    on:click=reset
+                >
+                    "Reset to defaults"
+                </button>
+                <button
+                    class="px-4 py-2 bg-gradient-to-r from-gm-accent-cyan to-gm-accent-purple text-white rounded-lg hover:opacity-90 transition-opacity disabled:opacity-50"
+                    disabled=move || saving.get() || settings.get().is_none()
+                    on:click=save
+                >
+                    {move || if saving.get() { "Saving..." } else { "Save" }}
+                </button>
+            </ModalFooter>
+        </Modal>
+    }
+}
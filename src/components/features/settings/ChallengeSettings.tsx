@@ -0,0 +1,110 @@
+/**
+ * Challenge Settings Component
+ *
+ * React implementation of ChallengeSettings component.
+ * Allows users to choose how hard auto-generated challenges are.
+ *
+ * Related Documentation:
+ *   - Original (Leptos): ../settings/challenge_settings.rs
+ */
+
+import React, { useState, useEffect, useRef } from 'react';
+import { useSettings } from '../../../stores/settingsStore';
+import type { ChallengeDifficulty } from '../../../types';
+import {
+  CHALLENGE_DIFFICULTIES,
+  challengeDifficultyDescription,
+  challengeDifficultyLabel,
+} from '../../../types/settings';
+
+export const ChallengeSettings: React.FC = () => {
+  const { settings, isLoading, error: storeError, updateSettings } = useSettings();
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+  const initialLoadCompleteRef = useRef(false);
+
+  // Load settings on mount
+  useEffect(() => {
+    if (initialLoadCompleteRef.current) return;
+
+    if (!isLoading && settings) {
+      setLoading(false);
+      initialLoadCompleteRef.current = true;
+    } else if (!isLoading && storeError) {
+      setError(`設定の読み込みに失敗しました: ${storeError}`);
+      setLoading(false);
+      initialLoadCompleteRef.current = true;
+    }
+  }, [isLoading, settings, storeError]);
+
+  const updateDifficulty = (difficulty: ChallengeDifficulty) => {
+    if (!settings) return;
+    setError(null);
+
+    updateSettings({
+      ...settings,
+      challengeDifficulty: difficulty,
+    }).catch((e) => {
+      setError(`設定の保存に失敗しました: ${e}`);
+    });
+  };
+
+  const currentDifficulty: ChallengeDifficulty = settings?.challengeDifficulty ?? 'normal';
+
+  return (
+    <div className="space-y-6">
+      {/* Loading state */}
+      {loading && (
+        <div className="text-center py-8 text-dt-text-sub">設定を読み込み中...</div>
+      )}
+
+      {/* Error message */}
+      {error && (
+        <div className="p-3 bg-red-900/30 border border-red-500/50 rounded-lg text-red-200 text-sm">
+          {error}
+        </div>
+      )}
+
+      {/* Settings form */}
+      {settings && !loading && (
+        <div className="space-y-3">
+          <h3 className="text-lg font-gaming font-bold text-white">自動チャレンジの難易度</h3>
+          <div className="space-y-2 p-4 bg-gm-bg-card/50 rounded-xl border border-gm-accent-cyan/20">
+            {CHALLENGE_DIFFICULTIES.map((difficulty) => {
+              const isSelected = currentDifficulty === difficulty;
+              return (
+                <label
+                  key={difficulty}
+                  className={`flex items-center gap-3 p-3 rounded-lg cursor-pointer transition-colors ${
+                    isSelected
+                      ? 'bg-gm-accent-cyan/20 border border-gm-accent-cyan/50'
+                      : 'hover:bg-gm-bg-card/30'
+                  }`}
+                >
+                  <input
+                    type="radio"
+                    name="challenge_difficulty"
+                    checked={isSelected}
+                    onChange={() => updateDifficulty(difficulty)}
+                    className="w-4 h-4 text-gm-accent-cyan bg-gm-bg-card border-gm-accent-cyan/50 focus:ring-gm-accent-cyan focus:ring-2"
+                  />
+                  <div>
+                    <span className="text-white block">{challengeDifficultyLabel(difficulty)}</span>
+                    <span className="text-sm text-dt-text-sub">
+                      {challengeDifficultyDescription(difficulty)}
+                    </span>
+                  </div>
+                </label>
+              );
+            })}
+          </div>
+
+          {/* Hint text */}
+          <div className="text-xs text-dt-text-sub p-3 bg-gm-bg-card/30 rounded-lg">
+            ※ 目標値は直近のチャレンジの達成状況に合わせても自動で調整されます。変更は次に生成されるチャレンジから反映されます
+          </div>
+        </div>
+      )}
+    </div>
+  );
+};
@@ -21,6 +21,7 @@ const NOTIFICATION_FIELD_MAP = {
   badge_earned: 'notifyBadgeEarned',
   streak_update: 'notifyStreakUpdate',
   streak_milestone: 'notifyStreakMilestone',
+  weekly_report: 'notifyWeeklyReport',
 } as const;
 
 export const NotificationSettings: React.FC = () => {
@@ -84,6 +85,7 @@ export const NotificationSettings: React.FC = () => {
       notifyBadgeEarned: true,
       notifyStreakUpdate: true,
       notifyStreakMilestone: true,
+      notifyWeeklyReport: true,
     }).catch((e) => {
       setError(`設定の保存に失敗しました: ${e}`);
     });
@@ -101,6 +103,7 @@ export const NotificationSettings: React.FC = () => {
       notifyBadgeEarned: false,
       notifyStreakUpdate: false,
       notifyStreakMilestone: false,
+      notifyWeeklyReport: false,
     }).catch((e) => {
       setError(`設定の保存に失敗しました: ${e}`);
     });
@@ -184,6 +187,7 @@ export const NotificationSettings: React.FC = () => {
                   { field: 'badge_earned' as const, label: 'バッジ獲得通知' },
                   { field: 'streak_update' as const, label: 'ストリーク更新通知' },
                   { field: 'streak_milestone' as const, label: 'ストリークマイルストーン' },
+                  { field: 'weekly_report' as const, label: '週間レポート通知（毎週月曜）' },
                 ].map(({ field, label }) => {
                   const value = settings[NOTIFICATION_FIELD_MAP[field]] as boolean;
                   return (
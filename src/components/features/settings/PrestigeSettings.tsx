@@ -0,0 +1,182 @@
+/**
+ * Prestige Settings Component
+ *
+ * React implementation of PrestigeSettings component.
+ * Lets users choose the level required to prestige and, once reached,
+ * reset their level in exchange for a prestige rank and a permanent XP bonus.
+ *
+ * Related Documentation:
+ *   - Original (Leptos): ../settings/prestige_settings.rs
+ */
+
+import React, { useState, useEffect, useRef } from 'react';
+import { useSettings } from '../../../stores/settingsStore';
+import { gamification } from '../../../lib/tauri/commands';
+import { ConfirmDialog } from '../../ui/dialog';
+import type { LevelInfo } from '../../../types';
+import { DEFAULT_PRESTIGE_LEVEL, PRESTIGE_LEVELS } from '../../../types/settings';
+
+export const PrestigeSettings: React.FC = () => {
+  const { settings, isLoading, error: storeError, updateSettings } = useSettings();
+  const [levelInfo, setLevelInfo] = useState<LevelInfo | null>(null);
+  const [loading, setLoading] = useState(true);
+  const [prestiging, setPrestiging] = useState(false);
+  const [showDialog, setShowDialog] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const [successMessage, setSuccessMessage] = useState<string | null>(null);
+  const initialLoadCompleteRef = useRef(false);
+
+  // Load level info on mount
+  useEffect(() => {
+    gamification
+      .getLevelInfo()
+      .then(setLevelInfo)
+      .catch((e) => setError(`レベル情報の読み込みに失敗しました: ${e}`));
+  }, []);
+
+  // Wait for settings to load
+  useEffect(() => {
+    if (initialLoadCompleteRef.current) return;
+
+    if (!isLoading && settings) {
+      setLoading(false);
+      initialLoadCompleteRef.current = true;
+    } else if (!isLoading && storeError) {
+      setError(`設定の読み込みに失敗しました: ${storeError}`);
+      setLoading(false);
+      initialLoadCompleteRef.current = true;
+    }
+  }, [isLoading, settings, storeError]);
+
+  const updatePrestigeLevel = (prestigeLevel: number) => {
+    if (!settings) return;
+    setError(null);
+
+    updateSettings({
+      ...settings,
+      prestigeLevel,
+    })
+      // canPrestige depends on the threshold, so refresh it
+      .then(() => gamification.getLevelInfo().then(setLevelInfo))
+      .catch((e) => {
+        setError(`設定の保存に失敗しました: ${e}`);
+      });
+  };
+
+  const handlePrestige = async () => {
+    setShowDialog(false);
+    setPrestiging(true);
+    setError(null);
+    setSuccessMessage(null);
+
+    try {
+      const result = await gamification.prestige();
+      let message = `プレステージ ${result.levelInfo.prestigeRank} に到達しました！`;
+      if (result.newBadges.length > 0) {
+        const names = result.newBadges.map((b) => `${b.icon} ${b.name}`).join(', ');
+        message += ` 新しいバッジ: ${names}`;
+      }
+      setLevelInfo(result.levelInfo);
+      setSuccessMessage(message);
+    } catch (e) {
+      setError(`プレステージに失敗しました: ${e}`);
+    } finally {
+      setPrestiging(false);
+    }
+  };
+
+  const currentPrestigeLevel = settings?.prestigeLevel ?? DEFAULT_PRESTIGE_LEVEL;
+
+  return (
+    <div className="space-y-6">
+      {/* Loading state */}
+      {loading && (
+        <div className="text-center py-8 text-dt-text-sub">設定を読み込み中...</div>
+      )}
+
+      {/* Error message */}
+      {error && (
+        <div className="p-3 bg-red-900/30 border border-red-500/50 rounded-lg text-red-200 text-sm">
+          {error}
+        </div>
+      )}
+
+      {/* Success message */}
+      {successMessage && (
+        <div className="p-3 bg-green-900/20 border border-green-500/30 rounded-lg text-green-300 text-sm">
+          {successMessage}
+        </div>
+      )}
+
+      {/* Current prestige status */}
+      {levelInfo && !loading && (
+        <div className="p-4 bg-gm-bg-card/50 rounded-xl border border-gm-accent-purple/30 space-y-2">
+          <div className="flex items-center justify-between">
+            <span className="text-white font-gaming">プレステージ {levelInfo.prestigeRank}</span>
+            <span className="text-gm-accent-purple text-sm">
+              {levelInfo.prestigeTitle ?? 'なし'}
+            </span>
+          </div>
+          <div className="text-sm text-dt-text-sub">
+            現在のレベル: Lv.{levelInfo.currentLevel} / XPボーナス: x
+            {levelInfo.xpMultiplier.toFixed(2)}
+          </div>
+          <button
+            type="button"
+            className="w-full mt-2 px-4 py-2 rounded-lg bg-gradient-to-r from-gm-accent-cyan to-gm-accent-purple text-white font-bold transition-opacity disabled:opacity-40 disabled:cursor-not-allowed"
+            disabled={prestiging || !levelInfo.canPrestige}
+            onClick={() => setShowDialog(true)}
+          >
+            {prestiging
+              ? 'プレステージ中...'
+              : levelInfo.canPrestige
+                ? 'プレステージする'
+                : `Lv.${levelInfo.prestigeLevel} で解放`}
+          </button>
+        </div>
+      )}
+
+      {/* Prestige level selector */}
+      {settings && !loading && (
+        <div className="space-y-3">
+          <h3 className="text-lg font-gaming font-bold text-white">プレステージ解放レベル</h3>
+          <div className="grid grid-cols-4 gap-2">
+            {PRESTIGE_LEVELS.map((prestigeLevel) => {
+              const isSelected = currentPrestigeLevel === prestigeLevel;
+              return (
+                <button
+                  key={prestigeLevel}
+                  type="button"
+                  className={`px-3 py-2 rounded-lg text-sm font-gaming-mono transition-colors ${
+                    isSelected
+                      ? 'bg-gm-accent-cyan/20 border border-gm-accent-cyan/50 text-white'
+                      : 'bg-gm-bg-card/50 border border-gm-accent-cyan/10 text-dt-text-sub hover:bg-gm-bg-card/30'
+                  }`}
+                  onClick={() => updatePrestigeLevel(prestigeLevel)}
+                >
+                  Lv.{prestigeLevel}
+                </button>
+              );
+            })}
+          </div>
+
+          {/* Hint text */}
+          <div className="text-xs text-dt-text-sub p-3 bg-gm-bg-card/30 rounded-lg">
+            ※ プレステージするとレベルが1に戻ります。累計XP・XP履歴・獲得済みバッジはそのまま残り、ランクごとにXPが5%ずつ恒久的に増えます
+          </div>
+        </div>
+      )}
+
+      {/* Prestige confirmation dialog */}
+      <ConfirmDialog
+        title="プレステージの確認"
+        message="レベルを1に戻してプレステージランクを上げますか？累計XPと獲得済みバッジは保持されます。"
+        confirmLabel="プレステージする"
+        cancelLabel="キャンセル"
+        visible={showDialog}
+        onConfirm={handlePrestige}
+        onCancel={() => setShowDialog(false)}
+      />
+    </div>
+  );
+};
@@ -0,0 +1,240 @@
+/**
+ * Repository Filter Settings Component
+ *
+ * React implementation of RepositoryFilterSettings component.
+ * Lets users choose which repositories count towards XP, badges, code
+ * stats and the dashboard: a work / personal preset, include / exclude
+ * lists of repositories and owners (users or organizations), and whether
+ * forks, archived and private repositories are counted.
+ *
+ * Related Documentation:
+ *   - Original (Leptos): ../settings/repository_filter_settings.rs
+ */
+
+import React, { useEffect, useState } from 'react';
+import { settings as settingsApi } from '../../../lib/tauri/commands';
+import { ToggleSwitch } from '../../ui/form';
+import {
+  REPO_FILTER_PRESETS,
+  repoFilterPresetDescription,
+  repoFilterPresetLabel,
+  type RepositoryFilter,
+} from '../../../types';
+
+type FilterListKey =
+  | 'workOwners'
+  | 'includeRepositories'
+  | 'excludeRepositories'
+  | 'includeOwners'
+  | 'excludeOwners';
+
+type FilterToggleKey = 'includeForks' | 'includeArchived' | 'includePrivate';
+
+const LISTS: { key: FilterListKey; label: string; hint: string; placeholder: string }[] = [
+  {
+    key: 'workOwners',
+    label: '仕事用のオーナー',
+    hint: 'Organization やユーザー名を1行に1つ。空なら自分以外がオーナーのリポジトリを仕事用とみなします',
+    placeholder: 'my-company',
+  },
+  {
+    key: 'includeRepositories',
+    label: '対象にするリポジトリ',
+    hint: 'owner/name を1行に1つ。対象リストを指定すると、リストにあるものだけを集計します',
+    placeholder: 'octocat/dotfiles',
+  },
+  {
+    key: 'excludeRepositories',
+    label: '除外するリポジトリ',
+    hint: 'owner/name を1行に1つ',
+    placeholder: 'octocat/dotfiles',
+  },
+  {
+    key: 'includeOwners',
+    label: '対象にするオーナー',
+    hint: 'Organization やユーザー名を1行に1つ',
+    placeholder: 'my-company',
+  },
+  {
+    key: 'excludeOwners',
+    label: '除外するオーナー',
+    hint: 'Organization やユーザー名を1行に1つ',
+    placeholder: 'my-company',
+  },
+];
+
+const TOGGLES: { key: FilterToggleKey; title: string; description: string }[] = [
+  {
+    key: 'includeForks',
+    title: 'フォークを含める',
+    description: 'フォークしたリポジトリのコミットやPRも集計します',
+  },
+  {
+    key: 'includeArchived',
+    title: 'アーカイブ済みを含める',
+    description: 'アーカイブされたリポジトリも集計します',
+  },
+  {
+    key: 'includePrivate',
+    title: 'プライベートを含める',
+    description: 'プライベートリポジトリも集計します',
+  },
+];
+
+// Parse a list textarea (one entry per line)
+const parseLines = (text: string): string[] =>
+  text
+    .split('\n')
+    .map((line) => line.trim())
+    .filter((line) => line.length > 0);
+
+export const RepositoryFilterSettings: React.FC = () => {
+  const [filter, setFilter] = useState<RepositoryFilter | null>(null);
+  const [isLoading, setIsLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    settingsApi
+      .getRepositoryFilter()
+      .then(setFilter)
+      .catch((e) => setError(`設定の読み込みに失敗しました: ${e}`))
+      .finally(() => setIsLoading(false));
+  }, []);
+
+  // Save the change right away, rolling back on failure (the backend
+  // validates and normalizes the names)
+  const save = (changes: Partial<RepositoryFilter>) => {
+    if (!filter) return;
+    const previous = filter;
+    setFilter({ ...filter, ...changes });
+    setError(null);
+
+    settingsApi
+      .updateRepositoryFilter({ ...filter, ...changes })
+      .then(setFilter)
+      .catch((e) => {
+        setFilter(previous);
+        setError(`設定の保存に失敗しました: ${e}`);
+      });
+  };
+
+  const reset = () => {
+    setError(null);
+    settingsApi
+      .resetRepositoryFilter()
+      .then(setFilter)
+      .catch((e) => setError(`リセットに失敗しました: ${e}`));
+  };
+
+  return (
+    <div className="space-y-6">
+      {/* Loading state */}
+      {isLoading && (
+        <div className="text-center py-8 text-dt-text-sub">設定を読み込み中...</div>
+      )}
+
+      {/* Error message */}
+      {error && (
+        <div className="p-3 bg-red-900/30 border border-red-500/50 rounded-lg text-red-200 text-sm">
+          {error}
+        </div>
+      )}
+
+      {filter && !isLoading && (
+        <>
+          {/* Preset */}
+          <div className="space-y-2 p-4 bg-gm-bg-card/50 rounded-xl border border-gm-accent-cyan/20">
+            {REPO_FILTER_PRESETS.map((preset) => {
+              const isSelected = filter.preset === preset;
+              return (
+                <label
+                  key={preset}
+                  className={`flex items-center gap-3 p-3 rounded-lg cursor-pointer transition-colors ${
+                    isSelected
+                      ? 'bg-gm-accent-cyan/20 border border-gm-accent-cyan/50'
+                      : 'hover:bg-gm-bg-card/30'
+                  }`}
+                >
+                  <input
+                    type="radio"
+                    name="repo_filter_preset"
+                    checked={isSelected}
+                    onChange={() => save({ preset })}
+                    className="w-4 h-4 text-gm-accent-cyan bg-gm-bg-card border-gm-accent-cyan/50 focus:ring-gm-accent-cyan focus:ring-2"
+                  />
+                  <div>
+                    <span className="text-white block">{repoFilterPresetLabel(preset)}</span>
+                    <span className="text-sm text-dt-text-sub">
+                      {repoFilterPresetDescription(preset)}
+                    </span>
+                  </div>
+                </label>
+              );
+            })}
+          </div>
+
+          {/* Include / exclude lists */}
+          <div className="space-y-2 p-4 bg-gm-bg-card/50 rounded-xl border border-gm-accent-cyan/20">
+            {LISTS.map(({ key, label, hint, placeholder }) => {
+              const id = `repo-filter-${key}`;
+              return (
+                <div key={key} className="p-3 space-y-2">
+                  <label className="text-white block font-gaming font-bold" htmlFor={id}>
+                    {label}
+                  </label>
+                  <span className="text-sm text-dt-text-sub block">{hint}</span>
+                  <textarea
+                    id={id}
+                    rows={2}
+                    placeholder={placeholder}
+                    className="w-full px-3 py-2 bg-gm-bg-primary border border-gm-accent-cyan/30 rounded-lg text-white font-mono text-sm"
+                    key={`${key}-${filter[key].join(',')}`}
+                    defaultValue={filter[key].join('\n')}
+                    onBlur={(e) => save({ [key]: parseLines(e.target.value) })}
+                  />
+                </div>
+              );
+            })}
+          </div>
+
+          {/* Repository kinds */}
+          <div className="space-y-2 p-4 bg-gm-bg-card/50 rounded-xl border border-gm-accent-cyan/20">
+            {TOGGLES.map(({ key, title, description }) => (
+              <div
+                key={key}
+                className="flex items-center justify-between p-3 rounded-lg hover:bg-gm-bg-card/30 transition-colors"
+              >
+                <div className="flex-1">
+                  <span className="text-white block font-gaming font-bold" id={`repo-filter-${key}-label`}>
+                    {title}
+                  </span>
+                  <span className="text-sm text-dt-text-sub mt-1 block">{description}</span>
+                </div>
+                <ToggleSwitch
+                  enabled={filter[key]}
+                  onToggle={() => save({ [key]: !filter[key] })}
+                  labelId={`repo-filter-${key}-label`}
+                />
+              </div>
+            ))}
+          </div>
+
+          <div className="flex justify-end">
+            <button
+              type="button"
+              className="px-4 py-2 text-sm rounded-lg border border-gm-accent-cyan/30 text-dt-text-sub hover:text-white hover:bg-gm-bg-card/50 transition-colors"
+              onClick={reset}
+            >
+              フィルターをリセット
+            </button>
+          </div>
+
+          {/* Hint text */}
+          <div className="text-xs text-dt-text-sub p-3 bg-gm-bg-card/30 rounded-lg">
+            ※ 変更後の最初の同期では、絞り込み後の集計を新しい基準として保存するためXPは付与されません。コントリビューションカレンダーとストリークは常にすべてのリポジトリが対象です
+          </div>
+        </>
+      )}
+    </div>
+  );
+};
@@ -0,0 +1,158 @@
+/**
+ * XP Safeguard Settings Component
+ *
+ * React implementation of XpSafeguardSettings component.
+ * Lets users tune the anti-gaming safeguards applied to commit XP:
+ * diminishing returns past a daily commit cap, a minimum diff size,
+ * excluding bot / GitHub Actions commits and excluding repositories.
+ *
+ * Related Documentation:
+ *   - Original (Leptos): ../settings/xp_safeguard_settings.rs
+ */
+
+import React, { useState, useEffect } from 'react';
+import { useSettings } from '../../../stores/settingsStore';
+import { ToggleSwitch } from '../../ui/form';
+import type { UserSettings } from '../../../types';
+
+// Parse the excluded repositories textarea (one "owner/name" per line)
+const parseRepositories = (text: string): string[] =>
+  text
+    .split('\n')
+    .map((line) => line.trim())
+    .filter((line) => line.length > 0);
+
+export const XpSafeguardSettings: React.FC = () => {
+  const { settings, isLoading, error: storeError, updateSettings } = useSettings();
+  const [error, setError] = useState<string | null>(null);
+  const [repositoriesText, setRepositoriesText] = useState('');
+
+  useEffect(() => {
+    if (settings) setRepositoriesText(settings.xpExcludedRepositories.join('\n'));
+  }, [settings]);
+
+  // The backend validates the values and normalizes the repository list
+  const save = (changes: Partial<UserSettings>) => {
+    if (!settings) return;
+    setError(null);
+
+    updateSettings({ ...settings, ...changes }).catch((e) => {
+      setError(`設定の保存に失敗しました: ${e}`);
+    });
+  };
+
+  const saveNumber = (key: 'xpDailyCommitCap' | 'xpMinCommitLines', value: string) => {
+    const parsed = Number.parseInt(value, 10);
+    if (!Number.isNaN(parsed)) save({ [key]: parsed });
+  };
+
+  return (
+    <div className="space-y-6">
+      {/* Loading state */}
+      {isLoading && !settings && (
+        <div className="text-center py-8 text-dt-text-sub">設定を読み込み中...</div>
+      )}
+
+      {/* Error message */}
+      {(error || (storeError && !settings)) && (
+        <div className="p-3 bg-red-900/30 border border-red-500/50 rounded-lg text-red-200 text-sm">
+          {error ?? `設定の読み込みに失敗しました: ${storeError}`}
+        </div>
+      )}
+
+      {settings && (
+        <>
+          <div className="space-y-2 p-4 bg-gm-bg-card/50 rounded-xl border border-gm-accent-cyan/20">
+            {/* Daily commit cap */}
+            <div className="flex items-center justify-between p-3 gap-4">
+              <div className="flex-1">
+                <label className="text-white block font-gaming font-bold" htmlFor="xp-daily-commit-cap">
+                  1日のコミット上限
+                </label>
+                <span className="text-sm text-dt-text-sub mt-1 block">
+                  上限を超えたコミットはXPが50%、2倍を超えると25%になります（0で無効）
+                </span>
+              </div>
+              <input
+                id="xp-daily-commit-cap"
+                type="number"
+                min={0}
+                max={500}
+                className="w-24 px-3 py-2 bg-gm-bg-primary border border-gm-accent-cyan/30 rounded-lg text-white text-right font-gaming-mono"
+                key={`cap-${settings.xpDailyCommitCap}`}
+                defaultValue={settings.xpDailyCommitCap}
+                onBlur={(e) => saveNumber('xpDailyCommitCap', e.target.value)}
+              />
+            </div>
+
+            {/* Minimum diff size */}
+            <div className="flex items-center justify-between p-3 gap-4">
+              <div className="flex-1">
+                <label className="text-white block font-gaming font-bold" htmlFor="xp-min-commit-lines">
+                  最小差分行数
+                </label>
+                <span className="text-sm text-dt-text-sub mt-1 block">
+                  1コミットあたりの平均変更行数がこれ未満の日はコミットXPを付与しません（0で無効）
+                </span>
+              </div>
+              <input
+                id="xp-min-commit-lines"
+                type="number"
+                min={0}
+                max={1000}
+                className="w-24 px-3 py-2 bg-gm-bg-primary border border-gm-accent-cyan/30 rounded-lg text-white text-right font-gaming-mono"
+                key={`lines-${settings.xpMinCommitLines}`}
+                defaultValue={settings.xpMinCommitLines}
+                onBlur={(e) => saveNumber('xpMinCommitLines', e.target.value)}
+              />
+            </div>
+
+            {/* Bot commits */}
+            <div className="flex items-center justify-between p-3 rounded-lg hover:bg-gm-bg-card/30 transition-colors">
+              <div className="flex-1">
+                <span
+                  className="text-white block font-gaming font-bold"
+                  id="xp-exclude-bot-commits-label"
+                >
+                  ボットのコミットを除外
+                </span>
+                <span className="text-sm text-dt-text-sub mt-1 block">
+                  GitHub Actions やボットが作成・コミットしたものはXPの対象外にします
+                </span>
+              </div>
+              <ToggleSwitch
+                enabled={settings.xpExcludeBotCommits}
+                onToggle={() => save({ xpExcludeBotCommits: !settings.xpExcludeBotCommits })}
+                labelId="xp-exclude-bot-commits-label"
+              />
+            </div>
+
+            {/* Excluded repositories */}
+            <div className="p-3 space-y-2">
+              <label className="text-white block font-gaming font-bold" htmlFor="xp-excluded-repositories">
+                除外リポジトリ
+              </label>
+              <span className="text-sm text-dt-text-sub block">
+                1行に1つ owner/name の形式で入力します
+              </span>
+              <textarea
+                id="xp-excluded-repositories"
+                rows={3}
+                placeholder="octocat/dotfiles"
+                className="w-full px-3 py-2 bg-gm-bg-primary border border-gm-accent-cyan/30 rounded-lg text-white font-mono text-sm"
+                value={repositoriesText}
+                onChange={(e) => setRepositoriesText(e.target.value)}
+                onBlur={() => save({ xpExcludedRepositories: parseRepositories(repositoriesText) })}
+              />
+            </div>
+          </div>
+
+          {/* Hint text */}
+          <div className="text-xs text-dt-text-sub p-3 bg-gm-bg-card/30 rounded-lg">
+            ※ 差し引かれたXPは「XP調整」としてXP履歴に理由付きで記録されます
+          </div>
+        </>
+      )}
+    </div>
+  );
+};
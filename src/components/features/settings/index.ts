@@ -9,8 +9,12 @@
 
 export { AccountSettings } from './AccountSettings';
 export { AppearanceSettings } from './AppearanceSettings';
+export { ChallengeSettings } from './ChallengeSettings';
 export { NotificationSettings } from './NotificationSettings';
+export { PrestigeSettings } from './PrestigeSettings';
 export { SyncSettings } from './SyncSettings';
+export { XpSafeguardSettings } from './XpSafeguardSettings';
+export { RepositoryFilterSettings } from './RepositoryFilterSettings';
 export { DataManagement } from './DataManagement';
 export { SettingsReset } from './SettingsReset';
 export { AppInfo } from './AppInfo';
@@ -33,7 +33,8 @@ pub use features::gamification::{
 };
 pub use features::issues::{
     CreateIssueModal, CreateProjectModal, IssueCard, IssueClickEvent, IssueDetailModal,
-    IssueDetailStatusChange, KanbanBoard, LinkRepositoryModal, StatusChangeEvent,
+    IssueDetailStatusChange, KanbanBoard, LinkRepositoryModal, OutboxAction, OutboxPanel,
+    StatusChangeEvent,
 };
 // TODO: [DEBT] Remove these re-exports after migrating all usages to contexts/ and hooks/
 pub use network_status::{
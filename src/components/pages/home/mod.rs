@@ -18,6 +18,7 @@
 //!   ├─ features/auth/login_card.rs - LoginCard
 //!   ├─ features/gamification/cache_indicator.rs - CacheIndicator
 //!   ├─ features/gamification/dashboard_content.rs - DashboardContent
+//!   ├─ features/gamification/repo_filter_switcher.rs - RepoFilterSwitcher
 //!   ├─ features/gamification/sync_notifications.rs - handle_sync_result_notifications
 //!   ├─ features/gamification/home_data_loader.rs - load_user_data
 //!   └─ features/gamification/xp_notification.rs - XpNotification, LevelUpModal, MultipleBadgesNotification
@@ -40,7 +41,9 @@ use crate::components::features::auth::LoginCard;
 use crate::components::features::gamification::xp_notification::{
     LevelUpModal, MultipleBadgesNotification,
 };
-use crate::components::features::gamification::{CacheIndicator, DashboardContent, XpNotification};
+use crate::components::features::gamification::{
+    CacheIndicator, DashboardContent, RepoFilterSwitcher, XpNotification,
+};
 use crate::hooks::use_is_online;
 use crate::tauri_api;
 use crate::types::{
@@ -427,6 +430,22 @@ pub fn HomePage(set_current_page: WriteSignal<AppPage>) -> impl IntoView {
         });
     });
 
+    // Reload the stats once the repository filter preset changes
+    let on_repo_filter_change = move || {
+        spawn_local(async move {
+            load_user_data(
+                set_github_stats,
+                set_level_info,
+                set_user_stats,
+                set_badges,
+                set_error,
+                set_data_from_cache,
+                set_cache_timestamp,
+            )
+            .await;
+        });
+    };
+
     // Callbacks for closing notifications
     let on_close_xp = move || set_xp_event.set(None);
     let on_close_level_up = move || set_level_up_event.set(None);
@@ -469,6 +488,7 @@ pub fn HomePage(set_current_page: WriteSignal<AppPage>) -> impl IntoView {
                         </Show>
                     }
                 >
+                    <RepoFilterSwitcher on_change=on_repo_filter_change />
                     <DashboardContent
                         auth_state=auth_state
                         level_info=level_info
@@ -1,7 +1,7 @@
 //! Project Dashboard Module
 //!
-//! Main dashboard for a single project with kanban board, repository linking,
-//! and issue management features.
+//! Main dashboard for a single project with kanban board, repository linking
+//! (one or more repositories per project), and issue management features.
 //!
 //! DEPENDENCY MAP:
 //! Parents (Files that import this page):
@@ -13,6 +13,10 @@
 //! Dependencies:
 //!   ├─ src/types/issue.rs
 //!   ├─ src/tauri_api.rs
+//!   ├─ src/components/network_status.rs (replay the offline outbox on reconnect)
+//!   ├─ src/components/features/issues/milestone_burndown.rs
+//!   ├─ src/components/features/issues/time_summary_panel.rs
+//!   ├─ src/components/features/issues/workflow_settings_modal.rs
 //!   └─ src/components/icons.rs
 //! Related Documentation:
 //!   └─ Issue: https://github.com/otomatty/development-tools/issues/117
@@ -25,12 +29,18 @@ use leptos::task::spawn_local;
 
 use crate::components::icons::Icon;
 use crate::components::issues::{
-    CreateIssueModal, IssueClickEvent, IssueDetailModal, IssueDetailStatusChange, KanbanBoard,
-    LinkRepositoryModal, StatusChangeEvent,
+    BulkActionEvent, CreateIssueModal, IssueClickEvent, IssueDetailModal, IssueDetailStatusChange,
+    KanbanBoard, LinkRepositoryModal, MilestoneBurndownPanel, OutboxAction, OutboxPanel,
+    StatusChangeEvent, TimeSummaryPanel, TimerToggleEvent, WorkflowSettingsModal,
 };
+use crate::components::network_status::use_is_online;
+use crate::components::ui::dialog::ConfirmDialog;
 use crate::tauri_api;
 use crate::types::{
-    issue::{CachedIssue, KanbanBoard as KanbanBoardType, Project},
+    issue::{
+        CachedIssue, KanbanBoard as KanbanBoardType, OutboxOperation, Project, ProjectMilestone,
+        ProjectRepository, TimeEntry,
+    },
     AppPage,
 };
 
@@ -49,9 +59,62 @@ pub fn ProjectDashboardPage(
     let (error, set_error) = signal(Option::<String>::None);
     let (show_link_modal, set_show_link_modal) = signal(false);
     let (show_create_issue_modal, set_show_create_issue_modal) = signal(false);
-    let (actions_yaml, set_actions_yaml) = signal(Option::<String>::None);
     let (show_actions_modal, set_show_actions_modal) = signal(false);
     let (selected_issue, set_selected_issue) = signal(Option::<CachedIssue>::None);
+    let (issue_updated, set_issue_updated) = signal(Option::<CachedIssue>::None);
+    let (outbox, set_outbox) = signal(Vec::<OutboxOperation>::new());
+    let (milestones, set_milestones) = signal(Vec::<ProjectMilestone>::new());
+    // Kanban milestone filter: `None` = all issues, `Some(0)` = issues
+    // without a milestone.
+    let (milestone_filter, set_milestone_filter) = signal(Option::<i32>::None);
+    let (repositories, set_repositories) = signal(Vec::<ProjectRepository>::new());
+    // Kanban repository filter: `None` = every linked repository.
+    let (repo_filter, set_repo_filter) = signal(Option::<String>::None);
+    let multi_repo = Signal::derive(move || repositories.with(|repos| repos.len() > 1));
+    let is_online = use_is_online();
+    // The user's running issue timer (at most one, possibly in another
+    // project) and a clock that ticks every second while it runs.
+    let (running_timer, set_running_timer) = signal(Option::<TimeEntry>::None);
+    let (now_ms, set_now_ms) = signal(js_sys::Date::now());
+    // Bumped when tracked time changes so the time panels reload.
+    let (time_version, set_time_version) = signal(0u32);
+
+    let refresh_outbox = move || {
+        spawn_local(async move {
+            match tauri_api::get_issue_outbox(Some(project_id)).await {
+                Ok(ops) => set_outbox.set(ops),
+                Err(e) => web_sys::console::log_1(&format!("Outbox load failed: {}", e).into()),
+            }
+        });
+    };
+
+    let refresh_milestones = move || {
+        spawn_local(async move {
+            match tauri_api::get_project_milestones(project_id).await {
+                Ok(list) => set_milestones.set(list),
+                Err(e) => web_sys::console::log_1(&format!("Milestones load failed: {}", e).into()),
+            }
+        });
+    };
+
+    let refresh_repositories = move || {
+        spawn_local(async move {
+            match tauri_api::get_project_repositories(project_id).await {
+                Ok(list) => {
+                    // Drop a filter pointing at a repository that was unlinked
+                    if let Some(selected) = repo_filter.get_untracked() {
+                        if !list.iter().any(|r| r.repo_full_name == selected) {
+                            set_repo_filter.set(None);
+                        }
+                    }
+                    set_repositories.set(list);
+                }
+                Err(e) => {
+                    web_sys::console::log_1(&format!("Repositories load failed: {}", e).into())
+                }
+            }
+        });
+    };
 
     // Load project and issues on mount
     {
@@ -69,7 +132,13 @@ pub fn ProjectDashboardPage(
             }
 
             // Load kanban board
-            match tauri_api::get_kanban_board(project_id).await {
+            match tauri_api::get_kanban_board(
+                project_id,
+                milestone_filter.get_untracked(),
+                repo_filter.get_untracked().as_deref(),
+            )
+            .await
+            {
                 Ok(board) => {
                     set_kanban.set(board);
                 }
@@ -81,8 +150,116 @@ pub fn ProjectDashboardPage(
 
             set_loading.set(false);
         });
+        refresh_outbox();
+        refresh_milestones();
+        refresh_repositories();
+        spawn_local(async move {
+            match tauri_api::get_running_timer().await {
+                Ok(timer) => set_running_timer.set(timer),
+                Err(e) => web_sys::console::log_1(&format!("Timer load failed: {}", e).into()),
+            }
+        });
+    }
+
+    // Tick the timer clock
+    if let Ok(handle) = set_interval_with_handle(
+        move || {
+            if running_timer.with_untracked(Option::is_some) {
+                set_now_ms.set(js_sys::Date::now());
+            }
+        },
+        std::time::Duration::from_secs(1),
+    ) {
+        on_cleanup(move || handle.clear());
     }
 
+    // Reload the board when the milestone or repository filter changes
+    // (skipping the initial run; the mount load above covers it).
+    Effect::new(move |prev: Option<(Option<i32>, Option<String>)>| {
+        let filter = (milestone_filter.get(), repo_filter.get());
+        if prev.as_ref().is_some_and(|prev| *prev != filter) {
+            let (milestone, repo) = filter.clone();
+            spawn_local(async move {
+                if let Ok(board) =
+                    tauri_api::get_kanban_board(project_id, milestone, repo.as_deref()).await
+                {
+                    set_kanban.set(board);
+                }
+            });
+        }
+        filter
+    });
+
+    // Replay offline edits when the network comes back. Tracks the
+    // previous value so the initial `true` on mount doesn't count as a
+    // reconnect (mount-time queues are flushed by the next Sync anyway).
+    Effect::new(move |was_online: Option<bool>| {
+        let online = is_online.get();
+        if online && was_online == Some(false) {
+            spawn_local(async move {
+                match tauri_api::replay_issue_outbox(Some(project_id)).await {
+                    Ok(result) => {
+                        if result.failed + result.conflicts > 0 {
+                            set_error.set(Some(format!(
+                                "オフライン中の変更のうち {} 件を反映できませんでした。",
+                                result.failed + result.conflicts
+                            )));
+                        }
+                    }
+                    Err(e) => set_error.set(Some(format!("Outbox replay failed: {}", e))),
+                }
+                if let Ok(board) = tauri_api::get_kanban_board(
+                    project_id,
+                    milestone_filter.get_untracked(),
+                    repo_filter.get_untracked().as_deref(),
+                )
+                .await
+                {
+                    set_kanban.set(board);
+                }
+                refresh_outbox();
+            });
+        }
+        online
+    });
+
+    // Retry / discard actions from the outbox panel
+    let (outbox_action, set_outbox_action) = signal(Option::<OutboxAction>::None);
+    Effect::new(move |_| {
+        if let Some(action) = outbox_action.get() {
+            spawn_local(async move {
+                let result = match action {
+                    OutboxAction::Retry {
+                        operation_id,
+                        force,
+                    } => match tauri_api::retry_outbox_operation(operation_id, force).await {
+                        Ok(_) => tauri_api::replay_issue_outbox(Some(project_id))
+                            .await
+                            .map(|_| ()),
+                        Err(e) => Err(e),
+                    },
+                    OutboxAction::Discard { operation_id } => {
+                        tauri_api::discard_outbox_operation(operation_id).await
+                    }
+                };
+                if let Err(e) = result {
+                    set_error.set(Some(e));
+                }
+                if let Ok(board) = tauri_api::get_kanban_board(
+                    project_id,
+                    milestone_filter.get_untracked(),
+                    repo_filter.get_untracked().as_deref(),
+                )
+                .await
+                {
+                    set_kanban.set(board);
+                }
+                refresh_outbox();
+            });
+            set_outbox_action.set(None);
+        }
+    });
+
     // Sync issues from GitHub
     let sync_issues = move |_| {
         set_syncing.set(true);
@@ -97,8 +274,21 @@ pub fn ProjectDashboardPage(
                     // flag below, so we don't need a transient error
                     // message for the same condition. Issue #190 /
                     // PR #213 review feedback.
-                    let board = KanbanBoardType::from_issues(response.issues);
+                    let mut issues = response.issues;
+                    if let Some(number) = milestone_filter.get_untracked() {
+                        issues.retain(|issue| issue.milestone_number.unwrap_or(0) == number);
+                    }
+                    if let Some(repo) = repo_filter.get_untracked() {
+                        issues
+                            .retain(|issue| issue.repo_full_name.as_deref() == Some(repo.as_str()));
+                    }
+                    let board = KanbanBoardType::from_issues(issues);
                     set_kanban.set(board);
+                    // The backend flushes the outbox before pulling, and
+                    // refreshes milestones after it.
+                    refresh_outbox();
+                    refresh_milestones();
+                    refresh_repositories();
 
                     // Refresh the project so `is_archived` etc. reflect
                     // the new state for the banner / re-link button. The
@@ -117,43 +307,93 @@ pub fn ProjectDashboardPage(
         });
     };
 
-    // Setup GitHub Actions
-    let setup_actions = move |_| {
-        spawn_local(async move {
-            match tauri_api::setup_github_actions(project_id).await {
-                Ok(yaml) => {
-                    set_actions_yaml.set(Some(yaml));
-                    set_show_actions_modal.set(true);
-                }
-                Err(e) => {
-                    set_error.set(Some(format!("Failed to generate Actions template: {}", e)));
-                }
-            }
-        });
-    };
-
     // Handle repository linked
     let on_repo_linked = move |updated_project: Project| {
         set_project.set(Some(updated_project));
         set_show_link_modal.set(false);
-        // Re-link to a different repo clears stale cached_issues on the
-        // backend (see `link_repository` — PR #213 P1 review). Refresh
-        // the kanban so the UI doesn't keep showing the old cards
-        // briefly while the user wonders whether the re-link took
+        refresh_repositories();
+        // Linking a recreated repo under a known name clears its stale
+        // cached_issues on the backend (see `link_repository` — PR #213 P1
+        // review). Refresh the kanban so the UI doesn't keep showing the
+        // old cards briefly while the user wonders whether the link took
         // effect.
         spawn_local(async move {
-            if let Ok(board) = tauri_api::get_kanban_board(project_id).await {
+            if let Ok(board) = tauri_api::get_kanban_board(
+                project_id,
+                milestone_filter.get_untracked(),
+                repo_filter.get_untracked().as_deref(),
+            )
+            .await
+            {
                 set_kanban.set(board);
             }
         });
     };
 
+    // Unlink one repository (its cached issues go with it), after the
+    // confirmation dialog
+    let (pending_unlink, set_pending_unlink) = signal(Option::<String>::None);
+    let (show_unlink_dialog, set_show_unlink_dialog) = signal(false);
+    let unlink_repo = move || {
+        set_show_unlink_dialog.set(false);
+        let Some(full_name) = pending_unlink.get_untracked() else {
+            return;
+        };
+        set_pending_unlink.set(None);
+        spawn_local(async move {
+            match tauri_api::unlink_repository(project_id, &full_name).await {
+                Ok(updated_project) => {
+                    set_project.set(Some(updated_project));
+                    refresh_repositories();
+                    refresh_milestones();
+                    if repo_filter.get_untracked().as_deref() == Some(full_name.as_str()) {
+                        set_repo_filter.set(None);
+                    }
+                    if let Ok(board) = tauri_api::get_kanban_board(
+                        project_id,
+                        milestone_filter.get_untracked(),
+                        repo_filter.get_untracked().as_deref(),
+                    )
+                    .await
+                    {
+                        set_kanban.set(board);
+                    }
+                }
+                Err(e) => set_error.set(Some(format!("Failed to unlink repository: {}", e))),
+            }
+        });
+    };
+
+    // Handle issue edited / closed / reopened from the detail modal: show
+    // the refreshed issue and reload the board (closing or reopening moves
+    // the card to another column).
+    Effect::new(move |_| {
+        if let Some(issue) = issue_updated.get() {
+            set_selected_issue.set(Some(issue));
+            spawn_local(async move {
+                if let Ok(board) = tauri_api::get_kanban_board(
+                    project_id,
+                    milestone_filter.get_untracked(),
+                    repo_filter.get_untracked().as_deref(),
+                )
+                .await
+                {
+                    set_kanban.set(board);
+                }
+            });
+        }
+    });
+
     // Handle issue created
     let on_issue_created = move |issue: CachedIssue| {
+        let queued = issue.pending_sync;
         set_kanban.update(|board| {
             utils::add_issue_to_board(board, issue);
         });
         set_show_create_issue_modal.set(false);
+        if queued {
+            refresh_outbox();
+        }
     };
 
     // Handle status change - using signal-based approach for Leptos thread safety
@@ -173,16 +413,30 @@ pub fn ProjectDashboardPage(
         if let Some(event) = status_change_event.get() {
             let issue_number = event.issue_number;
             let new_status = event.new_status.clone();
+            let repo_full_name = event.repo_full_name.clone();
             spawn_local(async move {
-                if let Err(e) =
-                    tauri_api::update_issue_status(project_id, issue_number, &new_status).await
+                if let Err(e) = tauri_api::update_issue_status(
+                    project_id,
+                    issue_number,
+                    &new_status,
+                    repo_full_name.as_deref(),
+                )
+                .await
                 {
                     set_error.set(Some(format!("Failed to update status: {}", e)));
                 } else {
                     // Refresh kanban board
-                    if let Ok(board) = tauri_api::get_kanban_board(project_id).await {
+                    if let Ok(board) = tauri_api::get_kanban_board(
+                        project_id,
+                        milestone_filter.get_untracked(),
+                        repo_filter.get_untracked().as_deref(),
+                    )
+                    .await
+                    {
                         set_kanban.set(board);
                     }
+                    // The change may have been queued while offline
+                    refresh_outbox();
                 }
             });
             // Clear the event after processing
@@ -190,6 +444,82 @@ pub fn ProjectDashboardPage(
         }
     });
 
+    // Bulk actions on the cards selected in the kanban
+    let (bulk_action_event, set_bulk_action_event) = signal(Option::<BulkActionEvent>::None);
+    Effect::new(move |_| {
+        if let Some(event) = bulk_action_event.get() {
+            spawn_local(async move {
+                match tauri_api::bulk_update_issues(project_id, &event.targets, &event.action).await
+                {
+                    Ok(result) => {
+                        if !result.failed.is_empty() {
+                            let details = result
+                                .failed
+                                .iter()
+                                .map(|f| format!("#{}: {}", f.issue_number, f.error))
+                                .collect::<Vec<_>>()
+                                .join(" / ");
+                            set_error.set(Some(format!(
+                                "{} 件の Issue を更新できませんでした: {}",
+                                result.failed.len(),
+                                details
+                            )));
+                        }
+                        if result.queued > 0 {
+                            refresh_outbox();
+                        }
+                    }
+                    Err(e) => set_error.set(Some(format!("Bulk update failed: {}", e))),
+                }
+                // Refresh kanban board (also after partial failures,
+                // which were rolled back)
+                if let Ok(board) = tauri_api::get_kanban_board(
+                    project_id,
+                    milestone_filter.get_untracked(),
+                    repo_filter.get_untracked().as_deref(),
+                )
+                .await
+                {
+                    set_kanban.set(board);
+                }
+            });
+            set_bulk_action_event.set(None);
+        }
+    });
+
+    // Start / stop issue timers from cards and the detail modal. Starting
+    // a timer stops the running one first (on the backend).
+    let (timer_toggle_event, set_timer_toggle_event) = signal(Option::<TimerToggleEvent>::None);
+    Effect::new(move |_| {
+        if let Some(event) = timer_toggle_event.get() {
+            spawn_local(async move {
+                let had_timer = running_timer.get_untracked().is_some();
+                let result = if event.start {
+                    tauri_api::start_issue_timer(
+                        project_id,
+                        event.issue_number,
+                        event.repo_full_name.as_deref(),
+                    )
+                    .await
+                    .map(Some)
+                } else {
+                    tauri_api::stop_issue_timer(None).await.map(|_| None)
+                };
+                match result {
+                    Ok(timer) => {
+                        set_now_ms.set(js_sys::Date::now());
+                        set_running_timer.set(timer);
+                        if had_timer {
+                            set_time_version.update(|v| *v += 1);
+                        }
+                    }
+                    Err(e) => set_error.set(Some(format!("Timer failed: {}", e))),
+                }
+            });
+            set_timer_toggle_event.set(None);
+        }
+    });
+
     // Callback for not linked state
     let on_link_click = Callback::new(move |_: ()| {
         set_show_link_modal.set(true);
@@ -261,14 +591,26 @@ pub fn ProjectDashboardPage(
                             </button>
                         </Show>
 
-                        // Setup Actions button (if linked but not setup, and not archived)
-                        <Show when=move || project.get().map(|p| p.is_linked() && !p.is_actions_setup && !p.is_archived).unwrap_or(false)>
+                        // Add another repository to a linked project
+                        <Show when=move || project.get().map(|p| p.is_linked()).unwrap_or(false)>
+                            <button
+                                class="flex items-center gap-2 px-3 py-1.5 text-sm text-dt-text-sub hover:text-dt-text border border-slate-700 hover:border-gm-accent-cyan rounded-lg transition-colors"
+                                on:click=move |_| set_show_link_modal.set(true)
+                            >
+                                <Icon name="plus".to_string() class="w-4 h-4".to_string() />
+                                <span>"Add Repository"</span>
+                            </button>
+                        </Show>
+
+                        // Actions workflow button (if linked and not archived). Stays
+                        // available after setup for editing settings and the drift check.
+                        <Show when=move || project.get().map(|p| p.is_linked() && !p.is_archived).unwrap_or(false)>
                             <button
                                 class="flex items-center gap-2 px-3 py-1.5 text-sm text-yellow-400 border border-yellow-400/50 hover:bg-yellow-400/10 rounded-lg transition-colors"
-                                on:click=setup_actions
+                                on:click=move |_| set_show_actions_modal.set(true)
                             >
                                 <Icon name="settings".to_string() class="w-4 h-4".to_string() />
-                                <span>"Setup Actions"</span>
+                                <span>{move || if project.get().map(|p| p.is_actions_setup).unwrap_or(false) { "Workflow" } else { "Setup Actions" }}</span>
                             </button>
                         </Show>
 
@@ -330,6 +672,99 @@ pub fn ProjectDashboardPage(
                     </div>
                 </Show>
 
+                // Linked repositories (shown once there is more than one).
+                // A repository that returned 404 is archived on its own;
+                // the rest of the project keeps syncing.
+                <Show when=move || multi_repo.get()>
+                    <div class="mt-3 flex flex-wrap items-center gap-2 text-sm">
+                        {move || repositories.get().into_iter().enumerate().map(|(i, r)| {
+                            let full_name = r.repo_full_name.clone();
+                            view! {
+                                <span class=format!(
+                                    "flex items-center gap-1 px-2 py-1 rounded-lg border {}",
+                                    if r.is_archived { "border-amber-500/50 text-amber-300" } else { "border-slate-700 text-dt-text-sub" },
+                                )>
+                                    <Icon name="github".to_string() class="w-3.5 h-3.5".to_string() />
+                                    <span>{r.repo_full_name.clone()}</span>
+                                    {(i == 0).then(|| view! { <span class="text-xs text-gm-accent-cyan">"primary"</span> })}
+                                    {r.is_archived.then(|| view! {
+                                        <span class="text-xs" title="GitHub 上でリポジトリが見つかりません">"archived"</span>
+                                    })}
+                                    <button
+                                        class="ml-1 text-dt-text-sub hover:text-red-400"
+                                        title="Unlink repository"
+                                        on:click=move |_| {
+                                            set_pending_unlink.set(Some(full_name.clone()));
+                                            set_show_unlink_dialog.set(true);
+                                        }
+                                    >
+                                        <Icon name="x".to_string() class="w-3.5 h-3.5".to_string() />
+                                    </button>
+                                </span>
+                            }
+                        }).collect_view()}
+                        <select
+                            class="px-2 py-1 bg-slate-800 border border-slate-700 rounded-lg text-dt-text focus:outline-none focus:border-gm-accent-cyan"
+                            on:change=move |ev| {
+                                let value = event_target_value(&ev);
+                                set_repo_filter.set(if value.is_empty() { None } else { Some(value) });
+                            }
+                        >
+                            <option value="" selected=move || repo_filter.get().is_none()>"All repositories"</option>
+                            {move || repositories.get().into_iter().map(|r| {
+                                let name = r.repo_full_name;
+                                let value = name.clone();
+                                let selected_name = name.clone();
+                                view! {
+                                    <option
+                                        value=value
+                                        selected=move || repo_filter.get().as_deref() == Some(selected_name.as_str())
+                                    >
+                                        {name}
+                                    </option>
+                                }
+                            }).collect_view()}
+                        </select>
+                    </div>
+                </Show>
+
+                // Milestone filter for the kanban board
+                <Show when=move || !milestones.get().is_empty()>
+                    <div class="mt-3 flex items-center gap-2 text-sm">
+                        <label class="text-dt-text-sub">"Milestone"</label>
+                        <select
+                            class="px-2 py-1 bg-slate-800 border border-slate-700 rounded-lg text-dt-text focus:outline-none focus:border-gm-accent-cyan"
+                            on:change=move |ev| {
+                                let value = event_target_value(&ev);
+                                set_milestone_filter.set(value.parse::<i32>().ok());
+                            }
+                        >
+                            <option value="" selected=move || milestone_filter.get().is_none()>"All issues"</option>
+                            <option value="0" selected=move || milestone_filter.get() == Some(0)>"No milestone"</option>
+                            {move || milestones.get().into_iter().map(|m| {
+                                let number = m.number;
+                                let label = format!(
+                                    "{}{} ({:.0}%)",
+                                    m.title,
+                                    if m.state == "closed" { " [closed]" } else { "" },
+                                    m.progress_percent()
+                                );
+                                view! {
+                                    <option
+                                        value=number.to_string()
+                                        selected=move || milestone_filter.get() == Some(number)
+                                    >
+                                        {label}
+                                    </option>
+                                }
+                            }).collect_view()}
+                        </select>
+                    </div>
+                </Show>
+
+                // Offline outbox (queued / failed / conflicting edits)
+                <OutboxPanel operations=outbox action_signal=set_outbox_action />
+
                 // Error message
                 <Show when=move || error.get().is_some()>
                     <div class="mt-3 p-3 bg-red-500/20 border border-red-500/50 rounded-lg text-sm text-red-400">
@@ -364,11 +799,21 @@ pub fn ProjectDashboardPage(
                 // synced cards so the historical view stays readable
                 // (PR #213 P2 review).
                 <Show when=move || !loading.get() && project.get().map(|p| p.is_linked()).unwrap_or(false)>
+                    // Burndown / burnup for the selected milestone
+                    {move || milestone_filter.get().filter(|n| *n > 0).map(|number| view! {
+                        <MilestoneBurndownPanel project_id=project_id milestone_number=number />
+                    })}
+                    <TimeSummaryPanel project_id=project_id refresh=time_version />
                     <KanbanBoard
                         board=kanban
                         status_change_signal=set_status_change_event
                         issue_click_signal=set_issue_click_event
                         read_only=Signal::derive(move || project.get().map(|p| p.is_archived).unwrap_or(false))
+                        show_repo=multi_repo
+                        running_timer=running_timer
+                        now_ms=now_ms
+                        timer_toggle_signal=set_timer_toggle_event
+                        bulk_action_signal=set_bulk_action_event
                     />
                 </Show>
             </div>
@@ -382,10 +827,27 @@ pub fn ProjectDashboardPage(
                         visible=visible
                         on_close=move || set_show_link_modal.set(false)
                         on_linked=on_repo_linked
+                        linked_repos=Signal::derive(move || {
+                            repositories.get().into_iter().map(|r| r.repo_full_name).collect::<Vec<_>>()
+                        })
                     />
                 }
             }
 
+            // Unlink repository confirmation
+            <ConfirmDialog
+                title="リポジトリのリンク解除".to_string()
+                message="このリポジトリのリンクを解除しますか？キャッシュされた Issue も削除されます（GitHub 上の Issue は残ります）。".to_string()
+                confirm_label="リンク解除".to_string()
+                cancel_label="キャンセル".to_string()
+                visible=show_unlink_dialog
+                on_confirm=move |_| unlink_repo()
+                on_cancel=move |_| {
+                    set_show_unlink_dialog.set(false);
+                    set_pending_unlink.set(None);
+                }
+            />
+
             // Create Issue Modal
             {
                 let visible = Memo::new(move |_| show_create_issue_modal.get());
@@ -395,68 +857,23 @@ pub fn ProjectDashboardPage(
                         visible=visible
                         on_close=move || set_show_create_issue_modal.set(false)
                         on_created=on_issue_created
+                        repositories=repositories
                     />
                 }
             }
 
-            // GitHub Actions Setup Modal
-            <Show when=move || show_actions_modal.get()>
-                <div class="fixed inset-0 bg-black/50 flex items-center justify-center z-50">
-                    <div class="bg-dt-card border border-slate-700/50 rounded-lg p-6 w-full max-w-2xl mx-4 max-h-[80vh] overflow-y-auto">
-                        <div class="flex items-center justify-between mb-4">
-                            <h2 class="text-xl font-semibold text-dt-text">"GitHub Actions Setup"</h2>
-                            <button
-                                class="p-1 text-dt-text-sub hover:text-dt-text"
-                                on:click=move |_| set_show_actions_modal.set(false)
-                            >
-                                <Icon name="x".to_string() class="w-5 h-5".to_string() />
-                            </button>
-                        </div>
-
-                        <p class="text-dt-text-sub mb-4">
-                            "Copy the following YAML content and create a new file at "
-                            <code class="px-1 py-0.5 bg-slate-800 rounded text-gm-accent-cyan">".github/workflows/issue-status.yml"</code>
-                            " in your repository:"
-                        </p>
-
-                        <div class="relative">
-                            <pre class="p-4 bg-slate-900 rounded-lg text-sm text-slate-300 overflow-x-auto">
-                                <code>{move || actions_yaml.get().unwrap_or_default()}</code>
-                            </pre>
-                            <button
-                                class="absolute top-2 right-2 p-2 text-dt-text-sub hover:text-dt-text bg-slate-800 rounded"
-                                title="Copy to clipboard"
-                                on:click=move |_| {
-                                    if let Some(yaml) = actions_yaml.get() {
-                                        if let Some(window) = web_sys::window() {
-                                            let clipboard = window.navigator().clipboard();
-                                            let _ = clipboard.write_text(&yaml);
-                                        }
-                                    }
-                                }
-                            >
-                                <Icon name="clipboard-copy".to_string() class="w-4 h-4".to_string() />
-                            </button>
-                        </div>
-
-                        <div class="mt-4 p-3 bg-yellow-500/10 border border-yellow-500/30 rounded-lg">
-                            <p class="text-sm text-yellow-400">
-                                <strong>"Note:"</strong>
-                                " After adding this file to your repository, the workflow will automatically update issue status based on branch activity."
-                            </p>
-                        </div>
-
-                        <div class="flex justify-end mt-6">
-                            <button
-                                class="px-4 py-2 bg-gradient-to-r from-gm-accent-cyan to-gm-accent-purple text-white rounded-lg hover:opacity-90 transition-opacity"
-                                on:click=move |_| set_show_actions_modal.set(false)
-                            >
-                                "Got it!"
-                            </button>
-                        </div>
-                    </div>
-                </div>
-            </Show>
+            // GitHub Actions workflow settings
+            {
+                let visible = Memo::new(move |_| show_actions_modal.get());
+                view! {
+                    <WorkflowSettingsModal
+                        project_id=project_id
+                        visible=visible
+                        on_close=move || set_show_actions_modal.set(false)
+                        repositories=repositories
+                    />
+                }
+            }
 
             // Issue Detail Modal
             {move || {
@@ -471,6 +888,7 @@ pub fn ProjectDashboardPage(
                         set_status_change_event.set(Some(StatusChangeEvent {
                             issue_number: event.issue_number,
                             new_status: event.new_status,
+                            repo_full_name: event.repo_full_name,
                         }));
                         // Close the modal after status change
                         set_selected_issue.set(None);
@@ -484,6 +902,12 @@ pub fn ProjectDashboardPage(
                             visible=visible
                             on_close=move || set_selected_issue.set(None)
                             status_change_signal=set_detail_status_change
+                            project_id=project_id
+                            issue_updated_signal=set_issue_updated
+                            running_timer=running_timer
+                            now_ms=now_ms
+                            timer_toggle_signal=set_timer_toggle_event
+                            time_refresh=time_version
                         />
                     }
                 })
@@ -14,6 +14,7 @@
 //!   ├─ src/tauri_api.rs
 //!   ├─ src/components/icons.rs
 //!   ├─ src/components/features/issues/create_project_modal.rs
+//!   ├─ src/components/features/issues/issue_search.rs
 //!   └─ src/components/features/issues/project_card.rs
 //! Related Documentation:
 //!   └─ Issue: https://github.com/otomatty/development-tools/issues/117
@@ -24,7 +25,9 @@ pub mod utils;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
-use crate::components::features::issues::{CreateProjectModal, ProjectCard, ProjectsEmptyState};
+use crate::components::features::issues::{
+    CreateProjectModal, IssueSearch, ProjectCard, ProjectsEmptyState,
+};
 use crate::components::icons::Icon;
 use crate::tauri_api;
 use crate::types::{issue::Project, AppPage};
@@ -100,6 +103,9 @@ pub fn ProjectsPage(set_current_page: WriteSignal<AppPage>) -> impl IntoView {
                 </button>
             </div>
 
+            // Cross-project search over the local issue cache
+            <IssueSearch on_select=navigate_to_project />
+
             // Error message
             <Show when=move || error.get().is_some()>
                 <div class="mb-4 p-4 bg-red-500/20 border border-red-500/50 rounded-lg text-red-400">
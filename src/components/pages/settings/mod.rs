@@ -24,8 +24,9 @@ use std::collections::HashSet;
 
 use crate::components::icons::Icon;
 use crate::components::settings::{
-    AccountSettings, AppInfoSection, AppearanceSettings, DataManagement, NotificationSettings,
-    SettingsResetSection, SyncSettings,
+    AccountSettings, AppInfoSection, AppearanceSettings, ChallengeSettings, DataManagement,
+    NotificationSettings, PrestigeSettings, RepositoryFilterSettings, SettingsResetSection,
+    SyncSettings, XpSafeguardSettings,
 };
 use crate::components::ui::AccordionSection;
 use crate::types::{AppPage, AuthState};
@@ -70,6 +71,23 @@ pub fn SettingsPage(
             .get()
             .contains(&SettingsSection::Appearance)
     });
+    let challenge_expanded = Signal::derive(move || {
+        expanded_sections
+            .get()
+            .contains(&SettingsSection::Challenge)
+    });
+    let prestige_expanded =
+        Signal::derive(move || expanded_sections.get().contains(&SettingsSection::Prestige));
+    let xp_safeguard_expanded = Signal::derive(move || {
+        expanded_sections
+            .get()
+            .contains(&SettingsSection::XpSafeguard)
+    });
+    let repository_filter_expanded = Signal::derive(move || {
+        expanded_sections
+            .get()
+            .contains(&SettingsSection::RepositoryFilter)
+    });
     let data_management_expanded = Signal::derive(move || {
         expanded_sections
             .get()
@@ -134,6 +152,50 @@ pub fn SettingsPage(
                     <AppearanceSettings />
                 </AccordionSection>
 
+                // Challenge Settings Section
+                <AccordionSection
+                    title="チャレンジ設定".to_string()
+                    icon="trophy"
+                    expanded=challenge_expanded
+                    on_toggle=move || toggle_section(SettingsSection::Challenge)
+                    max_height="600px"
+                >
+                    <ChallengeSettings />
+                </AccordionSection>
+
+                // Prestige Section
+                <AccordionSection
+                    title="プレステージ".to_string()
+                    icon="star"
+                    expanded=prestige_expanded
+                    on_toggle=move || toggle_section(SettingsSection::Prestige)
+                    max_height="600px"
+                >
+                    <PrestigeSettings />
+                </AccordionSection>
+
+                // XP Safeguard Section
+                <AccordionSection
+                    title="XPセーフガード".to_string()
+                    icon="shield"
+                    expanded=xp_safeguard_expanded
+                    on_toggle=move || toggle_section(SettingsSection::XpSafeguard)
+                    max_height="800px"
+                >
+                    <XpSafeguardSettings />
+                </AccordionSection>
+
+                // Repository Filter Section
+                <AccordionSection
+                    title="リポジトリフィルター".to_string()
+                    icon="git-branch"
+                    expanded=repository_filter_expanded
+                    on_toggle=move || toggle_section(SettingsSection::RepositoryFilter)
+                    max_height="1400px"
+                >
+                    <RepositoryFilterSettings />
+                </AccordionSection>
+
                 // Data Management Section
                 <AccordionSection
                     title="データ管理".to_string()
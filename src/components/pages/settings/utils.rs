@@ -15,6 +15,10 @@ pub enum SettingsSection {
     Notification,
     Sync,
     Appearance,
+    Challenge,
+    Prestige,
+    XpSafeguard,
+    RepositoryFilter,
     DataManagement,
     AppInfo,
 }
@@ -26,6 +30,10 @@ pub fn get_section_icon(section: SettingsSection) -> &'static str {
         SettingsSection::Notification => "bell",
         SettingsSection::Sync => "refresh-cw",
         SettingsSection::Appearance => "palette",
+        SettingsSection::Challenge => "trophy",
+        SettingsSection::Prestige => "star",
+        SettingsSection::XpSafeguard => "shield",
+        SettingsSection::RepositoryFilter => "git-branch",
         SettingsSection::DataManagement => "database",
         SettingsSection::AppInfo => "info",
     }
@@ -38,6 +46,10 @@ pub fn get_section_title(section: SettingsSection) -> &'static str {
         SettingsSection::Notification => "通知設定",
         SettingsSection::Sync => "同期設定",
         SettingsSection::Appearance => "外観設定",
+        SettingsSection::Challenge => "チャレンジ設定",
+        SettingsSection::Prestige => "プレステージ",
+        SettingsSection::XpSafeguard => "XPセーフガード",
+        SettingsSection::RepositoryFilter => "リポジトリフィルター",
         SettingsSection::DataManagement => "データ管理",
         SettingsSection::AppInfo => "アプリ情報",
     }
@@ -50,6 +62,10 @@ pub fn get_section_max_height(section: SettingsSection) -> &'static str {
         SettingsSection::Notification => "1000px",
         SettingsSection::Sync => "1000px",
         SettingsSection::Appearance => "500px",
+        SettingsSection::Challenge => "600px",
+        SettingsSection::Prestige => "600px",
+        SettingsSection::XpSafeguard => "800px",
+        SettingsSection::RepositoryFilter => "1400px",
         SettingsSection::DataManagement => "1200px",
         SettingsSection::AppInfo => "600px",
     }
@@ -31,6 +31,17 @@ pub fn XpHistoryItem(entry: XpHistoryEntry) -> impl IntoView {
     let relative_time = format_relative_time(&entry.created_at);
     let absolute_time = format_absolute_time(&entry.created_at);
     let xp_amount = entry.xp_amount;
+    // Negative amounts come from `xp_adjusted` entries (XP safeguards)
+    let xp_amount_label = if xp_amount < 0 {
+        xp_amount.to_string()
+    } else {
+        format!("+{}", xp_amount)
+    };
+    let xp_amount_class = if xp_amount < 0 {
+        "text-slate-400"
+    } else {
+        "text-gm-success"
+    };
     let description = entry.description.clone();
     let description_for_expanded = entry.description.clone();
     let action_type = entry.action_type.clone();
@@ -71,8 +82,8 @@ pub fn XpHistoryItem(entry: XpHistoryEntry) -> impl IntoView {
 
                 // XP Amount
                 <div class="flex-shrink-0 text-right">
-                    <span class="text-xl font-gaming-mono font-bold text-gm-success">
-                        "+" {xp_amount}
+                    <span class=format!("text-xl font-gaming-mono font-bold {}", xp_amount_class)>
+                        {xp_amount_label.clone()}
                     </span>
                     <span class="text-gm-accent-cyan text-sm ml-1">"XP"</span>
                 </div>
@@ -101,8 +112,8 @@ pub fn XpHistoryItem(entry: XpHistoryEntry) -> impl IntoView {
                             </div>
                             <div>
                                 <div class="text-dt-text-sub text-xs mb-1">"獲得XP"</div>
-                                <div class="text-gm-success font-gaming-mono font-bold">
-                                    "+" {xp_amount} " XP"
+                                <div class=format!("font-gaming-mono font-bold {}", xp_amount_class)>
+                                    {xp_amount_label} " XP"
                                 </div>
                             </div>
                             <div>
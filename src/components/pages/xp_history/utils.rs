@@ -17,7 +17,10 @@ pub fn get_action_icon(action_type: &str) -> &'static str {
         "review" => "👀",
         "issue" => "📋",
         "issue_closed" => "✔️",
+        "time_tracked" => "⏱",
         "streak_bonus" => "🔥",
+        "prestige_bonus" => "🌀",
+        "xp_adjusted" => "⚖️",
         "star" => "⭐",
         _ => "💫",
     }
@@ -32,7 +35,10 @@ pub fn get_action_display_name(action_type: &str) -> &'static str {
         "review" => "レビュー",
         "issue" => "Issue作成",
         "issue_closed" => "Issueクローズ",
+        "time_tracked" => "タイムトラッキング",
         "streak_bonus" => "ストリークボーナス",
+        "prestige_bonus" => "プレステージボーナス",
+        "xp_adjusted" => "XP調整",
         "star" => "スター獲得",
         _ => "その他",
     }
@@ -47,7 +53,10 @@ pub fn get_action_color_class(action_type: &str) -> &'static str {
         "review" => "text-yellow-400",
         "issue" => "text-orange-400",
         "issue_closed" => "text-emerald-400",
+        "time_tracked" => "text-cyan-400",
         "streak_bonus" => "text-red-400",
+        "prestige_bonus" => "text-fuchsia-400",
+        "xp_adjusted" => "text-slate-400",
         "star" => "text-amber-400",
         _ => "text-gray-400",
     }
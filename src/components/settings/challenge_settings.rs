@@ -0,0 +1,120 @@
+//! Challenge settings component
+//!
+//! Allows users to choose how hard auto-generated challenges are.
+
+use leptos::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::tauri_api;
+use crate::types::{ChallengeDifficulty, UpdateSettingsRequest, UserSettings};
+
+/// Challenge settings component
+#[component]
+pub fn ChallengeSettings() -> impl IntoView {
+    let (settings, set_settings) = signal(Option::<UserSettings>::None);
+    let (loading, set_loading) = signal(true);
+    let (error, set_error) = signal(None::<String>);
+
+    // Load settings on mount
+    spawn_local(async move {
+        match tauri_api::get_settings().await {
+            Ok(loaded_settings) => {
+                set_settings.set(Some(loaded_settings));
+            }
+            Err(e) => {
+                set_error.set(Some(format!("設定の読み込みに失敗しました: {}", e)));
+            }
+        }
+        set_loading.set(false);
+    });
+
+    // Save the new difficulty right away, rolling back on failure
+    let update_difficulty = move |difficulty: ChallengeDifficulty| {
+        let Some(previous) = settings.get_untracked() else {
+            return;
+        };
+        let mut updated = previous.clone();
+        updated.challenge_difficulty = difficulty.as_str().to_string();
+        set_settings.set(Some(updated.clone()));
+        set_error.set(None);
+
+        spawn_local(async move {
+            let update_request = UpdateSettingsRequest::from(&updated);
+            if let Err(e) = tauri_api::update_settings(&update_request).await {
+                set_settings.set(Some(previous));
+                set_error.set(Some(format!("設定の保存に失敗しました: {}", e)));
+            }
+        });
+    };
+
+    view! {
+        <div class="space-y-6">
+            // Loading state
+            <Show when=move || loading.get()>
+                <div class="text-center py-8 text-dt-text-sub">
+                    "設定を読み込み中..."
+                </div>
+            </Show>
+
+            // Error message
+            <Show when=move || error.get().is_some()>
+                <div class="p-3 bg-red-900/30 border border-red-500/50 rounded-lg text-red-200 text-sm">
+                    {move || error.get().unwrap_or_default()}
+                </div>
+            </Show>
+
+            // Settings form
+            <Show when=move || settings.get().is_some() && !loading.get()>
+                {move || {
+                    let current_settings = settings.get().unwrap();
+                    let current = ChallengeDifficulty::from_str(&current_settings.challenge_difficulty);
+
+                    view! {
+                        <div class="space-y-3">
+                            <h3 class="text-lg font-gaming font-bold text-white">
+                                "自動チャレンジの難易度"
+                            </h3>
+                            <div class="space-y-2 p-4 bg-gm-bg-card/50 rounded-xl border border-gm-accent-cyan/20">
+                                {ChallengeDifficulty::ALL.into_iter().map(move |difficulty| {
+                                    let is_selected = current == difficulty;
+
+                                    view! {
+                                        <label
+                                            class=format!(
+                                                "flex items-center gap-3 p-3 rounded-lg cursor-pointer transition-colors {}",
+                                                if is_selected {
+                                                    "bg-gm-accent-cyan/20 border border-gm-accent-cyan/50"
+                                                } else {
+                                                    "hover:bg-gm-bg-card/30"
+                                                }
+                                            )
+                                        >
+                                            <input
+                                                type="radio"
+                                                name="challenge_difficulty"
+                                                checked=is_selected
+                                                on:change=move |_| update_difficulty(difficulty)
+                                                class="w-4 h-4 text-gm-accent-cyan bg-gm-bg-card border-gm-accent-cyan/50 focus:ring-gm-accent-cyan focus:ring-2"
+                                            />
+                                            <div>
+                                                <span class="text-white block">{difficulty.label()}</span>
+                                                <span class="text-sm text-dt-text-sub">
+                                                    {difficulty.description()}
+                                                </span>
+                                            </div>
+                                        </label>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </div>
+
+                            // Hint text
+                            <div class="text-xs text-dt-text-sub p-3 bg-gm-bg-card/30 rounded-lg">
+                                "※ 目標値は直近のチャレンジの達成状況に合わせても自動で調整されます。変更は次に生成されるチャレンジから反映されます"
+                            </div>
+                        </div>
+                    }
+                }}
+            </Show>
+        </div>
+    }
+}
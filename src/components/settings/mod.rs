@@ -1,16 +1,24 @@
 pub mod account_settings;
 pub mod app_info;
 pub mod appearance_settings;
+pub mod challenge_settings;
 pub mod data_management;
 pub mod notification_settings;
+pub mod prestige_settings;
+pub mod repository_filter_settings;
 pub mod settings_reset;
 pub mod sync_settings;
+pub mod xp_safeguard_settings;
 
 pub use account_settings::AccountSettings;
 pub use app_info::AppInfoSection;
 pub use appearance_settings::AppearanceSettings;
+pub use challenge_settings::ChallengeSettings;
 pub use data_management::DataManagement;
 pub use notification_settings::NotificationSettings;
+pub use prestige_settings::PrestigeSettings;
+pub use repository_filter_settings::RepositoryFilterSettings;
 // SettingsPage is exported through pages/settings_page.rs
 pub use settings_reset::SettingsResetSection;
 pub use sync_settings::SyncSettings;
+pub use xp_safeguard_settings::XpSafeguardSettings;
@@ -66,6 +66,9 @@ pub fn NotificationSettings() -> impl IntoView {
                     current_settings.notify_streak_milestone =
                         !current_settings.notify_streak_milestone
                 }
+                "weekly_report" => {
+                    current_settings.notify_weekly_report = !current_settings.notify_weekly_report
+                }
                 _ => {}
             }
             set_settings.set(Some(current_settings));
@@ -80,6 +83,7 @@ pub fn NotificationSettings() -> impl IntoView {
             current_settings.notify_badge_earned = true;
             current_settings.notify_streak_update = true;
             current_settings.notify_streak_milestone = true;
+            current_settings.notify_weekly_report = true;
             set_settings.set(Some(current_settings));
         }
     };
@@ -92,6 +96,7 @@ pub fn NotificationSettings() -> impl IntoView {
             current_settings.notify_badge_earned = false;
             current_settings.notify_streak_update = false;
             current_settings.notify_streak_milestone = false;
+            current_settings.notify_weekly_report = false;
             set_settings.set(Some(current_settings));
         }
     };
@@ -274,6 +279,7 @@ pub fn NotificationSettings() -> impl IntoView {
                                         ("badge_earned", "バッジ獲得通知", current_settings.notify_badge_earned),
                                         ("streak_update", "ストリーク更新通知", current_settings.notify_streak_update),
                                         ("streak_milestone", "ストリークマイルストーン", current_settings.notify_streak_milestone),
+                                        ("weekly_report", "週間レポート通知（毎週月曜）", current_settings.notify_weekly_report),
                                     ].into_iter().map(move |(field, label, enabled)| {
                                         let field_str = field;
                                         let toggle_fn = toggle_notification.clone();
@@ -0,0 +1,214 @@
+//! Prestige settings component
+//!
+//! Lets users choose the level required to prestige and, once reached,
+//! reset their level in exchange for a prestige rank and a permanent XP bonus.
+
+use leptos::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::components::ConfirmDialog;
+use crate::tauri_api;
+use crate::types::{LevelInfo, UpdateSettingsRequest, UserSettings, PRESTIGE_LEVELS};
+
+/// Prestige settings component
+#[component]
+pub fn PrestigeSettings() -> impl IntoView {
+    let (settings, set_settings) = signal(Option::<UserSettings>::None);
+    let (level_info, set_level_info) = signal(Option::<LevelInfo>::None);
+    let (loading, set_loading) = signal(true);
+    let (prestiging, set_prestiging) = signal(false);
+    let (show_dialog, set_show_dialog) = signal(false);
+    let (error, set_error) = signal(None::<String>);
+    let (success_message, set_success_message) = signal(None::<String>);
+
+    // Load settings and level info on mount
+    spawn_local(async move {
+        let (settings_result, level_result) =
+            futures::join!(tauri_api::get_settings(), tauri_api::get_level_info());
+        match settings_result {
+            Ok(loaded_settings) => set_settings.set(Some(loaded_settings)),
+            Err(e) => set_error.set(Some(format!("設定の読み込みに失敗しました: {}", e))),
+        }
+        match level_result {
+            Ok(info) => set_level_info.set(info),
+            Err(e) => set_error.set(Some(format!("レベル情報の読み込みに失敗しました: {}", e))),
+        }
+        set_loading.set(false);
+    });
+
+    // Save the new prestige level right away, rolling back on failure
+    let update_prestige_level = move |prestige_level: i32| {
+        let Some(previous) = settings.get_untracked() else {
+            return;
+        };
+        let mut updated = previous.clone();
+        updated.prestige_level = prestige_level;
+        set_settings.set(Some(updated.clone()));
+        set_error.set(None);
+
+        spawn_local(async move {
+            let update_request = UpdateSettingsRequest::from(&updated);
+            if let Err(e) = tauri_api::update_settings(&update_request).await {
+                set_settings.set(Some(previous));
+                set_error.set(Some(format!("設定の保存に失敗しました: {}", e)));
+                return;
+            }
+            // can_prestige depends on the threshold, so refresh it
+            if let Ok(info) = tauri_api::get_level_info().await {
+                set_level_info.set(info);
+            }
+        });
+    };
+
+    let on_confirm = move |_: leptos::ev::MouseEvent| {
+        set_show_dialog.set(false);
+        set_prestiging.set(true);
+        set_error.set(None);
+        set_success_message.set(None);
+
+        spawn_local(async move {
+            match tauri_api::prestige().await {
+                Ok(result) => {
+                    let mut message = format!(
+                        "プレステージ {} に到達しました！",
+                        result.level_info.prestige_rank
+                    );
+                    if !result.new_badges.is_empty() {
+                        let names: Vec<String> = result
+                            .new_badges
+                            .iter()
+                            .map(|b| format!("{} {}", b.icon, b.name))
+                            .collect();
+                        message.push_str(&format!(" 新しいバッジ: {}", names.join(", ")));
+                    }
+                    set_level_info.set(Some(result.level_info));
+                    set_success_message.set(Some(message));
+                }
+                Err(e) => {
+                    set_error.set(Some(format!("プレステージに失敗しました: {}", e)));
+                }
+            }
+            set_prestiging.set(false);
+        });
+    };
+
+    view! {
+        <div class="space-y-6">
+            // Loading state
+            <Show when=move || loading.get()>
+                <div class="text-center py-8 text-dt-text-sub">
+                    "設定を読み込み中..."
+                </div>
+            </Show>
+
+            // Error message
+            <Show when=move || error.get().is_some()>
+                <div class="p-3 bg-red-900/30 border border-red-500/50 rounded-lg text-red-200 text-sm">
+                    {move || error.get().unwrap_or_default()}
+                </div>
+            </Show>
+
+            // Success message
+            <Show when=move || success_message.get().is_some()>
+                <div class="p-3 bg-green-900/20 border border-green-500/30 rounded-lg text-green-300 text-sm">
+                    {move || success_message.get().unwrap_or_default()}
+                </div>
+            </Show>
+
+            // Current prestige status
+            <Show when=move || level_info.get().is_some() && !loading.get()>
+                {move || {
+                    let info = level_info.get().unwrap();
+                    let title = info.prestige_title.clone().unwrap_or_else(|| "なし".to_string());
+                    let can_prestige = info.can_prestige;
+                    let prestige_level = info.prestige_level;
+
+                    view! {
+                        <div class="p-4 bg-gm-bg-card/50 rounded-xl border border-gm-accent-purple/30 space-y-2">
+                            <div class="flex items-center justify-between">
+                                <span class="text-white font-gaming">
+                                    "プレステージ " {info.prestige_rank}
+                                </span>
+                                <span class="text-gm-accent-purple text-sm">{title}</span>
+                            </div>
+                            <div class="text-sm text-dt-text-sub">
+                                "現在のレベル: Lv." {info.current_level}
+                                " / XPボーナス: x" {format!("{:.2}", info.xp_multiplier)}
+                            </div>
+                            <button
+                                class="w-full mt-2 px-4 py-2 rounded-lg bg-gradient-to-r from-gm-accent-cyan to-gm-accent-purple text-white font-bold transition-opacity disabled:opacity-40 disabled:cursor-not-allowed"
+                                disabled=move || {
+                                    prestiging.get()
+                                        || !level_info.get().map(|i| i.can_prestige).unwrap_or(false)
+                                }
+                                on:click=move |_| set_show_dialog.set(true)
+                            >
+                                {move || {
+                                    if prestiging.get() {
+                                        "プレステージ中...".to_string()
+                                    } else if can_prestige {
+                                        "プレステージする".to_string()
+                                    } else {
+                                        format!("Lv.{} で解放", prestige_level)
+                                    }
+                                }}
+                            </button>
+                        </div>
+                    }
+                }}
+            </Show>
+
+            // Prestige level selector
+            <Show when=move || settings.get().is_some() && !loading.get()>
+                {move || {
+                    let current = settings.get().unwrap().prestige_level;
+
+                    view! {
+                        <div class="space-y-3">
+                            <h3 class="text-lg font-gaming font-bold text-white">
+                                "プレステージ解放レベル"
+                            </h3>
+                            <div class="grid grid-cols-4 gap-2">
+                                {PRESTIGE_LEVELS.iter().copied().map(move |prestige_level| {
+                                    let is_selected = current == prestige_level;
+
+                                    view! {
+                                        <button
+                                            class=format!(
+                                                "px-3 py-2 rounded-lg text-sm font-gaming-mono transition-colors {}",
+                                                if is_selected {
+                                                    "bg-gm-accent-cyan/20 border border-gm-accent-cyan/50 text-white"
+                                                } else {
+                                                    "bg-gm-bg-card/50 border border-gm-accent-cyan/10 text-dt-text-sub hover:bg-gm-bg-card/30"
+                                                }
+                                            )
+                                            on:click=move |_| update_prestige_level(prestige_level)
+                                        >
+                                            "Lv." {prestige_level}
+                                        </button>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </div>
+
+                            // Hint text
+                            <div class="text-xs text-dt-text-sub p-3 bg-gm-bg-card/30 rounded-lg">
+                                "※ プレステージするとレベルが1に戻ります。累計XP・XP履歴・獲得済みバッジはそのまま残り、ランクごとにXPが5%ずつ恒久的に増えます"
+                            </div>
+                        </div>
+                    }
+                }}
+            </Show>
+
+            // Prestige confirmation dialog
+            <ConfirmDialog
+                title="プレステージの確認".to_string()
+                message="レベルを1に戻してプレステージランクを上げますか？累計XPと獲得済みバッジは保持されます。".to_string()
+                confirm_label="プレステージする".to_string()
+                cancel_label="キャンセル".to_string()
+                visible=show_dialog
+                on_confirm=on_confirm
+                on_cancel=move |_| set_show_dialog.set(false)
+            />
+        </div>
+    }
+}
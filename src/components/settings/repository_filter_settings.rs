@@ -0,0 +1,307 @@
+//! Repository filter settings component
+//!
+//! Lets users choose which repositories count towards XP, badges, code
+//! stats and the dashboard: a work / personal preset, include / exclude
+//! lists of repositories and owners (users or organizations), and whether
+//! forks, archived and private repositories are counted.
+
+use leptos::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::components::ui::form::ToggleSwitch;
+use crate::tauri_api;
+use crate::types::{RepoFilterPreset, RepositoryFilter};
+
+/// Parse a list textarea (one entry per line)
+fn parse_lines(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// A boolean filter switch: test id, label, description, current value,
+/// and how to flip it
+type FilterToggle = (
+    &'static str,
+    &'static str,
+    &'static str,
+    bool,
+    fn(&mut RepositoryFilter),
+);
+
+/// Which list a textarea edits
+#[derive(Clone, Copy)]
+enum FilterList {
+    WorkOwners,
+    IncludeRepositories,
+    ExcludeRepositories,
+    IncludeOwners,
+    ExcludeOwners,
+}
+
+impl FilterList {
+    const ALL: [FilterList; 5] = [
+        FilterList::WorkOwners,
+        FilterList::IncludeRepositories,
+        FilterList::ExcludeRepositories,
+        FilterList::IncludeOwners,
+        FilterList::ExcludeOwners,
+    ];
+
+    fn id(self) -> &'static str {
+        match self {
+            FilterList::WorkOwners => "repo-filter-work-owners",
+            FilterList::IncludeRepositories => "repo-filter-include-repositories",
+            FilterList::ExcludeRepositories => "repo-filter-exclude-repositories",
+            FilterList::IncludeOwners => "repo-filter-include-owners",
+            FilterList::ExcludeOwners => "repo-filter-exclude-owners",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            FilterList::WorkOwners => "仕事用のオーナー",
+            FilterList::IncludeRepositories => "対象にするリポジトリ",
+            FilterList::ExcludeRepositories => "除外するリポジトリ",
+            FilterList::IncludeOwners => "対象にするオーナー",
+            FilterList::ExcludeOwners => "除外するオーナー",
+        }
+    }
+
+    fn hint(self) -> &'static str {
+        match self {
+            FilterList::WorkOwners => {
+                "Organization やユーザー名を1行に1つ。空なら自分以外がオーナーのリポジトリを仕事用とみなします"
+            }
+            FilterList::IncludeRepositories => {
+                "owner/name を1行に1つ。対象リストを指定すると、リストにあるものだけを集計します"
+            }
+            FilterList::ExcludeRepositories => "owner/name を1行に1つ",
+            FilterList::IncludeOwners => "Organization やユーザー名を1行に1つ",
+            FilterList::ExcludeOwners => "Organization やユーザー名を1行に1つ",
+        }
+    }
+
+    fn placeholder(self) -> &'static str {
+        match self {
+            FilterList::IncludeRepositories | FilterList::ExcludeRepositories => "octocat/dotfiles",
+            _ => "my-company",
+        }
+    }
+
+    fn get(self, filter: &RepositoryFilter) -> &Vec<String> {
+        match self {
+            FilterList::WorkOwners => &filter.work_owners,
+            FilterList::IncludeRepositories => &filter.include_repositories,
+            FilterList::ExcludeRepositories => &filter.exclude_repositories,
+            FilterList::IncludeOwners => &filter.include_owners,
+            FilterList::ExcludeOwners => &filter.exclude_owners,
+        }
+    }
+
+    fn get_mut(self, filter: &mut RepositoryFilter) -> &mut Vec<String> {
+        match self {
+            FilterList::WorkOwners => &mut filter.work_owners,
+            FilterList::IncludeRepositories => &mut filter.include_repositories,
+            FilterList::ExcludeRepositories => &mut filter.exclude_repositories,
+            FilterList::IncludeOwners => &mut filter.include_owners,
+            FilterList::ExcludeOwners => &mut filter.exclude_owners,
+        }
+    }
+}
+
+/// Repository filter settings component
+#[component]
+pub fn RepositoryFilterSettings() -> impl IntoView {
+    let (filter, set_filter) = signal(Option::<RepositoryFilter>::None);
+    let (loading, set_loading) = signal(true);
+    let (error, set_error) = signal(None::<String>);
+
+    // Load the filter on mount
+    spawn_local(async move {
+        match tauri_api::get_repository_filter().await {
+            Ok(loaded) => set_filter.set(Some(loaded)),
+            Err(e) => set_error.set(Some(format!("設定の読み込みに失敗しました: {}", e))),
+        }
+        set_loading.set(false);
+    });
+
+    // Save the change right away, rolling back on failure (the backend
+    // validates and normalizes the names)
+    let save = move |update: Box<dyn FnOnce(&mut RepositoryFilter)>| {
+        let Some(previous) = filter.get_untracked() else {
+            return;
+        };
+        let mut updated = previous.clone();
+        update(&mut updated);
+        set_filter.set(Some(updated.clone()));
+        set_error.set(None);
+
+        spawn_local(async move {
+            match tauri_api::update_repository_filter(&updated).await {
+                Ok(saved) => set_filter.set(Some(saved)),
+                Err(e) => {
+                    set_filter.set(Some(previous));
+                    set_error.set(Some(format!("設定の保存に失敗しました: {}", e)));
+                }
+            }
+        });
+    };
+
+    let reset = move |_| {
+        set_error.set(None);
+        spawn_local(async move {
+            match tauri_api::reset_repository_filter().await {
+                Ok(saved) => set_filter.set(Some(saved)),
+                Err(e) => set_error.set(Some(format!("リセットに失敗しました: {}", e))),
+            }
+        });
+    };
+
+    view! {
+        <div class="space-y-6">
+            // Loading state
+            <Show when=move || loading.get()>
+                <div class="text-center py-8 text-dt-text-sub">
+                    "設定を読み込み中..."
+                </div>
+            </Show>
+
+            // Error message
+            <Show when=move || error.get().is_some()>
+                <div class="p-3 bg-red-900/30 border border-red-500/50 rounded-lg text-red-200 text-sm">
+                    {move || error.get().unwrap_or_default()}
+                </div>
+            </Show>
+
+            <Show when=move || filter.get().is_some() && !loading.get()>
+                {move || {
+                    let current = filter.get().unwrap();
+                    let current_preset = RepoFilterPreset::from_str(&current.preset);
+                    let toggles: [FilterToggle; 3] = [
+                        (
+                            "repo-filter-include-forks-label",
+                            "フォークを含める",
+                            "フォークしたリポジトリのコミットやPRも集計します",
+                            current.include_forks,
+                            |f| f.include_forks = !f.include_forks,
+                        ),
+                        (
+                            "repo-filter-include-archived-label",
+                            "アーカイブ済みを含める",
+                            "アーカイブされたリポジトリも集計します",
+                            current.include_archived,
+                            |f| f.include_archived = !f.include_archived,
+                        ),
+                        (
+                            "repo-filter-include-private-label",
+                            "プライベートを含める",
+                            "プライベートリポジトリも集計します",
+                            current.include_private,
+                            |f| f.include_private = !f.include_private,
+                        ),
+                    ];
+
+                    view! {
+                        // Preset
+                        <div class="space-y-2 p-4 bg-gm-bg-card/50 rounded-xl border border-gm-accent-cyan/20">
+                            {RepoFilterPreset::ALL.into_iter().map(move |preset| {
+                                let is_selected = current_preset == preset;
+                                view! {
+                                    <label
+                                        class=format!(
+                                            "flex items-center gap-3 p-3 rounded-lg cursor-pointer transition-colors {}",
+                                            if is_selected {
+                                                "bg-gm-accent-cyan/20 border border-gm-accent-cyan/50"
+                                            } else {
+                                                "hover:bg-gm-bg-card/30"
+                                            }
+                                        )
+                                    >
+                                        <input
+                                            type="radio"
+                                            name="repo_filter_preset"
+                                            checked=is_selected
+                                            on:change=move |_| {
+                                                save(Box::new(move |f| f.preset = preset.as_str().to_string()))
+                                            }
+                                            class="w-4 h-4 text-gm-accent-cyan bg-gm-bg-card border-gm-accent-cyan/50 focus:ring-gm-accent-cyan focus:ring-2"
+                                        />
+                                        <div>
+                                            <span class="text-white block">{preset.label()}</span>
+                                            <span class="text-sm text-dt-text-sub">{preset.description()}</span>
+                                        </div>
+                                    </label>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+
+                        // Include / exclude lists
+                        <div class="space-y-2 p-4 bg-gm-bg-card/50 rounded-xl border border-gm-accent-cyan/20">
+                            {FilterList::ALL.into_iter().map(|list| {
+                                let text = list.get(&current).join("\n");
+                                view! {
+                                    <div class="p-3 space-y-2">
+                                        <label class="text-white block font-gaming font-bold" for=list.id()>
+                                            {list.label()}
+                                        </label>
+                                        <span class="text-sm text-dt-text-sub block">{list.hint()}</span>
+                                        <textarea
+                                            id=list.id()
+                                            rows="2"
+                                            placeholder=list.placeholder()
+                                            class="w-full px-3 py-2 bg-gm-bg-primary border border-gm-accent-cyan/30 rounded-lg text-white font-mono text-sm"
+                                            prop:value=text
+                                            on:change=move |ev| {
+                                                let entries = parse_lines(&event_target_value(&ev));
+                                                save(Box::new(move |f| *list.get_mut(f) = entries));
+                                            }
+                                        />
+                                    </div>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+
+                        // Repository kinds
+                        <div class="space-y-2 p-4 bg-gm-bg-card/50 rounded-xl border border-gm-accent-cyan/20">
+                            {toggles.into_iter().map(|(label_id, title, description, enabled, toggle)| {
+                                view! {
+                                    <div class="flex items-center justify-between p-3 rounded-lg hover:bg-gm-bg-card/30 transition-colors">
+                                        <div class="flex-1">
+                                            <span class="text-white block font-gaming font-bold" id=label_id>
+                                                {title}
+                                            </span>
+                                            <span class="text-sm text-dt-text-sub mt-1 block">{description}</span>
+                                        </div>
+                                        <ToggleSwitch
+                                            enabled=enabled
+                                            on_toggle=move || save(Box::new(toggle))
+                                            label_id=label_id
+                                        />
+                                    </div>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+
+                        <div class="flex justify-end">
+                            <button
+                                type="button"
+                                class="px-4 py-2 text-sm rounded-lg border border-gm-accent-cyan/30 text-dt-text-sub hover:text-white hover:bg-gm-bg-card/50 transition-colors"
+                                on:click=reset
+                            >
+                                "フィルターをリセット"
+                            </button>
+                        </div>
+
+                        // Hint text
+                        <div class="text-xs text-dt-text-sub p-3 bg-gm-bg-card/30 rounded-lg">
+                            "※ 変更後の最初の同期では、絞り込み後の集計を新しい基準として保存するためXPは付与されません。コントリビューションカレンダーとストリークは常にすべてのリポジトリが対象です"
+                        </div>
+                    }
+                }}
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,185 @@
+//! XP safeguard settings component
+//!
+//! Lets users tune the anti-gaming safeguards applied to commit XP:
+//! diminishing returns past a daily commit cap, a minimum diff size,
+//! excluding bot / GitHub Actions commits and excluding repositories.
+
+use leptos::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::components::ui::form::ToggleSwitch;
+use crate::tauri_api;
+use crate::types::{UpdateSettingsRequest, UserSettings};
+
+/// Parse the excluded repositories textarea (one "owner/name" per line)
+fn parse_repositories(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// XP safeguard settings component
+#[component]
+pub fn XpSafeguardSettings() -> impl IntoView {
+    let (settings, set_settings) = signal(Option::<UserSettings>::None);
+    let (loading, set_loading) = signal(true);
+    let (error, set_error) = signal(None::<String>);
+
+    // Load settings on mount
+    spawn_local(async move {
+        match tauri_api::get_settings().await {
+            Ok(loaded_settings) => set_settings.set(Some(loaded_settings)),
+            Err(e) => set_error.set(Some(format!("設定の読み込みに失敗しました: {}", e))),
+        }
+        set_loading.set(false);
+    });
+
+    // Save the change right away, rolling back on failure (the backend
+    // validates the values and normalizes the repository list)
+    let save = move |update: Box<dyn FnOnce(&mut UserSettings)>| {
+        let Some(previous) = settings.get_untracked() else {
+            return;
+        };
+        let mut updated = previous.clone();
+        update(&mut updated);
+        set_settings.set(Some(updated.clone()));
+        set_error.set(None);
+
+        spawn_local(async move {
+            let update_request = UpdateSettingsRequest::from(&updated);
+            match tauri_api::update_settings(&update_request).await {
+                Ok(saved) => set_settings.set(Some(saved)),
+                Err(e) => {
+                    set_settings.set(Some(previous));
+                    set_error.set(Some(format!("設定の保存に失敗しました: {}", e)));
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="space-y-6">
+            // Loading state
+            <Show when=move || loading.get()>
+                <div class="text-center py-8 text-dt-text-sub">
+                    "設定を読み込み中..."
+                </div>
+            </Show>
+
+            // Error message
+            <Show when=move || error.get().is_some()>
+                <div class="p-3 bg-red-900/30 border border-red-500/50 rounded-lg text-red-200 text-sm">
+                    {move || error.get().unwrap_or_default()}
+                </div>
+            </Show>
+
+            <Show when=move || settings.get().is_some() && !loading.get()>
+                {move || {
+                    let current = settings.get().unwrap();
+                    let repositories_text = current.xp_excluded_repositories.join("\n");
+
+                    view! {
+                        <div class="space-y-2 p-4 bg-gm-bg-card/50 rounded-xl border border-gm-accent-cyan/20">
+                            // Daily commit cap
+                            <div class="flex items-center justify-between p-3 gap-4">
+                                <div class="flex-1">
+                                    <label class="text-white block font-gaming font-bold" for="xp-daily-commit-cap">
+                                        "1日のコミット上限"
+                                    </label>
+                                    <span class="text-sm text-dt-text-sub mt-1 block">
+                                        "上限を超えたコミットはXPが50%、2倍を超えると25%になります（0で無効）"
+                                    </span>
+                                </div>
+                                <input
+                                    id="xp-daily-commit-cap"
+                                    type="number"
+                                    min="0"
+                                    max="500"
+                                    class="w-24 px-3 py-2 bg-gm-bg-primary border border-gm-accent-cyan/30 rounded-lg text-white text-right font-gaming-mono"
+                                    prop:value=current.xp_daily_commit_cap.to_string()
+                                    on:change=move |ev| {
+                                        if let Ok(cap) = event_target_value(&ev).parse::<i32>() {
+                                            save(Box::new(move |s| s.xp_daily_commit_cap = cap));
+                                        }
+                                    }
+                                />
+                            </div>
+
+                            // Minimum diff size
+                            <div class="flex items-center justify-between p-3 gap-4">
+                                <div class="flex-1">
+                                    <label class="text-white block font-gaming font-bold" for="xp-min-commit-lines">
+                                        "最小差分行数"
+                                    </label>
+                                    <span class="text-sm text-dt-text-sub mt-1 block">
+                                        "1コミットあたりの平均変更行数がこれ未満の日はコミットXPを付与しません（0で無効）"
+                                    </span>
+                                </div>
+                                <input
+                                    id="xp-min-commit-lines"
+                                    type="number"
+                                    min="0"
+                                    max="1000"
+                                    class="w-24 px-3 py-2 bg-gm-bg-primary border border-gm-accent-cyan/30 rounded-lg text-white text-right font-gaming-mono"
+                                    prop:value=current.xp_min_commit_lines.to_string()
+                                    on:change=move |ev| {
+                                        if let Ok(lines) = event_target_value(&ev).parse::<i32>() {
+                                            save(Box::new(move |s| s.xp_min_commit_lines = lines));
+                                        }
+                                    }
+                                />
+                            </div>
+
+                            // Bot commits
+                            <div class="flex items-center justify-between p-3 rounded-lg hover:bg-gm-bg-card/30 transition-colors">
+                                <div class="flex-1">
+                                    <span class="text-white block font-gaming font-bold" id="xp-exclude-bot-commits-label">
+                                        "ボットのコミットを除外"
+                                    </span>
+                                    <span class="text-sm text-dt-text-sub mt-1 block">
+                                        "GitHub Actions やボットが作成・コミットしたものはXPの対象外にします"
+                                    </span>
+                                </div>
+                                <ToggleSwitch
+                                    enabled=current.xp_exclude_bot_commits
+                                    on_toggle=move || {
+                                        save(Box::new(|s| s.xp_exclude_bot_commits = !s.xp_exclude_bot_commits))
+                                    }
+                                    label_id="xp-exclude-bot-commits-label"
+                                />
+                            </div>
+
+                            // Excluded repositories
+                            <div class="p-3 space-y-2">
+                                <label class="text-white block font-gaming font-bold" for="xp-excluded-repositories">
+                                    "除外リポジトリ"
+                                </label>
+                                <span class="text-sm text-dt-text-sub block">
+                                    "1行に1つ owner/name の形式で入力します"
+                                </span>
+                                <textarea
+                                    id="xp-excluded-repositories"
+                                    rows="3"
+                                    placeholder="octocat/dotfiles"
+                                    class="w-full px-3 py-2 bg-gm-bg-primary border border-gm-accent-cyan/30 rounded-lg text-white font-mono text-sm"
+                                    prop:value=repositories_text
+                                    on:change=move |ev| {
+                                        let repositories = parse_repositories(&event_target_value(&ev));
+                                        save(Box::new(move |s| s.xp_excluded_repositories = repositories));
+                                    }
+                                />
+                            </div>
+                        </div>
+
+                        // Hint text
+                        <div class="text-xs text-dt-text-sub p-3 bg-gm-bg-card/30 rounded-lg">
+                            "※ 差し引かれたXPは「XP調整」としてXP履歴に理由付きで記録されます"
+                        </div>
+                    }
+                }}
+            </Show>
+        </div>
+    }
+}
@@ -17,20 +17,51 @@ import type {
   DeviceTokenStatus,
   UserSettings,
   UpdateSettingsRequest,
+  RepositoryFilter,
+  RepoFilterPreset,
   DatabaseInfo,
   ClearCacheResult,
   AppInfo,
   SyncIntervalOption,
   Project,
+  ProjectRepository,
   ProjectWithStats,
   RepositoryInfo,
   CachedIssue,
+  BulkIssueTarget,
+  BulkIssueAction,
+  BulkIssueResult,
   KanbanBoard,
   MyOpenWork,
   PrProgress,
   SyncAllProjectsResult,
   SyncProjectIssuesResponse,
+  OutboxOperation,
+  OutboxReplayResult,
+  IssueSearchResult,
+  UpdateIssueFieldsRequest,
+  IssueEditOptions,
+  IssueComment,
+  IssueCommentsPage,
+  ProjectMilestone,
+  MilestoneBurndown,
+  TimeEntry,
+  IssueTimeLog,
+  TimeSummary,
+  IssueTemplate,
+  IssueTemplateSet,
+  IssueDraft,
+  WorkflowSettings,
+  WorkflowDrift,
   LevelInfo,
+  PrestigeResult,
+  PeriodComparison,
+  PeriodComparisonRequest,
+  ReportFormat,
+  ReportPeriod,
+  SummaryReport,
+  SeasonArchive,
+  SeasonInfo,
   Badge,
   BadgeDefinition,
   XpHistoryEntry,
@@ -38,6 +69,7 @@ import type {
   ChallengeInfo,
   CreateChallengeRequest,
   ChallengeStats,
+  ChallengeTokens,
   GitHubUser,
   GitHubStats,
   UserStats,
@@ -45,10 +77,15 @@ import type {
   CodeStatsSyncResult,
   CodeStatsResponse,
   LanguageBreakdownResponse,
+  RepositoryDetail,
+  RepositoryDetailDays,
   RateLimitInfo,
   RateLimitDetailed,
   TodayCommitsSummary,
   ContributionCalendar,
+  ContributionHistory,
+  ActivityHeatmap,
+  HeatmapRequest,
   BadgeWithProgress,
   CachedResponse,
   CacheStats,
@@ -134,6 +171,30 @@ export const settings = {
   reset: (): Promise<UserSettings> =>
     invoke<UserSettings>('reset_settings'),
 
+  /**
+   * Get the repository / organization filter (defaults until saved)
+   */
+  getRepositoryFilter: (): Promise<RepositoryFilter> =>
+    invoke<RepositoryFilter>('get_repository_filter'),
+
+  /**
+   * Validate and save the repository / organization filter
+   */
+  updateRepositoryFilter: (filter: RepositoryFilter): Promise<RepositoryFilter> =>
+    invoke<RepositoryFilter>('update_repository_filter', { filter }),
+
+  /**
+   * Switch the repository filter preset, keeping the rest of the filter
+   */
+  setRepositoryFilterPreset: (preset: RepoFilterPreset): Promise<RepositoryFilter> =>
+    invoke<RepositoryFilter>('set_repository_filter_preset', { preset }),
+
+  /**
+   * Go back to counting every repository
+   */
+  resetRepositoryFilter: (): Promise<RepositoryFilter> =>
+    invoke<RepositoryFilter>('reset_repository_filter'),
+
   /**
    * Clear cache
    */
@@ -236,20 +297,37 @@ export const repositories = {
     invoke<RepositoryInfo[]>('get_user_repositories'),
 
   /**
-   * Link a repository to a project
+   * Link a repository to a project. Adds it next to the repositories
+   * already linked; the first one linked stays the primary.
    */
   link: (project_id: number, owner: string, repo: string): Promise<Project> =>
     invoke<Project>('link_repository', { project_id, owner, repo }),
 
   /**
-   * Re-link an archived project to a (possibly different) repository.
+   * Link a (possibly different) repository in place of an archived one.
    *
-   * Functionally equivalent to `link` — the dedicated verb makes the user
-   * intent explicit in logs / analytics and lets the backend evolve the
-   * archive cleanup later without changing the UI surface. Issue #190.
+   * Links `owner/repo`, then unlinks `replaces` (an `owner/repo` name)
+   * when given. Issue #190.
+   */
+  relink: (
+    project_id: number,
+    owner: string,
+    repo: string,
+    replaces?: string | null,
+  ): Promise<Project> =>
+    invoke<Project>('relink_repository', { project_id, owner, repo, replaces }),
+
+  /**
+   * Remove a repository and its cached issues from a project
    */
-  relink: (project_id: number, owner: string, repo: string): Promise<Project> =>
-    invoke<Project>('relink_repository', { project_id, owner, repo }),
+  unlink: (project_id: number, repo_full_name: string): Promise<Project> =>
+    invoke<Project>('unlink_repository', { project_id, repo_full_name }),
+
+  /**
+   * Repositories linked to a project, primary first
+   */
+  getProjectRepositories: (project_id: number): Promise<ProjectRepository[]> =>
+    invoke<ProjectRepository[]>('get_project_repositories', { project_id }),
 };
 
 export const issues = {
@@ -259,6 +337,40 @@ export const issues = {
   setupGitHubActions: (project_id: number): Promise<string> =>
     invoke<string>('setup_github_actions', { project_id }),
 
+  /**
+   * Get a project's Actions workflow settings (defaults until saved)
+   */
+  getWorkflowSettings: (project_id: number): Promise<WorkflowSettings> =>
+    invoke<WorkflowSettings>('get_workflow_settings', { project_id }),
+
+  /**
+   * Validate and save a project's Actions workflow settings
+   */
+  updateWorkflowSettings: (
+    project_id: number,
+    settings: WorkflowSettings
+  ): Promise<WorkflowSettings> =>
+    invoke<WorkflowSettings>('update_workflow_settings', { project_id, settings }),
+
+  /**
+   * Restore a project's default Actions workflow settings
+   */
+  resetWorkflowSettings: (project_id: number): Promise<WorkflowSettings> =>
+    invoke<WorkflowSettings>('reset_workflow_settings', { project_id }),
+
+  /**
+   * Render the workflow YAML for unsaved settings (live preview)
+   */
+  previewWorkflow: (settings: WorkflowSettings): Promise<string> =>
+    invoke<string>('preview_workflow', { settings }),
+
+  /**
+   * Compare the workflow committed to a linked repository with the
+   * one the project's settings generate
+   */
+  checkWorkflowDrift: (project_id: number, repo_full_name?: string | null): Promise<WorkflowDrift> =>
+    invoke<WorkflowDrift>('check_workflow_drift', { project_id, repo_full_name }),
+
   /**
    * Sync issues from GitHub to local cache.
    *
@@ -288,19 +400,50 @@ export const issues = {
     invoke<CachedIssue[]>('get_project_issues', { project_id, status }),
 
   /**
-   * Get issues as kanban board
+   * Get issues as kanban board, optionally limited to one milestone
+   * (`0` = issues without a milestone) and / or one linked repository
    */
-  getKanbanBoard: (project_id: number): Promise<KanbanBoard> =>
-    invoke<KanbanBoard>('get_kanban_board', { project_id }),
+  getKanbanBoard: (
+    project_id: number,
+    milestone_number?: number | null,
+    repo_full_name?: string | null,
+  ): Promise<KanbanBoard> =>
+    invoke<KanbanBoard>('get_kanban_board', { project_id, milestone_number, repo_full_name }),
+
+  /**
+   * Update issue status (also updates on GitHub). `repo_full_name` is the
+   * issue's repository; omit it for the project's primary one. The same
+   * applies to the other per-issue calls below.
+   */
+  updateStatus: (
+    project_id: number,
+    issue_number: number,
+    new_status: string,
+    repo_full_name?: string | null,
+  ): Promise<CachedIssue> =>
+    invoke<CachedIssue>('update_issue_status', {
+      project_id,
+      issue_number,
+      new_status,
+      repo_full_name,
+    }),
 
   /**
-   * Update issue status (also updates on GitHub)
+   * Apply one action (move, add / remove labels, set assignees, close) to
+   * many issues. Issues that could not be updated are listed in `failed`;
+   * the rest stay applied.
    */
-  updateStatus: (project_id: number, issue_number: number, new_status: string): Promise<CachedIssue> =>
-    invoke<CachedIssue>('update_issue_status', { project_id, issue_number, new_status }),
+  bulkUpdate: (
+    project_id: number,
+    targets: BulkIssueTarget[],
+    action: BulkIssueAction,
+  ): Promise<BulkIssueResult> =>
+    invoke<BulkIssueResult>('bulk_update_issues', { project_id, targets, action }),
 
   /**
-   * Create a new issue (on GitHub and cache locally)
+   * Create a new issue (on GitHub and cache locally). `labels` /
+   * `assignees` are applied in addition to the status / priority labels
+   * (e.g. from an issue template).
    */
   create: (
     project_id: number,
@@ -308,8 +451,213 @@ export const issues = {
     body?: string | null,
     status?: string | null,
     priority?: string | null,
+    repo_full_name?: string | null,
+    labels?: string[] | null,
+    assignees?: string[] | null,
+  ): Promise<CachedIssue> =>
+    invoke<CachedIssue>('create_github_issue', {
+      project_id,
+      title,
+      body,
+      status,
+      priority,
+      repo_full_name,
+      labels,
+      assignees,
+    }),
+
+  /**
+   * Issue templates / issue forms of a linked repository
+   * (`.github/ISSUE_TEMPLATE`). Omit `repo_full_name` for the primary one.
+   */
+  getTemplates: (project_id: number, repo_full_name?: string | null): Promise<IssueTemplateSet> =>
+    invoke<IssueTemplateSet>('get_issue_templates', { project_id, repo_full_name }),
+
+  /**
+   * Validate the entered values and render the issue body of a template.
+   * `values` maps field keys to entered values; Markdown templates use `body`.
+   */
+  buildFromTemplate: (
+    template: IssueTemplate,
+    title: string,
+    values: Record<string, string[]>,
+  ): Promise<IssueDraft> =>
+    invoke<IssueDraft>('build_issue_from_template', { template, title, values }),
+
+  /**
+   * List queued offline issue mutations (pending / failed / conflict).
+   * Omit `project_id` to list every project.
+   */
+  getOutbox: (project_id?: number | null): Promise<OutboxOperation[]> =>
+    invoke<OutboxOperation[]>('get_issue_outbox', { project_id }),
+
+  /**
+   * Push queued offline mutations to GitHub. Call when the network comes
+   * back online; stops early if GitHub is still unreachable.
+   */
+  replayOutbox: (project_id?: number | null): Promise<OutboxReplayResult> =>
+    invoke<OutboxReplayResult>('replay_issue_outbox', { project_id }),
+
+  /**
+   * Move a failed / conflicting operation back to pending. `force`
+   * resolves a conflict in favour of the local change.
+   */
+  retryOutboxOperation: (operation_id: number, force: boolean): Promise<OutboxOperation> =>
+    invoke<OutboxOperation>('retry_outbox_operation', { operation_id, force }),
+
+  /**
+   * Drop a queued operation and roll back its optimistic cache change.
+   */
+  discardOutboxOperation: (operation_id: number): Promise<void> =>
+    invoke<void>('discard_outbox_operation', { operation_id }),
+
+  /**
+   * Full-text search over the local issue cache of every project. Works
+   * offline. Accepts `status:`, `priority:`, `label:`, `assignee:` and
+   * `repo:` filters alongside free text.
+   */
+  searchLocal: (query: string, limit?: number | null): Promise<IssueSearchResult[]> =>
+    invoke<IssueSearchResult[]>('search_issues_local', { query, limit }),
+
+  /**
+   * Edit title, body, assignees, milestone and/or labels (on GitHub and
+   * in the cache). Fails while offline or while an outbox change is pending.
+   */
+  updateFields: (
+    project_id: number,
+    issue_number: number,
+    update: UpdateIssueFieldsRequest,
+    repo_full_name?: string | null,
+  ): Promise<CachedIssue> =>
+    invoke<CachedIssue>('update_issue_fields', { project_id, issue_number, update, repo_full_name }),
+
+  /**
+   * Close an issue as `completed` (→ Done) or `not_planned` (→ Cancelled)
+   */
+  close: (
+    project_id: number,
+    issue_number: number,
+    reason: 'completed' | 'not_planned',
+    repo_full_name?: string | null,
+  ): Promise<CachedIssue> =>
+    invoke<CachedIssue>('close_issue', { project_id, issue_number, reason, repo_full_name }),
+
+  /**
+   * Reopen a closed issue into `status` (default: backlog)
+   */
+  reopen: (
+    project_id: number,
+    issue_number: number,
+    status?: string | null,
+    repo_full_name?: string | null,
   ): Promise<CachedIssue> =>
-    invoke<CachedIssue>('create_github_issue', { project_id, title, body, status, priority }),
+    invoke<CachedIssue>('reopen_issue', { project_id, issue_number, status, repo_full_name }),
+
+  /**
+   * Labels, assignable users and open milestones of the issue's
+   * repository, for the edit form
+   */
+  getEditOptions: (project_id: number, repo_full_name?: string | null): Promise<IssueEditOptions> =>
+    invoke<IssueEditOptions>('get_issue_edit_options', { project_id, repo_full_name }),
+
+  /**
+   * One page (1-based) of an issue's comments, oldest first. Served from a
+   * 10-minute local cache, or from a stale copy when GitHub is unreachable
+   * (`fromCache: true`).
+   */
+  getComments: (
+    project_id: number,
+    issue_number: number,
+    page?: number | null,
+    force_refresh?: boolean | null,
+    repo_full_name?: string | null,
+  ): Promise<CachedResponse<IssueCommentsPage>> =>
+    invoke<CachedResponse<IssueCommentsPage>>('get_issue_comments', {
+      project_id,
+      issue_number,
+      page,
+      force_refresh,
+      repo_full_name,
+    }),
+
+  /**
+   * Post a Markdown comment on an issue
+   */
+  createComment: (
+    project_id: number,
+    issue_number: number,
+    body: string,
+    repo_full_name?: string | null,
+  ): Promise<IssueComment> =>
+    invoke<IssueComment>('create_issue_comment', { project_id, issue_number, body, repo_full_name }),
+
+  /**
+   * List the project's synced milestones (open first, by due date)
+   */
+  getMilestones: (project_id: number): Promise<ProjectMilestone[]> =>
+    invoke<ProjectMilestone[]>('get_project_milestones', { project_id }),
+
+  /**
+   * Daily burndown / burnup series of a milestone
+   */
+  getMilestoneBurndown: (project_id: number, milestone_number: number): Promise<MilestoneBurndown> =>
+    invoke<MilestoneBurndown>('get_milestone_burndown', { project_id, milestone_number }),
+
+  /**
+   * Start tracking time on an issue. Any other running timer is stopped
+   * first; starting the issue that already runs returns its entry.
+   */
+  startTimer: (
+    project_id: number,
+    issue_number: number,
+    repo_full_name?: string | null,
+  ): Promise<TimeEntry> =>
+    invoke<TimeEntry>('start_issue_timer', { project_id, issue_number, repo_full_name }),
+
+  /**
+   * Stop the running timer (null when none was running)
+   */
+  stopTimer: (note?: string | null): Promise<TimeEntry | null> =>
+    invoke<TimeEntry | null>('stop_issue_timer', { note }),
+
+  /**
+   * The currently running timer, if any
+   */
+  getRunningTimer: (): Promise<TimeEntry | null> =>
+    invoke<TimeEntry | null>('get_running_timer'),
+
+  /**
+   * Time entries logged on an issue (newest first)
+   */
+  getTimeEntries: (
+    project_id: number,
+    issue_number: number,
+    repo_full_name?: string | null,
+  ): Promise<IssueTimeLog> =>
+    invoke<IssueTimeLog>('get_issue_time_entries', { project_id, issue_number, repo_full_name }),
+
+  /**
+   * Delete a logged time entry
+   */
+  deleteTimeEntry: (entry_id: number): Promise<void> =>
+    invoke<void>('delete_time_entry', { entry_id }),
+
+  /**
+   * Tracked time per issue, project and day over the last `days` days
+   * (default 14). Omit `project_id` for all projects.
+   */
+  getTimeSummary: (project_id?: number | null, days?: number | null): Promise<TimeSummary> =>
+    invoke<TimeSummary>('get_time_summary', { project_id, days }),
+
+  /**
+   * Post the issue's tracked time as a summary comment on GitHub
+   */
+  postTimeSummary: (
+    project_id: number,
+    issue_number: number,
+    repo_full_name?: string | null,
+  ): Promise<IssueComment> =>
+    invoke<IssueComment>('post_issue_time_summary', { project_id, issue_number, repo_full_name }),
 
   /**
    * Cross-repository "Today / Inbox": Open Issues assigned to the current
@@ -346,6 +694,43 @@ export const gamification = {
   getLevelInfo: (): Promise<LevelInfo | null> =>
     invoke<LevelInfo | null>('get_level_info'),
 
+  /**
+   * Reset the level to 1 and raise the prestige rank (keeps XP history and badges)
+   */
+  prestige: (): Promise<PrestigeResult> => invoke<PrestigeResult>('prestige'),
+
+  /**
+   * Get the current season (archives the previous season if it has ended)
+   */
+  getSeasonInfo: (): Promise<SeasonInfo> => invoke<SeasonInfo>('get_season_info'),
+
+  /**
+   * Get archived seasons, most recent first
+   */
+  getSeasonHistory: (limit?: number | null): Promise<SeasonArchive[]> =>
+    invoke<SeasonArchive[]>('get_season_history', { limit }),
+
+  /**
+   * Compare two periods (this week vs last week, this month vs last month /
+   * the same month last year, or custom ranges) from local snapshots,
+   * code stats and XP history
+   */
+  getPeriodComparison: (request: PeriodComparisonRequest): Promise<PeriodComparison> =>
+    invoke<PeriodComparison>('get_period_comparison', { request }),
+
+  /**
+   * Get the summary report of the last complete week or month
+   */
+  getSummaryReport: (period: ReportPeriod = 'week'): Promise<SummaryReport> =>
+    invoke<SummaryReport>('get_summary_report', { period }),
+
+  /**
+   * Save the summary report as Markdown or HTML via a save dialog.
+   * Resolves to the saved path, or null when the dialog was cancelled.
+   */
+  exportSummaryReport: (period: ReportPeriod, format: ReportFormat): Promise<string | null> =>
+    invoke<string | null>('export_summary_report', { period, format }),
+
   /**
    * Add XP to current user (for testing/admin purposes)
    */
@@ -433,6 +818,24 @@ export const challenges = {
    */
   getStats: (): Promise<ChallengeStats> =>
     invoke<ChallengeStats>('get_challenge_stats'),
+
+  /**
+   * Get the reroll / skip token balance
+   */
+  getTokens: (): Promise<ChallengeTokens> =>
+    invoke<ChallengeTokens>('get_challenge_tokens'),
+
+  /**
+   * Swap an active daily/weekly challenge for another, spending a reroll token
+   */
+  reroll: (challenge_id: number): Promise<ChallengeInfo> =>
+    invoke<ChallengeInfo>('reroll_challenge', { challenge_id }),
+
+  /**
+   * Retire an active daily/weekly challenge without failing it, spending a skip token
+   */
+  skip: (challenge_id: number): Promise<ChallengeInfo> =>
+    invoke<ChallengeInfo>('skip_challenge', { challenge_id }),
 };
 
 // ============================================================================
@@ -470,6 +873,30 @@ export const github = {
   getContributionCalendar: (): Promise<ContributionCalendar> =>
     invoke<ContributionCalendar>('get_contribution_calendar'),
 
+  /**
+   * Get stored per-year and lifetime contribution totals
+   */
+  getContributionHistory: (): Promise<ContributionHistory> =>
+    invoke<ContributionHistory>('get_contribution_history'),
+
+  /**
+   * Get the contribution calendar of one year from the stored history
+   */
+  getContributionCalendarForYear: (year: number): Promise<ContributionCalendar> =>
+    invoke<ContributionCalendar>('get_contribution_calendar_for_year', { year }),
+
+  /**
+   * Fetch and store past years that have not been backfilled yet
+   */
+  backfillContributionHistory: (): Promise<ContributionHistory> =>
+    invoke<ContributionHistory>('backfill_contribution_history'),
+
+  /**
+   * Get a heatmap of contributions, XP, lines changed or commits
+   */
+  getActivityHeatmap: (request: HeatmapRequest): Promise<ActivityHeatmap> =>
+    invoke<ActivityHeatmap>('get_activity_heatmap', { request }),
+
   /**
    * Get badges with progress information
    */
@@ -553,6 +980,21 @@ export const github = {
    */
   getLanguageBreakdownWithCache: (): Promise<CachedResponse<LanguageBreakdownResponse>> =>
     invoke<CachedResponse<LanguageBreakdownResponse>>('get_language_breakdown_with_cache'),
+
+  /**
+   * 1 リポジトリのドリルダウン
+   *
+   * 週ごとのコミット、追加・削除、PR・レビュー・Issue、言語、自分の
+   * コミットの割合を返す。リポジトリと期間ごとに 1 時間キャッシュされる。
+   */
+  getRepositoryDetailWithCache: (
+    nameWithOwner: string,
+    days?: RepositoryDetailDays
+  ): Promise<CachedResponse<RepositoryDetail>> =>
+    invoke<CachedResponse<RepositoryDetail>>('get_repository_detail_with_cache', {
+      name_with_owner: nameWithOwner,
+      days: days ?? null,
+    }),
 };
 
 // ============================================================================
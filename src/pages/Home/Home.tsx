@@ -16,7 +16,13 @@
 import { useCallback, useEffect, useState } from 'react';
 import { LoginCard } from '../../components/features/auth';
 import { ActivityTimeline } from '../../components/features/activity';
-import { DashboardContent, XpNotification } from '../../components/features/gamification';
+import {
+  DashboardContent,
+  PeriodComparisonPanel,
+  RepoFilterSwitcher,
+  SummaryReportPanel,
+  XpNotification,
+} from '../../components/features/gamification';
 import { useAuth } from '../../stores/authStore';
 import { useCachedFetch } from '../../hooks/useCachedFetch';
 import { gamification, github } from '../../lib/tauri/commands';
@@ -188,6 +194,7 @@ export const Home = () => {
             cachedAt={bannerCachedAt}
             onRetry={handleRetry}
           />
+          <RepoFilterSwitcher onChange={handleRetry} />
           <DashboardContent
             levelInfo={levelInfo}
             userStats={userStatsQuery.data}
@@ -200,6 +207,12 @@ export const Home = () => {
             languageBreakdownError={languageBreakdownQuery.error}
             languageBreakdownFromCache={languageBreakdownQuery.fromCache}
           />
+          <div className="mt-6">
+            <PeriodComparisonPanel />
+          </div>
+          <div className="mt-6">
+            <SummaryReportPanel />
+          </div>
           <div className="mt-6">
             <ActivityTimeline
               items={activityQuery.data?.items ?? null}
@@ -16,13 +16,27 @@ import {
   NotificationSettings,
   SyncSettings,
   AppearanceSettings,
+  ChallengeSettings,
+  PrestigeSettings,
   DataManagement,
   AppInfo,
   SettingsReset,
   XpRecalculation,
+  XpSafeguardSettings,
+  RepositoryFilterSettings,
 } from '../../components/features/settings';
 
-type SettingsSection = 'Account' | 'Notification' | 'Sync' | 'Appearance' | 'DataManagement' | 'AppInfo';
+type SettingsSection =
+  | 'Account'
+  | 'Notification'
+  | 'Sync'
+  | 'Appearance'
+  | 'Challenge'
+  | 'Prestige'
+  | 'XpSafeguard'
+  | 'RepositoryFilter'
+  | 'DataManagement'
+  | 'AppInfo';
 
 export const Settings = () => {
   const [expandedSections, setExpandedSections] = useState<Set<SettingsSection>>(
@@ -95,6 +109,50 @@ export const Settings = () => {
           <AppearanceSettings />
         </AccordionSection>
 
+        {/* Challenge Settings Section */}
+        <AccordionSection
+          title="チャレンジ設定"
+          icon="trophy"
+          expanded={isExpanded('Challenge')}
+          onToggle={() => toggleSection('Challenge')}
+          maxHeight="600px"
+        >
+          <ChallengeSettings />
+        </AccordionSection>
+
+        {/* Prestige Section */}
+        <AccordionSection
+          title="プレステージ"
+          icon="star"
+          expanded={isExpanded('Prestige')}
+          onToggle={() => toggleSection('Prestige')}
+          maxHeight="600px"
+        >
+          <PrestigeSettings />
+        </AccordionSection>
+
+        {/* XP Safeguard Section */}
+        <AccordionSection
+          title="XPセーフガード"
+          icon="shield"
+          expanded={isExpanded('XpSafeguard')}
+          onToggle={() => toggleSection('XpSafeguard')}
+          maxHeight="800px"
+        >
+          <XpSafeguardSettings />
+        </AccordionSection>
+
+        {/* Repository Filter Section */}
+        <AccordionSection
+          title="リポジトリフィルター"
+          icon="git-branch"
+          expanded={isExpanded('RepositoryFilter')}
+          onToggle={() => toggleSection('RepositoryFilter')}
+          maxHeight="1400px"
+        >
+          <RepositoryFilterSettings />
+        </AccordionSection>
+
         {/* Data Management Section */}
         <AccordionSection
           title="データ管理"
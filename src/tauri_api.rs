@@ -1,9 +1,12 @@
+use std::collections::HashMap;
+
 use wasm_bindgen::prelude::*;
 
 use crate::types::{
     AppInfo, AuthState, Badge, BadgeDefinition, BadgeWithProgress, ClearCacheResult, DatabaseInfo,
-    DeviceCodeResponse, DeviceTokenStatus, GitHubStats, GitHubUser, LevelInfo, SyncIntervalOption,
-    SyncResult, UpdateSettingsRequest, UserSettings, UserStats, XpGainedEvent, XpHistoryEntry,
+    DeviceCodeResponse, DeviceTokenStatus, GitHubStats, GitHubUser, LevelInfo, PrestigeResult,
+    RepositoryFilter, SeasonArchive, SeasonInfo, SyncIntervalOption, SyncResult,
+    UpdateSettingsRequest, UserSettings, UserStats, XpGainedEvent, XpHistoryEntry,
 };
 
 #[wasm_bindgen]
@@ -272,6 +275,37 @@ pub async fn get_level_info() -> Result<Option<LevelInfo>, String> {
     serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to get level info: {:?}", e))
 }
 
+/// プレステージ（レベルを1に戻してプレステージランクを上げる）
+pub async fn prestige() -> Result<PrestigeResult, String> {
+    let args = serde_wasm_bindgen::to_value(&()).unwrap();
+    let result = invoke("prestige", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to prestige: {:?}", e))
+}
+
+/// 現在のシーズン情報を取得（終了したシーズンはアーカイブされる）
+pub async fn get_season_info() -> Result<SeasonInfo, String> {
+    let args = serde_wasm_bindgen::to_value(&()).unwrap();
+    let result = invoke("get_season_info", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to get season info: {:?}", e))
+}
+
+/// シーズン履歴を取得
+pub async fn get_season_history(limit: Option<i32>) -> Result<Vec<SeasonArchive>, String> {
+    #[derive(serde::Serialize)]
+    struct Args {
+        limit: Option<i32>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { limit }).unwrap();
+    let result = invoke("get_season_history", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to get season history: {:?}", e))
+}
+
 /// バッジ一覧を取得
 pub async fn get_badges() -> Result<Vec<Badge>, String> {
     let args = serde_wasm_bindgen::to_value(&()).unwrap();
@@ -426,6 +460,54 @@ pub async fn reset_settings() -> Result<UserSettings, String> {
     serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to reset settings: {:?}", e))
 }
 
+/// リポジトリフィルターを取得（未保存ならデフォルト）
+pub async fn get_repository_filter() -> Result<RepositoryFilter, String> {
+    let args = serde_wasm_bindgen::to_value(&()).unwrap();
+    let result = invoke("get_repository_filter", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to get repository filter: {:?}", e))
+}
+
+/// リポジトリフィルターを保存
+pub async fn update_repository_filter(
+    filter: &RepositoryFilter,
+) -> Result<RepositoryFilter, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        filter: &'a RepositoryFilter,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { filter }).unwrap();
+    let result = invoke("update_repository_filter", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to update repository filter: {:?}", e))
+}
+
+/// リポジトリフィルターのプリセットを切り替え
+pub async fn set_repository_filter_preset(preset: &str) -> Result<RepositoryFilter, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        preset: &'a str,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { preset }).unwrap();
+    let result = invoke("set_repository_filter_preset", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to set repository filter preset: {:?}", e))
+}
+
+/// リポジトリフィルターをリセット
+pub async fn reset_repository_filter() -> Result<RepositoryFilter, String> {
+    let args = serde_wasm_bindgen::to_value(&()).unwrap();
+    let result = invoke("reset_repository_filter", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to reset repository filter: {:?}", e))
+}
+
 /// キャッシュをクリア
 pub async fn clear_cache() -> Result<ClearCacheResult, String> {
     let args = serde_wasm_bindgen::to_value(&()).unwrap();
@@ -497,7 +579,7 @@ pub async fn open_external_url(url: &str) -> Result<(), String> {
 // チャレンジ関連のAPI
 // ============================================
 
-use crate::types::{ChallengeInfo, ChallengeStats, CreateChallengeRequest};
+use crate::types::{ChallengeInfo, ChallengeStats, ChallengeTokens, CreateChallengeRequest};
 
 /// アクティブなチャレンジを取得
 pub async fn get_active_challenges() -> Result<Vec<ChallengeInfo>, String> {
@@ -598,12 +680,55 @@ pub async fn get_challenge_stats() -> Result<ChallengeStats, String> {
         .map_err(|e| format!("Failed to get challenge stats: {:?}", e))
 }
 
+/// リロール / スキップトークンの残数を取得
+pub async fn get_challenge_tokens() -> Result<ChallengeTokens, String> {
+    let args = serde_wasm_bindgen::to_value(&()).unwrap();
+    let result = invoke("get_challenge_tokens", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to get challenge tokens: {:?}", e))
+}
+
+/// リロールトークンを使ってチャレンジを入れ替え
+pub async fn reroll_challenge(challenge_id: i64) -> Result<ChallengeInfo, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        challenge_id: i64,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { challenge_id }).unwrap();
+    let result = invoke("reroll_challenge", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to reroll challenge: {:?}", e))
+}
+
+/// スキップトークンを使ってチャレンジを取り下げ
+pub async fn skip_challenge(challenge_id: i64) -> Result<ChallengeInfo, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        challenge_id: i64,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { challenge_id }).unwrap();
+    let result = invoke("skip_challenge", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to skip challenge: {:?}", e))
+}
+
 // =============================================================================
 // Issue Management API
 // =============================================================================
 
 use crate::types::issue::{
-    CachedIssue, KanbanBoard, Project, RepositoryInfo, SyncProjectIssuesResponse,
+    BulkIssueAction, BulkIssueResult, BulkIssueTarget, CachedIssue, IssueComment,
+    IssueCommentsPage, IssueDraft, IssueEditOptions, IssueSearchResult, IssueTemplate,
+    IssueTemplateSet, IssueTimeLog, KanbanBoard, MilestoneBurndown, OutboxOperation,
+    OutboxReplayResult, Project, ProjectMilestone, ProjectRepository, RepositoryInfo,
+    SyncProjectIssuesResponse, TimeEntry, TimeSummary, UpdateIssueFieldsRequest, WorkflowDrift,
+    WorkflowSettings,
 };
 
 /// Get all projects for current user
@@ -696,7 +821,7 @@ pub async fn get_user_repositories() -> Result<Vec<RepositoryInfo>, String> {
         .map_err(|e| format!("Failed to get repositories: {:?}", e))
 }
 
-/// Link a repository to a project
+/// Link a repository to a project (added next to the ones already linked)
 pub async fn link_repository(project_id: i64, owner: &str, repo: &str) -> Result<Project, String> {
     #[derive(serde::Serialize)]
     #[serde(rename_all = "camelCase")]
@@ -718,8 +843,43 @@ pub async fn link_repository(project_id: i64, owner: &str, repo: &str) -> Result
         .map_err(|e| format!("Failed to link repository: {:?}", e))
 }
 
-/// Setup GitHub Actions for a project
-pub async fn setup_github_actions(project_id: i64) -> Result<String, String> {
+/// Remove a repository (and its cached issues) from a project
+pub async fn unlink_repository(project_id: i64, repo_full_name: &str) -> Result<Project, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        repo_full_name: &'a str,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        repo_full_name,
+    })
+    .unwrap();
+    let result = invoke("unlink_repository", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to unlink repository: {:?}", e))
+}
+
+/// Get the repositories linked to a project, primary first
+pub async fn get_project_repositories(project_id: i64) -> Result<Vec<ProjectRepository>, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        project_id: i64,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { project_id }).unwrap();
+    let result = invoke("get_project_repositories", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to get repositories: {:?}", e))
+}
+
+/// Get a project's Actions workflow settings (defaults until saved)
+pub async fn get_workflow_settings(project_id: i64) -> Result<WorkflowSettings, String> {
     #[derive(serde::Serialize)]
     #[serde(rename_all = "camelCase")]
     struct Args {
@@ -727,10 +887,85 @@ pub async fn setup_github_actions(project_id: i64) -> Result<String, String> {
     }
 
     let args = serde_wasm_bindgen::to_value(&Args { project_id }).unwrap();
-    let result = invoke("setup_github_actions", args).await;
+    let result = invoke("get_workflow_settings", args).await;
 
     serde_wasm_bindgen::from_value(result)
-        .map_err(|e| format!("Failed to setup GitHub Actions: {:?}", e))
+        .map_err(|e| format!("Failed to get workflow settings: {:?}", e))
+}
+
+/// Save a project's Actions workflow settings
+pub async fn update_workflow_settings(
+    project_id: i64,
+    settings: &WorkflowSettings,
+) -> Result<WorkflowSettings, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        settings: &'a WorkflowSettings,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        settings,
+    })
+    .unwrap();
+    let result = invoke("update_workflow_settings", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to save workflow settings: {:?}", e))
+}
+
+/// Restore a project's default Actions workflow settings
+pub async fn reset_workflow_settings(project_id: i64) -> Result<WorkflowSettings, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        project_id: i64,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { project_id }).unwrap();
+    let result = invoke("reset_workflow_settings", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to reset workflow settings: {:?}", e))
+}
+
+/// Render the workflow YAML for unsaved settings
+pub async fn preview_workflow(settings: &WorkflowSettings) -> Result<String, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        settings: &'a WorkflowSettings,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { settings }).unwrap();
+    let result = invoke("preview_workflow", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to render workflow: {:?}", e))
+}
+
+/// Compare the committed workflow file with the expected one
+pub async fn check_workflow_drift(
+    project_id: i64,
+    repo_full_name: Option<&str>,
+) -> Result<WorkflowDrift, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        repo_full_name: Option<&'a str>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        repo_full_name,
+    })
+    .unwrap();
+    let result = invoke("check_workflow_drift", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to check workflow drift: {:?}", e))
 }
 
 /// Sync issues from GitHub for a project.
@@ -767,26 +1002,40 @@ pub async fn get_project_issues(project_id: i64) -> Result<Vec<CachedIssue>, Str
     serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to get issues: {:?}", e))
 }
 
-/// Get kanban board for a project
-pub async fn get_kanban_board(project_id: i64) -> Result<KanbanBoard, String> {
+/// Get kanban board for a project, optionally limited to one milestone
+/// (`Some(0)` = issues without a milestone) and / or one linked repository
+pub async fn get_kanban_board(
+    project_id: i64,
+    milestone_number: Option<i32>,
+    repo_full_name: Option<&str>,
+) -> Result<KanbanBoard, String> {
     #[derive(serde::Serialize)]
     #[serde(rename_all = "camelCase")]
-    struct Args {
+    struct Args<'a> {
         project_id: i64,
+        milestone_number: Option<i32>,
+        repo_full_name: Option<&'a str>,
     }
 
-    let args = serde_wasm_bindgen::to_value(&Args { project_id }).unwrap();
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        milestone_number,
+        repo_full_name,
+    })
+    .unwrap();
     let result = invoke("get_kanban_board", args).await;
 
     serde_wasm_bindgen::from_value(result)
         .map_err(|e| format!("Failed to get kanban board: {:?}", e))
 }
 
-/// Update issue status
+/// Update issue status (`repo_full_name` = the issue's repository;
+/// `None` = the project's primary one)
 pub async fn update_issue_status(
     project_id: i64,
     issue_number: i32,
     new_status: &str,
+    repo_full_name: Option<&str>,
 ) -> Result<(), String> {
     #[derive(serde::Serialize)]
     #[serde(rename_all = "camelCase")]
@@ -794,12 +1043,14 @@ pub async fn update_issue_status(
         project_id: i64,
         issue_number: i32,
         new_status: &'a str,
+        repo_full_name: Option<&'a str>,
     }
 
     let args = serde_wasm_bindgen::to_value(&Args {
         project_id,
         issue_number,
         new_status,
+        repo_full_name,
     })
     .unwrap();
     let result = invoke("update_issue_status", args).await;
@@ -813,13 +1064,45 @@ pub async fn update_issue_status(
     }
 }
 
-/// Create a new issue on GitHub
+/// Apply one action (move, labels, assignees, close) to many issues.
+/// Per-issue failures come back in [`BulkIssueResult::failed`].
+pub async fn bulk_update_issues(
+    project_id: i64,
+    targets: &[BulkIssueTarget],
+    action: &BulkIssueAction,
+) -> Result<BulkIssueResult, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        targets: &'a [BulkIssueTarget],
+        action: &'a BulkIssueAction,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        targets,
+        action,
+    })
+    .unwrap();
+    let result = invoke("bulk_update_issues", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to update issues: {:?}", e))
+}
+
+/// Create a new issue on GitHub in one of the project's repositories
+/// (`None` = the primary one). `labels` / `assignees` come from an
+/// issue template.
+#[allow(clippy::too_many_arguments)]
 pub async fn create_github_issue(
     project_id: i64,
     title: &str,
     body: Option<&str>,
     status: Option<&str>,
     priority: Option<&str>,
+    repo_full_name: Option<&str>,
+    labels: Option<&[String]>,
+    assignees: Option<&[String]>,
 ) -> Result<CachedIssue, String> {
     #[derive(serde::Serialize)]
     #[serde(rename_all = "camelCase")]
@@ -829,6 +1112,9 @@ pub async fn create_github_issue(
         body: Option<&'a str>,
         status: Option<&'a str>,
         priority: Option<&'a str>,
+        repo_full_name: Option<&'a str>,
+        labels: Option<&'a [String]>,
+        assignees: Option<&'a [String]>,
     }
 
     let args = serde_wasm_bindgen::to_value(&Args {
@@ -837,9 +1123,504 @@ pub async fn create_github_issue(
         body,
         status,
         priority,
+        repo_full_name,
+        labels,
+        assignees,
     })
     .unwrap();
     let result = invoke("create_github_issue", args).await;
 
     serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to create issue: {:?}", e))
 }
+
+/// Edit title / body / assignees / milestone / labels of an issue
+pub async fn update_issue_fields(
+    project_id: i64,
+    issue_number: i32,
+    update: &UpdateIssueFieldsRequest,
+    repo_full_name: Option<&str>,
+) -> Result<CachedIssue, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        issue_number: i32,
+        update: &'a UpdateIssueFieldsRequest,
+        repo_full_name: Option<&'a str>,
+    }
+
+    // JSON-compatible serializer so `milestone: Some(None)` reaches the
+    // backend as `null` (the default serializer emits `undefined`, which
+    // would read as "field absent").
+    let args = serde::Serialize::serialize(
+        &Args {
+            project_id,
+            issue_number,
+            update,
+            repo_full_name,
+        },
+        &serde_wasm_bindgen::Serializer::json_compatible(),
+    )
+    .unwrap();
+    let result = invoke("update_issue_fields", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to update issue: {:?}", e))
+}
+
+/// Close an issue as `completed` or `not_planned`
+pub async fn close_issue(
+    project_id: i64,
+    issue_number: i32,
+    reason: &str,
+    repo_full_name: Option<&str>,
+) -> Result<CachedIssue, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        issue_number: i32,
+        reason: &'a str,
+        repo_full_name: Option<&'a str>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        issue_number,
+        reason,
+        repo_full_name,
+    })
+    .unwrap();
+    let result = invoke("close_issue", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to close issue: {:?}", e))
+}
+
+/// Reopen a closed issue (lands in Backlog unless `status` is given)
+pub async fn reopen_issue(
+    project_id: i64,
+    issue_number: i32,
+    status: Option<&str>,
+    repo_full_name: Option<&str>,
+) -> Result<CachedIssue, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        issue_number: i32,
+        status: Option<&'a str>,
+        repo_full_name: Option<&'a str>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        issue_number,
+        status,
+        repo_full_name,
+    })
+    .unwrap();
+    let result = invoke("reopen_issue", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to reopen issue: {:?}", e))
+}
+
+/// Labels, assignable users and milestones of an issue's repository, for
+/// the issue edit form
+pub async fn get_issue_edit_options(
+    project_id: i64,
+    repo_full_name: Option<&str>,
+) -> Result<IssueEditOptions, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        repo_full_name: Option<&'a str>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        repo_full_name,
+    })
+    .unwrap();
+    let result = invoke("get_issue_edit_options", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to load edit options: {:?}", e))
+}
+
+/// Issue templates / forms of one of the project's repositories
+/// (`None` = the primary one)
+pub async fn get_issue_templates(
+    project_id: i64,
+    repo_full_name: Option<&str>,
+) -> Result<IssueTemplateSet, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        repo_full_name: Option<&'a str>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        repo_full_name,
+    })
+    .unwrap();
+    let result = invoke("get_issue_templates", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to load issue templates: {:?}", e))
+}
+
+/// Validate the values entered into a template and render the issue body
+pub async fn build_issue_from_template(
+    template: &IssueTemplate,
+    title: &str,
+    values: &HashMap<String, Vec<String>>,
+) -> Result<IssueDraft, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        template: &'a IssueTemplate,
+        title: &'a str,
+        values: &'a HashMap<String, Vec<String>>,
+    }
+
+    // JSON-compatible serializer so `values` reaches the backend as a plain
+    // object (the default serializer emits a JS `Map`).
+    let args = serde::Serialize::serialize(
+        &Args {
+            template,
+            title,
+            values,
+        },
+        &serde_wasm_bindgen::Serializer::json_compatible(),
+    )
+    .unwrap();
+    let result = invoke("build_issue_from_template", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to build issue: {:?}", e))
+}
+
+/// Get one page (1-based) of an issue's comments. Served from the local
+/// cache while fresh, or when GitHub is unreachable.
+pub async fn get_issue_comments(
+    project_id: i64,
+    issue_number: i32,
+    page: i32,
+    force_refresh: bool,
+    repo_full_name: Option<&str>,
+) -> Result<crate::types::CachedResponse<IssueCommentsPage>, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        issue_number: i32,
+        page: i32,
+        force_refresh: bool,
+        repo_full_name: Option<&'a str>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        issue_number,
+        page,
+        force_refresh,
+        repo_full_name,
+    })
+    .unwrap();
+    let result = invoke("get_issue_comments", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to load comments: {:?}", e))
+}
+
+/// Post a Markdown comment on an issue
+pub async fn create_issue_comment(
+    project_id: i64,
+    issue_number: i32,
+    body: &str,
+    repo_full_name: Option<&str>,
+) -> Result<IssueComment, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        issue_number: i32,
+        body: &'a str,
+        repo_full_name: Option<&'a str>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        issue_number,
+        body,
+        repo_full_name,
+    })
+    .unwrap();
+    let result = invoke("create_issue_comment", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to post comment: {:?}", e))
+}
+
+/// Start tracking time on an issue (stops any other running timer first)
+pub async fn start_issue_timer(
+    project_id: i64,
+    issue_number: i32,
+    repo_full_name: Option<&str>,
+) -> Result<TimeEntry, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        issue_number: i32,
+        repo_full_name: Option<&'a str>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        issue_number,
+        repo_full_name,
+    })
+    .unwrap();
+    let result = invoke("start_issue_timer", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to start timer: {:?}", e))
+}
+
+/// Stop the running timer, optionally attaching a note to the entry
+pub async fn stop_issue_timer(note: Option<&str>) -> Result<Option<TimeEntry>, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        note: Option<&'a str>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { note }).unwrap();
+    let result = invoke("stop_issue_timer", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to stop timer: {:?}", e))
+}
+
+/// Get the currently running timer, if any
+pub async fn get_running_timer() -> Result<Option<TimeEntry>, String> {
+    let args = serde_wasm_bindgen::to_value(&()).unwrap();
+    let result = invoke("get_running_timer", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to get running timer: {:?}", e))
+}
+
+/// Get the time entries logged on an issue
+pub async fn get_issue_time_entries(
+    project_id: i64,
+    issue_number: i32,
+    repo_full_name: Option<&str>,
+) -> Result<IssueTimeLog, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        issue_number: i32,
+        repo_full_name: Option<&'a str>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        issue_number,
+        repo_full_name,
+    })
+    .unwrap();
+    let result = invoke("get_issue_time_entries", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to get time entries: {:?}", e))
+}
+
+/// Delete a logged time entry
+pub async fn delete_time_entry(entry_id: i64) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        entry_id: i64,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { entry_id }).unwrap();
+    let result = invoke("delete_time_entry", args).await;
+
+    if result.is_null() || result.is_undefined() {
+        Ok(())
+    } else if let Ok(err) = serde_wasm_bindgen::from_value::<String>(result) {
+        Err(err)
+    } else {
+        Ok(())
+    }
+}
+
+/// Get tracked time totals per issue, project and day
+pub async fn get_time_summary(
+    project_id: Option<i64>,
+    days: Option<i64>,
+) -> Result<TimeSummary, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        project_id: Option<i64>,
+        days: Option<i64>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { project_id, days }).unwrap();
+    let result = invoke("get_time_summary", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to get time summary: {:?}", e))
+}
+
+/// Post the issue's tracked time as a summary comment on GitHub
+pub async fn post_issue_time_summary(
+    project_id: i64,
+    issue_number: i32,
+    repo_full_name: Option<&str>,
+) -> Result<IssueComment, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        project_id: i64,
+        issue_number: i32,
+        repo_full_name: Option<&'a str>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        issue_number,
+        repo_full_name,
+    })
+    .unwrap();
+    let result = invoke("post_issue_time_summary", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to post time summary: {:?}", e))
+}
+
+/// List a project's synced milestones (open first, by due date)
+pub async fn get_project_milestones(project_id: i64) -> Result<Vec<ProjectMilestone>, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        project_id: i64,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { project_id }).unwrap();
+    let result = invoke("get_project_milestones", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to get milestones: {:?}", e))
+}
+
+/// Get the daily burndown / burnup series of a milestone
+pub async fn get_milestone_burndown(
+    project_id: i64,
+    milestone_number: i32,
+) -> Result<MilestoneBurndown, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        project_id: i64,
+        milestone_number: i32,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        project_id,
+        milestone_number,
+    })
+    .unwrap();
+    let result = invoke("get_milestone_burndown", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to get milestone burndown: {:?}", e))
+}
+
+/// List queued offline issue mutations for a project
+pub async fn get_issue_outbox(project_id: Option<i64>) -> Result<Vec<OutboxOperation>, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        project_id: Option<i64>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { project_id }).unwrap();
+    let result = invoke("get_issue_outbox", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to get outbox: {:?}", e))
+}
+
+/// Push queued offline mutations to GitHub (call when back online)
+pub async fn replay_issue_outbox(project_id: Option<i64>) -> Result<OutboxReplayResult, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        project_id: Option<i64>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { project_id }).unwrap();
+    let result = invoke("replay_issue_outbox", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to replay outbox: {:?}", e))
+}
+
+/// Move a failed / conflicting outbox operation back to pending
+pub async fn retry_outbox_operation(
+    operation_id: i64,
+    force: bool,
+) -> Result<OutboxOperation, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        operation_id: i64,
+        force: bool,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args {
+        operation_id,
+        force,
+    })
+    .unwrap();
+    let result = invoke("retry_outbox_operation", args).await;
+
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| format!("Failed to retry operation: {:?}", e))
+}
+
+/// Drop a queued outbox operation and roll back its optimistic change
+pub async fn discard_outbox_operation(operation_id: i64) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        operation_id: i64,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { operation_id }).unwrap();
+    let result = invoke("discard_outbox_operation", args).await;
+
+    if result.is_null() || result.is_undefined() {
+        Ok(())
+    } else if let Ok(err) = serde_wasm_bindgen::from_value::<String>(result) {
+        Err(err)
+    } else {
+        Ok(())
+    }
+}
+
+/// Full-text search over cached issues of all projects (works offline)
+pub async fn search_issues_local(
+    query: &str,
+    limit: Option<i64>,
+) -> Result<Vec<IssueSearchResult>, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        query: &'a str,
+        limit: Option<i64>,
+    }
+
+    let args = serde_wasm_bindgen::to_value(&Args { query, limit }).unwrap();
+    let result = invoke("search_issues_local", args).await;
+
+    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to search issues: {:?}", e))
+}
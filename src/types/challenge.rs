@@ -17,11 +17,16 @@ pub struct ChallengeInfo {
     pub end_date: String,
     pub status: String,
     pub completed_at: Option<String>,
+    /// 目標値の算出理由（自動生成チャレンジのみ）
+    #[serde(default)]
+    pub target_reason: Option<String>,
     // Computed fields
     pub progress_percent: f32,
     pub remaining_time_hours: i64,
     pub is_completed: bool,
     pub is_expired: bool,
+    #[serde(default)]
+    pub is_upcoming: bool,
 }
 
 impl ChallengeInfo {
@@ -30,19 +35,23 @@ impl ChallengeInfo {
         match self.challenge_type.as_str() {
             "daily" => "デイリー",
             "weekly" => "ウィークリー",
+            "monthly" => "マンスリー",
+            "quarterly" => "四半期",
+            "custom" => "カスタム",
             _ => "その他",
         }
     }
 
     /// Get display name for target metric
-    pub fn target_metric_label(&self) -> &'static str {
-        match self.target_metric.as_str() {
-            "commits" => "コミット",
-            "prs" => "PR",
-            "reviews" => "レビュー",
-            "issues" => "Issue",
-            _ => "その他",
+    pub fn target_metric_label(&self) -> String {
+        if let Some(language) = self.target_metric.strip_prefix(LANGUAGE_METRIC_PREFIX) {
+            return format!("{} を使った日数", language);
         }
+        TARGET_METRICS
+            .iter()
+            .find(|(value, _, _)| *value == self.target_metric)
+            .map(|(_, label, _)| label.to_string())
+            .unwrap_or_else(|| "その他".to_string())
     }
 
     /// Get icon for target metric
@@ -52,6 +61,14 @@ impl ChallengeInfo {
             "prs" => "🔀",
             "reviews" => "👀",
             "issues" => "🐛",
+            "focus_minutes" => "⏱",
+            "prs_merged" => "✅",
+            "issues_closed" => "📕",
+            "lines_added" => "➕",
+            "lines_deleted" => "➖",
+            "repositories" => "📦",
+            "fast_reviews" => "⚡",
+            m if m.starts_with(LANGUAGE_METRIC_PREFIX) => "🗣",
             _ => "🎯",
         }
     }
@@ -66,6 +83,15 @@ impl ChallengeInfo {
         }
     }
 
+    /// Start date of a scheduled challenge, e.g. "11/01 開始"
+    pub fn upcoming_label(&self) -> String {
+        // start_date is RFC 3339 in UTC: take the "MM-DD" part.
+        self.start_date
+            .get(5..10)
+            .map(|md| format!("{} 開始", md.replace('-', "/")))
+            .unwrap_or_else(|| "開始前".to_string())
+    }
+
     /// Format remaining time as human-readable string
     pub fn remaining_time_label(&self) -> String {
         if self.remaining_time_hours <= 0 {
@@ -90,6 +116,9 @@ pub struct CreateChallengeRequest {
     pub target_metric: String,
     pub target_value: i32,
     pub reward_xp: Option<i32>,
+    /// Custom challenges only: first / last day (`YYYY-MM-DD`, inclusive)
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
 }
 
 /// チャレンジ統計
@@ -101,8 +130,25 @@ pub struct ChallengeStats {
     pub active_count: i32,
 }
 
+/// リロール / スキップトークンの残数
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeTokens {
+    pub reroll_tokens: i32,
+    pub skip_tokens: i32,
+}
+
+/// トークンでリロール / スキップできるチャレンジタイプ
+pub const TOKEN_CHALLENGE_TYPES: &[&str] = &["daily", "weekly"];
+
 /// チャレンジタイプの選択肢
-pub const CHALLENGE_TYPES: &[(&str, &str)] = &[("daily", "デイリー"), ("weekly", "ウィークリー")];
+pub const CHALLENGE_TYPES: &[(&str, &str)] = &[
+    ("daily", "デイリー"),
+    ("weekly", "ウィークリー"),
+    ("monthly", "マンスリー"),
+    ("quarterly", "四半期"),
+    ("custom", "カスタム"),
+];
 
 /// ターゲットメトリクスの選択肢
 pub const TARGET_METRICS: &[(&str, &str, &str)] = &[
@@ -110,4 +156,14 @@ pub const TARGET_METRICS: &[(&str, &str, &str)] = &[
     ("prs", "PR", "🔀"),
     ("reviews", "レビュー", "👀"),
     ("issues", "Issue", "🐛"),
+    ("focus_minutes", "集中時間(分)", "⏱"),
+    ("prs_merged", "マージしたPR", "✅"),
+    ("issues_closed", "クローズしたIssue", "📕"),
+    ("lines_added", "追加行数", "➕"),
+    ("lines_deleted", "削除行数", "➖"),
+    ("repositories", "コミットしたリポジトリ数", "📦"),
+    ("fast_reviews", "24時間以内のレビュー", "⚡"),
 ];
+
+/// Prefix of the per-language metric (`language:Rust`), counted in days
+pub const LANGUAGE_METRIC_PREFIX: &str = "language:";
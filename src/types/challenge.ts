@@ -1,13 +1,28 @@
 // Challenge-related types
 
 /// チャレンジタイプ
-export type ChallengeType = 'daily' | 'weekly';
+export type ChallengeType = 'daily' | 'weekly' | 'monthly' | 'quarterly' | 'custom';
 
 /// ターゲットメトリクス
-export type TargetMetric = 'commits' | 'prs' | 'reviews' | 'issues';
+export type TargetMetric =
+  | 'commits'
+  | 'prs'
+  | 'reviews'
+  | 'issues'
+  | 'focus_minutes'
+  | 'prs_merged'
+  | 'issues_closed'
+  | 'lines_added'
+  | 'lines_deleted'
+  | 'repositories'
+  | 'fast_reviews'
+  | `language:${string}`;
+
+/// Prefix of the per-language metric (`language:Rust`), counted in days
+export const LANGUAGE_METRIC_PREFIX = 'language:';
 
 /// チャレンジステータス
-export type ChallengeStatus = 'active' | 'completed' | 'failed';
+export type ChallengeStatus = 'active' | 'completed' | 'failed' | 'skipped';
 
 /// チャレンジ情報
 export interface ChallengeInfo {
@@ -22,11 +37,14 @@ export interface ChallengeInfo {
   endDate: string;
   status: ChallengeStatus;
   completedAt: string | null;
+  /// 目標値の算出理由（自動生成チャレンジのみ）
+  targetReason: string | null;
   // Computed fields
   progressPercent: number;
   remainingTimeHours: number;
   isCompleted: boolean;
   isExpired: boolean;
+  isUpcoming: boolean;
 }
 
 /// チャレンジ作成リクエスト
@@ -35,6 +53,9 @@ export interface CreateChallengeRequest {
   targetMetric: TargetMetric;
   targetValue: number;
   rewardXp: number | null;
+  /// Custom challenges only: first / last day (`YYYY-MM-DD`, inclusive)
+  startDate?: string | null;
+  endDate?: string | null;
 }
 
 /// チャレンジ統計
@@ -44,10 +65,22 @@ export interface ChallengeStats {
   activeCount: number;
 }
 
+/// リロール / スキップトークンの残数
+export interface ChallengeTokens {
+  rerollTokens: number;
+  skipTokens: number;
+}
+
+/// トークンでリロール / スキップできるチャレンジタイプ
+export const TOKEN_CHALLENGE_TYPES: ChallengeType[] = ['daily', 'weekly'];
+
 /// チャレンジタイプの選択肢
 export const CHALLENGE_TYPES: [string, string][] = [
   ['daily', 'デイリー'],
   ['weekly', 'ウィークリー'],
+  ['monthly', 'マンスリー'],
+  ['quarterly', '四半期'],
+  ['custom', 'カスタム'],
 ];
 
 /// ターゲットメトリクスの選択肢
@@ -56,6 +89,13 @@ export const TARGET_METRICS: [string, string, string][] = [
   ['prs', 'PR', '🔀'],
   ['reviews', 'レビュー', '👀'],
   ['issues', 'Issue', '🐛'],
+  ['focus_minutes', '集中時間(分)', '⏱'],
+  ['prs_merged', 'マージしたPR', '✅'],
+  ['issues_closed', 'クローズしたIssue', '📕'],
+  ['lines_added', '追加行数', '➕'],
+  ['lines_deleted', '削除行数', '➖'],
+  ['repositories', 'コミットしたリポジトリ数', '📦'],
+  ['fast_reviews', '24時間以内のレビュー', '⚡'],
 ];
 
 /// Get display name for challenge type
@@ -65,35 +105,29 @@ export function challengeTypeLabel(challengeType: ChallengeType): string {
       return 'デイリー';
     case 'weekly':
       return 'ウィークリー';
+    case 'monthly':
+      return 'マンスリー';
+    case 'quarterly':
+      return '四半期';
+    case 'custom':
+      return 'カスタム';
   }
 }
 
 /// Get display name for target metric
 export function targetMetricLabel(targetMetric: TargetMetric): string {
-  switch (targetMetric) {
-    case 'commits':
-      return 'コミット';
-    case 'prs':
-      return 'PR';
-    case 'reviews':
-      return 'レビュー';
-    case 'issues':
-      return 'Issue';
+  if (targetMetric.startsWith(LANGUAGE_METRIC_PREFIX)) {
+    return `${targetMetric.slice(LANGUAGE_METRIC_PREFIX.length)} を使った日数`;
   }
+  return TARGET_METRICS.find(([value]) => value === targetMetric)?.[1] ?? 'その他';
 }
 
 /// Get icon for target metric
 export function targetMetricIcon(targetMetric: TargetMetric): string {
-  switch (targetMetric) {
-    case 'commits':
-      return '📝';
-    case 'prs':
-      return '🔀';
-    case 'reviews':
-      return '👀';
-    case 'issues':
-      return '🐛';
+  if (targetMetric.startsWith(LANGUAGE_METRIC_PREFIX)) {
+    return '🗣';
   }
+  return TARGET_METRICS.find(([value]) => value === targetMetric)?.[2] ?? '🎯';
 }
 
 /// Get status label
@@ -108,6 +142,13 @@ export function statusLabel(status: ChallengeStatus): string {
   }
 }
 
+/// Start date of a scheduled challenge, e.g. "11/01 開始"
+export function upcomingLabel(startDate: string): string {
+  // startDate is RFC 3339 in UTC: take the "MM-DD" part.
+  const monthDay = startDate.slice(5, 10);
+  return monthDay.length === 5 ? `${monthDay.replace('-', '/')} 開始` : '開始前';
+}
+
 /// Format remaining time as human-readable string
 export function remainingTimeLabel(remainingTimeHours: number): string {
   if (remainingTimeHours <= 0) {
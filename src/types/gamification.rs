@@ -57,15 +57,79 @@ pub struct UserStats {
 }
 
 /// レベル情報
+///
+/// `total_xp` は累計XP。レベル関連の値は前回のプレステージ以降に獲得した
+/// `level_xp` から計算される。
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct LevelInfo {
     pub current_level: i32,
     pub total_xp: i32,
+    #[serde(default)]
+    pub level_xp: i32,
     pub xp_for_current_level: i32,
     pub xp_for_next_level: i32,
     pub xp_to_next_level: i32,
     pub progress_percent: f32,
+    #[serde(default)]
+    pub prestige_rank: i32,
+    #[serde(default)]
+    pub prestige_title: Option<String>,
+    #[serde(default)]
+    pub xp_multiplier: f64,
+    #[serde(default)]
+    pub prestige_level: i32,
+    #[serde(default)]
+    pub can_prestige: bool,
+}
+
+/// プレステージ結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrestigeResult {
+    pub level_info: LevelInfo,
+    pub new_badges: Vec<NewBadgeInfo>,
+}
+
+/// 現在のシーズン情報
+///
+/// シーズンXPは累計XPとは別に、四半期ごとにリセットされる。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonInfo {
+    /// シーズンID（例: "2026-Q4"）
+    pub season_id: String,
+    pub starts_on: String,
+    /// 次シーズンの開始日（この日を含まない）
+    pub ends_on: String,
+    pub days_remaining: i64,
+    pub season_xp: i32,
+    pub season_level: i32,
+    pub xp_to_next_level: i32,
+    pub progress_percent: f32,
+    pub best_streak: i32,
+    pub seasons_completed: i32,
+    /// 今回の呼び出しでアーカイブされた前シーズン
+    pub archived_season: Option<SeasonArchive>,
+    pub new_badges: Vec<NewBadgeInfo>,
+}
+
+/// アーカイブ済みシーズンのサマリー
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonArchive {
+    pub id: i64,
+    pub season_id: String,
+    pub starts_on: String,
+    pub ends_on: String,
+    pub season_xp: i32,
+    pub season_level: i32,
+    pub best_streak: i32,
+    /// シーズン中に獲得したレアなバッジ（レア度順）
+    pub top_badges: Vec<String>,
+    /// 過去シーズンと比較したパーセンタイル（履歴が少ない間はNone）
+    pub percentile: Option<i32>,
+    pub archived_at: String,
 }
 
 /// GitHub統計
@@ -184,6 +248,11 @@ pub struct XpBreakdown {
     pub reviews_xp: i32,
     pub stars_xp: i32,
     pub streak_bonus_xp: i32,
+    #[serde(default)]
+    pub prestige_bonus_xp: i32,
+    /// XPセーフガードで差し引かれたコミットXP（0以下）
+    #[serde(default)]
+    pub adjusted_xp: i32,
     pub total_xp: i32,
 }
 
@@ -46,13 +46,65 @@ export interface UserStats {
 }
 
 /// レベル情報
+///
+/// totalXp は累計XP。レベル関連の値は前回のプレステージ以降に獲得した
+/// levelXp から計算される。
 export interface LevelInfo {
   currentLevel: number;
   totalXp: number;
+  levelXp: number;
   xpForCurrentLevel: number;
   xpForNextLevel: number;
   xpToNextLevel: number;
   progressPercent: number;
+  prestigeRank: number;
+  prestigeTitle: string | null;
+  xpMultiplier: number;
+  prestigeLevel: number;
+  canPrestige: boolean;
+}
+
+/// プレステージ結果
+export interface PrestigeResult {
+  levelInfo: LevelInfo;
+  newBadges: NewBadgeInfo[];
+}
+
+/// 現在のシーズン情報
+///
+/// シーズンXPは累計XPとは別に、四半期ごとにリセットされる。
+export interface SeasonInfo {
+  /** シーズンID（例: "2026-Q4"） */
+  seasonId: string;
+  startsOn: string;
+  /** 次シーズンの開始日（この日を含まない） */
+  endsOn: string;
+  daysRemaining: number;
+  seasonXp: number;
+  seasonLevel: number;
+  xpToNextLevel: number;
+  progressPercent: number;
+  bestStreak: number;
+  seasonsCompleted: number;
+  /** 今回の呼び出しでアーカイブされた前シーズン */
+  archivedSeason: SeasonArchive | null;
+  newBadges: NewBadgeInfo[];
+}
+
+/// アーカイブ済みシーズンのサマリー
+export interface SeasonArchive {
+  id: number;
+  seasonId: string;
+  startsOn: string;
+  endsOn: string;
+  seasonXp: number;
+  seasonLevel: number;
+  bestStreak: number;
+  /** シーズン中に獲得したレアなバッジ（レア度順） */
+  topBadges: string[];
+  /** 過去シーズンと比較したパーセンタイル（履歴が少ない間はnull） */
+  percentile: number | null;
+  archivedAt: string;
 }
 
 /// GitHub統計
@@ -89,6 +141,66 @@ export interface ContributionDay {
   weekday: number;
 }
 
+/// 保存済みコントリビューションの年別集計
+export interface ContributionYearSummary {
+  year: number;
+  totalContributions: number;
+  /// コントリビューションのあった日数
+  activeDays: number;
+  /// 過去分の取得で 1 年分すべて取得済みか
+  backfilled: boolean;
+}
+
+/// 保存済みコントリビューション履歴 (`get_contribution_history`)
+export interface ContributionHistory {
+  /// 新しい年から順
+  years: ContributionYearSummary[];
+  lifetimeContributions: number;
+  lifetimeActiveDays: number;
+  /// 過去分を最後に取得した日時（未取得なら null）
+  backfilledAt: string | null;
+}
+
+/// ヒートマップの指標
+export type HeatmapMetric = 'contributions' | 'xp' | 'lines' | 'commits';
+
+/// ヒートマップのリクエスト (`get_activity_heatmap`)
+export interface HeatmapRequest {
+  metric: HeatmapMetric;
+  /// null なら直近365日
+  year?: number | null;
+  /// owner/name。lines / commits のみ。そのリポジトリの分だけを表示
+  repository?: string | null;
+}
+
+/// ヒートマップの1日
+export interface HeatmapDay {
+  date: string;
+  value: number;
+  /// 色の段階（0〜4）
+  level: number;
+  /// 変更行数の指標のみ
+  additions: number | null;
+  deletions: number | null;
+}
+
+/// 正規化済みのヒートマップ
+export interface ActivityHeatmap {
+  metric: HeatmapMetric;
+  repository: string | null;
+  start: string;
+  end: string;
+  /// 日曜始まりの週、古い順
+  weeks: HeatmapDay[][];
+  total: number;
+  activeDays: number;
+  max: number;
+  /// 段階1〜3の上限値。これを超えると段階4
+  thresholds: [number, number, number];
+  /// 期間内にコミットのあったリポジトリ
+  repositories: string[];
+}
+
 /// バッジ
 export interface Badge {
   id: number;
@@ -155,6 +267,9 @@ export interface XpBreakdown {
   reviewsXp: number;
   starsXp: number;
   streakBonusXp: number;
+  prestigeBonusXp?: number;
+  /// XPセーフガードで差し引かれたコミットXP（0以下）
+  adjustedXp?: number;
   totalXp: number;
 }
 
@@ -441,6 +556,201 @@ export interface LanguageBreakdownResponse {
   repositoriesScanned: number;
 }
 
+// ============================================
+// リポジトリ別ドリルダウン
+// ============================================
+
+/// 週ごとのコミット集計（月曜始まり・UTC）
+export interface RepositoryCommitWeek {
+  /// 週の開始日（月曜、YYYY-MM-DD）
+  weekStart: string;
+  commits: number;
+  additions: number;
+  deletions: number;
+}
+
+/// 1 リポジトリのドリルダウン (`get_repository_detail_with_cache`)
+///
+/// **IMPORTANT**: keep in sync with `src-tauri/src/github/types.rs::RepositoryDetail`.
+export interface RepositoryDetail {
+  nameWithOwner: string;
+  url: string | null;
+  description: string | null;
+  isFork: boolean;
+  isArchived: boolean;
+  isPrivate: boolean;
+  stargazerCount: number;
+  /// 集計期間の開始 ISO8601 タイムスタンプ
+  since: string;
+  /// 集計期間（日数）
+  days: number;
+  /// 自分のコミット数（デフォルトブランチ）
+  commitsCount: number;
+  additions: number;
+  deletions: number;
+  /// 全員のコミット数（デフォルトブランチ）
+  totalCommits: number;
+  /// 自分のコミットの割合 (0.0..=1.0)
+  contributionShare: number;
+  /// 期間全体をカバーする週ごとの集計（古い順）
+  commitTimeline: RepositoryCommitWeek[];
+  pullRequestsOpened: number;
+  pullRequestsMerged: number;
+  /// レビューした他の人の PR の数
+  reviewsGiven: number;
+  issuesOpened: number;
+  issuesClosed: number;
+  languages: LanguageStats[];
+  totalBytes: number;
+  /// コミットが多すぎて追加・削除行数とタイムラインが最新分のみの場合 true
+  commitsTruncated: boolean;
+}
+
+/// ドリルダウンの集計期間（日数）
+export type RepositoryDetailDays = 30 | 90 | 365;
+
+// ============================================
+// 期間比較レポート
+// ============================================
+
+/// 比較プリセット（`comparison_presets`）
+export type ComparisonPreset =
+  | 'week_over_week'
+  | 'month_over_month'
+  | 'month_year_over_year'
+  | 'custom';
+
+/// 比較する指標（`comparison_metrics`）
+export type ComparisonMetric =
+  | 'commits'
+  | 'pullRequests'
+  | 'pullRequestsMerged'
+  | 'reviews'
+  | 'issues'
+  | 'issuesClosed'
+  | 'starsReceived'
+  | 'contributions'
+  | 'additions'
+  | 'deletions'
+  | 'netChange'
+  | 'activeDays'
+  | 'xp';
+
+/// 期間（両端を含む、YYYY-MM-DD・UTC）
+export interface PeriodRange {
+  start: string;
+  end: string;
+}
+
+/// `get_period_comparison` のリクエスト
+export interface PeriodComparisonRequest {
+  preset: ComparisonPreset;
+  /// `custom` のときのみ必須
+  current?: PeriodRange | null;
+  /// `custom` のときのみ必須
+  previous?: PeriodRange | null;
+}
+
+/// 1 指標の比較結果
+export interface MetricComparison {
+  metric: ComparisonMetric;
+  /// スナップショットが足りず不明な場合は null
+  current: number | null;
+  previous: number | null;
+  /// current - previous（どちらかが不明なら null）
+  delta: number | null;
+  /// 比較期間に対する増減率（%）。比較期間が 0 または不明なら null
+  percentChange: number | null;
+}
+
+/// 期間比較レポート (`get_period_comparison`)
+///
+/// **IMPORTANT**: keep in sync with
+/// `src-tauri/src/database/models/period_comparison.rs::PeriodComparison`.
+export interface PeriodComparison {
+  preset: ComparisonPreset;
+  current: PeriodRange;
+  previous: PeriodRange;
+  metrics: MetricComparison[];
+}
+
+// ============================================
+// 週間・月間サマリーレポート
+// ============================================
+
+/// レポート期間（`report_periods`）。直近の完了した週（月〜日）または月
+export type ReportPeriod = 'week' | 'month';
+
+/// 保存形式（`report_formats`）
+export type ReportFormat = 'markdown' | 'html';
+
+/// 期間中に獲得した XP（`xp_history.action_type` ごと）
+export interface XpSourceTotal {
+  actionType: string;
+  xp: number;
+  count: number;
+}
+
+/// 期間中に獲得したバッジ
+export interface ReportBadge {
+  badgeId: string;
+  name: string;
+  icon: string;
+  rarity: string;
+  earnedAt: string;
+}
+
+/// 期間中に完了したチャレンジ
+export interface ReportChallenge {
+  challengeType: string;
+  targetMetric: string;
+  targetValue: number;
+  rewardXp: number;
+  completedAt: string;
+}
+
+/// 期間中にコミットしたリポジトリ
+export interface ReportRepository {
+  nameWithOwner: string;
+  /// コミットのあった日数
+  activeDays: number;
+  primaryLanguage: string | null;
+}
+
+/// 期間中のリポジトリの主要言語
+export interface ReportLanguage {
+  name: string;
+  activeDays: number;
+}
+
+/// サマリーレポート (`get_summary_report`)
+///
+/// **IMPORTANT**: keep in sync with
+/// `src-tauri/src/database/models/summary_report.rs::SummaryReport`.
+export interface SummaryReport {
+  period: ReportPeriod;
+  range: PeriodRange;
+  username: string;
+  generatedAt: string;
+  xpTotal: number;
+  xpBySource: XpSourceTotal[];
+  levelStart: number;
+  levelEnd: number;
+  badges: ReportBadge[];
+  currentStreak: number;
+  longestStreak: number;
+  activeDays: number;
+  additions: number;
+  deletions: number;
+  /// 信頼できる差分を算出できない場合は null
+  commits: number | null;
+  pullRequestsMerged: number | null;
+  reviews: number | null;
+  topRepositories: ReportRepository[];
+  languages: ReportLanguage[];
+  challengesCompleted: ReportChallenge[];
+}
+
 // ============================================
 // 過去データの遡及 XP 再計算（Issue #194）
 // ============================================
@@ -121,7 +121,8 @@ impl IssuePriority {
     }
 }
 
-/// Project model (1 project = 1 repository)
+/// Project model. `repo_*` mirror the primary linked repository; see
+/// `ProjectRepository` for the full list.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
     pub id: i64,
@@ -168,6 +169,28 @@ impl Project {
     }
 }
 
+/// Repository linked to a project. A project can link several; the
+/// oldest link is the primary one mirrored on `Project::repo_*`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectRepository {
+    pub id: i64,
+    pub project_id: i64,
+    pub github_repo_id: i64,
+    pub repo_owner: String,
+    pub repo_name: String,
+    pub repo_full_name: String,
+    /// True when this repository returned 404 (Issue #190, per repository)
+    #[serde(default)]
+    pub is_archived: bool,
+    #[serde(default)]
+    pub archived_at: Option<String>,
+    #[serde(default)]
+    pub archived_reason: Option<String>,
+    #[serde(default)]
+    pub last_synced_at: Option<String>,
+    pub created_at: String,
+}
+
 /// Project with additional stats
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectWithStats {
@@ -182,6 +205,9 @@ pub struct ProjectWithStats {
 pub struct CachedIssue {
     pub id: i64,
     pub project_id: i64,
+    /// `owner/repo` the issue belongs to (projects can link several)
+    #[serde(default)]
+    pub repo_full_name: Option<String>,
     pub github_issue_id: i64,
     pub number: i32,
     pub title: String,
@@ -202,6 +228,101 @@ pub struct CachedIssue {
     pub is_archived: bool,
     #[serde(default)]
     pub archived_at: Option<String>,
+    /// True while an offline change to this issue is still queued in the
+    /// outbox. Queued creates also carry a negative placeholder `number`.
+    #[serde(default)]
+    pub pending_sync: bool,
+    /// JSON array of assignee logins.
+    #[serde(default)]
+    pub assignees_json: Option<String>,
+    #[serde(default)]
+    pub milestone_number: Option<i32>,
+    #[serde(default)]
+    pub milestone_title: Option<String>,
+    /// `completed` / `not_planned` / `reopened`
+    #[serde(default)]
+    pub state_reason: Option<String>,
+    /// JSON array of `LinkedPullRequest`
+    #[serde(default)]
+    pub linked_prs_json: Option<String>,
+}
+
+/// Pull request linked to an issue, with review / checks / merge state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinkedPullRequest {
+    pub number: i32,
+    pub title: String,
+    pub url: String,
+    pub repo_full_name: String,
+    /// `OPEN` / `CLOSED` / `MERGED`
+    pub state: String,
+    pub is_draft: bool,
+    /// `MERGEABLE` / `CONFLICTING` / `UNKNOWN`
+    pub mergeable: String,
+    pub review_decision: Option<String>,
+    /// `SUCCESS` / `FAILURE` / `PENDING` / `ERROR` / `EXPECTED`
+    pub checks_state: Option<String>,
+    /// Closing reference (vs. a plain mention)
+    pub closes_issue: bool,
+}
+
+impl LinkedPullRequest {
+    /// Badge color: merge state first, then CI result.
+    pub fn badge_class(&self) -> &'static str {
+        match (self.state.as_str(), self.checks_state.as_deref()) {
+            ("MERGED", _) => "bg-purple-500/20 text-purple-300 border-purple-500/40",
+            ("CLOSED", _) => "bg-gray-500/20 text-gray-400 border-gray-500/40",
+            (_, Some("FAILURE" | "ERROR")) => "bg-red-500/20 text-red-300 border-red-500/40",
+            (_, Some("PENDING" | "EXPECTED")) => {
+                "bg-yellow-500/20 text-yellow-300 border-yellow-500/40"
+            }
+            (_, Some("SUCCESS")) => "bg-green-500/20 text-green-300 border-green-500/40",
+            _ => "bg-slate-500/20 text-slate-300 border-slate-500/40",
+        }
+    }
+
+    /// Short glyph for the CI result (`None` without checks or once merged
+    /// / closed).
+    pub fn checks_icon(&self) -> Option<&'static str> {
+        if self.state != "OPEN" {
+            return None;
+        }
+        match self.checks_state.as_deref() {
+            Some("SUCCESS") => Some("✓"),
+            Some("FAILURE" | "ERROR") => Some("✗"),
+            Some("PENDING" | "EXPECTED") => Some("●"),
+            _ => None,
+        }
+    }
+
+    /// Tooltip text summarising every state.
+    pub fn summary(&self) -> String {
+        let mut parts = vec![format!(
+            "{} #{}: {}",
+            self.repo_full_name, self.number, self.title
+        )];
+        let state = match (self.state.as_str(), self.is_draft) {
+            ("OPEN", true) => "Draft".to_string(),
+            (state, _) => state.to_lowercase(),
+        };
+        parts.push(state);
+        if let Some(review) = &self.review_decision {
+            parts.push(format!(
+                "review: {}",
+                review.to_lowercase().replace('_', " ")
+            ));
+        }
+        if let Some(checks) = &self.checks_state {
+            parts.push(format!("checks: {}", checks.to_lowercase()));
+        }
+        if self.state == "OPEN" && self.mergeable == "CONFLICTING" {
+            parts.push("merge conflicts".to_string());
+        }
+        if !self.closes_issue {
+            parts.push("mentions this issue".to_string());
+        }
+        parts.join(" · ")
+    }
 }
 
 /// Response payload from `sync_project_issues`. Carries both the resulting
@@ -246,6 +367,22 @@ impl CachedIssue {
             .unwrap_or_default()
     }
 
+    /// Get parsed assignee logins (falls back to `assignee_login`)
+    pub fn get_assignees(&self) -> Vec<String> {
+        self.assignees_json
+            .as_ref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_else(|| self.assignee_login.iter().cloned().collect())
+    }
+
+    /// Get parsed linked pull requests
+    pub fn get_linked_prs(&self) -> Vec<LinkedPullRequest> {
+        self.linked_prs_json
+            .as_ref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default()
+    }
+
     /// Check if issue is open
     pub fn is_open(&self) -> bool {
         self.state == "open"
@@ -347,3 +484,537 @@ pub struct RepositoryInfo {
     pub private: bool,
     pub open_issues_count: i32,
 }
+
+/// Fields to change with `update_issue_fields`. `None` leaves a field
+/// untouched; `milestone: Some(None)` clears the milestone.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateIssueFieldsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignees: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<Option<i32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+}
+
+/// Choices for the issue edit form (`get_issue_edit_options`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IssueEditOptions {
+    pub labels: Vec<LabelOption>,
+    pub assignees: Vec<AssigneeOption>,
+    pub milestones: Vec<MilestoneOption>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabelOption {
+    pub name: String,
+    /// Hex color without `#`
+    pub color: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssigneeOption {
+    pub login: String,
+    pub avatar_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MilestoneOption {
+    pub number: i32,
+    pub title: String,
+    pub due_on: Option<String>,
+}
+
+/// One option of an issue form `checkboxes` element.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueFormCheckbox {
+    pub label: String,
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// One element of an issue form (`markdown`, `input`, `textarea`,
+/// `dropdown` or `checkboxes`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueFormField {
+    /// Key of the entered values (`id`, or `field-<index>`)
+    pub key: String,
+    pub field_type: String,
+    pub label: Option<String>,
+    pub description: Option<String>,
+    pub placeholder: Option<String>,
+    /// Default value, or the text of a `markdown` element
+    pub value: Option<String>,
+    #[serde(default)]
+    pub options: Vec<String>,
+    #[serde(default)]
+    pub checkboxes: Vec<IssueFormCheckbox>,
+    #[serde(default)]
+    pub multiple: bool,
+    pub default_option: Option<usize>,
+    pub render: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// Issue template of a repository (`get_issue_templates`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueTemplate {
+    pub file_name: String,
+    /// `markdown` or `form`
+    pub kind: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub title: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub assignees: Vec<String>,
+    /// Markdown templates: body to prefill
+    pub body: Option<String>,
+    /// Issue forms: elements to render
+    #[serde(default)]
+    pub fields: Vec<IssueFormField>,
+}
+
+impl IssueTemplate {
+    pub fn is_form(&self) -> bool {
+        self.kind == "form"
+    }
+}
+
+/// Templates of one repository.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IssueTemplateSet {
+    pub templates: Vec<IssueTemplate>,
+    pub blank_issues_enabled: bool,
+    /// `"<file>: <reason>"` for files that could not be parsed
+    #[serde(default)]
+    pub invalid: Vec<String>,
+}
+
+/// Issue built from a template (`build_issue_from_template`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IssueDraft {
+    pub title: String,
+    pub body: String,
+    pub labels: Vec<String>,
+    pub assignees: Vec<String>,
+}
+
+/// Count of one reaction kind on a comment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReactionCount {
+    pub content: String,
+    pub emoji: String,
+    pub count: i64,
+}
+
+/// One comment of an issue thread (`get_issue_comments`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueComment {
+    pub id: i64,
+    pub author_login: Option<String>,
+    pub author_avatar_url: Option<String>,
+    pub author_association: Option<String>,
+    pub body: String,
+    pub html_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub reactions: Vec<ReactionCount>,
+}
+
+/// One page of an issue's comments, oldest first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueCommentsPage {
+    pub comments: Vec<IssueComment>,
+    pub page: i32,
+    pub has_more: bool,
+}
+
+/// Synced milestone of a project (`get_project_milestones`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectMilestone {
+    pub id: i64,
+    pub project_id: i64,
+    pub github_milestone_id: i64,
+    pub number: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub state: String,
+    pub due_on: Option<String>,
+    pub open_issues: i32,
+    pub closed_issues: i32,
+    pub github_created_at: Option<String>,
+    pub github_closed_at: Option<String>,
+    pub synced_at: String,
+}
+
+impl ProjectMilestone {
+    /// Completion in percent (0 for an empty milestone).
+    pub fn progress_percent(&self) -> f64 {
+        let total = self.open_issues + self.closed_issues;
+        if total == 0 {
+            0.0
+        } else {
+            self.closed_issues as f64 * 100.0 / total as f64
+        }
+    }
+
+    /// Due date part (`YYYY-MM-DD`), if any.
+    pub fn due_date(&self) -> Option<&str> {
+        self.due_on
+            .as_deref()
+            .map(|d| d.split('T').next().unwrap_or(d))
+    }
+}
+
+/// One day of a milestone burndown / burnup series.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BurndownPoint {
+    pub date: String,
+    pub open: Option<i64>,
+    pub closed: Option<i64>,
+    pub scope: Option<i64>,
+    pub ideal: Option<f64>,
+}
+
+/// Response of `get_milestone_burndown`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MilestoneBurndown {
+    pub milestone: ProjectMilestone,
+    pub points: Vec<BurndownPoint>,
+}
+
+/// One queued offline issue mutation (`get_issue_outbox`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboxOperation {
+    pub id: i64,
+    pub user_id: i64,
+    pub project_id: i64,
+    /// `update_status` / `create_issue`
+    pub operation: String,
+    /// Negative placeholder number for queued creates.
+    pub issue_number: i32,
+    pub payload_json: String,
+    pub base_updated_at: Option<String>,
+    /// `pending` / `failed` / `conflict`
+    pub status: String,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl OutboxOperation {
+    /// Whether the operation needs the user's attention (retry / discard).
+    pub fn needs_attention(&self) -> bool {
+        self.status == "failed" || self.status == "conflict"
+    }
+
+    /// Short human-readable description for the outbox panel.
+    pub fn summary(&self) -> String {
+        let payload: serde_json::Value =
+            serde_json::from_str(&self.payload_json).unwrap_or_default();
+        match self.operation.as_str() {
+            "create_issue" => format!(
+                "Create \"{}\"",
+                payload["title"].as_str().unwrap_or_default()
+            ),
+            _ => format!(
+                "#{} → {}",
+                self.issue_number,
+                payload["new_status"].as_str().unwrap_or_default()
+            ),
+        }
+    }
+}
+
+/// Summary returned by `replay_issue_outbox`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboxReplayResult {
+    pub applied: i32,
+    pub failed: i32,
+    pub conflicts: i32,
+    pub remaining: i32,
+}
+
+/// One hit from `search_issues_local`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueSearchResult {
+    #[serde(flatten)]
+    pub issue: CachedIssue,
+    pub project_name: String,
+    pub repo_full_name: Option<String>,
+    /// Title with matches wrapped in `\u{2}` … `\u{3}` markers.
+    pub title_highlight: String,
+    /// Body excerpt around the first match, same markers.
+    pub body_snippet: Option<String>,
+    pub rank: f64,
+}
+
+/// One timer session on an issue (`issue_time_entries`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeEntry {
+    pub id: i64,
+    pub project_id: i64,
+    pub cached_issue_id: Option<i64>,
+    pub repo_full_name: Option<String>,
+    pub issue_number: i32,
+    pub issue_title: String,
+    pub started_at: String,
+    /// `None` while the timer is running.
+    pub ended_at: Option<String>,
+    /// 0 while the timer is running.
+    pub duration_seconds: i64,
+    pub note: Option<String>,
+    /// Time-based XP granted when the entry stopped.
+    pub xp_awarded: i32,
+}
+
+impl TimeEntry {
+    pub fn is_running(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// Whether this entry belongs to the given issue.
+    pub fn is_for(&self, issue_number: i32, repo_full_name: Option<&str>) -> bool {
+        self.issue_number == issue_number
+            && (repo_full_name.is_none() || self.repo_full_name.as_deref() == repo_full_name)
+    }
+
+    /// Seconds so far; a running timer counts up to `now_ms`.
+    pub fn elapsed_seconds(&self, now_ms: f64) -> i64 {
+        if !self.is_running() {
+            return self.duration_seconds;
+        }
+        let started_ms = js_sys::Date::parse(&self.started_at);
+        if started_ms.is_nan() {
+            return 0;
+        }
+        ((now_ms - started_ms) / 1000.0).max(0.0) as i64
+    }
+}
+
+/// Time logged on one issue (`get_issue_time_entries`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IssueTimeLog {
+    /// Newest first.
+    pub entries: Vec<TimeEntry>,
+    /// Finished entries only.
+    pub total_seconds: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueTimeTotal {
+    pub project_id: i64,
+    pub repo_full_name: Option<String>,
+    pub issue_number: i32,
+    pub issue_title: String,
+    pub total_seconds: i64,
+    pub entry_count: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectTimeTotal {
+    pub project_id: i64,
+    pub project_name: String,
+    pub total_seconds: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyTimeTotal {
+    /// `YYYY-MM-DD` (UTC)
+    pub date: String,
+    pub total_seconds: i64,
+}
+
+/// Tracked time per issue, project and day (`get_time_summary`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimeSummary {
+    pub from: String,
+    pub to: String,
+    pub total_seconds: i64,
+    /// Longest first.
+    pub by_issue: Vec<IssueTimeTotal>,
+    /// Longest first.
+    pub by_project: Vec<ProjectTimeTotal>,
+    /// Every day of the period, oldest first.
+    pub by_day: Vec<DailyTimeTotal>,
+}
+
+/// `1h 05m`, `25m` — same format as the backend's comment summary.
+pub fn format_duration(seconds: i64) -> String {
+    let minutes = seconds.max(0) / 60;
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, m) => format!("{}h {:02}m", h, m),
+    }
+}
+
+/// `0:25:07` for a running timer.
+pub fn format_clock(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
+}
+
+/// Events the generated Actions workflow reacts to, with display names.
+pub const WORKFLOW_EVENTS: &[(&str, &str)] = &[
+    ("push", "Push to branch"),
+    ("pull_request_opened", "PR opened"),
+    ("pull_request_merged", "PR merged"),
+    ("pull_request_closed", "PR closed without merge"),
+];
+
+/// What one event does to the issue named by the branch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowEventRule {
+    pub event: String,
+    /// Column to move the issue to (`None` = leave the status alone).
+    pub status: Option<IssueStatus>,
+    /// Extra labels added alongside the status label.
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+/// Settings for the generated "Issue Status Sync" workflow (`get_workflow_settings`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowSettings {
+    /// Bash (POSIX ERE) pattern a branch name must match.
+    pub branch_pattern: String,
+    /// 1-based capture group holding the issue number.
+    pub issue_number_group: i32,
+    pub excluded_branches: Vec<String>,
+    pub rules: Vec<WorkflowEventRule>,
+    pub close_on_merge: bool,
+    /// `None` while the project uses the defaults.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl WorkflowSettings {
+    /// The rule for `event` (events without one do nothing).
+    pub fn rule(&self, event: &str) -> Option<&WorkflowEventRule> {
+        self.rules.iter().find(|r| r.event == event)
+    }
+
+    /// Mutable rule for `event`, created empty when missing.
+    pub fn rule_mut(&mut self, event: &str) -> &mut WorkflowEventRule {
+        if let Some(index) = self.rules.iter().position(|r| r.event == event) {
+            &mut self.rules[index]
+        } else {
+            self.rules.push(WorkflowEventRule {
+                event: event.to_string(),
+                status: None,
+                labels: Vec::new(),
+            });
+            self.rules.last_mut().unwrap()
+        }
+    }
+}
+
+/// One line of the expected-vs-committed workflow comparison.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowDiffLine {
+    /// `same` / `added` (only expected) / `removed` (only committed)
+    pub kind: String,
+    pub text: String,
+}
+
+/// Result of `check_workflow_drift`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowDrift {
+    pub repo_full_name: String,
+    pub path: String,
+    /// `missing` / `in_sync` / `drifted`
+    pub state: String,
+    pub expected: String,
+    pub actual: Option<String>,
+    pub diff: Vec<WorkflowDiffLine>,
+}
+
+/// One selected card for `bulk_update_issues`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BulkIssueTarget {
+    pub issue_number: i32,
+    pub repo_full_name: Option<String>,
+}
+
+/// What `bulk_update_issues` does with every selected issue.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BulkIssueAction {
+    Move {
+        status: IssueStatus,
+    },
+    AddLabels {
+        labels: Vec<String>,
+    },
+    RemoveLabels {
+        labels: Vec<String>,
+    },
+    /// Replaces the assignees (empty = unassign everyone).
+    SetAssignees {
+        assignees: Vec<String>,
+    },
+    /// `completed` / `not_planned`
+    Close {
+        reason: String,
+    },
+}
+
+/// An issue a bulk action could not be applied to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkIssueFailure {
+    pub issue_number: i32,
+    pub repo_full_name: Option<String>,
+    pub error: String,
+}
+
+/// Result of `bulk_update_issues`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkIssueResult {
+    /// Issues as cached after the batch, including queued offline moves.
+    pub updated: Vec<CachedIssue>,
+    /// How many of `updated` are queued in the outbox.
+    pub queued: usize,
+    pub failed: Vec<BulkIssueFailure>,
+}
+
+/// Split a highlighted search string into `(text, is_match)` segments.
+///
+/// The backend marks matches with control characters instead of HTML so
+/// issue text is always rendered as plain text.
+pub fn highlight_segments(text: &str) -> Vec<(String, bool)> {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+    let mut in_match = false;
+
+    for c in text.chars() {
+        match c {
+            '\u{2}' | '\u{3}' => {
+                if !current.is_empty() {
+                    segments.push((std::mem::take(&mut current), in_match));
+                }
+                in_match = c == '\u{2}';
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        segments.push((current, in_match));
+    }
+
+    segments
+}
@@ -91,7 +91,8 @@ export function issuePriorityColorClass(priority: IssuePriority): string {
 /// Reasons a project may be archived. `null` while the project is healthy.
 export type ProjectArchivedReason = 'repository_gone' | (string & {});
 
-/// Project model (1 project = 1 repository)
+/// Project model. `repo*` mirror the primary linked repository; see
+/// `ProjectRepository` for the full list.
 export interface Project {
   id: number;
   userId: number;
@@ -112,6 +113,24 @@ export interface Project {
   updatedAt: string;
 }
 
+/// Repository linked to a project. A project can link several; the oldest
+/// link is the primary one mirrored on `Project.repo*`.
+export interface ProjectRepository {
+  id: number;
+  projectId: number;
+  githubRepoId: number;
+  repoOwner: string;
+  repoName: string;
+  repoFullName: string;
+  /// True when this repository returned 404 (Issue #190, per repository).
+  /// The project itself is archived only once every repository is.
+  isArchived: boolean;
+  archivedAt: string | null;
+  archivedReason: ProjectArchivedReason | null;
+  lastSyncedAt: string | null;
+  createdAt: string;
+}
+
 /// Check if repository is linked
 export function isLinked(project: Project): boolean {
   return project.githubRepoId !== null;
@@ -182,6 +201,8 @@ export interface SyncFailure {
 export interface CachedIssue {
   id: number;
   projectId: number;
+  /// `owner/repo` the issue belongs to (projects can link several)
+  repoFullName: string | null;
   githubIssueId: number;
   number: number;
   title: string;
@@ -200,6 +221,35 @@ export interface CachedIssue {
   /// disappeared from GitHub. See Issue #190.
   isArchived: boolean;
   archivedAt: string | null;
+  /// True while an offline change to this issue is still queued in the
+  /// outbox. Queued creates also carry a negative placeholder `number`.
+  pendingSync: boolean;
+  /// JSON array of every assignee login (`assigneeLogin` is the first).
+  assigneesJson: string | null;
+  milestoneNumber: number | null;
+  milestoneTitle: string | null;
+  /// GitHub close / reopen reason (`completed`, `not_planned`, `reopened`).
+  stateReason: string | null;
+  /// JSON array of `LinkedPullRequest`, refreshed for open issues on sync.
+  linkedPrsJson: string | null;
+}
+
+/// Pull request linked to an issue (closing reference or mention).
+export interface LinkedPullRequest {
+  number: number;
+  title: string;
+  url: string;
+  repoFullName: string;
+  /// `OPEN` / `CLOSED` / `MERGED`
+  state: string;
+  isDraft: boolean;
+  /// `MERGEABLE` / `CONFLICTING` / `UNKNOWN`
+  mergeable: string;
+  reviewDecision: string | null;
+  /// Head commit `statusCheckRollup.state`; null without checks.
+  checksState: string | null;
+  /// True for a closing reference, false for a plain mention.
+  closesIssue: boolean;
 }
 
 /// Get parsed status
@@ -493,3 +543,352 @@ export interface PrProgress {
   truncated: boolean;
 }
 
+
+/// Kind of a queued offline issue mutation.
+export type OutboxOperationKind = 'update_status' | 'create_issue';
+
+/// Lifecycle state of a queued operation. Applied operations are removed
+/// from the outbox, so they never appear here.
+export type OutboxOperationStatus = 'pending' | 'failed' | 'conflict';
+
+/// One queued issue mutation returned by `get_issue_outbox`.
+///
+/// Mirrors `database::models::issue_outbox::OutboxOperation` on the backend.
+export interface OutboxOperation {
+  id: number;
+  userId: number;
+  projectId: number;
+  operation: OutboxOperationKind | (string & {});
+  /// Negative placeholder number for queued creates.
+  issueNumber: number;
+  payloadJson: string;
+  /// `githubUpdatedAt` observed at enqueue time (conflict baseline).
+  baseUpdatedAt: string | null;
+  status: OutboxOperationStatus | (string & {});
+  attempts: number;
+  lastError: string | null;
+  createdAt: string;
+  updatedAt: string;
+}
+
+/// Summary returned by `replay_issue_outbox`.
+export interface OutboxReplayResult {
+  applied: number;
+  failed: number;
+  conflicts: number;
+  /// Still pending because GitHub remained unreachable.
+  remaining: number;
+}
+
+/// One hit returned by `search_issues_local`.
+///
+/// Matches in `titleHighlight` / `bodySnippet` are wrapped in `\u0002` …
+/// `\u0003` marker characters (not HTML); split on them to render
+/// highlights without injecting issue text as markup.
+export interface IssueSearchResult extends CachedIssue {
+  projectName: string;
+  repoFullName: string | null;
+  titleHighlight: string;
+  /// Body excerpt around the first match; null when only other fields matched.
+  bodySnippet: string | null;
+  /// bm25 score (lower is better); 0 for filter-only searches.
+  rank: number;
+}
+
+/// Request for `update_issue_fields`. Omitted fields are left untouched;
+/// `milestone: null` clears the milestone. `status:*` labels are managed
+/// by the kanban and ignored here.
+export interface UpdateIssueFieldsRequest {
+  title?: string;
+  body?: string;
+  assignees?: string[];
+  milestone?: number | null;
+  labels?: string[];
+}
+
+/// Choices for the issue edit form (`get_issue_edit_options`).
+export interface IssueEditOptions {
+  labels: LabelOption[];
+  assignees: AssigneeOption[];
+  milestones: MilestoneOption[];
+}
+
+export interface LabelOption {
+  name: string;
+  color: string;
+}
+
+export interface AssigneeOption {
+  login: string;
+  avatarUrl: string;
+}
+
+export interface MilestoneOption {
+  number: number;
+  title: string;
+  dueOn: string | null;
+}
+
+/// One option of a `checkboxes` issue form element.
+export interface IssueFormCheckbox {
+  label: string;
+  required: boolean;
+}
+
+/// One element of an issue form. Values are keyed by `key` (the element's
+/// `id`, or `field-<index>`).
+export interface IssueFormField {
+  key: string;
+  /// `markdown` / `textarea` / `input` / `dropdown` / `checkboxes`
+  fieldType: string;
+  label: string | null;
+  description: string | null;
+  placeholder: string | null;
+  /// Default value, or the text of a `markdown` element.
+  value: string | null;
+  options: string[];
+  checkboxes: IssueFormCheckbox[];
+  multiple: boolean;
+  defaultOption: number | null;
+  /// `textarea` content is wrapped in a code block of this language.
+  render: string | null;
+  required: boolean;
+}
+
+/// A parsed `.github/ISSUE_TEMPLATE/` file.
+export interface IssueTemplate {
+  fileName: string;
+  /// `markdown` / `form`
+  kind: string;
+  name: string;
+  description: string | null;
+  title: string | null;
+  labels: string[];
+  assignees: string[];
+  /// Markdown templates only.
+  body: string | null;
+  /// Issue forms only.
+  fields: IssueFormField[];
+}
+
+/// Templates of one repository (`get_issue_templates`).
+export interface IssueTemplateSet {
+  templates: IssueTemplate[];
+  blankIssuesEnabled: boolean;
+  /// Files that could not be fetched or parsed, as `<file>: <reason>`.
+  invalid: string[];
+}
+
+/// Issue ready to be created (`build_issue_from_template`).
+export interface IssueDraft {
+  title: string;
+  body: string;
+  labels: string[];
+  assignees: string[];
+}
+
+/// Count of one reaction kind on a comment (zero counts are omitted).
+export interface ReactionCount {
+  /// GitHub reaction content: `+1`, `-1`, `laugh`, `hooray`, `confused`,
+  /// `heart`, `rocket`, `eyes`.
+  content: string;
+  emoji: string;
+  count: number;
+}
+
+/// One comment of an issue thread (`get_issue_comments`).
+export interface IssueComment {
+  id: number;
+  /// null for comments of deleted accounts.
+  authorLogin: string | null;
+  authorAvatarUrl: string | null;
+  authorAssociation: string | null;
+  body: string;
+  htmlUrl: string;
+  createdAt: string;
+  updatedAt: string;
+  reactions: ReactionCount[];
+}
+
+/// One page of an issue's comments, oldest first.
+export interface IssueCommentsPage {
+  comments: IssueComment[];
+  /// 1-based page number.
+  page: number;
+  /// True when another page may follow.
+  hasMore: boolean;
+}
+
+/// Synced milestone of a project (`get_project_milestones`).
+export interface ProjectMilestone {
+  id: number;
+  projectId: number;
+  githubMilestoneId: number;
+  number: number;
+  title: string;
+  description: string | null;
+  /// `open` / `closed`
+  state: string;
+  dueOn: string | null;
+  openIssues: number;
+  closedIssues: number;
+  githubCreatedAt: string | null;
+  githubClosedAt: string | null;
+  syncedAt: string;
+}
+
+/// One day of a milestone burndown / burnup series. Actual values are
+/// null for days after the last observed day.
+export interface BurndownPoint {
+  /// `YYYY-MM-DD` (UTC)
+  date: string;
+  open: number | null;
+  closed: number | null;
+  scope: number | null;
+  /// Ideal remaining line to the due date; null without a due date.
+  ideal: number | null;
+}
+
+export interface MilestoneBurndown {
+  milestone: ProjectMilestone;
+  points: BurndownPoint[];
+}
+
+/// One timer session on an issue (`issue_time_entries`).
+export interface TimeEntry {
+  id: number;
+  projectId: number;
+  cachedIssueId: number | null;
+  repoFullName: string | null;
+  issueNumber: number;
+  issueTitle: string;
+  startedAt: string;
+  /// null while the timer is running.
+  endedAt: string | null;
+  /// 0 while the timer is running.
+  durationSeconds: number;
+  note: string | null;
+  /// Time-based XP granted when the entry stopped.
+  xpAwarded: number;
+}
+
+/// Time logged on one issue (`get_issue_time_entries`).
+export interface IssueTimeLog {
+  /// Newest first.
+  entries: TimeEntry[];
+  /// Finished entries only.
+  totalSeconds: number;
+}
+
+export interface IssueTimeTotal {
+  projectId: number;
+  repoFullName: string | null;
+  issueNumber: number;
+  issueTitle: string;
+  totalSeconds: number;
+  entryCount: number;
+}
+
+export interface ProjectTimeTotal {
+  projectId: number;
+  projectName: string;
+  totalSeconds: number;
+}
+
+export interface DailyTimeTotal {
+  /// `YYYY-MM-DD` (UTC)
+  date: string;
+  totalSeconds: number;
+}
+
+/// Tracked time per issue, project and day (`get_time_summary`).
+export interface TimeSummary {
+  from: string;
+  to: string;
+  totalSeconds: number;
+  /// Longest first.
+  byIssue: IssueTimeTotal[];
+  /// Longest first.
+  byProject: ProjectTimeTotal[];
+  /// Every day of the period, oldest first.
+  byDay: DailyTimeTotal[];
+}
+
+/// Events the generated Actions workflow reacts to, with display names.
+export const WORKFLOW_EVENTS: ReadonlyArray<readonly [string, string]> = [
+  ['push', 'Push to branch'],
+  ['pull_request_opened', 'PR opened'],
+  ['pull_request_merged', 'PR merged'],
+  ['pull_request_closed', 'PR closed without merge'],
+];
+
+/// What one event does to the issue named by the branch.
+export interface WorkflowEventRule {
+  event: string;
+  /// Column to move the issue to (`null` = leave the status alone).
+  status: IssueStatus | null;
+  /// Extra labels added alongside the status label.
+  labels: string[];
+}
+
+/// Settings for the generated "Issue Status Sync" workflow (`get_workflow_settings`).
+export interface WorkflowSettings {
+  /// Bash (POSIX ERE) pattern a branch name must match.
+  branchPattern: string;
+  /// 1-based capture group holding the issue number.
+  issueNumberGroup: number;
+  excludedBranches: string[];
+  rules: WorkflowEventRule[];
+  closeOnMerge: boolean;
+  /// `null` while the project uses the defaults.
+  updatedAt: string | null;
+}
+
+/// One line of the expected-vs-committed workflow comparison.
+export interface WorkflowDiffLine {
+  /// `same` / `added` (only expected) / `removed` (only committed)
+  kind: 'same' | 'added' | 'removed';
+  text: string;
+}
+
+/// Result of `check_workflow_drift`.
+export interface WorkflowDrift {
+  repoFullName: string;
+  path: string;
+  state: 'missing' | 'in_sync' | 'drifted';
+  expected: string;
+  actual: string | null;
+  diff: WorkflowDiffLine[];
+}
+
+/// One selected card for `bulk_update_issues`.
+export interface BulkIssueTarget {
+  issueNumber: number;
+  /// `null` = the project's primary repository.
+  repoFullName: string | null;
+}
+
+/// What `bulk_update_issues` does with every selected issue.
+export type BulkIssueAction =
+  | { type: 'move'; status: IssueStatus }
+  | { type: 'add_labels'; labels: string[] }
+  | { type: 'remove_labels'; labels: string[] }
+  /// Replaces the assignees (empty = unassign everyone).
+  | { type: 'set_assignees'; assignees: string[] }
+  | { type: 'close'; reason: 'completed' | 'not_planned' };
+
+/// An issue a bulk action could not be applied to.
+export interface BulkIssueFailure {
+  issueNumber: number;
+  repoFullName: string | null;
+  error: string;
+}
+
+/// Result of `bulk_update_issues`.
+export interface BulkIssueResult {
+  /// Issues as cached after the batch, including queued offline moves.
+  updated: CachedIssue[];
+  /// How many of `updated` are queued in the outbox.
+  queued: number;
+  failed: BulkIssueFailure[];
+}
@@ -14,10 +14,24 @@ pub struct UserSettings {
     pub notify_badge_earned: bool,
     pub notify_streak_update: bool,
     pub notify_streak_milestone: bool,
+    #[serde(default)]
+    pub notify_weekly_report: bool,
     pub sync_interval_minutes: i32,
     pub background_sync: bool,
     pub sync_on_startup: bool,
     pub animations_enabled: bool,
+    #[serde(default)]
+    pub challenge_difficulty: String,
+    #[serde(default = "default_prestige_level")]
+    pub prestige_level: i32,
+    #[serde(default = "default_xp_daily_commit_cap")]
+    pub xp_daily_commit_cap: i32,
+    #[serde(default)]
+    pub xp_min_commit_lines: i32,
+    #[serde(default = "default_xp_exclude_bot_commits")]
+    pub xp_exclude_bot_commits: bool,
+    #[serde(default)]
+    pub xp_excluded_repositories: Vec<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -32,10 +46,17 @@ pub struct UpdateSettingsRequest {
     pub notify_badge_earned: bool,
     pub notify_streak_update: bool,
     pub notify_streak_milestone: bool,
+    pub notify_weekly_report: bool,
     pub sync_interval_minutes: i32,
     pub background_sync: bool,
     pub sync_on_startup: bool,
     pub animations_enabled: bool,
+    pub challenge_difficulty: String,
+    pub prestige_level: i32,
+    pub xp_daily_commit_cap: i32,
+    pub xp_min_commit_lines: i32,
+    pub xp_exclude_bot_commits: bool,
+    pub xp_excluded_repositories: Vec<String>,
 }
 
 impl From<&UserSettings> for UpdateSettingsRequest {
@@ -47,10 +68,17 @@ impl From<&UserSettings> for UpdateSettingsRequest {
             notify_badge_earned: settings.notify_badge_earned,
             notify_streak_update: settings.notify_streak_update,
             notify_streak_milestone: settings.notify_streak_milestone,
+            notify_weekly_report: settings.notify_weekly_report,
             sync_interval_minutes: settings.sync_interval_minutes,
             background_sync: settings.background_sync,
             sync_on_startup: settings.sync_on_startup,
             animations_enabled: settings.animations_enabled,
+            challenge_difficulty: settings.challenge_difficulty.clone(),
+            prestige_level: settings.prestige_level,
+            xp_daily_commit_cap: settings.xp_daily_commit_cap,
+            xp_min_commit_lines: settings.xp_min_commit_lines,
+            xp_exclude_bot_commits: settings.xp_exclude_bot_commits,
+            xp_excluded_repositories: settings.xp_excluded_repositories.clone(),
         }
     }
 }
@@ -129,6 +157,58 @@ impl NotificationMethod {
     }
 }
 
+/// 自動生成チャレンジの難易度
+///
+/// **IMPORTANT**: This enum must be kept in sync with the backend definition at:
+/// `src-tauri/src/database/models/challenge.rs::ChallengeDifficulty`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChallengeDifficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl ChallengeDifficulty {
+    pub const ALL: [ChallengeDifficulty; 3] = [
+        ChallengeDifficulty::Easy,
+        ChallengeDifficulty::Normal,
+        ChallengeDifficulty::Hard,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChallengeDifficulty::Easy => "easy",
+            ChallengeDifficulty::Normal => "normal",
+            ChallengeDifficulty::Hard => "hard",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "easy" => ChallengeDifficulty::Easy,
+            "hard" => ChallengeDifficulty::Hard,
+            _ => ChallengeDifficulty::Normal,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChallengeDifficulty::Easy => "やさしい",
+            ChallengeDifficulty::Normal => "ふつう",
+            ChallengeDifficulty::Hard => "むずかしい",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            ChallengeDifficulty::Easy => "目標を控えめにします（報酬 0.8倍）",
+            ChallengeDifficulty::Normal => "普段のペースに合わせます",
+            ChallengeDifficulty::Hard => "目標を高めにします（報酬 1.3倍）",
+        }
+    }
+}
+
 /// 同期間隔の選択肢
 ///
 /// **IMPORTANT**: This constant must be kept in sync with the backend definition at:
@@ -145,6 +225,33 @@ pub const SYNC_INTERVALS: &[(i32, &str)] = &[
     (0, "手動のみ"),
 ];
 
+/// プレステージ解放レベルの選択肢
+///
+/// **IMPORTANT**: This constant must be kept in sync with the backend definition at:
+/// `src-tauri/src/database/models/settings.rs::settings_defaults::PRESTIGE_LEVELS`
+pub const PRESTIGE_LEVELS: &[i32] = &[30, 50, 75, 100];
+
+/// プレステージ解放レベルのデフォルト値
+pub const DEFAULT_PRESTIGE_LEVEL: i32 = 50;
+
+fn default_prestige_level() -> i32 {
+    DEFAULT_PRESTIGE_LEVEL
+}
+
+/// 1日あたり満額XPとなるコミット数のデフォルト値
+///
+/// **IMPORTANT**: Must be kept in sync with the backend definition at:
+/// `src-tauri/src/database/models/settings.rs::settings_defaults::XP_DAILY_COMMIT_CAP`
+pub const DEFAULT_XP_DAILY_COMMIT_CAP: i32 = 20;
+
+fn default_xp_daily_commit_cap() -> i32 {
+    DEFAULT_XP_DAILY_COMMIT_CAP
+}
+
+fn default_xp_exclude_bot_commits() -> bool {
+    true
+}
+
 /// 同期間隔のラベルを取得
 pub fn get_sync_interval_label(minutes: i32) -> &'static str {
     SYNC_INTERVALS
@@ -161,3 +268,79 @@ pub struct SyncIntervalOption {
     pub value: i32,
     pub label: String,
 }
+
+/// リポジトリフィルターのプリセット
+///
+/// **IMPORTANT**: This enum must be kept in sync with the backend definition at:
+/// `src-tauri/src/database/models/repo_filter.rs::repo_filter_presets`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RepoFilterPreset {
+    #[default]
+    All,
+    Work,
+    Personal,
+}
+
+impl RepoFilterPreset {
+    pub const ALL: [RepoFilterPreset; 3] = [
+        RepoFilterPreset::All,
+        RepoFilterPreset::Work,
+        RepoFilterPreset::Personal,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RepoFilterPreset::All => "all",
+            RepoFilterPreset::Work => "work",
+            RepoFilterPreset::Personal => "personal",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "work" => RepoFilterPreset::Work,
+            "personal" => RepoFilterPreset::Personal,
+            _ => RepoFilterPreset::All,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RepoFilterPreset::All => "すべて",
+            RepoFilterPreset::Work => "仕事のみ",
+            RepoFilterPreset::Personal => "個人のみ",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            RepoFilterPreset::All => "すべてのリポジトリを集計します",
+            RepoFilterPreset::Work => "仕事用オーナーのリポジトリだけを集計します",
+            RepoFilterPreset::Personal => "仕事用オーナー以外のリポジトリだけを集計します",
+        }
+    }
+}
+
+/// リポジトリ / Organization フィルター (`get_repository_filter`)
+///
+/// XP・バッジ・コード統計・ダッシュボードの集計対象を絞り込む。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryFilter {
+    /// `all` / `work` / `personal`
+    pub preset: String,
+    /// 仕事用とみなすオーナー（空なら自分以外のオーナーすべて）
+    pub work_owners: Vec<String>,
+    /// `owner/name`
+    pub include_repositories: Vec<String>,
+    /// `owner/name`
+    pub exclude_repositories: Vec<String>,
+    pub include_owners: Vec<String>,
+    pub exclude_owners: Vec<String>,
+    pub include_forks: bool,
+    pub include_archived: bool,
+    pub include_private: bool,
+    /// 未保存（デフォルト）の間は `None`
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
@@ -37,6 +37,36 @@ export function notificationMethodLabel(method: NotificationMethod): string {
   }
 }
 
+/// 自動生成チャレンジの難易度
+///
+/// **IMPORTANT**: Must be kept in sync with the backend definition at:
+/// `src-tauri/src/database/models/challenge.rs::ChallengeDifficulty`
+export type ChallengeDifficulty = 'easy' | 'normal' | 'hard';
+
+export const CHALLENGE_DIFFICULTIES: ChallengeDifficulty[] = ['easy', 'normal', 'hard'];
+
+export function challengeDifficultyLabel(difficulty: ChallengeDifficulty): string {
+  switch (difficulty) {
+    case 'easy':
+      return 'やさしい';
+    case 'normal':
+      return 'ふつう';
+    case 'hard':
+      return 'むずかしい';
+  }
+}
+
+export function challengeDifficultyDescription(difficulty: ChallengeDifficulty): string {
+  switch (difficulty) {
+    case 'easy':
+      return '目標を控えめにします（報酬 0.8倍）';
+    case 'normal':
+      return '普段のペースに合わせます';
+    case 'hard':
+      return '目標を高めにします（報酬 1.3倍）';
+  }
+}
+
 /// ユーザー設定
 export interface UserSettings {
   id: number;
@@ -47,10 +77,21 @@ export interface UserSettings {
   notifyBadgeEarned: boolean;
   notifyStreakUpdate: boolean;
   notifyStreakMilestone: boolean;
+  /// 週間レポート通知（毎週月曜、既定OFF）
+  notifyWeeklyReport: boolean;
   syncIntervalMinutes: number;
   backgroundSync: boolean;
   syncOnStartup: boolean;
   animationsEnabled: boolean;
+  challengeDifficulty: ChallengeDifficulty;
+  prestigeLevel: number;
+  /// 1日あたり満額XPとなるコミット数（0 = 無効）
+  xpDailyCommitCap: number;
+  /// コミットあたりの最小差分行数（0 = 無効）
+  xpMinCommitLines: number;
+  xpExcludeBotCommits: boolean;
+  /// XP対象外のリポジトリ（owner/name）
+  xpExcludedRepositories: string[];
   createdAt: string;
   updatedAt: string;
 }
@@ -63,10 +104,17 @@ export interface UpdateSettingsRequest {
   notifyBadgeEarned: boolean;
   notifyStreakUpdate: boolean;
   notifyStreakMilestone: boolean;
+  notifyWeeklyReport?: boolean;
   syncIntervalMinutes: number;
   backgroundSync: boolean;
   syncOnStartup: boolean;
   animationsEnabled: boolean;
+  challengeDifficulty?: ChallengeDifficulty;
+  prestigeLevel?: number;
+  xpDailyCommitCap?: number;
+  xpMinCommitLines?: number;
+  xpExcludeBotCommits?: boolean;
+  xpExcludedRepositories?: string[];
 }
 
 /// データベース情報
@@ -106,6 +154,21 @@ export const SYNC_INTERVALS: [number, string][] = [
   [0, '手動のみ'],
 ];
 
+/// プレステージ解放レベルの選択肢
+///
+/// **IMPORTANT**: Must be kept in sync with the backend definition at:
+/// `src-tauri/src/database/models/settings.rs::settings_defaults::PRESTIGE_LEVELS`
+export const PRESTIGE_LEVELS: number[] = [30, 50, 75, 100];
+
+/// プレステージ解放レベルのデフォルト値
+export const DEFAULT_PRESTIGE_LEVEL = 50;
+
+/// 1日あたり満額XPとなるコミット数のデフォルト値
+///
+/// **IMPORTANT**: Must be kept in sync with the backend definition at:
+/// `src-tauri/src/database/models/settings.rs::settings_defaults::XP_DAILY_COMMIT_CAP`
+export const DEFAULT_XP_DAILY_COMMIT_CAP = 20;
+
 /// 同期間隔のラベルを取得
 export function getSyncIntervalLabel(minutes: number): string {
   const found = SYNC_INTERVALS.find(([m]) => m === minutes);
@@ -161,3 +224,54 @@ export function schedulerSkipReasonLabel(reason: string | null | undefined): str
   }
 }
 
+
+/// リポジトリフィルターのプリセット
+///
+/// **IMPORTANT**: keep in sync with `src-tauri/src/database/models/repo_filter.rs::repo_filter_presets`.
+export type RepoFilterPreset = 'all' | 'work' | 'personal';
+
+export const REPO_FILTER_PRESETS: RepoFilterPreset[] = ['all', 'work', 'personal'];
+
+/// プリセットのラベルを取得
+export function repoFilterPresetLabel(preset: RepoFilterPreset): string {
+  switch (preset) {
+    case 'work':
+      return '仕事のみ';
+    case 'personal':
+      return '個人のみ';
+    default:
+      return 'すべて';
+  }
+}
+
+/// プリセットの説明を取得
+export function repoFilterPresetDescription(preset: RepoFilterPreset): string {
+  switch (preset) {
+    case 'work':
+      return '仕事用オーナーのリポジトリだけを集計します';
+    case 'personal':
+      return '仕事用オーナー以外のリポジトリだけを集計します';
+    default:
+      return 'すべてのリポジトリを集計します';
+  }
+}
+
+/// リポジトリ / Organization フィルター (`get_repository_filter`)
+///
+/// XP・バッジ・コード統計・ダッシュボードの集計対象を絞り込む。
+export interface RepositoryFilter {
+  preset: RepoFilterPreset;
+  /// 仕事用とみなすオーナー（空なら自分以外のオーナーすべて）
+  workOwners: string[];
+  /// owner/name
+  includeRepositories: string[];
+  /// owner/name
+  excludeRepositories: string[];
+  includeOwners: string[];
+  excludeOwners: string[];
+  includeForks: boolean;
+  includeArchived: boolean;
+  includePrivate: boolean;
+  /// 未保存（デフォルト）の間は null
+  updatedAt: string | null;
+}